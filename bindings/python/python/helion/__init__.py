@@ -20,7 +20,18 @@ from ._helion import (
     Point2D,
     Color,
     PyScatterPlot as ScatterPlot,
+    PyLinePlot as LinePlot,
+    PyFigure as Figure,
+    PyPlotManager as PlotManager,
+    PySeries as Series,
     scatter,
+    line,
+    figure,
+    hist,
+    heatmap,
+    hist2d,
+    register_colormap,
+    colormaps,
 )
 
 __all__ = [
@@ -28,5 +39,16 @@ __all__ = [
     "Point2D",
     "Color",
     "ScatterPlot",
+    "LinePlot",
+    "Figure",
+    "PlotManager",
+    "Series",
     "scatter",
+    "line",
+    "figure",
+    "hist",
+    "heatmap",
+    "hist2d",
+    "register_colormap",
+    "colormaps",
 ]
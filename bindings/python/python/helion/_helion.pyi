@@ -0,0 +1,345 @@
+# Type stubs for the compiled extension. Keep in step with src/lib.rs:
+# tests/test_scatter_plots.py::TestTypeStubs fails on any public name
+# missing from either side.
+
+from collections.abc import Callable, Iterator, Sequence
+from datetime import datetime
+from os import PathLike
+from types import TracebackType
+from typing import Any, Literal, Optional, Union, overload
+
+import numpy as np
+import numpy.typing as npt
+
+__version__: str
+
+ArrayLike = npt.ArrayLike
+ColorLike = Union[str, tuple[float, float, float], tuple[float, float, float, float]]
+Range = tuple[float, float]
+Scale = Literal["linear", "log", "log10", "log2", "symlog"]
+AxisKind = Literal["time"]
+Missing = Literal["skip", "clamp", "error"]
+Norm = Literal["linear", "log", "sqrt", "cbrt", "eq_hist"]
+EventName = Literal["view_changed", "point_picked", "point_hovered", "selection_changed", "data_appended"]
+TimeLike = Union[ArrayLike, Sequence[datetime]]
+# pandas, polars or pyarrow tables, other frames implementing
+# __dataframe__, or dicts of arrays
+DataFrameLike = Any
+
+class Point2D:
+    x: float
+    y: float
+    def __init__(self, x: float, y: float) -> None: ...
+
+class Color:
+    r: float
+    g: float
+    b: float
+    a: float
+    def __init__(self, r: float, g: float, b: float, a: float = 1.0) -> None: ...
+    @staticmethod
+    def from_hex(hex: str) -> Color: ...
+    @staticmethod
+    def from_name(name: str) -> Color: ...
+    @staticmethod
+    def from_hsl(h: float, s: float, l: float, a: float = 1.0) -> Color: ...
+    @staticmethod
+    def from_hsv(h: float, s: float, v: float, a: float = 1.0) -> Color: ...
+
+class PyScatterPlot:
+    def __init__(self) -> None: ...
+    def set_title(self, title: str) -> None: ...
+    def show(self, title: Optional[str] = None, serve: Optional[bool] = None, port: int = 0) -> None: ...
+    def save(
+        self, path: Union[str, PathLike[str]], width: Optional[int] = None, height: Optional[int] = None
+    ) -> None: ...
+    def _repr_png_(self) -> Optional[bytes]: ...
+    def _repr_html_(self) -> Optional[str]: ...
+    def _repr_mimebundle_(
+        self, include: Optional[Any] = None, exclude: Optional[Any] = None
+    ) -> Optional[dict[str, Union[str, bytes]]]: ...
+    def on(self, event: EventName, callback: Callable[[dict[str, Any]], Any]) -> None: ...
+    def on_hover(self, callback: Callable[[dict[str, Any]], Any]) -> None: ...
+    def on_select(self, callback: Callable[[dict[str, Any]], Any]) -> None: ...
+    def select_box(self, x0: float, y0: float, x1: float, y1: float) -> npt.NDArray[np.int64]: ...
+    def select_lasso(self, points: Sequence[tuple[float, float]]) -> npt.NDArray[np.int64]: ...
+    def pick(self, x_px: float, y_px: float) -> Optional[int]: ...
+    def set_tooltip(self, callback: Optional[Callable[[dict[str, Any]], Optional[str]]]) -> None: ...
+    def frame_stats(self) -> Optional[dict[str, int]]: ...
+    def get_selection(self) -> npt.NDArray[np.int64]: ...
+    def append(
+        self,
+        x: ArrayLike,
+        y: ArrayLike,
+        color: Optional[ColorLike] = None,
+        size: Optional[float] = None,
+        max_points: Optional[int] = None,
+    ) -> None: ...
+    def set_alpha(self, alpha: Union[float, ArrayLike]) -> None: ...
+    def set_sizes(self, size: Union[float, ArrayLike]) -> None: ...
+    def set_colors(self, colors: Union[ArrayLike, Sequence[str]]) -> None: ...
+    def from_arrays(
+        self,
+        x: ArrayLike,
+        y: ArrayLike,
+        color: Optional[tuple[float, float, float, float]] = None,
+        size: Optional[float] = None,
+        width: float = 800.0,
+        height: float = 600.0,
+        x_range: Optional[Range] = None,
+        y_range: Optional[Range] = None,
+        xscale: Optional[Scale] = None,
+        yscale: Optional[Scale] = None,
+        xaxis: Optional[AxisKind] = None,
+        missing: Optional[Missing] = None,
+    ) -> str: ...
+    def __len__(self) -> int: ...
+    @property
+    def dropped_points(self) -> int: ...
+
+class PyLinePlot:
+    def __init__(self) -> None: ...
+    def set_title(self, title: str) -> None: ...
+    def show(self, title: Optional[str] = None, serve: Optional[bool] = None, port: int = 0) -> None: ...
+    def save(
+        self, path: Union[str, PathLike[str]], width: Optional[int] = None, height: Optional[int] = None
+    ) -> None: ...
+    def _repr_png_(self) -> Optional[bytes]: ...
+    def _repr_html_(self) -> Optional[str]: ...
+    def _repr_mimebundle_(
+        self, include: Optional[Any] = None, exclude: Optional[Any] = None
+    ) -> Optional[dict[str, Union[str, bytes]]]: ...
+    def on(self, event: EventName, callback: Callable[[dict[str, Any]], Any]) -> None: ...
+    def frame_stats(self) -> Optional[dict[str, int]]: ...
+    def get_selection(self) -> npt.NDArray[np.int64]: ...
+    def from_arrays(
+        self,
+        x: ArrayLike,
+        y: ArrayLike,
+        color: Optional[tuple[float, float, float, float]] = None,
+        width: float = 800.0,
+        height: float = 600.0,
+        x_range: Optional[Range] = None,
+        y_range: Optional[Range] = None,
+        xscale: Optional[Scale] = None,
+        yscale: Optional[Scale] = None,
+        xaxis: Optional[AxisKind] = None,
+        missing: Optional[Missing] = None,
+    ) -> str: ...
+    def __len__(self) -> int: ...
+    @property
+    def dropped_points(self) -> int: ...
+
+class PyFigure:
+    def __init__(
+        self, width: float = 800.0, height: float = 600.0, save: Optional[Union[str, PathLike[str]]] = None
+    ) -> None: ...
+    def __enter__(self) -> PyFigure: ...
+    def __exit__(
+        self,
+        exc_type: Optional[type[BaseException]],
+        _exc_value: Optional[BaseException],
+        _traceback: Optional[TracebackType],
+    ) -> bool: ...
+    def set_title(self, title: str) -> None: ...
+    def set_xlabel(self, label: Optional[str]) -> None: ...
+    def set_ylabel(self, label: Optional[str]) -> None: ...
+    @property
+    def title(self) -> Optional[str]: ...
+    @property
+    def xlabel(self) -> Optional[str]: ...
+    @property
+    def ylabel(self) -> Optional[str]: ...
+    def legend(self, loc: str = "upper right") -> None: ...
+    def scatter(
+        self,
+        x: TimeLike,
+        y: ArrayLike,
+        color: Optional[ColorLike] = None,
+        size: Optional[float] = None,
+        label: Optional[str] = None,
+        xscale: Optional[Scale] = None,
+        yscale: Optional[Scale] = None,
+        xaxis: Optional[AxisKind] = None,
+        zorder: Optional[int] = None,
+    ) -> PySeries: ...
+    def line(
+        self,
+        x: TimeLike,
+        y: ArrayLike,
+        color: Optional[ColorLike] = None,
+        label: Optional[str] = None,
+        xscale: Optional[Scale] = None,
+        yscale: Optional[Scale] = None,
+        xaxis: Optional[AxisKind] = None,
+        zorder: Optional[int] = None,
+        gap: Optional[float] = None,
+    ) -> PySeries: ...
+    def hist(
+        self,
+        values: ArrayLike,
+        bins: int = 64,
+        density: bool = False,
+        color: Optional[ColorLike] = None,
+        label: Optional[str] = None,
+        zorder: Optional[int] = None,
+    ) -> PySeries: ...
+    def heatmap(
+        self,
+        z: ArrayLike,
+        extent: Optional[tuple[float, float, float, float]] = None,
+        cmap: str = "viridis",
+        vmin: Optional[float] = None,
+        vmax: Optional[float] = None,
+        norm: Norm = "linear",
+        center: Optional[float] = None,
+    ) -> None: ...
+    def hist2d(
+        self,
+        x: ArrayLike,
+        y: ArrayLike,
+        bins: Optional[Union[int, tuple[int, int]]] = None,
+        range: Optional[tuple[Range, Range]] = None,
+        cmap: str = "viridis",
+        norm: Norm = "linear",
+    ) -> None: ...
+    def show(self, title: Optional[str] = None, serve: Optional[bool] = None, port: int = 0) -> None: ...
+    def save(
+        self, path: Union[str, PathLike[str]], width: Optional[int] = None, height: Optional[int] = None
+    ) -> None: ...
+    def _repr_png_(self) -> Optional[bytes]: ...
+    def _repr_html_(self) -> Optional[str]: ...
+    def _repr_mimebundle_(
+        self, include: Optional[Any] = None, exclude: Optional[Any] = None
+    ) -> Optional[dict[str, Union[str, bytes]]]: ...
+    def on(self, event: EventName, callback: Callable[[dict[str, Any]], Any]) -> None: ...
+    def on_hover(self, callback: Callable[[dict[str, Any]], Any]) -> None: ...
+    def on_select(self, callback: Callable[[dict[str, Any]], Any]) -> None: ...
+    def pick(self, x_px: float, y_px: float) -> Optional[tuple[int, int]]: ...
+    def set_tooltip(self, callback: Optional[Callable[[dict[str, Any]], Optional[str]]]) -> None: ...
+    def frame_stats(self) -> Optional[dict[str, int]]: ...
+    def get_selection(self) -> npt.NDArray[np.int64]: ...
+    def to_spec(self) -> str: ...
+    def __len__(self) -> int: ...
+    def __iter__(self) -> Iterator[PySeries]: ...
+
+class PySeries:
+    @property
+    def kind(self) -> Literal["scatter", "line", "bars"]: ...
+    @property
+    def label(self) -> Optional[str]: ...
+    @property
+    def color(self) -> Color: ...
+    @property
+    def zorder(self) -> int: ...
+    def set_zorder(self, zorder: int) -> None: ...
+    def set_data_labels(self, labels: Optional[Sequence[str]] = None, precision: int = 2) -> None: ...
+    @property
+    def x(self) -> npt.NDArray[np.float32]: ...
+    @property
+    def y(self) -> npt.NDArray[np.float32]: ...
+    def __len__(self) -> int: ...
+    def __iter__(self) -> Iterator[tuple[float, float]]: ...
+
+class PyPlotManager:
+    def __init__(self) -> None: ...
+    def add(self, plot: Union[PyScatterPlot, PyLinePlot, PyFigure], title: Optional[str] = None) -> None: ...
+    def show(self) -> None: ...
+    def frame_stats(self) -> list[Optional[dict[str, int]]]: ...
+    def __len__(self) -> int: ...
+
+@overload
+def scatter(
+    x: TimeLike,
+    y: ArrayLike,
+    *,
+    size: Optional[Union[float, ArrayLike]] = None,
+    alpha: Optional[Union[float, ArrayLike]] = None,
+    c: Optional[ArrayLike] = None,
+    color: Optional[Union[ColorLike, ArrayLike, Sequence[str]]] = None,
+    width: float = 800.0,
+    height: float = 600.0,
+    x_range: Optional[Range] = None,
+    y_range: Optional[Range] = None,
+    cmap: str = "viridis",
+    vmin: Optional[float] = None,
+    vmax: Optional[float] = None,
+    norm: Norm = "linear",
+    center: Optional[float] = None,
+    xscale: Optional[Scale] = None,
+    yscale: Optional[Scale] = None,
+    xaxis: Optional[AxisKind] = None,
+    missing: Optional[Missing] = None,
+) -> PyScatterPlot: ...
+@overload
+def scatter(
+    data: DataFrameLike,
+    /,
+    *,
+    x: Union[str, TimeLike],
+    y: Union[str, ArrayLike],
+    size: Optional[Union[str, float, ArrayLike]] = None,
+    alpha: Optional[Union[str, float, ArrayLike]] = None,
+    c: Optional[Union[str, ArrayLike]] = None,
+    color: Optional[Union[ColorLike, ArrayLike, Sequence[str]]] = None,
+    width: float = 800.0,
+    height: float = 600.0,
+    x_range: Optional[Range] = None,
+    y_range: Optional[Range] = None,
+    cmap: str = "viridis",
+    vmin: Optional[float] = None,
+    vmax: Optional[float] = None,
+    norm: Norm = "linear",
+    center: Optional[float] = None,
+    xscale: Optional[Scale] = None,
+    yscale: Optional[Scale] = None,
+    xaxis: Optional[AxisKind] = None,
+    missing: Optional[Missing] = None,
+) -> PyScatterPlot: ...
+def line(
+    x: TimeLike,
+    y: ArrayLike,
+    color: Optional[ColorLike] = None,
+    width: float = 800.0,
+    height: float = 600.0,
+    x_range: Optional[Range] = None,
+    y_range: Optional[Range] = None,
+    xscale: Optional[Scale] = None,
+    yscale: Optional[Scale] = None,
+    xaxis: Optional[AxisKind] = None,
+    missing: Optional[Missing] = None,
+) -> PyLinePlot: ...
+def figure(
+    width: float = 800.0, height: float = 600.0, save: Optional[Union[str, PathLike[str]]] = None
+) -> PyFigure: ...
+def hist(
+    values: ArrayLike,
+    bins: int = 64,
+    density: bool = False,
+    color: Optional[ColorLike] = None,
+    width: float = 800.0,
+    height: float = 600.0,
+) -> PyFigure: ...
+def heatmap(
+    z: ArrayLike,
+    extent: Optional[tuple[float, float, float, float]] = None,
+    cmap: str = "viridis",
+    vmin: Optional[float] = None,
+    vmax: Optional[float] = None,
+    norm: Norm = "linear",
+    center: Optional[float] = None,
+    width: float = 800.0,
+    height: float = 600.0,
+) -> PyFigure: ...
+def hist2d(
+    x: ArrayLike,
+    y: ArrayLike,
+    bins: Optional[Union[int, tuple[int, int]]] = None,
+    range: Optional[tuple[Range, Range]] = None,
+    cmap: str = "viridis",
+    norm: Norm = "linear",
+    width: float = 800.0,
+    height: float = 600.0,
+) -> PyFigure: ...
+def register_colormap(name: str, colors: list[str], space: Literal["oklab", "srgb", "rgb", "lab", "cielab"] = "oklab") -> None: ...
+def colormaps() -> list[str]: ...
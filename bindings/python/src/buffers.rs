@@ -0,0 +1,233 @@
+use numpy::{PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::prelude::*;
+use pyo3::types::{IntoPyDict, PyMemoryView};
+
+/// Point indices as an int64 array, ready for `df.iloc[...]` or indexing
+/// the arrays that were plotted
+pub fn index_array<'py>(py: Python<'py>, indices: &[usize]) -> Bound<'py, PyArray1<i64>> {
+    PyArray1::from_iter_bound(py, indices.iter().map(|&index| index as i64))
+}
+
+/// Borrow `obj` as a contiguous 1-D float32 array, copying only when its
+/// element type or layout has to change
+///
+/// Accepts NumPy arrays, anything exporting the buffer protocol
+/// (`array.array`, `memoryview`, `bytearray`, `mmap`, ...), tensors that
+/// implement `__dlpack__` (PyTorch, CuPy, JAX, ...) on the CPU or a GPU, and
+/// plain sequences. Buffers
+/// are read with their own element format, so `array.array('d', ...)` is
+/// converted from float64 and `bytearray` from uint8.
+pub fn as_f32_array<'py>(obj: &Bound<'py, PyAny>, name: &str) -> PyResult<PyReadonlyArray1<'py, f32>> {
+    // Already what the GPU wants
+    if let Ok(array) = obj.downcast::<PyArray1<f32>>() {
+        if array.is_contiguous() {
+            return Ok(array.readonly());
+        }
+    }
+
+    let np = obj.py().import_bound("numpy")?;
+    let array = if obj.is_instance(&np.getattr("ndarray")?)? {
+        obj.clone()
+    } else if let Some(array) = from_dlpack(&np, obj, name)? {
+        array
+    } else if let Ok(view) = PyMemoryView::from_bound(obj) {
+        // NumPy wraps the buffer without copying, keeping its format and shape
+        np.call_method1("asarray", (view,))?
+    } else {
+        np.call_method1("asarray", (obj,))?
+    };
+
+    let ndim: usize = array.getattr("ndim")?.extract()?;
+    if ndim != 1 {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "{} must be 1-dimensional, got {} dimensions",
+            name, ndim
+        )));
+    }
+
+    // Returns `array` itself when it is already contiguous float32
+    np.call_method1("ascontiguousarray", (array, np.getattr("float32")?))?
+        .extract()
+        .map_err(|_| pyo3::exceptions::PyTypeError::new_err(format!("{} must contain numbers", name)))
+}
+
+/// Borrow `obj` as a contiguous 1-D float64 array if it is a NumPy array
+/// of float64 or 64-bit integers, whose values float32 can't hold exactly
+///
+/// # Returns
+/// `None` for any other input, which `as_f32_array` reads as usual
+pub fn as_wide_array<'py>(obj: &Bound<'py, PyAny>) -> PyResult<Option<PyReadonlyArray1<'py, f64>>> {
+    if let Ok(array) = obj.downcast::<PyArray1<f64>>() {
+        if array.is_contiguous() {
+            return Ok(Some(array.readonly()));
+        }
+    }
+    let np = obj.py().import_bound("numpy")?;
+    if !obj.is_instance(&np.getattr("ndarray")?)? || obj.getattr("ndim")?.extract::<usize>()? != 1 {
+        return Ok(None);
+    }
+    let dtype = obj.getattr("dtype")?;
+    let kind: String = dtype.getattr("kind")?.extract()?;
+    let itemsize: usize = dtype.getattr("itemsize")?.extract()?;
+    if !matches!(kind.as_str(), "f" | "i" | "u") || itemsize != 8 {
+        return Ok(None);
+    }
+    Ok(Some(np.call_method1("ascontiguousarray", (obj, np.getattr("float64")?))?.extract()?))
+}
+
+/// Read timestamps as float64 seconds since the Unix epoch
+///
+/// `datetime64` arrays and sequences of `datetime` objects are converted
+/// (NaT becomes NaN); numbers are taken to be seconds already. float32
+/// can't tell present-day timestamps a minute apart, so unlike
+/// `as_f32_array` this always reads double precision.
+pub fn as_seconds(obj: &Bound<'_, PyAny>, name: &str) -> PyResult<Vec<f64>> {
+    let np = obj.py().import_bound("numpy")?;
+    let mut array = np.call_method1("asarray", (obj,))?;
+    let ndim: usize = array.getattr("ndim")?.extract()?;
+    if ndim != 1 {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "{} must be 1-dimensional, got {} dimensions",
+            name, ndim
+        )));
+    }
+    let kind: String = array.getattr("dtype")?.getattr("kind")?.extract()?;
+    if kind == "M" || kind == "O" {
+        let micros = array.call_method1("astype", ("datetime64[us]",)).map_err(|_| {
+            pyo3::exceptions::PyTypeError::new_err(format!("{} must contain datetimes or numbers", name))
+        })?;
+        let seconds = micros.call_method1("astype", ("float64",))?.call_method1("__truediv__", (1e6,))?;
+        array = np.call_method1("where", (np.call_method1("isnat", (&micros,))?, f64::NAN, seconds))?;
+    }
+    let array: PyReadonlyArray1<f64> = np
+        .call_method1("ascontiguousarray", (array, np.getattr("float64")?))?
+        .extract()
+        .map_err(|_| pyo3::exceptions::PyTypeError::new_err(format!("{} must contain datetimes or numbers", name)))?;
+    Ok(array.as_slice()?.to_vec())
+}
+
+/// Copy a 2-D array-like (NumPy array, tensor or nested sequences) into
+/// row-major float32 values
+///
+/// # Returns
+/// The values with the number of rows and columns
+pub fn as_f32_grid(obj: &Bound<'_, PyAny>, name: &str) -> PyResult<(Vec<f32>, usize, usize)> {
+    let np = obj.py().import_bound("numpy")?;
+    let array = match from_dlpack(&np, obj, name)? {
+        Some(array) => array,
+        None => np.call_method1("asarray", (obj,))?,
+    };
+    let ndim: usize = array.getattr("ndim")?.extract()?;
+    if ndim != 2 {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "{} must be 2-dimensional, got {} dimensions",
+            name, ndim
+        )));
+    }
+    let (rows, cols): (usize, usize) = array.getattr("shape")?.extract()?;
+    let values = as_f32_array(&array.call_method0("ravel")?, name)?;
+    Ok((values.as_slice()?.to_vec(), rows, cols))
+}
+
+/// Per-point colors packed as RGBA floats (4 per point, 0.0-1.0)
+///
+/// Accepts an (N, 3) or (N, 4) array-like of floats in 0.0-1.0 (uint8 arrays
+/// are taken as 0-255), or a sequence of hex strings and CSS color names. RGB
+/// colors get an alpha of 1.0.
+pub fn as_rgba_colors(obj: &Bound<'_, PyAny>, name: &str) -> PyResult<Vec<f32>> {
+    if let Ok(strings) = obj.extract::<Vec<String>>() {
+        let mut rgba = Vec::with_capacity(strings.len() * 4);
+        for color in &strings {
+            let color = helion_core::Color::parse(color).map_err(pyo3::exceptions::PyValueError::new_err)?;
+            rgba.extend([color.r, color.g, color.b, color.a]);
+        }
+        return Ok(rgba);
+    }
+
+    let np = obj.py().import_bound("numpy")?;
+    let array = match from_dlpack(&np, obj, name)? {
+        Some(array) => array,
+        None => np.call_method1("asarray", (obj,))?,
+    };
+    let shape: Vec<usize> = array.getattr("shape")?.extract()?;
+    if shape.len() != 2 || !matches!(shape[1], 3 | 4) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "{} must have shape (N, 3) or (N, 4) for per-point colors, got {:?}",
+            name, shape
+        )));
+    }
+
+    let is_bytes = array.getattr("dtype")?.eq(np.getattr("uint8")?)?;
+    let mut array = array.call_method1("astype", (np.getattr("float32")?,))?;
+    if is_bytes {
+        array = array.call_method1("__truediv__", (255.0f32,))?;
+    }
+    if shape[1] == 3 {
+        let alpha = np.call_method1("ones", ((shape[0], 1), np.getattr("float32")?))?;
+        array = np.call_method1("hstack", ((array, alpha),))?;
+    }
+    let flat = np.call_method1("ascontiguousarray", (array.call_method0("ravel")?, np.getattr("float32")?))?;
+    let flat: PyReadonlyArray1<f32> = flat
+        .extract()
+        .map_err(|_| pyo3::exceptions::PyTypeError::new_err(format!("{} must contain numbers", name)))?;
+    Ok(flat.as_slice()?.to_vec())
+}
+
+/// DLPack device types whose memory the CPU can read directly
+/// (`kDLCPU`, `kDLCUDAHost`, `kDLROCMHost`)
+const HOST_DEVICES: [i32; 3] = [1, 3, 11];
+
+/// NumPy array holding the data of a `__dlpack__` tensor
+///
+/// Host tensors (including pinned CUDA host memory) are viewed without
+/// copying. GPU tensors are copied to the host in one transfer done by the
+/// producing framework, since wgpu can't import CUDA or ROCm memory.
+/// Returns None when the object isn't a DLPack producer or NumPy can't view
+/// it, so the caller can fall back to other paths.
+fn from_dlpack<'py>(np: &Bound<'py, PyModule>, obj: &Bound<'py, PyAny>, name: &str) -> PyResult<Option<Bound<'py, PyAny>>> {
+    if !obj.hasattr("__dlpack__")? {
+        return Ok(None);
+    }
+
+    // Tensors tracking gradients refuse to export their storage
+    let obj = match obj.getattr("requires_grad").and_then(|g| g.extract::<bool>()) {
+        Ok(true) => obj.call_method0("detach")?,
+        _ => obj.clone(),
+    };
+
+    let device: (i32, i32) = match obj.call_method0("__dlpack_device__") {
+        Ok(device) => device.extract()?,
+        Err(_) => (1, 0),
+    };
+    if HOST_DEVICES.contains(&device.0) {
+        return Ok(np.call_method1("from_dlpack", (&obj,)).ok());
+    }
+
+    device_to_host(np, &obj)
+        .map(Some)
+        .map_err(|e| {
+            pyo3::exceptions::PyTypeError::new_err(format!(
+                "{} is on device {:?} and could not be copied to host memory: {}",
+                name, device, e
+            ))
+        })
+}
+
+/// Copy a GPU tensor into a host NumPy array
+fn device_to_host<'py>(np: &Bound<'py, PyModule>, obj: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    // DLPack 1.0 producers copy straight into host memory on request
+    let kwargs = [("device", "cpu".into_py(obj.py())), ("copy", true.into_py(obj.py()))].into_py_dict_bound(obj.py());
+    if let Ok(array) = np.call_method("from_dlpack", (obj,), Some(&kwargs)) {
+        return Ok(array);
+    }
+
+    // Older producers: PyTorch's .cpu() and CuPy's .get()
+    if obj.hasattr("cpu")? {
+        let host = obj.call_method0("cpu")?;
+        return np.call_method1("from_dlpack", (&host,)).or_else(|_| np.call_method1("asarray", (host,)));
+    }
+    if obj.hasattr("get")? {
+        return obj.call_method0("get");
+    }
+    np.call_method1("asarray", (obj,))
+}
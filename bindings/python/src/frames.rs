@@ -0,0 +1,183 @@
+use pyo3::prelude::*;
+use pyo3::types::{IntoPyDict, PyDict, PySlice};
+
+/// Dataframe interchange protocol dtype kinds (`DtypeKind`) that map onto a
+/// NumPy dtype
+const KIND_INT: i32 = 0;
+const KIND_UINT: i32 = 1;
+const KIND_FLOAT: i32 = 2;
+const KIND_DATETIME: i32 = 22;
+
+/// Interchange protocol null representations (`ColumnNullType`) that need a
+/// mask applied
+const NULL_SENTINEL: i32 = 2;
+const NULL_BITMASK: i32 = 3;
+const NULL_BYTEMASK: i32 = 4;
+
+/// Whether `obj` holds named columns to plot from: a pandas, polars or
+/// pyarrow table, anything else implementing the dataframe interchange
+/// protocol, or a dict of arrays
+pub fn is_dataframe(obj: &Bound<'_, PyAny>) -> bool {
+    obj.is_instance_of::<PyDict>() || obj.hasattr("__dataframe__").unwrap_or(false)
+}
+
+/// Column `name` of the dataframe `data` as an array `as_f32_array` and
+/// friends accept
+///
+/// Frames that can be indexed by column name (pandas, polars, pyarrow,
+/// dicts) hand over their column through its own `to_numpy()`, which is a
+/// view for float columns without missing values. Other frames are read
+/// through the interchange protocol, viewing each chunk's buffer in place.
+/// Missing values become NaN (NaT for timestamps), which the `missing`
+/// policy then handles.
+///
+/// # Parameters
+/// * `arg` - Argument the name was passed as, for error messages
+pub fn dataframe_column<'py>(data: &Bound<'py, PyAny>, name: &str, arg: &str) -> PyResult<Bound<'py, PyAny>> {
+    let names = column_names(data)?;
+    if !names.iter().any(|column| column == name) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "{} names column '{}', but the DataFrame's columns are {:?}",
+            arg, name, names
+        )));
+    }
+    let indexable = data.is_instance_of::<PyDict>() || data.hasattr("columns")? || data.hasattr("column_names")?;
+    if !indexable {
+        return interchange_column(data, name);
+    }
+    let column = data.get_item(name)?;
+    if !column.hasattr("to_numpy")? {
+        return Ok(column);
+    }
+    let array = column.call_method0("to_numpy")?;
+    // pandas' nullable dtypes come out as objects holding pd.NA
+    if array.getattr("dtype")?.getattr("kind")?.extract::<String>()? == "O" {
+        let py = data.py();
+        let nan = f64::NAN.into_py(py);
+        let kwargs = [("dtype", "float64".into_py(py)), ("na_value", nan)].into_py_dict_bound(py);
+        return column.call_method("to_numpy", (), Some(&kwargs));
+    }
+    Ok(array)
+}
+
+/// Names of the columns of `data`, in order
+fn column_names(data: &Bound<'_, PyAny>) -> PyResult<Vec<String>> {
+    let names = if let Ok(dict) = data.downcast::<PyDict>() {
+        dict.keys().into_any()
+    } else if data.hasattr("column_names")? {
+        // pyarrow; its `columns` are the arrays themselves
+        data.getattr("column_names")?
+    } else if data.hasattr("columns")? {
+        data.getattr("columns")?
+    } else {
+        data.call_method0("__dataframe__")?.call_method0("column_names")?
+    };
+    names.iter()?.map(|name| name?.str()?.extract()).collect()
+}
+
+/// Column `name` read through the dataframe interchange protocol
+fn interchange_column<'py>(data: &Bound<'py, PyAny>, name: &str) -> PyResult<Bound<'py, PyAny>> {
+    let np = data.py().import_bound("numpy")?;
+    let column = data.call_method0("__dataframe__")?.call_method1("get_column_by_name", (name,))?;
+    let mut chunks = Vec::new();
+    for chunk in column.call_method0("get_chunks")?.iter()? {
+        chunks.push(interchange_chunk(&np, &chunk?, name)?);
+    }
+    match chunks.len() {
+        1 => Ok(chunks.remove(0)),
+        _ => np.call_method1("concatenate", (chunks,)),
+    }
+}
+
+/// One chunk of an interchange column as a NumPy array, viewing its data
+/// buffer when it has no missing values
+///
+/// The view is only read while the plot is built, during which the caller
+/// keeps the frame that owns the buffer alive.
+fn interchange_chunk<'py>(np: &Bound<'py, PyModule>, column: &Bound<'py, PyAny>, name: &str) -> PyResult<Bound<'py, PyAny>> {
+    let size: usize = column.call_method0("size")?.extract()?;
+    let offset: usize = column.getattr("offset")?.extract()?;
+    let (kind, bits, format, _): (i32, usize, String, String) = column.getattr("dtype")?.extract()?;
+    let dtype = match kind {
+        KIND_INT => format!("int{}", bits),
+        KIND_UINT => format!("uint{}", bits),
+        KIND_FLOAT => format!("float{}", bits),
+        // Formats like "tsn:" or "tsu:UTC": int64 counts of a unit
+        KIND_DATETIME => match format.get(..3) {
+            Some("tss") => "datetime64[s]".to_string(),
+            Some("tsm") => "datetime64[ms]".to_string(),
+            Some("tsu") => "datetime64[us]".to_string(),
+            Some("tsn") => "datetime64[ns]".to_string(),
+            _ => return Err(unsupported_column(name, &format)),
+        },
+        _ => return Err(unsupported_column(name, &format)),
+    };
+
+    let buffers = column.call_method0("get_buffers")?;
+    let (data, _) = buffers.get_item("data")?.extract::<(Bound<PyAny>, Bound<PyAny>)>()?;
+    let array = buffer_view(np, &data, &dtype, offset, size)?;
+
+    let (null_kind, sentinel): (i32, Option<PyObject>) = column.getattr("describe_null")?.extract()?;
+    let null_count: Option<usize> = column.getattr("null_count")?.extract()?;
+    if null_count == Some(0) {
+        return Ok(array);
+    }
+    let missing = match null_kind {
+        NULL_SENTINEL => array.call_method1("__eq__", (sentinel,))?,
+        NULL_BITMASK | NULL_BYTEMASK => {
+            let (validity, _) = buffers.get_item("validity")?.extract::<(Bound<PyAny>, Bound<PyAny>)>()?;
+            let mask = if null_kind == NULL_BITMASK {
+                let bytes = buffer_view(np, &validity, "uint8", 0, (offset + size).div_ceil(8))?;
+                let kwargs = [("bitorder", "little")].into_py_dict_bound(np.py());
+                let bits = np.call_method("unpackbits", (bytes,), Some(&kwargs))?;
+                bits.get_item(PySlice::new_bound(np.py(), offset as isize, (offset + size) as isize, 1))?
+            } else {
+                buffer_view(np, &validity, "uint8", offset, size)?
+            };
+            mask.call_method1("__eq__", (sentinel,))?
+        }
+        _ => return Ok(array),
+    };
+    let fill = match kind {
+        KIND_DATETIME => np.getattr("datetime64")?.call1(("NaT",))?,
+        _ => f64::NAN.into_py(np.py()).into_bound(np.py()),
+    };
+    let array = match kind {
+        KIND_DATETIME => array,
+        _ => array.call_method1("astype", ("float64",))?,
+    };
+    np.call_method1("where", (missing, fill, array))
+}
+
+/// `size` elements of `dtype` from interchange buffer `buffer`, starting
+/// `offset` elements in, without copying
+fn buffer_view<'py>(
+    np: &Bound<'py, PyModule>,
+    buffer: &Bound<'py, PyAny>,
+    dtype: &str,
+    offset: usize,
+    size: usize,
+) -> PyResult<Bound<'py, PyAny>> {
+    let py = np.py();
+    let ptr: usize = buffer.getattr("ptr")?.extract()?;
+    let bufsize: usize = buffer.getattr("bufsize")?.extract()?;
+    if size == 0 || bufsize == 0 {
+        return np.call_method1("empty", (0, dtype));
+    }
+    let bytes = py
+        .import_bound("ctypes")?
+        .getattr("c_char")?
+        .call_method1("__mul__", (bufsize,))?
+        .call_method1("from_address", (ptr,))?;
+    // Buffers may be padded past the last element
+    let kwargs = [("count", offset + size)].into_py_dict_bound(py);
+    let array = np.call_method("frombuffer", (bytes, dtype), Some(&kwargs))?;
+    array.get_item(PySlice::new_bound(py, offset as isize, (offset + size) as isize, 1))
+}
+
+fn unsupported_column(name: &str, format: &str) -> PyErr {
+    pyo3::exceptions::PyTypeError::new_err(format!(
+        "Column '{}' has type '{}', which can't be plotted; use numbers or timestamps",
+        name, format
+    ))
+}
@@ -1,12 +1,53 @@
+// pyo3's generated wrappers trip these lints on every #[pymethods]/#[pyfunction]
+#![allow(clippy::useless_conversion, clippy::too_many_arguments)]
+
+mod buffers;
+mod frames;
+
 use pyo3::prelude::*;
-use numpy::PyReadonlyArray1;
-use helion_core::{ChartData, Point2D, Color, run_window};
+use pyo3::types::{PyBytes, PyDict, PyIterator, PyList, PyString, PyTuple};
+use buffers::{as_f32_array, as_f32_grid, as_rgba_colors, as_seconds, as_wide_array, index_array};
+use frames::{dataframe_column, is_dataframe};
+use helion_core::{AxisScale, CancelToken, ChartData, MissingDataPolicy, ChartKind, Colormap, Point2D, Color, Figure, FigureSpec, Series, FrameReport, HelionError, RenderOptions, ScatterTransform, WindowQueue, WindowSpec, try_run_window, try_run_windows};
+use helion_core::{HeadlessRenderer, HistogramRenderer, LineRenderer, ScatterRenderer};
+use helion_core::serve::{FrameServer, FrameSession};
+use helion_core::aggregate::BinGrid;
+use helion_core::compute;
+use helion_core::heatmap::{AggregatedPoints, Heatmap};
+use helion_core::norm::Normalization;
+use helion_core::histogram::{BinStrategy, HistogramNorm, HistogramOptions};
+use helion_core::brush::{BrushShape, Selection};
+use helion_core::camera::Camera2D;
+use helion_core::data::source_index;
+use helion_core::events::{EventBus, FigureEvent, FigureEventKind};
+use helion_core::figure::SeriesLayer;
+use helion_core::legend::LegendLocation;
+use helion_core::tooltip::Tooltip;
+use helion_core::colormap::{colormap_names, register_colormap_in};
+use helion_core::colorspace::ColorSpace;
+use helion_core::timeseries::GapThreshold;
+use numpy::{PyArray1, PyReadonlyArray1};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 
 /// GPU-accelerated scatter plot renderer
 #[pyclass]
 pub struct PyScatterPlot {
     chart_data: Option<ChartData>,
+    /// How data coordinates were mapped to vertices, for `append`
+    transform: Option<ScatterTransform>,
+    /// Axis layouts the data was read with, for `append`
+    scales: AxisScales,
     title: String,
+    last_report: Option<FrameReport>,
+    handlers: Vec<(FigureEventKind, PyObject)>,
+    /// Points selected in the window, kept after it closes
+    selection: SharedSelection,
+    /// Builds the tooltip text; None shows the data values
+    tooltip: Option<PyObject>,
+    /// Offscreen renderer `pick` reuses, made on first use
+    headless: Option<HeadlessRenderer>,
 }
 
 #[pymethods]
@@ -15,7 +56,14 @@ impl PyScatterPlot {
     fn new() -> Self {
         Self {
             chart_data: None,
+            transform: None,
+            scales: (AxisScale::Linear, AxisScale::Linear),
             title: "Helion Scatter Plot".to_string(),
+            last_report: None,
+            handlers: Vec::new(),
+            selection: SharedSelection::default(),
+            tooltip: None,
+            headless: None,
         }
     }
     
@@ -25,161 +73,2669 @@ impl PyScatterPlot {
         self.title = title;
     }
     
-    /// Show the scatter plot in a window
+    /// Show the scatter plot in an interactive window
     /// 
     /// Opens a window and renders the scatter plot. This is a blocking call
-    /// that runs until the window is closed.
-    fn show(&self) -> PyResult<()> {
+    /// that runs until the window is closed; other Python threads and `on`
+    /// callbacks keep running meanwhile. Plots can be shown one after
+    /// another, from the thread that showed the first.
+    ///
+    /// Without a display, e.g. on a server reached over SSH, the plot is
+    /// served to a browser instead: a URL is printed, and the page it
+    /// opens can be panned and zoomed like the window. `show` returns once
+    /// the page's "Close" link is clicked or on Ctrl+C. Event callbacks and
+    /// selections only work in windows.
+    ///
+    /// Args:
+    ///     title: Window title. Default is the one set with `set_title`.
+    ///     serve: True to always serve to a browser, False to never do so.
+    ///         Default serves only when no window can be opened.
+    ///     port: Local port to serve on. Default picks a free one.
+    ///
+    /// Raises:
+    ///     ValueError: If the plot has no data
+    ///     RuntimeError: If no window can be opened: called off the main
+    ///         thread on macOS, or there is no display and `serve` is False
+    #[pyo3(signature = (title=None, serve=None, port=0))]
+    fn show(&mut self, py: Python, title: Option<String>, serve: Option<bool>, port: u16) -> PyResult<()> {
+        let title = title.unwrap_or_else(|| self.title.clone());
+        let spec = self.window_spec(py, Some(title.clone()))?;
+        let chart_data = self.chart_data.clone();
+        self.last_report = show_or_serve(py, spec, &title, serve, port, move || {
+            FrameSession::chart(chart_data.ok_or(HelionError::EmptyData)?, ChartKind::Scatter, RenderOptions::default())
+        })?;
+        Ok(())
+    }
+
+    /// Render the plot offscreen and write it to a PNG file
+    ///
+    /// Works without a display, e.g. in scripts, on servers and in CI.
+    ///
+    /// Args:
+    ///     path: File to write (str or os.PathLike)
+    ///     width: Image width in pixels. Default is the plot's width.
+    ///     height: Image height in pixels. Default is the plot's height.
+    #[pyo3(signature = (path, width=None, height=None))]
+    fn save(&self, py: Python, path: PathBuf, width: Option<u32>, height: Option<u32>) -> PyResult<()> {
         let chart_data = self.chart_data.as_ref()
             .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(
                 "No data set. Call scatter() with data first."
             ))?;
-        
-        // Clone the data for the window (run_window takes ownership)
-        let data_clone = ChartData {
-            vertices: chart_data.vertices.clone(),
-            viewport_width: chart_data.viewport_width,
-            viewport_height: chart_data.viewport_height,
-        };
-        
-        run_window(data_clone, &self.title);
+        save_png(py, chart_data, ChartKind::Scatter, path, width, height)
+    }
+
+    /// Render the plot offscreen as a PNG for Jupyter and VS Code notebooks,
+    /// which display it inline without opening a window
+    ///
+    /// Returns:
+    ///     PNG bytes at the plot's size, or None if the plot has no data or
+    ///     couldn't be rendered (a RuntimeWarning says why)
+    fn _repr_png_<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyBytes>>> {
+        let png = notebook_png(py, self.chart_data.as_ref().map(|data| render_png(py, data, ChartKind::Scatter, None, None)))?;
+        Ok(png.map(|png| PyBytes::new_bound(py, &png)))
+    }
+
+    /// The inline PNG in an `<img>` tag, for frontends that prefer HTML
+    fn _repr_html_(&self, py: Python) -> PyResult<Option<String>> {
+        notebook_png(py, self.chart_data.as_ref().map(|data| render_png(py, data, ChartKind::Scatter, None, None)))?.map(|png| png_html(py, &png)).transpose()
+    }
+
+    /// The PNG and HTML reprs from one render; IPython asks for this
+    /// instead of calling `_repr_png_` and `_repr_html_` in turn
+    #[pyo3(signature = (include=None, exclude=None))]
+    fn _repr_mimebundle_<'py>(
+        &self,
+        py: Python<'py>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Option<Bound<'py, PyDict>>> {
+        // Every format is returned; the frontend picks one
+        let _ = (include, exclude);
+        png_bundle(py, notebook_png(py, self.chart_data.as_ref().map(|data| render_png(py, data, ChartKind::Scatter, None, None)))?)
+    }
+
+    /// Call `callback` whenever `event` happens while the plot is shown
+    ///
+    /// Args:
+    ///     event: "view_changed", "point_picked", "point_hovered",
+    ///         "selection_changed" or "data_appended"
+    ///     callback: Called with one dict holding the event's "type" and its
+    ///         fields, e.g. {"type": "view_changed", "scale": (2.0, 2.0),
+    ///         "translate": (0.0, 0.0)}. Selections hold their point
+    ///         indices as an int64 numpy array under "indices".
+    fn on(&mut self, event: &str, callback: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.handlers.push(subscription(event, callback)?);
+        Ok(())
+    }
+
+    /// Call `callback` whenever the point under the cursor changes while
+    /// the plot is shown; the same as `on("point_hovered", callback)`
+    ///
+    /// Args:
+    ///     callback: Called with {"type": "point_hovered", "series": 0,
+    ///         "index": i}, i being the row of the input data, or with
+    ///         "series" and "index" None once the cursor leaves the points
+    fn on_hover(&mut self, callback: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.on(FigureEventKind::PointHovered.name(), callback)
+    }
+
+    /// Call `callback` whenever points are selected in the window, by
+    /// shift+dragging a box or alt+dragging a lasso; the same as
+    /// `on("selection_changed", callback)`
+    ///
+    /// Args:
+    ///     callback: Called with {"type": "selection_changed",
+    ///         "subplots": [0], "indices": rows}, rows being an int64 numpy
+    ///         array of input data rows; empty when Escape clears the
+    ///         selection
+    ///
+    /// Example:
+    ///     >>> plot.on_select(lambda e: print(df.iloc[e["indices"]]))
+    fn on_select(&mut self, callback: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.on(FigureEventKind::SelectionChanged.name(), callback)
+    }
+
+    /// Select the points drawn inside a box, as a shift+drag in the window
+    /// would, and keep them as the plot's selection
+    ///
+    /// Coordinates are pixels of the plot at its size and initial view,
+    /// from its top left corner; the corners may come in any order.
+    ///
+    /// Returns:
+    ///     Sorted int64 numpy array of input data rows
+    ///
+    /// Raises:
+    ///     ValueError: If the plot has no data
+    fn select_box<'py>(
+        &self,
+        py: Python<'py>,
+        x0: f32,
+        y0: f32,
+        x1: f32,
+        y1: f32,
+    ) -> PyResult<Bound<'py, PyArray1<i64>>> {
+        let mut shape = BrushShape::rect([x0, y0]);
+        shape.extend_to([x1, y1]);
+        self.select_shape(py, &shape)
+    }
+
+    /// Select the points drawn inside a polygon, as an alt+drag lasso in
+    /// the window would, and keep them as the plot's selection
+    ///
+    /// Args:
+    ///     points: (x, y) pixels of the outline, like `select_box`'s
+    ///         corners; the last point joins back to the first
+    ///
+    /// Returns:
+    ///     Sorted int64 numpy array of input data rows
+    ///
+    /// Raises:
+    ///     ValueError: If the plot has no data or `points` has fewer than 3
+    ///         points
+    fn select_lasso<'py>(&self, py: Python<'py>, points: Vec<(f32, f32)>) -> PyResult<Bound<'py, PyArray1<i64>>> {
+        if points.len() < 3 {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "A lasso needs at least 3 points, got {}",
+                points.len()
+            )));
+        }
+        let shape = BrushShape::Lasso(points.into_iter().map(|(x, y)| [x, y]).collect());
+        self.select_shape(py, &shape)
+    }
+
+    /// Choose the text of the tooltip shown while the cursor is over a
+    /// point in the window
+    ///
+    /// Args:
+    ///     callback: Called with {"series": 0, "index": i, "x": x, "y": y}
+    ///         for the point under the cursor, i being the row of the input
+    ///         data and x, y its values; returns the text to show, or None
+    ///         for no tooltip. None restores the default, which lists x and
+    ///         y.
+    ///
+    /// Example:
+    ///     >>> plot.set_tooltip(lambda p: f"{names[p['index']]}: {p['y']:.1f}")
+    #[pyo3(signature = (callback))]
+    fn set_tooltip(&mut self, callback: Option<&Bound<'_, PyAny>>) -> PyResult<()> {
+        self.tooltip = tooltip_callback(callback)?;
         Ok(())
     }
+
+    /// Row of the input data drawn at a pixel of the plot
+    ///
+    /// Renders the point ids offscreen at the plot's size, so it works
+    /// without a window; the topmost point wins where markers overlap.
+    ///
+    /// Args:
+    ///     x_px: Pixels from the left edge
+    ///     y_px: Pixels from the top edge
+    ///
+    /// Returns:
+    ///     The index of the point, or None over the background
+    ///
+    /// Raises:
+    ///     ValueError: If the plot has no data
+    ///     RuntimeError: If no GPU is available
+    fn pick(&mut self, py: Python, x_px: f32, y_px: f32) -> PyResult<Option<usize>> {
+        let chart_data = self.chart_data.as_ref()
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(
+                "No data set. Call scatter() with data first."
+            ))?;
+        let (width, height) = image_size(chart_data.viewport_width as u32, chart_data.viewport_height as u32)?;
+        let layer = SeriesLayer {
+            series: 0,
+            kind: ChartKind::Scatter,
+            data: chart_data.clone(),
+        };
+        let headless = &mut self.headless;
+        let picked = py.allow_threads(move || cached_headless(headless, width, height)?.pick(&[layer], x_px, y_px))?;
+        Ok(picked.map(|(_, index)| source_index(chart_data.dropped(), index)))
+    }
+
+    /// Draw statistics of the last frame shown
+    ///
+    /// Returns:
+    ///     Dictionary with `frame`, `vertices`, `instances`, `draw_calls` and
+    ///     `bytes_uploaded`, or None if the plot has not been shown yet
+    fn frame_stats(&self) -> Option<HashMap<String, u64>> {
+        self.last_report.as_ref().map(report_to_dict)
+    }
+
+    /// Indices of the points last selected in the window or with
+    /// `select_box` / `select_lasso`
+    ///
+    /// Kept after the window closes, and cleared when it is shown again.
+    ///
+    /// Returns:
+    ///     Sorted int64 numpy array, empty if nothing is selected
+    ///
+    /// Example:
+    ///     >>> plot = helion.scatter(df["x"], df["y"])
+    ///     >>> plot.show()
+    ///     >>> picked = df.iloc[plot.get_selection()]
+    fn get_selection<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<i64>> {
+        selection_array(py, &self.selection)
+    }
+    
+    /// Add points to the plot, placed with the same scaling as its data
+    ///
+    /// New points outside the original data range land outside the initial
+    /// view; pan or zoom out to see them. Shown plots upload only the points
+    /// appended since the last frame.
+    ///
+    /// Args:
+    ///     x: Array-like of x coordinates
+    ///     y: Array-like of y coordinates, the same length as x
+    ///     color: Color of the new points. Default is the plot's color if all
+    ///         its points share one, otherwise blue.
+    ///     size: Size of the new points in pixels. Default is the plot's size
+    ///         if all its points share one, otherwise 2.0.
+    ///     max_points: Keep at most this many points from now on, dropping
+    ///         the oldest, e.g. for a rolling window of live data
+    ///
+    /// Points that aren't finite are handled as the plot's `missing`
+    /// keyword chose.
+    ///
+    /// Raises:
+    ///     ValueError: If no data is set, x and y differ in length, or a
+    ///         point isn't finite and the plot was made with missing="error"
+    #[pyo3(signature = (x, y, color=None, size=None, max_points=None))]
+    fn append(
+        &mut self,
+        x: &Bound<'_, PyAny>,
+        y: &Bound<'_, PyAny>,
+        color: Option<&Bound<'_, PyAny>>,
+        size: Option<f32>,
+        max_points: Option<usize>,
+    ) -> PyResult<()> {
+        let (Some(chart_data), Some(transform)) = (self.chart_data.as_mut(), self.transform.as_ref()) else {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "No data set. Call scatter() with data first."
+            ));
+        };
+        let (xscale, yscale) = self.scales;
+        let (coords, _) = PlotCoords::read(x, y, (AxisRequest::Scale(xscale), AxisRequest::Scale(yscale)))?;
+        let (x_len, y_len) = coords.lens()?;
+        if x_len != y_len {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "x and y must have the same length, got {} and {}",
+                x_len, y_len
+            )));
+        }
+
+        let (plot_color, plot_size) = chart_data.uniform_style().unwrap_or((Color::default(), 2.0));
+        let color = parse_color(color)?.map_or(plot_color, |(r, g, b, a)| Color { r, g, b, a });
+        if max_points.is_some() {
+            chart_data.set_max_points(max_points);
+        }
+        let size = size.unwrap_or(plot_size);
+        match &coords {
+            PlotCoords::Narrow(x, y) => chart_data.append_scatter(x.as_slice()?, y.as_slice()?, transform, color, size),
+            PlotCoords::Wide(x, y) => chart_data.append_scatter_f64(x.as_slice()?, y.as_slice()?, transform, color, size),
+        }
+        .map_err(PyErr::from)
+    }
+
+    /// Number of points
+    fn __len__(&self) -> usize {
+        self.chart_data.as_ref().map_or(0, |data| data.vertices.len())
+    }
+
+    /// Number of input points left out for not being finite, appended
+    /// ones included; see the `missing` keyword of `helion.scatter`
+    #[getter]
+    fn dropped_points(&self) -> usize {
+        self.chart_data.as_ref().map_or(0, |data| data.dropped().len())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ScatterPlot(title={}, points={})", py_str_repr(&self.title), self.__len__())
+    }
+
+    /// Set per-point opacity, independent of the color
+    ///
+    /// Args:
+    ///     alpha: A single opacity (0.0-1.0) for all points, or an array with
+    ///         one opacity per point (e.g. confidence or recency)
+    fn set_alpha(&mut self, alpha: &Bound<'_, PyAny>) -> PyResult<()> {
+        let chart_data = self.chart_data.as_mut()
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(
+                "No data set. Call scatter() with data first."
+            ))?;
+        let point_count = chart_data.vertices.len();
+
+        if let Ok(value) = alpha.extract::<f32>() {
+            return chart_data
+                .set_alpha(&vec![value; point_count])
+                .map_err(PyErr::from);
+        }
+
+        let values = as_f32_array(alpha, "alpha")?;
+        let values = kept_rows(values.as_slice()?, 1, chart_data);
+        chart_data.set_alpha(&values).map_err(PyErr::from)
+    }
     
-    /// Create a scatter plot from numpy arrays
+    /// Set point sizes in pixels
+    ///
+    /// Args:
+    ///     size: A single size for all points, or an array with one size per
+    ///         point (e.g. for a bubble chart)
+    fn set_sizes(&mut self, size: &Bound<'_, PyAny>) -> PyResult<()> {
+        let chart_data = self.chart_data.as_mut()
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(
+                "No data set. Call scatter() with data first."
+            ))?;
+        let point_count = chart_data.vertices.len();
+
+        if let Ok(value) = size.extract::<f32>() {
+            chart_data.set_sizes(&vec![value; point_count]);
+            return Ok(());
+        }
+
+        let values = as_f32_array(size, "size")?;
+        let values = kept_rows(values.as_slice()?, 1, chart_data);
+        if values.len() != point_count {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "size has {} values but the plot has {} points",
+                values.len(), point_count
+            )));
+        }
+        chart_data.set_sizes(&values);
+        Ok(())
+    }
+
+    /// Give each point its own color
+    ///
+    /// Args:
+    ///     colors: An (N, 3) or (N, 4) array of floats in 0.0-1.0 (uint8
+    ///         arrays are read as 0-255), or a list of N hex strings or color names
+    fn set_colors(&mut self, colors: &Bound<'_, PyAny>) -> PyResult<()> {
+        let chart_data = self.chart_data.as_mut()
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(
+                "No data set. Call scatter() with data first."
+            ))?;
+
+        let rgba = as_rgba_colors(colors, "color")?;
+        let rgba = kept_rows(&rgba, 4, chart_data);
+        let point_count = chart_data.vertices.len();
+        if rgba.len() / 4 != point_count {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "color has {} colors but the plot has {} points",
+                rgba.len() / 4, point_count
+            )));
+        }
+        chart_data
+            .set_colors_rgba(&rgba)
+            .map_err(pyo3::exceptions::PyValueError::new_err)
+    }
+
+    /// Create a scatter plot from arrays
     /// 
     /// Args:
-    ///     x: NumPy array of x coordinates
-    ///     y: NumPy array of y coordinates
+    ///     x: Array of x coordinates: a NumPy array, any buffer-protocol object
+    ///         (array.array, memoryview, ...), a CPU or GPU tensor supporting DLPack,
+    ///         or a sequence of numbers
+    ///     y: Array of y coordinates, accepted in the same forms as x
     ///     color: Optional tuple (r, g, b, a) with values 0.0-1.0. Default is blue.
     ///     size: Point size in pixels. Default is 2.0.
     ///     width: Viewport width in pixels. Default is 800.0.
     ///     height: Viewport height in pixels. Default is 600.0.
     ///     x_range: Optional tuple (min, max) for custom x-axis range
     ///     y_range: Optional tuple (min, max) for custom y-axis range
+    ///     xscale: "linear" (default), "log", "log2" or "symlog"
+    ///     yscale: "linear" (default), "log", "log2" or "symlog"
+    ///     xaxis: "time" to read x as timestamps, like `helion.scatter`
+    ///     missing: What to do with points that aren't finite, like
+    ///         `helion.scatter`. Default is "skip".
     /// 
     /// Returns:
     ///     Dictionary with plot information
-    #[pyo3(signature = (x, y, color=None, size=None, width=800.0, height=600.0, x_range=None, y_range=None))]
+    #[pyo3(signature = (x, y, color=None, size=None, width=800.0, height=600.0, x_range=None, y_range=None, xscale=None, yscale=None, xaxis=None, missing=None))]
+    #[allow(clippy::wrong_self_convention)]
     fn from_arrays(
         &mut self,
         py: Python,
-        x: PyReadonlyArray1<f32>,
-        y: PyReadonlyArray1<f32>,
+        x: &Bound<'_, PyAny>,
+        y: &Bound<'_, PyAny>,
         color: Option<(f32, f32, f32, f32)>,
         size: Option<f32>,
         width: f32,
         height: f32,
         x_range: Option<(f32, f32)>,
         y_range: Option<(f32, f32)>,
+        xscale: Option<&str>,
+        yscale: Option<&str>,
+        xaxis: Option<&str>,
+        missing: Option<&str>,
     ) -> PyResult<String> {
-        let x_slice = x.as_slice()?;
-        let y_slice = y.as_slice()?;
-        
-        // Warn if arrays have different lengths (core will use shorter length)
-        if x_slice.len() != y_slice.len() {
-            let min_len = x_slice.len().min(y_slice.len());
-            py.import_bound("warnings")?
-                .call_method1(
-                    "warn",
-                    (format!(
-                        "x and y arrays have different lengths ({} vs {}). Using {} points.",
-                        x_slice.len(), y_slice.len(), min_len
-                    ),)
-                )?;
-        }
-        
-        // Create color
-        let color_opt = color.map(|(r, g, b, a)| Color { r, g, b, a });
-        
-        // Create chart data with optional custom ranges
-        self.chart_data = Some(ChartData::from_scatter_with_range(
-            x_slice,
-            y_slice,
-            color_opt,
-            size,
-            width,
-            height,
-            x_range,
-            y_range,
-        ));
-        
-        Ok(format!(
-            "Scatter plot created with {} points. Call show() to display.",
-            x_slice.len()
-        ))
+        let missing = parse_missing(missing)?;
+        let (coords, scales) = read_plot_axes(x, y, xscale, yscale, xaxis)?;
+        let (chart_data, transform) = prepare_chart_data(py, &coords, color, size, width, height, x_range, y_range, missing)?;
+        let summary = created_summary("Scatter", &chart_data);
+        self.chart_data = Some(chart_data);
+        self.transform = Some(transform);
+        self.scales = scales;
+        Ok(summary)
     }
 }
 
-/// Create a scatter plot from Python lists or numpy arrays
-/// 
-/// Args:
-///     x: List or NumPy array of x coordinates
-///     y: List or NumPy array of y coordinates
-///     color: Optional hex color string (e.g., "#FF5733") or RGB tuple
-///     size: Point size in pixels. Default is 2.0.
-///     width: Viewport width in pixels. Default is 800.0.
-///     height: Viewport height in pixels. Default is 600.0.
-///     x_range: Optional tuple (min, max) for custom x output range. Default is [-1.0, 1.0].
-///     y_range: Optional tuple (min, max) for custom y output range. Default is [-1.0, 1.0].
-/// 
-/// Returns:
-///     PyScatterPlot object
-/// 
-/// Example:
-///     >>> import helion
-///     >>> import numpy as np
-///     >>> x = np.random.rand(100000)
-///     >>> y = np.random.rand(100000)
-///     >>> plot = helion.scatter(x, y, color="#FF5733")
-///     >>> 
-///     >>> # Custom range mapping to [0, 1] instead of [-1, 1]
-///     >>> plot2 = helion.scatter(x, y, x_range=(0.0, 1.0), y_range=(0.0, 1.0))
-#[pyfunction]
-#[pyo3(signature = (x, y, color=None, size=None, width=800.0, height=600.0, x_range=None, y_range=None))]
-fn scatter(
-    py: Python,
-    x: &Bound<'_, PyAny>,
-    y: &Bound<'_, PyAny>,
-    color: Option<&Bound<'_, PyAny>>,
-    size: Option<f32>,
-    width: f32,
-    height: f32,
-    x_range: Option<(f32, f32)>,
-    y_range: Option<(f32, f32)>,
-) -> PyResult<PyScatterPlot> {
-    let mut plot = PyScatterPlot::new();
-    
-    // Convert inputs to float32 numpy arrays if they aren't already
-    // NumPy defaults to float64, but GPUs work best with float32
-    let np = py.import_bound("numpy")?;
-    let x_array: PyReadonlyArray1<f32> = np
-        .call_method1("asarray", (x, np.getattr("float32")?))?
-        .extract()?;
-    let y_array: PyReadonlyArray1<f32> = np
-        .call_method1("asarray", (y, np.getattr("float32")?))?
-        .extract()?;
-    
-    // Parse color if provided
-    let color_tuple = if let Some(c) = color {
-        if let Ok(hex) = c.extract::<String>() {
-            let color = Color::from_hex(&hex);
-            Some((color.r, color.g, color.b, color.a))
-        } else if let Ok(rgba) = c.extract::<(f32, f32, f32, f32)>() {
-            Some(rgba)
-        } else if let Ok((r, g, b)) = c.extract::<(f32, f32, f32)>() {
-            Some((r, g, b, 1.0))
-        } else {
-            return Err(pyo3::exceptions::PyTypeError::new_err(
-                "color must be a hex string, (r, g, b) tuple, or (r, g, b, a) tuple"
-            ));
+impl PyScatterPlot {
+    /// Record and return the points inside `shape`, as input data rows
+    fn select_shape<'py>(&self, py: Python<'py>, shape: &BrushShape) -> PyResult<Bound<'py, PyArray1<i64>>> {
+        let chart_data = self.chart_data.as_ref()
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(
+                "No data set. Call scatter() with data first."
+            ))?;
+        let selected = shape.select(chart_data, &Camera2D::identity());
+        let rows = selected.indices().iter().map(|&i| source_index(chart_data.dropped(), i)).collect();
+        *self.selection.lock().unwrap_or_else(|e| e.into_inner()) = Selection::new(rows);
+        Ok(selection_array(py, &self.selection))
+    }
+
+    /// The window `show` opens, for it and `PlotManager.add`
+    fn window_spec(&self, py: Python, title: Option<String>) -> PyResult<WindowSpec> {
+        let chart_data = self.chart_data.as_ref()
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(
+                "No data set. Call scatter() with data first."
+            ))?;
+        let title = title.unwrap_or_else(|| self.title.clone());
+        // The window takes its own copy of the data
+        let mut spec = WindowSpec::chart(chart_data.clone(), ChartKind::Scatter, &title)
+            .with_events(event_bus(py, &self.handlers, &self.selection, chart_data.dropped()));
+        if let Some(callback) = &self.tooltip {
+            spec = spec.with_tooltip(Some(tooltip(py, callback, chart_data.dropped())));
         }
-    } else {
-        None
-    };
-    
-    plot.from_arrays(py, x_array, y_array, color_tuple, size, width, height, x_range, y_range)?;
-    Ok(plot)
+        Ok(spec)
+    }
+}
+
+/// GPU-accelerated line chart renderer
+#[pyclass]
+pub struct PyLinePlot {
+    chart_data: Option<ChartData>,
+    title: String,
+    last_report: Option<FrameReport>,
+    handlers: Vec<(FigureEventKind, PyObject)>,
+    /// Points selected in the window, kept after it closes
+    selection: SharedSelection,
+}
+
+#[pymethods]
+impl PyLinePlot {
+    #[new]
+    fn new() -> Self {
+        Self {
+            chart_data: None,
+            title: "Helion Line Plot".to_string(),
+            last_report: None,
+            handlers: Vec::new(),
+            selection: SharedSelection::default(),
+        }
+    }
+
+    /// Set the window title
+    #[pyo3(signature = (title))]
+    fn set_title(&mut self, title: String) {
+        self.title = title;
+    }
+
+    /// Show the line chart in a window, like `ScatterPlot.show`
+    ///
+    /// Blocks until the window is closed. Without a display the chart is
+    /// served to a browser; `serve` and `port` work as in `ScatterPlot.show`.
+    #[pyo3(signature = (title=None, serve=None, port=0))]
+    fn show(&mut self, py: Python, title: Option<String>, serve: Option<bool>, port: u16) -> PyResult<()> {
+        let title = title.unwrap_or_else(|| self.title.clone());
+        let spec = self.window_spec(py, Some(title.clone()))?;
+        let chart_data = self.chart_data.clone();
+        self.last_report = show_or_serve(py, spec, &title, serve, port, move || {
+            FrameSession::chart(chart_data.ok_or(HelionError::EmptyData)?, ChartKind::Line, RenderOptions::default())
+        })?;
+        Ok(())
+    }
+
+    /// Render the chart offscreen to a PNG file, like `ScatterPlot.save`
+    #[pyo3(signature = (path, width=None, height=None))]
+    fn save(&self, py: Python, path: PathBuf, width: Option<u32>, height: Option<u32>) -> PyResult<()> {
+        let chart_data = self.chart_data.as_ref()
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(
+                "No data set. Call line() with data first."
+            ))?;
+        save_png(py, chart_data, ChartKind::Line, path, width, height)
+    }
+
+    /// Render the chart offscreen as a PNG for notebooks, like
+    /// `ScatterPlot._repr_png_`
+    fn _repr_png_<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyBytes>>> {
+        let png = notebook_png(py, self.chart_data.as_ref().map(|data| render_png(py, data, ChartKind::Line, None, None)))?;
+        Ok(png.map(|png| PyBytes::new_bound(py, &png)))
+    }
+
+    /// The inline PNG in an `<img>` tag, for frontends that prefer HTML
+    fn _repr_html_(&self, py: Python) -> PyResult<Option<String>> {
+        notebook_png(py, self.chart_data.as_ref().map(|data| render_png(py, data, ChartKind::Line, None, None)))?.map(|png| png_html(py, &png)).transpose()
+    }
+
+    /// The PNG and HTML reprs from one render; IPython asks for this
+    /// instead of calling `_repr_png_` and `_repr_html_` in turn
+    #[pyo3(signature = (include=None, exclude=None))]
+    fn _repr_mimebundle_<'py>(
+        &self,
+        py: Python<'py>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Option<Bound<'py, PyDict>>> {
+        // Every format is returned; the frontend picks one
+        let _ = (include, exclude);
+        png_bundle(py, notebook_png(py, self.chart_data.as_ref().map(|data| render_png(py, data, ChartKind::Line, None, None)))?)
+    }
+
+    /// Call `callback` whenever `event` happens while the chart is shown,
+    /// like `ScatterPlot.on`
+    fn on(&mut self, event: &str, callback: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.handlers.push(subscription(event, callback)?);
+        Ok(())
+    }
+
+    /// Draw statistics of the last frame shown, like `ScatterPlot.frame_stats`
+    fn frame_stats(&self) -> Option<HashMap<String, u64>> {
+        self.last_report.as_ref().map(report_to_dict)
+    }
+
+    /// Indices of the points last selected in the window, like
+    /// `ScatterPlot.get_selection`
+    fn get_selection<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<i64>> {
+        selection_array(py, &self.selection)
+    }
+
+    /// Number of points
+    fn __len__(&self) -> usize {
+        self.chart_data.as_ref().map_or(0, |data| data.vertices.len())
+    }
+
+    /// Number of input points left out for not being finite, appended
+    /// ones included; see the `missing` keyword of `helion.scatter`
+    #[getter]
+    fn dropped_points(&self) -> usize {
+        self.chart_data.as_ref().map_or(0, |data| data.dropped().len())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("LinePlot(title={}, points={})", py_str_repr(&self.title), self.__len__())
+    }
+
+    /// Create a line chart from arrays
+    ///
+    /// Args:
+    ///     x: Array-like of x coordinates, in drawing order
+    ///     y: Array-like of y coordinates
+    ///     color: Optional tuple (r, g, b, a) with values 0.0-1.0. Default is blue.
+    ///     width: Viewport width in pixels. Default is 800.0.
+    ///     height: Viewport height in pixels. Default is 600.0.
+    ///     x_range: Optional tuple (min, max) for custom x-axis range
+    ///     y_range: Optional tuple (min, max) for custom y-axis range
+    ///     xscale: "linear" (default), "log", "log2" or "symlog"
+    ///     yscale: "linear" (default), "log", "log2" or "symlog"
+    ///     xaxis: "time" to read x as timestamps, like `helion.line`
+    ///     missing: What to do with points that aren't finite, like
+    ///         `helion.scatter`; the line joins the points either side of
+    ///         one left out. Default is "skip".
+    #[pyo3(signature = (x, y, color=None, width=800.0, height=600.0, x_range=None, y_range=None, xscale=None, yscale=None, xaxis=None, missing=None))]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_arrays(
+        &mut self,
+        py: Python,
+        x: &Bound<'_, PyAny>,
+        y: &Bound<'_, PyAny>,
+        color: Option<(f32, f32, f32, f32)>,
+        width: f32,
+        height: f32,
+        x_range: Option<(f32, f32)>,
+        y_range: Option<(f32, f32)>,
+        xscale: Option<&str>,
+        yscale: Option<&str>,
+        xaxis: Option<&str>,
+        missing: Option<&str>,
+    ) -> PyResult<String> {
+        let missing = parse_missing(missing)?;
+        let (coords, _) = read_plot_axes(x, y, xscale, yscale, xaxis)?;
+        // Lines are one pixel wide, matching ChartData::from_line
+        let (chart_data, _) = prepare_chart_data(py, &coords, color, Some(1.0), width, height, x_range, y_range, missing)?;
+        let summary = created_summary("Line", &chart_data);
+        self.chart_data = Some(chart_data);
+        Ok(summary)
+    }
+}
+
+impl PyLinePlot {
+    /// The window `show` opens, for it and `PlotManager.add`
+    fn window_spec(&self, py: Python, title: Option<String>) -> PyResult<WindowSpec> {
+        let chart_data = self.chart_data.as_ref()
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(
+                "No data set. Call line() with data first."
+            ))?;
+        let title = title.unwrap_or_else(|| self.title.clone());
+        Ok(WindowSpec::chart(chart_data.clone(), ChartKind::Line, &title)
+            .with_events(event_bus(py, &self.handlers, &self.selection, chart_data.dropped())))
+    }
+}
+
+/// Several series drawn on shared axes
+///
+/// Every series added with `scatter` or `line` is scaled against the range
+/// covering all of them, so overlaid datasets line up. Series without an
+/// explicit color take the next one from a ten-color cycle.
+#[pyclass]
+pub struct PyFigure {
+    figure: Figure,
+    title: String,
+    last_report: Option<FrameReport>,
+    handlers: Vec<(FigureEventKind, PyObject)>,
+    /// Points selected in the window, kept after it closes
+    selection: SharedSelection,
+    /// Where a `with` block saves the figure; shown instead if None
+    save_path: Option<PathBuf>,
+    /// Builds the tooltip text; None shows the data values
+    tooltip: Option<PyObject>,
+    /// Offscreen renderer `pick` reuses, made on first use
+    headless: Option<HeadlessRenderer>,
+}
+
+#[pymethods]
+impl PyFigure {
+    #[new]
+    #[pyo3(signature = (width=800.0, height=600.0, save=None))]
+    fn new(width: f32, height: f32, save: Option<PathBuf>) -> Self {
+        Self {
+            figure: Figure::new(width, height),
+            title: "Helion Figure".to_string(),
+            last_report: None,
+            handlers: Vec::new(),
+            selection: SharedSelection::default(),
+            save_path: save,
+            tooltip: None,
+            headless: None,
+        }
+    }
+
+    fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    /// Save or show the figure when a `with` block ends without an error
+    ///
+    /// An exception raised in the block propagates and nothing is drawn.
+    #[pyo3(signature = (exc_type, _exc_value, _traceback))]
+    fn __exit__(
+        &mut self,
+        py: Python,
+        exc_type: Option<&Bound<'_, PyAny>>,
+        _exc_value: Option<&Bound<'_, PyAny>>,
+        _traceback: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<bool> {
+        if exc_type.is_some() {
+            return Ok(false);
+        }
+        match self.save_path.clone() {
+            Some(path) => self.save(py, path, None, None)?,
+            None => self.show(py, None, None, 0)?,
+        }
+        Ok(false)
+    }
+
+    /// Set the title, drawn above the plot and used for the window
+    #[pyo3(signature = (title))]
+    fn set_title(&mut self, title: String) {
+        self.figure.set_title(Some(title.clone()));
+        self.title = title;
+    }
+
+    /// Label the x axis; None removes the label
+    #[pyo3(signature = (label))]
+    fn set_xlabel(&mut self, label: Option<String>) {
+        self.figure.set_xlabel(label);
+    }
+
+    /// Label the y axis; None removes the label
+    #[pyo3(signature = (label))]
+    fn set_ylabel(&mut self, label: Option<String>) {
+        self.figure.set_ylabel(label);
+    }
+
+    /// Title drawn above the plot, if set
+    #[getter]
+    fn title(&self) -> Option<String> {
+        self.figure.text().title.clone()
+    }
+
+    #[getter]
+    fn xlabel(&self) -> Option<String> {
+        self.figure.text().xlabel.clone()
+    }
+
+    #[getter]
+    fn ylabel(&self) -> Option<String> {
+        self.figure.text().ylabel.clone()
+    }
+
+    /// Place the legend, drawn when any series has a label
+    ///
+    /// Args:
+    ///     loc: Corner to draw it in: "upper right" (the default), "upper
+    ///         left", "lower left" or "lower right"
+    ///
+    /// Raises:
+    ///     ValueError: If loc is not one of those corners
+    #[pyo3(signature = (loc="upper right"))]
+    fn legend(&mut self, loc: &str) -> PyResult<()> {
+        let location = LegendLocation::from_name(loc).ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "Unknown legend location '{}'; expected 'upper right', 'upper left', 'lower left' or 'lower right'",
+                loc
+            ))
+        })?;
+        self.figure.set_legend_location(location);
+        Ok(())
+    }
+
+    /// Add a scatter series
+    ///
+    /// Args:
+    ///     x: Array-like of x coordinates
+    ///     y: Array-like of y coordinates, as many as x
+    ///     color: Optional hex string, CSS color name or RGB(A) tuple
+    ///     size: Point size in pixels. Default is 2.0.
+    ///     label: Optional name shown in the legend; clicking it there hides
+    ///         the series
+    ///     xscale: "linear", "log", "log2" or "symlog" to change the x axis
+    ///         of the whole figure; unchanged if omitted
+    ///     yscale: The y axis scale, as for xscale
+    ///     xaxis: "time" to make x a time axis and read x as timestamps.
+    ///         Once it is one, x values of every series are read that way.
+    ///     zorder: Drawing order; series with a higher zorder are drawn on
+    ///         top, ties in the order added. Default is 0.
+    ///
+    /// Returns:
+    ///     The new `Series`, e.g. to change its zorder later
+    ///
+    /// Raises:
+    ///     ValueError: If x and y differ in length, or a scale or axis type
+    ///         is unknown
+    #[pyo3(signature = (x, y, color=None, size=None, label=None, xscale=None, yscale=None, xaxis=None, zorder=None))]
+    fn scatter(
+        mut slf: PyRefMut<'_, Self>,
+        x: &Bound<'_, PyAny>,
+        y: &Bound<'_, PyAny>,
+        color: Option<&Bound<'_, PyAny>>,
+        size: Option<f32>,
+        label: Option<String>,
+        xscale: Option<&str>,
+        yscale: Option<&str>,
+        xaxis: Option<&str>,
+        zorder: Option<i32>,
+    ) -> PyResult<PySeries> {
+        let color = parse_color(color)?;
+        let (x, y, scales) = slf.read_series(x, y, xscale, yscale, xaxis)?;
+        let series = slf.figure.scatter(x, y)?;
+        if let Some((r, g, b, a)) = color {
+            series.style.color = Color::new(r, g, b, a);
+        }
+        if let Some(size) = size {
+            series.style.size = size;
+        }
+        if let Some(zorder) = zorder {
+            series.style.z_order = zorder;
+        }
+        series.label = label;
+        slf.set_scales(scales);
+        Ok(Self::last_series(slf))
+    }
+
+    /// Add a line series, drawn through the points in the order given
+    ///
+    /// Args:
+    ///     x: Array-like of x coordinates
+    ///     y: Array-like of y coordinates, as many as x
+    ///     color: Optional hex string, CSS color name or RGB(A) tuple
+    ///     label: Optional name shown in the legend
+    ///     xscale: Scale of the figure's x axis, as for `scatter`
+    ///     yscale: Scale of the figure's y axis
+    ///     xaxis: "time" to make x a time axis, as for `scatter`
+    ///     zorder: Drawing order, as for `scatter`. Default is 0.
+    ///     gap: Leave the line broken wherever x steps by more than this
+    ///         (seconds on a time axis), e.g. over missing days. NaN values
+    ///         always break it.
+    ///
+    /// Returns:
+    ///     The new `Series`
+    ///
+    /// Raises:
+    ///     ValueError: If x and y differ in length, a scale or axis type is
+    ///         unknown, or gap isn't positive
+    #[pyo3(signature = (x, y, color=None, label=None, xscale=None, yscale=None, xaxis=None, zorder=None, gap=None))]
+    fn line(
+        mut slf: PyRefMut<'_, Self>,
+        x: &Bound<'_, PyAny>,
+        y: &Bound<'_, PyAny>,
+        color: Option<&Bound<'_, PyAny>>,
+        label: Option<String>,
+        xscale: Option<&str>,
+        yscale: Option<&str>,
+        xaxis: Option<&str>,
+        zorder: Option<i32>,
+        gap: Option<f32>,
+    ) -> PyResult<PySeries> {
+        let color = parse_color(color)?;
+        if let Some(gap) = gap.filter(|gap| gap.is_nan() || *gap <= 0.0) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!("gap must be positive, got {}", gap)));
+        }
+        let (x, y, scales) = slf.read_series(x, y, xscale, yscale, xaxis)?;
+        let series = slf.figure.line(x, y)?;
+        series.gaps = gap.map(GapThreshold::Absolute);
+        if let Some((r, g, b, a)) = color {
+            series.style.color = Color::new(r, g, b, a);
+        }
+        if let Some(zorder) = zorder {
+            series.style.z_order = zorder;
+        }
+        series.label = label;
+        slf.set_scales(scales);
+        Ok(Self::last_series(slf))
+    }
+
+    /// Add a histogram of `values`, drawn as bars
+    ///
+    /// Large arrays are binned on the GPU when one with compute shaders is
+    /// available, on the CPU otherwise; the counts are the same either way.
+    /// NaN and infinite values are skipped.
+    ///
+    /// Args:
+    ///     values: Array-like of samples
+    ///     bins: Number of equal-width bins spanning the data. Default is 64.
+    ///     density: Scale bars so their total area is 1. Default is False.
+    ///     color: Optional hex string, CSS color name or RGB(A) tuple
+    ///     label: Optional name shown in the legend
+    ///     zorder: Drawing order, as for `scatter`. Default is 0.
+    ///
+    /// Returns:
+    ///     The new `Series` of bars
+    ///
+    /// Raises:
+    ///     ValueError: If bins is 0
+    #[pyo3(signature = (values, bins=64, density=false, color=None, label=None, zorder=None))]
+    fn hist(
+        mut slf: PyRefMut<'_, Self>,
+        py: Python,
+        values: &Bound<'_, PyAny>,
+        bins: usize,
+        density: bool,
+        color: Option<&Bound<'_, PyAny>>,
+        label: Option<String>,
+        zorder: Option<i32>,
+    ) -> PyResult<PySeries> {
+        if bins == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err("bins must be at least 1"));
+        }
+        let color = parse_color(color)?;
+        let values = as_f32_array(values, "values")?;
+        let values = values.as_slice()?;
+        let options = HistogramOptions {
+            bins: BinStrategy::Count(bins),
+            norm: if density { HistogramNorm::Density } else { HistogramNorm::Count },
+            cumulative: false,
+        };
+        let histogram = py.allow_threads(|| compute::bin1d::histogram(values, &options));
+
+        let series = slf.figure.histogram(histogram);
+        if let Some((r, g, b, a)) = color {
+            series.style.color = Color::new(r, g, b, a);
+        }
+        if let Some(zorder) = zorder {
+            series.style.z_order = zorder;
+        }
+        series.label = label;
+        Ok(Self::last_series(slf))
+    }
+
+    /// Show a 2D array as colored cells behind the series
+    ///
+    /// Replaces any heatmap already on the figure.
+    ///
+    /// Args:
+    ///     z: 2D array-like of shape (rows, columns); row 0 is drawn at the
+    ///         bottom, so pass np.flipud(z) for image-style data. NaN cells
+    ///         are not drawn.
+    ///     extent: Optional (xmin, xmax, ymin, ymax) the grid covers.
+    ///         Default is (0, columns, 0, rows).
+    ///     cmap: Name of a built-in or registered colormap. Default is
+    ///         "viridis"; add "_r" to reverse it.
+    ///     vmin: Value given the first color. Default is the smallest value.
+    ///     vmax: Value given the last color. Default is the largest value.
+    ///     norm: How values are spread over the colormap: "linear"
+    ///         (default), "log" (values <= 0 are not drawn), "sqrt", "cbrt"
+    ///         or "eq_hist" to give each color about as many cells.
+    ///     center: Optional value given the middle color, e.g. 0 with a
+    ///         diverging cmap such as "coolwarm"; each side of it spans half
+    ///         the colormap however far vmin and vmax reach.
+    ///
+    /// Raises:
+    ///     ValueError: If z isn't 2-dimensional or empty, or cmap or norm is
+    ///         unknown
+    #[pyo3(signature = (z, extent=None, cmap="viridis", vmin=None, vmax=None, norm="linear", center=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn heatmap(
+        &mut self,
+        z: &Bound<'_, PyAny>,
+        extent: Option<(f32, f32, f32, f32)>,
+        cmap: &str,
+        vmin: Option<f32>,
+        vmax: Option<f32>,
+        norm: &str,
+        center: Option<f32>,
+    ) -> PyResult<()> {
+        let cmap = parse_colormap(cmap)?;
+        let norm = parse_normalization(norm)?;
+        let (values, rows, cols) = as_f32_grid(z, "z")?;
+        let mut heatmap = Heatmap::new(values, cols as u32, rows as u32)?
+            .with_colormap(cmap)
+            .with_range(vmin, vmax)
+            .with_normalization(norm);
+        if let Some(center) = center {
+            heatmap = heatmap.with_center(center);
+        }
+        if let Some((xmin, xmax, ymin, ymax)) = extent {
+            heatmap = heatmap.with_extent((xmin, xmax), (ymin, ymax));
+        }
+        self.figure.set_heatmap(Some(heatmap));
+        Ok(())
+    }
+
+    /// Show the density of the points (x[i], y[i]) as a 2D histogram
+    ///
+    /// Points are counted on the GPU when one with compute shaders is
+    /// available, on the CPU otherwise. Empty cells are left transparent;
+    /// replaces any heatmap already on the figure. In a window the points
+    /// are counted again for the visible range as you zoom.
+    ///
+    /// Args:
+    ///     x: Array-like of x coordinates
+    ///     y: Array-like of y coordinates, as many as x
+    ///     bins: Number of cells along both axes, or (nx, ny). Default is 256.
+    ///     range: Optional ((xmin, xmax), (ymin, ymax)) covered by the cells.
+    ///         Default spans all finite points.
+    ///     cmap: Name of a built-in or registered colormap. Default is "viridis".
+    ///     norm: How counts are spread over the colormap, as for heatmap().
+    ///         "log" or "eq_hist" show sparse regions next to dense ones.
+    ///         Default is "linear".
+    ///
+    /// Raises:
+    ///     ValueError: If x and y differ in length, a bin count is 0 or cmap
+    ///         or norm is unknown
+    #[pyo3(signature = (x, y, bins=None, range=None, cmap="viridis", norm="linear"))]
+    #[allow(clippy::too_many_arguments)]
+    fn hist2d(
+        &mut self,
+        py: Python,
+        x: &Bound<'_, PyAny>,
+        y: &Bound<'_, PyAny>,
+        bins: Option<&Bound<'_, PyAny>>,
+        range: Option<((f32, f32), (f32, f32))>,
+        cmap: &str,
+        norm: &str,
+    ) -> PyResult<()> {
+        let cmap = parse_colormap(cmap)?;
+        let norm = parse_normalization(norm)?;
+        let (nx, ny) = match bins {
+            None => (256, 256),
+            Some(bins) => match bins.extract::<u32>() {
+                Ok(n) => (n, n),
+                Err(_) => bins.extract::<(u32, u32)>()?,
+            },
+        };
+        if nx == 0 || ny == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err("bins must be at least 1"));
+        }
+        let (x, y) = (as_f32_array(x, "x")?, as_f32_array(y, "y")?);
+        let (x, y) = (x.as_slice()?, y.as_slice()?);
+        if x.len() != y.len() {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "x and y must have the same length, got {} and {}",
+                x.len(),
+                y.len()
+            )));
+        }
+        let grid = match range {
+            Some((x_range, y_range)) => BinGrid::new(x_range, y_range, nx, ny),
+            None => BinGrid::covering(x, y, nx, ny),
+        };
+        let heatmap = py.allow_threads(|| Heatmap::hist2d_auto(x, y, &grid));
+        // Windows re-bin on zoom with cells about as wide as these
+        let cell_px = self.figure.width() / nx as f32;
+        let points = AggregatedPoints::new(x.to_vec(), y.to_vec(), cell_px);
+        self.figure.set_hist2d(heatmap.with_colormap(cmap).with_normalization(norm), points);
+        Ok(())
+    }
+
+    /// Show every series in one window, like `ScatterPlot.show`
+    ///
+    /// Blocks until the window is closed.
+    ///
+    /// Args:
+    ///     title: Window title. Default is the figure's title.
+    ///     serve: Whether to serve to a browser, as in `ScatterPlot.show`.
+    ///         Served figures leave out axes, text and the legend.
+    ///     port: Local port to serve on. Default picks a free one.
+    #[pyo3(signature = (title=None, serve=None, port=0))]
+    fn show(&mut self, py: Python, title: Option<String>, serve: Option<bool>, port: u16) -> PyResult<()> {
+        let title = title.unwrap_or_else(|| self.title.clone());
+        let spec = self.window_spec(py, Some(title.clone()))?;
+        let figure = self.figure.clone();
+        self.last_report = show_or_serve(py, spec, &title, serve, port, move || {
+            FrameSession::figure(&figure, RenderOptions::default())
+        })?;
+        Ok(())
+    }
+
+    /// Render the heatmap and every series offscreen and write them to a
+    /// PNG file, like `ScatterPlot.save`
+    ///
+    /// Axes, text and the legend are only drawn in windows.
+    ///
+    /// Raises:
+    ///     ValueError: If the figure is empty or a size is 0
+    #[pyo3(signature = (path, width=None, height=None))]
+    fn save(&self, py: Python, path: PathBuf, width: Option<u32>, height: Option<u32>) -> PyResult<()> {
+        if self.figure.is_empty() && self.figure.heatmap().is_none() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "Figure is empty. Call scatter(), line(), hist() or heatmap() first."
+            ));
+        }
+        let png = self.render_png(py, width, height)?;
+        write_png(&path, png)
+    }
+
+    /// Render the figure offscreen as a PNG for notebooks, like
+    /// `ScatterPlot._repr_png_`; None while it is empty
+    fn _repr_png_<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyBytes>>> {
+        let png = notebook_png(py, self.notebook_render(py))?;
+        Ok(png.map(|png| PyBytes::new_bound(py, &png)))
+    }
+
+    /// The inline PNG in an `<img>` tag, for frontends that prefer HTML
+    fn _repr_html_(&self, py: Python) -> PyResult<Option<String>> {
+        notebook_png(py, self.notebook_render(py))?.map(|png| png_html(py, &png)).transpose()
+    }
+
+    /// The PNG and HTML reprs from one render, like
+    /// `ScatterPlot._repr_mimebundle_`
+    #[pyo3(signature = (include=None, exclude=None))]
+    fn _repr_mimebundle_<'py>(
+        &self,
+        py: Python<'py>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Option<Bound<'py, PyDict>>> {
+        let _ = (include, exclude);
+        png_bundle(py, notebook_png(py, self.notebook_render(py))?)
+    }
+
+    /// Call `callback` whenever `event` happens while the figure is shown,
+    /// like `ScatterPlot.on`
+    fn on(&mut self, event: &str, callback: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.handlers.push(subscription(event, callback)?);
+        Ok(())
+    }
+
+    /// Call `callback` whenever the scatter point under the cursor changes
+    /// while the figure is shown, like `ScatterPlot.on_hover`; "series" is
+    /// the position of the point's series in the figure
+    fn on_hover(&mut self, callback: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.on(FigureEventKind::PointHovered.name(), callback)
+    }
+
+    /// Call `callback` whenever points are box or lasso selected in the
+    /// window, like `ScatterPlot.on_select`; one event per scatter series,
+    /// with its position in the figure under "subplots"
+    fn on_select(&mut self, callback: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.on(FigureEventKind::SelectionChanged.name(), callback)
+    }
+
+    /// Choose the tooltip text of scatter points, like
+    /// `ScatterPlot.set_tooltip`; "series" is the position of the point's
+    /// series in the figure
+    #[pyo3(signature = (callback))]
+    fn set_tooltip(&mut self, callback: Option<&Bound<'_, PyAny>>) -> PyResult<()> {
+        self.tooltip = tooltip_callback(callback)?;
+        Ok(())
+    }
+
+    /// Scatter point drawn at a pixel of the figure, like
+    /// `ScatterPlot.pick`; line and bar series can't be picked
+    ///
+    /// Returns:
+    ///     (series, index) of the point, or None over the background
+    fn pick(&mut self, py: Python, x_px: f32, y_px: f32) -> PyResult<Option<(usize, usize)>> {
+        let (width, height) = image_size(self.figure.width() as u32, self.figure.height() as u32)?;
+        let layers = self.figure.layers();
+        let headless = &mut self.headless;
+        let picked = py.allow_threads(move || cached_headless(headless, width, height)?.pick(&layers, x_px, y_px))?;
+        Ok(picked)
+    }
+
+    /// Draw statistics of the last frame shown, like `ScatterPlot.frame_stats`
+    fn frame_stats(&self) -> Option<HashMap<String, u64>> {
+        self.last_report.as_ref().map(report_to_dict)
+    }
+
+    /// Indices of the points last selected in the window, like
+    /// `ScatterPlot.get_selection`
+    ///
+    /// Indices refer to the rows of the selected series' data.
+    fn get_selection<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<i64>> {
+        selection_array(py, &self.selection)
+    }
+
+    /// The figure as a JSON spec, for `HelionChart.fromSpec` in a browser
+    ///
+    /// Holds the size, title, axis labels, limits and every series with its
+    /// data and style.
+    fn to_spec(&self) -> String {
+        FigureSpec::from_figure(&self.figure).to_json()
+    }
+
+    /// Number of series
+    fn __len__(&self) -> usize {
+        self.figure.len()
+    }
+
+    /// The series in the order they were added, as `Series` snapshots
+    fn __iter__<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyIterator>> {
+        let py = slf.py();
+        let figure = slf.clone().unbind();
+        let series = slf
+            .borrow()
+            .figure
+            .series()
+            .iter()
+            .enumerate()
+            .map(|(index, series)| {
+                Py::new(
+                    py,
+                    PySeries {
+                        series: series.clone(),
+                        figure: figure.clone_ref(py),
+                        index,
+                    },
+                )
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        PyList::new_bound(py, series).as_any().iter()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Figure(title={}, width={}, height={}, series={})",
+            py_str_repr(&self.title),
+            self.figure.width(),
+            self.figure.height(),
+            self.figure.len()
+        )
+    }
+}
+
+impl PyFigure {
+    /// The series just added, as the plotting methods return it
+    fn last_series(slf: PyRefMut<'_, Self>) -> PySeries {
+        let index = slf.figure.len() - 1;
+        let series = slf.figure.series()[index].clone();
+        PySeries {
+            series,
+            figure: slf.into(),
+            index,
+        }
+    }
+
+    /// The window `show` opens, for it and `PlotManager.add`
+    fn window_spec(&self, py: Python, title: Option<String>) -> PyResult<WindowSpec> {
+        if self.figure.is_empty() && self.figure.heatmap().is_none() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "Figure is empty. Call scatter(), line(), hist() or heatmap() first."
+            ));
+        }
+        let title = title.unwrap_or_else(|| self.title.clone());
+        let mut spec = WindowSpec::figure(&self.figure, &title).with_events(event_bus(py, &self.handlers, &self.selection, &[]));
+        if let Some(callback) = &self.tooltip {
+            spec = spec.with_tooltip(Some(tooltip(py, callback, &[])));
+        }
+        Ok(spec)
+    }
+
+    /// Render the figure offscreen as PNG bytes, at its size unless `width`
+    /// or `height` are given
+    fn render_png(&self, py: Python, width: Option<u32>, height: Option<u32>) -> PyResult<Vec<u8>> {
+        let (width, height) = image_size(
+            width.unwrap_or(self.figure.width() as u32),
+            height.unwrap_or(self.figure.height() as u32),
+        )?;
+        let figure = &self.figure;
+        let png = py.allow_threads(|| {
+            HeadlessRenderer::new(width, height, RenderOptions::default())?.render_figure_png(figure)
+        })?;
+        Ok(png)
+    }
+
+    /// The notebook PNG render, or None while there is nothing to draw
+    fn notebook_render(&self, py: Python) -> Option<PyResult<Vec<u8>>> {
+        if self.figure.is_empty() && self.figure.heatmap().is_none() {
+            return None;
+        }
+        Some(self.render_png(py, None, None))
+    }
+    /// Read a series' coordinates for the axes its keywords ask for
+    ///
+    /// Values are kept unmapped (the figure maps them when drawn) except
+    /// for time axes, which store seconds after their origin.
+    ///
+    /// Returns:
+    ///     The x and y values, and the (x, y) scales to give the figure
+    fn read_series(
+        &self,
+        x: &Bound<'_, PyAny>,
+        y: &Bound<'_, PyAny>,
+        xscale: Option<&str>,
+        yscale: Option<&str>,
+        xaxis: Option<&str>,
+    ) -> PyResult<(Vec<f32>, Vec<f32>, AxisScales)> {
+        let (current_x, current_y) = (self.figure.xscale(), self.figure.yscale());
+        let x_request = AxisRequest::parse(xscale, xaxis, "x")?.unwrap_or(AxisRequest::Scale(current_x));
+        let y_request = AxisRequest::parse(yscale, None, "y")?.unwrap_or(AxisRequest::Scale(current_y));
+        let (x, x_scale) = read_axis(x, "x", x_request.keeping(current_x))?;
+        let (y, y_scale) = read_axis(y, "y", y_request.keeping(current_y))?;
+        Ok((x.as_slice()?.to_vec(), y.as_slice()?.to_vec(), (x_scale, y_scale)))
+    }
+
+    fn set_scales(&mut self, (xscale, yscale): AxisScales) {
+        self.figure.set_xscale(xscale);
+        self.figure.set_yscale(yscale);
+    }
+}
+
+/// One series of a figure, as iterating over a `Figure` or adding to it
+/// yields them
+///
+/// A copy taken when it was yielded; later changes to the figure don't
+/// reach it. `set_zorder` goes the other way and changes the figure.
+///
+/// Example:
+///     >>> for series in fig:
+///     ...     print(series.label, len(series), series.y.max())
+///     >>> fig.line(t, model).set_zorder(10)  # always on top
+#[pyclass]
+pub struct PySeries {
+    series: Series,
+    /// Figure the series belongs to, at `index`
+    figure: Py<PyFigure>,
+    index: usize,
+}
+
+#[pymethods]
+impl PySeries {
+    /// "scatter", "line" or "bars"
+    #[getter]
+    fn kind(&self) -> &'static str {
+        self.series.kind.label()
+    }
+
+    /// Legend text, if set
+    #[getter]
+    fn label(&self) -> Option<String> {
+        self.series.label.clone()
+    }
+
+    #[getter]
+    fn color(&self) -> Color {
+        self.series.style.color
+    }
+
+    /// Drawing order; higher is drawn on top
+    #[getter]
+    fn zorder(&self) -> i32 {
+        self.series.style.z_order
+    }
+
+    /// Draw the series above those with a lower zorder and below those
+    /// with a higher one; ties are drawn in the order added
+    #[pyo3(signature = (zorder))]
+    fn set_zorder(&mut self, py: Python, zorder: i32) {
+        if let Some(series) = self.figure.borrow_mut(py).figure.series_mut(self.index) {
+            series.style.z_order = zorder;
+        }
+        self.series.style.z_order = zorder;
+    }
+
+    /// Draw text next to each point or at the end of each bar: `labels`,
+    /// one per point or bar (e.g. a metadata column), or else the y values
+    /// to `precision` decimals. Labels that would overlap are left out.
+    ///
+    /// Raises:
+    ///     ValueError: If labels doesn't hold one string per point or bar
+    #[pyo3(signature = (labels=None, precision=2))]
+    fn set_data_labels(&mut self, py: Python, labels: Option<Vec<String>>, precision: usize) -> PyResult<()> {
+        let series = match labels {
+            Some(labels) if labels.len() != self.series.len() => {
+                return Err(HelionError::ShapeMismatch {
+                    what: "data labels (one per point or bar)".to_string(),
+                    expected: self.series.len(),
+                    actual: labels.len(),
+                }
+                .into());
+            }
+            Some(labels) => self.series.clone().with_data_labels(labels),
+            None => self.series.clone().with_value_labels(precision),
+        };
+        if let Some(figure_series) = self.figure.borrow_mut(py).figure.series_mut(self.index) {
+            figure_series.data_labels = series.data_labels.clone();
+        }
+        self.series = series;
+        Ok(())
+    }
+
+    /// x values as float32 numpy array; bars hold their n + 1 edges, and
+    /// time axes seconds after the axis origin
+    #[getter]
+    fn x<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f32>> {
+        PyArray1::from_slice_bound(py, self.series.x())
+    }
+
+    /// y values as float32 numpy array; bars hold their heights
+    #[getter]
+    fn y<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f32>> {
+        PyArray1::from_slice_bound(py, self.series.y())
+    }
+
+    /// Number of points, or of bars
+    fn __len__(&self) -> usize {
+        self.series.len()
+    }
+
+    /// `(x, y)` tuples, one per point; bars give `(left edge, height)`
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        let points: Vec<(f32, f32)> = self.series.x().iter().copied().zip(self.series.y().iter().copied()).collect();
+        PyList::new_bound(py, points).as_any().iter()
+    }
+
+    fn __repr__(&self) -> String {
+        let label = self.series.label.as_deref().map_or("None".to_string(), py_str_repr);
+        format!("Series(kind={}, label={}, points={})", py_str_repr(self.kind()), label, self.series.len())
+    }
+}
+
+/// Shows several plots at once, each in its own window
+///
+/// Only one OS event loop can run per process (and on macOS only on the
+/// main thread), so calling `show()` on plots one by one opens one window
+/// at a time. A PlotManager queues plots from any thread and opens them
+/// all on a single event loop when `show()` runs.
+///
+/// Example:
+///     >>> manager = helion.PlotManager()
+///     >>> manager.add(helion.scatter(x, y), title="Raw")
+///     >>> manager.add(helion.line(t, y.cumsum()), title="Cumulative")
+///     >>> manager.show()  # both windows at once, until the last is closed
+#[pyclass]
+pub struct PyPlotManager {
+    queue: WindowQueue,
+    /// Stats of every window the last `show` opened
+    last_reports: Mutex<Vec<Option<FrameReport>>>,
+}
+
+#[pymethods]
+impl PyPlotManager {
+    #[new]
+    fn new() -> Self {
+        Self {
+            queue: WindowQueue::new(),
+            last_reports: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Queue a plot to be shown
+    ///
+    /// Safe to call from any thread. While `show()` is running the window
+    /// opens straight away; otherwise it waits for the next `show()`. The
+    /// plot's data is copied, so later changes to it don't reach the window.
+    ///
+    /// Args:
+    ///     plot: A ScatterPlot, LinePlot or Figure
+    ///     title: Window title. Default is the plot's own title.
+    ///
+    /// Raises:
+    ///     TypeError: If `plot` isn't a plot
+    ///     ValueError: If the plot has no data
+    #[pyo3(signature = (plot, title=None))]
+    fn add(&self, py: Python, plot: &Bound<'_, PyAny>, title: Option<String>) -> PyResult<()> {
+        let spec = if let Ok(plot) = plot.downcast::<PyScatterPlot>() {
+            plot.try_borrow()?.window_spec(py, title)?
+        } else if let Ok(plot) = plot.downcast::<PyLinePlot>() {
+            plot.try_borrow()?.window_spec(py, title)?
+        } else if let Ok(plot) = plot.downcast::<PyFigure>() {
+            plot.try_borrow()?.window_spec(py, title)?
+        } else {
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "add() takes a ScatterPlot, LinePlot or Figure, got {}",
+                plot.get_type().name()?
+            )));
+        };
+        self.queue.push(spec);
+        Ok(())
+    }
+
+    /// Open every queued plot and block until all their windows are closed
+    ///
+    /// Plots added from other threads meanwhile open as they arrive. Run it
+    /// from the main thread on macOS; elsewhere, from the thread that
+    /// showed the first window.
+    ///
+    /// Raises:
+    ///     RuntimeError: If no window can be opened, as for `ScatterPlot.show`
+    fn show(&self, py: Python) -> PyResult<()> {
+        let queue = self.queue.clone();
+        let reports = show_window(py, move || try_run_windows(&queue))?;
+        *self.last_reports.lock().unwrap_or_else(|e| e.into_inner()) = reports;
+        Ok(())
+    }
+
+    /// Draw statistics of each window the last `show()` opened, in the
+    /// order they opened, like `ScatterPlot.frame_stats`
+    fn frame_stats(&self) -> Vec<Option<HashMap<String, u64>>> {
+        let reports = self.last_reports.lock().unwrap_or_else(|e| e.into_inner());
+        reports.iter().map(|report| report.as_ref().map(report_to_dict)).collect()
+    }
+
+    /// Number of plots waiting to be opened
+    fn __len__(&self) -> usize {
+        self.queue.pending()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("PlotManager(pending={})", self.queue.pending())
+    }
+}
+
+/// Built-in or registered colormap by name
+fn parse_colormap(name: &str) -> PyResult<Colormap> {
+    Colormap::by_name(name)
+        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(format!("Unknown colormap: {}", name)))
+}
+
+fn parse_normalization(name: &str) -> PyResult<Normalization> {
+    Normalization::from_name(name).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "Unknown norm: {} (expected \"linear\", \"log\", \"sqrt\", \"cbrt\" or \"eq_hist\")",
+            name
+        ))
+    })
+}
+
+/// Layouts of the x and y axes
+type AxisScales = (AxisScale, AxisScale);
+
+/// Axis layout asked for with the `xscale`/`yscale` and `xaxis` keywords
+#[derive(Debug, Clone, Copy)]
+enum AxisRequest {
+    Scale(AxisScale),
+    /// Time axis starting at the earliest timestamp read
+    Time,
+}
+
+impl AxisRequest {
+    /// Parse the keywords of one axis; `None` if neither is given
+    ///
+    /// `axis` is the `xaxis` keyword, which only the x axis has.
+    fn parse(scale: Option<&str>, axis: Option<&str>, name: &str) -> PyResult<Option<Self>> {
+        let scale = scale
+            .map(|scale| {
+                AxisScale::from_name(scale).ok_or_else(|| {
+                    pyo3::exceptions::PyValueError::new_err(format!(
+                        "Unknown {}scale '{}': expected 'linear', 'log', 'log2' or 'symlog'",
+                        name, scale
+                    ))
+                })
+            })
+            .transpose()?;
+        match axis {
+            None => Ok(scale.map(AxisRequest::Scale)),
+            Some("time") if matches!(scale, None | Some(AxisScale::Linear)) => Ok(Some(AxisRequest::Time)),
+            Some("time") => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "{}axis='time' can't be combined with a log {}scale",
+                name, name
+            ))),
+            Some(other) => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Unknown {}axis '{}': expected 'time'",
+                name, other
+            ))),
+        }
+    }
+
+    /// Keep the origin of an axis that is already a time axis, so series
+    /// added later line up with the first
+    fn keeping(self, current: AxisScale) -> Self {
+        match (self, current) {
+            (AxisRequest::Time, AxisScale::Time { .. }) => AxisRequest::Scale(current),
+            _ => self,
+        }
+    }
+}
+
+/// Coordinates of one axis: the array itself where it needs no mapping
+enum AxisValues<'py> {
+    Array(PyReadonlyArray1<'py, f32>),
+    Mapped(Vec<f32>),
+}
+
+impl AxisValues<'_> {
+    fn as_slice(&self) -> PyResult<&[f32]> {
+        match self {
+            AxisValues::Array(array) => Ok(array.as_slice()?),
+            AxisValues::Mapped(values) => Ok(values),
+        }
+    }
+
+    /// Positions along an axis laid out with `scale`, for data normalized
+    /// right away rather than kept in a `Figure`
+    fn positions(self, scale: AxisScale) -> PyResult<Self> {
+        Ok(match scale {
+            AxisScale::Linear | AxisScale::Time { .. } => self,
+            _ => AxisValues::Mapped(scale.forward_all(self.as_slice()?)),
+        })
+    }
+}
+
+/// Coordinates of a plot normalized right away (`ScatterPlot`, `LinePlot`)
+///
+/// float64 and 64-bit integer arrays on linear axes are kept 64-bit, so
+/// nanosecond timestamps and geographic coordinates don't lose precision
+/// to a float32 cast before normalization.
+enum PlotCoords<'py> {
+    Narrow(AxisValues<'py>, AxisValues<'py>),
+    Wide(WideValues<'py>, WideValues<'py>),
+}
+
+/// float64 coordinates of one axis: the array itself where it was float64
+/// or int64 already
+enum WideValues<'py> {
+    Array(PyReadonlyArray1<'py, f64>),
+    Widened(Vec<f64>),
+}
+
+impl WideValues<'_> {
+    fn as_slice(&self) -> PyResult<&[f64]> {
+        match self {
+            WideValues::Array(array) => Ok(array.as_slice()?),
+            WideValues::Widened(values) => Ok(values),
+        }
+    }
+}
+
+impl<'py> PlotCoords<'py> {
+    /// Read `x` and `y` for the axes `requests` ask for, positioned for
+    /// their scales; if either is wide, the other is widened to match
+    fn read(
+        x: &Bound<'py, PyAny>,
+        y: &Bound<'py, PyAny>,
+        requests: (AxisRequest, AxisRequest),
+    ) -> PyResult<(Self, AxisScales)> {
+        let wide = |values: &Bound<'py, PyAny>, request: AxisRequest| match request {
+            AxisRequest::Scale(AxisScale::Linear) => as_wide_array(values),
+            _ => Ok(None),
+        };
+        let narrow = |values: &Bound<'py, PyAny>, name: &str, request: AxisRequest| {
+            let (values, scale) = read_axis(values, name, request)?;
+            Ok::<_, PyErr>((values.positions(scale)?, scale))
+        };
+        let widened = |wide: Option<PyReadonlyArray1<'py, f64>>, values: &Bound<'py, PyAny>, name: &str, request| {
+            if let Some(array) = wide {
+                return Ok::<_, PyErr>((WideValues::Array(array), AxisScale::Linear));
+            }
+            let (values, scale) = narrow(values, name, request)?;
+            Ok((WideValues::Widened(values.as_slice()?.iter().map(|&v| v as f64).collect()), scale))
+        };
+
+        match (wide(x, requests.0)?, wide(y, requests.1)?) {
+            (None, None) => {
+                let (x, xscale) = narrow(x, "x", requests.0)?;
+                let (y, yscale) = narrow(y, "y", requests.1)?;
+                Ok((PlotCoords::Narrow(x, y), (xscale, yscale)))
+            }
+            (wide_x, wide_y) => {
+                let (x, xscale) = widened(wide_x, x, "x", requests.0)?;
+                let (y, yscale) = widened(wide_y, y, "y", requests.1)?;
+                Ok((PlotCoords::Wide(x, y), (xscale, yscale)))
+            }
+        }
+    }
+
+    /// Number of x and y values
+    fn lens(&self) -> PyResult<(usize, usize)> {
+        Ok(match self {
+            PlotCoords::Narrow(x, y) => (x.as_slice()?.len(), y.as_slice()?.len()),
+            PlotCoords::Wide(x, y) => (x.as_slice()?.len(), y.as_slice()?.len()),
+        })
+    }
+}
+
+/// Read one coordinate array for the axis `request` asks for
+///
+/// Time axes read timestamps and store them as float32 seconds after the
+/// axis origin; other values are read as they are.
+///
+/// # Returns
+/// The values with the scale the axis ends up with
+fn read_axis<'py>(
+    values: &Bound<'py, PyAny>,
+    name: &str,
+    request: AxisRequest,
+) -> PyResult<(AxisValues<'py>, AxisScale)> {
+    let (seconds, origin) = match request {
+        AxisRequest::Scale(AxisScale::Time { origin }) => (as_seconds(values, name)?, origin),
+        AxisRequest::Scale(scale) => return Ok((AxisValues::Array(as_f32_array(values, name)?), scale)),
+        AxisRequest::Time => {
+            let seconds = as_seconds(values, name)?;
+            let earliest = seconds.iter().copied().filter(|s| s.is_finite()).fold(f64::INFINITY, f64::min);
+            let origin = if earliest.is_finite() { earliest.floor() } else { 0.0 };
+            (seconds, origin)
+        }
+    };
+    let offsets = seconds.iter().map(|&s| (s - origin) as f32).collect();
+    Ok((AxisValues::Mapped(offsets), AxisScale::time(origin)))
+}
+
+/// Render `chart_data` without a window and write it to `path` as a PNG
+fn save_png(
+    py: Python,
+    chart_data: &ChartData,
+    kind: ChartKind,
+    path: PathBuf,
+    width: Option<u32>,
+    height: Option<u32>,
+) -> PyResult<()> {
+    let png = render_png(py, chart_data, kind, width, height)?;
+    write_png(&path, png)
+}
+
+/// Render `chart_data` without a window as PNG bytes, at the data's viewport
+/// size unless `width` or `height` are given
+fn render_png(
+    py: Python,
+    chart_data: &ChartData,
+    kind: ChartKind,
+    width: Option<u32>,
+    height: Option<u32>,
+) -> PyResult<Vec<u8>> {
+    let (width, height) = image_size(
+        width.unwrap_or(chart_data.viewport_width as u32),
+        height.unwrap_or(chart_data.viewport_height as u32),
+    )?;
+
+    let mut data = chart_data.clone();
+    data.viewport_width = width as f32;
+    data.viewport_height = height as f32;
+    // Rendering doesn't touch Python objects, so let other threads run.
+    // HelionError converts to RuntimeError for GPU failures
+    let png = py.allow_threads(move || {
+        let mut headless = HeadlessRenderer::new(width, height, RenderOptions::default())?;
+        match kind {
+            ChartKind::Scatter => headless.render_png::<ScatterRenderer>(data),
+            ChartKind::Line => headless.render_png::<LineRenderer>(data),
+            ChartKind::Bars => headless.render_png::<HistogramRenderer>(data),
+        }
+    })?;
+    Ok(png)
+}
+
+/// The PNG a notebook displays inline, or None for an empty plot
+///
+/// A failed render (no GPU on the machine, say) becomes a RuntimeWarning
+/// rather than an exception, so the notebook still shows the text repr.
+fn notebook_png(py: Python, png: Option<PyResult<Vec<u8>>>) -> PyResult<Option<Vec<u8>>> {
+    match png {
+        Some(Ok(png)) => Ok(Some(png)),
+        Some(Err(e)) => {
+            let category = py.get_type_bound::<pyo3::exceptions::PyRuntimeWarning>();
+            PyErr::warn_bound(py, &category, &format!("Helion couldn't render the plot inline: {}", e), 1)?;
+            Ok(None)
+        }
+        None => Ok(None),
+    }
+}
+
+/// `png` inlined in an `<img>` tag
+fn png_html(py: Python, png: &[u8]) -> PyResult<String> {
+    let encoded: String = py
+        .import_bound("base64")?
+        .call_method1("b64encode", (PyBytes::new_bound(py, png),))?
+        .call_method1("decode", ("ascii",))?
+        .extract()?;
+    Ok(format!("<img src=\"data:image/png;base64,{}\" alt=\"Helion plot\"/>", encoded))
+}
+
+/// Both notebook formats for `_repr_mimebundle_`, from a single render
+fn png_bundle<'py>(py: Python<'py>, png: Option<Vec<u8>>) -> PyResult<Option<Bound<'py, PyDict>>> {
+    let Some(png) = png else {
+        return Ok(None);
+    };
+    let bundle = PyDict::new_bound(py);
+    bundle.set_item("text/html", png_html(py, &png)?)?;
+    bundle.set_item("image/png", PyBytes::new_bound(py, &png))?;
+    Ok(Some(bundle))
+}
+
+/// Run `open` with the GIL released, so other Python threads and event
+/// callbacks run while the window is open
+///
+/// AppKit only runs on the process's main thread and winit aborts rather
+/// than erroring anywhere else, so on macOS other threads get a
+/// RuntimeError up front. A Ctrl+C pressed while the window was open is
+/// raised once it closes.
+fn show_window<T, F>(py: Python, open: F) -> PyResult<T>
+where
+    T: Send,
+    F: FnOnce() -> Result<T, HelionError> + Send,
+{
+    if cfg!(target_os = "macos") {
+        let threading = py.import_bound("threading")?;
+        let current = threading.call_method0("current_thread")?;
+        if !current.is(&threading.call_method0("main_thread")?) {
+            return Err(pyo3::exceptions::PyRuntimeError::new_err(
+                "Windows can only be shown from the main thread on macOS; use save() in other threads",
+            ));
+        }
+    }
+    let report = py.allow_threads(open)?;
+    py.check_signals()?;
+    Ok(report)
+}
+
+/// Open `spec` in a window, or serve the plot `session` builds to a browser
+/// when `serve` asks for it or, left unset, when no window can be opened
+///
+/// # Returns
+/// The window's frame report; None when the plot was served
+fn show_or_serve<S>(
+    py: Python,
+    spec: WindowSpec,
+    title: &str,
+    serve: Option<bool>,
+    port: u16,
+    session: S,
+) -> PyResult<Option<FrameReport>>
+where
+    S: FnOnce() -> Result<FrameSession, HelionError> + Send,
+{
+    if serve != Some(true) {
+        match show_window(py, move || Ok(try_run_window(spec)))? {
+            // No display (or no event loop) to open a window on
+            Err(HelionError::Window(_)) if serve.is_none() => {}
+            opened => return Ok(opened?),
+        }
+    }
+    serve_frames(py, title, port, session)?;
+    Ok(None)
+}
+
+/// Serve the plot `session` builds on a local port until its page is closed
+///
+/// The GPU work stays on one thread with the GIL released; the GIL is only
+/// taken back to print the URL and, between requests, to check for Ctrl+C.
+fn serve_frames<S>(py: Python, title: &str, port: u16, session: S) -> PyResult<()>
+where
+    S: FnOnce() -> Result<FrameSession, HelionError> + Send,
+{
+    py.allow_threads(move || {
+        let mut server = FrameServer::bind(port, session()?, title)?;
+        let url = server.url();
+        let port = server.local_addr()?.port();
+        Python::with_gil(|py| {
+            let message = format!(
+                "Serving \"{}\" at {}\nOver SSH, forward the port first: ssh -L {}:localhost:{} <host>",
+                title, url, port, port
+            );
+            py.import_bound("builtins")?.call_method1("print", (message,)).map(|_| ())
+        })?;
+        let mut interrupt = None;
+        server.run(&mut || match Python::with_gil(|py| py.check_signals()) {
+            Ok(()) => true,
+            Err(e) => {
+                interrupt = Some(e);
+                false
+            }
+        })?;
+        interrupt.map_or(Ok(()), Err)
+    })
+}
+
+/// `text` quoted the way Python's `repr` quotes a string: in single quotes
+/// unless it holds only double ones
+fn py_str_repr(text: &str) -> String {
+    let escaped = text.replace('\\', "\\\\").replace('\n', "\\n");
+    if text.contains('\'') && !text.contains('"') {
+        format!("\"{}\"", escaped)
+    } else {
+        format!("'{}'", escaped.replace('\'', "\\'"))
+    }
+}
+
+/// `(width, height)` if both are positive
+fn image_size(width: u32, height: u32) -> PyResult<(u32, u32)> {
+    if width == 0 || height == 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Image size must be positive, got {}x{}",
+            width, height
+        )));
+    }
+    Ok((width, height))
+}
+
+/// Headless renderer in `cache`, sized `width` x `height`: creating a GPU
+/// device takes far longer than a pick, so it is only made once
+fn cached_headless(
+    cache: &mut Option<HeadlessRenderer>,
+    width: u32,
+    height: u32,
+) -> Result<&mut HeadlessRenderer, HelionError> {
+    let headless = match cache.take() {
+        Some(mut headless) => {
+            headless.resize(width, height);
+            headless
+        }
+        None => HeadlessRenderer::new(width, height, RenderOptions::default())?,
+    };
+    Ok(cache.insert(headless))
+}
+
+fn write_png(path: &std::path::Path, png: Vec<u8>) -> PyResult<()> {
+    std::fs::write(path, png).map_err(|e| {
+        pyo3::exceptions::PyOSError::new_err(format!("Failed to write {}: {}", path.display(), e))
+    })
+}
+
+/// Validate an `on(event, callback)` registration
+fn subscription(event: &str, callback: &Bound<'_, PyAny>) -> PyResult<(FigureEventKind, PyObject)> {
+    let kind = FigureEventKind::from_name(event).map_err(pyo3::exceptions::PyValueError::new_err)?;
+    if !callback.is_callable() {
+        return Err(pyo3::exceptions::PyTypeError::new_err("callback must be callable"));
+    }
+    Ok((kind, callback.clone().unbind()))
+}
+
+/// Validate a `set_tooltip(callback)` argument
+fn tooltip_callback(callback: Option<&Bound<'_, PyAny>>) -> PyResult<Option<PyObject>> {
+    match callback {
+        Some(callback) if !callback.is_callable() => {
+            Err(pyo3::exceptions::PyTypeError::new_err("callback must be callable or None"))
+        }
+        callback => Ok(callback.map(|callback| callback.clone().unbind())),
+    }
+}
+
+/// Tooltip whose text `callback` builds from a dict describing the point
+///
+/// Indices are rows of the input data, as in `event_bus`. Exceptions, and
+/// results that aren't str or None, are printed and show no tooltip.
+fn tooltip(py: Python, callback: &PyObject, dropped: &[usize]) -> Tooltip {
+    let callback = callback.clone_ref(py);
+    let dropped: Arc<[usize]> = dropped.into();
+    Tooltip::new().with_formatter(move |point| {
+        Python::with_gil(|py| {
+            let result = (|| {
+                let payload = PyDict::new_bound(py);
+                payload.set_item("series", point.series)?;
+                payload.set_item("index", source_index(&dropped, point.index))?;
+                payload.set_item("x", point.value.map(|[x, _]| x))?;
+                payload.set_item("y", point.value.map(|[_, y]| y))?;
+                callback.call1(py, (payload,))?.extract::<Option<String>>(py)
+            })();
+            result.unwrap_or_else(|e| {
+                e.print(py);
+                None
+            })
+        })
+    })
+}
+
+/// Data arguments of a plotting function called as `f(x, y)`,
+/// `f(x=..., y=...)` or `f(data, x="column", y="column")`
+struct PlotInputs<'py> {
+    function: &'static str,
+    /// DataFrame the arguments name columns of
+    data: Option<Bound<'py, PyAny>>,
+    x: Option<Bound<'py, PyAny>>,
+    y: Option<Bound<'py, PyAny>>,
+}
+
+impl<'py> PlotInputs<'py> {
+    /// Sort the positional `args` of `function` into a DataFrame and the x
+    /// and y given by keyword
+    fn new(
+        function: &'static str,
+        args: &Bound<'py, PyTuple>,
+        x: Option<&Bound<'py, PyAny>>,
+        y: Option<&Bound<'py, PyAny>>,
+    ) -> PyResult<Self> {
+        let mut positional: Vec<Bound<'py, PyAny>> = args.iter().collect();
+        let data = match positional.first() {
+            Some(first) if is_dataframe(first) => Some(positional.remove(0)),
+            _ => None,
+        };
+        if positional.len() > 2 {
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "{}() takes x and y positionally; pass the other arguments by keyword",
+                function
+            )));
+        }
+        let mut positional = positional.into_iter();
+        let mut take = |keyword: Option<&Bound<'py, PyAny>>, name: &str| match (positional.next(), keyword) {
+            (Some(_), Some(_)) => Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "{}() got multiple values for argument '{}'",
+                function, name
+            ))),
+            (value, keyword) => Ok(value.or_else(|| keyword.cloned())),
+        };
+        let (x, y) = (take(x, "x")?, take(y, "y")?);
+        Ok(Self { function, data, x, y })
+    }
+
+    /// The x and y arrays, read from the DataFrame when they name columns
+    fn xy(&mut self) -> PyResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)> {
+        let missing = |name: &str| {
+            pyo3::exceptions::PyTypeError::new_err(format!("{}() missing argument '{}'", self.function, name))
+        };
+        let x = self.x.take().ok_or_else(|| missing("x"))?;
+        let y = self.y.take().ok_or_else(|| missing("y"))?;
+        Ok((self.column(Some(&x), "x")?.unwrap_or(x), self.column(Some(&y), "y")?.unwrap_or(y)))
+    }
+
+    /// `value` as given, or the column it names when there is a DataFrame
+    fn column(&self, value: Option<&Bound<'py, PyAny>>, arg: &str) -> PyResult<Option<Bound<'py, PyAny>>> {
+        match (&self.data, value) {
+            (Some(data), Some(value)) if value.is_instance_of::<PyString>() => {
+                Ok(Some(dataframe_column(data, &value.extract::<String>()?, arg)?))
+            }
+            (_, value) => Ok(value.cloned()),
+        }
+    }
+}
+
+/// Latest selection made in a plot's window, written by its event bus
+type SharedSelection = Arc<Mutex<Selection>>;
+
+/// Event bus forwarding each event to the registered Python callbacks and
+/// recording selections in `selection`
+///
+/// The selection is cleared first, since a new window starts without one.
+/// Exceptions raised by a callback are printed and don't stop the window.
+/// Point indices are reported as rows of the input data, counting the
+/// `dropped` rows the plot left out.
+fn event_bus(
+    py: Python,
+    handlers: &[(FigureEventKind, PyObject)],
+    selection: &SharedSelection,
+    dropped: &[usize],
+) -> EventBus {
+    let mut bus = EventBus::new();
+    *selection.lock().unwrap_or_else(|e| e.into_inner()) = Selection::default();
+    let dropped: Arc<[usize]> = dropped.into();
+    let recorded = Arc::clone(selection);
+    let rows = Arc::clone(&dropped);
+    bus.subscribe_to(FigureEventKind::SelectionChanged, move |event| {
+        if let FigureEvent::SelectionChanged { selection, .. } = with_input_rows(event, &rows) {
+            *recorded.lock().unwrap_or_else(|e| e.into_inner()) = selection;
+        }
+    });
+    for (kind, callback) in handlers {
+        let callback = callback.clone_ref(py);
+        let rows = Arc::clone(&dropped);
+        bus.subscribe_to(*kind, move |event| {
+            let event = with_input_rows(event, &rows);
+            Python::with_gil(|py| {
+                let result = event_to_dict(py, &event).and_then(|payload| callback.call1(py, (payload,)));
+                if let Err(e) = result {
+                    e.print(py);
+                }
+            })
+        });
+    }
+    bus
+}
+
+/// `event` with vertex indices turned into rows of the input data, given
+/// the `dropped` rows the plot left out
+fn with_input_rows(event: &FigureEvent, dropped: &[usize]) -> FigureEvent {
+    match event {
+        FigureEvent::PointPicked { series, index } => FigureEvent::PointPicked {
+            series: *series,
+            index: source_index(dropped, *index),
+        },
+        FigureEvent::PointHovered { point } => FigureEvent::PointHovered {
+            point: point.map(|(series, index)| (series, source_index(dropped, index))),
+        },
+        FigureEvent::SelectionChanged { subplots, selection } => FigureEvent::SelectionChanged {
+            subplots: subplots.clone(),
+            selection: Selection::new(selection.indices().iter().map(|&i| source_index(dropped, i)).collect()),
+        },
+        other => other.clone(),
+    }
+}
+
+fn event_to_dict<'py>(py: Python<'py>, event: &FigureEvent) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new_bound(py);
+    dict.set_item("type", event.kind().name())?;
+    match event {
+        FigureEvent::ViewChanged { camera } => {
+            dict.set_item("scale", (camera.scale[0], camera.scale[1]))?;
+            dict.set_item("translate", (camera.translate[0], camera.translate[1]))?;
+        }
+        FigureEvent::PointPicked { series, index } => {
+            dict.set_item("series", series)?;
+            dict.set_item("index", index)?;
+        }
+        FigureEvent::PointHovered { point } => {
+            dict.set_item("series", point.map(|(series, _)| series))?;
+            dict.set_item("index", point.map(|(_, index)| index))?;
+        }
+        FigureEvent::SelectionChanged { subplots, selection } => {
+            dict.set_item("subplots", subplots.clone())?;
+            dict.set_item("indices", index_array(py, selection.indices()))?;
+        }
+        FigureEvent::DataAppended { series, count, total } => {
+            dict.set_item("series", series)?;
+            dict.set_item("count", count)?;
+            dict.set_item("total", total)?;
+        }
+    }
+    Ok(dict)
+}
+
+fn selection_array<'py>(py: Python<'py>, selection: &SharedSelection) -> Bound<'py, PyArray1<i64>> {
+    let selection = selection.lock().unwrap_or_else(|e| e.into_inner());
+    index_array(py, selection.indices())
+}
+
+fn report_to_dict(report: &FrameReport) -> HashMap<String, u64> {
+    let total = report.total();
+    HashMap::from([
+        ("frame".to_string(), report.frame),
+        ("vertices".to_string(), total.vertices),
+        ("instances".to_string(), total.instances),
+        ("draw_calls".to_string(), total.draw_calls as u64),
+        ("bytes_uploaded".to_string(), total.bytes_uploaded),
+    ])
+}
+
+/// Convert array-likes and normalize them into vertices, returning the map
+/// applied so points appended later can be placed alike
+fn prepare_chart_data(
+    py: Python,
+    coords: &PlotCoords,
+    color: Option<(f32, f32, f32, f32)>,
+    size: Option<f32>,
+    width: f32,
+    height: f32,
+    x_range: Option<(f32, f32)>,
+    y_range: Option<(f32, f32)>,
+    missing: MissingDataPolicy,
+) -> PyResult<(ChartData, ScatterTransform)> {
+    // Warn if arrays have different lengths (core will use shorter length)
+    let (x_len, y_len) = coords.lens()?;
+    if x_len != y_len {
+        let min_len = x_len.min(y_len);
+        py.import_bound("warnings")?
+            .call_method1(
+                "warn",
+                (format!(
+                    "x and y arrays have different lengths ({} vs {}). Using {} points.",
+                    x_len, y_len, min_len
+                ),)
+            )?;
+    }
+
+    let color_opt = color.map(|(r, g, b, a)| Color { r, g, b, a });
+
+    // Create chart data with optional custom ranges. Huge inputs take a
+    // while, so let Ctrl-C (KeyboardInterrupt) abort the preparation
+    let token = CancelToken::new();
+    let mut interrupt = None;
+    let mut chart_data = ChartData::new(width, height);
+    chart_data.set_missing_data(missing);
+    let mut progress = |_| {
+        if interrupt.is_none() {
+            if let Err(e) = py.check_signals() {
+                interrupt = Some(e);
+                token.cancel();
+            }
+        }
+    };
+    let (prepared, transform) = match coords {
+        PlotCoords::Narrow(x, y) => {
+            let (x, y) = (x.as_slice()?, y.as_slice()?);
+            let prepared = chart_data.fill_scatter_cancelable(x, y, color_opt, size, x_range, y_range, &token, &mut progress);
+            (prepared, ScatterTransform::fit(x, y, x_range, y_range))
+        }
+        PlotCoords::Wide(x, y) => {
+            let (x, y) = (x.as_slice()?, y.as_slice()?);
+            let prepared = chart_data.fill_scatter_f64_cancelable(x, y, color_opt, size, x_range, y_range, &token, &mut progress);
+            (prepared, ScatterTransform::fit_f64(x, y, x_range, y_range))
+        }
+    };
+    if let Some(e) = interrupt {
+        return Err(e);
+    }
+    // Only Ctrl-C cancels, so any other error is from missing="error"
+    prepared?;
+    Ok((chart_data, transform))
+}
+
+/// Parse a `missing` keyword: "skip" (default), "clamp" or "error"
+fn parse_missing(missing: Option<&str>) -> PyResult<MissingDataPolicy> {
+    let Some(name) = missing else {
+        return Ok(MissingDataPolicy::default());
+    };
+    MissingDataPolicy::from_name(name).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "Unknown missing '{}': expected 'skip', 'clamp' or 'error'",
+            name
+        ))
+    })
+}
+
+/// What `from_arrays` returns: the point count, and how many were dropped
+fn created_summary(kind: &str, data: &ChartData) -> String {
+    let dropped = match data.dropped().len() {
+        0 => String::new(),
+        n => format!(" ({} not finite, left out)", n),
+    };
+    format!("{} plot created with {} points{}. Call show() to display.", kind, data.vertices.len(), dropped)
+}
+
+/// Per-point `values` (`stride` numbers per point) lined up with the
+/// vertices of `data`
+///
+/// Arrays with one entry per input point, including those left out for
+/// not being finite, lose the entries of the points left out; any other
+/// length is returned as is for the caller to check.
+fn kept_rows<'a>(values: &'a [f32], stride: usize, data: &ChartData) -> std::borrow::Cow<'a, [f32]> {
+    let dropped = data.dropped();
+    if dropped.is_empty() || values.len() != (data.vertices.len() + dropped.len()) * stride {
+        return values.into();
+    }
+    let mut kept = Vec::with_capacity(data.vertices.len() * stride);
+    let mut next = dropped.iter().peekable();
+    for (row, chunk) in values.chunks_exact(stride).enumerate() {
+        if next.peek() == Some(&&row) {
+            next.next();
+        } else {
+            kept.extend_from_slice(chunk);
+        }
+    }
+    kept.into()
+}
+
+/// Read the coordinates of a standalone plot, mapped for the scales its
+/// keywords ask for
+///
+/// # Returns
+/// The x and y positions with the (x, y) scales used
+fn read_plot_axes<'py>(
+    x: &Bound<'py, PyAny>,
+    y: &Bound<'py, PyAny>,
+    xscale: Option<&str>,
+    yscale: Option<&str>,
+    xaxis: Option<&str>,
+) -> PyResult<(PlotCoords<'py>, AxisScales)> {
+    let linear = AxisRequest::Scale(AxisScale::Linear);
+    PlotCoords::read(
+        x,
+        y,
+        (
+            AxisRequest::parse(xscale, xaxis, "x")?.unwrap_or(linear),
+            AxisRequest::parse(yscale, None, "y")?.unwrap_or(linear),
+        ),
+    )
+}
+
+/// Parse a hex string, (r, g, b) or (r, g, b, a) color argument
+fn parse_color(color: Option<&Bound<'_, PyAny>>) -> PyResult<Option<(f32, f32, f32, f32)>> {
+    let Some(c) = color else {
+        return Ok(None);
+    };
+    if let Ok(text) = c.extract::<String>() {
+        let color = Color::parse(&text).map_err(pyo3::exceptions::PyValueError::new_err)?;
+        Ok(Some((color.r, color.g, color.b, color.a)))
+    } else if let Ok(rgba) = c.extract::<(f32, f32, f32, f32)>() {
+        Ok(Some(rgba))
+    } else if let Ok((r, g, b)) = c.extract::<(f32, f32, f32)>() {
+        Ok(Some((r, g, b, 1.0)))
+    } else {
+        Err(pyo3::exceptions::PyTypeError::new_err(
+            "color must be a hex string, color name, (r, g, b) tuple, or (r, g, b, a) tuple"
+        ))
+    }
+}
+
+/// Create a scatter plot from Python lists, numpy arrays or other array-likes,
+/// or from the columns of a DataFrame
+/// 
+/// Pass `x` and `y` as arrays, or a pandas or polars DataFrame (or a pyarrow
+/// Table, another frame supporting the dataframe interchange protocol, or a
+/// dict of arrays) first with column names for `x`, `y`, `c`, `size` and
+/// `alpha`. Float columns without missing values are read in place.
+/// 
+/// Inputs are converted to float32 only when needed: float32 NumPy arrays,
+/// buffer-protocol objects (array.array, memoryview, mmap) and CPU tensors
+/// exposing DLPack (PyTorch, JAX) are read without an intermediate copy.
+/// GPU tensors (PyTorch on CUDA, CuPy) are copied to host memory in a single
+/// transfer by their own framework. float64 and int64 NumPy arrays on a
+/// linear axis are normalized in double precision, so nanosecond timestamps
+/// or longitudes within a city keep their spacing.
+/// 
+/// Args:
+///     x: Array-like of x coordinates
+///     y: Array-like of y coordinates
+///     color: Optional hex color string (e.g., "#FF5733"), CSS color name
+///         (e.g., "tomato") or RGB tuple, or one color per point as an
+///         (N, 3)/(N, 4) array or a list of hex strings and names
+///     size: Point size in pixels, either a single value or an array with one
+///         size per point for a bubble chart. Default is 2.0.
+///     width: Viewport width in pixels. Default is 800.0.
+///     height: Viewport height in pixels. Default is 600.0.
+///     x_range: Optional tuple (min, max) for custom x output range. Default is [-1.0, 1.0].
+///     y_range: Optional tuple (min, max) for custom y output range. Default is [-1.0, 1.0].
+///     alpha: Optional opacity, either a single value or an array with one value
+///         (0.0-1.0) per point. Overrides the alpha of `color`.
+///     c: Optional array with one value per point, colored through `cmap`
+///         instead of using `color`. NaN values are not drawn.
+///     cmap: Name of a built-in or registered colormap for `c`. Default is
+///         "viridis"; add "_r" to reverse it.
+///     vmin: Value given the first color of `cmap`. Default is the smallest
+///         value of `c`.
+///     vmax: Value given the last color of `cmap`. Default is the largest
+///         value of `c`.
+///     norm: How `c` is spread over `cmap`: "linear" (default), "log"
+///         (values <= 0 are not drawn), "sqrt", "cbrt" or "eq_hist" to give
+///         each color about as many points.
+///     center: Optional value of `c` given the middle color of `cmap`, e.g.
+///         0 with "coolwarm"; each side of it spans half the colormap
+///         however far vmin and vmax reach.
+///     xscale: "log" to space decades of x evenly or "log2" powers of two;
+///         values <= 0 are not drawn. "symlog" spaces decades evenly on
+///         both sides of zero, for data with negative values and zeros.
+///         Default is "linear".
+///     yscale: "linear" (default), "log", "log2" or "symlog", as for xscale
+///     xaxis: "time" to read x as timestamps: a datetime64 array, a
+///         sequence of datetime objects, or seconds since the Unix epoch.
+///         They are kept to the microsecond, which float32 couldn't do.
+///     missing: What to do with points whose x or y is NaN or infinite:
+///         "skip" (default) leaves them out, "clamp" pins infinities to the
+///         edge of the plot and leaves NaN out, "error" raises ValueError.
+///         `dropped_points` counts the points left out; per-point colors,
+///         sizes and alpha may still have one entry per input point.
+/// 
+/// Returns:
+///     PyScatterPlot object
+/// 
+/// Raises:
+///     ValueError: If both color and c are given, cmap is unknown, c
+///         doesn't have one value per point, a scale, axis type or missing
+///         policy is unknown, or missing="error" and a point isn't finite
+/// 
+/// Example:
+///     >>> import helion
+///     >>> import numpy as np
+///     >>> x = np.random.rand(100000)
+///     >>> y = np.random.rand(100000)
+///     >>> plot = helion.scatter(x, y, color="#FF5733")
+///     >>> 
+///     >>> # Custom range mapping to [0, 1] instead of [-1, 1]
+///     >>> plot2 = helion.scatter(x, y, x_range=(0.0, 1.0), y_range=(0.0, 1.0))
+///     >>>
+///     >>> # Color by distance from the center
+///     >>> plot3 = helion.scatter(x, y, c=np.hypot(x - 0.5, y - 0.5), cmap="magma")
+///     >>>
+///     >>> # Sizes spanning six orders of magnitude
+///     >>> plot4 = helion.scatter(10 ** (6 * x), y, xscale="log")
+///     >>>
+///     >>> # Columns of a DataFrame
+///     >>> df = pd.DataFrame({"carat": carat, "price": price, "depth": depth})
+///     >>> plot5 = helion.scatter(df, x="carat", y="price", c="depth")
+#[pyfunction]
+#[pyo3(signature = (*args, x=None, y=None, color=None, size=None, width=800.0, height=600.0, x_range=None, y_range=None, alpha=None, c=None, cmap="viridis", vmin=None, vmax=None, norm="linear", center=None, xscale=None, yscale=None, xaxis=None, missing=None))]
+#[allow(clippy::too_many_arguments)]
+fn scatter(
+    py: Python,
+    args: &Bound<'_, PyTuple>,
+    x: Option<&Bound<'_, PyAny>>,
+    y: Option<&Bound<'_, PyAny>>,
+    color: Option<&Bound<'_, PyAny>>,
+    size: Option<&Bound<'_, PyAny>>,
+    width: f32,
+    height: f32,
+    x_range: Option<(f32, f32)>,
+    y_range: Option<(f32, f32)>,
+    alpha: Option<&Bound<'_, PyAny>>,
+    c: Option<&Bound<'_, PyAny>>,
+    cmap: &str,
+    vmin: Option<f32>,
+    vmax: Option<f32>,
+    norm: &str,
+    center: Option<f32>,
+    xscale: Option<&str>,
+    yscale: Option<&str>,
+    xaxis: Option<&str>,
+    missing: Option<&str>,
+) -> PyResult<PyScatterPlot> {
+    if color.is_some() && c.is_some() {
+        return Err(pyo3::exceptions::PyValueError::new_err("Pass either color or c, not both"));
+    }
+    let mut inputs = PlotInputs::new("scatter", args, x, y)?;
+    let (x, y) = inputs.xy()?;
+    let (c, size, alpha) = (inputs.column(c, "c")?, inputs.column(size, "size")?, inputs.column(alpha, "alpha")?);
+    let (x, y, c, size, alpha) = (&x, &y, c.as_ref(), size.as_ref(), alpha.as_ref());
+    let colormap = parse_colormap(cmap)?;
+    let norm = parse_normalization(norm)?;
+    let mut plot = PyScatterPlot::new();
+    // A single color, or failing that one color per point
+    let (color_tuple, per_point) = match parse_color(color) {
+        Ok(color_tuple) => (color_tuple, None),
+        Err(e) => match color {
+            Some(c) if !c.is_instance_of::<pyo3::types::PyString>() && c.hasattr("__len__")? => (None, Some(c)),
+            _ => return Err(e),
+        },
+    };
+
+    // A single size, or failing that one size per point
+    let (scalar_size, per_point_size) = match size {
+        Some(s) => match s.extract::<f32>() {
+            Ok(value) => (Some(value), None),
+            Err(_) => (None, Some(s)),
+        },
+        None => (None, None),
+    };
+
+    plot.from_arrays(py, x, y, color_tuple, scalar_size, width, height, x_range, y_range, xscale, yscale, xaxis, missing)?;
+
+    if let Some(colors) = per_point {
+        plot.set_colors(colors)?;
+    }
+    if let (Some(c), Some(chart_data)) = (c, plot.chart_data.as_mut()) {
+        let values = as_f32_array(c, "c")?;
+        let values = kept_rows(values.as_slice()?, 1, chart_data);
+        if values.len() != chart_data.vertices.len() {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "c has {} values but the plot has {} points",
+                values.len(), chart_data.vertices.len()
+            )));
+        }
+        let mut norm = norm.fit_range(&values, vmin, vmax);
+        if let Some(center) = center {
+            norm = norm.with_center(center);
+        }
+        chart_data.set_colormapped(&values, &colormap, &norm);
+    }
+    if let Some(sizes) = per_point_size {
+        plot.set_sizes(sizes)?;
+    }
+    if let Some(alpha) = alpha {
+        plot.set_alpha(alpha)?;
+    }
+    Ok(plot)
+}
+
+/// Create a line chart from Python lists, numpy arrays or other array-likes
+///
+/// Points are joined in the order given, so sort by x first for a time
+/// series. Accepts the same inputs as `scatter`.
+///
+/// Args:
+///     x: Array-like of x coordinates
+///     y: Array-like of y coordinates
+///     color: Optional hex color string (e.g., "#FF5733"), CSS color name or RGB tuple
+///     width: Viewport width in pixels. Default is 800.0.
+///     height: Viewport height in pixels. Default is 600.0.
+///     x_range: Optional tuple (min, max) for custom x output range. Default is [-1.0, 1.0].
+///     y_range: Optional tuple (min, max) for custom y output range. Default is [-1.0, 1.0].
+///     xscale: "linear" (default), "log", "log2" or "symlog", as for `scatter`
+///     yscale: The y axis scale, as for xscale
+///     xaxis: "time" to read x as timestamps, as for `scatter`
+///     missing: "skip" (default), "clamp" or "error", as for `scatter`;
+///         the line joins the points either side of one left out
+///
+/// Returns:
+///     PyLinePlot object
+///
+/// Raises:
+///     ValueError: If a scale, axis type or missing policy is unknown, or
+///         missing="error" and a point isn't finite
+///
+/// Example:
+///     >>> import helion
+///     >>> import numpy as np
+///     >>> t = np.linspace(0, 10, 10000)
+///     >>> plot = helion.line(t, np.sin(t), color="#1f77b4")
+///     >>>
+///     >>> # One sample a minute through a day
+///     >>> times = np.arange("2024-03-01", "2024-03-02", dtype="datetime64[m]")
+///     >>> plot2 = helion.line(times, np.random.randn(times.size).cumsum(), xaxis="time")
+#[pyfunction]
+#[pyo3(signature = (x, y, color=None, width=800.0, height=600.0, x_range=None, y_range=None, xscale=None, yscale=None, xaxis=None, missing=None))]
+fn line(
+    py: Python,
+    x: &Bound<'_, PyAny>,
+    y: &Bound<'_, PyAny>,
+    color: Option<&Bound<'_, PyAny>>,
+    width: f32,
+    height: f32,
+    x_range: Option<(f32, f32)>,
+    y_range: Option<(f32, f32)>,
+    xscale: Option<&str>,
+    yscale: Option<&str>,
+    xaxis: Option<&str>,
+    missing: Option<&str>,
+) -> PyResult<PyLinePlot> {
+    let mut plot = PyLinePlot::new();
+    let color_tuple = parse_color(color)?;
+    plot.from_arrays(py, x, y, color_tuple, width, height, x_range, y_range, xscale, yscale, xaxis, missing)?;
+    Ok(plot)
+}
+
+/// Create a histogram of `values`
+///
+/// Bins on the GPU with a compute shader when available, so millions of
+/// samples are counted in a few milliseconds; falls back to the CPU with
+/// identical counts. NaN and infinite values are skipped.
+///
+/// Args:
+///     values: Array-like of samples
+///     bins: Number of equal-width bins spanning the data. Default is 64.
+///     density: Scale bars so their total area is 1. Default is False.
+///     color: Optional hex string, CSS color name or RGB(A) tuple
+///     width: Width in pixels. Default is 800.0.
+///     height: Height in pixels. Default is 600.0.
+///
+/// Returns:
+///     PyFigure object holding the bars
+///
+/// Raises:
+///     ValueError: If bins is 0
+///
+/// Example:
+///     >>> import helion
+///     >>> import numpy as np
+///     >>> fig = helion.hist(np.random.normal(size=5_000_000), bins=100)
+///     >>> fig.show()
+#[pyfunction]
+#[pyo3(signature = (values, bins=64, density=false, color=None, width=800.0, height=600.0))]
+fn hist(
+    py: Python,
+    values: &Bound<'_, PyAny>,
+    bins: usize,
+    density: bool,
+    color: Option<&Bound<'_, PyAny>>,
+    width: f32,
+    height: f32,
+) -> PyResult<Py<PyFigure>> {
+    let figure = Bound::new(py, PyFigure::new(width, height, None))?;
+    PyFigure::hist(figure.borrow_mut(), py, values, bins, density, color, None, None)?;
+    Ok(figure.unbind())
+}
+
+/// Create a heatmap of a 2D array
+///
+/// Args:
+///     z: 2D array-like of shape (rows, columns); row 0 is drawn at the
+///         bottom. NaN cells are not drawn.
+///     extent: Optional (xmin, xmax, ymin, ymax) the grid covers.
+///     cmap: Name of a built-in or registered colormap. Default is "viridis".
+///     vmin: Value given the first color. Default is the smallest value.
+///     vmax: Value given the last color. Default is the largest value.
+///     norm: How values are spread over the colormap: "linear" (default),
+///         "log", "sqrt", "cbrt" or "eq_hist".
+///     center: Optional value given the middle color, as for scatter().
+///     width: Width in pixels. Default is 800.0.
+///     height: Height in pixels. Default is 600.0.
+///
+/// Returns:
+///     PyFigure object; add series on top with its scatter() and line()
+///
+/// Example:
+///     >>> import helion
+///     >>> import numpy as np
+///     >>> xx, yy = np.meshgrid(np.linspace(-3, 3, 400), np.linspace(-3, 3, 300))
+///     >>> fig = helion.heatmap(np.exp(-(xx**2 + yy**2)), extent=(-3, 3, -3, 3), cmap="magma")
+///     >>> fig.show()
+#[pyfunction]
+#[pyo3(signature = (z, extent=None, cmap="viridis", vmin=None, vmax=None, norm="linear", center=None, width=800.0, height=600.0))]
+#[allow(clippy::too_many_arguments)]
+fn heatmap(
+    z: &Bound<'_, PyAny>,
+    extent: Option<(f32, f32, f32, f32)>,
+    cmap: &str,
+    vmin: Option<f32>,
+    vmax: Option<f32>,
+    norm: &str,
+    center: Option<f32>,
+    width: f32,
+    height: f32,
+) -> PyResult<PyFigure> {
+    let mut figure = PyFigure::new(width, height, None);
+    figure.heatmap(z, extent, cmap, vmin, vmax, norm, center)?;
+    Ok(figure)
+}
+
+/// Create a 2D histogram of the points (x[i], y[i])
+///
+/// The readable alternative to a scatter plot of millions of overlapping
+/// points. Counting runs on the GPU when one with compute shaders is
+/// available, on the CPU otherwise.
+///
+/// Args:
+///     x: Array-like of x coordinates
+///     y: Array-like of y coordinates, as many as x
+///     bins: Number of cells along both axes, or (nx, ny). Default is 256.
+///     range: Optional ((xmin, xmax), (ymin, ymax)) covered by the cells.
+///     cmap: Name of a built-in or registered colormap. Default is "viridis".
+///     norm: How counts are spread over the colormap: "linear" (default),
+///         "log", "sqrt", "cbrt" or "eq_hist".
+///     width: Width in pixels. Default is 800.0.
+///     height: Height in pixels. Default is 600.0.
+///
+/// Returns:
+///     PyFigure object
+///
+/// Example:
+///     >>> import helion
+///     >>> import numpy as np
+///     >>> x = np.random.normal(size=10_000_000)
+///     >>> fig = helion.hist2d(x, x + np.random.normal(size=x.size), bins=512, norm="log")
+///     >>> fig.show()
+#[pyfunction]
+#[pyo3(signature = (x, y, bins=None, range=None, cmap="viridis", norm="linear", width=800.0, height=600.0))]
+#[allow(clippy::too_many_arguments)]
+fn hist2d(
+    py: Python,
+    x: &Bound<'_, PyAny>,
+    y: &Bound<'_, PyAny>,
+    bins: Option<&Bound<'_, PyAny>>,
+    range: Option<((f32, f32), (f32, f32))>,
+    cmap: &str,
+    norm: &str,
+    width: f32,
+    height: f32,
+) -> PyResult<PyFigure> {
+    let mut figure = PyFigure::new(width, height, None);
+    figure.hist2d(py, x, y, bins, range, cmap, norm)?;
+    Ok(figure)
+}
+
+/// Create an empty figure to add several series to
+///
+/// Used as a context manager, the figure is shown (or saved to `save`)
+/// when the `with` block ends.
+///
+/// Args:
+///     width: Width in pixels. Default is 800.0.
+///     height: Height in pixels. Default is 600.0.
+///     save: PNG file a `with` block writes the figure to instead of
+///         showing it. Default is None.
+///
+/// Returns:
+///     PyFigure object
+///
+/// Example:
+///     >>> import helion
+///     >>> import numpy as np
+///     >>> t = np.linspace(0, 10, 1000)
+///     >>> with helion.figure(width=800, height=400) as fig:
+///     ...     fig.scatter(t, np.sin(t) + np.random.normal(0, 0.1, t.size), label="samples")
+///     ...     fig.line(t, np.sin(t), color="black", label="model")
+#[pyfunction]
+#[pyo3(signature = (width=800.0, height=600.0, save=None))]
+fn figure(width: f32, height: f32, save: Option<PathBuf>) -> PyFigure {
+    PyFigure::new(width, height, save)
+}
+
+/// Register a colormap by name from a list of hex color stops
+///
+/// The stops are spaced evenly from low to high values. Registering an
+/// existing custom name replaces it; built-in names can't be replaced.
+///
+/// Args:
+///     name: Case-insensitive name; append "_r" when using it for the reversed map
+///     colors: At least two hex strings ("#RRGGBB" or "#RRGGBBAA")
+///     space: Space the stops are blended in: "oklab" (default, perceptually
+///         even), "lab", or "srgb" to match gradients designed in RGB
+///
+/// Raises:
+///     ValueError: If the name is taken by a built-in, a color is invalid or
+///         the space is unknown
+///
+/// Example:
+///     >>> import helion
+///     >>> helion.register_colormap("ocean", ["#e0f3f8", "#4575b4", "#08306b"])
+///     >>> "ocean" in helion.colormaps()
+///     True
+#[pyfunction]
+#[pyo3(signature = (name, colors, space="oklab"))]
+fn register_colormap(name: &str, colors: Vec<String>, space: &str) -> PyResult<()> {
+    let stops: Vec<&str> = colors.iter().map(String::as_str).collect();
+    ColorSpace::from_name(space)
+        .and_then(|space| register_colormap_in(name, &stops, space))
+        .map_err(pyo3::exceptions::PyValueError::new_err)
+}
+
+/// Names of all built-in and registered colormaps, sorted
+#[pyfunction]
+fn colormaps() -> Vec<String> {
+    colormap_names()
 }
 
 /// Helion Python bindings
@@ -191,9 +2747,20 @@ fn _helion(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Point2D>()?;
     m.add_class::<Color>()?;
     m.add_class::<PyScatterPlot>()?;
+    m.add_class::<PyLinePlot>()?;
+    m.add_class::<PyFigure>()?;
+    m.add_class::<PyPlotManager>()?;
+    m.add_class::<PySeries>()?;
     
     // Functions
     m.add_function(wrap_pyfunction!(scatter, m)?)?;
+    m.add_function(wrap_pyfunction!(line, m)?)?;
+    m.add_function(wrap_pyfunction!(figure, m)?)?;
+    m.add_function(wrap_pyfunction!(hist, m)?)?;
+    m.add_function(wrap_pyfunction!(heatmap, m)?)?;
+    m.add_function(wrap_pyfunction!(hist2d, m)?)?;
+    m.add_function(wrap_pyfunction!(register_colormap, m)?)?;
+    m.add_function(wrap_pyfunction!(colormaps, m)?)?;
     
     Ok(())
 }
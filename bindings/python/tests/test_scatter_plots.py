@@ -5,6 +5,13 @@ These tests verify that scatter plots can be created without errors
 and that the data structures are correctly initialized.
 """
 
+import array
+import ast
+import mmap
+import pathlib
+import sys
+import threading
+
 import pytest
 import helion
 import numpy as np
@@ -83,6 +90,118 @@ class TestScatterPlotCreation:
         plot = helion.scatter(x, y)
         assert plot is not None
     
+    def test_scatter_with_alpha_scalar(self):
+        """Test scatter plot with a single opacity value"""
+        x = np.random.rand(1000)
+        y = np.random.rand(1000)
+        
+        plot = helion.scatter(x, y, alpha=0.5)
+        assert plot is not None
+    
+    def test_scatter_with_alpha_array(self):
+        """Test scatter plot with per-point opacity"""
+        x = np.random.rand(1000)
+        y = np.random.rand(1000)
+        confidence = np.random.rand(1000)
+        
+        plot = helion.scatter(x, y, color="#FF5733", alpha=confidence)
+        assert plot is not None
+    
+    def test_scatter_alpha_length_mismatch(self):
+        """Test that a per-point alpha array must match the point count"""
+        x = np.random.rand(1000)
+        y = np.random.rand(1000)
+        
+        with pytest.raises(ValueError, match="alpha has 10 values"):
+            helion.scatter(x, y, alpha=np.random.rand(10))
+    
+    def test_scatter_with_per_point_rgba(self):
+        """Test scatter plot with one RGBA color per point"""
+        x = np.random.rand(1000)
+        y = np.random.rand(1000)
+
+        plot = helion.scatter(x, y, color=np.random.rand(1000, 4))
+        assert plot is not None
+
+    def test_scatter_with_per_point_rgb_and_uint8(self):
+        """RGB arrays get full opacity; uint8 arrays are read as 0-255"""
+        x = np.random.rand(100)
+        y = np.random.rand(100)
+
+        assert helion.scatter(x, y, color=np.random.rand(100, 3)) is not None
+        colors = np.random.randint(0, 256, size=(100, 4), dtype=np.uint8)
+        assert helion.scatter(x, y, color=colors, alpha=0.5) is not None
+
+    def test_scatter_with_hex_list(self):
+        """Test per-point colors given as hex strings"""
+        plot = helion.scatter([0, 1, 2], [0, 1, 2], color=["#ff0000", "#00ff00", "#0000ff80"])
+        assert plot is not None
+
+    def test_scatter_with_named_colors(self):
+        """CSS color names work for single and per-point colors"""
+        assert helion.scatter([0, 1], [0, 1], color="tomato") is not None
+        assert helion.scatter([0, 1], [0, 1], color=["steelblue", "#ff000080"]) is not None
+
+    def test_scatter_rejects_malformed_color_strings(self):
+        """Malformed strings raise instead of silently becoming black"""
+        with pytest.raises(ValueError, match="Invalid hex color"):
+            helion.scatter([0, 1], [0, 1], color="#ff00")
+        with pytest.raises(ValueError, match="Unknown color"):
+            helion.scatter([0, 1], [0, 1], color="tomatoe")
+
+    def test_scatter_per_point_color_errors(self):
+        """Per-point colors must match the point count and shape"""
+        x = np.random.rand(100)
+        y = np.random.rand(100)
+
+        with pytest.raises(ValueError, match="color has 10 colors"):
+            helion.scatter(x, y, color=np.random.rand(10, 4))
+        with pytest.raises(ValueError, match="shape"):
+            helion.scatter(x, y, color=np.random.rand(100, 5))
+        with pytest.raises(ValueError, match="Unknown color"):
+            helion.scatter([0, 1], [0, 1], color=["#ff0000", "reddish"])
+
+    def test_scatter_with_colormap(self):
+        """c= colors points by value through cmap="""
+        x = np.random.rand(100)
+        y = np.random.rand(100)
+        plot = helion.scatter(x, y, c=x + y, cmap="plasma")
+        assert plot is not None
+        plot = helion.scatter(x, y, c=np.arange(100), cmap="Magma_r", vmin=10, vmax=90)
+        assert plot is not None
+        plot = helion.scatter(x, y, c=10 ** (6 * x), norm="log")
+        assert plot is not None
+        plot = helion.scatter(x, y, c=x - 0.2, cmap="coolwarm", center=0.0)
+        assert plot is not None
+
+    def test_scatter_colormap_errors(self):
+        """Bad c= and cmap= arguments raise ValueError"""
+        with pytest.raises(ValueError, match="Unknown colormap"):
+            helion.scatter([0, 1], [0, 1], c=[0, 1], cmap="no_such_map")
+        with pytest.raises(ValueError, match="c has 1 values but the plot has 2 points"):
+            helion.scatter([0, 1], [0, 1], c=[0])
+        with pytest.raises(ValueError, match="either color or c"):
+            helion.scatter([0, 1], [0, 1], color="red", c=[0, 1])
+        with pytest.raises(ValueError, match="Unknown norm"):
+            helion.scatter([0, 1], [0, 1], c=[0, 1], norm="logarithmic")
+
+    def test_scatter_with_per_point_sizes(self):
+        """Test a bubble chart with one size per point"""
+        x = np.random.rand(1000)
+        y = np.random.rand(1000)
+
+        plot = helion.scatter(x, y, size=np.random.rand(1000) * 20)
+        assert plot is not None
+        assert helion.scatter([0, 1, 2], [0, 1, 2], size=[2, 8, 16]) is not None
+
+    def test_scatter_size_length_mismatch(self):
+        """A per-point size array must match the point count"""
+        x = np.random.rand(100)
+        y = np.random.rand(100)
+
+        with pytest.raises(ValueError, match="size has 10 values"):
+            helion.scatter(x, y, size=np.random.rand(10))
+
     def test_mismatched_array_lengths(self):
         """Test handling of mismatched x/y array lengths"""
         x = np.random.rand(1000)
@@ -94,6 +213,109 @@ class TestScatterPlotCreation:
         assert plot is not None
 
 
+class TestArrayLikeInputs:
+    """Test inputs that are not NumPy arrays"""
+    
+    def test_array_module_buffers(self):
+        """Test array.array inputs of float and double type"""
+        x = array.array("f", [0.0, 0.5, 1.0])
+        y = array.array("d", [1.0, 0.5, 0.0])
+        
+        plot = helion.scatter(x, y)
+        assert plot is not None
+    
+    def test_memoryview_input(self):
+        """Test memoryview inputs, including integer formats"""
+        x = memoryview(array.array("i", [1, 2, 3]))
+        y = memoryview(np.array([3.0, 2.0, 1.0], dtype=np.float32))
+        
+        plot = helion.scatter(x, y, alpha=memoryview(array.array("f", [0.2, 0.4, 0.6])))
+        assert plot is not None
+    
+    def test_mmap_buffer_input(self):
+        """Test reading coordinates straight out of a memory map"""
+        values = np.arange(100, dtype=np.float32)
+        buffer = mmap.mmap(-1, values.nbytes)
+        buffer.write(values.tobytes())
+        
+        plot = helion.scatter(np.frombuffer(buffer, dtype=np.float32), values)
+        assert plot is not None
+    
+    def test_non_contiguous_array(self):
+        """Test that strided views are accepted"""
+        x = np.random.rand(2000).astype(np.float32)[::2]
+        y = np.random.rand(1000)
+        
+        plot = helion.scatter(x, y)
+        assert plot is not None
+    
+    def test_from_arrays_accepts_lists(self):
+        """Test that ScatterPlot.from_arrays converts sequences itself"""
+        plot = helion.ScatterPlot()
+        message = plot.from_arrays([0.0, 1.0, 2.0], (2.0, 1.0, 0.0))
+        assert "3 points" in message
+    
+    def test_torch_cpu_tensor(self):
+        """Test CPU tensors, which are read through DLPack"""
+        torch = pytest.importorskip("torch")
+        x = torch.linspace(0, 1, 1000)
+        y = torch.rand(1000, dtype=torch.float64)
+        
+        plot = helion.scatter(x, y)
+        assert plot is not None
+    
+    def test_torch_tensor_requiring_grad(self):
+        """Test tensors that track gradients, e.g. model outputs"""
+        torch = pytest.importorskip("torch")
+        x = torch.rand(100, requires_grad=True)
+        y = x * 2
+        
+        plot = helion.scatter(x, y)
+        assert plot is not None
+    
+    def test_torch_cuda_tensor(self):
+        """Test CUDA tensors, which are copied to the host through DLPack"""
+        torch = pytest.importorskip("torch")
+        if not torch.cuda.is_available():
+            pytest.skip("CUDA is not available")
+        x = torch.rand(1000, device="cuda")
+        y = torch.rand(1000, device="cuda", dtype=torch.float64)
+        
+        plot = helion.scatter(x, y, alpha=torch.rand(1000, device="cuda"))
+        assert plot is not None
+    
+    def test_cupy_array(self):
+        """Test CuPy arrays living on the GPU"""
+        cupy = pytest.importorskip("cupy")
+        x = cupy.linspace(0, 1, 1000, dtype=cupy.float32)
+        
+        plot = helion.scatter(x, cupy.sin(x))
+        assert plot is not None
+    
+    def test_multidimensional_input_rejected(self):
+        """Test that 2-D inputs raise a clear error"""
+        with pytest.raises(ValueError, match="x must be 1-dimensional"):
+            helion.scatter(np.zeros((10, 2)), np.zeros(10))
+    
+    def test_non_numeric_input_rejected(self):
+        """Test that non-numeric inputs raise an error"""
+        with pytest.raises((TypeError, ValueError)):
+            helion.scatter(["a", "b"], [1.0, 2.0])
+
+    def test_wide_arrays(self):
+        """float64 and int64 arrays are read without a float32 cast"""
+        t = np.arange(5, dtype=np.int64) * 1000 + 1_700_000_000_000_000_000
+        plot = helion.scatter(t, np.arange(5.0), missing="error")
+        assert len(plot) == 5
+        plot.append(np.array([t[-1] + 1000], dtype=np.float64), [5.0])
+        plot.append([0.5], [0.5])
+        assert len(plot) == 7
+        line = helion.line(t.astype(np.float64), np.arange(5, dtype=np.float32), missing="error")
+        assert len(line) == 5
+        with pytest.raises(ValueError, match="Point 1"):
+            helion.scatter(np.array([0.0, np.nan]), [0.0, 1.0], missing="error")
+
+
 class TestColorClass:
     """Test the Color class functionality"""
     
@@ -122,6 +344,28 @@ class TestColorClass:
         assert abs(color.a - 0.8) < 1e-6
 
 
+    def test_color_from_hex_rejects_malformed(self):
+        """Malformed hex strings raise ValueError"""
+        with pytest.raises(ValueError, match="Invalid hex color"):
+            helion.Color.from_hex("#FF57")
+        with pytest.raises(ValueError, match="Invalid hex color"):
+            helion.Color.from_hex("#GG5733")
+
+    def test_color_from_name(self):
+        """CSS named colors, ignoring case"""
+        color = helion.Color.from_name("SteelBlue")
+        assert (round(color.r * 255), round(color.g * 255), round(color.b * 255)) == (70, 130, 180)
+        with pytest.raises(ValueError, match="Unknown color name"):
+            helion.Color.from_name("notacolor")
+
+    def test_color_from_hsl_and_hsv(self):
+        """Hue is in degrees; saturation, lightness and value in 0.0-1.0"""
+        red = helion.Color.from_hsl(0.0, 1.0, 0.5)
+        assert (red.r, red.g, red.b, red.a) == (1.0, 0.0, 0.0, 1.0)
+        green = helion.Color.from_hsv(120.0, 1.0, 1.0, 0.5)
+        assert (green.r, green.g, green.b, green.a) == (0.0, 1.0, 0.0, 0.5)
+
+
 class TestPoint2D:
     """Test the Point2D class functionality"""
     
@@ -146,6 +390,789 @@ class TestPlotTitle:
         assert plot is not None
 
 
+class TestFrameStats:
+    """Test draw statistics reporting"""
+    
+    def test_frame_stats_before_show(self):
+        """No frame has been drawn until show() runs"""
+        plot = helion.scatter(np.random.rand(100), np.random.rand(100))
+        assert plot.frame_stats() is None
+
+
+class TestSelection:
+    """Test reading back points selected in the window"""
+
+    def test_selection_before_show(self):
+        """Nothing is selected until the window is shown"""
+        plot = helion.scatter(np.random.rand(100), np.random.rand(100))
+        selection = plot.get_selection()
+        assert isinstance(selection, np.ndarray)
+        assert selection.dtype == np.int64
+        assert selection.size == 0
+
+    def test_selection_indexes_data(self):
+        """Selections can index the plotted arrays directly"""
+        x = np.random.rand(10)
+        plot = helion.line(x, x)
+        assert x[plot.get_selection()].size == 0
+        assert helion.figure().get_selection().dtype == np.int64
+
+
+class TestSavePng:
+    """Test offscreen rendering to PNG files"""
+
+    PNG_SIGNATURE = b"\x89PNG\r\n\x1a\n"
+
+    def test_save_scatter(self, tmp_path):
+        """A scatter plot can be saved without opening a window"""
+        plot = helion.scatter(np.random.rand(100), np.random.rand(100), size=6.0)
+        path = tmp_path / "scatter.png"
+        plot.save(path, 320, 240)
+        data = path.read_bytes()
+        assert data.startswith(self.PNG_SIGNATURE)
+        # IHDR holds the big-endian width and height
+        assert int.from_bytes(data[16:20], "big") == 320
+        assert int.from_bytes(data[20:24], "big") == 240
+
+    def test_save_defaults_to_plot_size(self, tmp_path):
+        """Without a size the plot's own viewport is used"""
+        path = tmp_path / "line.png"
+        helion.line([0, 1, 2], [0, 1, 0], width=200, height=100).save(str(path))
+        data = path.read_bytes()
+        assert int.from_bytes(data[16:20], "big") == 200
+        assert int.from_bytes(data[20:24], "big") == 100
+
+    def test_save_errors(self, tmp_path):
+        """Missing data and empty sizes are rejected"""
+        with pytest.raises(ValueError, match="No data set"):
+            helion.ScatterPlot().save(tmp_path / "empty.png")
+        plot = helion.scatter([0, 1], [0, 1])
+        with pytest.raises(ValueError, match="positive"):
+            plot.save(tmp_path / "zero.png", 0, 100)
+
+
+class TestPlotManager:
+    """Test showing several plots on one event loop"""
+
+    def test_add_queues_plots(self):
+        """Plots wait in the queue until show() runs"""
+        manager = helion.PlotManager()
+        manager.add(helion.scatter([0, 1], [0, 1]))
+        manager.add(helion.line([0, 1], [1, 0]), title="Line")
+        fig = helion.figure()
+        fig.scatter([0, 1], [0, 1])
+        manager.add(fig)
+        assert len(manager) == 3
+
+    def test_add_from_threads(self):
+        """Any thread can queue a plot"""
+        manager = helion.PlotManager()
+        threads = [
+            threading.Thread(target=manager.add, args=(helion.scatter([0, 1], [0, 1]),))
+            for _ in range(4)
+        ]
+        for thread in threads:
+            thread.start()
+        for thread in threads:
+            thread.join()
+        assert len(manager) == 4
+
+    def test_add_rejects_non_plots(self):
+        """Only plots can be added, and only with data"""
+        manager = helion.PlotManager()
+        with pytest.raises(TypeError, match="ScatterPlot, LinePlot or Figure"):
+            manager.add("plot")
+        with pytest.raises(ValueError, match="No data set"):
+            manager.add(helion.ScatterPlot())
+        with pytest.raises(ValueError, match="Figure is empty"):
+            manager.add(helion.figure())
+        assert len(manager) == 0
+
+    def test_show_nothing_queued(self):
+        """With nothing queued show() returns without opening a window"""
+        manager = helion.PlotManager()
+        manager.show()
+        assert manager.frame_stats() == []
+
+
+class TestNotebookDisplay:
+    """Test inline display in Jupyter and VS Code notebooks"""
+
+    def test_repr_png(self):
+        """Plots render themselves as PNGs at their own size"""
+        data = helion.scatter(np.random.rand(100), np.random.rand(100), width=200, height=100)._repr_png_()
+        assert data.startswith(TestSavePng.PNG_SIGNATURE)
+        assert int.from_bytes(data[16:20], "big") == 200
+        assert int.from_bytes(data[20:24], "big") == 100
+
+    def test_repr_html_inlines_png(self):
+        """The HTML repr is an img tag holding the PNG"""
+        html = helion.line([0, 1, 2], [0, 1, 0])._repr_html_()
+        assert html.startswith('<img src="data:image/png;base64,')
+
+    def test_mimebundle(self):
+        """One render gives every format"""
+        fig = helion.figure(width=200, height=100)
+        fig.scatter([0, 1], [0, 1])
+        bundle = fig._repr_mimebundle_(include=None, exclude=None)
+        assert set(bundle) == {"image/png", "text/html"}
+        assert bundle["image/png"].startswith(TestSavePng.PNG_SIGNATURE)
+
+    def test_empty_plots_have_no_image(self):
+        """Without data notebooks fall back to the text repr"""
+        assert helion.ScatterPlot()._repr_png_() is None
+        assert helion.LinePlot()._repr_html_() is None
+        assert helion.figure()._repr_mimebundle_() is None
+
+
+class TestReprs:
+    """Test reprs, lengths and iteration"""
+
+    def test_plot_reprs(self):
+        """Plots name their title and point count"""
+        plot = helion.scatter([0.0, 1.0, 2.0], [0.0, 1.0, 4.0])
+        plot.set_title("It's mine")
+        assert repr(plot) == 'ScatterPlot(title="It\'s mine", points=3)'
+        assert repr(helion.LinePlot()) == "LinePlot(title='Helion Line Plot', points=0)"
+        assert len(helion.line([0, 1], [1, 0])) == 2
+        assert repr(helion.PlotManager()) == "PlotManager(pending=0)"
+
+    def test_value_reprs(self):
+        """Colors and points repr like their constructors"""
+        assert repr(helion.Color(1.0, 0.5, 0.0)) == "Color(r=1.0, g=0.5, b=0.0, a=1.0)"
+        assert repr(helion.Point2D(1.0, -2.0)) == "Point2D(x=1.0, y=-2.0)"
+
+    def test_figure_iterates_series(self):
+        """Iterating a figure yields its series in order"""
+        fig = helion.figure(width=640, height=480)
+        fig.set_title("Results")
+        fig.scatter([0, 1, 2], [3, 4, 5], label="samples")
+        fig.line([0, 1], [1, 0], color="red")
+        assert repr(fig) == "Figure(title='Results', width=640, height=480, series=2)"
+
+        samples, model = list(fig)
+        assert isinstance(samples, helion.Series)
+        assert (samples.kind, samples.label, len(samples)) == ("scatter", "samples", 3)
+        assert repr(samples) == "Series(kind='scatter', label='samples', points=3)"
+        assert list(samples) == [(0.0, 3.0), (1.0, 4.0), (2.0, 5.0)]
+        assert samples.y.dtype == np.float32
+        np.testing.assert_array_equal(samples.x, [0, 1, 2])
+        assert model.kind == "line" and model.label is None
+        assert (model.color.r, model.color.g, model.color.b) == (1.0, 0.0, 0.0)
+
+    def test_bars_iterate_left_edges(self):
+        """Bars pair each height with its left edge"""
+        fig = helion.figure()
+        fig.hist([0.0, 0.0, 1.0], bins=2)
+        (bars,) = fig
+        assert bars.kind == "bars" and len(bars) == 2 and len(bars.x) == 3
+        assert list(bars) == [(0.0, 2.0), (0.5, 1.0)]
+
+    def test_series_is_a_snapshot(self):
+        """Adding to the figure later doesn't change series already yielded"""
+        fig = helion.figure()
+        fig.scatter([0, 1], [0, 1])
+        (series,) = fig
+        fig.scatter([2], [2])
+        assert len(series) == 2 and len(fig) == 2
+
+
+    def test_zorder(self):
+        """zorder= and set_zorder() reach the figure's spec"""
+        import json
+
+        fig = helion.figure()
+        samples = fig.scatter([0, 1], [0, 1], zorder=2)
+        model = fig.line([0, 1], [1, 0])
+        bars = fig.hist([0.0, 1.0], bins=2, zorder=-1)
+        assert (samples.zorder, model.zorder, bars.zorder) == (2, 0, -1)
+
+        model.set_zorder(5)
+        assert model.zorder == 5
+        styles = [series["style"] for series in json.loads(fig.to_spec())["series"]]
+        assert [style["z_order"] for style in styles] == [2, 5, -1]
+        assert [series.zorder for series in fig] == [2, 5, -1]
+
+
+class TestTypeStubs:
+    """Test that the shipped type stubs match the compiled module"""
+
+    STUB = pathlib.Path(helion.__file__).with_name("_helion.pyi")
+
+    @classmethod
+    def stub_classes(cls):
+        tree = ast.parse(cls.STUB.read_text())
+        return {
+            node.name: {
+                item.name
+                for item in node.body
+                if isinstance(item, ast.FunctionDef) and not item.name.startswith("__")
+            }
+            | {
+                item.target.id
+                for item in node.body
+                if isinstance(item, ast.AnnAssign) and isinstance(item.target, ast.Name)
+            }
+            for node in tree.body
+            if isinstance(node, ast.ClassDef)
+        }
+
+    def test_stub_covers_module(self):
+        """Every public class and function has a stub, and nothing else does"""
+        tree = ast.parse(self.STUB.read_text())
+        stubbed = {node.name for node in tree.body if isinstance(node, (ast.ClassDef, ast.FunctionDef))}
+        compiled = {name for name in dir(helion._helion) if not name.startswith("_")}
+        assert stubbed == compiled
+
+    def test_stub_covers_methods(self):
+        """Each class's public methods and attributes match its stub"""
+        for name, members in self.stub_classes().items():
+            cls = getattr(helion._helion, name)
+            public = {attr for attr in dir(cls) if not attr.startswith("__")}
+            assert public == members, name
+
+
+class TestAppend:
+    """Test streaming points into a scatter plot"""
+
+    def test_append_points(self):
+        """Appended points are added after the existing ones"""
+        plot = helion.scatter(np.random.rand(100), np.random.rand(100), color="red")
+        plot.append(np.random.rand(10), np.random.rand(10))
+        plot.append([0.5], [0.5], color="#00FF00", size=8.0)
+        assert len(plot) == 111
+
+    def test_append_rolling_window(self):
+        """max_points keeps only the newest points"""
+        plot = helion.scatter([0, 1], [0, 1])
+        plot.append(np.arange(10.0), np.arange(10.0), max_points=5)
+        assert len(plot) == 5
+        plot.append([1.0], [1.0])
+        assert len(plot) == 5
+
+    def test_append_errors(self):
+        """Appending needs data and matching lengths"""
+        with pytest.raises(ValueError, match="No data set"):
+            helion.ScatterPlot().append([0.0], [0.0])
+        plot = helion.scatter([0, 1], [0, 1])
+        with pytest.raises(ValueError, match="same length"):
+            plot.append([0.0, 1.0], [0.0])
+
+
+class TestEvents:
+    """Test figure event subscriptions"""
+
+    def test_on_accepts_known_events(self):
+        """Callbacks can be registered for every event type"""
+        plot = helion.scatter(np.random.rand(10), np.random.rand(10))
+        for event in ["view_changed", "point_picked", "point_hovered", "selection_changed", "data_appended"]:
+            plot.on(event, lambda payload: None)
+        helion.line([0, 1], [0, 1]).on("view_changed", print)
+
+    def test_on_rejects_unknown_event(self):
+        """Misspelled event names fail at registration, not silently"""
+        plot = helion.scatter(np.random.rand(10), np.random.rand(10))
+        with pytest.raises(ValueError, match="Unknown event 'zoom'"):
+            plot.on("zoom", lambda payload: None)
+
+    def test_on_requires_callable(self):
+        """The callback must be callable"""
+        plot = helion.scatter(np.random.rand(10), np.random.rand(10))
+        with pytest.raises(TypeError, match="callable"):
+            plot.on("view_changed", 42)
+
+    def test_on_hover(self):
+        """on_hover subscribes to point_hovered on plots and figures"""
+        plot = helion.scatter(np.random.rand(10), np.random.rand(10))
+        plot.on("point_hovered", lambda payload: None)
+        plot.on_hover(lambda payload: None)
+        helion.figure().on_hover(print)
+        with pytest.raises(TypeError, match="callable"):
+            plot.on_hover(42)
+
+
+class TestPicking:
+    """Test finding the point drawn at a pixel"""
+
+    def test_pick_scatter(self):
+        """The point under a pixel is found, and None over the background"""
+        plot = helion.scatter([0, 1, 2], [0, 1, 2], size=10.0, width=200, height=100)
+        assert plot.pick(100, 50) == 1
+        assert plot.pick(50, 20) is None
+        assert plot.pick(-5, 50) is None
+
+    def test_pick_counts_dropped_rows(self):
+        """Indices are rows of the input, like selections"""
+        plot = helion.scatter([0, np.nan, 1, 2], [0, 0, 1, 2], size=10.0, width=200, height=100)
+        assert plot.pick(100, 50) == 2
+
+    def test_pick_figure(self):
+        """Figures report the series and index of the topmost point"""
+        fig = helion.figure(width=200, height=100)
+        fig.scatter([0, 1, 2], [0, 1, 2], size=10.0)
+        assert fig.pick(100, 50) == (0, 1)
+        fig.scatter([1], [1], size=10.0)
+        assert fig.pick(100, 50) == (1, 0)
+
+    def test_pick_without_data(self):
+        """Picking needs data"""
+        with pytest.raises(ValueError, match="No data set"):
+            helion.ScatterPlot().pick(0, 0)
+
+
+class TestTooltip:
+    """Test customizing the hover tooltip"""
+
+    def test_set_tooltip(self):
+        """Tooltips take a callable, and None restores the default"""
+        plot = helion.scatter(np.random.rand(10), np.random.rand(10))
+        plot.set_tooltip(lambda point: f"#{point['index']}")
+        plot.set_tooltip(None)
+        helion.figure().set_tooltip(lambda point: None)
+
+    def test_set_tooltip_requires_callable(self):
+        """Anything else is rejected when set"""
+        with pytest.raises(TypeError, match="callable"):
+            helion.scatter([0, 1], [0, 1]).set_tooltip("x")
+
+
+class TestDataFrame:
+    """Test plotting the columns of a DataFrame"""
+
+    def test_dict_columns(self):
+        """Arguments naming columns are read from the frame"""
+        data = {"a": [0.0, 1.0, 2.0], "b": [0.0, 1.0, 2.0], "c": [1.0, 2.0, 3.0], "s": [4.0, 5.0, 6.0]}
+        plot = helion.scatter(data, x="a", y="b", c="c", size="s", width=200, height=100)
+        assert len(plot) == 3
+        assert plot.pick(100, 50) == 1
+
+    def test_pandas(self):
+        """pandas columns, including nullable ones, plot like arrays"""
+        pd = pytest.importorskip("pandas")
+        df = pd.DataFrame({"x": [0.0, 1.0, 2.0, 3.0], "y": pd.array([0.0, None, 2.0, 3.0], dtype="Float64")})
+        plot = helion.scatter(df, x="x", y="y")
+        assert len(plot) == 3
+        assert plot.dropped_points == 1
+
+    def test_polars(self):
+        """polars frames are read through their columns"""
+        pl = pytest.importorskip("polars")
+        df = pl.DataFrame({"x": np.arange(10.0), "y": np.arange(10.0) ** 2, "t": np.arange(10.0)})
+        assert len(helion.scatter(df, x="x", y="y", c="t")) == 10
+
+    def test_pyarrow(self):
+        """pyarrow tables name their columns with column_names"""
+        pa = pytest.importorskip("pyarrow")
+        table = pa.table({"x": [0.0, 1.0, None], "y": [0.0, 1.0, 2.0]})
+        assert len(helion.scatter(table, x="x", y="y")) == 2
+
+    def test_unknown_column(self):
+        """Misspelled columns are reported with the ones available"""
+        with pytest.raises(ValueError, match="names column 'z'"):
+            helion.scatter({"x": [0, 1], "y": [0, 1]}, x="x", y="z")
+
+    def test_arguments_checked(self):
+        """x and y can't be given twice or left out"""
+        with pytest.raises(TypeError, match="multiple values for argument 'x'"):
+            helion.scatter([0, 1], [0, 1], x=[0, 1])
+        with pytest.raises(TypeError, match="missing argument 'y'"):
+            helion.scatter({"x": [0, 1]}, x="x")
+        with pytest.raises(TypeError, match="by keyword"):
+            helion.scatter([0, 1], [0, 1], "red")
+
+    def test_keyword_arrays(self):
+        """x and y may also be passed by keyword"""
+        assert len(helion.scatter(x=[0, 1], y=[0, 1])) == 2
+
+
+class TestBoxLassoSelection:
+    """Test box and lasso selection"""
+
+    def test_select_box(self):
+        """Points inside the box are selected and kept as the selection"""
+        plot = helion.scatter([0, 1, 2], [0, 1, 2], width=200, height=100)
+        selected = plot.select_box(150, -10, 90, 60)
+        assert selected.dtype == np.int64
+        assert list(selected) == [1, 2]
+        assert list(plot.get_selection()) == [1, 2]
+        assert len(plot.select_box(10, 10, 20, 20)) == 0
+
+    def test_select_lasso(self):
+        """Points inside the outline are selected, counting dropped rows"""
+        plot = helion.scatter([0, np.nan, 1, 2], [0, 0, 1, 2], width=200, height=100)
+        selected = plot.select_lasso([(-10, 40), (120, 40), (120, 110), (-10, 110)])
+        assert list(selected) == [0, 2]
+
+    def test_select_lasso_needs_three_points(self):
+        """Two points don't enclose anything"""
+        plot = helion.scatter([0, 1], [0, 1])
+        with pytest.raises(ValueError, match="at least 3 points"):
+            plot.select_lasso([(0, 0), (10, 10)])
+
+    def test_select_without_data(self):
+        """Selecting needs data"""
+        with pytest.raises(ValueError, match="No data set"):
+            helion.ScatterPlot().select_box(0, 0, 1, 1)
+
+    def test_on_select(self):
+        """on_select subscribes to selection_changed on plots and figures"""
+        plot = helion.scatter(np.random.rand(10), np.random.rand(10))
+        plot.on_select(lambda payload: None)
+        helion.figure().on_select(print)
+        with pytest.raises(TypeError, match="callable"):
+            plot.on_select(42)
+
+
+class TestLinePlot:
+    """Test line chart creation"""
+
+    def test_basic_line_plot(self):
+        """Test creating a line chart from a time series"""
+        t = np.linspace(0, 10, 1000)
+        plot = helion.line(t, np.sin(t))
+        assert isinstance(plot, helion.LinePlot)
+        assert plot.frame_stats() is None
+
+    def test_line_with_color_and_ranges(self):
+        """Test line chart styling options shared with scatter"""
+        t = np.arange(100, dtype=np.float32)
+        plot = helion.line(t, t ** 2, color="#1f77b4", x_range=(0.0, 1.0), y_range=(0.0, 1.0))
+        assert plot is not None
+
+    def test_line_accepts_lists(self):
+        """Test that plain sequences work like in scatter"""
+        plot = helion.line([0, 1, 2], [3.0, 1.0, 2.0])
+        assert plot is not None
+
+    def test_line_show_without_data(self):
+        """Test that an empty line chart refuses to show"""
+        with pytest.raises(ValueError, match="No data set"):
+            helion.LinePlot().show()
+
+    def test_line_invalid_color(self):
+        """Test that line validates colors like scatter"""
+        with pytest.raises(TypeError):
+            helion.line([0, 1], [0, 1], color=12345)
+
+
+class TestFigure:
+    """Test figures holding several series"""
+
+    def test_figure_collects_series(self):
+        """scatter() and line() add series to the same figure"""
+        t = np.linspace(0, 10, 100)
+        fig = helion.figure(width=640, height=480)
+        assert isinstance(fig, helion.Figure)
+        assert len(fig) == 0
+
+        fig.scatter(t, np.sin(t), label="samples")
+        fig.line(t, np.cos(t), color="black", label="model")
+        fig.scatter([0, 1], [1, 0], color=(1.0, 0.0, 0.0), size=5.0)
+        assert len(fig) == 3
+
+    def test_series_length_mismatch(self):
+        """Unlike helion.scatter, a figure series needs one y per x"""
+        fig = helion.figure()
+        with pytest.raises(ValueError, match="expected 3, got 2"):
+            fig.scatter([0, 1, 2], [0, 1])
+        assert len(fig) == 0
+
+    def test_line_gap(self):
+        """A line can be left broken over gaps in x"""
+        fig = helion.figure()
+        fig.line([0, 1, 2, 10, 11], [0, 1, 0, 1, 0], gap=2.0)
+        assert len(fig) == 1
+        with pytest.raises(ValueError, match="gap must be positive"):
+            fig.line([0, 1], [0, 1], gap=0.0)
+        assert len(fig) == 1
+
+    def test_data_labels(self):
+        """Points and bars can be labelled with values or given text"""
+        fig = helion.figure()
+        fig.scatter([0, 1, 2], [3, 1, 2]).set_data_labels(["a", "b", "c"])
+        fig.line([0, 1], [0.5, 1.5]).set_data_labels(precision=1)
+        with pytest.raises(ValueError, match="expected 3, got 2"):
+            fig.scatter([0, 1, 2], [0, 1, 0]).set_data_labels(["a", "b"])
+
+    def test_invalid_color(self):
+        """Bad colors are rejected before the series is added"""
+        fig = helion.figure()
+        with pytest.raises(ValueError, match="Unknown color"):
+            fig.line([0, 1], [0, 1], color="blurple")
+        assert len(fig) == 0
+
+    def test_show_empty_figure(self):
+        """Showing a figure without series raises"""
+        with pytest.raises(ValueError, match="empty"):
+            helion.figure().show()
+
+    def test_to_spec(self):
+        """to_spec() serializes the figure as JSON for the web bindings"""
+        import json
+
+        fig = helion.figure(width=640, height=480)
+        fig.set_title("Load")
+        fig.line([0, 1, 2], [3, 4, 5], color="black", label="api")
+        spec = json.loads(fig.to_spec())
+        assert spec["version"] == 1
+        assert (spec["width"], spec["height"], spec["title"]) == (640, 480, "Load")
+        assert spec["series"][0]["kind"] == "line"
+        assert spec["series"][0]["y"] == [3, 4, 5]
+        assert spec["series"][0]["label"] == "api"
+        assert spec["series"][0]["style"]["color"] == "#000000ff"
+
+    def test_title_and_axis_labels(self):
+        """Title and axis labels are kept on the figure; None removes a label"""
+        fig = helion.figure()
+        assert fig.title is None
+        fig.set_title("Throughput")
+        fig.set_xlabel("time (s)")
+        fig.set_ylabel("requests")
+        assert (fig.title, fig.xlabel, fig.ylabel) == ("Throughput", "time (s)", "requests")
+
+        fig.set_ylabel(None)
+        assert fig.ylabel is None
+
+    def test_legend_location(self):
+        """legend() takes matplotlib-style corner names"""
+        fig = helion.figure()
+        fig.scatter([0.0, 1.0], [0.0, 1.0], label="points")
+        fig.legend()
+        fig.legend(loc="lower left")
+        fig.legend("upper_left")
+
+        with pytest.raises(ValueError, match="Unknown legend location"):
+            fig.legend(loc="center")
+
+    def test_hist(self):
+        """hist() bins the values into bars, skipping NaN"""
+        import json
+
+        values = np.concatenate([np.random.normal(size=100_000), [np.nan]])
+        fig = helion.hist(values, bins=32)
+        assert len(fig) == 1
+        bars = json.loads(fig.to_spec())["series"][0]
+        assert bars["kind"] == "bars"
+        assert len(bars["x"]) == 33
+        assert sum(bars["y"]) == 100_000
+
+    def test_hist_density_and_errors(self):
+        """density=True scales the bars to unit area; bins must be positive"""
+        import json
+
+        fig = helion.figure()
+        fig.hist([0.0, 1.0, 1.5, 2.0], bins=2, density=True, label="latency")
+        bars = json.loads(fig.to_spec())["series"][0]
+        assert bars["y"] == [0.25, 0.75]
+        assert bars["label"] == "latency"
+
+        with pytest.raises(ValueError, match="bins must be at least 1"):
+            fig.hist([1.0, 2.0], bins=0)
+
+    def test_heatmap(self):
+        """A 2D array becomes the figure's heatmap, not a series"""
+        z = np.arange(12, dtype=np.float32).reshape(3, 4)
+        fig = helion.heatmap(z, extent=(0.0, 4.0, 0.0, 3.0), cmap="magma")
+        assert len(fig) == 0
+
+        with pytest.raises(ValueError, match="must be 2-dimensional"):
+            fig.heatmap(np.arange(4.0))
+        with pytest.raises(ValueError):
+            fig.heatmap(z, cmap="no-such-map")
+        fig.heatmap(z, norm="eq_hist")
+        fig.heatmap(z - 3.0, cmap="coolwarm", center=0.0)
+        with pytest.raises(ValueError, match="Unknown norm"):
+            fig.heatmap(z, norm="histogram")
+
+    def test_hist2d(self):
+        """hist2d bins points into a heatmap; bins must be positive"""
+        x = np.random.rand(1000)
+        y = np.random.rand(1000)
+        fig = helion.hist2d(x, y, bins=(16, 8))
+        assert len(fig) == 0
+        fig.hist2d(x, y, bins=4, range=((0.0, 1.0), (0.0, 1.0)))
+        fig.hist2d(x, y, bins=32, norm="log")
+
+        with pytest.raises(ValueError, match="bins must be at least 1"):
+            fig.hist2d(x, y, bins=0)
+        with pytest.raises(ValueError):
+            fig.hist2d(x, y[:10])
+
+
+class TestFigureContext:
+    """Test figures used as context managers"""
+
+    def test_with_block_saves_on_exit(self, tmp_path):
+        """Series added in the block are saved when it ends"""
+        path = tmp_path / "figure.png"
+        with helion.figure(width=200, height=100, save=path) as fig:
+            fig.scatter([0, 1, 2], [0, 1, 0])
+            fig.line([0, 1, 2], [1, 0, 1])
+            assert not path.exists()
+        assert len(fig) == 2
+        data = path.read_bytes()
+        assert data.startswith(TestSavePng.PNG_SIGNATURE)
+        assert int.from_bytes(data[16:20], "big") == 200
+
+    def test_error_in_block_propagates(self, tmp_path):
+        """An exception in the block is re-raised and nothing is saved"""
+        path = tmp_path / "figure.png"
+        with pytest.raises(KeyError):
+            with helion.figure(save=path) as fig:
+                fig.scatter([0, 1], [0, 1])
+                raise KeyError("boom")
+        assert not path.exists()
+
+    def test_empty_figure_errors(self, tmp_path):
+        """Leaving a block that added nothing is an error"""
+        with pytest.raises(ValueError, match="Figure is empty"):
+            with helion.figure(save=tmp_path / "empty.png"):
+                pass
+
+
+class TestAxisScales:
+    """Test the xscale, yscale and xaxis keywords"""
+
+    def test_log_scale(self):
+        """Log axes accept values spanning many decades; zeros aren't drawn"""
+        x = np.logspace(-3, 6, 1000)
+        plot = helion.scatter(x, np.log10(x), xscale="log")
+        assert len(plot) == 1000
+        helion.line([0.0, 1.0, 10.0], [1, 2, 3], yscale="log", xscale="linear")
+
+    def test_log_scale_append(self):
+        """Appended points are placed on the same log axis"""
+        plot = helion.scatter([1.0, 1000.0], [0.0, 1.0], xscale="log")
+        plot.append([10.0, 100.0], [0.5, 0.5])
+        assert len(plot) == 4
+
+    def test_time_axis(self):
+        """datetime64 arrays, datetimes and epoch seconds are all timestamps"""
+        import datetime
+        import json
+
+        times = np.arange("2024-03-01", "2024-03-02", dtype="datetime64[m]")
+        helion.line(times, np.arange(times.size), xaxis="time")
+
+        fig = helion.figure()
+        fig.line(times, np.arange(times.size), xaxis="time")
+        start = datetime.datetime(2024, 3, 1, 12, tzinfo=datetime.timezone.utc)
+        fig.scatter([start.replace(tzinfo=None)], [5.0])
+        spec = json.loads(fig.to_spec())
+        assert spec["xscale"] == {"time": {"origin": 1709251200.0}}
+        # Stored relative to the origin, to the second
+        assert spec["series"][0]["x"][:2] == [0.0, 60.0]
+        assert spec["series"][1]["x"] == [43200.0]
+
+    def test_figure_scales(self):
+        """Scale keywords change the whole figure's axes"""
+        import json
+
+        fig = helion.figure()
+        fig.scatter([1, 10, 100], [1, 2, 3], yscale="log")
+        fig.line([1, 10, 100], [3, 2, 1])
+        assert json.loads(fig.to_spec())["yscale"] == "log10"
+        fig.line([1, 10, 100], [3, 2, 1], yscale="linear")
+        assert "yscale" not in json.loads(fig.to_spec())
+
+    def test_log2_and_symlog_scales(self):
+        """log2 and symlog are stored by name; symlog keeps zeros and negatives"""
+        import json
+
+        fig = helion.figure()
+        fig.scatter([1, 2, 4, 8], [-10.0, 0.0, 0.5, 1000.0], xscale="log2", yscale="symlog")
+        spec = json.loads(fig.to_spec())
+        assert spec["xscale"] == "log2"
+        assert spec["yscale"] == {"symlog": {"linthresh": 1.0}}
+        plot = helion.scatter([-100.0, 0.0, 100.0], [1, 2, 3], xscale="symlog")
+        assert len(plot) == 3
+
+    def test_invalid_scales(self):
+        """Unknown names and log time axes are rejected"""
+        with pytest.raises(ValueError, match="Unknown xscale 'sqrt'"):
+            helion.scatter([1, 2], [1, 2], xscale="sqrt")
+        with pytest.raises(ValueError, match="Unknown xaxis 'date'"):
+            helion.line([1, 2], [1, 2], xaxis="date")
+        with pytest.raises(ValueError, match="can't be combined"):
+            helion.figure().scatter([1, 2], [1, 2], xscale="log", xaxis="time")
+
+
+
+class TestMissingData:
+    """Test the missing keyword for NaN and infinite coordinates"""
+
+    def test_skip_by_default(self):
+        """Points that aren't finite are left out and counted"""
+        x = np.array([0.0, np.nan, 2.0, np.inf, 4.0])
+        plot = helion.scatter(x, x)
+        assert len(plot) == 3 and plot.dropped_points == 2
+        line = helion.line(x, x)
+        assert len(line) == 3 and line.dropped_points == 2
+        assert helion.scatter([1.0, 1.0], [2.0, 2.0]).dropped_points == 0
+
+    def test_clamp_keeps_infinities(self):
+        """Infinities are pinned to the edge; NaN is still left out"""
+        plot = helion.scatter([0.0, np.inf, np.nan, 1.0], [0.0, 1.0, 1.0, 1.0], missing="clamp")
+        assert len(plot) == 3 and plot.dropped_points == 1
+
+    def test_error_raises(self):
+        """missing="error" names the first point that isn't finite"""
+        with pytest.raises(ValueError, match="Point 1"):
+            helion.scatter([0.0, np.nan], [0.0, 1.0], missing="error")
+        plot = helion.scatter([0.0, 1.0], [0.0, 1.0], missing="error")
+        with pytest.raises(ValueError, match="Point 0"):
+            plot.append([np.inf], [0.0])
+        assert len(plot) == 2
+        with pytest.raises(ValueError, match="Unknown missing 'drop'"):
+            helion.line([0.0], [0.0], missing="drop")
+
+    def test_per_point_values_follow_input_rows(self):
+        """Per-point arrays may have one entry per input point"""
+        x = np.array([0.0, np.nan, 2.0])
+        plot = helion.scatter(x, x, size=[1.0, 2.0, 3.0], c=[0.0, 0.5, 1.0], alpha=[0.1, 0.2, 0.3])
+        assert len(plot) == 2
+        plot.set_colors(["red", "green", "blue"])
+        plot.set_sizes([4.0, 5.0])
+
+    def test_appended_points_counted(self):
+        """Appended points that aren't finite are skipped too"""
+        plot = helion.scatter([0.0, 1.0], [0.0, 1.0])
+        plot.append([np.nan, 0.5], [0.0, 0.5])
+        assert len(plot) == 3 and plot.dropped_points == 1
+
+class TestColormaps:
+    """Test colormap registration"""
+
+    def test_builtin_colormaps_listed(self):
+        """Built-in colormaps are always available"""
+        names = helion.colormaps()
+        assert "viridis" in names
+        assert "coolwarm" in names
+        assert names == sorted(names)
+
+    def test_register_colormap(self):
+        """A registered colormap is listed under its lowercase name"""
+        helion.register_colormap("Py_Ocean", ["#e0f3f8", "#4575b4", "#08306b"])
+        assert "py_ocean" in helion.colormaps()
+
+    def test_register_colormap_errors(self):
+        """Invalid registrations raise ValueError"""
+        with pytest.raises(ValueError, match="built in"):
+            helion.register_colormap("viridis", ["#000000", "#ffffff"])
+        with pytest.raises(ValueError, match="Invalid hex color"):
+            helion.register_colormap("py_bad", ["#000000", "red"])
+        with pytest.raises(ValueError, match="two color stops"):
+            helion.register_colormap("py_short", ["#000000"])
+        with pytest.raises(ValueError, match="Unknown color space"):
+            helion.register_colormap("py_space", ["#000000", "#ffffff"], space="hsv")
+
+    def test_register_colormap_space(self):
+        """Stops can be blended in RGB instead of the default Oklab"""
+        helion.register_colormap("py_rgb", ["#000000", "#ffffff"], space="srgb")
+        helion.register_colormap("py_lab", ["#000000", "#ffffff"], space="lab")
+        assert {"py_rgb", "py_lab"} <= set(helion.colormaps())
+
+
 class TestErrorHandling:
     """Test error handling"""
     
@@ -155,6 +1182,41 @@ class TestErrorHandling:
         
         with pytest.raises(ValueError, match="No data set"):
             plot.show()
+
+    def test_show_with_title_without_data(self):
+        """A window title doesn't get around the missing data"""
+        with pytest.raises(ValueError, match="No data set"):
+            helion.ScatterPlot().show(title="My plot")
+
+    def test_serve_without_data(self):
+        """Serving to a browser still needs data"""
+        with pytest.raises(ValueError, match="No data set"):
+            helion.ScatterPlot().show(serve=True)
+        with pytest.raises(ValueError, match="Figure is empty"):
+            helion.Figure().show(serve=True)
+
+    def test_serve_port_out_of_range(self):
+        """Ports above 65535 are rejected before anything is shown"""
+        plot = helion.scatter([0.0, 1.0], [0.0, 1.0])
+        with pytest.raises(OverflowError):
+            plot.show(serve=True, port=70000)
+
+    @pytest.mark.skipif(sys.platform != "darwin", reason="Only macOS needs the main thread")
+    def test_show_off_main_thread_on_macos(self):
+        """macOS can only run windows on the main thread"""
+        plot = helion.scatter([0.0, 1.0], [0.0, 1.0])
+        errors = []
+
+        def show():
+            try:
+                plot.show()
+            except RuntimeError as e:
+                errors.append(e)
+
+        thread = threading.Thread(target=show)
+        thread.start()
+        thread.join()
+        assert len(errors) == 1 and "main thread" in str(errors[0])
     
     def test_invalid_color_format(self):
         """Test that invalid color format raises error"""
@@ -1,5 +1,13 @@
-import React, { useEffect, useRef } from 'react';
-import { scatter as helionScatter, line as helionLine, ScatterOptions, LineOptions } from '@helion/vanilla';
+import React, { forwardRef, useEffect, useRef } from 'react';
+import {
+  createChart,
+  scatter as helionScatter,
+  line as helionLine,
+  ChartHandle,
+  ChartProps,
+  ScatterOptions,
+  LineOptions,
+} from '@helion/vanilla';
 
 export interface ScatterPlotProps extends Omit<ScatterOptions, 'canvas'> {
   className?: string;
@@ -106,3 +114,56 @@ export const LinePlot: React.FC<LinePlotProps> = ({
     />
   );
 };
+
+export interface HelionChartProps extends ChartProps {
+  className?: string;
+  style?: React.CSSProperties;
+}
+
+function setRef<T>(ref: React.ForwardedRef<T>, value: T): void {
+  if (typeof ref === 'function') ref(value);
+  else if (ref) ref.current = value;
+}
+
+/**
+ * A whole figure, kept in sync with its props through a `ChartHandle`
+ *
+ * Re-renders only upload series whose `x`/`y` arrays changed identity. Pass
+ * a ref to reach the handle, e.g. to stream with `updateSeries` between
+ * renders.
+ */
+export const HelionChart = forwardRef<ChartHandle | null, HelionChartProps>(
+  ({ className, style, ...props }, ref) => {
+    const canvasRef = useRef<HTMLCanvasElement>(null);
+    const handleRef = useRef<ChartHandle | null>(null);
+    const propsRef = useRef(props);
+    propsRef.current = props;
+
+    useEffect(() => {
+      if (!canvasRef.current) return;
+      let cancelled = false;
+      createChart(canvasRef.current, propsRef.current).then((handle) => {
+        if (cancelled) {
+          handle.destroy();
+        } else {
+          handleRef.current = handle;
+          setRef(ref, handle);
+          // Catch up with props that changed while the chart was created
+          handle.update(propsRef.current);
+        }
+      });
+      return () => {
+        cancelled = true;
+        handleRef.current?.destroy();
+        handleRef.current = null;
+        setRef(ref, null);
+      };
+    }, []);
+
+    useEffect(() => {
+      handleRef.current?.update(props);
+    });
+
+    return <canvas ref={canvasRef} width={props.width} height={props.height} className={className} style={style} />;
+  },
+);
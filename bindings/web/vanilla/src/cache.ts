@@ -0,0 +1,214 @@
+/**
+ * IndexedDB cache for large datasets
+ *
+ * Stores parsed columns (typed arrays, which IndexedDB keeps as binary)
+ * keyed by a content hash, so reloading a dashboard reads them back from
+ * disk instead of downloading and parsing hundreds of MB again. Optional:
+ * nothing else in Helion depends on it, and every operation degrades to a
+ * cache miss where IndexedDB is unavailable (private browsing, workers
+ * without storage access).
+ */
+
+export type Column = Float32Array | Float64Array | Int32Array | Uint32Array | Uint8Array;
+
+/** A dataset as stored: named columns of equal length */
+export type Dataset = Record<string, Column>;
+
+export interface DatasetCacheOptions {
+  /** Database name; separate apps on one origin should use their own */
+  name?: string;
+  /** Total size kept before the least recently used entries are evicted */
+  maxBytes?: number;
+}
+
+interface Entry {
+  key: string;
+  columns: Dataset;
+  bytes: number;
+  /** Last read or write, for LRU eviction */
+  usedAt: number;
+}
+
+const STORE = 'datasets';
+const DEFAULT_MAX_BYTES = 1024 * 1024 * 1024;
+
+/** SHA-256 of `data` as lowercase hex, the key format the cache uses */
+export async function hashBytes(data: ArrayBuffer | ArrayBufferView): Promise<string> {
+  const view = data instanceof ArrayBuffer
+    ? new Uint8Array(data)
+    : new Uint8Array(data.buffer, data.byteOffset, data.byteLength);
+  const digest = await crypto.subtle.digest('SHA-256', view);
+  return Array.from(new Uint8Array(digest), (b) => b.toString(16).padStart(2, '0')).join('');
+}
+
+function datasetBytes(columns: Dataset): number {
+  return Object.values(columns).reduce((sum, column) => sum + column.byteLength, 0);
+}
+
+/** Resolve an IDBRequest as a promise */
+function done<T>(request: IDBRequest<T>): Promise<T> {
+  return new Promise((resolve, reject) => {
+    request.onsuccess = () => resolve(request.result);
+    request.onerror = () => reject(request.error);
+  });
+}
+
+/** Resolve once a transaction commits */
+function committed(tx: IDBTransaction): Promise<void> {
+  return new Promise((resolve, reject) => {
+    tx.oncomplete = () => resolve();
+    tx.onerror = () => reject(tx.error);
+    tx.onabort = () => reject(tx.error ?? new Error('IndexedDB transaction aborted'));
+  });
+}
+
+export class DatasetCache {
+  private db: IDBDatabase | null;
+  private maxBytes: number;
+
+  private constructor(db: IDBDatabase | null, maxBytes: number) {
+    this.db = db;
+    this.maxBytes = maxBytes;
+  }
+
+  /** Open (or create) the cache; resolves to a no-op cache if IndexedDB is unavailable */
+  static async open(options: DatasetCacheOptions = {}): Promise<DatasetCache> {
+    const maxBytes = options.maxBytes ?? DEFAULT_MAX_BYTES;
+    if (typeof indexedDB === 'undefined') {
+      return new DatasetCache(null, maxBytes);
+    }
+    try {
+      const request = indexedDB.open(options.name ?? 'helion-cache', 1);
+      request.onupgradeneeded = () => {
+        const store = request.result.createObjectStore(STORE, { keyPath: 'key' });
+        store.createIndex('usedAt', 'usedAt');
+      };
+      return new DatasetCache(await done(request), maxBytes);
+    } catch (e) {
+      console.warn('Helion dataset cache disabled:', e);
+      return new DatasetCache(null, maxBytes);
+    }
+  }
+
+  /** Whether entries are actually persisted */
+  get enabled(): boolean {
+    return this.db !== null;
+  }
+
+  /** Columns stored under `key`, or undefined on a miss */
+  async get(key: string): Promise<Dataset | undefined> {
+    if (!this.db) return undefined;
+    try {
+      const tx = this.db.transaction(STORE, 'readwrite');
+      const store = tx.objectStore(STORE);
+      const entry = (await done(store.get(key))) as Entry | undefined;
+      if (entry) {
+        entry.usedAt = Date.now();
+        store.put(entry);
+      }
+      await committed(tx);
+      return entry?.columns;
+    } catch (e) {
+      console.warn('Helion dataset cache read failed:', e);
+      return undefined;
+    }
+  }
+
+  /** Store `columns` under `key`, evicting old entries to stay within `maxBytes` */
+  async put(key: string, columns: Dataset): Promise<void> {
+    if (!this.db) return;
+    const bytes = datasetBytes(columns);
+    if (bytes > this.maxBytes) return;
+    try {
+      const tx = this.db.transaction(STORE, 'readwrite');
+      tx.objectStore(STORE).put({ key, columns, bytes, usedAt: Date.now() } satisfies Entry);
+      await committed(tx);
+      await this.evict();
+    } catch (e) {
+      // Usually QuotaExceededError; the data is still usable, just not cached
+      console.warn('Helion dataset cache write failed:', e);
+    }
+  }
+
+  async delete(key: string): Promise<void> {
+    if (!this.db) return;
+    const tx = this.db.transaction(STORE, 'readwrite');
+    tx.objectStore(STORE).delete(key);
+    await committed(tx);
+  }
+
+  async clear(): Promise<void> {
+    if (!this.db) return;
+    const tx = this.db.transaction(STORE, 'readwrite');
+    tx.objectStore(STORE).clear();
+    await committed(tx);
+  }
+
+  /** Drop least recently used entries until the total fits `maxBytes` */
+  private async evict(): Promise<void> {
+    if (!this.db) return;
+    const tx = this.db.transaction(STORE, 'readwrite');
+    const index = tx.objectStore(STORE).index('usedAt');
+    // Newest first: keep entries while they fit, delete the rest
+    let kept = 0;
+    const cursors = index.openCursor(null, 'prev');
+    cursors.onsuccess = () => {
+      const cursor = cursors.result;
+      if (!cursor) return;
+      const entry = cursor.value as Entry;
+      kept += entry.bytes;
+      if (kept > this.maxBytes) {
+        cursor.delete();
+      }
+      cursor.continue();
+    };
+    await committed(tx);
+  }
+
+  close(): void {
+    this.db?.close();
+    this.db = null;
+  }
+}
+
+export interface CachedFetchOptions {
+  cache: DatasetCache;
+  /**
+   * Content hash published with the data (e.g. in a manifest). With it, a
+   * cached copy is used without touching the network; without it the
+   * download is hashed, which still skips parsing on a hit.
+   */
+  hash?: string;
+  init?: RequestInit;
+}
+
+/**
+ * Fetch and parse a dataset through the cache
+ *
+ * @param parse Turns the downloaded bytes into columns; skipped on a hit
+ */
+export async function fetchDataset(
+  url: string,
+  parse: (bytes: ArrayBuffer) => Dataset | Promise<Dataset>,
+  { cache, hash, init }: CachedFetchOptions,
+): Promise<Dataset> {
+  if (hash !== undefined) {
+    const cached = await cache.get(hash);
+    if (cached) return cached;
+  }
+
+  const response = await fetch(url, init);
+  if (!response.ok) {
+    throw new Error(`Failed to fetch ${url}: ${response.status} ${response.statusText}`);
+  }
+  const bytes = await response.arrayBuffer();
+  const key = hash ?? await hashBytes(bytes);
+  if (hash === undefined) {
+    const cached = await cache.get(key);
+    if (cached) return cached;
+  }
+
+  const columns = await parse(bytes);
+  await cache.put(key, columns);
+  return columns;
+}
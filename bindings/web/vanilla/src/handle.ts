@@ -0,0 +1,278 @@
+/**
+ * Imperative chart handles for framework wrappers
+ *
+ * React, Vue and similar libraries re-render by handing a component new
+ * props. A `ChartHandle` takes those props as they are: `update(props)`
+ * diffs them against the previous ones and only touches what changed, so a
+ * wrapper creates the chart on mount, updates it on every render and
+ * destroys it on unmount.
+ *
+ * Series are matched by `id`, not position, and arrays are compared by
+ * identity: replace an array to signal new data (as immutable state
+ * updates do), or call `updateSeries` for streaming data without a
+ * re-render.
+ */
+
+import { parseFigureSpec, type AxisScaleSpec, type FigureSpec, type SeriesSpec, type SeriesStyleSpec } from './spec';
+
+/** A series with a stable id for diffing */
+export interface SeriesProps extends SeriesSpec {
+  id: string;
+}
+
+/** Everything a chart shows; the shape of a `FigureSpec` with ids on series */
+export interface ChartProps extends Omit<FigureSpec, 'version' | 'series'> {
+  series?: SeriesProps[];
+}
+
+/** New data for one series; omitted fields keep their current value */
+export interface SeriesData {
+  x?: ArrayLike<number>;
+  y?: ArrayLike<number>;
+  label?: string;
+  style?: SeriesStyleSpec;
+}
+
+/** One change found by `diffProps` */
+export type ChartChange =
+  | { type: 'figure'; keys: (keyof ChartProps)[] }
+  | { type: 'add'; id: string }
+  | { type: 'remove'; id: string }
+  | { type: 'data'; id: string }
+  | { type: 'style'; id: string }
+  | { type: 'order' };
+
+const FIGURE_PROPS: (keyof ChartProps)[] = ['width', 'height', 'title', 'xlabel', 'ylabel', 'xlim', 'ylim', 'xscale', 'yscale', 'axes'];
+
+function sameRange(a?: [number, number], b?: [number, number]): boolean {
+  return a === b || (a !== undefined && b !== undefined && a[0] === b[0] && a[1] === b[1]);
+}
+
+function sameScale(a?: AxisScaleSpec, b?: AxisScaleSpec): boolean {
+  if (typeof a === 'object' && typeof b === 'object') return JSON.stringify(a) === JSON.stringify(b);
+  return (a ?? 'linear') === (b ?? 'linear');
+}
+
+function sameStyle(a?: SeriesStyleSpec, b?: SeriesStyleSpec): boolean {
+  return a === b || JSON.stringify(a ?? {}) === JSON.stringify(b ?? {});
+}
+
+function withoutId({ kind, x, y, label, style }: SeriesProps): SeriesSpec {
+  return { kind, x, y, label, style };
+}
+
+function checkIds(series: SeriesProps[]): void {
+  const ids = new Set<string>();
+  for (const { id } of series) {
+    if (typeof id !== 'string') throw new Error('Every series needs a string id');
+    if (ids.has(id)) throw new Error(`Duplicate series id '${id}'`);
+    ids.add(id);
+  }
+}
+
+/**
+ * Changes between two sets of props
+ *
+ * Series data counts as changed when `x` or `y` is a different array
+ * object; styles and labels are compared by value, so inline style objects
+ * re-created on every render cost nothing.
+ */
+export function diffProps(prev: ChartProps, next: ChartProps): ChartChange[] {
+  const changes: ChartChange[] = [];
+  const keys = FIGURE_PROPS.filter((key) => {
+    if (key === 'xlim' || key === 'ylim') return !sameRange(prev[key], next[key]);
+    if (key === 'xscale' || key === 'yscale') return !sameScale(prev[key], next[key]);
+    return prev[key] !== next[key];
+  });
+  if (keys.length > 0) changes.push({ type: 'figure', keys });
+
+  const before = new Map((prev.series ?? []).map((s) => [s.id, s]));
+  const after = new Map((next.series ?? []).map((s) => [s.id, s]));
+  for (const id of before.keys()) {
+    if (!after.has(id)) changes.push({ type: 'remove', id });
+  }
+  for (const [id, series] of after) {
+    const old = before.get(id);
+    if (!old) {
+      changes.push({ type: 'add', id });
+      continue;
+    }
+    if (old.x !== series.x || old.y !== series.y || old.kind !== series.kind) changes.push({ type: 'data', id });
+    if (old.label !== series.label || !sameStyle(old.style, series.style)) changes.push({ type: 'style', id });
+  }
+
+  const kept = (series: SeriesProps[] | undefined, other: Map<string, SeriesProps>) =>
+    (series ?? []).filter((s) => other.has(s.id)).map((s) => s.id);
+  const order = kept(prev.series, after);
+  if (kept(next.series, before).some((id, i) => id !== order[i])) changes.push({ type: 'order' });
+  return changes;
+}
+
+/**
+ * Vertex storage of one series
+ *
+ * Grows by doubling and is overwritten in place while new data fits, so
+ * the GPU buffer uploaded from it can be rewritten with
+ * `queue.writeBuffer` instead of being recreated.
+ */
+export class SeriesBuffer {
+  x = new Float32Array(0);
+  y = new Float32Array(0);
+  length = 0;
+  /** Times the storage had to grow; stays put for same-size updates */
+  reallocations = 0;
+
+  write(x: ArrayLike<number>, y: ArrayLike<number>): void {
+    const length = Math.max(x.length, y.length);
+    if (length > this.x.length) {
+      const capacity = Math.max(length, this.x.length * 2);
+      this.x = new Float32Array(capacity);
+      this.y = new Float32Array(capacity);
+      this.reallocations += 1;
+    }
+    this.x.set(x);
+    this.y.set(y);
+    this.length = length;
+  }
+
+  /** Views of the live values, valid until the next `write` */
+  view(): { x: Float32Array; y: Float32Array } {
+    return { x: this.x.subarray(0, this.length), y: this.y.subarray(0, this.length) };
+  }
+}
+
+/** A chart created by `createChart` */
+export class ChartHandle {
+  readonly canvas: HTMLCanvasElement;
+  private props: ChartProps;
+  private buffers = new Map<string, SeriesBuffer>();
+  private destroyed = false;
+
+  private constructor(canvas: HTMLCanvasElement, props: ChartProps) {
+    this.canvas = canvas;
+    this.props = props;
+  }
+
+  /** @internal Use `createChart` */
+  static create(canvas: HTMLCanvasElement, props: ChartProps): ChartHandle {
+    const handle = new ChartHandle(canvas, { ...props, series: [] });
+    handle.update(props);
+    canvas.width = props.width;
+    canvas.height = props.height;
+    return handle;
+  }
+
+  /**
+   * Apply new props
+   *
+   * @returns The changes applied; empty if the props are equivalent
+   * @throws Error if the props are invalid; the chart then keeps its
+   *   previous props
+   */
+  update(props: ChartProps): ChartChange[] {
+    this.checkAlive();
+    const series = props.series ?? [];
+    checkIds(series);
+    this.validate({ ...props, series });
+
+    const changes = diffProps(this.props, props);
+    for (const change of changes) {
+      if (change.type === 'remove') this.buffers.delete(change.id);
+      if (change.type === 'add' || change.type === 'data') {
+        const s = series.find((s) => s.id === change.id)!;
+        this.buffer(change.id).write(s.x, s.y);
+      }
+      if (change.type === 'figure' && (change.keys.includes('width') || change.keys.includes('height'))) {
+        this.canvas.width = props.width;
+        this.canvas.height = props.height;
+      }
+    }
+    this.props = { ...props, series: series.map((s) => ({ ...s })) };
+    // Will be drawn with the WASM bindings
+    return changes;
+  }
+
+  /**
+   * Replace the data (and optionally label and style) of one series
+   *
+   * Its vertex storage is rewritten in place when the new data fits, so
+   * streaming updates of steady size don't allocate.
+   *
+   * @throws Error if there is no series `id` or the new data is invalid
+   */
+  updateSeries(id: string, data: SeriesData): void {
+    this.checkAlive();
+    const series = this.props.series ?? [];
+    const index = series.findIndex((s) => s.id === id);
+    if (index < 0) throw new Error(`No series with id '${id}'`);
+
+    const updated: SeriesProps = { ...series[index], ...data, id };
+    const next = series.slice();
+    next[index] = updated;
+    this.validate({ ...this.props, series: next });
+
+    if (data.x !== undefined || data.y !== undefined) this.buffer(id).write(updated.x, updated.y);
+    this.props = { ...this.props, series: next };
+  }
+
+  /** Current values of a series, or `undefined` for an unknown id */
+  seriesData(id: string): { x: Float32Array; y: Float32Array } | undefined {
+    return this.buffers.get(id)?.view();
+  }
+
+  /** Vertex storage of a series, e.g. to check it is being reused */
+  seriesBuffer(id: string): SeriesBuffer | undefined {
+    return this.buffers.get(id);
+  }
+
+  /** The chart as a figure spec, e.g. to store or send to a server */
+  toSpec(): FigureSpec {
+    const { series = [], ...figure } = this.props;
+    return {
+      ...figure,
+      series: series.map(withoutId),
+    };
+  }
+
+  /** Free the chart's resources; the handle can't be used afterwards */
+  destroy(): void {
+    this.buffers.clear();
+    this.destroyed = true;
+  }
+
+  private buffer(id: string): SeriesBuffer {
+    let buffer = this.buffers.get(id);
+    if (!buffer) {
+      buffer = new SeriesBuffer();
+      this.buffers.set(id, buffer);
+    }
+    return buffer;
+  }
+
+  private validate(props: ChartProps): void {
+    const { series = [], ...figure } = props;
+    parseFigureSpec({ ...figure, series: series.map(withoutId) });
+  }
+
+  private checkAlive(): void {
+    if (this.destroyed) throw new Error('Chart was destroyed');
+  }
+}
+
+/**
+ * Create a chart on `canvas` that is updated through its handle
+ *
+ * @example
+ * const chart = await createChart(canvas, {
+ *   width: 800,
+ *   height: 400,
+ *   series: [{ id: 'cpu', kind: 'line', x: t, y: cpu }],
+ * });
+ * chart.updateSeries('cpu', { y: nextCpu });
+ * chart.destroy();
+ *
+ * @throws Error if the props are invalid
+ */
+export async function createChart(canvas: HTMLCanvasElement, props: ChartProps): Promise<ChartHandle> {
+  return ChartHandle.create(canvas, props);
+}
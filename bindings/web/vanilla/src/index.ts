@@ -72,3 +72,19 @@ export async function detectGPUBackend(): Promise<'webgpu' | 'webgl' | 'none'> {
 }
 
 export { ChartOptions, ScatterOptions, LineOptions };
+
+// Figures serialized by a server
+export { HelionChart, parseFigureSpec, SPEC_VERSION } from './spec';
+export type { AxisScaleSpec, ChartKind, ColorSpec, FigureSpec, LineDash, Marker, SeriesSpec, SeriesStyleSpec } from './spec';
+
+// Imperative handles for React, Vue and other component wrappers
+export { ChartHandle, createChart, diffProps, SeriesBuffer } from './handle';
+export type { ChartChange, ChartProps, SeriesData, SeriesProps } from './handle';
+
+// One batched submission per frame for pages with many charts
+export { FrameScheduler, frameScheduler, ScheduledChart } from './scheduler';
+export type { DrawCallback, FrameDevice, FrameEncoder, FrameSchedulerOptions, FrameStats } from './scheduler';
+
+// Optional IndexedDB cache for large datasets
+export { DatasetCache, fetchDataset, hashBytes } from './cache';
+export type { Column, Dataset, DatasetCacheOptions, CachedFetchOptions } from './cache';
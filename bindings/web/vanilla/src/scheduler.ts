@@ -0,0 +1,218 @@
+/**
+ * Frame scheduling for pages with many charts
+ *
+ * Each chart redrawing itself on its own schedule means one queue
+ * submission per chart per change, several per frame on a busy dashboard.
+ * A `FrameScheduler` instead collects the charts that changed, encodes them
+ * all into one command encoder on the next animation frame and submits
+ * once. Charts scrolled out of view (tracked with an IntersectionObserver)
+ * aren't drawn at all; they stay pending and are drawn when they scroll
+ * back in.
+ *
+ * Use one scheduler per GPU device: `frameScheduler(device)` returns the
+ * device's shared one.
+ */
+
+/** The parts of a `GPUCommandEncoder` the scheduler uses */
+export interface FrameEncoder {
+  finish(): unknown;
+}
+
+/** The parts of a `GPUDevice` the scheduler uses */
+export interface FrameDevice {
+  createCommandEncoder(): FrameEncoder;
+  queue: { submit(commandBuffers: unknown[]): void };
+}
+
+/** Encodes a chart's render passes into the frame's shared encoder */
+export type DrawCallback = (encoder: FrameEncoder) => void;
+
+export interface FrameSchedulerOptions {
+  /** Margin around the viewport within which charts count as visible, e.g. '200px' */
+  rootMargin?: string;
+}
+
+/** Counters for checking that batching works */
+export interface FrameStats {
+  /** Frames in which something was drawn */
+  frames: number;
+  /** Queue submissions; one per frame */
+  submissions: number;
+  /** Chart draws across all frames */
+  draws: number;
+  /** Redraws postponed because the chart was offscreen */
+  skipped: number;
+}
+
+/** A chart registered with `FrameScheduler.add` */
+export class ScheduledChart {
+  readonly element: Element;
+  /** @internal */
+  visible = true;
+  /** @internal */
+  dirty = true;
+  private readonly scheduler: FrameScheduler;
+  /** @internal */
+  readonly draw: DrawCallback;
+
+  /** @internal Use `FrameScheduler.add` */
+  constructor(scheduler: FrameScheduler, element: Element, draw: DrawCallback) {
+    this.scheduler = scheduler;
+    this.element = element;
+    this.draw = draw;
+  }
+
+  /** Whether the chart is drawn on the next frame */
+  get pending(): boolean {
+    return this.dirty;
+  }
+
+  /** Redraw the chart on the next frame it is visible */
+  invalidate(): void {
+    this.dirty = true;
+    if (this.visible) this.scheduler.request();
+  }
+
+  /** Stop scheduling the chart */
+  remove(): void {
+    this.scheduler.remove(this);
+  }
+}
+
+/** Batches the redraws of charts sharing one device; see the module docs */
+export class FrameScheduler {
+  readonly stats: FrameStats = { frames: 0, submissions: 0, draws: 0, skipped: 0 };
+  private readonly device: FrameDevice;
+  private readonly charts = new Map<Element, ScheduledChart>();
+  private readonly observer?: IntersectionObserver;
+  private frame?: number;
+
+  constructor(device: FrameDevice, options: FrameSchedulerOptions = {}) {
+    this.device = device;
+    // Without IntersectionObserver (old browsers, tests) every chart counts as visible
+    if (typeof IntersectionObserver !== 'undefined') {
+      this.observer = new IntersectionObserver((entries) => this.onIntersect(entries), {
+        rootMargin: options.rootMargin ?? '0px',
+      });
+    }
+  }
+
+  /**
+   * Schedule the chart drawn on `element` (usually its canvas)
+   *
+   * The chart is drawn on the next frame and again after each
+   * `invalidate()`.
+   *
+   * @throws Error if `element` is already scheduled
+   */
+  add(element: Element, draw: DrawCallback): ScheduledChart {
+    if (this.charts.has(element)) throw new Error('Element already has a scheduled chart');
+    const chart = new ScheduledChart(this, element, draw);
+    this.charts.set(element, chart);
+    this.observer?.observe(element);
+    this.request();
+    return chart;
+  }
+
+  /** Stop scheduling `chart`; a no-op for charts already removed */
+  remove(chart: ScheduledChart): void {
+    if (this.charts.get(chart.element) !== chart) return;
+    this.charts.delete(chart.element);
+    this.observer?.unobserve(chart.element);
+  }
+
+  /** Charts currently scheduled */
+  get size(): number {
+    return this.charts.size;
+  }
+
+  /** @internal Ask for an animation frame if none is pending */
+  request(): void {
+    if (this.frame !== undefined || typeof requestAnimationFrame === 'undefined') return;
+    this.frame = requestAnimationFrame(() => {
+      this.frame = undefined;
+      this.flush();
+    });
+  }
+
+  /**
+   * Draw every visible pending chart now, with a single submission
+   *
+   * Runs on each animation frame; call it directly to draw synchronously,
+   * e.g. before reading a canvas back. A chart whose draw callback throws
+   * is logged and skipped so it doesn't take the others down with it.
+   *
+   * @returns Number of charts drawn
+   */
+  flush(): number {
+    let encoder: FrameEncoder | undefined;
+    let drawn = 0;
+    for (const chart of this.charts.values()) {
+      if (!chart.dirty) continue;
+      if (!chart.visible) {
+        this.stats.skipped += 1;
+        continue;
+      }
+      encoder ??= this.device.createCommandEncoder();
+      chart.dirty = false;
+      try {
+        chart.draw(encoder);
+        drawn += 1;
+      } catch (e) {
+        console.error('Helion chart failed to draw:', e);
+      }
+    }
+    if (encoder !== undefined) {
+      this.device.queue.submit([encoder.finish()]);
+      this.stats.frames += 1;
+      this.stats.submissions += 1;
+      this.stats.draws += drawn;
+    }
+    return drawn;
+  }
+
+  /** Stop all scheduling; pending redraws are dropped */
+  destroy(): void {
+    if (this.frame !== undefined) cancelAnimationFrame(this.frame);
+    this.frame = undefined;
+    this.observer?.disconnect();
+    this.charts.clear();
+    if (schedulers.get(this.device) === this) schedulers.delete(this.device);
+  }
+
+  /**
+   * Mark the chart on `element` as on- or offscreen
+   *
+   * The IntersectionObserver does this by itself; call it where there is
+   * none, or for charts hidden by other means (a collapsed panel).
+   */
+  setVisible(element: Element, visible: boolean): void {
+    const chart = this.charts.get(element);
+    if (!chart || chart.visible === visible) return;
+    chart.visible = visible;
+    if (visible && chart.dirty) this.request();
+  }
+
+  private onIntersect(entries: IntersectionObserverEntry[]): void {
+    for (const entry of entries) this.setVisible(entry.target, entry.isIntersecting);
+  }
+}
+
+const schedulers = new WeakMap<FrameDevice, FrameScheduler>();
+
+/**
+ * The scheduler shared by all charts on `device`, created on first use
+ *
+ * @example
+ * const scheduler = frameScheduler(device);
+ * const chart = scheduler.add(canvas, (encoder) => renderer.encode(encoder));
+ * chart.invalidate(); // after changing the chart's data
+ */
+export function frameScheduler(device: FrameDevice, options?: FrameSchedulerOptions): FrameScheduler {
+  let scheduler = schedulers.get(device);
+  if (!scheduler) {
+    scheduler = new FrameScheduler(device, options);
+    schedulers.set(device, scheduler);
+  }
+  return scheduler;
+}
@@ -0,0 +1,168 @@
+/**
+ * Figure specs: a whole figure as JSON
+ *
+ * Mirrors `helion_core::spec::FigureSpec`. Servers build a figure (e.g.
+ * `fig.to_spec()` in Python) and send the JSON; the page passes it to
+ * `HelionChart.fromSpec` and only renders it.
+ */
+
+export const SPEC_VERSION = 1;
+
+export type ChartKind = 'scatter' | 'line' | 'bars';
+
+const CHART_KINDS = new Set<string>(['scatter', 'line', 'bars']);
+
+export type Marker = 'circle' | 'square' | 'diamond' | 'triangle' | 'cross';
+
+export type LineDash = 'solid' | { dashed: { on: number; off: number } };
+
+/** Hex string, CSS color name or [r, g, b(, a)] with channels in 0-1 */
+export type ColorSpec = string | [number, number, number] | [number, number, number, number];
+
+/** Keys left out keep the figure's defaults, including the color cycle */
+export interface SeriesStyleSpec {
+  marker?: Marker;
+  size?: number;
+  color?: ColorSpec;
+  line_width?: number;
+  dash?: LineDash;
+  opacity?: number;
+  z_order?: number;
+}
+
+/** For `bars`, `x` holds the bar edges: one more than the heights in `y` */
+export interface SeriesSpec {
+  kind?: ChartKind;
+  x: ArrayLike<number>;
+  y: ArrayLike<number>;
+  label?: string;
+  style?: SeriesStyleSpec;
+}
+
+/**
+ * Axis layout; a time axis holds seconds after `origin`, a Unix timestamp,
+ * and a symlog axis is linear within `linthresh` of zero
+ */
+export type AxisScaleSpec =
+  | 'linear'
+  | 'log10'
+  | 'log2'
+  | { time: { origin: number } }
+  | { symlog: { linthresh: number } };
+
+export interface FigureSpec {
+  version?: number;
+  width: number;
+  height: number;
+  title?: string;
+  xlabel?: string;
+  ylabel?: string;
+  xlim?: [number, number];
+  ylim?: [number, number];
+  xscale?: AxisScaleSpec;
+  yscale?: AxisScaleSpec;
+  /** Gridlines, ticks and frame; default true */
+  axes?: boolean;
+  series?: SeriesSpec[];
+}
+
+const FIGURE_KEYS = new Set(['version', 'width', 'height', 'title', 'xlabel', 'ylabel', 'xlim', 'ylim', 'xscale', 'yscale', 'axes', 'series']);
+const SERIES_KEYS = new Set(['kind', 'x', 'y', 'label', 'style']);
+
+function fail(message: string): never {
+  throw new Error(`Invalid figure spec: ${message}`);
+}
+
+function checkKeys(value: object, allowed: Set<string>, where: string): void {
+  for (const key of Object.keys(value)) {
+    if (!allowed.has(key)) fail(`unknown field '${key}' in ${where}`);
+  }
+}
+
+function isScale(value: unknown): value is AxisScaleSpec {
+  if (value === 'linear' || value === 'log10' || value === 'log2') return true;
+  const { time, symlog } = (value ?? {}) as { time?: { origin?: unknown }; symlog?: { linthresh?: unknown } };
+  if (symlog !== undefined) return typeof symlog.linthresh === 'number' && symlog.linthresh > 0;
+  return typeof time?.origin === 'number';
+}
+
+function isRange(value: unknown): value is [number, number] {
+  return Array.isArray(value) && value.length === 2 && value.every((v) => typeof v === 'number');
+}
+
+/**
+ * Check the structure of a spec, parsing it first if given as text
+ *
+ * Catches what the renderer would reject (unknown keys, newer versions,
+ * series whose x and y differ in length) with the same wording, before any
+ * GPU work starts. Style values are checked by the renderer.
+ */
+export function parseFigureSpec(spec: FigureSpec | string): FigureSpec {
+  const value: unknown = typeof spec === 'string' ? JSON.parse(spec) : spec;
+  if (typeof value !== 'object' || value === null || Array.isArray(value)) fail('expected a JSON object');
+  const figure = value as FigureSpec;
+  checkKeys(figure, FIGURE_KEYS, 'figure');
+
+  const version = figure.version ?? SPEC_VERSION;
+  if (version > SPEC_VERSION) {
+    fail(`version ${version} is newer than the supported version ${SPEC_VERSION}`);
+  }
+  if (!(figure.width > 0) || !(figure.height > 0)) fail('width and height must be positive numbers');
+  for (const key of ['xlim', 'ylim'] as const) {
+    if (figure[key] !== undefined && !isRange(figure[key])) fail(`${key} must be [min, max]`);
+  }
+  for (const key of ['xscale', 'yscale'] as const) {
+    if (figure[key] !== undefined && !isScale(figure[key])) fail(`${key} must be 'linear', 'log10', 'log2', {time: {origin}} or {symlog: {linthresh}}`);
+  }
+
+  (figure.series ?? []).forEach((series, i) => {
+    checkKeys(series, SERIES_KEYS, `series ${i}`);
+    if (series.kind !== undefined && !CHART_KINDS.has(series.kind)) {
+      fail(`series ${i} has unknown kind '${series.kind}'`);
+    }
+    if (series.x?.length === undefined || series.y?.length === undefined) fail(`series ${i} needs x and y arrays`);
+    if (series.kind === 'bars') {
+      const edges = series.y.length > 0 ? series.y.length + 1 : Math.min(series.x.length, 1);
+      if (series.x.length !== edges) {
+        fail(`series ${i} has ${series.x.length} bar edges but needs ${edges} for ${series.y.length} heights`);
+      }
+    } else if (series.x.length !== series.y.length) {
+      fail(`series ${i} has ${series.x.length} x values but ${series.y.length} y values`);
+    }
+  });
+  return figure;
+}
+
+/** A chart drawn from a figure spec */
+export class HelionChart {
+  readonly canvas: HTMLCanvasElement;
+  readonly spec: FigureSpec;
+
+  private constructor(canvas: HTMLCanvasElement, spec: FigureSpec) {
+    this.canvas = canvas;
+    this.spec = spec;
+  }
+
+  /**
+   * Render a serialized figure on `canvas`
+   *
+   * @param spec Spec object or its JSON text
+   * @throws Error if the spec is invalid
+   */
+  static async fromSpec(spec: FigureSpec | string, canvas: HTMLCanvasElement): Promise<HelionChart> {
+    const figure = parseFigureSpec(spec);
+    canvas.width = figure.width;
+    canvas.height = figure.height;
+    // Will be drawn with the WASM bindings
+    return new HelionChart(canvas, figure);
+  }
+
+  /** The spec as JSON, e.g. to store or send back to a server */
+  toJSON(): FigureSpec {
+    return this.spec;
+  }
+
+  destroy(): void {
+    // Cleanup will be implemented
+  }
+}
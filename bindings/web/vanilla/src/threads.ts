@@ -0,0 +1,44 @@
+/**
+ * Worker threads for Helion's data preparation
+ *
+ * Needs a WASM build with the `threads` feature and a cross-origin isolated
+ * page (see the `parallel` module in helion_core). Call `initThreads` once,
+ * from a worker: the browser's main thread isn't allowed to block on the
+ * pool.
+ */
+
+import init, { initThreadPool, startWorker } from '../pkg/helion_core.js';
+
+interface WorkerStart {
+  module: WebAssembly.Module;
+  memory: WebAssembly.Memory;
+  ptr: number;
+}
+
+/** Whether this page can share WASM memory with workers */
+export function threadsSupported(): boolean {
+  return typeof SharedArrayBuffer !== 'undefined' && self.crossOriginIsolated === true;
+}
+
+/** Start `count` pool threads (defaults to the number of logical cores) */
+export function initThreads(count: number = navigator.hardwareConcurrency): void {
+  if (!threadsSupported()) {
+    throw new Error('Helion threads need SharedArrayBuffer (serve the page cross-origin isolated)');
+  }
+  initThreadPool(count, (module: WebAssembly.Module, memory: WebAssembly.Memory, ptr: number) => {
+    const worker = new Worker(new URL('./threads.js', import.meta.url), { type: 'module' });
+    worker.postMessage({ module, memory, ptr } satisfies WorkerStart);
+  });
+}
+
+// Inside a pool worker: join the shared memory and run the thread handed over
+// (`WorkerGlobalScope` isn't in the DOM typings this package builds against)
+const workerScope = (globalThis as { WorkerGlobalScope?: new () => unknown }).WorkerGlobalScope;
+if (workerScope !== undefined && self instanceof workerScope) {
+  self.addEventListener('message', async (event: MessageEvent<WorkerStart>) => {
+    const { module, memory, ptr } = event.data;
+    if (!(module instanceof WebAssembly.Module)) return;
+    await init({ module_or_path: module, memory });
+    startWorker(ptr);
+  }, { once: true });
+}
@@ -0,0 +1,151 @@
+//! `helion` command-line tool: plot two columns of a CSV or Parquet file in
+//! a window, or straight to a PNG
+//!
+//! ```text
+//! helion plot data.csv -x time -y value --out plot.png
+//! cat data.csv | helion plot - -x time -y value --kind line
+//! ```
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use helion_core::data::Color;
+use helion_core::ingest::csv::read_csv_columns;
+use helion_core::{
+    try_run_window, ChartData, ChartKind, HeadlessRenderer, LineRenderer, RenderOptions, ScatterRenderer, WindowSpec,
+};
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Instant;
+
+#[derive(Parser)]
+#[command(name = "helion", version, about = "GPU-accelerated plots of tabular data")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Plot column y against column x, in a window unless --out is given
+    Plot(PlotArgs),
+}
+
+#[derive(Args)]
+struct PlotArgs {
+    /// CSV, TSV (.tsv, .tab) or Parquet (.parquet, .pq) file; `-` reads CSV
+    /// from stdin
+    input: String,
+    /// Header name of the column holding x
+    #[arg(short, long)]
+    x: String,
+    /// Header name of the column holding y
+    #[arg(short, long)]
+    y: String,
+    /// Draw rows as points, or join them in file order
+    #[arg(long, value_enum, default_value_t = Kind::Scatter)]
+    kind: Kind,
+    /// Write a PNG here instead of opening a window
+    #[arg(short, long)]
+    out: Option<String>,
+    /// Image or window width in pixels
+    #[arg(long, default_value_t = 800)]
+    width: u32,
+    /// Image or window height in pixels
+    #[arg(long, default_value_t = 600)]
+    height: u32,
+    /// CSS color name, #RRGGBB or #RRGGBBAA
+    #[arg(long, value_parser = Color::parse)]
+    color: Option<Color>,
+    /// Point size in pixels (scatter only)
+    #[arg(long)]
+    size: Option<f32>,
+    /// Window title, the input's name by default
+    #[arg(long)]
+    title: Option<String>,
+    /// Read stdin as tab-separated rather than comma-separated
+    #[arg(long)]
+    tsv: bool,
+    /// Print load and render times, and the last frame's draw statistics,
+    /// to stderr
+    #[arg(long)]
+    stats: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Kind {
+    Scatter,
+    Line,
+}
+
+impl From<Kind> for ChartKind {
+    fn from(kind: Kind) -> Self {
+        match kind {
+            Kind::Scatter => ChartKind::Scatter,
+            Kind::Line => ChartKind::Line,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let Command::Plot(args) = Cli::parse().command;
+    match plot(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("helion: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn plot(args: &PlotArgs) -> Result<(), String> {
+    let started = Instant::now();
+    let data = load(args)?;
+    if data.vertices.is_empty() {
+        return Err(format!("{}: no rows with both '{}' and '{}' to plot", args.input, args.x, args.y));
+    }
+    if args.stats {
+        eprintln!("loaded {} points in {:.1?}", data.vertices.len(), started.elapsed());
+    }
+
+    let Some(out) = &args.out else {
+        let title = args.title.clone().unwrap_or_else(|| args.input.clone());
+        let report = try_run_window(WindowSpec::chart(data, args.kind.into(), &title)).map_err(|e| e.to_string())?;
+        if let Some(report) = report.filter(|_| args.stats) {
+            report.overlay_lines().iter().for_each(|line| eprintln!("{}", line));
+        }
+        return Ok(());
+    };
+    let started = Instant::now();
+    let mut renderer =
+        HeadlessRenderer::new(args.width, args.height, RenderOptions::default()).map_err(|e| e.to_string())?;
+    let png = match args.kind {
+        Kind::Scatter => renderer.render_png::<ScatterRenderer>(data),
+        Kind::Line => renderer.render_png::<LineRenderer>(data),
+    }
+    .map_err(|e| e.to_string())?;
+    std::fs::write(out, png).map_err(|e| format!("Failed to write {}: {}", out, e))?;
+    if args.stats {
+        eprintln!("rendered {}x{} in {:.1?}", args.width, args.height, started.elapsed());
+    }
+    Ok(())
+}
+
+/// Chart data from the input file or stdin, normalized to the viewport
+fn load(args: &PlotArgs) -> Result<ChartData, String> {
+    let (width, height) = (args.width as f32, args.height as f32);
+    // Lines are one pixel wide, like `ChartData::from_line`
+    let size = match args.kind {
+        Kind::Scatter => args.size,
+        Kind::Line => Some(1.0),
+    };
+    if args.input == "-" {
+        let delimiter = if args.tsv { b'\t' } else { b',' };
+        let columns = read_csv_columns(std::io::stdin().lock(), delimiter, &[&args.x, &args.y])
+            .map_err(|e| format!("stdin: {}", e))?;
+        return Ok(ChartData::from_scatter_f64(&columns[0], &columns[1], args.color, size, width, height));
+    }
+    let path = Path::new(&args.input);
+    match path.extension().and_then(|ext| ext.to_str()).map(str::to_ascii_lowercase).as_deref() {
+        Some("parquet" | "pq") => ChartData::from_parquet(path, &args.x, &args.y, args.color, size, width, height),
+        _ => ChartData::from_csv(path, &args.x, &args.y, args.color, size, width, height),
+    }
+}
@@ -0,0 +1,75 @@
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("helion_cli_{}_{}", std::process::id(), name))
+}
+
+fn helion() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_helion"))
+}
+
+/// Width and height from a PNG's IHDR chunk
+fn png_size(bytes: &[u8]) -> (u32, u32) {
+    assert_eq!(&bytes[..8], b"\x89PNG\r\n\x1a\n");
+    let read = |at: usize| u32::from_be_bytes(bytes[at..at + 4].try_into().unwrap());
+    (read(16), read(20))
+}
+
+#[test]
+fn test_plot_csv_to_png() {
+    let csv = temp_path("series.csv");
+    let out = temp_path("series.png");
+    std::fs::write(&csv, "time,value,label\n0,1.5,a\n1,,b\n2,3.0,c\n3,2.0,d\n").unwrap();
+    let status = helion()
+        .args(["plot", csv.to_str().unwrap(), "-x", "time", "-y", "value", "--width", "320", "--height", "200"])
+        .arg("--out")
+        .arg(&out)
+        .status()
+        .unwrap();
+    assert!(status.success());
+    assert_eq!(png_size(&std::fs::read(&out).unwrap()), (320, 200));
+    std::fs::remove_file(csv).unwrap();
+    std::fs::remove_file(out).unwrap();
+}
+
+#[test]
+fn test_plot_stdin_as_line() {
+    let out = temp_path("stdin.png");
+    let mut child = helion()
+        .args(["plot", "-", "-x", "t", "-y", "v", "--kind", "line", "--tsv", "--color", "tomato", "--stats"])
+        .arg("--out")
+        .arg(&out)
+        .stdin(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"t\tv\n0\t0\n1\t1\n2\t0\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("loaded 3 points in "));
+    assert_eq!(png_size(&std::fs::read(&out).unwrap()), (800, 600));
+    std::fs::remove_file(out).unwrap();
+}
+
+#[test]
+fn test_errors_exit_with_message() {
+    let csv = temp_path("errors.csv");
+    std::fs::write(&csv, "a,b\n1,\n2,NA\n").unwrap();
+    let run = |y: &str| {
+        let args = ["plot", csv.to_str().unwrap(), "-x", "a", "-y", y, "--out", "unused.png"];
+        helion().args(args).output().unwrap()
+    };
+
+    let output = run("c");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("helion: ") && stderr.contains("no column 'c'"));
+
+    let output = run("b");
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr).unwrap().contains("no rows with both 'a' and 'b' to plot"));
+    std::fs::remove_file(csv).unwrap();
+}
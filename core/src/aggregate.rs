@@ -0,0 +1,212 @@
+use crate::transition::ViewRange;
+
+/// Regular grid of aggregation cells over a data range
+///
+/// Cells are half-open: a point on the range's max edge falls outside the
+/// grid. Counts are stored row-major, starting at the (x min, y min) corner.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BinGrid {
+    pub x_range: (f32, f32),
+    pub y_range: (f32, f32),
+    /// Number of cells along x
+    pub nx: u32,
+    /// Number of cells along y
+    pub ny: u32,
+}
+
+impl BinGrid {
+    pub fn new(x_range: (f32, f32), y_range: (f32, f32), nx: u32, ny: u32) -> Self {
+        Self {
+            x_range,
+            y_range,
+            nx: nx.max(1),
+            ny: ny.max(1),
+        }
+    }
+
+    /// Grid covering `view` with cells of roughly `cell_px` screen pixels
+    ///
+    /// # Parameters
+    /// * `view` - Visible data range
+    /// * `width`, `height` - Viewport size in pixels
+    /// * `cell_px` - Desired cell size in pixels (1.0 = one cell per pixel)
+    pub fn for_view(view: &ViewRange, width: f32, height: f32, cell_px: f32) -> Self {
+        let cell_px = cell_px.max(1.0);
+        Self::new(
+            view.x,
+            view.y,
+            (width / cell_px).round() as u32,
+            (height / cell_px).round() as u32,
+        )
+    }
+
+    /// `nx` x `ny` cells spanning the finite values of `x` and `y`
+    ///
+    /// Cells are half-open, so the far edges are nudged out by a millionth
+    /// of the span to keep the largest values inside. An axis whose values
+    /// are all equal gets a unit-wide range centered on them, one without
+    /// finite values (0, 1).
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::aggregate::{aggregate_counts, BinGrid};
+    ///
+    /// let (x, y) = ([0.0, 1.0, 2.0], [5.0, 5.0, 5.0]);
+    /// let grid = BinGrid::covering(&x, &y, 2, 1);
+    /// assert_eq!(grid.y_range, (4.5, 5.5));
+    /// assert_eq!(aggregate_counts(&x, &y, &grid), vec![2, 1]);
+    /// ```
+    pub fn covering(x: &[f32], y: &[f32], nx: u32, ny: u32) -> Self {
+        Self::new(covering_range(x), covering_range(y), nx, ny)
+    }
+
+    /// Total number of cells
+    pub fn len(&self) -> usize {
+        self.nx as usize * self.ny as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Size of one cell in data units
+    pub fn cell_size(&self) -> (f32, f32) {
+        (
+            (self.x_range.1 - self.x_range.0) / self.nx as f32,
+            (self.y_range.1 - self.y_range.0) / self.ny as f32,
+        )
+    }
+
+    /// Row-major cell index containing (x, y), if inside the grid
+    pub fn cell_index(&self, x: f32, y: f32) -> Option<usize> {
+        // Same arithmetic as the compute shader so CPU and GPU agree on edges
+        let (cw, ch) = self.cell_size();
+        let cx = ((x - self.x_range.0) * (1.0 / cw)).floor();
+        let cy = ((y - self.y_range.0) * (1.0 / ch)).floor();
+        let inside = cx >= 0.0 && cy >= 0.0 && cx < self.nx as f32 && cy < self.ny as f32;
+        inside.then(|| cy as usize * self.nx as usize + cx as usize)
+    }
+}
+
+fn covering_range(values: &[f32]) -> (f32, f32) {
+    let (min, max) = values
+        .iter()
+        .filter(|v| v.is_finite())
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+    if min > max {
+        (0.0, 1.0)
+    } else if min == max {
+        (min - 0.5, min + 0.5)
+    } else {
+        (min, max + (max - min) * 1e-6)
+    }
+}
+
+/// Count points per grid cell on the CPU
+///
+/// Reference implementation of the `compute::GpuBinner` dispatch, and the
+/// fallback when compute shaders are unavailable.
+pub fn aggregate_counts(x: &[f32], y: &[f32], grid: &BinGrid) -> Vec<u32> {
+    let len = x.len().min(y.len());
+    crate::parallel::count_bins(len, grid.len(), |i| grid.cell_index(x[i], y[i]))
+}
+
+/// Cell counts as values for coloring, with empty cells as NaN so they stay
+/// transparent and don't weigh on a log or equalized normalization
+///
+/// # Example
+/// ```
+/// use helion_core::aggregate::counts_as_values;
+/// use helion_core::norm::Normalization;
+///
+/// let values = counts_as_values(&[0, 1, 10, 100]);
+/// let norm = Normalization::Log.fit(&values, None);
+/// assert!(norm.normalize(values[0]).is_nan());
+/// assert_eq!(norm.normalize(values[3]), 1.0);
+/// ```
+pub fn counts_as_values(counts: &[u32]) -> Vec<f32> {
+    counts
+        .iter()
+        .map(|&c| if c == 0 { f32::NAN } else { c as f32 })
+        .collect()
+}
+
+/// Decides when an aggregated view must be re-binned after a pan or zoom
+///
+/// Aggregation is tied to a data range and a resolution. Stretching the old
+/// grid after a zoom just magnifies its cells, so once the view moves by more
+/// than a fraction of a cell, or the viewport resolution changes, the grid is
+/// rebuilt for the new visible range and detail emerges.
+///
+/// # Example
+/// ```
+/// use helion_core::aggregate::Rebinner;
+/// use helion_core::transition::ViewRange;
+///
+/// let mut rebinner = Rebinner::new(4.0);
+/// let view = ViewRange::new((0.0, 100.0), (0.0, 100.0));
+/// assert!(rebinner.update(&view, 400.0, 400.0).is_some()); // first frame
+/// assert!(rebinner.update(&view, 400.0, 400.0).is_none()); // unchanged
+///
+/// let zoomed = ViewRange::new((40.0, 60.0), (40.0, 60.0));
+/// let grid = rebinner.update(&zoomed, 400.0, 400.0).unwrap();
+/// assert_eq!(grid.x_range, (40.0, 60.0));
+/// assert_eq!(grid.nx, 100);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Rebinner {
+    /// Target cell size in pixels
+    pub cell_px: f32,
+    /// View movement, as a fraction of a cell, that triggers a re-bin
+    pub tolerance: f32,
+    current: Option<BinGrid>,
+}
+
+impl Rebinner {
+    pub fn new(cell_px: f32) -> Self {
+        Self {
+            cell_px,
+            tolerance: 0.5,
+            current: None,
+        }
+    }
+
+    /// The grid the current aggregation was computed for
+    pub fn current(&self) -> Option<&BinGrid> {
+        self.current.as_ref()
+    }
+
+    /// Forget the current grid so the next `update` re-bins (e.g. new data)
+    pub fn invalidate(&mut self) {
+        self.current = None;
+    }
+
+    /// Report the grid to aggregate into if the view changed enough
+    ///
+    /// # Returns
+    /// `Some(grid)` when the caller should re-run the aggregation (the grid is
+    /// then remembered as current), `None` when the existing result is still
+    /// good for this view.
+    pub fn update(&mut self, view: &ViewRange, width: f32, height: f32) -> Option<BinGrid> {
+        let grid = BinGrid::for_view(view, width, height, self.cell_px);
+        if let Some(current) = &self.current {
+            if !self.differs(current, &grid) {
+                return None;
+            }
+        }
+        self.current = Some(grid);
+        Some(grid)
+    }
+
+    fn differs(&self, old: &BinGrid, new: &BinGrid) -> bool {
+        if old.nx != new.nx || old.ny != new.ny {
+            return true;
+        }
+        let (cw, ch) = new.cell_size();
+        let (tx, ty) = (cw.abs() * self.tolerance, ch.abs() * self.tolerance);
+        (old.x_range.0 - new.x_range.0).abs() > tx
+            || (old.x_range.1 - new.x_range.1).abs() > tx
+            || (old.y_range.0 - new.y_range.0).abs() > ty
+            || (old.y_range.1 - new.y_range.1).abs() > ty
+    }
+}
@@ -0,0 +1,930 @@
+use crate::chunked::ChunkedBuffer;
+use crate::data::{Color, Point2D, Vertex};
+use crate::renderer::{RenderOptions, Renderer};
+use crate::scale::{break_marker_segments, Axis, AxisScale, BrokenAxis};
+use crate::shaders::{SIMPLE_FRAGMENT_SHADER, SIMPLE_VERTEX_SHADER};
+use crate::stats::DrawStats;
+use crate::text::{HAlign, TextSection, VAlign};
+use crate::transition::ViewRange;
+
+/// Major and minor tick positions along one axis (data units)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Ticks {
+    pub major: Vec<f32>,
+    pub minor: Vec<f32>,
+    /// Spacing between major ticks
+    pub step: f32,
+}
+
+/// Round a raw step up to a "nice" 1, 2 or 5 x 10^n value
+fn nice_step(raw: f64) -> f64 {
+    let magnitude = 10f64.powf(raw.log10().floor());
+    let residual = raw / magnitude;
+    let nice = if residual <= 1.0 {
+        1.0
+    } else if residual <= 2.0 {
+        2.0
+    } else if residual <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+    nice * magnitude
+}
+
+/// Compute human-friendly tick positions covering [min, max]
+///
+/// # Parameters
+/// * `min`, `max` - Data range of the axis
+/// * `target` - Approximate number of major ticks wanted
+/// * `minor_subdivisions` - Minor intervals per major interval (0 or 1 = none)
+///
+/// # Returns
+/// Major ticks on multiples of a 1/2/5 x 10^n step, plus minor ticks that
+/// fall between (not on) the majors. Empty for an empty or invalid range.
+pub fn compute_ticks(min: f32, max: f32, target: usize, minor_subdivisions: usize) -> Ticks {
+    let (min, max) = (min as f64, max as f64);
+    if !min.is_finite() || !max.is_finite() || max <= min {
+        return Ticks::default();
+    }
+
+    let step = nice_step((max - min) / target.max(1) as f64);
+    // Generate from integer multiples to avoid accumulating float error
+    let first = (min / step).ceil() as i64;
+    let last = (max / step).floor() as i64;
+    let major: Vec<f32> = (first..=last).map(|i| (i as f64 * step) as f32).collect();
+
+    let mut minor = Vec::new();
+    if minor_subdivisions > 1 {
+        let minor_step = step / minor_subdivisions as f64;
+        let first = (min / minor_step).ceil() as i64;
+        let last = (max / minor_step).floor() as i64;
+        minor = (first..=last)
+            .filter(|i| i % minor_subdivisions as i64 != 0)
+            .map(|i| (i as f64 * minor_step) as f32)
+            .collect();
+    }
+
+    Ticks {
+        major,
+        minor,
+        step: step as f32,
+    }
+}
+
+/// Dash pattern for grid, axis and series lines
+///
+/// Serializes as `"solid"` or `{"dashed": {"on": 4.0, "off": 2.0}}`.
+#[derive(Debug, Clone, Copy, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineDash {
+    #[default]
+    Solid,
+    /// Dash and gap lengths in pixels
+    Dashed { on: f32, off: f32 },
+}
+
+/// Appearance of one class of gridlines (major or minor)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridStyle {
+    pub color: Color,
+    /// Multiplied into `color.a`
+    pub opacity: f32,
+    pub dash: LineDash,
+}
+
+impl GridStyle {
+    pub fn major() -> Self {
+        Self {
+            color: Color::new(0.8, 0.8, 0.8, 1.0),
+            opacity: 1.0,
+            dash: LineDash::Solid,
+        }
+    }
+
+    pub fn minor() -> Self {
+        Self {
+            color: Color::new(0.9, 0.9, 0.9, 1.0),
+            opacity: 0.6,
+            dash: LineDash::Dashed { on: 2.0, off: 2.0 },
+        }
+    }
+}
+
+/// Gridline settings for a single axis
+#[derive(Debug, Clone, PartialEq)]
+pub struct AxisGridOptions {
+    pub major: bool,
+    pub minor: bool,
+    pub major_style: GridStyle,
+    pub minor_style: GridStyle,
+    /// Approximate number of major ticks
+    pub tick_target: usize,
+    /// Minor intervals per major interval
+    pub minor_subdivisions: usize,
+    /// Where ticks go and how they are labelled; see `AxisScale::ticks`
+    pub scale: AxisScale,
+    /// Ranges cut out of the axis; positions along it are then compressed
+    /// (`BrokenAxis::compress` after `scale`'s forward mapping) and each
+    /// continuous piece gets its own ticks
+    pub breaks: Option<BrokenAxis>,
+}
+
+impl Default for AxisGridOptions {
+    fn default() -> Self {
+        Self {
+            major: true,
+            minor: false,
+            major_style: GridStyle::major(),
+            minor_style: GridStyle::minor(),
+            tick_target: 6,
+            minor_subdivisions: 5,
+            scale: AxisScale::Linear,
+            breaks: None,
+        }
+    }
+}
+
+impl AxisGridOptions {
+    /// Ticks for the axis positions [min, max]
+    ///
+    /// Without breaks this is `AxisScale::ticks`. With breaks, ticks are
+    /// placed on each piece of the axis between gaps, in data units, and
+    /// compressed back onto the axis, so none lands inside a gap.
+    pub fn ticks(&self, min: f32, max: f32, minor_subdivisions: usize) -> Ticks {
+        self.labelled_ticks(min, max, minor_subdivisions).0
+    }
+
+    /// `ticks` and the labels of their major ticks
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::axes::AxisGridOptions;
+    /// use helion_core::scale::{AxisBreak, BrokenAxis};
+    ///
+    /// // 0-10 and 990-1000, with 10-990 collapsed to 1 unit
+    /// let options = AxisGridOptions {
+    ///     breaks: Some(BrokenAxis::new(vec![AxisBreak::new(10.0, 990.0)], 1.0)),
+    ///     ..Default::default()
+    /// };
+    /// let (ticks, labels) = options.labelled_ticks(0.0, 21.0, 0);
+    /// assert_eq!(ticks.major, [0.0, 5.0, 10.0, 11.0, 16.0, 21.0]);
+    /// assert_eq!(labels, ["0", "5", "10", "990", "995", "1000"]);
+    /// ```
+    pub fn labelled_ticks(&self, min: f32, max: f32, minor_subdivisions: usize) -> (Ticks, Vec<String>) {
+        let gaps = self.breaks.as_ref().map(BrokenAxis::gaps).unwrap_or_default();
+        if gaps.is_empty() || max <= min {
+            let ticks = self.scale.ticks(min, max, self.tick_target, minor_subdivisions);
+            let labels = self.scale.tick_labels(&ticks);
+            return (ticks, labels);
+        }
+        let breaks = self.breaks.as_ref().expect("gaps come from breaks");
+
+        // Continuous pieces of [min, max] between the gaps
+        let mut pieces = Vec::new();
+        let mut start = min;
+        for (gap_start, gap_end) in gaps {
+            if gap_start > start {
+                pieces.push((start, gap_start.min(max)));
+            }
+            start = start.max(gap_end);
+            if start >= max {
+                break;
+            }
+        }
+        if start < max {
+            pieces.push((start, max));
+        }
+
+        let mut result = Ticks::default();
+        let mut labels = Vec::new();
+        for (lo, hi) in pieces {
+            let share = (hi - lo) / (max - min);
+            let target = ((self.tick_target as f32 * share).round() as usize).max(1);
+            let ticks = self.scale.ticks(breaks.expand(lo), breaks.expand(hi), target, minor_subdivisions);
+            labels.extend(self.scale.tick_labels(&ticks));
+            result.major.extend(ticks.major.iter().map(|&v| breaks.compress(v)));
+            result.minor.extend(ticks.minor.iter().map(|&v| breaks.compress(v)));
+            if result.step == 0.0 {
+                result.step = ticks.step;
+            }
+        }
+        (result, labels)
+    }
+}
+
+/// Gridline settings for both axes
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GridOptions {
+    /// Vertical lines at x ticks
+    pub x: AxisGridOptions,
+    /// Horizontal lines at y ticks
+    pub y: AxisGridOptions,
+}
+
+/// One straight gridline segment in clip space
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridLine {
+    pub start: [f32; 2],
+    pub end: [f32; 2],
+    pub color: [f32; 4],
+}
+
+impl GridLine {
+    /// The segment as two vertices for a `LineList` draw
+    pub fn vertices(&self) -> [Vertex; 2] {
+        let [r, g, b, a] = self.color;
+        let color = Color::new(r, g, b, a);
+        [
+            Vertex::new(Point2D::new(self.start[0], self.start[1]), color, 1.0),
+            Vertex::new(Point2D::new(self.end[0], self.end[1]), color, 1.0),
+        ]
+    }
+}
+
+/// Build gridline segments for a plot showing the given data ranges
+///
+/// Dashed styles are split into individual segments so the result can be
+/// drawn with a plain `LineList` pipeline.
+///
+/// # Parameters
+/// * `x_range`, `y_range` - Data range mapped onto clip space [-1, 1]
+/// * `options` - Per-axis grid settings
+/// * `width`, `height` - Viewport size in pixels (for dash lengths)
+pub fn grid_lines(
+    x_range: (f32, f32),
+    y_range: (f32, f32),
+    options: &GridOptions,
+    width: f32,
+    height: f32,
+) -> Vec<GridLine> {
+    let mut lines = Vec::new();
+
+    let to_clip = |v: f32, (min, max): (f32, f32)| (v - min) / (max - min) * 2.0 - 1.0;
+    let x_ticks = options.x.ticks(x_range.0, x_range.1, options.x.minor_subdivisions);
+    let y_ticks = options.y.ticks(y_range.0, y_range.1, options.y.minor_subdivisions);
+
+    // Minor first so major lines draw on top
+    let passes = [
+        (options.x.minor, &x_ticks.minor, options.x.minor_style, true),
+        (options.y.minor, &y_ticks.minor, options.y.minor_style, false),
+        (options.x.major, &x_ticks.major, options.x.major_style, true),
+        (options.y.major, &y_ticks.major, options.y.major_style, false),
+    ];
+
+    for (enabled, ticks, style, vertical) in passes {
+        if !enabled {
+            continue;
+        }
+        for &tick in ticks {
+            let (start, end, length_px) = if vertical {
+                let x = to_clip(tick, x_range);
+                ([x, -1.0], [x, 1.0], height)
+            } else {
+                let y = to_clip(tick, y_range);
+                ([-1.0, y], [1.0, y], width)
+            };
+            push_styled(&mut lines, start, end, length_px, &style);
+        }
+    }
+
+    lines
+}
+
+/// Push a full-length line, split into dashes if the style requires it
+fn push_styled(lines: &mut Vec<GridLine>, start: [f32; 2], end: [f32; 2], length_px: f32, style: &GridStyle) {
+    let color = [style.color.r, style.color.g, style.color.b, style.color.a * style.opacity];
+
+    match style.dash {
+        LineDash::Solid => lines.push(GridLine { start, end, color }),
+        LineDash::Dashed { on, off } => {
+            let period = on + off;
+            if period <= 0.0 || length_px <= 0.0 {
+                lines.push(GridLine { start, end, color });
+                return;
+            }
+            let lerp = |t: f32| {
+                [start[0] + (end[0] - start[0]) * t, start[1] + (end[1] - start[1]) * t]
+            };
+            let mut offset = 0.0;
+            while offset < length_px {
+                let dash_end = (offset + on).min(length_px);
+                lines.push(GridLine {
+                    start: lerp(offset / length_px),
+                    end: lerp(dash_end / length_px),
+                    color,
+                });
+                offset += period;
+            }
+        }
+    }
+}
+
+/// Tick marks, axis lines and frame drawn with the gridlines
+#[derive(Debug, Clone, PartialEq)]
+pub struct AxesOptions {
+    pub grid: GridOptions,
+    /// Lines along the bottom (x) and left (y) edges of the plot
+    pub axis_lines: bool,
+    /// Lines along the top and right edges, closing the plot area
+    pub frame: bool,
+    /// Marks at each major tick, pointing into the plot from the axis lines
+    pub ticks: bool,
+    /// Style of axis lines, frame and tick marks
+    pub line_style: GridStyle,
+    /// Major tick mark length in pixels
+    pub tick_length: f32,
+    /// Minor tick mark length in pixels; 0 draws none
+    pub minor_tick_length: f32,
+    /// Labels at the major ticks, inside the plot next to the axis lines;
+    /// `None` draws none
+    pub tick_labels: Option<TickLabelOptions>,
+}
+
+impl Default for AxesOptions {
+    fn default() -> Self {
+        Self {
+            grid: GridOptions::default(),
+            axis_lines: true,
+            frame: true,
+            ticks: true,
+            line_style: GridStyle {
+                color: Color::new(0.2, 0.2, 0.2, 1.0),
+                opacity: 1.0,
+                dash: LineDash::Solid,
+            },
+            tick_length: 5.0,
+            minor_tick_length: 0.0,
+            tick_labels: Some(TickLabelOptions::default()),
+        }
+    }
+}
+
+impl AxesOptions {
+    pub fn with_grid(mut self, grid: GridOptions) -> Self {
+        self.grid = grid;
+        self
+    }
+
+    pub fn with_frame(mut self, frame: bool) -> Self {
+        self.frame = frame;
+        self
+    }
+
+    pub fn with_ticks(mut self, ticks: bool) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    pub fn with_tick_labels(mut self, tick_labels: Option<TickLabelOptions>) -> Self {
+        self.tick_labels = tick_labels;
+        self
+    }
+
+    /// Distance in pixels from the axis lines to the tick labels
+    pub fn label_inset(&self) -> f32 {
+        let ticks = if self.ticks { self.tick_length } else { 0.0 };
+        ticks + 3.0
+    }
+}
+
+/// Build every line of the axes for a plot showing `view`: gridlines, then
+/// tick marks, axis lines and frame, back to front
+///
+/// Axis lines and the frame sit on the outermost pixel row or column of the
+/// viewport so they aren't clipped.
+///
+/// # Parameters
+/// * `view` - Data range mapped onto clip space [-1, 1]
+/// * `options` - Grid, tick and frame settings
+/// * `width`, `height` - Viewport size in pixels
+///
+/// # Example
+/// ```
+/// use helion_core::axes::{axes_lines, AxesOptions, GridOptions, AxisGridOptions};
+/// use helion_core::transition::ViewRange;
+///
+/// let no_grid = AxisGridOptions { major: false, ..Default::default() };
+/// let options = AxesOptions::default()
+///     .with_grid(GridOptions { x: no_grid.clone(), y: no_grid })
+///     .with_ticks(false);
+/// // Just the four sides of the frame
+/// assert_eq!(axes_lines(&ViewRange::new((0.0, 1.0), (0.0, 1.0)), &options, 800.0, 600.0).len(), 4);
+/// ```
+pub fn axes_lines(view: &ViewRange, options: &AxesOptions, width: f32, height: f32) -> Vec<GridLine> {
+    let mut lines = grid_lines(view.x, view.y, &options.grid, width, height);
+
+    // Clip-space size of one pixel along each axis
+    let (px, py) = (2.0 / width.max(1.0), 2.0 / height.max(1.0));
+    let (left, right) = (-1.0 + px / 2.0, 1.0 - px / 2.0);
+    let (bottom, top) = (-1.0 + py / 2.0, 1.0 - py / 2.0);
+    let style = &options.line_style;
+
+    if options.ticks {
+        let to_clip = |v: f32, (min, max): (f32, f32)| (v - min) / (max - min) * 2.0 - 1.0;
+        let (x_grid, y_grid) = (&options.grid.x, &options.grid.y);
+        let x_ticks = x_grid.ticks(view.x.0, view.x.1, x_grid.minor_subdivisions);
+        let y_ticks = y_grid.ticks(view.y.0, view.y.1, y_grid.minor_subdivisions);
+        let marks = [
+            (&x_ticks.major, options.tick_length),
+            (&x_ticks.minor, options.minor_tick_length),
+        ];
+        for (ticks, length) in marks.into_iter().filter(|&(_, length)| length > 0.0) {
+            for &tick in ticks {
+                let x = to_clip(tick, view.x);
+                push_styled(&mut lines, [x, bottom], [x, bottom + length * py], length, style);
+            }
+        }
+        let marks = [
+            (&y_ticks.major, options.tick_length),
+            (&y_ticks.minor, options.minor_tick_length),
+        ];
+        for (ticks, length) in marks.into_iter().filter(|&(_, length)| length > 0.0) {
+            for &tick in ticks {
+                let y = to_clip(tick, view.y);
+                push_styled(&mut lines, [left, y], [left + length * px, y], length, style);
+            }
+        }
+    }
+
+    if options.axis_lines {
+        push_styled(&mut lines, [-1.0, bottom], [1.0, bottom], width, style);
+        push_styled(&mut lines, [left, -1.0], [left, 1.0], height, style);
+    }
+    if options.frame {
+        push_styled(&mut lines, [-1.0, top], [1.0, top], width, style);
+        push_styled(&mut lines, [right, -1.0], [right, 1.0], height, style);
+    }
+
+    // "//" across the axis lines (and frame) at the middle of each gap
+    let color = [style.color.r, style.color.g, style.color.b, style.color.a * style.opacity];
+    let to_clip = |v: f32, (min, max): (f32, f32)| (v - min) / (max - min) * 2.0 - 1.0;
+    let axes = [
+        (Axis::X, &options.grid.x, view.x, [bottom, top], py),
+        (Axis::Y, &options.grid.y, view.y, [left, right], px),
+    ];
+    for (axis, grid, range, crosses, pixel) in axes {
+        let Some(breaks) = &grid.breaks else {
+            continue;
+        };
+        for (start, end) in breaks.gaps() {
+            let position = to_clip((start + end) / 2.0, range);
+            if !(-1.0..=1.0).contains(&position) {
+                continue;
+            }
+            let drawn = [options.axis_lines, options.frame];
+            for cross in crosses.into_iter().zip(drawn).filter_map(|(cross, drawn)| drawn.then_some(cross)) {
+                for [start, end] in break_marker_segments(axis, position, cross, BREAK_MARKER_SIZE * pixel) {
+                    lines.push(GridLine { start, end, color });
+                }
+            }
+        }
+    }
+
+    lines
+}
+
+/// Half-length in pixels of the strokes marking an axis break
+const BREAK_MARKER_SIZE: f32 = 5.0;
+
+/// Draws gridlines, tick marks, axis lines and frame as one `LineList` pass
+///
+/// Ticks depend on the visible range, so instead of following a camera the
+/// lines are rebuilt by `set_view` (a few hundred vertices at most). Draw it
+/// before the series so the points land on top of the grid.
+pub struct AxesRenderer {
+    render_pipeline: wgpu::RenderPipeline,
+    vertex_buffer: Option<ChunkedBuffer>,
+    options: AxesOptions,
+    view: ViewRange,
+    width: f32,
+    height: f32,
+    uploaded_bytes: u64,
+}
+
+impl AxesRenderer {
+    /// Renderer for a `width` x `height` pixel plot showing `view`
+    ///
+    /// # Parameters
+    /// * `format` - Format of the target the pass renders to
+    /// * `render_options` - Must match the options the pass is begun with
+    pub fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        render_options: &RenderOptions,
+        options: AxesOptions,
+        view: ViewRange,
+        width: f32,
+        height: f32,
+    ) -> Self {
+        let mut renderer = AxesRenderer {
+            render_pipeline: Self::create_pipeline(device, format, render_options),
+            vertex_buffer: None,
+            options,
+            view,
+            width,
+            height,
+            uploaded_bytes: 0,
+        };
+        renderer.rebuild(device);
+        renderer
+    }
+
+    fn create_pipeline(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        options: &RenderOptions,
+    ) -> wgpu::RenderPipeline {
+        let vertex_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Axes Vertex Shader"),
+            source: wgpu::ShaderSource::Wgsl(SIMPLE_VERTEX_SHADER.into()),
+        });
+
+        let fragment_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Axes Fragment Shader"),
+            source: wgpu::ShaderSource::Wgsl(SIMPLE_FRAGMENT_SHADER.into()),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Axes Pipeline Layout"),
+            bind_group_layouts: &[],
+            push_constant_ranges: &[],
+        });
+
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Axes Render Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &vertex_shader,
+                entry_point: "vs_main",
+                buffers: &[Vertex::desc()],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: options.blend_state(),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::LineList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: options.depth_stencil_state(),
+            multisample: options.multisample_state(),
+            multiview: None,
+            cache: None,
+        })
+    }
+
+    /// Recompute the lines and upload them
+    fn rebuild(&mut self, device: &wgpu::Device) {
+        let vertices: Vec<Vertex> = axes_lines(&self.view, &self.options, self.width, self.height)
+            .iter()
+            .flat_map(GridLine::vertices)
+            .collect();
+        // Chunks must not split a segment
+        self.vertex_buffer =
+            ChunkedBuffer::upload_or_log(device, "Axes Vertex Buffer", &vertices, wgpu::BufferUsages::VERTEX, 2);
+        self.uploaded_bytes += self.vertex_buffer.as_ref().map_or(0, ChunkedBuffer::byte_size);
+    }
+
+    pub fn options(&self) -> &AxesOptions {
+        &self.options
+    }
+
+    pub fn view(&self) -> &ViewRange {
+        &self.view
+    }
+
+    pub fn set_options(&mut self, device: &wgpu::Device, options: AxesOptions) {
+        self.options = options;
+        self.rebuild(device);
+    }
+
+    /// Show ticks for a new visible range, e.g. after a pan or zoom
+    pub fn set_view(&mut self, device: &wgpu::Device, view: ViewRange) {
+        if view != self.view {
+            self.view = view;
+            self.rebuild(device);
+        }
+    }
+
+    /// Keep dashes and tick marks their pixel size in a resized viewport
+    pub fn resize(&mut self, device: &wgpu::Device, width: f32, height: f32) {
+        self.width = width;
+        self.height = height;
+        self.rebuild(device);
+    }
+}
+
+impl Renderer for AxesRenderer {
+    fn render_to_pass<'rpass>(&'rpass mut self, render_pass: &mut wgpu::RenderPass<'rpass>) {
+        render_pass.set_pipeline(&self.render_pipeline);
+
+        if let Some(ref buffer) = self.vertex_buffer {
+            buffer.draw(render_pass, 0);
+        }
+    }
+
+    fn frame_stats(&mut self) -> DrawStats {
+        let stats = self.vertex_buffer.as_ref().map_or_else(DrawStats::default, ChunkedBuffer::draw_stats);
+        stats.with_bytes_uploaded(std::mem::take(&mut self.uploaded_bytes))
+    }
+}
+
+/// Format tick values with just enough decimals for their spacing
+///
+/// # Example
+/// ```
+/// use helion_core::axes::{compute_ticks, format_ticks};
+///
+/// let ticks = compute_ticks(-0.5, 0.5, 4, 0);
+/// assert_eq!(format_ticks(&ticks.major, ticks.step), ["-0.5", "0.0", "0.5"]);
+/// ```
+pub fn format_ticks(values: &[f32], step: f32) -> Vec<String> {
+    let decimals = if step.is_finite() && step > 0.0 {
+        (-step.log10().floor()).max(0.0) as usize
+    } else {
+        0
+    };
+    // Adding 0.0 turns -0.0 into 0.0, so the zero tick isn't printed as "-0"
+    values.iter().map(|&v| format!("{:.*}", decimals, v + 0.0)).collect()
+}
+
+/// Shorten `text` to at most `max_chars` characters, ending in '…' if cut
+pub fn elide(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    match max_chars {
+        0 => String::new(),
+        n => text.chars().take(n - 1).chain(std::iter::once('…')).collect(),
+    }
+}
+
+/// Rotation of tick labels
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LabelRotation {
+    /// Upright if the labels fit, otherwise 45 degrees, otherwise 90 -
+    /// whichever needs the least thinning
+    #[default]
+    Auto,
+    /// Fixed counter-clockwise angle in degrees (0 = upright)
+    Degrees(f32),
+}
+
+/// Angles tried by `LabelRotation::Auto`, in order of preference
+const AUTO_ROTATIONS: [f32; 3] = [0.0, 45.0, 90.0];
+
+/// Tick label layout options
+#[derive(Debug, Clone, PartialEq)]
+pub struct TickLabelOptions {
+    /// Font size in pixels (label height)
+    pub font_size: f32,
+    /// Approximate glyph advance as a fraction of `font_size`
+    pub char_width: f32,
+    pub rotation: LabelRotation,
+    /// Minimum free space between neighbouring labels, in pixels
+    pub min_gap: f32,
+    /// Longer labels (typically category names) are elided to this many
+    /// characters; `None` keeps them whole
+    pub max_chars: Option<usize>,
+}
+
+impl Default for TickLabelOptions {
+    fn default() -> Self {
+        Self {
+            font_size: 12.0,
+            char_width: 0.6,
+            rotation: LabelRotation::Auto,
+            min_gap: 4.0,
+            max_chars: Some(16),
+        }
+    }
+}
+
+/// A tick label that survived thinning
+#[derive(Debug, Clone, PartialEq)]
+pub struct TickLabel {
+    /// Index of the tick in the input
+    pub index: usize,
+    /// Text after elision
+    pub text: String,
+    /// Tick position along the axis, in pixels
+    pub position: f32,
+    /// Size of the text box before rotation, in pixels
+    pub width: f32,
+    pub height: f32,
+}
+
+/// Tick labels chosen for one axis
+#[derive(Debug, Clone, PartialEq)]
+pub struct TickLabelLayout {
+    pub labels: Vec<TickLabel>,
+    /// Counter-clockwise rotation of every label, in degrees
+    pub rotation: f32,
+    /// Every `stride`-th tick keeps its label (1 = all of them)
+    pub stride: usize,
+}
+
+/// Choose rotation and thinning so tick labels don't overlap
+///
+/// Long labels are elided first. With `LabelRotation::Auto` the labels
+/// stay upright if they fit and are turned to 45 or 90 degrees if that
+/// avoids thinning. Ticks that still crowd each other are thinned at a
+/// regular stride (every 2nd, 3rd, ... label, starting from the first), so
+/// the remaining labels keep an even rhythm.
+///
+/// # Parameters
+/// * `axis` - Axis the ticks run along; x labels sit side by side, y labels
+///   are stacked
+/// * `positions` - Tick positions along the axis in pixels, in order
+/// * `texts` - Label per tick; extra ticks or texts are ignored
+/// * `options` - Font metrics, rotation and elision
+///
+/// # Example
+/// ```
+/// use helion_core::axes::{layout_tick_labels, TickLabelOptions};
+/// use helion_core::scale::Axis;
+///
+/// let positions = [0.0, 30.0, 60.0, 90.0];
+/// let texts: Vec<String> = ["January", "February", "March", "April"].map(String::from).into();
+/// let layout = layout_tick_labels(Axis::X, &positions, &texts, &TickLabelOptions::default());
+/// // Too wide to sit upright 30px apart, but they fit when turned
+/// assert_eq!(layout.stride, 1);
+/// assert!(layout.rotation > 0.0);
+/// ```
+pub fn layout_tick_labels(
+    axis: Axis,
+    positions: &[f32],
+    texts: &[String],
+    options: &TickLabelOptions,
+) -> TickLabelLayout {
+    let labels: Vec<TickLabel> = positions
+        .iter()
+        .zip(texts)
+        .enumerate()
+        .map(|(index, (&position, text))| {
+            let text = match options.max_chars {
+                Some(max) => elide(text, max),
+                None => text.clone(),
+            };
+            TickLabel {
+                index,
+                width: text.chars().count() as f32 * options.font_size * options.char_width,
+                height: options.font_size,
+                text,
+                position,
+            }
+        })
+        .collect();
+
+    let candidates: &[f32] = match options.rotation {
+        LabelRotation::Auto => &AUTO_ROTATIONS,
+        LabelRotation::Degrees(ref degrees) => std::slice::from_ref(degrees),
+    };
+    // First angle needing the least thinning, so upright wins ties
+    let (rotation, stride) = candidates
+        .iter()
+        .map(|&degrees| (degrees, thinning_stride(axis, &labels, degrees, options.min_gap)))
+        .fold(None, |best: Option<(f32, usize)>, candidate| match best {
+            Some(best) if best.1 <= candidate.1 => Some(best),
+            _ => Some(candidate),
+        })
+        .unwrap_or((0.0, 1));
+
+    TickLabelLayout {
+        labels: labels.into_iter().step_by(stride).collect(),
+        rotation,
+        stride,
+    }
+}
+
+/// Room a label rotated by `degrees` needs along `axis`, in pixels
+///
+/// Rotated labels are parallel, so neighbours only need to clear each
+/// other's text height measured across the slant, which is less than the
+/// full rotated extent for long text.
+fn footprint(axis: Axis, label: &TickLabel, degrees: f32) -> f32 {
+    let (sin, cos) = degrees.to_radians().sin_cos();
+    // Share of the label's width and height that lies along the axis
+    let (along, across) = match axis {
+        Axis::X => (cos.abs(), sin.abs()),
+        Axis::Y => (sin.abs(), cos.abs()),
+    };
+    if across < 1e-3 {
+        label.width
+    } else {
+        (label.width * along + label.height * across).min(label.height / across)
+    }
+}
+
+/// Smallest stride at which every kept label clears its kept neighbours
+fn thinning_stride(axis: Axis, labels: &[TickLabel], degrees: f32, min_gap: f32) -> usize {
+    let fits = |stride: usize| {
+        let kept: Vec<&TickLabel> = labels.iter().step_by(stride).collect();
+        kept.windows(2).all(|pair| {
+            let needed = (footprint(axis, pair[0], degrees) + footprint(axis, pair[1], degrees)) / 2.0 + min_gap;
+            (pair[1].position - pair[0].position).abs() >= needed
+        })
+    };
+    (1..labels.len()).find(|&stride| fits(stride)).unwrap_or(labels.len().max(1))
+}
+
+/// Tick labels of both axes for a `width` x `height` plot showing `view`,
+/// as (x, y) layouts; positions are pixels from the left and from the top
+///
+/// `None` if `options` has no tick labels.
+pub fn tick_label_layouts(
+    view: &ViewRange,
+    options: &AxesOptions,
+    width: f32,
+    height: f32,
+) -> Option<(TickLabelLayout, TickLabelLayout)> {
+    let label_options = options.tick_labels.as_ref()?;
+    let (x, y) = (&options.grid.x, &options.grid.y);
+    let (x_ticks, x_labels) = x.labelled_ticks(view.x.0, view.x.1, 0);
+    let (y_ticks, y_labels) = y.labelled_ticks(view.y.0, view.y.1, 0);
+    let x_positions: Vec<f32> = x_ticks
+        .major
+        .iter()
+        .map(|&v| (v - view.x.0) / (view.x.1 - view.x.0) * width)
+        .collect();
+    let y_positions: Vec<f32> = y_ticks
+        .major
+        .iter()
+        .map(|&v| (view.y.1 - v) / (view.y.1 - view.y.0) * height)
+        .collect();
+    Some((
+        layout_tick_labels(Axis::X, &x_positions, &x_labels, label_options),
+        layout_tick_labels(Axis::Y, &y_positions, &y_labels, label_options),
+    ))
+}
+
+/// Text for the tick labels, ready for a `TextRenderer`
+///
+/// x labels sit above the bottom axis line and y labels right of the left
+/// one. Upright labels that would hang over the edge of the plot, and y
+/// labels that would run into the x labels, are dropped.
+///
+/// # Example
+/// ```
+/// use helion_core::axes::{tick_label_sections, AxesOptions};
+/// use helion_core::transition::ViewRange;
+///
+/// let view = ViewRange::new((0.0, 100.0), (0.0, 1.0));
+/// let sections = tick_label_sections(&view, &AxesOptions::default(), 800.0, 600.0);
+/// assert!(sections.iter().any(|s| s.text == "40"));
+/// assert!(sections.iter().any(|s| s.text == "0.4"));
+/// ```
+pub fn tick_label_sections(view: &ViewRange, options: &AxesOptions, width: f32, height: f32) -> Vec<TextSection> {
+    let (Some(label_options), Some((x_layout, y_layout))) =
+        (&options.tick_labels, tick_label_layouts(view, options, width, height))
+    else {
+        return Vec::new();
+    };
+    let color = options.line_style.color;
+    let size = label_options.font_size;
+    let inset = options.label_inset();
+    let mut sections = Vec::new();
+
+    let upright = x_layout.rotation == 0.0;
+    for label in x_layout.labels {
+        let half = label.width / 2.0;
+        if upright && (label.position - half < 0.0 || label.position + half > width) {
+            continue;
+        }
+        // Turned labels start at the tick and run up into the plot
+        let align = if upright { (HAlign::Center, VAlign::Bottom) } else { (HAlign::Left, VAlign::Middle) };
+        sections.push(
+            TextSection::new(label.text, [label.position, height - inset], size)
+                .with_color(color)
+                .with_align(align.0, align.1)
+                .with_rotation(x_layout.rotation),
+        );
+    }
+
+    let x_row_top = height - inset - size;
+    for label in y_layout.labels {
+        let half = label.height / 2.0;
+        if label.position - half < 0.0 || label.position + half > x_row_top {
+            continue;
+        }
+        sections.push(
+            TextSection::new(label.text, [inset, label.position], size)
+                .with_color(color)
+                .with_align(HAlign::Left, VAlign::Middle),
+        );
+    }
+    sections
+}
@@ -1,4 +1,5 @@
-use std::sync::Arc;
+use crate::error::HelionError;
+use std::sync::{Arc, Mutex};
 
 /// GPU backend type
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -7,6 +8,149 @@ pub enum BackendType {
     WebGL2,
 }
 
+impl BackendType {
+    /// Backends tried, in `GPUBackend::new`'s order
+    pub const ALL: [BackendType; 2] = [BackendType::WebGPU, BackendType::WebGL2];
+
+    /// wgpu backends this type covers
+    ///
+    /// Natively, "WebGPU" means any of the modern APIs (Vulkan, Metal, DX12)
+    /// and "WebGL2" means OpenGL ES / WebGL.
+    pub fn backends(self) -> wgpu::Backends {
+        match self {
+            BackendType::WebGPU => wgpu::Backends::PRIMARY,
+            BackendType::WebGL2 => wgpu::Backends::GL,
+        }
+    }
+
+    /// Device limits to request from `adapter`
+    ///
+    /// WebGPU gets the defaults, or the downlevel limits if the adapter
+    /// falls short of them (`limits_for_adapter`). WebGL2 gets wgpu's downlevel WebGL2 limits, with texture sizes raised
+    /// to what the adapter supports; requesting the WebGPU defaults there
+    /// would fail (no compute or storage buffers, smaller uniform blocks).
+    pub fn required_limits(self, adapter: &wgpu::Adapter) -> wgpu::Limits {
+        match self {
+            BackendType::WebGPU => limits_for_adapter(adapter),
+            BackendType::WebGL2 => wgpu::Limits::downlevel_webgl2_defaults().using_resolution(adapter.limits()),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            BackendType::WebGPU => "WebGPU",
+            BackendType::WebGL2 => "WebGL2",
+        }
+    }
+}
+
+impl std::fmt::Display for BackendType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Limits to request from `adapter` for a device of any backend
+///
+/// The WebGPU defaults where the adapter supports them; otherwise (GL
+/// adapters, old mobile GPUs) the downlevel WebGL2 limits with texture sizes
+/// raised to what the adapter allows. Requesting the defaults from such an
+/// adapter fails outright.
+pub fn limits_for_adapter(adapter: &wgpu::Adapter) -> wgpu::Limits {
+    let defaults = wgpu::Limits::default();
+    if defaults.check_limits(&adapter.limits()) {
+        defaults
+    } else {
+        wgpu::Limits::downlevel_webgl2_defaults().using_resolution(adapter.limits())
+    }
+}
+
+/// Whether a feature runs on the GPU or falls back to the CPU
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeaturePath {
+    Gpu,
+    Cpu,
+}
+
+/// What a device can do, read from its limits
+///
+/// WebGL2 devices (and some old native GPUs) are "downlevel": no compute
+/// shaders or storage buffers, and smaller buffers and textures. Features
+/// with a GPU implementation ask `aggregation` or `picking` which path to
+/// take, so figures keep working there, just slower.
+///
+/// # Example
+/// ```
+/// use helion_core::backend::{Capabilities, FeaturePath};
+///
+/// let webgl2 = Capabilities::from_limits(&wgpu::Limits::downlevel_webgl2_defaults());
+/// assert!(webgl2.is_downlevel());
+/// assert_eq!(webgl2.aggregation(), FeaturePath::Cpu);
+/// assert_eq!(Capabilities::from_limits(&wgpu::Limits::default()).aggregation(), FeaturePath::Gpu);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capabilities {
+    /// Compute pipelines can be dispatched
+    pub compute: bool,
+    /// Shaders can bind read/write storage buffers
+    pub storage_buffers: bool,
+    /// Largest single buffer, in bytes
+    pub max_buffer_size: u64,
+    /// Largest 2D texture side, in pixels
+    pub max_texture_dimension: u32,
+    /// Largest uniform buffer binding, in bytes
+    pub max_uniform_buffer_binding_size: u32,
+}
+
+impl Capabilities {
+    pub fn from_limits(limits: &wgpu::Limits) -> Self {
+        Self {
+            compute: limits.max_compute_workgroups_per_dimension > 0 && limits.max_compute_invocations_per_workgroup > 0,
+            storage_buffers: limits.max_storage_buffers_per_shader_stage > 0,
+            max_buffer_size: limits.max_buffer_size,
+            max_texture_dimension: limits.max_texture_dimension_2d,
+            max_uniform_buffer_binding_size: limits.max_uniform_buffer_binding_size,
+        }
+    }
+
+    /// Capabilities of `device`
+    pub fn of(device: &wgpu::Device) -> Self {
+        Self::from_limits(&device.limits())
+    }
+
+    /// Missing compute or storage buffers, as on WebGL2
+    pub fn is_downlevel(&self) -> bool {
+        !(self.compute && self.storage_buffers)
+    }
+
+    /// Where 2D binning runs: `GpuBinner` needs a compute pass with storage
+    /// buffers, otherwise `aggregate::aggregate_counts` does the work
+    pub fn aggregation(&self) -> FeaturePath {
+        if self.is_downlevel() {
+            FeaturePath::Cpu
+        } else {
+            FeaturePath::Gpu
+        }
+    }
+
+    /// Where point picking runs: the GPU path draws point ids with
+    /// `picking::GpuPicker`, otherwise `picking::pick_nearest` searches the
+    /// vertices
+    pub fn picking(&self) -> FeaturePath {
+        if self.storage_buffers {
+            FeaturePath::Gpu
+        } else {
+            FeaturePath::Cpu
+        }
+    }
+
+    /// Whether a `width` x `height` texture (e.g. an offscreen render or a
+    /// colormap atlas) fits on this device
+    pub fn fits_texture(&self, width: u32, height: u32) -> bool {
+        width <= self.max_texture_dimension && height <= self.max_texture_dimension
+    }
+}
+
 /// GPU backend abstraction - OPTIONAL helper for web contexts
 /// 
 /// This struct is maintained for backward compatibility and web-based usage.
@@ -16,90 +160,190 @@ pub enum BackendType {
 /// Design rationale:
 /// - Native contexts: Create device/queue/surface directly, pass to renderer
 /// - Web contexts: Use GPUBackend as a convenient factory/manager
+///
+/// Browsers without WebGPU get a WebGL2 device instead; check
+/// `capabilities` before using compute passes such as `GpuBinner`, which
+/// WebGL2 lacks (`compute::Aggregator` does this for you).
 pub struct GPUBackend {
     pub backend_type: BackendType,
     pub device: Option<Arc<wgpu::Device>>,
     pub queue: Option<Arc<wgpu::Queue>>,
     pub surface: Option<wgpu::Surface<'static>>,
     pub config: Option<wgpu::SurfaceConfiguration>,
+    instance: wgpu::Instance,
+    adapter: wgpu::Adapter,
+    /// Set by the device-lost callback
+    lost: Arc<Mutex<Option<String>>>,
 }
 
 impl GPUBackend {
     /// Create a new GPU backend with automatic detection
-    pub async fn new() -> Result<Self, String> {
-        // Try WebGPU first
-        match Self::init_webgpu().await {
-            Ok(backend) => {
-                log::info!("Initialized WebGPU backend");
-                Ok(backend)
-            }
-            Err(e) => {
-                log::warn!("WebGPU initialization failed: {}, falling back to WebGL2", e);
-                // WebGL2 fallback will be implemented later
-                Err(format!("WebGPU failed: {}", e))
+    ///
+    /// Tries WebGPU, then WebGL2. In a browser, WebGL2 needs the canvas to
+    /// create its context, so use `for_surface` there to get the fallback.
+    pub async fn new() -> Result<Self, HelionError> {
+        Self::detect(None::<fn() -> wgpu::SurfaceTarget<'static>>).await
+    }
+
+    /// Create a backend able to present to `target` (e.g. an
+    /// `HtmlCanvasElement` or a window), trying WebGPU, then WebGL2
+    ///
+    /// The surface is configured at `width` x `height` in a format it
+    /// supports.
+    pub async fn for_surface<T>(target: T, width: u32, height: u32) -> Result<Self, HelionError>
+    where
+        T: Into<wgpu::SurfaceTarget<'static>> + Clone,
+    {
+        let mut backend = Self::detect(Some(|| target.clone().into())).await?;
+        let surface = backend.create_surface(target)?;
+        backend.configure_surface(surface, width, height)?;
+        Ok(backend)
+    }
+
+    /// Create a backend of one specific type, without falling back
+    pub async fn with_backend_type(backend_type: BackendType) -> Result<Self, HelionError> {
+        Self::init(backend_type, None).await
+    }
+
+    async fn detect<F>(target: Option<F>) -> Result<Self, HelionError>
+    where
+        F: Fn() -> wgpu::SurfaceTarget<'static>,
+    {
+        let mut failures = Vec::new();
+        for backend_type in BackendType::ALL {
+            let surface_target = target.as_ref().map(|make| make());
+            match Self::init(backend_type, surface_target).await {
+                Ok(backend) => {
+                    log::info!("Initialized {} backend", backend_type);
+                    return Ok(backend);
+                }
+                Err(e) => {
+                    log::warn!("{} initialization failed: {}", backend_type, e);
+                    failures.push((backend_type, e));
+                }
             }
         }
+        Err(HelionError::NoBackend(failures))
     }
 
-    /// Initialize WebGPU backend
-    async fn init_webgpu() -> Result<Self, String> {
+    /// Initialize a backend of `backend_type`
+    ///
+    /// `target` is only used to find a compatible adapter; WebGL2 in a
+    /// browser has no adapter without one.
+    async fn init(backend_type: BackendType, target: Option<wgpu::SurfaceTarget<'static>>) -> Result<Self, HelionError> {
         let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
-            backends: wgpu::Backends::all(),
+            backends: backend_type.backends(),
             ..Default::default()
         });
+        let probe = target
+            .map(|target| instance.create_surface(target))
+            .transpose()
+            .map_err(|e| HelionError::Surface(format!("Failed to create surface: {}", e)))?;
 
         let adapter = instance
             .request_adapter(&wgpu::RequestAdapterOptions {
                 power_preference: wgpu::PowerPreference::HighPerformance,
-                compatible_surface: None,
+                compatible_surface: probe.as_ref(),
                 force_fallback_adapter: false,
             })
             .await
-            .ok_or("Failed to find GPU adapter")?;
+            .ok_or(HelionError::AdapterNotFound)?;
 
         let (device, queue) = adapter
             .request_device(
                 &wgpu::DeviceDescriptor {
                     label: Some("Helion Device"),
                     required_features: wgpu::Features::empty(),
-                    required_limits: wgpu::Limits::default(),
+                    required_limits: backend_type.required_limits(&adapter),
                     memory_hints: Default::default(),
                 },
                 None,
             )
             .await
-            .map_err(|e| format!("Failed to create device: {}", e))?;
+            .map_err(|e| HelionError::DeviceRequest(e.to_string()))?;
+
+        let lost = Arc::new(Mutex::new(None));
+        let lost_reason = Arc::clone(&lost);
+        device.set_device_lost_callback(move |reason, message| {
+            // Dropping the backend also "loses" the device; only record real losses
+            if !matches!(reason, wgpu::DeviceLostReason::Dropped | wgpu::DeviceLostReason::ReplacedCallback) {
+                *lost_reason.lock().unwrap() = Some(message);
+            }
+        });
 
         Ok(GPUBackend {
-            backend_type: BackendType::WebGPU,
+            backend_type,
             device: Some(Arc::new(device)),
             queue: Some(Arc::new(queue)),
             surface: None,
             config: None,
+            instance,
+            adapter,
+            lost,
         })
     }
 
+    /// Backend that was selected, e.g. to show a "WebGL2 fallback" notice
+    pub fn backend_type(&self) -> BackendType {
+        self.backend_type
+    }
+
+    /// Whether compute passes (`GpuBinner`) can run on this device
+    pub fn supports_compute(&self) -> bool {
+        self.capabilities().is_ok_and(|caps| !caps.is_downlevel())
+    }
+
+    /// What the device can do; decides which features fall back to the CPU
+    pub fn capabilities(&self) -> Result<Capabilities, HelionError> {
+        Ok(Capabilities::of(self.device()?))
+    }
+
+    /// Create a surface for `target` on this backend's instance, for
+    /// `configure_surface`
+    pub fn create_surface(&self, target: impl Into<wgpu::SurfaceTarget<'static>>) -> Result<wgpu::Surface<'static>, HelionError> {
+        self.instance
+            .create_surface(target)
+            .map_err(|e| HelionError::Surface(format!("Failed to create surface: {}", e)))
+    }
+
     /// Configure surface for rendering
     pub fn configure_surface(
         &mut self,
         surface: wgpu::Surface<'static>,
         width: u32,
         height: u32,
-    ) -> Result<(), String> {
-        let device = self.device.as_ref().ok_or("Device not initialized")?;
+    ) -> Result<(), HelionError> {
+        let device = self.device()?;
+
+        // WebGL2 canvases only offer RGBA formats, so take what the surface
+        // supports, preferring sRGB
+        let capabilities = surface.get_capabilities(&self.adapter);
+        let format = capabilities
+            .formats
+            .iter()
+            .copied()
+            .find(|format| *format == wgpu::TextureFormat::Bgra8UnormSrgb)
+            .or_else(|| capabilities.formats.iter().copied().find(|format| format.is_srgb()))
+            .or_else(|| capabilities.formats.first().copied())
+            .ok_or_else(|| HelionError::Surface("Surface is not compatible with the adapter".to_string()))?;
+        let alpha_mode = if capabilities.alpha_modes.contains(&wgpu::CompositeAlphaMode::Opaque) {
+            wgpu::CompositeAlphaMode::Opaque
+        } else {
+            capabilities.alpha_modes.first().copied().unwrap_or_default()
+        };
 
         let config = wgpu::SurfaceConfiguration {
             usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
-            format: wgpu::TextureFormat::Bgra8UnormSrgb,
+            format,
             width,
             height,
             present_mode: wgpu::PresentMode::Fifo,
-            alpha_mode: wgpu::CompositeAlphaMode::Opaque,
+            alpha_mode,
             view_formats: vec![],
             desired_maximum_frame_latency: 2,
         };
 
-        surface.configure(device.as_ref(), &config);
+        surface.configure(device, &config);
         
         self.surface = Some(surface);
         self.config = Some(config);
@@ -108,18 +352,32 @@ impl GPUBackend {
     }
 
     /// Get device reference
-    pub fn device(&self) -> Result<&wgpu::Device, String> {
+    ///
+    /// # Returns
+    /// `HelionError::DeviceLost` once the device has been lost; the backend
+    /// must then be recreated
+    pub fn device(&self) -> Result<&wgpu::Device, HelionError> {
+        if let Some(reason) = self.lost.lock().unwrap().as_ref() {
+            return Err(HelionError::DeviceLost(reason.clone()));
+        }
         self.device
             .as_ref()
             .map(|d| d.as_ref())
-            .ok_or("Device not initialized".to_string())
+            .ok_or(HelionError::NotInitialized("device"))
     }
 
     /// Get queue reference
-    pub fn queue(&self) -> Result<&wgpu::Queue, String> {
+    pub fn queue(&self) -> Result<&wgpu::Queue, HelionError> {
         self.queue
             .as_ref()
             .map(|q| q.as_ref())
-            .ok_or("Queue not initialized".to_string())
+            .ok_or(HelionError::NotInitialized("queue"))
+    }
+
+    /// Configured surface and its configuration
+    pub fn surface(&self) -> Result<(&wgpu::Surface<'static>, &wgpu::SurfaceConfiguration), HelionError> {
+        let surface = self.surface.as_ref().ok_or(HelionError::NotInitialized("surface"))?;
+        let config = self.config.as_ref().ok_or(HelionError::NotInitialized("surface configuration"))?;
+        Ok((surface, config))
     }
 }
@@ -0,0 +1,304 @@
+use std::collections::HashMap;
+
+use crate::camera::Camera2D;
+use crate::data::{ChartData, Color, Point2D};
+use crate::polygon::Polygon;
+
+/// Shortest lasso segment kept while dragging, in pixels
+const LASSO_MIN_STEP: f32 = 2.0;
+
+/// Width of the outline drawn around a brush, in pixels
+const OUTLINE_WIDTH: f32 = 1.0;
+
+/// Identifier for a shared data source
+///
+/// Subplots that plot the same underlying rows should be registered with the
+/// same `DataSourceId` so that point index `i` refers to the same record in
+/// each of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DataSourceId(pub u64);
+
+/// Identifier for a subplot participating in linked brushing
+pub type SubplotId = usize;
+
+/// Set of selected point indices (sorted, without duplicates)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Selection {
+    indices: Vec<usize>,
+}
+
+impl Selection {
+    pub fn new(mut indices: Vec<usize>) -> Self {
+        indices.sort_unstable();
+        indices.dedup();
+        Self { indices }
+    }
+
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        self.indices.binary_search(&index).is_ok()
+    }
+}
+
+/// Registry that links selections across subplots sharing a data source
+///
+/// Brushing in one subplot stores the selection against that subplot's data
+/// source; every other subplot registered with the same source sees it too.
+///
+/// # Example
+/// ```
+/// use helion_core::brush::{BrushLink, DataSourceId, Selection};
+///
+/// let mut link = BrushLink::new();
+/// link.register(0, DataSourceId(1));
+/// link.register(1, DataSourceId(1));
+/// link.register(2, DataSourceId(2));
+///
+/// // Brushing subplot 0 updates subplots 0 and 1, but not 2
+/// let affected = link.brush(0, Selection::new(vec![3, 4]));
+/// assert_eq!(affected, vec![0, 1]);
+/// assert!(link.selection_for(1).unwrap().contains(3));
+/// assert!(link.selection_for(2).is_none());
+/// ```
+#[derive(Debug, Default)]
+pub struct BrushLink {
+    members: HashMap<SubplotId, DataSourceId>,
+    selections: HashMap<DataSourceId, Selection>,
+}
+
+impl BrushLink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a subplot as displaying rows from `source`
+    pub fn register(&mut self, subplot: SubplotId, source: DataSourceId) {
+        self.members.insert(subplot, source);
+    }
+
+    /// Remove a subplot from the link
+    pub fn unregister(&mut self, subplot: SubplotId) {
+        self.members.remove(&subplot);
+    }
+
+    /// Record a selection made in `subplot`
+    ///
+    /// # Returns
+    /// The subplots (sorted, including `subplot` itself) whose highlighting
+    /// changed and need a redraw. Empty if `subplot` is not registered.
+    pub fn brush(&mut self, subplot: SubplotId, selection: Selection) -> Vec<SubplotId> {
+        let Some(&source) = self.members.get(&subplot) else {
+            return Vec::new();
+        };
+        self.selections.insert(source, selection);
+        self.linked_subplots(source)
+    }
+
+    /// Clear the selection shared by `subplot`, returning affected subplots
+    pub fn clear(&mut self, subplot: SubplotId) -> Vec<SubplotId> {
+        let Some(&source) = self.members.get(&subplot) else {
+            return Vec::new();
+        };
+        self.selections.remove(&source);
+        self.linked_subplots(source)
+    }
+
+    /// Current selection visible in `subplot`, if any
+    pub fn selection_for(&self, subplot: SubplotId) -> Option<&Selection> {
+        let source = self.members.get(&subplot)?;
+        self.selections.get(source)
+    }
+
+    fn linked_subplots(&self, source: DataSourceId) -> Vec<SubplotId> {
+        let mut subplots: Vec<SubplotId> = self
+            .members
+            .iter()
+            .filter(|(_, s)| **s == source)
+            .map(|(id, _)| *id)
+            .collect();
+        subplots.sort_unstable();
+        subplots
+    }
+}
+
+/// Recolor the selected points of `data` with `highlight`
+///
+/// Indices beyond the number of vertices are ignored. Callers keep the
+/// original `ChartData` around and re-apply on each selection change.
+pub fn apply_highlight(data: &mut ChartData, selection: &Selection, highlight: Color) {
+    let color = [highlight.r, highlight.g, highlight.b, highlight.a];
+    for &index in selection.indices() {
+        if let Some(vertex) = data.vertices.get_mut(index) {
+            vertex.color = color;
+        }
+    }
+}
+
+/// Shape dragged out to select points, in window pixels (origin at the top
+/// left)
+///
+/// A point is selected when its center, as drawn through the camera, is
+/// inside the shape; marker size doesn't matter.
+///
+/// # Example
+/// ```
+/// use helion_core::brush::BrushShape;
+/// use helion_core::camera::Camera2D;
+/// use helion_core::data::ChartData;
+///
+/// // Points at pixels (0, 100), (50, 50) and (100, 0)
+/// let data = ChartData::from_scatter(&[0.0, 1.0, 2.0], &[0.0, 1.0, 2.0], None, None, 100.0, 100.0);
+/// let mut brush = BrushShape::rect([40.0, 60.0]);
+/// brush.extend_to([110.0, -10.0]);
+/// assert_eq!(brush.select(&data, &Camera2D::identity()).indices(), [1, 2]);
+///
+/// let mut lasso = BrushShape::lasso([-10.0, 110.0]);
+/// for point in [[60.0, 110.0], [60.0, 40.0]] {
+///     lasso.extend_to(point);
+/// }
+/// assert_eq!(lasso.select(&data, &Camera2D::identity()).indices(), [0, 1]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum BrushShape {
+    /// Axis-aligned box between two corners
+    Rect { from: [f32; 2], to: [f32; 2] },
+    /// Freehand outline, closed back to its first point
+    Lasso(Vec<[f32; 2]>),
+}
+
+impl BrushShape {
+    /// Box starting at `start`, e.g. where a drag began
+    pub fn rect(start: [f32; 2]) -> Self {
+        BrushShape::Rect { from: start, to: start }
+    }
+
+    /// Lasso starting at `start`
+    pub fn lasso(start: [f32; 2]) -> Self {
+        BrushShape::Lasso(vec![start])
+    }
+
+    /// Follow the cursor to `point`: move the box's far corner, or add
+    /// `point` to the lasso unless it is too close to the last one
+    ///
+    /// # Returns
+    /// Whether the shape changed
+    pub fn extend_to(&mut self, point: [f32; 2]) -> bool {
+        match self {
+            BrushShape::Rect { to, .. } => std::mem::replace(to, point) != point,
+            BrushShape::Lasso(path) => {
+                let far_enough = path
+                    .last()
+                    .is_none_or(|last| (last[0] - point[0]).hypot(last[1] - point[1]) >= LASSO_MIN_STEP);
+                if far_enough {
+                    path.push(point);
+                }
+                far_enough
+            }
+        }
+    }
+
+    /// Corners of the outline, in order
+    pub fn outline(&self) -> Vec<[f32; 2]> {
+        match self {
+            BrushShape::Rect { from, to } => vec![*from, [to[0], from[1]], *to, [from[0], to[1]]],
+            BrushShape::Lasso(path) => path.clone(),
+        }
+    }
+
+    /// Whether pixel (`x`, `y`) is inside the shape; lassos use the
+    /// even-odd rule where they cross themselves
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        match self {
+            BrushShape::Rect { from, to } => {
+                x >= from[0].min(to[0]) && x <= from[0].max(to[0]) && y >= from[1].min(to[1]) && y <= from[1].max(to[1])
+            }
+            BrushShape::Lasso(path) => path.len() >= 3 && self.polygon().contains(Point2D::new(x, y)),
+        }
+    }
+
+    fn polygon(&self) -> Polygon {
+        Polygon::new(self.outline().into_iter().map(|[x, y]| Point2D::new(x, y)).collect())
+    }
+
+    /// Vertices of `data` inside the shape when drawn through `camera`
+    pub fn select(&self, data: &ChartData, camera: &Camera2D) -> Selection {
+        let positions = data.vertices.iter().map(|vertex| vertex.position);
+        self.select_positions(positions, camera, [data.viewport_width, data.viewport_height])
+    }
+
+    /// Indices of the clip-space `positions` inside the shape when drawn
+    /// through `camera` in a `viewport`-sized window
+    pub fn select_positions(
+        &self,
+        positions: impl IntoIterator<Item = [f32; 2]>,
+        camera: &Camera2D,
+        viewport: [f32; 2],
+    ) -> Selection {
+        let [width, height] = [viewport[0].max(1.0), viewport[1].max(1.0)];
+        // Test the lasso's bounding box first; most points fail it
+        let outline = self.outline();
+        let (min, max) = outline.iter().fold(
+            ([f32::INFINITY; 2], [f32::NEG_INFINITY; 2]),
+            |(min, max), p| ([min[0].min(p[0]), min[1].min(p[1])], [max[0].max(p[0]), max[1].max(p[1])]),
+        );
+        let polygon = matches!(self, BrushShape::Lasso(path) if path.len() >= 3).then(|| self.polygon());
+        let indices = positions
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, position)| {
+                let [cx, cy] = camera.apply(position);
+                let (x, y) = ((cx + 1.0) / 2.0 * width, (1.0 - cy) / 2.0 * height);
+                let in_box = x >= min[0] && x <= max[0] && y >= min[1] && y <= max[1];
+                let inside = match (&polygon, self) {
+                    (_, BrushShape::Rect { .. }) => in_box,
+                    (Some(polygon), _) => in_box && polygon.contains(Point2D::new(x, y)),
+                    (None, _) => false,
+                };
+                inside.then_some(i)
+            })
+            .collect();
+        Selection { indices }
+    }
+
+    /// Translucent fill and outline for `MeshRenderer` in a `width` x
+    /// `height` viewport
+    pub fn chart_data(&self, color: Color, width: f32, height: f32) -> ChartData {
+        let clip = |[x, y]: [f32; 2]| Point2D::new(x / width * 2.0 - 1.0, 1.0 - y / height * 2.0);
+        let mut data = ChartData::new(width, height);
+        let outline = self.outline();
+        if outline.len() < 2 {
+            return data;
+        }
+        let fill = Color { a: color.a * 0.2, ..color };
+        for vertex in self.polygon().triangulate() {
+            data.add_point(clip([vertex.x, vertex.y]), fill, 0.0);
+        }
+
+        // Each edge as a thin quad, the lasso's closed back to its start
+        let closing = [outline[outline.len() - 1], outline[0]];
+        for [a, b] in outline.windows(2).map(|w| [w[0], w[1]]).chain(std::iter::once(closing)) {
+            let length = (b[0] - a[0]).hypot(b[1] - a[1]);
+            if length == 0.0 {
+                continue;
+            }
+            let half = OUTLINE_WIDTH / 2.0;
+            let normal = [-(b[1] - a[1]) / length * half, (b[0] - a[0]) / length * half];
+            let corner = |p: [f32; 2], side: f32| clip([p[0] + normal[0] * side, p[1] + normal[1] * side]);
+            for (p, side) in [(a, 1.0), (b, 1.0), (b, -1.0), (a, 1.0), (b, -1.0), (a, -1.0)] {
+                data.add_point(corner(p, side), color, 0.0);
+            }
+        }
+        data
+    }
+}
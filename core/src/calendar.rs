@@ -0,0 +1,230 @@
+use crate::colormap::ColormapLut;
+use crate::data::{ChartData, Color, Point2D};
+use crate::error::HelionError;
+use crate::norm::Normalization;
+use crate::scale::{civil_from_days, SECONDS_PER_DAY};
+
+const MONTH_NAMES: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+/// Days since 1970-01-01 (a Thursday) to the Sunday-based weekday, 0 for
+/// Sunday
+fn weekday(day: i64) -> usize {
+    (day + 4).rem_euclid(7) as usize
+}
+
+/// One value per day laid out as a GitHub-style calendar: a column per
+/// week, a row per weekday, colored through a colormap
+///
+/// Days are UTC calendar days, as on a time axis. Weeks start on Sunday
+/// unless `with_monday_first` is set.
+///
+/// # Example
+/// ```
+/// use helion_core::calendar::CalendarHeatmap;
+///
+/// // Friday 2024-03-01 and the Monday after, as Unix timestamps
+/// let timestamps = [1_709_251_200.0, 1_709_251_200.0 + 3600.0, 1_709_510_400.0];
+/// let calendar = CalendarHeatmap::new(&timestamps, &[2.0, 3.0, 1.0]).unwrap();
+/// assert_eq!(calendar.values(), [5.0, 0.0, 0.0, 1.0]);
+/// assert_eq!(calendar.cell(0), (0, 5));
+/// assert_eq!(calendar.cell(3), (1, 1));
+/// assert_eq!(calendar.weeks(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CalendarHeatmap {
+    /// Days since the Unix epoch of the first value
+    first_day: i64,
+    /// Sum of the values of each day from `first_day` on
+    values: Vec<f32>,
+    colormap: ColormapLut,
+    normalization: Normalization,
+    range: Option<(f32, f32)>,
+    empty_color: Color,
+    monday_first: bool,
+    /// Gap between cells as a fraction of the cell pitch
+    gap: f32,
+}
+
+impl CalendarHeatmap {
+    /// Calendar of the values summed by day
+    ///
+    /// # Parameters
+    /// * `timestamps` - Seconds since the Unix epoch; NaN and infinite
+    ///   timestamps are skipped
+    /// * `values` - One per timestamp; NaN values are skipped
+    ///
+    /// The calendar spans the first to the last day with a timestamp; days
+    /// in between without one hold 0.
+    ///
+    /// # Returns
+    /// `HelionError::ShapeMismatch` unless there is one value per
+    /// timestamp, or `HelionError::EmptyData` if no timestamp is finite
+    pub fn new(timestamps: &[f64], values: &[f32]) -> Result<Self, HelionError> {
+        if values.len() != timestamps.len() {
+            return Err(HelionError::ShapeMismatch {
+                what: "calendar values, one per timestamp".to_string(),
+                expected: timestamps.len(),
+                actual: values.len(),
+            });
+        }
+        let days: Vec<Option<i64>> = timestamps
+            .iter()
+            .map(|t| t.is_finite().then(|| (t / SECONDS_PER_DAY).floor() as i64))
+            .collect();
+        let (first_day, last_day) = days
+            .iter()
+            .flatten()
+            .fold(None, |span: Option<(i64, i64)>, &day| match span {
+                Some((first, last)) => Some((first.min(day), last.max(day))),
+                None => Some((day, day)),
+            })
+            .ok_or(HelionError::EmptyData)?;
+
+        let mut totals = vec![0.0; (last_day - first_day + 1) as usize];
+        for (day, &value) in days.iter().zip(values) {
+            if let (Some(day), false) = (day, value.is_nan()) {
+                totals[(day - first_day) as usize] += value;
+            }
+        }
+        Ok(Self {
+            first_day,
+            values: totals,
+            colormap: ColormapLut::default(),
+            normalization: Normalization::default(),
+            range: None,
+            empty_color: Color::new(0.92, 0.93, 0.94, 1.0),
+            monday_first: false,
+            gap: 0.15,
+        })
+    }
+
+    pub fn with_colormap(mut self, colormap: ColormapLut) -> Self {
+        self.colormap = colormap;
+        self
+    }
+
+    /// How values map onto the colormap; `Normalization::Linear` by default
+    pub fn with_normalization(mut self, normalization: Normalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Values at the two ends of the colormap, instead of the data's extent
+    pub fn with_range(mut self, vmin: f32, vmax: f32) -> Self {
+        self.range = Some((vmin, vmax));
+        self
+    }
+
+    /// Fill of days whose total is 0, as days without activity on GitHub;
+    /// light gray by default
+    pub fn with_empty_color(mut self, color: Color) -> Self {
+        self.empty_color = color;
+        self
+    }
+
+    /// Start weeks on Monday instead of Sunday
+    pub fn with_monday_first(mut self, monday_first: bool) -> Self {
+        self.monday_first = monday_first;
+        self
+    }
+
+    /// Gap between cells as a fraction of the cell pitch, 0.15 by default
+    pub fn with_gap(mut self, gap: f32) -> Self {
+        self.gap = gap.clamp(0.0, 0.9);
+        self
+    }
+
+    /// Total of each day, from the first day on
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+
+    /// Unix timestamp of the midnight starting day `index`
+    pub fn timestamp(&self, index: usize) -> f64 {
+        (self.first_day + index as i64) as f64 * SECONDS_PER_DAY
+    }
+
+    /// Row of `day`: its weekday counted from the start of the week
+    fn row(&self, day: i64) -> usize {
+        (weekday(day) + if self.monday_first { 6 } else { 0 }) % 7
+    }
+
+    /// (week column, weekday row) of day `index`; row 0 is Sunday, or
+    /// Monday with `with_monday_first`
+    pub fn cell(&self, index: usize) -> (usize, usize) {
+        let offset = self.row(self.first_day) + index;
+        (offset / 7, offset % 7)
+    }
+
+    /// Number of week columns
+    pub fn weeks(&self) -> usize {
+        self.cell(self.values.len().saturating_sub(1)).0 + 1
+    }
+
+    /// Week column each month starts in, with the month's short name, for
+    /// labels above the grid
+    ///
+    /// A month starting mid-week gets the column holding its first day; the
+    /// first month is labelled at the first column even if it began earlier.
+    pub fn month_labels(&self) -> Vec<(usize, &'static str)> {
+        (0..self.values.len())
+            .filter_map(|index| {
+                let (_, month, day) = civil_from_days(self.first_day + index as i64);
+                (day == 1 || index == 0).then(|| (self.cell(index).0, MONTH_NAMES[month as usize - 1]))
+            })
+            .collect()
+    }
+
+    /// Fill color of each day
+    pub fn fill_colors(&self) -> Vec<Color> {
+        let active: Vec<f32> = self.values.iter().map(|&v| if v == 0.0 { f32::NAN } else { v }).collect();
+        let norm = self.normalization.fit(&active, self.range);
+        self.colormap
+            .colorize(&active, &norm)
+            .into_iter()
+            .map(|[r, g, b, a]| match a {
+                0 => self.empty_color,
+                _ => Color::new(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, a as f32 / 255.0),
+            })
+            .collect()
+    }
+
+    /// Side of a square cell and the grid's top-left corner, in pixels,
+    /// for the grid centered in a `width` x `height` viewport
+    fn grid(&self, width: f32, height: f32) -> (f32, [f32; 2]) {
+        let pitch = (width / self.weeks() as f32).min(height / 7.0);
+        let origin = [(width - pitch * self.weeks() as f32) / 2.0, (height - pitch * 7.0) / 2.0];
+        (pitch, origin)
+    }
+
+    /// Pixel rectangle `[x, y, width, height]` of day `index` in a
+    /// `width` x `height` viewport, y down
+    pub fn cell_rect(&self, index: usize, width: f32, height: f32) -> [f32; 4] {
+        let (pitch, [left, top]) = self.grid(width, height);
+        let (week, row) = self.cell(index);
+        let side = pitch * (1.0 - self.gap);
+        [left + week as f32 * pitch, top + row as f32 * pitch, side, side]
+    }
+
+    /// Index of the day under pixel (`x`, `y`), if any
+    pub fn day_at(&self, x: f32, y: f32, width: f32, height: f32) -> Option<usize> {
+        let (pitch, [left, top]) = self.grid(width, height);
+        if pitch <= 0.0 || x < left || y < top {
+            return None;
+        }
+        let (week, row) = (((x - left) / pitch) as usize, ((y - top) / pitch) as usize);
+        let index = (week * 7 + row).checked_sub(self.row(self.first_day))?;
+        (row < 7 && index < self.values.len()).then_some(index)
+    }
+
+    /// Day cells for `MeshRenderer` in a `width` x `height` viewport
+    pub fn chart_data(&self, width: f32, height: f32) -> ChartData {
+        let mut data = ChartData::new(width, height);
+        let clip = |x: f32, y: f32| Point2D::new(x / width * 2.0 - 1.0, 1.0 - y / height * 2.0);
+        for (index, color) in self.fill_colors().into_iter().enumerate() {
+            let [x, y, w, h] = self.cell_rect(index, width, height);
+            data.add_rect(clip(x, y + h), clip(x + w, y), color);
+        }
+        data
+    }
+}
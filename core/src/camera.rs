@@ -0,0 +1,381 @@
+use crate::transition::ViewRange;
+use wgpu::util::DeviceExt;
+
+/// Bind group index of the camera in renderers whose own uniforms are at
+/// group 0
+pub const CAMERA_GROUP: u32 = 1;
+
+/// 2D view transform applied on the GPU to positions already normalized to
+/// clip space
+///
+/// `ChartData` bakes the data range into its vertices, so without a camera
+/// every pan or zoom means rebuilding and re-uploading the whole vertex
+/// buffer. With one, the vertices stay put and only the 64-byte matrix in
+/// `CameraUniforms` changes. Point sizes and other pixel offsets are added
+/// after the transform, so markers keep their size when zooming.
+///
+/// # Example
+/// ```
+/// use helion_core::camera::Camera2D;
+/// use helion_core::transition::ViewRange;
+///
+/// // Data spans 0..100 on both axes; show only 25..75
+/// let data = ViewRange::new((0.0, 100.0), (0.0, 100.0));
+/// let camera = Camera2D::from_view(&data, &ViewRange::new((25.0, 75.0), (25.0, 75.0)));
+/// // x = 25 was normalized to -0.5 and now sits on the left edge
+/// assert_eq!(camera.apply([-0.5, 0.0]), [-1.0, 0.0]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Camera2D {
+    /// Clip-space scale per axis
+    pub scale: [f32; 2],
+    /// Clip-space offset added after scaling
+    pub translate: [f32; 2],
+}
+
+impl Default for Camera2D {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Camera2D {
+    /// Camera that leaves positions where `ChartData` put them
+    pub fn identity() -> Self {
+        Self {
+            scale: [1.0, 1.0],
+            translate: [0.0, 0.0],
+        }
+    }
+
+    /// Camera showing `view` of data that was normalized against `data`
+    ///
+    /// # Parameters
+    /// * `data` - Data range the vertex positions were normalized with, e.g.
+    ///   the range passed to `ChartData::from_scatter_with_range`
+    /// * `view` - Data range that should fill the viewport
+    ///
+    /// An empty or non-finite range on either side leaves that axis untouched.
+    pub fn from_view(data: &ViewRange, view: &ViewRange) -> Self {
+        let (sx, tx) = axis_transform(data.x, view.x);
+        let (sy, ty) = axis_transform(data.y, view.y);
+        Self {
+            scale: [sx, sy],
+            translate: [tx, ty],
+        }
+    }
+
+    /// Data range in view, for data normalized against `data`; the inverse
+    /// of `from_view`
+    ///
+    /// An axis the camera can't invert (zero or non-finite scale) reports
+    /// `data` unchanged.
+    pub fn visible_range(&self, data: &ViewRange) -> ViewRange {
+        let axis = |(min, max): (f32, f32), scale: f32, translate: f32| {
+            let span = (max - min) / scale;
+            if !span.is_finite() || scale == 0.0 {
+                return (min, max);
+            }
+            let lo = (min + max - translate * span - span) / 2.0;
+            (lo, lo + span)
+        };
+        ViewRange::new(
+            axis(data.x, self.scale[0], self.translate[0]),
+            axis(data.y, self.scale[1], self.translate[1]),
+        )
+    }
+
+    /// Map a clip-space position through the camera, as the vertex shader does
+    pub fn apply(&self, position: [f32; 2]) -> [f32; 2] {
+        [
+            position[0] * self.scale[0] + self.translate[0],
+            position[1] * self.scale[1] + self.translate[1],
+        ]
+    }
+
+    /// Shift the view by `delta` in clip space (2.0 is a full viewport)
+    pub fn pan(&mut self, delta: [f32; 2]) {
+        self.translate[0] += delta[0];
+        self.translate[1] += delta[1];
+    }
+
+    /// Zoom by `factor` (> 1 zooms in) keeping the clip-space point
+    /// `anchor`, e.g. the cursor, fixed on screen
+    pub fn zoom_about(&mut self, factor: [f32; 2], anchor: [f32; 2]) {
+        for axis in 0..2 {
+            if factor[axis].is_finite() && factor[axis] > 0.0 {
+                self.scale[axis] *= factor[axis];
+                self.translate[axis] = anchor[axis] + (self.translate[axis] - anchor[axis]) * factor[axis];
+            }
+        }
+    }
+
+    /// Column-major 4x4 matrix as uploaded to the shader
+    pub fn matrix(&self) -> [[f32; 4]; 4] {
+        [
+            [self.scale[0], 0.0, 0.0, 0.0],
+            [0.0, self.scale[1], 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [self.translate[0], self.translate[1], 0.0, 1.0],
+        ]
+    }
+}
+
+/// (scale, translate) taking `data`-normalized clip coordinates to `view`
+fn axis_transform(data: (f32, f32), view: (f32, f32)) -> (f32, f32) {
+    let view_span = view.1 - view.0;
+    let data_span = data.1 - data.0;
+    if !(view_span.is_finite() && data_span.is_finite()) || view_span == 0.0 || data_span == 0.0 {
+        return (1.0, 0.0);
+    }
+    (data_span / view_span, (data.0 + data.1 - view.0 - view.1) / view_span)
+}
+
+/// Uniform block layout of `camera` in the shaders (64 bytes)
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct CameraUniforms {
+    pub view: [[f32; 4]; 4],
+}
+
+impl From<&Camera2D> for CameraUniforms {
+    fn from(camera: &Camera2D) -> Self {
+        Self { view: camera.matrix() }
+    }
+}
+
+/// Uniform buffer and bind group holding a renderer's camera
+///
+/// Renderers add `layout()` to their pipeline layout, at `CAMERA_GROUP` if
+/// they have uniforms of their own, and bind `bind_group()` there before
+/// drawing.
+pub struct CameraBinding {
+    layout: wgpu::BindGroupLayout,
+    buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+    camera: Camera2D,
+}
+
+impl CameraBinding {
+    /// Buffer initialized to the identity camera
+    pub fn new(device: &wgpu::Device) -> Self {
+        let camera = Camera2D::identity();
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Camera Bind Group Layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+        let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Camera Uniform Buffer"),
+            contents: bytemuck::bytes_of(&CameraUniforms::from(&camera)),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Camera Bind Group"),
+            layout: &layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: buffer.as_entire_binding(),
+            }],
+        });
+        Self {
+            layout,
+            buffer,
+            bind_group,
+            camera,
+        }
+    }
+
+    /// Upload `camera` if it changed; returns the number of bytes written
+    pub fn set(&mut self, queue: &wgpu::Queue, camera: &Camera2D) -> u64 {
+        if *camera == self.camera {
+            return 0;
+        }
+        self.camera = *camera;
+        queue.write_buffer(&self.buffer, 0, bytemuck::bytes_of(&CameraUniforms::from(camera)));
+        std::mem::size_of::<CameraUniforms>() as u64
+    }
+
+    pub fn camera(&self) -> &Camera2D {
+        &self.camera
+    }
+
+    pub fn layout(&self) -> &wgpu::BindGroupLayout {
+        &self.layout
+    }
+
+    pub fn bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_group
+    }
+}
+
+/// Turns mouse drags and scroll-wheel input into a `Camera2D`
+///
+/// Dragging with the button held pans; each scroll step zooms by
+/// `zoom_step` around the cursor so the point under it stays put. Positions
+/// are window pixels with the origin at the top left, as windowing toolkits
+/// report them. Every input method returns whether the camera changed, i.e.
+/// whether the caller needs to upload it and redraw.
+///
+/// # Example
+/// ```
+/// use helion_core::camera::PanZoom;
+///
+/// let mut controller = PanZoom::new(800.0, 600.0);
+/// controller.cursor_moved(400.0, 300.0);
+/// controller.set_dragging(true);
+/// // Drag a quarter of the width to the right
+/// assert!(controller.cursor_moved(600.0, 300.0));
+/// assert_eq!(controller.camera().translate, [0.5, 0.0]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PanZoom {
+    camera: Camera2D,
+    viewport: [f32; 2],
+    /// Last cursor position in clip space, if the cursor is over the window
+    cursor: Option<[f32; 2]>,
+    dragging: bool,
+    /// Zoom factor per scroll step
+    pub zoom_step: f32,
+    /// Smallest and largest allowed `Camera2D::scale`
+    pub scale_limits: (f32, f32),
+    /// Viewport the data was normalized for, when its proportions are kept
+    aspect_base: Option<[f32; 2]>,
+}
+
+impl PanZoom {
+    /// Controller for a `width` x `height` pixel window, starting unzoomed
+    pub fn new(width: f32, height: f32) -> Self {
+        Self {
+            camera: Camera2D::identity(),
+            viewport: [width.max(1.0), height.max(1.0)],
+            cursor: None,
+            dragging: false,
+            zoom_step: 1.1,
+            scale_limits: (1e-3, 1e5),
+            aspect_base: None,
+        }
+    }
+
+    /// Keep the proportions of data normalized for a `width` x `height`
+    /// viewport, whatever the window's size
+    ///
+    /// Vertices are baked into clip space, so a window of another shape
+    /// stretches them. With this the camera scales them back down on one
+    /// axis, fitting the data inside the window with margins either side
+    /// instead. Pans and zooms apply on top of that.
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::camera::PanZoom;
+    ///
+    /// // Square data in a window twice as wide as it is tall
+    /// let controller = PanZoom::new(800.0, 400.0).with_equal_aspect(400.0, 400.0);
+    /// assert_eq!(controller.camera().scale, [0.5, 1.0]);
+    /// ```
+    pub fn with_equal_aspect(mut self, width: f32, height: f32) -> Self {
+        let old = self.aspect_scale();
+        self.aspect_base = Some([width.max(1.0), height.max(1.0)]);
+        self.rescale_aspect(old);
+        self
+    }
+
+    /// Per-axis scale fitting the aspect base into the viewport, 1 on both
+    /// axes without one
+    fn aspect_scale(&self) -> [f32; 2] {
+        let Some(base) = self.aspect_base else {
+            return [1.0, 1.0];
+        };
+        let fit = (self.viewport[0] / base[0]).min(self.viewport[1] / base[1]);
+        [fit * base[0] / self.viewport[0], fit * base[1] / self.viewport[1]]
+    }
+
+    /// Swap the aspect scale `old` in the camera for the current one,
+    /// about the viewport's center so a panned view stays put; returns
+    /// whether it changed
+    fn rescale_aspect(&mut self, old: [f32; 2]) -> bool {
+        let new = self.aspect_scale();
+        self.camera.zoom_about([new[0] / old[0], new[1] / old[1]], [0.0, 0.0]);
+        new != old
+    }
+
+    pub fn camera(&self) -> &Camera2D {
+        &self.camera
+    }
+
+    /// Keep pixel to clip-space conversion right after the window is
+    /// resized, and with `with_equal_aspect`, the data's proportions
+    pub fn resize(&mut self, width: f32, height: f32) -> bool {
+        let old = self.aspect_scale();
+        self.viewport = [width.max(1.0), height.max(1.0)];
+        self.rescale_aspect(old)
+    }
+
+    /// Start (button pressed) or stop (button released) panning
+    pub fn set_dragging(&mut self, dragging: bool) {
+        self.dragging = dragging;
+    }
+
+    /// The cursor moved to pixel (`x`, `y`); pans if a drag is in progress
+    pub fn cursor_moved(&mut self, x: f32, y: f32) -> bool {
+        let position = [2.0 * x / self.viewport[0] - 1.0, 1.0 - 2.0 * y / self.viewport[1]];
+        let previous = self.cursor.replace(position);
+        match previous {
+            Some(previous) if self.dragging && previous != position => {
+                self.camera.pan([position[0] - previous[0], position[1] - previous[1]]);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// The cursor left the window; the next move starts a fresh drag segment
+    pub fn cursor_left(&mut self) {
+        self.cursor = None;
+    }
+
+    /// Zoom by `steps` scroll steps (positive zooms in) around the cursor,
+    /// or the window center if the cursor position is unknown
+    pub fn scroll(&mut self, steps: f32) -> bool {
+        if steps == 0.0 || !steps.is_finite() {
+            return false;
+        }
+        let anchor = self.cursor.unwrap_or([0.0, 0.0]);
+        let (min, max) = self.scale_limits;
+        let factor = self.zoom_step.powf(steps);
+        let clamp = |scale: f32| (scale * factor).clamp(min, max) / scale;
+        let factor = [clamp(self.camera.scale[0]), clamp(self.camera.scale[1])];
+        if factor == [1.0, 1.0] {
+            return false;
+        }
+        self.camera.zoom_about(factor, anchor);
+        true
+    }
+
+    /// Show `camera`'s view, e.g. a step of an animated transition;
+    /// returns whether it changed
+    pub fn set_camera(&mut self, camera: Camera2D) -> bool {
+        let changed = self.camera != camera;
+        self.camera = camera;
+        changed
+    }
+
+    /// Go back to the unzoomed view
+    pub fn reset(&mut self) -> bool {
+        let unzoomed = Camera2D {
+            scale: self.aspect_scale(),
+            translate: [0.0, 0.0],
+        };
+        let changed = self.camera != unzoomed;
+        self.camera = unzoomed;
+        changed
+    }
+}
@@ -0,0 +1,55 @@
+use crate::error::HelionError;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Number of points processed between cancellation checks and progress reports
+pub const PREPARE_CHUNK: usize = 1 << 16;
+
+/// Shared flag for aborting long-running data preparation
+///
+/// Clones share the same flag: hand one to the worker building `ChartData`
+/// and keep another in the GUI (or a Ctrl-C handler) to call `cancel`.
+///
+/// # Example
+/// ```
+/// use helion_core::cancel::CancelToken;
+/// use helion_core::error::HelionError;
+/// use helion_core::ChartData;
+///
+/// let token = CancelToken::new();
+/// token.clone().cancel();
+///
+/// let x = vec![0.0; 1000];
+/// let result = ChartData::from_scatter_cancelable(
+///     &x, &x, None, None, 800.0, 600.0, None, None, &token, &mut |_| {},
+/// );
+/// assert_eq!(result.err(), Some(HelionError::Cancelled));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ask every holder of this token to stop
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// `Err(HelionError::Cancelled)` once `cancel` has been called
+    pub fn check(&self) -> Result<(), HelionError> {
+        if self.is_cancelled() {
+            Err(HelionError::Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
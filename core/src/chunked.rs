@@ -0,0 +1,678 @@
+use crate::error::HelionError;
+use crate::stats::DrawStats;
+use std::ops::Range;
+use wgpu::util::DeviceExt;
+
+/// Largest number of items that fit in one buffer and one draw call
+///
+/// Bounded by the device's `max_buffer_size`, by `u32::MAX` (draw counts are
+/// u32), and rounded down to a multiple of `granularity` so primitives are
+/// never split across chunks (3 for triangle lists, 2 for line lists, 1 for
+/// points).
+///
+/// # Returns
+/// 0 if a single primitive does not fit in a buffer
+pub fn max_items_per_chunk(max_buffer_size: u64, item_size: u64, granularity: u32) -> u64 {
+    let granularity = granularity.max(1) as u64;
+    if item_size == 0 {
+        return 0;
+    }
+    let max = (max_buffer_size / item_size).min(u32::MAX as u64);
+    max - max % granularity
+}
+
+/// Split `len` items into consecutive ranges of at most `per_chunk` items
+pub fn chunk_ranges(len: usize, per_chunk: usize) -> Vec<Range<usize>> {
+    if per_chunk == 0 {
+        return Vec::new();
+    }
+    (0..len)
+        .step_by(per_chunk)
+        .map(|start| start..(start + per_chunk).min(len))
+        .collect()
+}
+
+/// Split a strip of `len` items into ranges of at most `per_chunk` items,
+/// each starting on the last item of the previous one so that drawing the
+/// ranges one after another leaves no gap in a line strip
+pub fn strip_ranges(len: usize, per_chunk: usize) -> Vec<Range<usize>> {
+    if per_chunk < 2 || len == 0 {
+        return Vec::new();
+    }
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    loop {
+        let end = (start + per_chunk).min(len);
+        ranges.push(start..end);
+        if end == len {
+            return ranges;
+        }
+        start = end - 1;
+    }
+}
+
+/// Room to allocate for `needed` units (items or bytes) so that later
+/// updates can grow without reallocating every time: the next power of two,
+/// capped at `max` unless `needed` is larger
+///
+/// # Example
+/// ```
+/// use helion_core::chunked::grown_capacity;
+///
+/// assert_eq!(grown_capacity(600, 1 << 20), 1024);
+/// assert_eq!(grown_capacity(600, 800), 800);
+/// ```
+pub fn grown_capacity(needed: u64, max: u64) -> u64 {
+    needed.next_power_of_two().min(max).max(needed)
+}
+
+/// Write `contents` into the buffer in `slot` if it is large enough, else
+/// replace it with one that has room to grow
+///
+/// The single-buffer counterpart of `ChunkedBuffer::update`, for renderers
+/// drawing several buffers in step that can't be chunked independently.
+/// New buffers are sized to the next power of two bytes, within the
+/// device limit, and get `COPY_DST` added to `usage`. Empty `contents`
+/// leave the slot as it is.
+///
+/// # Returns
+/// Bytes written
+pub fn write_or_grow(
+    slot: &mut Option<wgpu::Buffer>,
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    label: &str,
+    contents: &[u8],
+    usage: wgpu::BufferUsages,
+) -> u64 {
+    let size = contents.len() as u64;
+    if size == 0 {
+        return 0;
+    }
+    let fits = slot
+        .as_ref()
+        .is_some_and(|buffer| buffer.size() >= size && buffer.usage().contains(wgpu::BufferUsages::COPY_DST));
+    if !fits {
+        let capacity = grown_capacity(size, device.limits().max_buffer_size);
+        *slot = Some(device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(label),
+            size: capacity,
+            usage: usage | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        }));
+    }
+    if let Some(buffer) = slot {
+        queue.write_buffer(buffer, 0, contents);
+    }
+    size
+}
+
+/// Vertex data split over as many GPU buffers as the device limits require
+///
+/// A single `wgpu::Buffer` cannot exceed `Limits::max_buffer_size` (often
+/// 256 MB-2 GB) and a draw call counts vertices in a u32, so very large
+/// series are uploaded in chunks and drawn with one call per chunk. Small
+/// series end up in a single chunk and behave exactly like a plain buffer.
+///
+/// Buffers made by `update` can hold more items than they currently do,
+/// so later updates with up to that many items reuse them.
+pub struct ChunkedBuffer {
+    /// Each buffer with the number of items it holds, which may be fewer
+    /// than it has room for
+    chunks: Vec<(wgpu::Buffer, u32)>,
+    len: u64,
+    byte_size: u64,
+    /// Capacity of every chunk but the last, in items
+    per_chunk: u64,
+    item_size: u64,
+    /// Whether consecutive chunks share an item (see `new_strip`)
+    strip: bool,
+}
+
+impl ChunkedBuffer {
+    /// Upload `items`, splitting as needed
+    ///
+    /// # Parameters
+    /// * `label` - Debug label shared by all chunks
+    /// * `items` - Vertex data
+    /// * `usage` - Buffer usages (e.g. `VERTEX`)
+    /// * `granularity` - Vertices per primitive, so chunks hold whole primitives
+    ///
+    /// # Returns
+    /// An error, instead of a wgpu validation panic, if one primitive is
+    /// larger than the device's buffer size limit
+    pub fn new<T: bytemuck::Pod>(
+        device: &wgpu::Device,
+        label: &str,
+        items: &[T],
+        usage: wgpu::BufferUsages,
+        granularity: u32,
+    ) -> Result<Self, HelionError> {
+        let item_size = std::mem::size_of::<T>() as u64;
+        let max_buffer_size = device.limits().max_buffer_size;
+        let per_chunk = max_items_per_chunk(max_buffer_size, item_size, granularity);
+        if per_chunk == 0 && !items.is_empty() {
+            return Err(HelionError::BufferTooLarge(format!(
+                "{}: a primitive of {} x {} bytes exceeds the device buffer limit of {} bytes",
+                label, granularity, item_size, max_buffer_size
+            )));
+        }
+
+        let ranges = chunk_ranges(items.len(), per_chunk as usize);
+        Ok(Self::from_ranges(device, label, items, usage, ranges, per_chunk, false))
+    }
+
+    /// Upload the vertices of a line strip, splitting as needed
+    ///
+    /// Chunks overlap by one vertex, so the strip continues across buffer
+    /// boundaries instead of losing a segment at each. The result can't be
+    /// updated with `write`.
+    pub fn new_strip<T: bytemuck::Pod>(
+        device: &wgpu::Device,
+        label: &str,
+        items: &[T],
+        usage: wgpu::BufferUsages,
+    ) -> Result<Self, HelionError> {
+        let item_size = std::mem::size_of::<T>() as u64;
+        let max_buffer_size = device.limits().max_buffer_size;
+        let per_chunk = max_items_per_chunk(max_buffer_size, item_size, 1);
+        if per_chunk < 2 && items.len() > 1 {
+            return Err(HelionError::BufferTooLarge(format!(
+                "{}: a segment of 2 x {} bytes exceeds the device buffer limit of {} bytes",
+                label, item_size, max_buffer_size
+            )));
+        }
+
+        let ranges = strip_ranges(items.len(), per_chunk as usize);
+        Ok(Self::from_ranges(device, label, items, usage, ranges, per_chunk, true))
+    }
+
+    fn from_ranges<T: bytemuck::Pod>(
+        device: &wgpu::Device,
+        label: &str,
+        items: &[T],
+        usage: wgpu::BufferUsages,
+        ranges: Vec<Range<usize>>,
+        per_chunk: u64,
+        strip: bool,
+    ) -> Self {
+        let item_size = std::mem::size_of::<T>() as u64;
+        if ranges.len() > 1 {
+            log::info!("{}: splitting {} items into {} buffers", label, items.len(), ranges.len());
+        }
+
+        let chunks = ranges
+            .into_iter()
+            .map(|range| {
+                let count = range.len() as u32;
+                let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some(label),
+                    contents: bytemuck::cast_slice(&items[range]),
+                    usage,
+                });
+                (buffer, count)
+            })
+            .collect();
+
+        Self {
+            chunks,
+            len: items.len() as u64,
+            byte_size: item_size * items.len() as u64,
+            per_chunk,
+            item_size,
+            strip,
+        }
+    }
+
+    /// Allocate zeroed buffers for `len` items of type `T`, to be filled
+    /// piece by piece with `write`
+    ///
+    /// For data too large to hold in RAM at once (e.g. memory-mapped files):
+    /// only the piece being written has to be resident. `COPY_DST` is added
+    /// to `usage`.
+    pub fn new_empty<T: bytemuck::Pod>(
+        device: &wgpu::Device,
+        label: &str,
+        len: u64,
+        usage: wgpu::BufferUsages,
+        granularity: u32,
+    ) -> Result<Self, HelionError> {
+        let item_size = std::mem::size_of::<T>() as u64;
+        let max_buffer_size = device.limits().max_buffer_size;
+        let per_chunk = max_items_per_chunk(max_buffer_size, item_size, granularity);
+        if per_chunk == 0 && len > 0 {
+            return Err(HelionError::BufferTooLarge(format!(
+                "{}: a primitive of {} x {} bytes exceeds the device buffer limit of {} bytes",
+                label, granularity, item_size, max_buffer_size
+            )));
+        }
+
+        let mut chunks = Vec::new();
+        let mut start = 0;
+        while start < len {
+            let count = per_chunk.min(len - start);
+            let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some(label),
+                size: count * item_size,
+                usage: usage | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+            chunks.push((buffer, count as u32));
+            start += count;
+        }
+        if chunks.len() > 1 {
+            log::info!("{}: allocating {} items in {} buffers", label, len, chunks.len());
+        }
+
+        Ok(Self {
+            chunks,
+            len,
+            byte_size: item_size * len,
+            per_chunk,
+            item_size,
+            strip: false,
+        })
+    }
+
+    /// Write `items` starting at item index `first`, across chunk boundaries
+    /// if needed
+    ///
+    /// # Returns
+    /// An error if `T` is not the item type the buffer was created with, is
+    /// not a multiple of 4 bytes, or the items would run past the end, or if
+    /// the buffer was made by `new_strip`
+    pub fn write<T: bytemuck::Pod>(&self, queue: &wgpu::Queue, first: u64, items: &[T]) -> Result<(), HelionError> {
+        if self.strip {
+            return Err(HelionError::InvalidOption(
+                "Strip buffers share vertices between chunks and can't be written in place".to_string(),
+            ));
+        }
+        if std::mem::size_of::<T>() as u64 != self.item_size {
+            return Err(HelionError::ShapeMismatch {
+                what: "Buffer item size in bytes".to_string(),
+                expected: self.item_size as usize,
+                actual: std::mem::size_of::<T>(),
+            });
+        }
+        if !self.item_size.is_multiple_of(wgpu::COPY_BUFFER_ALIGNMENT) {
+            return Err(HelionError::InvalidOption(format!(
+                "Cannot write {}-byte items: writes must be 4-byte aligned",
+                self.item_size
+            )));
+        }
+        if first + items.len() as u64 > self.len {
+            return Err(HelionError::InvalidOption(format!(
+                "Write of {} items at {} overflows a buffer of {} items",
+                items.len(),
+                first,
+                self.len
+            )));
+        }
+
+        let mut written = 0usize;
+        while written < items.len() {
+            let index = first + written as u64;
+            let (chunk, offset) = ((index / self.per_chunk) as usize, index % self.per_chunk);
+            let (buffer, count) = &self.chunks[chunk];
+            let n = ((*count as u64 - offset) as usize).min(items.len() - written);
+            queue.write_buffer(
+                buffer,
+                offset * self.item_size,
+                bytemuck::cast_slice(&items[written..written + n]),
+            );
+            written += n;
+        }
+        Ok(())
+    }
+
+    /// Replace the contents of `slot` with `items`, reusing its buffers when
+    /// they have room
+    ///
+    /// Renderers that get new data every frame keep their buffer and call
+    /// this rather than `new`: data that fits what was allocated is written
+    /// with `queue.write_buffer`, and only outgrowing it allocates again,
+    /// with room for the next power of two items (within the device limit)
+    /// so steadily growing data doesn't reallocate every time. Empty `items`
+    /// keep the buffers allocated but draw nothing; an empty `slot` stays
+    /// `None`.
+    ///
+    /// # Parameters
+    /// * `slot` - The renderer's buffer, `None` before the first upload
+    /// * `label`, `usage`, `granularity` - As for `new`; `COPY_DST` is added
+    ///   to `usage`
+    ///
+    /// # Returns
+    /// Bytes written, or an error if one primitive is larger than the
+    /// device's buffer size limit or `T` isn't a multiple of 4 bytes
+    ///
+    /// # Example
+    /// ```no_run
+    /// use helion_core::chunked::ChunkedBuffer;
+    /// # fn frame(device: &wgpu::Device, queue: &wgpu::Queue, frames: &[Vec<[f32; 2]>]) -> Result<(), helion_core::error::HelionError> {
+    /// let mut buffer = None;
+    /// for points in frames {
+    ///     // Allocates on the first frame and whenever the data outgrows it
+    ///     ChunkedBuffer::update(&mut buffer, device, queue, "Points", points, wgpu::BufferUsages::VERTEX, 1)?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update<T: bytemuck::Pod>(
+        slot: &mut Option<Self>,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        label: &str,
+        items: &[T],
+        usage: wgpu::BufferUsages,
+        granularity: u32,
+    ) -> Result<u64, HelionError> {
+        Self::update_ranges(slot, device, queue, label, items, usage, granularity, false)
+    }
+
+    /// `update`, logging the error and dropping the buffers if it fails
+    ///
+    /// For `WindowRenderer` implementations, which have no way to report errors.
+    ///
+    /// # Returns
+    /// Bytes written
+    pub fn update_or_log<T: bytemuck::Pod>(
+        slot: &mut Option<Self>,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        label: &str,
+        items: &[T],
+        usage: wgpu::BufferUsages,
+        granularity: u32,
+    ) -> u64 {
+        Self::update(slot, device, queue, label, items, usage, granularity).unwrap_or_else(|e| {
+            log::error!("{}", e);
+            *slot = None;
+            0
+        })
+    }
+
+    /// `update` for the vertices of a line strip; chunks overlap as with
+    /// `new_strip`
+    pub fn update_strip<T: bytemuck::Pod>(
+        slot: &mut Option<Self>,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        label: &str,
+        items: &[T],
+        usage: wgpu::BufferUsages,
+    ) -> Result<u64, HelionError> {
+        Self::update_ranges(slot, device, queue, label, items, usage, 1, true)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn update_ranges<T: bytemuck::Pod>(
+        slot: &mut Option<Self>,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        label: &str,
+        items: &[T],
+        usage: wgpu::BufferUsages,
+        granularity: u32,
+        strip: bool,
+    ) -> Result<u64, HelionError> {
+        let item_size = std::mem::size_of::<T>() as u64;
+        if !item_size.is_multiple_of(wgpu::COPY_BUFFER_ALIGNMENT) {
+            return Err(HelionError::InvalidOption(format!(
+                "{}: {}-byte items can't be written in place",
+                label, item_size
+            )));
+        }
+        let max_buffer_size = device.limits().max_buffer_size;
+        let per_chunk = max_items_per_chunk(max_buffer_size, item_size, granularity);
+        let ranges = if strip {
+            if per_chunk < 2 && items.len() > 1 {
+                return Err(HelionError::BufferTooLarge(format!(
+                    "{}: a segment of 2 x {} bytes exceeds the device buffer limit of {} bytes",
+                    label, item_size, max_buffer_size
+                )));
+            }
+            strip_ranges(items.len(), per_chunk as usize)
+        } else {
+            if per_chunk == 0 && !items.is_empty() {
+                return Err(HelionError::BufferTooLarge(format!(
+                    "{}: a primitive of {} x {} bytes exceeds the device buffer limit of {} bytes",
+                    label, granularity, item_size, max_buffer_size
+                )));
+            }
+            chunk_ranges(items.len(), per_chunk as usize)
+        };
+
+        let fits = slot.as_ref().is_some_and(|buffer| buffer.has_room(&ranges, item_size, strip));
+        if !fits {
+            if items.is_empty() {
+                *slot = None;
+                return Ok(0);
+            }
+            let capacities: Vec<u64> = ranges
+                .iter()
+                .map(|range| grown_capacity(range.len() as u64, per_chunk))
+                .collect();
+            if capacities.len() > 1 {
+                log::info!("{}: allocating {} items in {} buffers", label, items.len(), capacities.len());
+            }
+            let chunks = capacities
+                .into_iter()
+                .map(|capacity| {
+                    let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+                        label: Some(label),
+                        size: capacity * item_size,
+                        usage: usage | wgpu::BufferUsages::COPY_DST,
+                        mapped_at_creation: false,
+                    });
+                    (buffer, 0)
+                })
+                .collect();
+            *slot = Some(Self {
+                chunks,
+                len: 0,
+                byte_size: 0,
+                per_chunk,
+                item_size,
+                strip,
+            });
+        }
+
+        let Some(buffer) = slot.as_mut() else {
+            return Ok(0);
+        };
+        for (index, (chunk, count)) in buffer.chunks.iter_mut().enumerate() {
+            let range = ranges.get(index).cloned().unwrap_or_default();
+            if !range.is_empty() {
+                queue.write_buffer(chunk, 0, bytemuck::cast_slice(&items[range.clone()]));
+            }
+            *count = range.len() as u32;
+        }
+        buffer.len = items.len() as u64;
+        buffer.byte_size = item_size * items.len() as u64;
+        buffer.per_chunk = per_chunk;
+        Ok(buffer.byte_size)
+    }
+
+    /// Write `items` after the last one held, into room left by `update`
+    ///
+    /// Chunks fill up in order as they would have had all the items been
+    /// passed to `update`, so later updates can still reuse them.
+    ///
+    /// # Returns
+    /// Bytes written, or an error if the items don't fit in the spare room,
+    /// aren't the buffer's item type, or the buffer was made by `new_strip`
+    pub fn append<T: bytemuck::Pod>(&mut self, queue: &wgpu::Queue, items: &[T]) -> Result<u64, HelionError> {
+        if self.strip {
+            return Err(HelionError::InvalidOption(
+                "Strip buffers share vertices between chunks and can't be appended to".to_string(),
+            ));
+        }
+        if std::mem::size_of::<T>() as u64 != self.item_size {
+            return Err(HelionError::ShapeMismatch {
+                what: "Buffer item size in bytes".to_string(),
+                expected: self.item_size as usize,
+                actual: std::mem::size_of::<T>(),
+            });
+        }
+        let room = |buffer: &wgpu::Buffer| {
+            if buffer.usage().contains(wgpu::BufferUsages::COPY_DST) {
+                (buffer.size() / self.item_size).min(self.per_chunk)
+            } else {
+                0
+            }
+        };
+        let free: u64 = self.chunks.iter().map(|(buffer, count)| room(buffer).saturating_sub(*count as u64)).sum();
+        if items.len() as u64 > free {
+            return Err(HelionError::InvalidOption(format!(
+                "{} more items don't fit in a buffer holding {} of {}",
+                items.len(),
+                self.len,
+                self.capacity()
+            )));
+        }
+        let mut rest = items;
+        for (buffer, count) in &mut self.chunks {
+            let take = (room(buffer).saturating_sub(*count as u64) as usize).min(rest.len());
+            if take > 0 {
+                let offset = *count as u64 * self.item_size;
+                queue.write_buffer(buffer, offset, bytemuck::cast_slice(&rest[..take]));
+                *count += take as u32;
+                rest = &rest[take..];
+            }
+        }
+        let written = items.len() as u64 * self.item_size;
+        self.len += items.len() as u64;
+        self.byte_size += written;
+        Ok(written)
+    }
+
+    /// Whether `ranges` of `item_size`-byte items can be written into the
+    /// existing buffers, one range per buffer
+    fn has_room(&self, ranges: &[Range<usize>], item_size: u64, strip: bool) -> bool {
+        self.item_size == item_size
+            && self.strip == strip
+            && ranges.len() <= self.chunks.len()
+            && self.chunks.iter().zip(ranges).all(|((buffer, _), range)| {
+                buffer.usage().contains(wgpu::BufferUsages::COPY_DST) && range.len() as u64 * item_size <= buffer.size()
+            })
+    }
+
+    /// Items the buffers have room for
+    pub fn capacity(&self) -> u64 {
+        self.chunks.iter().map(|(buffer, _)| buffer.size() / self.item_size).sum()
+    }
+
+    /// Upload `items`, logging and returning `None` if they can't be uploaded
+    /// or are empty
+    ///
+    /// For `WindowRenderer` implementations, which have no way to report errors.
+    pub fn upload_or_log<T: bytemuck::Pod>(
+        device: &wgpu::Device,
+        label: &str,
+        items: &[T],
+        usage: wgpu::BufferUsages,
+        granularity: u32,
+    ) -> Option<Self> {
+        if items.is_empty() {
+            return None;
+        }
+        match Self::new(device, label, items, usage, granularity) {
+            Ok(buffer) => Some(buffer),
+            Err(e) => {
+                log::error!("{}", e);
+                None
+            }
+        }
+    }
+
+    /// Total number of items across all chunks
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Total size of all chunks in bytes
+    pub fn byte_size(&self) -> u64 {
+        self.byte_size
+    }
+
+    /// Number of buffers holding items (and draw calls)
+    pub fn chunk_count(&self) -> usize {
+        self.filled().count()
+    }
+
+    /// Stats for `draw`
+    pub fn draw_stats(&self) -> DrawStats {
+        DrawStats::draws(self.len, self.chunk_count() as u32)
+    }
+
+    /// Stats for `draw_instanced` with a `mesh_vertices`-vertex mesh
+    pub fn instanced_stats(&self, mesh_vertices: u32) -> DrawStats {
+        DrawStats::instanced(mesh_vertices as u64, self.len, self.chunk_count() as u32)
+    }
+
+    /// Draw every chunk as vertices, binding each to vertex buffer `slot`
+    pub fn draw<'a>(&'a self, render_pass: &mut wgpu::RenderPass<'a>, slot: u32) {
+        for (buffer, count) in self.filled() {
+            render_pass.set_vertex_buffer(slot, buffer.slice(..*count as u64 * self.item_size));
+            render_pass.draw(0..*count, 0..1);
+        }
+    }
+
+    /// Draw only the items in `ranges`, one draw per range and chunk it
+    /// touches, binding each chunk to vertex buffer `slot`
+    ///
+    /// `ranges` index the items as uploaded, in ascending order. For a strip a
+    /// range crossing a chunk boundary continues in the next chunk without a
+    /// break.
+    pub fn draw_ranges<'a>(&'a self, render_pass: &mut wgpu::RenderPass<'a>, slot: u32, ranges: &[Range<u64>]) {
+        for (buffer, count, local) in self.range_draws(ranges) {
+            render_pass.set_vertex_buffer(slot, buffer.slice(..count as u64 * self.item_size));
+            render_pass.draw(local, 0..1);
+        }
+    }
+
+    /// Stats for `draw_ranges`
+    pub fn range_stats(&self, ranges: &[Range<u64>]) -> DrawStats {
+        let draws: Vec<_> = self.range_draws(ranges).collect();
+        let vertices = draws.iter().map(|(_, _, local)| local.len() as u64).sum();
+        DrawStats::draws(vertices, draws.len() as u32)
+    }
+
+    /// Each chunk with its item count and the part of it every range covers
+    fn range_draws<'s>(&'s self, ranges: &'s [Range<u64>]) -> impl Iterator<Item = (&'s wgpu::Buffer, u32, Range<u32>)> {
+        // A strip chunk repeats the last item of the one before it
+        let overlap = u64::from(self.strip);
+        let min_len = if self.strip { 2 } else { 1 };
+        let mut first = 0u64;
+        self.filled()
+            .map(move |(buffer, count)| {
+                let start = first;
+                first += (*count as u64).saturating_sub(overlap);
+                (buffer, *count, start)
+            })
+            .flat_map(move |(buffer, count, start)| {
+                let end = start + count as u64;
+                ranges.iter().filter_map(move |range| {
+                    let (lo, hi) = (range.start.max(start), range.end.min(end));
+                    (hi >= lo + min_len).then(|| (buffer, count, (lo - start) as u32..(hi - start) as u32))
+                })
+            })
+    }
+
+    /// Draw every chunk as instances of `vertices`, binding each to `slot`
+    pub fn draw_instanced<'a>(&'a self, render_pass: &mut wgpu::RenderPass<'a>, slot: u32, vertices: Range<u32>) {
+        for (buffer, count) in self.filled() {
+            render_pass.set_vertex_buffer(slot, buffer.slice(..*count as u64 * self.item_size));
+            render_pass.draw(vertices.clone(), 0..*count);
+        }
+    }
+
+    /// Chunks holding at least one item
+    fn filled(&self) -> impl Iterator<Item = &(wgpu::Buffer, u32)> {
+        self.chunks.iter().filter(|(_, count)| *count > 0)
+    }
+}
@@ -0,0 +1,254 @@
+use crate::data::{ChartData, Color, Point2D};
+use crate::transition::ViewRange;
+use std::collections::HashMap;
+
+/// How nearby points are grouped into clusters
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClusterMode {
+    /// Bucket points into square screen cells of `cell_px` pixels
+    Grid { cell_px: f32 },
+    /// Lloyd's k-means with at most `k` clusters, deterministically seeded
+    /// and run for a fixed number of iterations
+    KMeans { k: usize, iterations: usize },
+}
+
+impl Default for ClusterMode {
+    fn default() -> Self {
+        ClusterMode::Grid { cell_px: 40.0 }
+    }
+}
+
+/// Clustering configuration
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClusterOptions {
+    pub mode: ClusterMode,
+    /// Groups smaller than this are shown as individual points
+    pub min_points: usize,
+    /// Glyph size of a single point in pixels
+    pub point_size: f32,
+    /// Upper bound on a cluster glyph's size in pixels
+    pub max_glyph_size: f32,
+}
+
+impl Default for ClusterOptions {
+    fn default() -> Self {
+        Self {
+            mode: ClusterMode::default(),
+            min_points: 2,
+            point_size: 4.0,
+            max_glyph_size: 48.0,
+        }
+    }
+}
+
+/// A group of points drawn as one glyph
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cluster {
+    /// Centroid in data units
+    pub x: f32,
+    pub y: f32,
+    /// Indices of the member points in the input arrays
+    pub indices: Vec<usize>,
+}
+
+impl Cluster {
+    /// Number of member points
+    pub fn count(&self) -> usize {
+        self.indices.len()
+    }
+
+    fn from_members(x: &[f32], y: &[f32], indices: Vec<usize>) -> Self {
+        let n = indices.len() as f32;
+        let cx = indices.iter().map(|&i| x[i]).sum::<f32>() / n;
+        let cy = indices.iter().map(|&i| y[i]).sum::<f32>() / n;
+        Self { x: cx, y: cy, indices }
+    }
+}
+
+/// Cluster the points visible in `view`
+///
+/// Grouping happens in screen space, so the same data yields fewer, larger
+/// clusters when zoomed out and splits apart as the view narrows. Points
+/// outside the view are left out.
+///
+/// # Parameters
+/// * `x`, `y` - Point coordinates in data units
+/// * `view` - Visible data range
+/// * `width`, `height` - Viewport size in pixels
+/// * `options` - Clustering mode and thresholds
+///
+/// # Example
+/// ```
+/// use helion_core::cluster::{cluster_points, ClusterOptions};
+/// use helion_core::transition::ViewRange;
+///
+/// let x = [1.0, 1.1, 1.2, 9.0];
+/// let y = [1.0, 1.1, 1.0, 9.0];
+/// let view = ViewRange::new((0.0, 10.0), (0.0, 10.0));
+/// let clusters = cluster_points(&x, &y, &view, 400.0, 400.0, &ClusterOptions::default());
+///
+/// assert_eq!(clusters.len(), 2);
+/// assert_eq!(clusters[0].count(), 3);
+/// ```
+pub fn cluster_points(
+    x: &[f32],
+    y: &[f32],
+    view: &ViewRange,
+    width: f32,
+    height: f32,
+    options: &ClusterOptions,
+) -> Vec<Cluster> {
+    let to_px = |i: usize| {
+        (
+            (x[i] - view.x.0) / (view.x.1 - view.x.0) * width,
+            (y[i] - view.y.0) / (view.y.1 - view.y.0) * height,
+        )
+    };
+    let visible: Vec<usize> = (0..x.len().min(y.len()))
+        .filter(|&i| {
+            let (px, py) = to_px(i);
+            px >= 0.0 && px <= width && py >= 0.0 && py <= height
+        })
+        .collect();
+
+    let groups = match options.mode {
+        ClusterMode::Grid { cell_px } => grid_groups(&visible, &to_px, cell_px),
+        ClusterMode::KMeans { k, iterations } => kmeans_groups(&visible, &to_px, k, iterations),
+    };
+
+    let mut clusters = Vec::new();
+    for members in groups {
+        if members.len() >= options.min_points.max(1) {
+            clusters.push(Cluster::from_members(x, y, members));
+        } else {
+            clusters.extend(members.into_iter().map(|i| Cluster::from_members(x, y, vec![i])));
+        }
+    }
+    clusters
+}
+
+/// Group point indices by screen cell, ordered by first member
+fn grid_groups(indices: &[usize], to_px: &impl Fn(usize) -> (f32, f32), cell_px: f32) -> Vec<Vec<usize>> {
+    let cell_px = cell_px.max(1.0);
+    let mut order: Vec<(i64, i64)> = Vec::new();
+    let mut cells: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+
+    for &i in indices {
+        let (px, py) = to_px(i);
+        let key = ((px / cell_px).floor() as i64, (py / cell_px).floor() as i64);
+        cells
+            .entry(key)
+            .or_insert_with(|| {
+                order.push(key);
+                Vec::new()
+            })
+            .push(i);
+    }
+    order.into_iter().filter_map(|key| cells.remove(&key)).collect()
+}
+
+/// Group point indices with a few rounds of k-means in pixel space
+fn kmeans_groups(
+    indices: &[usize],
+    to_px: &impl Fn(usize) -> (f32, f32),
+    k: usize,
+    iterations: usize,
+) -> Vec<Vec<usize>> {
+    if indices.is_empty() || k == 0 {
+        return Vec::new();
+    }
+
+    // Deterministic farthest-point seeding: start at the overall centroid and
+    // keep adding the point farthest from every existing seed
+    let distance_sq = |(ax, ay): (f32, f32), (bx, by): (f32, f32)| (ax - bx).powi(2) + (ay - by).powi(2);
+    let mut centroids = vec![pixel_centroid(indices, to_px)];
+    while centroids.len() < k {
+        let farthest = indices
+            .iter()
+            .map(|&i| {
+                let p = to_px(i);
+                let nearest = centroids.iter().map(|&c| distance_sq(p, c)).fold(f32::INFINITY, f32::min);
+                (p, nearest)
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        match farthest {
+            Some((p, d)) if d > 0.0 => centroids.push(p),
+            _ => break,
+        }
+    }
+
+    let mut assignment = vec![0usize; indices.len()];
+    for _ in 0..iterations.max(1) {
+        for (slot, &i) in indices.iter().enumerate() {
+            let (px, py) = to_px(i);
+            assignment[slot] = centroids
+                .iter()
+                .enumerate()
+                .map(|(c, &centroid)| (c, distance_sq((px, py), centroid)))
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(c, _)| c)
+                .unwrap_or(0);
+        }
+
+        let mut sums = vec![(0.0f32, 0.0f32, 0usize); centroids.len()];
+        for (slot, &i) in indices.iter().enumerate() {
+            let (px, py) = to_px(i);
+            let sum = &mut sums[assignment[slot]];
+            *sum = (sum.0 + px, sum.1 + py, sum.2 + 1);
+        }
+        for (centroid, &(sx, sy, n)) in centroids.iter_mut().zip(&sums) {
+            if n > 0 {
+                *centroid = (sx / n as f32, sy / n as f32);
+            }
+        }
+    }
+
+    let mut groups = vec![Vec::new(); centroids.len()];
+    for (slot, &i) in indices.iter().enumerate() {
+        groups[assignment[slot]].push(i);
+    }
+    groups.retain(|g| !g.is_empty());
+    groups
+}
+
+fn pixel_centroid(members: &[usize], to_px: &impl Fn(usize) -> (f32, f32)) -> (f32, f32) {
+    let n = members.len() as f32;
+    let (sx, sy) = members.iter().fold((0.0, 0.0), |acc, &i| {
+        let (px, py) = to_px(i);
+        (acc.0 + px, acc.1 + py)
+    });
+    (sx / n, sy / n)
+}
+
+/// Glyphs for `clusters` as points in clip space
+///
+/// Glyph area grows with the member count, so a cluster of 4 points is drawn
+/// twice as wide as a single point, capped at `options.max_glyph_size`.
+pub fn cluster_chart_data(
+    clusters: &[Cluster],
+    view: &ViewRange,
+    width: f32,
+    height: f32,
+    color: Color,
+    options: &ClusterOptions,
+) -> ChartData {
+    let mut data = ChartData::new(width, height);
+    for cluster in clusters {
+        let position = Point2D::new(
+            (cluster.x - view.x.0) / (view.x.1 - view.x.0) * 2.0 - 1.0,
+            (cluster.y - view.y.0) / (view.y.1 - view.y.0) * 2.0 - 1.0,
+        );
+        let size = (options.point_size * (cluster.count() as f32).sqrt()).min(options.max_glyph_size);
+        data.add_point(position, color, size);
+    }
+    data
+}
+
+/// Count label per cluster for `labels::layout_labels`; single points get
+/// an empty label and are skipped
+pub fn count_labels(clusters: &[Cluster]) -> Vec<String> {
+    clusters
+        .iter()
+        .map(|c| if c.count() > 1 { c.count().to_string() } else { String::new() })
+        .collect()
+}
@@ -0,0 +1,682 @@
+use crate::chunked::write_or_grow;
+use crate::colorspace::ColorSpace;
+use crate::data::{ChartData, Color, Vertex};
+use crate::norm::Normalizer;
+use crate::renderer::{RenderOptions, Renderer, WindowRenderer};
+use crate::shaders::{COLORMAP_FRAGMENT_SHADER, COLORMAP_VERTEX_SHADER};
+use crate::stats::DrawStats;
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+use wgpu::util::DeviceExt;
+
+/// Number of entries in a colormap lookup table (and texels in its texture)
+pub const LUT_SIZE: usize = 256;
+
+/// Colormap sampled into 256 RGBA8 entries, ready to upload as a texture
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColormapLut {
+    pub colors: Vec<[u8; 4]>,
+}
+
+impl ColormapLut {
+    /// Build a table by linear interpolation between evenly spaced stops
+    ///
+    /// A single stop gives a constant colormap; no stops gives opaque black.
+    /// Stops are blended in raw sRGB, which keeps tables published as RGB
+    /// stops (like the built-ins) exact; use `from_stops_in` for perceptual
+    /// blending.
+    pub fn from_stops(stops: &[Color]) -> Self {
+        Self::from_stops_in(stops, ColorSpace::Srgb)
+    }
+
+    /// Build a table from evenly spaced stops, blending in `space`
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::colormap::ColormapLut;
+    /// use helion_core::colorspace::ColorSpace;
+    /// use helion_core::data::Color;
+    ///
+    /// let stops = [Color::new(1.0, 0.0, 0.0, 1.0), Color::new(0.0, 1.0, 0.0, 1.0)];
+    /// let rgb = ColormapLut::from_stops(&stops);
+    /// let oklab = ColormapLut::from_stops_in(&stops, ColorSpace::Oklab);
+    /// // Same ends, but the Oklab midpoint avoids the dark muddy brown
+    /// assert_eq!(rgb.colors[0], oklab.colors[0]);
+    /// assert!(oklab.colors[128][1] > rgb.colors[128][1]);
+    /// ```
+    pub fn from_stops_in(stops: &[Color], space: ColorSpace) -> Self {
+        let to_bytes = |c: Color| {
+            [c.r, c.g, c.b, c.a].map(|v| (v.clamp(0.0, 1.0) * 255.0).round() as u8)
+        };
+        let colors = (0..LUT_SIZE)
+            .map(|i| match stops.len() {
+                0 => [0, 0, 0, 255],
+                1 => to_bytes(stops[0]),
+                n => {
+                    let pos = i as f32 / (LUT_SIZE - 1) as f32 * (n - 1) as f32;
+                    let lo = (pos.floor() as usize).min(n - 2);
+                    let t = pos - lo as f32;
+                    to_bytes(space.mix(stops[lo], stops[lo + 1], t))
+                }
+            })
+            .collect();
+        Self { colors }
+    }
+
+    /// Black to white
+    pub fn grayscale() -> Self {
+        Self::from_stops(&[Color::new(0.0, 0.0, 0.0, 1.0), Color::new(1.0, 1.0, 1.0, 1.0)])
+    }
+
+    /// Matplotlib's perceptually uniform default, from ten control points
+    pub fn viridis() -> Self {
+        Self::from_hex_stops(&[
+            "#440154", "#482878", "#3e4989", "#31688e", "#26828e", "#1f9e89", "#35b779", "#6ece58",
+            "#b5de2b", "#fde725",
+        ])
+    }
+
+    /// Matplotlib's blue-purple-yellow sequential map
+    pub fn plasma() -> Self {
+        Self::from_hex_stops(&[
+            "#0d0887", "#41049d", "#6a00a8", "#8f0da4", "#b12a90", "#cc4778", "#e16462", "#f2844b",
+            "#fca636", "#fcce25", "#f0f921",
+        ])
+    }
+
+    /// Matplotlib's black-red-yellow sequential map, which stays readable
+    /// in grayscale
+    pub fn inferno() -> Self {
+        Self::from_hex_stops(&[
+            "#000004", "#1b0c41", "#4a0c6b", "#781c6d", "#a52c60", "#cf4446", "#ed6925", "#fb9b06",
+            "#f7d13d", "#fcffa4",
+        ])
+    }
+
+    /// Matplotlib's black-purple-cream sequential map
+    pub fn magma() -> Self {
+        Self::from_hex_stops(&[
+            "#000004", "#180f3d", "#440f76", "#721f81", "#9e2f7f", "#cd4071", "#f1605d", "#fd9668",
+            "#feca8d", "#fcfdbf",
+        ])
+    }
+
+    /// Diverging blue-white-red (Moreland's cool-warm), for data with a
+    /// meaningful midpoint; pair with `Normalizer::with_center`
+    pub fn coolwarm() -> Self {
+        Self::from_hex_stops(&[
+            "#3b4cc0", "#6282ea", "#8db0fe", "#b8d0f9", "#dddcdc", "#f5c4ac", "#f49a7b", "#de604d",
+            "#b40426",
+        ])
+    }
+
+    /// ColorBrewer's diverging red-white-blue
+    pub fn rd_bu() -> Self {
+        Self::from_hex_stops(&[
+            "#67001f", "#b2182b", "#d6604d", "#f4a582", "#fddbc7", "#f7f7f7", "#d1e5f0", "#92c5de",
+            "#4393c3", "#2166ac", "#053061",
+        ])
+    }
+
+    fn from_hex_stops(hex: &[&str]) -> Self {
+        let stops: Vec<Color> = hex.iter().map(|hex| Color::from_hex(hex)).collect();
+        Self::from_stops(&stops)
+    }
+
+    /// CPU lookup matching the shader: normalize `value` over [vmin, vmax]
+    /// and return the nearest entry
+    pub fn sample(&self, value: f32, vmin: f32, vmax: f32) -> [u8; 4] {
+        let span = vmax - vmin;
+        let t = if span != 0.0 { ((value - vmin) / span).clamp(0.0, 1.0) } else { 0.5 };
+        self.colors[(t * (LUT_SIZE - 1) as f32).round() as usize]
+    }
+
+    /// Color each value through `norm`, e.g. the cells of a heatmap
+    ///
+    /// Values the normalizer can't place (NaN, or <= 0 on a log scale) come
+    /// out fully transparent.
+    pub fn colorize(&self, values: &[f32], norm: &Normalizer) -> Vec<[u8; 4]> {
+        values
+            .iter()
+            .map(|&v| match norm.normalize(v) {
+                t if t.is_nan() => [0, 0, 0, 0],
+                t => self.sample(t, 0.0, 1.0),
+            })
+            .collect()
+    }
+}
+
+impl Default for ColormapLut {
+    fn default() -> Self {
+        Self::viridis()
+    }
+}
+
+/// Names of the colormaps that ship with Helion
+pub const BUILTIN_COLORMAPS: [&str; 7] = ["viridis", "plasma", "inferno", "magma", "grayscale", "coolwarm", "rdbu"];
+
+/// User colormaps registered with `register_colormap`, keyed by lowercase name
+fn registry() -> &'static RwLock<HashMap<String, ColormapLut>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<String, ColormapLut>>> = OnceLock::new();
+    REGISTRY.get_or_init(Default::default)
+}
+
+impl ColormapLut {
+    /// Look up a built-in or registered colormap
+    ///
+    /// Names are case-insensitive, and a `_r` suffix reverses the colormap,
+    /// as in matplotlib.
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::colormap::{register_colormap, ColormapLut};
+    ///
+    /// register_colormap("ocean_depth", &["#e0f3f8", "#4575b4", "#08306b"]).unwrap();
+    /// let lut = ColormapLut::by_name("Ocean_Depth").unwrap();
+    /// assert_eq!(lut.colors[0], [0xe0, 0xf3, 0xf8, 255]);
+    /// assert_eq!(ColormapLut::by_name("ocean_depth_r").unwrap().colors[0], [0x08, 0x30, 0x6b, 255]);
+    /// ```
+    pub fn by_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_ascii_lowercase();
+        if let Some(base) = name.strip_suffix("_r") {
+            if let Some(lut) = Self::by_name(base) {
+                return Some(lut.reversed());
+            }
+        }
+
+        Self::builtin(&name).or_else(|| registry().read().unwrap_or_else(|e| e.into_inner()).get(&name).cloned())
+    }
+
+    /// Built-in colormap by lowercase name or alias
+    fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "viridis" => Some(Self::viridis()),
+            "plasma" => Some(Self::plasma()),
+            "inferno" => Some(Self::inferno()),
+            "magma" => Some(Self::magma()),
+            "grayscale" | "gray" | "grey" => Some(Self::grayscale()),
+            "coolwarm" => Some(Self::coolwarm()),
+            "rdbu" | "rd_bu" => Some(Self::rd_bu()),
+            _ => None,
+        }
+    }
+
+    /// The same colors in the opposite order
+    pub fn reversed(&self) -> Self {
+        Self {
+            colors: self.colors.iter().rev().copied().collect(),
+        }
+    }
+}
+
+/// Register a colormap under `name` from evenly spaced hex color stops, so
+/// it can be used anywhere a colormap name is accepted
+///
+/// Stops are blended in Oklab, so lightness changes evenly along the
+/// gradient and midpoints don't go muddy; use `register_colormap_in` to pick
+/// another space. Registering an existing custom name replaces it. Built-in
+/// names (and names ending in `_r`, which are reserved for reversed maps)
+/// can't be taken.
+///
+/// # Parameters
+/// * `name` - Case-insensitive name
+/// * `stops` - At least two "#RRGGBB" or "#RRGGBBAA" colors, low to high
+///
+/// # Returns
+/// An error naming the problem if the name or a stop is invalid
+pub fn register_colormap(name: &str, stops: &[&str]) -> Result<(), String> {
+    register_colormap_in(name, stops, ColorSpace::Oklab)
+}
+
+/// `register_colormap`, blending the stops in `space`
+///
+/// `ColorSpace::Srgb` reproduces colormaps that were designed as RGB
+/// gradients elsewhere.
+pub fn register_colormap_in(name: &str, stops: &[&str], space: ColorSpace) -> Result<(), String> {
+    let key = name.trim().to_ascii_lowercase();
+    if key.is_empty() {
+        return Err("Colormap name must not be empty".to_string());
+    }
+    if key.ends_with("_r") {
+        return Err(format!("Colormap name '{}' is reserved: '_r' marks a reversed colormap", name));
+    }
+    if ColormapLut::builtin(&key).is_some() {
+        return Err(format!("Colormap '{}' is built in and can't be replaced", name));
+    }
+    if stops.len() < 2 {
+        return Err(format!("Colormap '{}' needs at least two color stops, got {}", name, stops.len()));
+    }
+
+    let stops = stops
+        .iter()
+        .map(|hex| Color::parse_hex(hex))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Colormap '{}': {}", name, e))?;
+    registry()
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(key, ColormapLut::from_stops_in(&stops, space));
+    Ok(())
+}
+
+/// Register every colormap in a JSON object mapping names to lists of hex
+/// stops, e.g. the `colormaps` section of a figure spec:
+/// `{"ocean": ["#e0f3f8", "#08306b"]}`
+///
+/// An entry can also be an object naming its blending space, as in
+/// `{"ocean": {"colors": ["#e0f3f8", "#08306b"], "space": "srgb"}}`;
+/// plain lists blend in Oklab like `register_colormap`.
+///
+/// # Returns
+/// The names registered, or the first error (earlier entries stay registered)
+pub fn register_colormaps_json(json: &str) -> Result<Vec<String>, String> {
+    let value: serde_json::Value = serde_json::from_str(json).map_err(|e| format!("Invalid colormap JSON: {}", e))?;
+    let maps = value
+        .as_object()
+        .ok_or("Colormap JSON must be an object mapping names to color lists")?;
+
+    let mut names = Vec::with_capacity(maps.len());
+    for (name, entry) in maps {
+        let (stops, space) = match entry.as_object() {
+            Some(spec) => {
+                let space = match spec.get("space") {
+                    None => ColorSpace::Oklab,
+                    Some(space) => space
+                        .as_str()
+                        .ok_or_else(|| format!("Colormap '{}': space must be a string", name))
+                        .and_then(ColorSpace::from_name)
+                        .map_err(|e| format!("Colormap '{}': {}", name, e))?,
+                };
+                (spec.get("colors"), space)
+            }
+            None => (Some(entry), ColorSpace::Oklab),
+        };
+        let stops: Vec<&str> = stops
+            .and_then(serde_json::Value::as_array)
+            .and_then(|stops| stops.iter().map(serde_json::Value::as_str).collect())
+            .ok_or_else(|| format!("Colormap '{}' must be a list of hex color strings", name))?;
+        register_colormap_in(name, &stops, space)?;
+        names.push(name.clone());
+    }
+    Ok(names)
+}
+
+/// Built-in and registered colormap names, sorted
+pub fn colormap_names() -> Vec<String> {
+    let mut names: Vec<String> = BUILTIN_COLORMAPS.iter().map(|name| name.to_string()).collect();
+    names.extend(registry().read().unwrap_or_else(|e| e.into_inner()).keys().cloned());
+    names.sort();
+    names
+}
+
+/// Uniform block layout shared with `COLORMAP_FRAGMENT_SHADER`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct ColormapUniforms {
+    pub vmin: f32,
+    pub vmax: f32,
+    pub opacity: f32,
+    pub _padding: f32,
+}
+
+impl Default for ColormapUniforms {
+    fn default() -> Self {
+        Self {
+            vmin: 0.0,
+            vmax: 1.0,
+            opacity: 1.0,
+            _padding: 0.0,
+        }
+    }
+}
+
+/// Scatter renderer that colors points on the GPU from a scalar per point
+///
+/// Values live in their own vertex buffer and are mapped to colors in the
+/// fragment shader through a 256x1 lookup texture. Switching colormaps only
+/// rewrites the 1 KB texture, and changing vmin/vmax only rewrites a 16-byte
+/// uniform, so neither touches per-point data however large the dataset is.
+///
+/// Positions come from the `ChartData` passed to `new`/`update_data`; the
+/// per-vertex colors in it are ignored. Until `set_values` is called every
+/// point maps to the bottom of the colormap. `WindowRenderer::new` has no
+/// queue to upload the lookup table with, so prefer `with_colormap`, or call
+/// `set_colormap` before the first frame.
+pub struct ColormapRenderer {
+    render_pipeline: wgpu::RenderPipeline,
+    uniform_buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+    lut_texture: wgpu::Texture,
+    vertex_buffer: Option<wgpu::Buffer>,
+    value_buffer: Option<wgpu::Buffer>,
+    vertex_count: u32,
+    value_count: u32,
+    uniforms: ColormapUniforms,
+    uploaded_bytes: u64,
+}
+
+impl ColormapRenderer {
+    /// Create a renderer with its lookup table and values already uploaded
+    pub fn with_colormap(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+        chart_data: ChartData,
+        values: &[f32],
+        lut: &ColormapLut,
+    ) -> Self {
+        let mut renderer = <Self as WindowRenderer>::new(device, config, chart_data);
+        renderer.set_colormap(queue, lut);
+        renderer.set_values(device, values);
+        renderer
+    }
+
+    /// Set the scalar value of each point (same order as the chart vertices)
+    pub fn set_values(&mut self, device: &wgpu::Device, values: &[f32]) {
+        self.value_count = values.len() as u32;
+        self.uploaded_bytes += std::mem::size_of_val(values) as u64;
+        self.value_buffer = (!values.is_empty()).then(|| {
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Colormap Value Buffer"),
+                contents: bytemuck::cast_slice(values),
+                usage: wgpu::BufferUsages::VERTEX,
+            })
+        });
+    }
+
+    /// Set values through a normalization, e.g. log or histogram-equalized
+    ///
+    /// Values are normalized on the CPU and the range set to [0, 1], so the
+    /// shader's linear lookup reproduces `ColormapLut::colorize` exactly.
+    /// Points the normalizer can't place are not drawn.
+    pub fn set_normalized_values(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        values: &[f32],
+        norm: &Normalizer,
+    ) {
+        self.set_values(device, &norm.apply(values));
+        self.set_range(queue, 0.0, 1.0);
+    }
+
+    /// Swap the colormap without touching point data
+    pub fn set_colormap(&mut self, queue: &wgpu::Queue, lut: &ColormapLut) {
+        write_lut(queue, &self.lut_texture, lut);
+        self.uploaded_bytes += (LUT_SIZE * 4) as u64;
+    }
+
+    /// Change the value range mapped onto the colormap
+    pub fn set_range(&mut self, queue: &wgpu::Queue, vmin: f32, vmax: f32) {
+        self.uniforms.vmin = vmin;
+        self.uniforms.vmax = vmax;
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&self.uniforms));
+        self.uploaded_bytes += std::mem::size_of::<ColormapUniforms>() as u64;
+    }
+
+    /// Change the opacity applied on top of the colormap's alpha
+    pub fn set_opacity(&mut self, queue: &wgpu::Queue, opacity: f32) {
+        self.uniforms.opacity = opacity;
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&self.uniforms));
+        self.uploaded_bytes += std::mem::size_of::<ColormapUniforms>() as u64;
+    }
+
+    pub fn uniforms(&self) -> &ColormapUniforms {
+        &self.uniforms
+    }
+
+    fn create_vertex_buffer(device: &wgpu::Device, vertices: &[Vertex]) -> Option<wgpu::Buffer> {
+        if vertices.is_empty() {
+            return None;
+        }
+        Some(device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Colormap Vertex Buffer"),
+            contents: bytemuck::cast_slice(vertices),
+            usage: wgpu::BufferUsages::VERTEX,
+        }))
+    }
+}
+
+/// Create an empty 256x1 lookup texture
+///
+/// A 2D texture is used rather than a 1D one because WebGL2 has no 1D
+/// textures.
+pub(crate) fn create_lut_texture(device: &wgpu::Device) -> wgpu::Texture {
+    device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("Colormap LUT Texture"),
+        size: wgpu::Extent3d {
+            width: LUT_SIZE as u32,
+            height: 1,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        // Unorm (not sRGB) so texels reach the shader exactly like vertex colors do
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+        view_formats: &[],
+    })
+}
+
+pub(crate) fn write_lut(queue: &wgpu::Queue, texture: &wgpu::Texture, lut: &ColormapLut) {
+    queue.write_texture(
+        wgpu::ImageCopyTexture {
+            texture,
+            mip_level: 0,
+            origin: wgpu::Origin3d::ZERO,
+            aspect: wgpu::TextureAspect::All,
+        },
+        bytemuck::cast_slice(&lut.colors),
+        wgpu::ImageDataLayout {
+            offset: 0,
+            bytes_per_row: Some(LUT_SIZE as u32 * 4),
+            rows_per_image: Some(1),
+        },
+        wgpu::Extent3d {
+            width: LUT_SIZE as u32,
+            height: 1,
+            depth_or_array_layers: 1,
+        },
+    );
+}
+
+impl Renderer for ColormapRenderer {
+    fn render_to_pass<'rpass>(&'rpass mut self, render_pass: &mut wgpu::RenderPass<'rpass>) {
+        if let (Some(vertices), Some(values)) = (&self.vertex_buffer, &self.value_buffer) {
+            render_pass.set_pipeline(&self.render_pipeline);
+            render_pass.set_bind_group(0, &self.bind_group, &[]);
+            render_pass.set_vertex_buffer(0, vertices.slice(..));
+            render_pass.set_vertex_buffer(1, values.slice(..));
+            render_pass.draw(0..self.vertex_count.min(self.value_count), 0..1);
+        }
+    }
+
+    fn frame_stats(&mut self) -> DrawStats {
+        let drawn = self.vertex_count.min(self.value_count);
+        let stats = if self.vertex_buffer.is_some() && self.value_buffer.is_some() && drawn > 0 {
+            DrawStats::draws(drawn as u64, 1)
+        } else {
+            DrawStats::default()
+        };
+        stats.with_bytes_uploaded(std::mem::take(&mut self.uploaded_bytes))
+    }
+}
+
+impl WindowRenderer for ColormapRenderer {
+    fn with_options(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        chart_data: ChartData,
+        options: &RenderOptions,
+    ) -> Self {
+        // WindowRenderer::new has no queue, so the LUT is filled later by
+        // set_colormap (see with_colormap)
+        let lut_texture = create_lut_texture(device);
+        let lut_view = lut_texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Colormap Sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let uniforms = ColormapUniforms::default();
+        let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Colormap Uniform Buffer"),
+            contents: bytemuck::bytes_of(&uniforms),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Colormap Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Colormap Bind Group"),
+            layout: &bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: uniform_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&lut_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Sampler(&sampler),
+                },
+            ],
+        });
+
+        let vertex_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Colormap Vertex Shader"),
+            source: wgpu::ShaderSource::Wgsl(COLORMAP_VERTEX_SHADER.into()),
+        });
+
+        let fragment_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Colormap Fragment Shader"),
+            source: wgpu::ShaderSource::Wgsl(COLORMAP_FRAGMENT_SHADER.into()),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Colormap Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        // Slot 0: only the position of each 40-byte Vertex; slot 1: one f32 per point
+        let position_layout = wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<Vertex>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &[wgpu::VertexAttribute {
+                offset: 0,
+                shader_location: 0,
+                format: wgpu::VertexFormat::Float32x2,
+            }],
+        };
+        let value_layout = wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<f32>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &[wgpu::VertexAttribute {
+                offset: 0,
+                shader_location: 1,
+                format: wgpu::VertexFormat::Float32,
+            }],
+        };
+
+        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Colormap Render Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &vertex_shader,
+                entry_point: "vs_main",
+                buffers: &[position_layout, value_layout],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: config.format,
+                    blend: options.blend_state(),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::PointList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: options.depth_stencil_state(),
+            multisample: options.multisample_state(),
+            multiview: None,
+            cache: None,
+        });
+
+        let vertex_count = chart_data.vertices.len() as u32;
+        let mut renderer = ColormapRenderer {
+            render_pipeline,
+            uniform_buffer,
+            bind_group,
+            lut_texture,
+            vertex_buffer: Self::create_vertex_buffer(device, &chart_data.vertices),
+            value_buffer: None,
+            vertex_count,
+            value_count: 0,
+            uniforms,
+            uploaded_bytes: std::mem::size_of_val(chart_data.vertices.as_slice()) as u64,
+        };
+        renderer.set_values(device, &vec![0.0; vertex_count as usize]);
+        renderer
+    }
+
+    fn update_data(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, chart_data: &ChartData) {
+        self.uploaded_bytes += write_or_grow(
+            &mut self.vertex_buffer,
+            device,
+            queue,
+            "Colormap Vertex Buffer",
+            bytemuck::cast_slice(&chart_data.vertices),
+            wgpu::BufferUsages::VERTEX,
+        );
+        self.vertex_count = chart_data.vertices.len() as u32;
+    }
+}
@@ -0,0 +1,167 @@
+use crate::data::Color;
+
+/// Space in which colors are blended when building gradients
+///
+/// Blending raw sRGB values is cheap but perceptually uneven: midpoints go
+/// muddy and dark (red to green passes through brown) and equal steps in `t`
+/// don't look like equal steps. Oklab and CIELAB are designed so that
+/// distances track perceived differences, which keeps lightness changing
+/// evenly along a gradient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorSpace {
+    /// Channel values as stored, matching most other plotting libraries
+    Srgb,
+    /// Björn Ottosson's Oklab: perceptually uniform and keeps hues stable
+    #[default]
+    Oklab,
+    /// CIE 1976 L*a*b* under a D65 white point
+    Lab,
+}
+
+impl ColorSpace {
+    /// Parse a space name ("srgb"/"rgb", "oklab", "lab"/"cielab")
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "srgb" | "rgb" => Ok(ColorSpace::Srgb),
+            "oklab" => Ok(ColorSpace::Oklab),
+            "lab" | "cielab" => Ok(ColorSpace::Lab),
+            other => Err(format!("Unknown color space '{}': expected srgb, oklab or lab", other)),
+        }
+    }
+
+    /// Blend from `a` (t = 0) to `b` (t = 1) in this space
+    ///
+    /// Alpha is always blended linearly. Results outside the sRGB gamut are
+    /// clamped.
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::colorspace::ColorSpace;
+    /// use helion_core::data::Color;
+    ///
+    /// let black = Color::new(0.0, 0.0, 0.0, 1.0);
+    /// let white = Color::new(1.0, 1.0, 1.0, 1.0);
+    /// // Perceptual mid-gray is darker than the RGB average
+    /// assert_eq!(ColorSpace::Srgb.mix(black, white, 0.5).r, 0.5);
+    /// assert!(ColorSpace::Oklab.mix(black, white, 0.5).r < 0.4);
+    /// ```
+    pub fn mix(self, a: Color, b: Color, t: f32) -> Color {
+        let alpha = lerp(a.a, b.a, t);
+        match self {
+            ColorSpace::Srgb => Color::new(lerp(a.r, b.r, t), lerp(a.g, b.g, t), lerp(a.b, b.b, t), alpha),
+            ColorSpace::Oklab => Color::from_oklab(lerp3(a.to_oklab(), b.to_oklab(), t), alpha),
+            ColorSpace::Lab => Color::from_lab(lerp3(a.to_lab(), b.to_lab(), t), alpha),
+        }
+    }
+}
+
+impl Color {
+    /// Oklab coordinates [L, a, b] of the RGB channels (L in [0, 1])
+    pub fn to_oklab(&self) -> [f32; 3] {
+        let [r, g, b] = self.linear_rgb();
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+        [
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        ]
+    }
+
+    /// Color from Oklab coordinates, clamped to the sRGB gamut
+    pub fn from_oklab([l, a, b]: [f32; 3], alpha: f32) -> Self {
+        let l_ = (l + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+        let m_ = (l - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+        let s_ = (l - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+        Self::from_linear_rgb(
+            [
+                4.076_741_7 * l_ - 3.307_711_6 * m_ + 0.230_969_94 * s_,
+                -1.268_438 * l_ + 2.609_757_4 * m_ - 0.341_319_38 * s_,
+                -0.004_196_086_3 * l_ - 0.703_418_6 * m_ + 1.707_614_7 * s_,
+            ],
+            alpha,
+        )
+    }
+
+    /// CIELAB coordinates [L*, a*, b*] under D65 (L* in [0, 100])
+    pub fn to_lab(&self) -> [f32; 3] {
+        let [r, g, b] = self.linear_rgb();
+        let x = (0.412_456_4 * r + 0.357_576_1 * g + 0.180_437_5 * b) / D65[0];
+        let y = (0.212_672_9 * r + 0.715_152_2 * g + 0.072_175 * b) / D65[1];
+        let z = (0.019_333_9 * r + 0.119_192 * g + 0.950_304_1 * b) / D65[2];
+        let (fx, fy, fz) = (lab_f(x), lab_f(y), lab_f(z));
+        [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+    }
+
+    /// Color from CIELAB coordinates, clamped to the sRGB gamut
+    pub fn from_lab([l, a, b]: [f32; 3], alpha: f32) -> Self {
+        let fy = (l + 16.0) / 116.0;
+        let x = lab_f_inv(fy + a / 500.0) * D65[0];
+        let y = lab_f_inv(fy) * D65[1];
+        let z = lab_f_inv(fy - b / 200.0) * D65[2];
+        Self::from_linear_rgb(
+            [
+                3.240_454_2 * x - 1.537_138_5 * y - 0.498_531_4 * z,
+                -0.969_266 * x + 1.876_010_8 * y + 0.041_556 * z,
+                0.055_643_4 * x - 0.204_025_9 * y + 1.057_225_2 * z,
+            ],
+            alpha,
+        )
+    }
+
+    fn linear_rgb(&self) -> [f32; 3] {
+        [self.r, self.g, self.b].map(|c| srgb_to_linear(c.clamp(0.0, 1.0)))
+    }
+
+    fn from_linear_rgb(rgb: [f32; 3], alpha: f32) -> Self {
+        let [r, g, b] = rgb.map(|c| linear_to_srgb(c.clamp(0.0, 1.0)));
+        Self::new(r, g, b, alpha)
+    }
+}
+
+/// D65 reference white in XYZ
+const D65: [f32; 3] = [0.950_47, 1.0, 1.088_83];
+
+/// Boundary between the cube-root and linear parts of the CIELAB curve
+const LAB_DELTA: f32 = 6.0 / 29.0;
+
+fn lab_f(t: f32) -> f32 {
+    if t > LAB_DELTA.powi(3) {
+        t.cbrt()
+    } else {
+        t / (3.0 * LAB_DELTA * LAB_DELTA) + 4.0 / 29.0
+    }
+}
+
+fn lab_f_inv(t: f32) -> f32 {
+    if t > LAB_DELTA {
+        t.powi(3)
+    } else {
+        3.0 * LAB_DELTA * LAB_DELTA * (t - 4.0 / 29.0)
+    }
+}
+
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.040_45 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+fn lerp3(a: [f32; 3], b: [f32; 3], t: f32) -> [f32; 3] {
+    [lerp(a[0], b[0], t), lerp(a[1], b[1], t), lerp(a[2], b[2], t)]
+}
@@ -0,0 +1,69 @@
+use crate::aggregate::{aggregate_counts, BinGrid};
+use crate::backend::{Capabilities, FeaturePath};
+use crate::compute::GpuBinner;
+
+/// 2D binning on the GPU where the device supports compute, on the CPU
+/// otherwise
+///
+/// Both paths give the same counts, so figures built on it work unchanged
+/// on WebGL2; only re-binning after a zoom gets slower.
+pub enum Aggregator {
+    Gpu(GpuBinner),
+    /// Keeps its own copy of the points, as the GPU path keeps a buffer
+    Cpu { x: Vec<f32>, y: Vec<f32> },
+}
+
+impl Aggregator {
+    /// Aggregator for `device`, choosing the path from `capabilities`
+    pub fn new(device: &wgpu::Device, capabilities: &Capabilities) -> Self {
+        match capabilities.aggregation() {
+            FeaturePath::Gpu => Aggregator::Gpu(GpuBinner::new(device)),
+            FeaturePath::Cpu => {
+                log::info!("Compute shaders unavailable; binning on the CPU");
+                Self::cpu()
+            }
+        }
+    }
+
+    /// CPU aggregator, usable without a device
+    pub fn cpu() -> Self {
+        Aggregator::Cpu { x: Vec::new(), y: Vec::new() }
+    }
+
+    pub fn path(&self) -> FeaturePath {
+        match self {
+            Aggregator::Gpu(_) => FeaturePath::Gpu,
+            Aggregator::Cpu { .. } => FeaturePath::Cpu,
+        }
+    }
+
+    /// Set the raw (data-space) point coordinates
+    pub fn set_points(&mut self, device: &wgpu::Device, x: &[f32], y: &[f32]) {
+        match self {
+            Aggregator::Gpu(binner) => binner.set_points(device, x, y),
+            Aggregator::Cpu { x: px, y: py } => {
+                let len = x.len().min(y.len());
+                *px = x[..len].to_vec();
+                *py = y[..len].to_vec();
+            }
+        }
+    }
+
+    pub fn point_count(&self) -> usize {
+        match self {
+            Aggregator::Gpu(binner) => binner.point_count() as usize,
+            Aggregator::Cpu { x, .. } => x.len(),
+        }
+    }
+
+    /// Count the points in each cell of `grid`
+    ///
+    /// The GPU path resolves once the dispatch has been read back; the CPU
+    /// path is ready immediately.
+    pub async fn bin(&self, device: &wgpu::Device, queue: &wgpu::Queue, grid: &BinGrid) -> Result<Vec<u32>, String> {
+        match self {
+            Aggregator::Gpu(binner) => binner.bin(device, queue, grid).await,
+            Aggregator::Cpu { x, y } => Ok(aggregate_counts(x, y, grid)),
+        }
+    }
+}
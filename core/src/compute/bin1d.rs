@@ -0,0 +1,277 @@
+use crate::error::HelionError;
+use crate::histogram::{edges_for, Histogram, HistogramOptions};
+use crate::shaders::HIST1D_COMPUTE_SHADER;
+use bytemuck::{Pod, Zeroable};
+use wgpu::util::DeviceExt;
+
+const WORKGROUP_SIZE: u32 = 256;
+const MAX_WORKGROUPS_PER_DIM: u32 = 65535;
+
+/// Uniform block for `HIST1D_COMPUTE_SHADER`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct HistParams {
+    pub count: u32,
+    pub bins: u32,
+    pub _padding: [u32; 2],
+}
+
+/// Counts values into histogram bins with a compute shader
+///
+/// Values are uploaded once with `set_values`; changing the bins (a new
+/// bin count, or edges fitted to a zoomed range) is then one dispatch over
+/// the resident buffer. Counts match `Histogram::compute`.
+pub struct GpuHistogram {
+    pipeline: wgpu::ComputePipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+    values: Option<wgpu::Buffer>,
+    value_count: u32,
+}
+
+impl GpuHistogram {
+    pub fn new(device: &wgpu::Device) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Hist1D Compute Shader"),
+            source: wgpu::ShaderSource::Wgsl(HIST1D_COMPUTE_SHADER.into()),
+        });
+
+        let entry = |binding, ty| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty,
+            count: None,
+        };
+        let storage = |read_only| wgpu::BindingType::Buffer {
+            ty: wgpu::BufferBindingType::Storage { read_only },
+            has_dynamic_offset: false,
+            min_binding_size: None,
+        };
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Hist1D Bind Group Layout"),
+            entries: &[
+                entry(
+                    0,
+                    wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                ),
+                entry(1, storage(true)),
+                entry(2, storage(true)),
+                entry(3, storage(false)),
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Hist1D Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Hist1D Pipeline"),
+            layout: Some(&pipeline_layout),
+            module: &shader,
+            entry_point: "cs_main",
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
+        Self {
+            pipeline,
+            bind_group_layout,
+            values: None,
+            value_count: 0,
+        }
+    }
+
+    /// Upload the raw values
+    pub fn set_values(&mut self, device: &wgpu::Device, values: &[f32]) {
+        self.value_count = values.len() as u32;
+        self.values = (!values.is_empty()).then(|| {
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Hist1D Values Buffer"),
+                contents: bytemuck::cast_slice(values),
+                usage: wgpu::BufferUsages::STORAGE,
+            })
+        });
+    }
+
+    pub fn value_count(&self) -> u32 {
+        self.value_count
+    }
+
+    /// Record a binning pass into `encoder`
+    ///
+    /// # Parameters
+    /// * `edges` - Increasing bin edges, n + 1 for n bins
+    ///
+    /// # Returns
+    /// A zero-initialized storage buffer holding n u32 counts once the
+    /// encoder is submitted (also usable as `COPY_SRC`), or `None` if no
+    /// values have been uploaded or there are no bins.
+    pub fn encode(
+        &self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        edges: &[f32],
+    ) -> Option<wgpu::Buffer> {
+        let values = self.values.as_ref()?;
+        let bins = edges.len().checked_sub(1).filter(|&bins| bins > 0)? as u32;
+
+        let params = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Hist1D Params Buffer"),
+            contents: bytemuck::bytes_of(&HistParams {
+                count: self.value_count,
+                bins,
+                _padding: [0; 2],
+            }),
+            usage: wgpu::BufferUsages::UNIFORM,
+        });
+        let edges = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Hist1D Edges Buffer"),
+            contents: bytemuck::cast_slice(edges),
+            usage: wgpu::BufferUsages::STORAGE,
+        });
+        // New buffers are zeroed by wgpu, so counts start from 0
+        let counts = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Hist1D Counts Buffer"),
+            size: bins as u64 * std::mem::size_of::<u32>() as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Hist1D Bind Group"),
+            layout: &self.bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: params.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: values.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: edges.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: counts.as_entire_binding(),
+                },
+            ],
+        });
+
+        let groups = self.value_count.div_ceil(WORKGROUP_SIZE);
+        let groups_x = groups.min(MAX_WORKGROUPS_PER_DIM);
+        let groups_y = groups.div_ceil(MAX_WORKGROUPS_PER_DIM);
+
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Hist1D Pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&self.pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.dispatch_workgroups(groups_x, groups_y, 1);
+        drop(pass);
+
+        Some(counts)
+    }
+
+    /// Count the uploaded values per bin and read the counts back
+    ///
+    /// Resolves once the GPU has finished; on native targets this blocks in
+    /// `device.poll`.
+    ///
+    /// # Returns
+    /// `HelionError::DeviceLost` if the device dropped the readback before
+    /// it completed, `HelionError::Readback` if the counts can't be mapped
+    pub async fn bin(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        edges: &[f32],
+    ) -> Result<Vec<u32>, HelionError> {
+        let bins = edges.len().saturating_sub(1);
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Hist1D Encoder"),
+        });
+        let Some(counts) = self.encode(device, &mut encoder, edges) else {
+            return Ok(vec![0; bins]);
+        };
+
+        let size = counts.size();
+        let staging = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Hist1D Staging Buffer"),
+            size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        encoder.copy_buffer_to_buffer(&counts, 0, &staging, 0, size);
+        queue.submit(Some(encoder.finish()));
+
+        let (sender, receiver) = futures::channel::oneshot::channel();
+        staging.slice(..).map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        device.poll(wgpu::Maintain::Wait);
+
+        receiver
+            .await
+            .map_err(|_| HelionError::DeviceLost("Hist1D readback was dropped before completing".to_string()))?
+            .map_err(|e| HelionError::Readback(format!("Failed to map histogram counts: {}", e)))?;
+
+        let counts = bytemuck::cast_slice(&staging.slice(..).get_mapped_range()).to_vec();
+        staging.unmap();
+        Ok(counts)
+    }
+
+    /// Upload `values` and build their histogram, like
+    /// `Histogram::compute`
+    ///
+    /// Edges come from `histogram::edges_for`, which scans the values once
+    /// on the CPU; the counting, the expensive part, runs on the GPU.
+    ///
+    /// # Returns
+    /// The readback error of `bin` if the counts can't be read back
+    pub async fn histogram(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        values: &[f32],
+        options: &HistogramOptions,
+    ) -> Result<Histogram, HelionError> {
+        let edges = edges_for(values, &options.bins);
+        if edges.len() < 2 {
+            return Ok(Histogram {
+                edges: Vec::new(),
+                values: Vec::new(),
+            });
+        }
+        self.set_values(device, values);
+        let counts = self.bin(device, queue, &edges).await?;
+        Ok(Histogram::from_counts(edges, &counts, options))
+    }
+}
+
+/// Histogram of `values`, counted on the GPU when one with compute support
+/// is available and on the CPU otherwise
+///
+/// Opens a headless device for the dispatch, so it suits one-off binning of
+/// large arrays (e.g. `helion.hist` in Python); keep a `GpuHistogram` to
+/// re-bin the same values repeatedly.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn histogram(values: &[f32], options: &HistogramOptions) -> Histogram {
+    let Some(headless) = super::compute_device() else {
+        return Histogram::compute(values, options);
+    };
+    let mut binner = GpuHistogram::new(headless.device());
+    futures::executor::block_on(binner.histogram(headless.device(), headless.queue(), values, options))
+        .unwrap_or_else(|e| {
+            log::warn!("GPU histogram failed ({}); binning on the CPU", e);
+            Histogram::compute(values, options)
+        })
+}
@@ -0,0 +1,229 @@
+use crate::aggregate::BinGrid;
+use crate::shaders::BIN2D_COMPUTE_SHADER;
+use bytemuck::{Pod, Zeroable};
+use wgpu::util::DeviceExt;
+
+const WORKGROUP_SIZE: u32 = 256;
+const MAX_WORKGROUPS_PER_DIM: u32 = 65535;
+
+/// Uniform block for `BIN2D_COMPUTE_SHADER`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct BinParams {
+    pub origin: [f32; 2],
+    pub inv_cell: [f32; 2],
+    pub dims: [u32; 2],
+    pub count: u32,
+    pub _padding: u32,
+}
+
+impl BinParams {
+    pub fn new(grid: &BinGrid, count: u32) -> Self {
+        let (cw, ch) = grid.cell_size();
+        Self {
+            origin: [grid.x_range.0, grid.y_range.0],
+            inv_cell: [1.0 / cw, 1.0 / ch],
+            dims: [grid.nx, grid.ny],
+            count,
+            _padding: 0,
+        }
+    }
+}
+
+/// Bins points into a 2D grid with a compute shader
+///
+/// Raw data coordinates are uploaded once with `set_points`; each zoom then
+/// only needs a new `BinGrid` and one dispatch, so re-binning at the visible
+/// range stays cheap even for millions of points.
+pub struct GpuBinner {
+    pipeline: wgpu::ComputePipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+    points: Option<wgpu::Buffer>,
+    point_count: u32,
+}
+
+impl GpuBinner {
+    pub fn new(device: &wgpu::Device) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Bin2D Compute Shader"),
+            source: wgpu::ShaderSource::Wgsl(BIN2D_COMPUTE_SHADER.into()),
+        });
+
+        let storage = |read_only| wgpu::BindingType::Buffer {
+            ty: wgpu::BufferBindingType::Storage { read_only },
+            has_dynamic_offset: false,
+            min_binding_size: None,
+        };
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Bin2D Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: storage(true),
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: storage(false),
+                    count: None,
+                },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Bin2D Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Bin2D Pipeline"),
+            layout: Some(&pipeline_layout),
+            module: &shader,
+            entry_point: "cs_main",
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
+        Self {
+            pipeline,
+            bind_group_layout,
+            points: None,
+            point_count: 0,
+        }
+    }
+
+    /// Upload raw (data-space) point coordinates
+    pub fn set_points(&mut self, device: &wgpu::Device, x: &[f32], y: &[f32]) {
+        let interleaved: Vec<[f32; 2]> = x.iter().zip(y).map(|(&x, &y)| [x, y]).collect();
+        self.point_count = interleaved.len() as u32;
+        self.points = (!interleaved.is_empty()).then(|| {
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Bin2D Points Buffer"),
+                contents: bytemuck::cast_slice(&interleaved),
+                usage: wgpu::BufferUsages::STORAGE,
+            })
+        });
+    }
+
+    pub fn point_count(&self) -> u32 {
+        self.point_count
+    }
+
+    /// Record a binning pass into `encoder`
+    ///
+    /// # Returns
+    /// A zero-initialized storage buffer holding `grid.len()` u32 counts once
+    /// the encoder is submitted (also usable as `COPY_SRC`), or `None` if no
+    /// points have been uploaded.
+    pub fn encode(
+        &self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        grid: &BinGrid,
+    ) -> Option<wgpu::Buffer> {
+        let points = self.points.as_ref()?;
+
+        let params = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Bin2D Params Buffer"),
+            contents: bytemuck::bytes_of(&BinParams::new(grid, self.point_count)),
+            usage: wgpu::BufferUsages::UNIFORM,
+        });
+        // New buffers are zeroed by wgpu, so counts start from 0
+        let counts = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Bin2D Counts Buffer"),
+            size: (grid.len() * std::mem::size_of::<u32>()) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Bin2D Bind Group"),
+            layout: &self.bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: params.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: points.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: counts.as_entire_binding(),
+                },
+            ],
+        });
+
+        let groups = self.point_count.div_ceil(WORKGROUP_SIZE);
+        let groups_x = groups.min(MAX_WORKGROUPS_PER_DIM);
+        let groups_y = groups.div_ceil(MAX_WORKGROUPS_PER_DIM);
+
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Bin2D Pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&self.pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.dispatch_workgroups(groups_x, groups_y, 1);
+        drop(pass);
+
+        Some(counts)
+    }
+
+    /// Bin the uploaded points into `grid` and read the counts back
+    ///
+    /// Resolves once the GPU has finished; on native targets this blocks in
+    /// `device.poll`.
+    pub async fn bin(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        grid: &BinGrid,
+    ) -> Result<Vec<u32>, String> {
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Bin2D Encoder"),
+        });
+        let Some(counts) = self.encode(device, &mut encoder, grid) else {
+            return Ok(vec![0; grid.len()]);
+        };
+
+        let size = counts.size();
+        let staging = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Bin2D Staging Buffer"),
+            size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        encoder.copy_buffer_to_buffer(&counts, 0, &staging, 0, size);
+        queue.submit(Some(encoder.finish()));
+
+        let (sender, receiver) = futures::channel::oneshot::channel();
+        staging.slice(..).map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        device.poll(wgpu::Maintain::Wait);
+
+        receiver
+            .await
+            .map_err(|_| "Bin2D readback was cancelled".to_string())?
+            .map_err(|e| format!("Failed to map bin counts: {}", e))?;
+
+        let counts = bytemuck::cast_slice(&staging.slice(..).get_mapped_range()).to_vec();
+        staging.unmap();
+        Ok(counts)
+    }
+}
@@ -0,0 +1,20 @@
+//! GPU compute passes used for data aggregation
+
+pub mod aggregator;
+pub mod bin1d;
+pub mod bin2d;
+
+pub use aggregator::Aggregator;
+pub use bin1d::{GpuHistogram, HistParams};
+pub use bin2d::{BinParams, GpuBinner};
+
+/// Headless device for one-off compute dispatches, if a GPU with compute
+/// shaders is available
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn compute_device() -> Option<crate::headless::HeadlessRenderer> {
+    use crate::backend::{Capabilities, FeaturePath};
+
+    crate::headless::HeadlessRenderer::new(1, 1, Default::default())
+        .ok()
+        .filter(|headless| Capabilities::of(headless.device()).aggregation() == FeaturePath::Gpu)
+}
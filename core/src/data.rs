@@ -1,4 +1,13 @@
+// pyo3's generated wrappers for the PyResult methods trip this lint
+#![cfg_attr(feature = "python", allow(clippy::useless_conversion))]
+
 use bytemuck::{Pod, Zeroable};
+use crate::cancel::{CancelToken, PREPARE_CHUNK};
+use crate::colormap::ColormapLut;
+use crate::error::HelionError;
+use crate::norm::Normalizer;
+use crate::parallel;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
@@ -26,11 +35,15 @@ impl Point2D {
     fn py_new(x: f32, y: f32) -> Self {
         Self::new(x, y)
     }
+
+    fn __repr__(&self) -> String {
+        format!("Point2D(x={:?}, y={:?})", self.x, self.y)
+    }
 }
 
 /// Color in RGBA format
 #[repr(C)]
-#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq, Pod, Zeroable)]
 #[cfg_attr(feature = "python", pyo3::pyclass(name = "Color", get_all, set_all))]
 pub struct Color {
     pub r: f32,
@@ -44,18 +57,134 @@ impl Color {
         Self { r, g, b, a }
     }
 
+    /// Lenient hex parsing for trusted literals: unreadable channels become
+    /// 0 (alpha 1), so a typo silently gives black. Use `parse_hex` or
+    /// `parse` for user input.
     pub fn from_hex(hex: &str) -> Self {
         let hex = hex.trim_start_matches('#');
-        let r = u8::from_str_radix(&hex[0..2], 16).unwrap_or(0) as f32 / 255.0;
-        let g = u8::from_str_radix(&hex[2..4], 16).unwrap_or(0) as f32 / 255.0;
-        let b = u8::from_str_radix(&hex[4..6], 16).unwrap_or(0) as f32 / 255.0;
+        let channel = |range: std::ops::Range<usize>| {
+            hex.get(range).and_then(|digits| u8::from_str_radix(digits, 16).ok())
+        };
+        let [r, g, b] = [0..2, 2..4, 4..6].map(|range| channel(range).unwrap_or(0) as f32 / 255.0);
         let a = if hex.len() >= 8 {
-            u8::from_str_radix(&hex[6..8], 16).unwrap_or(255) as f32 / 255.0
+            channel(6..8).unwrap_or(255) as f32 / 255.0
         } else {
             1.0
         };
         Self { r, g, b, a }
     }
+
+    /// Parse "#RRGGBB" or "#RRGGBBAA" (the '#' is optional), rejecting
+    /// anything else instead of substituting black like `from_hex`
+    pub fn parse_hex(hex: &str) -> Result<Self, String> {
+        let digits = hex.trim().trim_start_matches('#');
+        let valid = matches!(digits.len(), 6 | 8) && digits.bytes().all(|b| b.is_ascii_hexdigit());
+        if !valid {
+            return Err(format!("Invalid hex color '{}': expected #RRGGBB or #RRGGBBAA", hex));
+        }
+        Ok(Self::from_hex(digits))
+    }
+
+    /// Opaque color from a CSS color name such as "tomato" or "steelblue"
+    ///
+    /// Case, spaces and underscores are ignored. "transparent" gives
+    /// transparent black.
+    pub fn from_name(name: &str) -> Option<Self> {
+        if name.trim().eq_ignore_ascii_case("transparent") {
+            return Some(Self::new(0.0, 0.0, 0.0, 0.0));
+        }
+        crate::named_colors::lookup(name).map(|rgb| {
+            let [_, r, g, b] = rgb.to_be_bytes();
+            Self::new(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, 1.0)
+        })
+    }
+
+    /// Parse a color given as a hex string ("#RRGGBB", "#RRGGBBAA") or a
+    /// CSS color name
+    ///
+    /// # Returns
+    /// An error naming the input if it is neither
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::data::Color;
+    ///
+    /// assert_eq!(Color::parse("tomato"), Color::parse_hex("#ff6347"));
+    /// assert!(Color::parse("#ff63").is_err());
+    /// assert!(Color::parse("tomatoe").is_err());
+    /// ```
+    pub fn parse(color: &str) -> Result<Self, String> {
+        let trimmed = color.trim();
+        if trimmed.starts_with('#') {
+            return Self::parse_hex(trimmed);
+        }
+        Self::from_name(trimmed)
+            .or_else(|| Self::parse_hex(trimmed).ok())
+            .ok_or_else(|| format!("Unknown color '{}': expected a CSS color name, #RRGGBB or #RRGGBBAA", color))
+    }
+
+    /// "#rrggbbaa" string that `parse_hex` reads back
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::data::Color;
+    ///
+    /// assert_eq!(Color::new(1.0, 0.0, 0.5, 1.0).to_hex(), "#ff0080ff");
+    /// ```
+    pub fn to_hex(&self) -> String {
+        let [r, g, b, a] = [self.r, self.g, self.b, self.a].map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+        format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+    }
+
+    /// Color from hue, saturation and lightness
+    ///
+    /// # Parameters
+    /// * `h` - Hue in degrees; any value is wrapped into [0, 360)
+    /// * `s` - Saturation, 0.0-1.0
+    /// * `l` - Lightness, 0.0 (black) to 1.0 (white)
+    /// * `a` - Alpha, 0.0-1.0
+    pub fn from_hsl(h: f32, s: f32, l: f32, a: f32) -> Self {
+        let (s, l) = (s.clamp(0.0, 1.0), l.clamp(0.0, 1.0));
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        Self::from_hue(h, chroma, l - chroma / 2.0, a)
+    }
+
+    /// Color from hue, saturation and value (brightness)
+    ///
+    /// # Parameters
+    /// * `h` - Hue in degrees; any value is wrapped into [0, 360)
+    /// * `s` - Saturation, 0.0-1.0
+    /// * `v` - Value, 0.0 (black) to 1.0 (full brightness)
+    /// * `a` - Alpha, 0.0-1.0
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::data::Color;
+    ///
+    /// assert_eq!(Color::from_hsv(120.0, 1.0, 1.0, 1.0), Color::new(0.0, 1.0, 0.0, 1.0));
+    /// assert_eq!(Color::from_hsl(-120.0, 1.0, 0.5, 1.0), Color::new(0.0, 0.0, 1.0, 1.0));
+    /// ```
+    pub fn from_hsv(h: f32, s: f32, v: f32, a: f32) -> Self {
+        let (s, v) = (s.clamp(0.0, 1.0), v.clamp(0.0, 1.0));
+        let chroma = v * s;
+        Self::from_hue(h, chroma, v - chroma, a)
+    }
+
+    /// Shared tail of the HSL/HSV conversions: place `chroma` on the hue
+    /// wheel and lift every channel by `min`
+    fn from_hue(h: f32, chroma: f32, min: f32, a: f32) -> Self {
+        let sector = h.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (sector.rem_euclid(2.0) - 1.0).abs());
+        let (r, g, b) = match sector as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        Self::new(r + min, g + min, b + min, a)
+    }
 }
 
 /// Python-specific methods
@@ -67,12 +196,40 @@ impl Color {
     fn py_new(r: f32, g: f32, b: f32, a: f32) -> Self {
         Self::new(r, g, b, a)
     }
+
+    fn __repr__(&self) -> String {
+        format!("Color(r={:?}, g={:?}, b={:?}, a={:?})", self.r, self.g, self.b, self.a)
+    }
     
     /// Create color from hex string (e.g., "#FF5733")
+    ///
+    /// Raises ValueError for anything but "#RRGGBB" or "#RRGGBBAA"
     #[staticmethod]
     #[pyo3(name = "from_hex")]
-    fn from_hex_py(hex: &str) -> Self {
-        Self::from_hex(hex)
+    fn from_hex_py(hex: &str) -> PyResult<Self> {
+        Self::parse_hex(hex).map_err(pyo3::exceptions::PyValueError::new_err)
+    }
+
+    /// Create color from a CSS color name (e.g., "tomato", "steelblue")
+    #[staticmethod]
+    #[pyo3(name = "from_name")]
+    fn from_name_py(name: &str) -> PyResult<Self> {
+        Self::from_name(name)
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(format!("Unknown color name '{}'", name)))
+    }
+
+    /// Create color from hue (degrees), saturation and lightness (0.0-1.0)
+    #[staticmethod]
+    #[pyo3(name = "from_hsl", signature = (h, s, l, a=1.0))]
+    fn from_hsl_py(h: f32, s: f32, l: f32, a: f32) -> Self {
+        Self::from_hsl(h, s, l, a)
+    }
+
+    /// Create color from hue (degrees), saturation and value (0.0-1.0)
+    #[staticmethod]
+    #[pyo3(name = "from_hsv", signature = (h, s, v, a=1.0))]
+    fn from_hsv_py(h: f32, s: f32, v: f32, a: f32) -> Self {
+        Self::from_hsv(h, s, v, a)
     }
 }
 
@@ -82,6 +239,71 @@ impl Default for Color {
     }
 }
 
+/// Serialized as a "#rrggbbaa" string
+impl serde::Serialize for Color {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_hex())
+    }
+}
+
+/// Accepts anything `Color::parse` does, or an [r, g, b] / [r, g, b, a]
+/// array of 0.0-1.0 floats
+impl<'de> serde::Deserialize<'de> for Color {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Text(String),
+            Channels(Vec<f32>),
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Text(text) => Color::parse(&text).map_err(serde::de::Error::custom),
+            Repr::Channels(c) => match c[..] {
+                [r, g, b] => Ok(Color::new(r, g, b, 1.0)),
+                [r, g, b, a] => Ok(Color::new(r, g, b, a)),
+                _ => Err(serde::de::Error::custom(format!(
+                    "Color arrays need 3 or 4 channels, got {}",
+                    c.len()
+                ))),
+            },
+        }
+    }
+}
+
+/// Transfer function mapping a data column onto point opacity
+///
+/// Values are normalized to [0, 1] over `domain`, shaped by `gamma`, then
+/// rescaled into [`min_alpha`, `max_alpha`]. Swap the alpha bounds to make
+/// larger values more transparent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlphaTransfer {
+    /// Input range; None uses the data's min/max
+    pub domain: Option<(f32, f32)>,
+    pub min_alpha: f32,
+    pub max_alpha: f32,
+    pub gamma: f32,
+}
+
+impl AlphaTransfer {
+    /// Opacity for a value already normalized to [0, 1]
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0).powf(self.gamma);
+        (self.min_alpha + (self.max_alpha - self.min_alpha) * t).clamp(0.0, 1.0)
+    }
+}
+
+impl Default for AlphaTransfer {
+    fn default() -> Self {
+        Self {
+            domain: None,
+            min_alpha: 0.1,
+            max_alpha: 1.0,
+            gamma: 1.0,
+        }
+    }
+}
+
 /// Vertex data for rendering (position + color + size)
 #[repr(C)]
 #[derive(Debug, Clone, Copy, Pod, Zeroable)]
@@ -128,24 +350,669 @@ impl Vertex {
             ],
         }
     }
+
+    /// Same layout as `desc()`, but advanced once per instance instead of per vertex
+    pub fn instance_desc<'a>() -> wgpu::VertexBufferLayout<'a> {
+        wgpu::VertexBufferLayout {
+            step_mode: wgpu::VertexStepMode::Instance,
+            ..Self::desc()
+        }
+    }
+}
+
+/// Position-only vertex for single-style series (8 bytes instead of 40)
+///
+/// Color and size come from a per-series uniform instead, see
+/// `scatter::UniformScatterRenderer`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Pod, Zeroable)]
+pub struct PositionVertex {
+    pub position: [f32; 2],
+}
+
+impl PositionVertex {
+    pub fn desc<'a>() -> wgpu::VertexBufferLayout<'a> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<PositionVertex>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &[wgpu::VertexAttribute {
+                offset: 0,
+                shader_location: 0,
+                format: wgpu::VertexFormat::Float32x2,
+            }],
+        }
+    }
+
+    /// Same layout as `desc()`, but advanced once per instance instead of per vertex
+    pub fn instance_desc<'a>() -> wgpu::VertexBufferLayout<'a> {
+        wgpu::VertexBufferLayout {
+            step_mode: wgpu::VertexStepMode::Instance,
+            ..Self::desc()
+        }
+    }
+}
+
+impl From<&Vertex> for PositionVertex {
+    fn from(vertex: &Vertex) -> Self {
+        Self { position: vertex.position }
+    }
+}
+
+/// GPU vertex layout used to upload a series
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VertexLayout {
+    /// Position, color and size per point (`Vertex`, 40 bytes)
+    Full,
+    /// Position per point, color and size per series (`PositionVertex`, 8 bytes)
+    PositionOnly,
+}
+
+/// Colormap for coloring points by a third variable
+///
+/// The sequential maps come from matplotlib and are perceptually uniform;
+/// coolwarm is diverging, for values around a meaningful midpoint. Any other
+/// table, including ones registered with `colormap::register_colormap`,
+/// goes through `Custom`.
+///
+/// # Example
+/// ```
+/// use helion_core::data::Colormap;
+///
+/// assert_eq!(Colormap::by_name("Plasma"), Some(Colormap::Plasma));
+/// // Reversed and registered maps become custom tables
+/// assert!(matches!(Colormap::by_name("viridis_r"), Some(Colormap::Custom(_))));
+/// assert_eq!(Colormap::by_name("nope"), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Colormap {
+    #[default]
+    Viridis,
+    Plasma,
+    Inferno,
+    Magma,
+    Coolwarm,
+    Custom(ColormapLut),
+}
+
+impl Colormap {
+    /// Built-in or registered colormap by case-insensitive name; a `_r`
+    /// suffix reverses it
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "viridis" => Some(Self::Viridis),
+            "plasma" => Some(Self::Plasma),
+            "inferno" => Some(Self::Inferno),
+            "magma" => Some(Self::Magma),
+            "coolwarm" => Some(Self::Coolwarm),
+            _ => ColormapLut::by_name(name).map(Self::Custom),
+        }
+    }
+
+    /// The colormap's 256-entry lookup table
+    pub fn lut(&self) -> ColormapLut {
+        match self {
+            Self::Viridis => ColormapLut::viridis(),
+            Self::Plasma => ColormapLut::plasma(),
+            Self::Inferno => ColormapLut::inferno(),
+            Self::Magma => ColormapLut::magma(),
+            Self::Coolwarm => ColormapLut::coolwarm(),
+            Self::Custom(lut) => lut.clone(),
+        }
+    }
+
+    /// Color of each value, scaled linearly so `vmin` gets the first color
+    /// and `vmax` the last
+    ///
+    /// Values outside the range take the end colors; NaN comes out fully
+    /// transparent.
+    pub fn colors(&self, values: &[f32], vmin: f32, vmax: f32) -> Vec<Color> {
+        let lut = self.lut();
+        values
+            .iter()
+            .map(|&v| {
+                if v.is_nan() {
+                    return Color::new(0.0, 0.0, 0.0, 0.0);
+                }
+                let [r, g, b, a] = lut.sample(v, vmin, vmax).map(|c| c as f32 / 255.0);
+                Color::new(r, g, b, a)
+            })
+            .collect()
+    }
+
+    /// Color of each value placed on the colormap by `norm`
+    ///
+    /// Values `norm` can't place (NaN, or <= 0 on a log scale) come out
+    /// fully transparent.
+    pub fn normalized_colors(&self, values: &[f32], norm: &Normalizer) -> Vec<Color> {
+        self.lut()
+            .colorize(values, norm)
+            .into_iter()
+            .map(|rgba| {
+                let [r, g, b, a] = rgba.map(|c| c as f32 / 255.0);
+                Color::new(r, g, b, a)
+            })
+            .collect()
+    }
+}
+
+/// Linear map from data coordinates to vertex positions, as applied by
+/// `ChartData::fill_scatter`
+///
+/// Keep it to place points appended later in the same space as the
+/// original ones.
+///
+/// # Example
+/// ```
+/// use helion_core::data::{ChartData, ScatterTransform};
+///
+/// let (x, y) = ([0.0, 10.0], [0.0, 5.0]);
+/// let data = ChartData::from_scatter(&x, &y, None, None, 800.0, 600.0);
+/// let transform = ScatterTransform::fit(&x, &y, None, None);
+/// assert_eq!(transform.apply(10.0, 5.0).x, data.vertices[1].position[0]);
+/// assert_eq!(transform.apply(5.0, 2.5).y, 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScatterTransform {
+    /// Data bounds mapped onto `x_out` and `y_out`, kept in f64 so large
+    /// offsets like nanosecond timestamps still resolve small steps
+    pub x_in: (f64, f64),
+    pub y_in: (f64, f64),
+    pub x_out: (f32, f32),
+    pub y_out: (f32, f32),
+}
+
+impl ScatterTransform {
+    /// Map `x_in` and `y_in` onto `x_range` and `y_range`, each defaulting
+    /// to clip space (-1, 1)
+    pub fn new(x_in: (f64, f64), y_in: (f64, f64), x_range: Option<(f32, f32)>, y_range: Option<(f32, f32)>) -> Self {
+        Self {
+            x_in,
+            y_in,
+            x_out: x_range.unwrap_or((-1.0, 1.0)),
+            y_out: y_range.unwrap_or((-1.0, 1.0)),
+        }
+    }
+
+    /// The map `fill_scatter` applies to `x` and `y` with these ranges,
+    /// fitted to their finite values
+    pub fn fit(x: &[f32], y: &[f32], x_range: Option<(f32, f32)>, y_range: Option<(f32, f32)>) -> Self {
+        Self::new(f32::finite_bounds(x), f32::finite_bounds(y), x_range, y_range)
+    }
+
+    /// `fit` for f64 coordinates, as `fill_scatter_f64` applies it
+    pub fn fit_f64(x: &[f64], y: &[f64], x_range: Option<(f32, f32)>, y_range: Option<(f32, f32)>) -> Self {
+        Self::new(f64::finite_bounds(x), f64::finite_bounds(y), x_range, y_range)
+    }
+
+    /// Transform whose input bounds also cover `x` and `y`, or `None` if
+    /// these already do
+    ///
+    /// An axis that has to grow gets half its new span again as headroom on
+    /// the side it grew, so a series that keeps extending, like a step
+    /// counter, is re-normalized a logarithmic number of times rather than
+    /// on every append. Bounds with min above max cover nothing.
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::data::ScatterTransform;
+    ///
+    /// let transform = ScatterTransform::new((0.0, 10.0), (0.0, 1.0), None, None);
+    /// assert!(transform.covering((2.0, 8.0), (0.5, 0.5)).is_none());
+    ///
+    /// let grown = transform.covering((5.0, 12.0), (0.0, 1.0)).unwrap();
+    /// assert_eq!(grown.x_in, (0.0, 18.0));
+    /// assert_eq!(grown.y_in, (0.0, 1.0));
+    /// ```
+    pub fn covering(&self, x: (f64, f64), y: (f64, f64)) -> Option<Self> {
+        let grow = |(min, max): (f64, f64), (lo, hi): (f64, f64)| {
+            if lo > hi {
+                return (min, max);
+            }
+            if min > max {
+                return (lo, hi);
+            }
+            let pad = (max.max(hi) - min.min(lo)) / 2.0;
+            let min = if lo < min { lo - pad } else { min };
+            let max = if hi > max { hi + pad } else { max };
+            (min, max)
+        };
+        let (x_in, y_in) = (grow(self.x_in, x), grow(self.y_in, y));
+        (x_in != self.x_in || y_in != self.y_in).then_some(Self { x_in, y_in, ..*self })
+    }
+
+    /// Map a data point into the output ranges
+    ///
+    /// Infinities land on the matching end of the output range, and when
+    /// every input value was the same the point is centered. NaN stays NaN.
+    pub fn apply(&self, x: f32, y: f32) -> Point2D {
+        let map = |v: f32, (in_min, in_max): (f64, f64), (out_min, out_max): (f32, f32)| {
+            if v.is_infinite() {
+                return if v > 0.0 { out_max } else { out_min };
+            }
+            let (in_min, in_max) = (in_min as f32, in_max as f32);
+            let span = in_max - in_min;
+            if span > 0.0 {
+                ((v - in_min) / span) * (out_max - out_min) + out_min
+            } else {
+                (out_min + out_max) / 2.0
+            }
+        };
+        Point2D::new(map(x, self.x_in, self.x_out), map(y, self.y_in, self.y_out))
+    }
+
+    /// `apply` for f64 coordinates; only the result is rounded to f32
+    pub fn apply_f64(&self, x: f64, y: f64) -> Point2D {
+        let map = |v: f64, (in_min, in_max): (f64, f64), (out_min, out_max): (f32, f32)| {
+            if v.is_infinite() {
+                return if v > 0.0 { out_max } else { out_min };
+            }
+            let span = in_max - in_min;
+            if span > 0.0 {
+                (((v - in_min) / span) * (out_max - out_min) as f64 + out_min as f64) as f32
+            } else {
+                (out_min + out_max) / 2.0
+            }
+        };
+        Point2D::new(map(x, self.x_in, self.x_out), map(y, self.y_in, self.y_out))
+    }
+}
+
+/// Number types scatter coordinates are read from; f64 input is bounded
+/// and normalized in f64
+trait Coordinate: Copy + Send + Sync + std::fmt::Display {
+    fn to_f64(self) -> f64;
+
+    /// `transform` applied in this type's own precision
+    fn apply(transform: &ScatterTransform, x: Self, y: Self) -> Point2D;
+
+    /// `parallel::finite_min_max` widened to f64
+    fn finite_bounds(values: &[Self]) -> (f64, f64);
+}
+
+impl Coordinate for f32 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn apply(transform: &ScatterTransform, x: Self, y: Self) -> Point2D {
+        transform.apply(x, y)
+    }
+
+    fn finite_bounds(values: &[Self]) -> (f64, f64) {
+        let (min, max) = parallel::finite_min_max(values);
+        (min as f64, max as f64)
+    }
+}
+
+impl Coordinate for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn apply(transform: &ScatterTransform, x: Self, y: Self) -> Point2D {
+        transform.apply_f64(x, y)
+    }
+
+    fn finite_bounds(values: &[Self]) -> (f64, f64) {
+        parallel::finite_min_max_f64(values)
+    }
+}
+
+/// What building vertices does with points whose x or y is NaN or infinite
+///
+/// Such points come from gaps in recorded data, division by zero upstream
+/// or values a log scale can't map.
+///
+/// # Example
+/// ```
+/// use helion_core::data::{ChartData, MissingDataPolicy};
+///
+/// let x = [0.0, 1.0, f32::NAN, 3.0, f32::INFINITY];
+/// let mut data = ChartData::new(800.0, 600.0);
+/// data.fill_scatter(&x, &x, None, None, None, None);
+/// assert_eq!((data.vertices.len(), data.dropped()), (3, &[2, 4][..]));
+///
+/// data.set_missing_data(MissingDataPolicy::Clamp);
+/// data.fill_scatter(&x, &x, None, None, None, None);
+/// assert_eq!(data.vertices[3].position, [1.0, 1.0]);
+/// assert_eq!(data.dropped(), &[2]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MissingDataPolicy {
+    /// Leave the point out
+    #[default]
+    Skip,
+    /// Pin infinite coordinates to the edge of the output range; points
+    /// with a NaN coordinate are still left out
+    Clamp,
+    /// Fail, naming the first such point
+    Error,
 }
 
+impl MissingDataPolicy {
+    /// Look a policy up by name: "skip", "clamp" or "error"
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "skip" => Some(Self::Skip),
+            "clamp" => Some(Self::Clamp),
+            "error" => Some(Self::Error),
+            _ => None,
+        }
+    }
+
+    /// Whether point `index` at (`x`, `y`) gets a vertex
+    fn keeps<T: Coordinate>(self, index: usize, x: T, y: T) -> Result<bool, HelionError> {
+        let (x_f64, y_f64) = (x.to_f64(), y.to_f64());
+        match self {
+            _ if x_f64.is_finite() && y_f64.is_finite() => Ok(true),
+            Self::Skip => Ok(false),
+            Self::Clamp => Ok(!x_f64.is_nan() && !y_f64.is_nan()),
+            Self::Error => Err(HelionError::InvalidOption(format!(
+                "Point {} is ({}, {}); coordinates must be finite",
+                index, x, y
+            ))),
+        }
+    }
+}
+
+/// Input index of vertex `vertex` of data built leaving out the ascending
+/// input indices `dropped`, as `ChartData::source_index` gives it
+///
+/// For mapping indices after the data itself is gone, e.g. in an event
+/// handler holding only a copy of `ChartData::dropped`.
+pub fn source_index(dropped: &[usize], vertex: usize) -> usize {
+    // dropped[j] - j points are kept before dropped[j], rising with j
+    let (mut lo, mut hi) = (0, dropped.len());
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        if dropped[mid] - mid <= vertex {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    vertex + lo
+}
+
+/// Points added with `ChartData::append_points`, so renderers can upload
+/// just the new ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AppendLog {
+    /// Identifies the points appended to; a new stream starts whenever the
+    /// points change other than by appending
+    pub stream: u64,
+    /// Points appended to this stream so far, including any since dropped
+    /// by `max_points`
+    pub appended: u64,
+}
+
+/// Source of `AppendLog::stream` ids, unique across all chart data
+static NEXT_STREAM: AtomicU64 = AtomicU64::new(0);
+
 /// Chart data container
+///
+/// Cloning gives an independent copy whose appends start a new stream.
 pub struct ChartData {
     pub vertices: Vec<Vertex>,
     pub viewport_width: f32,
     pub viewport_height: f32,
+    append_log: Option<AppendLog>,
+    max_points: Option<usize>,
+    missing_data: MissingDataPolicy,
+    /// Input indices the last fill left out, ascending
+    dropped: Vec<usize>,
+    /// Data coordinates of each vertex before normalization; empty unless
+    /// every vertex came from a fill or `append_scatter`
+    original: Vec<[f64; 2]>,
+}
+
+impl Clone for ChartData {
+    fn clone(&self) -> Self {
+        Self {
+            vertices: self.vertices.clone(),
+            viewport_width: self.viewport_width,
+            viewport_height: self.viewport_height,
+            append_log: None,
+            max_points: self.max_points,
+            missing_data: self.missing_data,
+            dropped: self.dropped.clone(),
+            original: self.original.clone(),
+        }
+    }
 }
 
 impl ChartData {
     pub fn new(width: f32, height: f32) -> Self {
+        Self::with_capacity(width, height, 0)
+    }
+
+    /// Create empty data with room for `capacity` points
+    pub fn with_capacity(width: f32, height: f32, capacity: usize) -> Self {
         Self {
-            vertices: Vec::new(),
+            vertices: Vec::with_capacity(capacity),
             viewport_width: width,
             viewport_height: height,
+            append_log: None,
+            max_points: None,
+            missing_data: MissingDataPolicy::default(),
+            dropped: Vec::new(),
+            original: Vec::new(),
+        }
+    }
+
+    /// Remove all points, keeping the allocation for reuse
+    ///
+    /// The `dropped` list goes too, so indices from earlier data don't
+    /// carry over to whatever is added next.
+    pub fn clear(&mut self) {
+        self.vertices.clear();
+        self.original.clear();
+        self.dropped.clear();
+        self.mark_changed();
+    }
+
+    /// Add points at the end, for data that streams in
+    ///
+    /// Renderers that keep a ring buffer (see `ScatterRenderer`) upload only
+    /// the appended points on their next `update_data`. With `max_points`
+    /// set, the oldest points are dropped to make room.
+    ///
+    /// # Parameters
+    /// * `points` - Positions in the same space as the existing vertices,
+    ///   e.g. mapped with the `ScatterTransform` they were built with
+    /// * `color`, `size` - Style of the new points
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::data::{ChartData, Color, Point2D};
+    ///
+    /// let mut data = ChartData::new(800.0, 600.0);
+    /// data.set_max_points(Some(2));
+    /// let red = Color::new(1.0, 0.0, 0.0, 1.0);
+    /// data.append_points(&[Point2D::new(0.0, 0.0), Point2D::new(0.1, 0.1)], red, 4.0);
+    /// data.append_points(&[Point2D::new(0.2, 0.2)], red, 4.0);
+    /// assert_eq!(data.vertices.len(), 2);
+    /// assert_eq!(data.vertices[0].position, [0.1, 0.1]);
+    /// assert_eq!(data.append_log().unwrap().appended, 3);
+    /// ```
+    pub fn append_points(&mut self, points: &[Point2D], color: Color, size: f32) {
+        self.push_points(points, None, color, size);
+    }
+
+    /// `append_points`, with the data coordinates of `points` if known
+    fn push_points(&mut self, points: &[Point2D], original: Option<&[[f64; 2]]>, color: Color, size: f32) {
+        let log = self.append_log.get_or_insert_with(|| AppendLog {
+            stream: NEXT_STREAM.fetch_add(1, Ordering::Relaxed),
+            appended: 0,
+        });
+        log.appended += points.len() as u64;
+        match original {
+            Some(original) if self.has_original() => self.original.extend_from_slice(original),
+            // Points without data coordinates can't be told apart from
+            // the rest once interleaved
+            _ => self.original.clear(),
+        }
+        self.vertices.extend(points.iter().map(|&point| Vertex::new(point, color, size)));
+        self.drop_excess();
+    }
+
+    /// Drop the oldest points beyond `max_points`
+    fn drop_excess(&mut self) {
+        if let Some(max) = self.max_points {
+            let excess = self.vertices.len().saturating_sub(max);
+            self.vertices.drain(..excess);
+            self.original.drain(..excess.min(self.original.len()));
+        }
+    }
+
+    /// Map `x` and `y` with `transform` and append them like
+    /// `append_points`, handling points that aren't finite as
+    /// `set_missing_data` chose
+    ///
+    /// Points left out are added to `dropped`, numbered on from the input
+    /// of the last fill.
+    ///
+    /// # Returns
+    /// An error naming the first point that isn't finite under
+    /// `MissingDataPolicy::Error`; nothing is appended then
+    pub fn append_scatter(
+        &mut self,
+        x: &[f32],
+        y: &[f32],
+        transform: &ScatterTransform,
+        color: Color,
+        size: f32,
+    ) -> Result<(), HelionError> {
+        self.append_coords(x, y, transform, color, size)
+    }
+
+    /// `append_scatter` for f64 coordinates, mapped in f64 like
+    /// `fill_scatter_f64_cancelable`
+    pub fn append_scatter_f64(
+        &mut self,
+        x: &[f64],
+        y: &[f64],
+        transform: &ScatterTransform,
+        color: Color,
+        size: f32,
+    ) -> Result<(), HelionError> {
+        self.append_coords(x, y, transform, color, size)
+    }
+
+    fn append_coords<T: Coordinate>(
+        &mut self,
+        x: &[T],
+        y: &[T],
+        transform: &ScatterTransform,
+        color: Color,
+        size: f32,
+    ) -> Result<(), HelionError> {
+        let first = self.vertices.len() + self.dropped.len();
+        let mut points = Vec::with_capacity(x.len().min(y.len()));
+        let mut original = Vec::with_capacity(points.capacity());
+        for (i, (&x, &y)) in x.iter().zip(y).enumerate() {
+            if self.missing_data.keeps(i, x, y)? {
+                points.push(T::apply(transform, x, y));
+                original.push([x.to_f64(), y.to_f64()]);
+            } else {
+                self.dropped.push(first + i);
+            }
+        }
+        self.push_points(&points, Some(&original), color, size);
+        Ok(())
+    }
+
+    /// Appends since the points last changed any other way, or `None` if
+    /// they did after the last append
+    pub fn append_log(&self) -> Option<AppendLog> {
+        self.append_log
+    }
+
+    /// Keep at most `max` points, dropping the oldest on `append_points`;
+    /// `None` (the default) keeps every point
+    ///
+    /// Points beyond `max` are dropped right away.
+    pub fn set_max_points(&mut self, max: Option<usize>) {
+        self.max_points = max;
+        self.drop_excess();
+        self.mark_changed();
+    }
+
+    /// Rolling window size set with `set_max_points`
+    pub fn max_points(&self) -> Option<usize> {
+        self.max_points
+    }
+
+    /// Choose what the next fill does with points that aren't finite;
+    /// `MissingDataPolicy::Skip` by default
+    pub fn set_missing_data(&mut self, policy: MissingDataPolicy) {
+        self.missing_data = policy;
+    }
+
+    pub fn missing_data(&self) -> MissingDataPolicy {
+        self.missing_data
+    }
+
+    /// Input indices of the points the last fill left out, ascending
+    pub fn dropped(&self) -> &[usize] {
+        &self.dropped
+    }
+
+    /// Input index of the point at `vertices[vertex]`, accounting for the
+    /// points the last fill left out
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::data::ChartData;
+    ///
+    /// let x = [f32::NAN, 1.0, f32::NAN, 3.0];
+    /// let data = ChartData::from_scatter(&x, &x, None, None, 800.0, 600.0);
+    /// assert_eq!((data.source_index(0), data.source_index(1)), (1, 3));
+    /// ```
+    pub fn source_index(&self, vertex: usize) -> usize {
+        source_index(&self.dropped, vertex)
+    }
+
+    /// Whether `original` knows the data coordinates of every vertex
+    fn has_original(&self) -> bool {
+        self.original.len() == self.vertices.len()
+    }
+
+    /// Data coordinates of `vertices[vertex]` as given to the fill, before
+    /// they were normalized, e.g. for a tooltip
+    ///
+    /// `None` if the vertex doesn't exist, or the vertices were built or
+    /// changed other than by a `fill_scatter` or `append_scatter` call.
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::data::ChartData;
+    ///
+    /// let data = ChartData::from_scatter(&[10.0, 20.0], &[-1.0, 1.0], None, None, 800.0, 600.0);
+    /// assert_eq!(data.vertices[1].position, [1.0, 1.0]);
+    /// assert_eq!(data.original_point(1), Some([20.0, 1.0]));
+    /// assert_eq!(data.original_point(2), None);
+    /// ```
+    pub fn original_point(&self, vertex: usize) -> Option<[f64; 2]> {
+        match self.has_original() {
+            true => self.original.get(vertex).copied(),
+            false => None,
         }
     }
 
+    /// `original_point` of every vertex, if known
+    pub fn original_points(&self) -> Option<&[[f64; 2]]> {
+        self.has_original().then_some(&self.original[..])
+    }
+
+    /// Record that `vertices` were changed directly, so renderers upload
+    /// all of them again instead of only appended points
+    ///
+    /// `ChartData`'s own methods do this themselves.
+    pub fn mark_changed(&mut self) {
+        self.append_log = None;
+    }
+
     /// Add a point to the chart
     ///
     /// # Parameters
@@ -154,6 +1021,164 @@ impl ChartData {
     /// * `size` - The size/radius of the point in pixels
     pub fn add_point(&mut self, point: Point2D, color: Color, size: f32) {
         self.vertices.push(Vertex::new(point, color, size));
+        self.original.clear();
+        self.mark_changed();
+    }
+
+    /// Color and size shared by every point, if the series is single-style
+    ///
+    /// Returns `None` for an empty series or when any point differs.
+    pub fn uniform_style(&self) -> Option<(Color, f32)> {
+        let first = self.vertices.first()?;
+        let same = self
+            .vertices
+            .iter()
+            .all(|v| v.color == first.color && v.size == first.size);
+        let [r, g, b, a] = first.color;
+        same.then_some((Color::new(r, g, b, a), first.size))
+    }
+
+    /// Smallest vertex layout that can represent this data
+    pub fn vertex_layout(&self) -> VertexLayout {
+        if self.uniform_style().is_some() {
+            VertexLayout::PositionOnly
+        } else {
+            VertexLayout::Full
+        }
+    }
+
+    /// Positions only, for the `VertexLayout::PositionOnly` fast path
+    pub fn position_vertices(&self) -> Vec<PositionVertex> {
+        self.vertices.iter().map(PositionVertex::from).collect()
+    }
+
+    /// Like `position_vertices`, but writes into `out` (cleared first) to
+    /// reuse its allocation
+    pub fn position_vertices_into(&self, out: &mut Vec<PositionVertex>) {
+        out.clear();
+        out.extend(self.vertices.iter().map(PositionVertex::from));
+    }
+
+    /// Add a filled axis-aligned rectangle as two triangles
+    ///
+    /// Intended for `TriangleList` renderers such as `MeshRenderer`
+    /// (bars, candle bodies, cells).
+    ///
+    /// # Parameters
+    /// * `min` - Bottom-left corner
+    /// * `max` - Top-right corner
+    /// * `color` - Fill color
+    pub fn add_rect(&mut self, min: Point2D, max: Point2D, color: Color) {
+        let corners = [
+            Point2D::new(min.x, min.y),
+            Point2D::new(max.x, min.y),
+            Point2D::new(max.x, max.y),
+            Point2D::new(min.x, min.y),
+            Point2D::new(max.x, max.y),
+            Point2D::new(min.x, max.y),
+        ];
+        for corner in corners {
+            self.vertices.push(Vertex::new(corner, color, 0.0));
+        }
+        self.original.clear();
+        self.mark_changed();
+    }
+
+    /// Set per-point opacity, independent of each point's RGB color
+    ///
+    /// Overrides the alpha channel of each vertex with the matching value,
+    /// clamped to [0, 1]. NaN and infinite values leave the point opaque.
+    ///
+    /// # Returns
+    /// `HelionError::ShapeMismatch` unless there is one value per vertex;
+    /// nothing is changed then
+    pub fn set_alpha(&mut self, alpha: &[f32]) -> Result<(), HelionError> {
+        if alpha.len() != self.vertices.len() {
+            return Err(HelionError::ShapeMismatch {
+                what: "alpha values, one per point".to_string(),
+                expected: self.vertices.len(),
+                actual: alpha.len(),
+            });
+        }
+        for (vertex, &a) in self.vertices.iter_mut().zip(alpha) {
+            vertex.color[3] = if a.is_finite() { a.clamp(0.0, 1.0) } else { 1.0 };
+        }
+        self.mark_changed();
+        Ok(())
+    }
+
+    /// Give each point its own color
+    ///
+    /// If the lengths differ, only the overlapping prefix is updated.
+    pub fn set_colors(&mut self, colors: &[Color]) {
+        for (vertex, color) in self.vertices.iter_mut().zip(colors) {
+            vertex.color = [color.r, color.g, color.b, color.a];
+        }
+        self.mark_changed();
+    }
+
+    /// Give each point its own color from packed RGBA floats (4 per point,
+    /// 0.0-1.0), e.g. a flattened (N, 4) array
+    ///
+    /// # Returns
+    /// An error if the length isn't a multiple of 4; nothing is changed then
+    pub fn set_colors_rgba(&mut self, rgba: &[f32]) -> Result<(), String> {
+        if !rgba.len().is_multiple_of(4) {
+            return Err(format!("Packed RGBA colors need 4 values per point, got {} values", rgba.len()));
+        }
+        for (vertex, color) in self.vertices.iter_mut().zip(rgba.chunks_exact(4)) {
+            vertex.color.copy_from_slice(color);
+        }
+        self.mark_changed();
+        Ok(())
+    }
+
+    /// Give each point its own size in pixels, e.g. for a bubble chart
+    ///
+    /// If the lengths differ, only the overlapping prefix is updated.
+    /// Negative and non-finite sizes become 0; renderers draw every point at
+    /// least one pixel wide.
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::data::ChartData;
+    ///
+    /// let mut data = ChartData::from_scatter(&[0.0, 1.0], &[0.0, 1.0], None, None, 800.0, 600.0);
+    /// data.set_sizes(&[4.0, 12.0]);
+    /// assert_eq!(data.vertices[1].size, 12.0);
+    /// ```
+    pub fn set_sizes(&mut self, sizes: &[f32]) {
+        for (vertex, &size) in self.vertices.iter_mut().zip(sizes) {
+            vertex.size = if size.is_finite() { size.max(0.0) } else { 0.0 };
+        }
+        self.mark_changed();
+    }
+
+    /// Derive per-point opacity from a data column (e.g. confidence, age)
+    ///
+    /// # Parameters
+    /// * `values` - One value per point
+    /// * `transfer` - Mapping from value to opacity
+    ///
+    /// # Returns
+    /// `HelionError::ShapeMismatch` unless there is one value per vertex, as
+    /// for `set_alpha`
+    pub fn set_alpha_from(&mut self, values: &[f32], transfer: &AlphaTransfer) -> Result<(), HelionError> {
+        let (min, max) = transfer.domain.unwrap_or_else(|| {
+            let min = values.iter().cloned().fold(f32::INFINITY, f32::min);
+            let max = values.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+            (min, max)
+        });
+        let range = max - min;
+
+        let alpha: Vec<f32> = values
+            .iter()
+            .map(|&v| {
+                let t = if range > 0.0 { (v - min) / range } else { 1.0 };
+                transfer.apply(t)
+            })
+            .collect();
+        self.set_alpha(&alpha)
     }
 
     /// Create scatter plot data from raw arrays
@@ -182,6 +1207,157 @@ impl ChartData {
         Self::from_scatter_with_range(x, y, color, size, width, height, None, None)
     }
 
+    /// Create scatter plot data from f64 coordinates without casting them
+    /// to f32 first
+    ///
+    /// Like `from_scatter`, but bounds and normalization run in f64 (see
+    /// `fill_scatter_f64_cancelable`), so points a microsecond apart on a
+    /// present-day nanosecond timestamp axis still land apart.
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::data::ChartData;
+    ///
+    /// // Nanosecond timestamps a microsecond apart; as f32 all three would
+    /// // be the same number
+    /// let t = [1.7e18, 1.7e18 + 1e3, 1.7e18 + 2e3];
+    /// let data = ChartData::from_scatter_f64(&t, &[0.0, 1.0, 2.0], None, None, 800.0, 600.0);
+    /// assert_eq!(data.vertices[1].position, [0.0, 0.0]);
+    /// ```
+    pub fn from_scatter_f64(
+        x: &[f64],
+        y: &[f64],
+        color: Option<Color>,
+        size: Option<f32>,
+        width: f32,
+        height: f32,
+    ) -> Self {
+        let mut data = Self::new(width, height);
+        data.fill_scatter_f64_cancelable(x, y, color, size, None, None, &CancelToken::new(), &mut |_| {})
+            .expect("a fresh CancelToken is never cancelled");
+        data
+    }
+
+    /// Create scatter plot data where every point has its own color
+    ///
+    /// Like `from_scatter`, but `colors[i]` colors point `i`. Points without
+    /// a matching color keep the default color.
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::data::{ChartData, Color};
+    ///
+    /// let colors = [Color::new(1.0, 0.0, 0.0, 1.0), Color::new(0.0, 0.0, 1.0, 1.0)];
+    /// let data = ChartData::from_scatter_per_point(&[0.0, 1.0], &[0.0, 1.0], &colors, None, 800.0, 600.0);
+    /// assert_eq!(data.vertices[1].color, [0.0, 0.0, 1.0, 1.0]);
+    /// ```
+    pub fn from_scatter_per_point(
+        x: &[f32],
+        y: &[f32],
+        colors: &[Color],
+        size: Option<f32>,
+        width: f32,
+        height: f32,
+    ) -> Self {
+        let mut data = Self::from_scatter(x, y, None, size, width, height);
+        data.set_colors(colors);
+        data
+    }
+
+    /// Create scatter plot data colored by a third variable
+    ///
+    /// # Parameters
+    /// * `c` - Value per point, mapped through `cmap`
+    /// * `cmap` - Colormap to look the values up in
+    /// * `norm` - Places each value on the colormap, e.g.
+    ///   `Normalization::Log.fit_range(c, vmin, vmax)`
+    /// * `size`, `width`, `height` - As for `from_scatter`
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::data::{ChartData, Colormap};
+    /// use helion_core::norm::Normalization;
+    ///
+    /// let x = [0.0, 1.0, 2.0];
+    /// let c = [10.0, 20.0, f32::NAN];
+    /// let norm = Normalization::Linear.fit(&c, None);
+    /// let data = ChartData::from_scatter_colormapped(&x, &x, &c, &Colormap::Viridis, &norm, None, 800.0, 600.0);
+    /// // viridis runs from dark purple to yellow
+    /// assert!(data.vertices[0].color[2] > data.vertices[0].color[1]);
+    /// assert!(data.vertices[1].color[0] > 0.9);
+    /// assert_eq!(data.vertices[2].color[3], 0.0);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_scatter_colormapped(
+        x: &[f32],
+        y: &[f32],
+        c: &[f32],
+        cmap: &Colormap,
+        norm: &Normalizer,
+        size: Option<f32>,
+        width: f32,
+        height: f32,
+    ) -> Self {
+        let mut data = Self::from_scatter(x, y, None, size, width, height);
+        data.set_colormapped(c, cmap, norm);
+        data
+    }
+
+    /// Color each point by `c[i]` through `cmap`, like
+    /// `from_scatter_colormapped`
+    ///
+    /// If the lengths differ, only the overlapping prefix is updated.
+    pub fn set_colormapped(&mut self, c: &[f32], cmap: &Colormap, norm: &Normalizer) {
+        let colors = cmap.normalized_colors(&c[..c.len().min(self.vertices.len())], norm);
+        self.set_colors(&colors);
+    }
+
+    /// Create scatter plot data with per-point colors packed as RGBA floats
+    ///
+    /// # Parameters
+    /// * `rgba` - 4 values (0.0-1.0) per point, e.g. a flattened (N, 4) array
+    ///
+    /// # Returns
+    /// An error if `rgba` doesn't hold a whole number of colors
+    pub fn from_scatter_rgba(
+        x: &[f32],
+        y: &[f32],
+        rgba: &[f32],
+        size: Option<f32>,
+        width: f32,
+        height: f32,
+    ) -> Result<Self, String> {
+        let mut data = Self::from_scatter(x, y, None, size, width, height);
+        data.set_colors_rgba(rgba)?;
+        Ok(data)
+    }
+
+    /// Create line chart data from raw arrays, for `LineRenderer`
+    ///
+    /// Coordinates are normalized exactly like `from_scatter`; points are
+    /// connected in the order given, so sort by x first for a time series.
+    ///
+    /// # Parameters
+    /// * `x` - Array of x-coordinates for each vertex
+    /// * `y` - Array of y-coordinates for each vertex (must be same length as x)
+    /// * `color` - Optional line color. If None, uses default blue color
+    /// * `width` - Viewport width in pixels
+    /// * `height` - Viewport height in pixels
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::data::ChartData;
+    ///
+    /// let t: Vec<f32> = (0..100).map(|i| i as f32 * 0.1).collect();
+    /// let v: Vec<f32> = t.iter().map(|t| t.sin()).collect();
+    /// let data = ChartData::from_line(&t, &v, None, 800.0, 600.0);
+    /// assert_eq!(data.vertices.len(), 100);
+    /// ```
+    pub fn from_line(x: &[f32], y: &[f32], color: Option<Color>, width: f32, height: f32) -> Self {
+        // Line width is fixed at one pixel by the LineStrip topology
+        Self::from_scatter(x, y, color, Some(1.0), width, height)
+    }
+
     /// Create scatter plot data with custom normalization ranges
     ///
     /// Converts raw x and y coordinate arrays into normalized vertex data with user-specified
@@ -214,6 +1390,7 @@ impl ChartData {
     ///     Some((0.0, 1.0)),  // y maps to [0, 1]
     /// );
     /// ```
+    #[allow(clippy::too_many_arguments)]
     pub fn from_scatter_with_range(
         x: &[f32],
         y: &[f32],
@@ -224,33 +1401,188 @@ impl ChartData {
         x_range: Option<(f32, f32)>,
         y_range: Option<(f32, f32)>,
     ) -> Self {
+        Self::from_scatter_cancelable(
+            x, y, color, size, width, height, x_range, y_range,
+            &CancelToken::new(),
+            &mut |_| {},
+        )
+        .expect("a fresh CancelToken is never cancelled")
+    }
+
+    /// Create scatter plot data like `from_scatter_with_range`, checking for
+    /// cancellation and reporting progress as it goes
+    ///
+    /// Input is processed in chunks of `cancel::PREPARE_CHUNK` points. Between
+    /// chunks `token` is checked and `progress` is called with the fraction of
+    /// work done (0.0 to 1.0), so a GUI can show a progress bar and abort an
+    /// accidentally huge request.
+    ///
+    /// # Returns
+    /// `HelionError::Cancelled` if `token` was cancelled before completion
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_scatter_cancelable(
+        x: &[f32],
+        y: &[f32],
+        color: Option<Color>,
+        size: Option<f32>,
+        width: f32,
+        height: f32,
+        x_range: Option<(f32, f32)>,
+        y_range: Option<(f32, f32)>,
+        token: &CancelToken,
+        progress: &mut dyn FnMut(f32),
+    ) -> Result<Self, HelionError> {
         let mut data = Self::new(width, height);
+        data.fill_scatter_cancelable(x, y, color, size, x_range, y_range, token, progress)?;
+        Ok(data)
+    }
+
+    /// Replace the points with a scatter series, reusing the vertex
+    /// allocation
+    ///
+    /// Same as `from_scatter_with_range` but keeps this data's viewport and
+    /// capacity, so an animation loop that regenerates points every frame
+    /// doesn't allocate once the capacity has grown to fit.
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::data::ChartData;
+    ///
+    /// let mut data = ChartData::new(800.0, 600.0);
+    /// for frame in 0..3 {
+    ///     let x: Vec<f32> = (0..100).map(|i| i as f32).collect();
+    ///     let y: Vec<f32> = x.iter().map(|v| (v + frame as f32).sin()).collect();
+    ///     data.fill_scatter(&x, &y, None, None, None, None);
+    /// }
+    /// assert_eq!(data.vertices.len(), 100);
+    /// ```
+    ///
+    /// Points that aren't finite are skipped under
+    /// `MissingDataPolicy::Error` too; use `fill_scatter_cancelable` to get
+    /// the error instead.
+    pub fn fill_scatter(
+        &mut self,
+        x: &[f32],
+        y: &[f32],
+        color: Option<Color>,
+        size: Option<f32>,
+        x_range: Option<(f32, f32)>,
+        y_range: Option<(f32, f32)>,
+    ) {
+        let policy = match self.missing_data {
+            MissingDataPolicy::Error => MissingDataPolicy::Skip,
+            policy => policy,
+        };
+        // Can't fail: the policy never errors and the token is never canceled
+        let _ = self.fill_coords(x, y, color, size, x_range, y_range, policy, &CancelToken::new(), &mut |_| {});
+    }
+
+    /// `fill_scatter` with cancellation and progress, like
+    /// `from_scatter_cancelable`
+    ///
+    /// Points with a NaN or infinite coordinate are handled as
+    /// `set_missing_data` chose. On cancellation, or with
+    /// `MissingDataPolicy::Error`, the points written so far are left in
+    /// place.
+    #[allow(clippy::too_many_arguments)]
+    pub fn fill_scatter_cancelable(
+        &mut self,
+        x: &[f32],
+        y: &[f32],
+        color: Option<Color>,
+        size: Option<f32>,
+        x_range: Option<(f32, f32)>,
+        y_range: Option<(f32, f32)>,
+        token: &CancelToken,
+        progress: &mut dyn FnMut(f32),
+    ) -> Result<(), HelionError> {
+        self.fill_coords(x, y, color, size, x_range, y_range, self.missing_data, token, progress)
+    }
+
+    /// `fill_scatter_cancelable` for f64 coordinates
+    ///
+    /// Bounds and normalization are computed in f64 and only the vertex
+    /// positions are rounded to f32, so data whose range is small next to
+    /// its magnitude (nanosecond timestamps, longitudes in a city) keeps
+    /// its detail.
+    #[allow(clippy::too_many_arguments)]
+    pub fn fill_scatter_f64_cancelable(
+        &mut self,
+        x: &[f64],
+        y: &[f64],
+        color: Option<Color>,
+        size: Option<f32>,
+        x_range: Option<(f32, f32)>,
+        y_range: Option<(f32, f32)>,
+        token: &CancelToken,
+        progress: &mut dyn FnMut(f32),
+    ) -> Result<(), HelionError> {
+        self.fill_coords(x, y, color, size, x_range, y_range, self.missing_data, token, progress)
+    }
+
+    /// Normalize `x` and `y` into vertices for the `fill_scatter` family
+    #[allow(clippy::too_many_arguments)]
+    fn fill_coords<T: Coordinate>(
+        &mut self,
+        x: &[T],
+        y: &[T],
+        color: Option<Color>,
+        size: Option<f32>,
+        x_range: Option<(f32, f32)>,
+        y_range: Option<(f32, f32)>,
+        missing_data: MissingDataPolicy,
+        token: &CancelToken,
+        progress: &mut dyn FnMut(f32),
+    ) -> Result<(), HelionError> {
+        self.clear();
+        self.dropped.clear();
         let color = color.unwrap_or_default();
         let size = size.unwrap_or(2.0);
-
-        // Default to GPU clip space [-1, 1] if not specified
-        let (x_out_min, x_out_max) = x_range.unwrap_or((-1.0, 1.0));
-        let (y_out_min, y_out_max) = y_range.unwrap_or((-1.0, 1.0));
+        let len = x.len().min(y.len());
+        self.vertices.reserve(len);
+        self.original.reserve(len);
+        // One pass to find the bounds, one to normalize
+        let total_work = (2 * len).max(1) as f32;
 
         // Find input data bounds
-        let x_min = x.iter().cloned().fold(f32::INFINITY, f32::min);
-        let x_max = x.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
-        let y_min = y.iter().cloned().fold(f32::INFINITY, f32::min);
-        let y_max = y.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
-
-        let x_in_range = x_max - x_min;
-        let y_in_range = y_max - y_min;
-        let x_out_range = x_out_max - x_out_min;
-        let y_out_range = y_out_max - y_out_min;
+        let (mut x_min, mut x_max) = (f64::INFINITY, f64::NEG_INFINITY);
+        let (mut y_min, mut y_max) = (f64::INFINITY, f64::NEG_INFINITY);
+        for start in (0..x.len().max(y.len())).step_by(PREPARE_CHUNK) {
+            token.check()?;
+            let end = (start + PREPARE_CHUNK).min(x.len().max(y.len()));
+            let (lo, hi) = T::finite_bounds(x.get(start..end.min(x.len())).unwrap_or_default());
+            (x_min, x_max) = (x_min.min(lo), x_max.max(hi));
+            let (lo, hi) = T::finite_bounds(y.get(start..end.min(y.len())).unwrap_or_default());
+            (y_min, y_max) = (y_min.min(lo), y_max.max(hi));
+            progress(end.min(len) as f32 / total_work);
+        }
 
         // Normalize coordinates to specified output range
-        for i in 0..x.len().min(y.len()) {
-            let norm_x = ((x[i] - x_min) / x_in_range) * x_out_range + x_out_min;
-            let norm_y = ((y[i] - y_min) / y_in_range) * y_out_range + y_out_min;
-            
-            data.add_point(Point2D::new(norm_x, norm_y), color, size);
+        let transform = ScatterTransform::new((x_min, x_max), (y_min, y_max), x_range, y_range);
+        for start in (0..len).step_by(PREPARE_CHUNK) {
+            token.check()?;
+            let end = (start + PREPARE_CHUNK).min(len);
+            let finite = |v: &[T]| v.iter().all(|v| v.to_f64().is_finite());
+            if finite(&x[start..end]) && finite(&y[start..end]) {
+                parallel::extend_mapped(&mut self.vertices, start..end, |i| {
+                    Vertex::new(T::apply(&transform, x[i], y[i]), color, size)
+                });
+                self.original.extend((start..end).map(|i| [x[i].to_f64(), y[i].to_f64()]));
+            } else {
+                for i in start..end {
+                    if missing_data.keeps(i, x[i], y[i])? {
+                        self.vertices.push(Vertex::new(T::apply(&transform, x[i], y[i]), color, size));
+                        self.original.push([x[i].to_f64(), y[i].to_f64()]);
+                    } else {
+                        self.dropped.push(i);
+                    }
+                }
+            }
+            progress((len + end) as f32 / total_work);
         }
 
-        data
+        token.check()?;
+        progress(1.0);
+        Ok(())
     }
 }
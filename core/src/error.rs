@@ -0,0 +1,113 @@
+use crate::backend::BackendType;
+
+/// Error returned by Helion's GPU setup and rendering APIs
+///
+/// Variants say what went wrong so callers can react programmatically, e.g.
+/// retry on `DeviceLost` or show a "no GPU" notice on `AdapterNotFound`.
+/// `Display` gives a message suitable for users.
+///
+/// # Example
+/// ```
+/// use helion_core::error::HelionError;
+/// use helion_core::renderer::RenderOptions;
+///
+/// let err = RenderOptions::default().with_sample_count(3).validate().unwrap_err();
+/// assert!(matches!(err, HelionError::InvalidOption(_)));
+/// assert_eq!(err.to_string(), "sample_count must be 1 or 4, got 3");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum HelionError {
+    /// No GPU adapter supports the requested backends
+    AdapterNotFound,
+    /// The adapter refused to create a device
+    DeviceRequest(String),
+    /// The device stopped working (driver reset, GPU removed, context lost)
+    /// and has to be recreated
+    DeviceLost(String),
+    /// Every backend `GPUBackend::new` tried failed; holds each failure
+    NoBackend(Vec<(BackendType, HelionError)>),
+    /// A `GPUBackend` part was used before it was set up ("device",
+    /// "queue", "surface" or "surface configuration")
+    NotInitialized(&'static str),
+    /// The surface couldn't be created, configured or acquired
+    Surface(String),
+    /// There is nothing to draw
+    EmptyData,
+    /// Inputs that must line up differ in length
+    ShapeMismatch {
+        what: String,
+        expected: usize,
+        actual: usize,
+    },
+    /// An option or argument is outside its valid range
+    InvalidOption(String),
+    /// Data exceeds what the device's buffers can hold
+    BufferTooLarge(String),
+    /// Rendered pixels couldn't be read back from the GPU
+    Readback(String),
+    /// Rendered pixels couldn't be encoded as an image file
+    Encoding(String),
+    /// A window or its event loop couldn't be opened
+    Window(String),
+    /// Frames couldn't be served to a browser
+    Serve(String),
+    /// Work stopped early because its `CancelToken` was cancelled
+    Cancelled,
+}
+
+impl std::fmt::Display for HelionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HelionError::AdapterNotFound => write!(f, "Failed to find GPU adapter"),
+            HelionError::DeviceRequest(e) => write!(f, "Failed to create device: {}", e),
+            HelionError::DeviceLost(reason) => write!(f, "GPU device lost: {}", reason),
+            HelionError::NoBackend(failures) => {
+                write!(f, "No GPU backend available")?;
+                for (i, (backend, e)) in failures.iter().enumerate() {
+                    write!(f, "{} {} failed: {}", if i == 0 { ":" } else { ";" }, backend, e)?;
+                }
+                Ok(())
+            }
+            HelionError::NotInitialized(what) => write!(f, "GPU {} not initialized", what),
+            HelionError::Surface(e) => write!(f, "Surface error: {}", e),
+            HelionError::EmptyData => write!(f, "No data to draw"),
+            HelionError::ShapeMismatch { what, expected, actual } => {
+                write!(f, "{}: expected {}, got {}", what, expected, actual)
+            }
+            HelionError::InvalidOption(e) => f.write_str(e),
+            HelionError::BufferTooLarge(e) => f.write_str(e),
+            HelionError::Readback(e) => write!(f, "Failed to read back pixels: {}", e),
+            HelionError::Encoding(e) => write!(f, "Failed to encode image: {}", e),
+            HelionError::Window(e) => write!(f, "Failed to open window: {}", e),
+            HelionError::Serve(e) => write!(f, "Failed to serve frames: {}", e),
+            HelionError::Cancelled => write!(f, "Data preparation was cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for HelionError {}
+
+impl From<wgpu::SurfaceError> for HelionError {
+    fn from(e: wgpu::SurfaceError) -> Self {
+        HelionError::Surface(format!("Failed to get current texture: {}", e))
+    }
+}
+
+/// Invalid input becomes `ValueError`, oversized data `MemoryError`,
+/// cancellation `KeyboardInterrupt` (only Ctrl-C cancels from Python), and
+/// GPU failures `RuntimeError`
+#[cfg(feature = "python")]
+impl From<HelionError> for pyo3::PyErr {
+    fn from(e: HelionError) -> Self {
+        use pyo3::exceptions::{PyKeyboardInterrupt, PyMemoryError, PyRuntimeError, PyValueError};
+        let message = e.to_string();
+        match e {
+            HelionError::Cancelled => PyKeyboardInterrupt::new_err(message),
+            HelionError::EmptyData | HelionError::ShapeMismatch { .. } | HelionError::InvalidOption(_) => {
+                PyValueError::new_err(message)
+            }
+            HelionError::BufferTooLarge(_) => PyMemoryError::new_err(message),
+            _ => PyRuntimeError::new_err(message),
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use crate::brush::{Selection, SubplotId};
+use crate::camera::Camera2D;
+
+/// Something that happened to a figure that other parts of the app may want
+/// to react to
+///
+/// Windows, web canvases and the Python layer all publish these to an
+/// `EventBus` rather than each growing its own callbacks.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FigureEvent {
+    /// The visible region changed (pan, zoom or reset)
+    ViewChanged { camera: Camera2D },
+    /// A data point was clicked or picked under the cursor
+    PointPicked { series: usize, index: usize },
+    /// The point under the cursor changed; `None` once it leaves every
+    /// point
+    PointHovered { point: Option<(usize, usize)> },
+    /// A brushed selection changed; `subplots` lists every linked subplot
+    /// showing it, as returned by `BrushLink::brush`
+    SelectionChanged {
+        subplots: Vec<SubplotId>,
+        selection: Selection,
+    },
+    /// `count` points were appended to `series`, which now holds `total`
+    DataAppended { series: usize, count: usize, total: usize },
+}
+
+/// Discriminant of a `FigureEvent`, for subscribing to one kind only
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FigureEventKind {
+    ViewChanged,
+    PointPicked,
+    PointHovered,
+    SelectionChanged,
+    DataAppended,
+}
+
+impl FigureEventKind {
+    pub const ALL: [FigureEventKind; 5] = [
+        FigureEventKind::ViewChanged,
+        FigureEventKind::PointPicked,
+        FigureEventKind::PointHovered,
+        FigureEventKind::SelectionChanged,
+        FigureEventKind::DataAppended,
+    ];
+
+    /// snake_case name used by the Python and JavaScript APIs
+    pub fn name(self) -> &'static str {
+        match self {
+            FigureEventKind::ViewChanged => "view_changed",
+            FigureEventKind::PointPicked => "point_picked",
+            FigureEventKind::PointHovered => "point_hovered",
+            FigureEventKind::SelectionChanged => "selection_changed",
+            FigureEventKind::DataAppended => "data_appended",
+        }
+    }
+
+    /// Parse a name as returned by `name()`
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        Self::ALL.into_iter().find(|kind| kind.name() == name).ok_or_else(|| {
+            let names: Vec<&str> = Self::ALL.iter().map(|kind| kind.name()).collect();
+            format!("Unknown event '{}': expected one of {}", name, names.join(", "))
+        })
+    }
+}
+
+impl FigureEvent {
+    pub fn kind(&self) -> FigureEventKind {
+        match self {
+            FigureEvent::ViewChanged { .. } => FigureEventKind::ViewChanged,
+            FigureEvent::PointPicked { .. } => FigureEventKind::PointPicked,
+            FigureEvent::PointHovered { .. } => FigureEventKind::PointHovered,
+            FigureEvent::SelectionChanged { .. } => FigureEventKind::SelectionChanged,
+            FigureEvent::DataAppended { .. } => FigureEventKind::DataAppended,
+        }
+    }
+}
+
+/// Handle returned by `EventBus::subscribe`, used to unsubscribe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(u64);
+
+type Handler = Box<dyn FnMut(&FigureEvent) + Send>;
+
+struct Subscriber {
+    id: SubscriptionId,
+    /// `None` receives every event
+    kind: Option<FigureEventKind>,
+    handler: Handler,
+}
+
+/// Typed publish/subscribe hub for `FigureEvent`s
+///
+/// Handlers run synchronously inside `publish`, in subscription order.
+/// They must be `Send` so a figure and its bus can move to a render thread.
+///
+/// # Example
+/// ```
+/// use helion_core::events::{EventBus, FigureEvent, FigureEventKind};
+/// use std::sync::{Arc, Mutex};
+///
+/// let appended = Arc::new(Mutex::new(0));
+/// let mut bus = EventBus::new();
+/// let counter = Arc::clone(&appended);
+/// bus.subscribe_to(FigureEventKind::DataAppended, move |event| {
+///     if let FigureEvent::DataAppended { count, .. } = event {
+///         *counter.lock().unwrap() += count;
+///     }
+/// });
+///
+/// bus.publish(&FigureEvent::DataAppended { series: 0, count: 3, total: 3 });
+/// bus.publish(&FigureEvent::PointPicked { series: 0, index: 1 });
+/// assert_eq!(*appended.lock().unwrap(), 3);
+/// ```
+#[derive(Default)]
+pub struct EventBus {
+    subscribers: Vec<Subscriber>,
+    next_id: u64,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Call `handler` for every event
+    pub fn subscribe(&mut self, handler: impl FnMut(&FigureEvent) + Send + 'static) -> SubscriptionId {
+        self.add(None, Box::new(handler))
+    }
+
+    /// Call `handler` only for events of `kind`
+    pub fn subscribe_to(
+        &mut self,
+        kind: FigureEventKind,
+        handler: impl FnMut(&FigureEvent) + Send + 'static,
+    ) -> SubscriptionId {
+        self.add(Some(kind), Box::new(handler))
+    }
+
+    /// Remove a handler; returns false if it was already removed
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        let before = self.subscribers.len();
+        self.subscribers.retain(|s| s.id != id);
+        self.subscribers.len() != before
+    }
+
+    /// Deliver `event` to every matching handler
+    ///
+    /// # Returns
+    /// The number of handlers that were called
+    pub fn publish(&mut self, event: &FigureEvent) -> usize {
+        let kind = event.kind();
+        let mut delivered = 0;
+        for subscriber in &mut self.subscribers {
+            if subscriber.kind.is_none_or(|k| k == kind) {
+                (subscriber.handler)(event);
+                delivered += 1;
+            }
+        }
+        delivered
+    }
+
+    /// Whether any handler would receive an event of `kind`, so callers
+    /// can skip work such as a GPU pick nobody listens to
+    pub fn has_subscribers(&self, kind: FigureEventKind) -> bool {
+        self.subscribers.iter().any(|s| s.kind.is_none_or(|k| k == kind))
+    }
+
+    /// Number of subscribed handlers
+    pub fn len(&self) -> usize {
+        self.subscribers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.subscribers.is_empty()
+    }
+
+    fn add(&mut self, kind: Option<FigureEventKind>, handler: Handler) -> SubscriptionId {
+        let id = SubscriptionId(self.next_id);
+        self.next_id += 1;
+        self.subscribers.push(Subscriber { id, kind, handler });
+        id
+    }
+}
+
+impl std::fmt::Debug for EventBus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventBus").field("subscribers", &self.subscribers.len()).finish()
+    }
+}
@@ -0,0 +1,792 @@
+use crate::axes::{tick_label_layouts, tick_label_sections, AxesOptions};
+use crate::data::{ChartData, Color, Point2D};
+use crate::error::HelionError;
+use crate::heatmap::{AggregatedPoints, Heatmap};
+use crate::histogram::{Histogram, HistogramOptions};
+use crate::labels::{place_labels, value_labels, LabelOptions, LabelTarget};
+use crate::layout::data_extent;
+use crate::legend::{Legend, LegendLocation};
+use crate::scale::{AxisScale, BrokenAxis};
+use crate::style::SeriesStyle;
+use crate::text::{HAlign, TextSection, VAlign};
+use crate::timeseries::{gap_indices, GapThreshold};
+use crate::transition::ViewRange;
+
+/// Colors given to series added through `Figure::scatter` and
+/// `Figure::line`, in order (the Tableau 10 palette)
+pub const DEFAULT_COLOR_CYCLE: [&str; 10] = [
+    "#1f77b4", "#ff7f0e", "#2ca02c", "#d62728", "#9467bd", "#8c564b", "#e377c2", "#7f7f7f", "#bcbd22", "#17becf",
+];
+
+/// How a series is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChartKind {
+    /// One point per vertex (`ScatterRenderer`)
+    #[default]
+    Scatter,
+    /// Consecutive vertices joined by lines (`LineRenderer`)
+    Line,
+    /// Filled bars rising from y = 0 (`HistogramRenderer`); x holds the
+    /// n + 1 bar edges and y the n heights
+    Bars,
+}
+
+impl ChartKind {
+    /// Name frame statistics are recorded under
+    pub fn label(self) -> &'static str {
+        match self {
+            ChartKind::Scatter => "scatter",
+            ChartKind::Line => "line",
+            ChartKind::Bars => "bars",
+        }
+    }
+}
+
+/// One dataset in a `Figure`, with its own kind, style and label
+///
+/// Holds the raw coordinates; they are normalized only when the figure is
+/// drawn, against the range shared by every series.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    pub kind: ChartKind,
+    /// Legend text; unlabeled series still get a (blank) legend entry so
+    /// entry indices match series indices
+    pub label: Option<String>,
+    pub style: SeriesStyle,
+    /// For lines, where a step in x is large enough to leave the line
+    /// broken rather than joined across it; NaN values always break it
+    pub gaps: Option<GapThreshold>,
+    /// Text drawn next to each point or at the end of each bar; empty
+    /// strings leave that one unlabelled
+    pub data_labels: Option<Vec<String>>,
+    x: Vec<f32>,
+    y: Vec<f32>,
+}
+
+impl Series {
+    /// Series of `kind` through the points (`x[i]`, `y[i]`)
+    ///
+    /// # Returns
+    /// `HelionError::ShapeMismatch` if `x` and `y` differ in length, or for
+    /// bars if `x` doesn't hold one edge more than `y` has heights
+    pub fn new(kind: ChartKind, x: Vec<f32>, y: Vec<f32>) -> Result<Self, HelionError> {
+        if kind == ChartKind::Bars {
+            let edges = if y.is_empty() { x.len().min(1) } else { y.len() + 1 };
+            if x.len() != edges {
+                return Err(HelionError::ShapeMismatch {
+                    what: "bar edges (one more than heights)".to_string(),
+                    expected: edges,
+                    actual: x.len(),
+                });
+            }
+        } else if x.len() != y.len() {
+            return Err(HelionError::ShapeMismatch {
+                what: "y values (one per x value)".to_string(),
+                expected: x.len(),
+                actual: y.len(),
+            });
+        }
+        Ok(Self {
+            kind,
+            label: None,
+            style: SeriesStyle::default(),
+            gaps: None,
+            data_labels: None,
+            x,
+            y,
+        })
+    }
+
+    pub fn scatter(x: Vec<f32>, y: Vec<f32>) -> Result<Self, HelionError> {
+        Self::new(ChartKind::Scatter, x, y)
+    }
+
+    pub fn line(x: Vec<f32>, y: Vec<f32>) -> Result<Self, HelionError> {
+        Self::new(ChartKind::Line, x, y)
+    }
+
+    /// Bars spanning `[edges[i], edges[i + 1]]` with height `heights[i]`
+    pub fn bars(edges: Vec<f32>, heights: Vec<f32>) -> Result<Self, HelionError> {
+        Self::new(ChartKind::Bars, edges, heights)
+    }
+
+    /// Bars of a computed histogram
+    pub fn histogram(histogram: Histogram) -> Self {
+        Self::bars(histogram.edges, histogram.values).expect("a histogram has one more edge than values")
+    }
+
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn with_style(mut self, style: SeriesStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.style.color = color;
+        self
+    }
+
+    /// Break a line series at gaps in x, e.g. missing days in a time series
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::figure::{Figure, Series};
+    /// use helion_core::timeseries::GapThreshold;
+    ///
+    /// let line = Series::line(vec![0.0, 1.0, 2.0, 10.0, 11.0], vec![0.0; 5]).unwrap();
+    /// let mut fig = Figure::new(800.0, 600.0);
+    /// fig.add(line.with_gaps(GapThreshold::Absolute(2.0)));
+    /// // A break vertex between x = 2 and x = 10
+    /// let data = fig.chart_data(0).unwrap();
+    /// assert_eq!(data.vertices.len(), 6);
+    /// assert!(data.vertices[3].position[0].is_nan());
+    /// ```
+    pub fn with_gaps(mut self, threshold: GapThreshold) -> Self {
+        self.gaps = Some(threshold);
+        self
+    }
+
+    /// Label each point or bar with `labels`, e.g. a metadata field
+    pub fn with_data_labels(mut self, labels: Vec<String>) -> Self {
+        self.data_labels = Some(labels);
+        self
+    }
+
+    /// Label each point or bar with its y value, to `precision` decimals
+    pub fn with_value_labels(self, precision: usize) -> Self {
+        let labels = value_labels(&self.y, precision);
+        self.with_data_labels(labels)
+    }
+
+    pub fn x(&self) -> &[f32] {
+        &self.x
+    }
+
+    pub fn y(&self) -> &[f32] {
+        &self.y
+    }
+
+    /// Number of points, or of bars
+    pub fn len(&self) -> usize {
+        self.y.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.y.is_empty()
+    }
+
+    /// Data extent of the series; (inf, -inf) on an axis with no finite value
+    ///
+    /// Bars always include their baseline, y = 0.
+    pub fn extent(&self) -> ViewRange {
+        let mut y = data_extent(&self.y);
+        if self.kind == ChartKind::Bars && !self.y.is_empty() {
+            y = (y.0.min(0.0), y.1.max(0.0));
+        }
+        ViewRange::new(data_extent(&self.x), y)
+    }
+}
+
+/// A series prepared for drawing: its vertices in the figure's shared
+/// coordinate system
+pub struct SeriesLayer {
+    /// Index of the series in the figure, and of its legend entry
+    pub series: usize,
+    pub kind: ChartKind,
+    pub data: ChartData,
+}
+
+/// Title, axis labels and data labels of a figure
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FigureText {
+    pub title: Option<String>,
+    pub xlabel: Option<String>,
+    pub ylabel: Option<String>,
+    /// Labels of points and bars, anchored in axis units so they follow the
+    /// view; see `Figure::text_with_data_labels`
+    pub data_labels: Vec<(LabelTarget, String)>,
+}
+
+impl FigureText {
+    /// Title font size in pixels
+    pub const TITLE_SIZE: f32 = 16.0;
+    /// Axis label font size in pixels
+    pub const LABEL_SIZE: f32 = 13.0;
+
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none() && self.xlabel.is_none() && self.ylabel.is_none() && self.data_labels.is_empty()
+    }
+
+    /// Text for tick labels (with `axes`), data labels, title and axis
+    /// labels of a `width` x `height` plot showing `view`
+    ///
+    /// The title is centered along the top. The x label is centered above
+    /// the x tick labels, the y label turned upright right of the y tick
+    /// labels, so all of it stays inside the plot area. Data labels are laid
+    /// out with `place_labels`, dropping those that would overlap.
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::figure::FigureText;
+    /// use helion_core::text::HAlign;
+    /// use helion_core::transition::ViewRange;
+    ///
+    /// let text = FigureText { title: Some("Latency".into()), ..Default::default() };
+    /// let sections = text.sections(&ViewRange::new((0.0, 1.0), (0.0, 1.0)), None, 800.0, 600.0);
+    /// assert_eq!(sections.len(), 1);
+    /// assert_eq!(sections[0].position, [400.0, 6.0]);
+    /// assert_eq!(sections[0].h_align, HAlign::Center);
+    /// ```
+    pub fn sections(&self, view: &ViewRange, axes: Option<&AxesOptions>, width: f32, height: f32) -> Vec<TextSection> {
+        let mut sections = axes
+            .map(|axes| tick_label_sections(view, axes, width, height))
+            .unwrap_or_default();
+        let color = axes.map_or(Color::new(0.0, 0.0, 0.0, 1.0), |axes| axes.line_style.color);
+        let inset = axes.map_or(4.0, |axes| axes.label_inset());
+        let tick_font = axes.and_then(|axes| axes.tick_labels.as_ref()).map(|labels| labels.font_size);
+
+        if !self.data_labels.is_empty() {
+            // Axis units -> pixels from the top left
+            let to_pixels = |[x, y]: [f32; 2]| {
+                [
+                    (x - view.x.0) / (view.x.1 - view.x.0) * width,
+                    (view.y.1 - y) / (view.y.1 - view.y.0) * height,
+                ]
+            };
+            let (targets, texts): (Vec<LabelTarget>, Vec<String>) =
+                self.data_labels.iter().map(|(target, text)| (target.map(to_pixels), text.clone())).unzip();
+            let labels = place_labels(&targets, &texts, &LabelOptions::default(), width, height);
+            sections.extend(labels.iter().map(|label| label.section(color)));
+        }
+
+        if let Some(title) = &self.title {
+            sections.push(
+                TextSection::new(title.clone(), [width / 2.0, 6.0], Self::TITLE_SIZE)
+                    .with_color(color)
+                    .with_align(HAlign::Center, VAlign::Top),
+            );
+        }
+        if let Some(xlabel) = &self.xlabel {
+            let bottom = height - inset - tick_font.map_or(0.0, |size| size + 4.0);
+            sections.push(
+                TextSection::new(xlabel.clone(), [width / 2.0, bottom], Self::LABEL_SIZE)
+                    .with_color(color)
+                    .with_align(HAlign::Center, VAlign::Bottom),
+            );
+        }
+        if let Some(ylabel) = &self.ylabel {
+            let widest = axes
+                .and_then(|axes| tick_label_layouts(view, axes, width, height))
+                .map_or(0.0, |(_, y)| y.labels.iter().map(|label| label.width).fold(0.0, f32::max));
+            let left = inset + widest + if widest > 0.0 { 6.0 } else { 0.0 };
+            // Turned a quarter counter-clockwise, the top of the text faces left
+            sections.push(
+                TextSection::new(ylabel.clone(), [left, height / 2.0], Self::LABEL_SIZE)
+                    .with_color(color)
+                    .with_align(HAlign::Center, VAlign::Top)
+                    .with_rotation(90.0),
+            );
+        }
+        sections
+    }
+}
+
+/// A chart holding any number of series on one pair of axes
+///
+/// Every series is normalized against the same data range (the union of
+/// their extents unless limits are set), so overlaid datasets line up.
+///
+/// # Example
+/// ```
+/// use helion_core::figure::Figure;
+///
+/// let mut fig = Figure::new(800.0, 600.0);
+/// fig.scatter(vec![0.0, 10.0], vec![0.0, 10.0]).unwrap();
+/// fig.line(vec![0.0, 5.0], vec![5.0, 5.0]).unwrap().label = Some("threshold".into());
+///
+/// // x = 5 lands in the middle of the shared range [0, 10]
+/// let layers = fig.layers();
+/// assert_eq!(layers[1].data.vertices[1].position, [0.0, 0.0]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Figure {
+    series: Vec<Series>,
+    width: f32,
+    height: f32,
+    xlim: Option<(f32, f32)>,
+    ylim: Option<(f32, f32)>,
+    xscale: AxisScale,
+    yscale: AxisScale,
+    xbreaks: Option<BrokenAxis>,
+    ybreaks: Option<BrokenAxis>,
+    /// Gridlines, ticks and frame; `None` draws the bare series. Its grid
+    /// scales and breaks are kept equal to the figure's
+    axes: Option<AxesOptions>,
+    text: FigureText,
+    /// Drawn behind every series
+    heatmap: Option<Heatmap>,
+    /// Points `heatmap` counts, re-binned by windows as the view zooms
+    aggregated: Option<AggregatedPoints>,
+    legend_location: LegendLocation,
+}
+
+impl Figure {
+    /// Empty `width` x `height` pixel figure
+    pub fn new(width: f32, height: f32) -> Self {
+        Self {
+            series: Vec::new(),
+            width,
+            height,
+            xlim: None,
+            ylim: None,
+            xscale: AxisScale::Linear,
+            yscale: AxisScale::Linear,
+            xbreaks: None,
+            ybreaks: None,
+            axes: Some(AxesOptions::default()),
+            text: FigureText::default(),
+            heatmap: None,
+            aggregated: None,
+            legend_location: LegendLocation::default(),
+        }
+    }
+
+    /// Fix the visible x range instead of fitting it to the data
+    pub fn with_xlim(mut self, xlim: (f32, f32)) -> Self {
+        self.xlim = Some(xlim);
+        self
+    }
+
+    /// Fix the visible y range instead of fitting it to the data
+    pub fn with_ylim(mut self, ylim: (f32, f32)) -> Self {
+        self.ylim = Some(ylim);
+        self
+    }
+
+    /// Draw the axes with `axes`, or hide them with `None`
+    pub fn with_axes(mut self, axes: Option<AxesOptions>) -> Self {
+        self.set_axes(axes);
+        self
+    }
+
+    pub fn set_axes(&mut self, axes: Option<AxesOptions>) {
+        self.axes = axes;
+        self.sync_axis_scales();
+    }
+
+    pub fn axes(&self) -> Option<&AxesOptions> {
+        self.axes.as_ref()
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.text.title = Some(title.into());
+        self
+    }
+
+    pub fn set_title(&mut self, title: Option<String>) {
+        self.text.title = title;
+    }
+
+    pub fn set_xlabel(&mut self, xlabel: Option<String>) {
+        self.text.xlabel = xlabel;
+    }
+
+    pub fn set_ylabel(&mut self, ylabel: Option<String>) {
+        self.text.ylabel = ylabel;
+    }
+
+    /// Title and axis labels
+    pub fn text(&self) -> &FigureText {
+        &self.text
+    }
+
+    /// `text` with the data labels of every series, anchored at their
+    /// points and bar ends in axis units; series with a higher `z_order`
+    /// come first, so their labels win overlaps
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::figure::{Figure, Series};
+    /// use helion_core::labels::LabelTarget;
+    ///
+    /// let mut fig = Figure::new(800.0, 600.0);
+    /// fig.add(Series::bars(vec![0.0, 1.0, 2.0], vec![4.0, -2.0]).unwrap().with_value_labels(0));
+    /// let text = fig.text_with_data_labels();
+    /// assert_eq!(text.data_labels[1], (LabelTarget::BarEnd { position: [1.5, -2.0], down: true }, "-2".to_string()));
+    /// ```
+    pub fn text_with_data_labels(&self) -> FigureText {
+        let mut text = self.text.clone();
+        let mut order: Vec<&Series> = self.series.iter().filter(|s| s.data_labels.is_some()).collect();
+        order.sort_by_key(|s| std::cmp::Reverse(s.style.z_order));
+        for series in order {
+            let labels = series.data_labels.iter().flatten().cloned();
+            if series.kind == ChartKind::Bars {
+                let targets = series.x.windows(2).zip(&series.y).map(|(edges, &height)| LabelTarget::BarEnd {
+                    position: [(self.x_position(edges[0]) + self.x_position(edges[1])) / 2.0, self.y_position(height)],
+                    down: height < 0.0,
+                });
+                text.data_labels.extend(targets.zip(labels));
+            } else {
+                let targets = series
+                    .x
+                    .iter()
+                    .zip(&series.y)
+                    .map(|(&x, &y)| LabelTarget::Point([self.x_position(x), self.y_position(y)]));
+                text.data_labels.extend(targets.zip(labels));
+            }
+        }
+        text
+    }
+
+    /// Show `heatmap` behind the series, or remove it with `None`
+    pub fn set_heatmap(&mut self, heatmap: Option<Heatmap>) {
+        self.heatmap = heatmap;
+        self.aggregated = None;
+    }
+
+    pub fn heatmap(&self) -> Option<&Heatmap> {
+        self.heatmap.as_ref()
+    }
+
+    /// Show `heatmap`, a 2D histogram of `points`, behind the series
+    ///
+    /// Windows count the points again for the visible range after each pan
+    /// or zoom, keeping the heatmap's colormap and value range; offscreen
+    /// renders draw `heatmap` as it is.
+    pub fn set_hist2d(&mut self, heatmap: Heatmap, points: AggregatedPoints) {
+        self.heatmap = Some(heatmap);
+        self.aggregated = Some(points);
+    }
+
+    /// Points of the heatmap set with `set_hist2d`
+    pub fn aggregated(&self) -> Option<&AggregatedPoints> {
+        self.aggregated.as_ref()
+    }
+
+    /// Corner the legend is drawn in; upper right by default
+    pub fn set_legend_location(&mut self, location: LegendLocation) {
+        self.legend_location = location;
+    }
+
+    pub fn legend_location(&self) -> LegendLocation {
+        self.legend_location
+    }
+
+    /// Fixed x range, if set
+    pub fn xlim(&self) -> Option<(f32, f32)> {
+        self.xlim
+    }
+
+    pub fn ylim(&self) -> Option<(f32, f32)> {
+        self.ylim
+    }
+
+    pub fn set_xlim(&mut self, xlim: Option<(f32, f32)>) {
+        self.xlim = xlim;
+    }
+
+    pub fn set_ylim(&mut self, ylim: Option<(f32, f32)>) {
+        self.ylim = ylim;
+    }
+
+    /// Lay the x axis out with `scale`
+    ///
+    /// Series keep their data; it is mapped when drawn, and the limits, the
+    /// data range and the axes' ticks are in mapped units from then on. The
+    /// heatmap is placed as if the axes were linear.
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::figure::Figure;
+    /// use helion_core::scale::AxisScale;
+    ///
+    /// let mut fig = Figure::new(800.0, 600.0);
+    /// fig.scatter(vec![1.0, 10.0, 1000.0], vec![0.0, 1.0, 2.0]).unwrap();
+    /// fig.set_xscale(AxisScale::Log10);
+    /// assert_eq!(fig.data_range().x, (0.0, 3.0));
+    /// ```
+    pub fn set_xscale(&mut self, scale: AxisScale) {
+        self.xscale = scale;
+        self.sync_axis_scales();
+    }
+
+    /// Lay the y axis out with `scale`; see `set_xscale`
+    pub fn set_yscale(&mut self, scale: AxisScale) {
+        self.yscale = scale;
+        self.sync_axis_scales();
+    }
+
+    pub fn xscale(&self) -> AxisScale {
+        self.xscale
+    }
+
+    pub fn yscale(&self) -> AxisScale {
+        self.yscale
+    }
+
+    /// Cut ranges out of the x axis, drawing break markers on the axes
+    ///
+    /// Breaks are in the units of `xscale` (data units on a linear axis),
+    /// and like the scale they apply when the series are drawn; limits and
+    /// the data range are in compressed units from then on.
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::figure::Figure;
+    /// use helion_core::scale::{AxisBreak, BrokenAxis};
+    ///
+    /// let mut fig = Figure::new(800.0, 600.0);
+    /// fig.scatter(vec![0.0, 10.0, 1000.0], vec![0.0, 1.0, 2.0]).unwrap();
+    /// fig.set_xbreaks(Some(BrokenAxis::new(vec![AxisBreak::new(20.0, 990.0)], 5.0)));
+    /// assert_eq!(fig.data_range().x, (0.0, 35.0));
+    /// ```
+    pub fn set_xbreaks(&mut self, breaks: Option<BrokenAxis>) {
+        self.xbreaks = breaks;
+        self.sync_axis_scales();
+    }
+
+    /// Cut ranges out of the y axis; see `set_xbreaks`
+    pub fn set_ybreaks(&mut self, breaks: Option<BrokenAxis>) {
+        self.ybreaks = breaks;
+        self.sync_axis_scales();
+    }
+
+    pub fn xbreaks(&self) -> Option<&BrokenAxis> {
+        self.xbreaks.as_ref()
+    }
+
+    pub fn ybreaks(&self) -> Option<&BrokenAxis> {
+        self.ybreaks.as_ref()
+    }
+
+    fn sync_axis_scales(&mut self) {
+        if let Some(axes) = &mut self.axes {
+            axes.grid.x.scale = self.xscale;
+            axes.grid.y.scale = self.yscale;
+            axes.grid.x.breaks = self.xbreaks.clone();
+            axes.grid.y.breaks = self.ybreaks.clone();
+        }
+    }
+
+    /// Position of data value `x` along the x axis: scaled, then compressed
+    fn x_position(&self, x: f32) -> f32 {
+        axis_position(x, self.xscale, self.xbreaks.as_ref())
+    }
+
+    /// Position of data value `y` along the y axis
+    fn y_position(&self, y: f32) -> f32 {
+        axis_position(y, self.yscale, self.ybreaks.as_ref())
+    }
+
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+
+    pub fn height(&self) -> f32 {
+        self.height
+    }
+
+    /// Add a series as is; returns its index
+    pub fn add(&mut self, series: Series) -> usize {
+        self.series.push(series);
+        self.series.len() - 1
+    }
+
+    /// Add a scatter series colored from `DEFAULT_COLOR_CYCLE`
+    pub fn scatter(&mut self, x: Vec<f32>, y: Vec<f32>) -> Result<&mut Series, HelionError> {
+        Ok(self.add_cycled(Series::scatter(x, y)?))
+    }
+
+    /// Add a line series colored from `DEFAULT_COLOR_CYCLE`
+    pub fn line(&mut self, x: Vec<f32>, y: Vec<f32>) -> Result<&mut Series, HelionError> {
+        let mut series = Series::line(x, y)?;
+        series.style.size = 1.0;
+        Ok(self.add_cycled(series))
+    }
+
+    /// Add bars colored from `DEFAULT_COLOR_CYCLE`; see `Series::bars`
+    pub fn bars(&mut self, edges: Vec<f32>, heights: Vec<f32>) -> Result<&mut Series, HelionError> {
+        Ok(self.add_cycled(Series::bars(edges, heights)?))
+    }
+
+    /// Bin `values` and add the histogram as bars colored from
+    /// `DEFAULT_COLOR_CYCLE`
+    ///
+    /// Bins on the CPU; for millions of values, bin with
+    /// `compute::GpuHistogram` and add the result with `Figure::histogram`.
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::figure::Figure;
+    /// use helion_core::histogram::{BinStrategy, HistogramOptions};
+    ///
+    /// let mut fig = Figure::new(800.0, 600.0);
+    /// let options = HistogramOptions { bins: BinStrategy::Count(2), ..Default::default() };
+    /// let bars = fig.hist(&[0.0, 1.0, 1.5, 2.0], &options);
+    /// assert_eq!(bars.x(), &[0.0, 1.0, 2.0]);
+    /// assert_eq!(bars.y(), &[1.0, 3.0]);
+    /// ```
+    pub fn hist(&mut self, values: &[f32], options: &HistogramOptions) -> &mut Series {
+        self.histogram(Histogram::compute(values, options))
+    }
+
+    /// Add bars of an already binned histogram, colored from
+    /// `DEFAULT_COLOR_CYCLE`
+    pub fn histogram(&mut self, histogram: Histogram) -> &mut Series {
+        self.add_cycled(Series::histogram(histogram))
+    }
+
+    fn add_cycled(&mut self, series: Series) -> &mut Series {
+        let color = Color::from_hex(DEFAULT_COLOR_CYCLE[self.series.len() % DEFAULT_COLOR_CYCLE.len()]);
+        let index = self.add(series.with_color(color));
+        &mut self.series[index]
+    }
+
+    pub fn series(&self) -> &[Series] {
+        &self.series
+    }
+
+    pub fn series_mut(&mut self, index: usize) -> Option<&mut Series> {
+        self.series.get_mut(index)
+    }
+
+    /// Number of series
+    pub fn len(&self) -> usize {
+        self.series.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.series.is_empty()
+    }
+
+    /// Data range shown: the limits where set, else the union of all series'
+    /// extents and the heatmap's, mapped through the axis scales
+    ///
+    /// An axis without any finite data falls back to (0, 1).
+    pub fn data_range(&self) -> ViewRange {
+        let union = |axis: fn(&ViewRange) -> (f32, f32)| {
+            let (min, max) = self
+                .series
+                .iter()
+                .map(|s| self.scaled_extent(s))
+                .chain(self.heatmap.as_ref().map(Heatmap::extent))
+                .map(|extent| axis(&extent))
+                .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), (min, max)| (lo.min(min), hi.max(max)));
+            if min <= max {
+                (min, max)
+            } else {
+                (0.0, 1.0)
+            }
+        };
+        ViewRange::new(
+            self.xlim.map(|(min, max)| (self.x_position(min), self.x_position(max))).unwrap_or_else(|| union(|r| r.x)),
+            self.ylim.map(|(min, max)| (self.y_position(min), self.y_position(max))).unwrap_or_else(|| union(|r| r.y)),
+        )
+    }
+
+    /// `Series::extent` in mapped units; values a log scale can't show are
+    /// left out
+    fn scaled_extent(&self, series: &Series) -> ViewRange {
+        let fold = |values: &[f32], position: &dyn Fn(f32) -> f32| {
+            values
+                .iter()
+                .map(|&v| position(v))
+                .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), v| (lo.min(v), hi.max(v)))
+        };
+        let mut y = fold(&series.y, &|v| self.y_position(v));
+        if series.kind == ChartKind::Bars && !series.y.is_empty() {
+            let base = self.y_position(0.0);
+            y = (y.0.min(base), y.1.max(base));
+        }
+        ViewRange::new(fold(&series.x, &|v| self.x_position(v)), y)
+    }
+
+    /// Vertices of series `index` in the shared coordinate system, styled
+    /// with its `SeriesStyle`
+    pub fn chart_data(&self, index: usize) -> Option<ChartData> {
+        let series = self.series.get(index)?;
+        let range = self.data_range();
+        let color = series.style.effective_color();
+        if series.kind == ChartKind::Bars {
+            let mut data = ChartData::with_capacity(self.width, self.height, series.len() * 6);
+            // On a log axis bars rise from the bottom edge instead of y = 0
+            let base = match to_clip(self.y_position(0.0), range.y) {
+                base if base.is_nan() => -1.0,
+                base => base,
+            };
+            for (edges, &height) in series.x.windows(2).zip(&series.y) {
+                let top = to_clip(self.y_position(height), range.y);
+                data.add_rect(
+                    Point2D::new(to_clip(self.x_position(edges[0]), range.x), base.min(top)),
+                    Point2D::new(to_clip(self.x_position(edges[1]), range.x), base.max(top)),
+                    color,
+                );
+            }
+            return Some(data);
+        }
+        let gaps = match series.gaps {
+            Some(threshold) if series.kind == ChartKind::Line => gap_indices(&series.x, threshold),
+            _ => Vec::new(),
+        };
+        let mut data = ChartData::with_capacity(self.width, self.height, series.len() + gaps.len());
+        let mut gaps = gaps.into_iter().peekable();
+        for (i, (&x, &y)) in series.x.iter().zip(&series.y).enumerate() {
+            // `LineRenderer` doesn't join the vertices either side of a NaN one
+            if gaps.next_if_eq(&i).is_some() {
+                data.add_point(Point2D::new(f32::NAN, f32::NAN), color, series.style.size);
+            }
+            let position = Point2D::new(to_clip(self.x_position(x), range.x), to_clip(self.y_position(y), range.y));
+            data.add_point(position, color, series.style.size);
+        }
+        Some(data)
+    }
+
+    /// Every series ready to draw, back to front: by `SeriesStyle::z_order`,
+    /// then in the order added
+    pub fn layers(&self) -> Vec<SeriesLayer> {
+        let mut order: Vec<usize> = (0..self.series.len()).collect();
+        order.sort_by_key(|&i| self.series[i].style.z_order);
+        order
+            .into_iter()
+            .filter_map(|i| {
+                Some(SeriesLayer {
+                    series: i,
+                    kind: self.series[i].kind,
+                    data: self.chart_data(i)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Legend with one entry per series, or `None` if no series is labeled
+    pub fn legend(&self) -> Option<Legend> {
+        if self.series.iter().all(|s| s.label.is_none()) {
+            return None;
+        }
+        let mut legend = Legend::new(self.width, self.height).with_location(self.legend_location);
+        for series in &self.series {
+            legend.add(series.label.clone().unwrap_or_default(), series.style.color);
+        }
+        Some(legend)
+    }
+}
+
+/// Map `value` from `range` onto clip space [-1, 1]; an empty range maps
+/// everything to the center
+fn axis_position(value: f32, scale: AxisScale, breaks: Option<&BrokenAxis>) -> f32 {
+    let position = scale.forward(value);
+    breaks.map_or(position, |breaks| breaks.compress(position))
+}
+
+fn to_clip(value: f32, (min, max): (f32, f32)) -> f32 {
+    let span = max - min;
+    if span == 0.0 || !span.is_finite() {
+        return 0.0;
+    }
+    (value - min) / span * 2.0 - 1.0
+}
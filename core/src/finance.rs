@@ -0,0 +1,373 @@
+use crate::data::{ChartData, Color, Point2D};
+use crate::layout::{data_extent, CellRect, ShareAxis, SubplotGrid};
+
+/// Open/high/low/close/volume series, one entry per bar
+#[derive(Debug, Clone, Default)]
+pub struct Ohlc {
+    /// Bar timestamps (any monotonic unit, e.g. seconds since epoch)
+    pub time: Vec<f32>,
+    pub open: Vec<f32>,
+    pub high: Vec<f32>,
+    pub low: Vec<f32>,
+    pub close: Vec<f32>,
+    pub volume: Vec<f32>,
+}
+
+impl Ohlc {
+    /// Create a series; all arrays must have the same length
+    pub fn new(
+        time: Vec<f32>,
+        open: Vec<f32>,
+        high: Vec<f32>,
+        low: Vec<f32>,
+        close: Vec<f32>,
+        volume: Vec<f32>,
+    ) -> Result<Self, String> {
+        let n = time.len();
+        let lengths = [open.len(), high.len(), low.len(), close.len(), volume.len()];
+        if lengths.iter().any(|&len| len != n) {
+            return Err(format!(
+                "OHLC arrays must have equal lengths (time: {}, open: {}, high: {}, low: {}, close: {}, volume: {})",
+                n, lengths[0], lengths[1], lengths[2], lengths[3], lengths[4]
+            ));
+        }
+        Ok(Self { time, open, high, low, close, volume })
+    }
+
+    /// Number of bars
+    pub fn len(&self) -> usize {
+        self.time.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.time.is_empty()
+    }
+
+    /// Heikin-Ashi bars averaged from these, which smooth out noise so
+    /// trends show as runs of one color
+    ///
+    /// Each bar closes at the mean of its open, high, low and close, and
+    /// opens at the midpoint of the previous averaged bar's body (the first
+    /// at the midpoint of its own). Highs and lows are widened to cover the
+    /// averaged body; time and volume are kept.
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::finance::Ohlc;
+    ///
+    /// let ohlc = Ohlc::new(
+    ///     vec![0.0, 1.0],
+    ///     vec![10.0, 12.0],
+    ///     vec![14.0, 13.0],
+    ///     vec![8.0, 11.0],
+    ///     vec![12.0, 11.0],
+    ///     vec![100.0, 200.0],
+    /// ).unwrap();
+    /// let ha = ohlc.heikin_ashi();
+    /// assert_eq!((ha.open[0], ha.close[0]), (11.0, 11.0));
+    /// assert_eq!((ha.open[1], ha.close[1]), (11.0, 11.75));
+    /// ```
+    pub fn heikin_ashi(&self) -> Ohlc {
+        let mut ha = Ohlc {
+            time: self.time.clone(),
+            volume: self.volume.clone(),
+            ..Default::default()
+        };
+        for i in 0..self.len() {
+            let close = (self.open[i] + self.high[i] + self.low[i] + self.close[i]) / 4.0;
+            let open = match i {
+                0 => (self.open[0] + self.close[0]) / 2.0,
+                _ => (ha.open[i - 1] + ha.close[i - 1]) / 2.0,
+            };
+            ha.high.push(self.high[i].max(open).max(close));
+            ha.low.push(self.low[i].min(open).min(close));
+            ha.open.push(open);
+            ha.close.push(close);
+        }
+        ha
+    }
+
+    /// Whether bar `i` closed at or above its open
+    pub fn is_up(&self, i: usize) -> bool {
+        self.close[i] >= self.open[i]
+    }
+
+    /// Time extent padded by half a bar on each side so edge candles aren't clipped
+    pub fn time_extent(&self) -> (f32, f32) {
+        let (min, max) = data_extent(&self.time);
+        let spacing = self.min_spacing();
+        let pad = if spacing.is_finite() { spacing / 2.0 } else { 0.0 };
+        (min - pad, max + pad)
+    }
+
+    /// Smallest nonzero gap between consecutive timestamps (infinite if none)
+    fn min_spacing(&self) -> f32 {
+        self.time
+            .windows(2)
+            .map(|w| (w[1] - w[0]).abs())
+            .filter(|d| *d > 0.0)
+            .fold(f32::INFINITY, f32::min)
+    }
+}
+
+/// How each bar of a price series is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CandleKind {
+    /// Solid body between open and close, with a wick from low to high
+    #[default]
+    Filled,
+    /// Like `Filled`, but bars that closed at or above their open have an
+    /// outlined body, with the wick stopping at its edges
+    Hollow,
+    /// Western OHLC bar: a line from low to high with a tick to the left at
+    /// the open and one to the right at the close
+    Bar,
+}
+
+/// Candle and volume bar appearance
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CandleStyle {
+    pub kind: CandleKind,
+    /// Color of bars that closed at or above their open
+    pub up: Color,
+    /// Color of bars that closed below their open
+    pub down: Color,
+    /// Body width as a fraction of the smallest bar spacing
+    pub body_width: f32,
+    /// Wick width as a fraction of the body width; also the thickness of
+    /// hollow body outlines and OHLC bar ticks
+    pub wick_width: f32,
+    /// Opacity multiplier applied to volume bars
+    pub volume_alpha: f32,
+}
+
+impl Default for CandleStyle {
+    fn default() -> Self {
+        Self {
+            kind: CandleKind::Filled,
+            up: Color::from_hex("#26a69a"),
+            down: Color::from_hex("#ef5350"),
+            body_width: 0.7,
+            wick_width: 0.15,
+            volume_alpha: 0.5,
+        }
+    }
+}
+
+/// Map `value` from `extent` onto `out`
+fn normalize(value: f32, extent: (f32, f32), out: (f32, f32)) -> f32 {
+    let span = extent.1 - extent.0;
+    let t = if span > 0.0 { (value - extent.0) / span } else { 0.5 };
+    out.0 + t * (out.1 - out.0)
+}
+
+/// Build candlestick geometry (wicks and bodies, or OHLC bars, as
+/// `style.kind` picks) as a triangle list
+///
+/// # Parameters
+/// * `ohlc` - Price series; pass `ohlc.heikin_ashi()` for Heikin-Ashi candles
+/// * `style` - Colors, widths and kind of bar
+/// * `x_out`, `y_out` - Output ranges for the time and price extents, e.g.
+///   from `SubplotGrid::output_ranges`
+/// * `width`, `height` - Viewport size in pixels
+///
+/// # Returns
+/// `ChartData` to draw with `MeshRenderer`
+pub fn candlestick_data(
+    ohlc: &Ohlc,
+    style: &CandleStyle,
+    x_out: (f32, f32),
+    y_out: (f32, f32),
+    width: f32,
+    height: f32,
+) -> ChartData {
+    let mut data = ChartData::new(width, height);
+    if ohlc.is_empty() {
+        return data;
+    }
+
+    let x_extent = ohlc.time_extent();
+    let y_extent = (data_extent(&ohlc.low).0, data_extent(&ohlc.high).1);
+    let half_body = half_width(ohlc, x_extent, x_out, style.body_width);
+    let half_wick = half_body * style.wick_width;
+    // Wick thickness measured vertically, so outlines and ticks look as
+    // thick as the wicks
+    let line_y = 2.0 * half_wick * width / height.max(1.0);
+
+    for i in 0..ohlc.len() {
+        let color = if ohlc.is_up(i) { style.up } else { style.down };
+        let x = normalize(ohlc.time[i], x_extent, x_out);
+        let y = |v: f32| normalize(v, y_extent, y_out);
+        let (low, high) = (y(ohlc.low[i]).min(y(ohlc.high[i])), y(ohlc.low[i]).max(y(ohlc.high[i])));
+        let (open, close) = (y(ohlc.open[i]), y(ohlc.close[i]));
+        let (bottom, top) = (open.min(close), open.max(close));
+        let rect = |data: &mut ChartData, x0: f32, y0: f32, x1: f32, y1: f32| {
+            data.add_rect(Point2D::new(x0, y0), Point2D::new(x1, y1), color)
+        };
+
+        match style.kind {
+            CandleKind::Bar => {
+                rect(&mut data, x - half_wick, low, x + half_wick, high);
+                rect(&mut data, x - half_body, open - line_y / 2.0, x, open + line_y / 2.0);
+                rect(&mut data, x, close - line_y / 2.0, x + half_body, close + line_y / 2.0);
+            }
+            // Outlines too thin to leave a hole are drawn solid
+            CandleKind::Hollow if ohlc.is_up(i) && top - bottom > 2.0 * line_y => {
+                if bottom > low {
+                    rect(&mut data, x - half_wick, low, x + half_wick, bottom);
+                }
+                if high > top {
+                    rect(&mut data, x - half_wick, top, x + half_wick, high);
+                }
+                let (left, right) = (x - half_body, x + half_body);
+                let side = (2.0 * half_wick).min(half_body);
+                rect(&mut data, left, bottom, right, bottom + line_y);
+                rect(&mut data, left, top - line_y, right, top);
+                rect(&mut data, left, bottom + line_y, left + side, top - line_y);
+                rect(&mut data, right - side, bottom + line_y, right, top - line_y);
+            }
+            CandleKind::Filled | CandleKind::Hollow => {
+                // Wick first so the body covers its middle
+                rect(&mut data, x - half_wick, low, x + half_wick, high);
+                rect(&mut data, x - half_body, bottom, x + half_body, top);
+            }
+        }
+    }
+    data
+}
+
+/// Build volume bars rising from zero as a triangle list
+///
+/// Bars take the color of their candle, faded by `style.volume_alpha`.
+pub fn volume_data(
+    ohlc: &Ohlc,
+    style: &CandleStyle,
+    x_out: (f32, f32),
+    y_out: (f32, f32),
+    width: f32,
+    height: f32,
+) -> ChartData {
+    let mut data = ChartData::new(width, height);
+    if ohlc.is_empty() {
+        return data;
+    }
+
+    let x_extent = ohlc.time_extent();
+    let y_extent = (0.0, data_extent(&ohlc.volume).1.max(0.0));
+    let half_body = half_width(ohlc, x_extent, x_out, style.body_width);
+
+    for i in 0..ohlc.len() {
+        let mut color = if ohlc.is_up(i) { style.up } else { style.down };
+        color.a *= style.volume_alpha;
+        let x = normalize(ohlc.time[i], x_extent, x_out);
+        data.add_rect(
+            Point2D::new(x - half_body, normalize(0.0, y_extent, y_out)),
+            Point2D::new(x + half_body, normalize(ohlc.volume[i].max(0.0), y_extent, y_out)),
+            color,
+        );
+    }
+    data
+}
+
+/// Half of a bar's width in output units
+fn half_width(ohlc: &Ohlc, x_extent: (f32, f32), x_out: (f32, f32), fraction: f32) -> f32 {
+    let span = x_extent.1 - x_extent.0;
+    let out_span = (x_out.1 - x_out.0).abs();
+    // A single timestamp has no spacing to go by; let it fill the panel
+    let spacing = if span > 0.0 {
+        ohlc.min_spacing() / span * out_span
+    } else {
+        out_span
+    };
+    spacing * fraction / 2.0
+}
+
+/// Finance figure: candlestick panel above a linked volume panel
+///
+/// The two panels are stacked in a `SubplotGrid` that shares the x (time)
+/// axis, so a bar's candle and its volume always line up vertically.
+///
+/// # Example
+/// ```
+/// use helion_core::finance::{CandleStyle, FinanceFigure, Ohlc};
+///
+/// let ohlc = Ohlc::new(
+///     vec![0.0, 1.0, 2.0],
+///     vec![10.0, 11.0, 10.5],
+///     vec![12.0, 12.0, 11.0],
+///     vec![9.0, 10.0, 9.5],
+///     vec![11.0, 10.5, 10.8],
+///     vec![100.0, 250.0, 80.0],
+/// ).unwrap();
+///
+/// let figure = FinanceFigure::new(&ohlc, &CandleStyle::default(), 800.0, 600.0);
+/// assert_eq!(figure.price.vertices.len(), 3 * 12);
+/// assert_eq!(figure.volume.vertices.len(), 3 * 6);
+/// ```
+pub struct FinanceFigure {
+    pub grid: SubplotGrid,
+    /// Candle wicks and bodies (subplot 0)
+    pub price: ChartData,
+    /// Volume bars (subplot 1)
+    pub volume: ChartData,
+    width: f32,
+    height: f32,
+}
+
+impl FinanceFigure {
+    /// Compose a figure with a 3:1 price-to-volume height ratio
+    pub fn new(ohlc: &Ohlc, style: &CandleStyle, width: f32, height: f32) -> Self {
+        Self::with_height_ratios(ohlc, style, width, height, 3.0, 1.0)
+    }
+
+    /// Compose a figure with custom price and volume panel height ratios
+    pub fn with_height_ratios(
+        ohlc: &Ohlc,
+        style: &CandleStyle,
+        width: f32,
+        height: f32,
+        price_ratio: f32,
+        volume_ratio: f32,
+    ) -> Self {
+        let grid = SubplotGrid::new(2, 1)
+            .with_sharex(ShareAxis::All)
+            .with_height_ratios(vec![price_ratio, volume_ratio]);
+
+        let time = ohlc.time_extent();
+        let ranges = grid.output_ranges(&[
+            (time, (data_extent(&ohlc.low).0, data_extent(&ohlc.high).1)),
+            (time, (0.0, data_extent(&ohlc.volume).1)),
+        ]);
+
+        let price_rect = grid.cell_rect(0, width, height);
+        let volume_rect = grid.cell_rect(1, width, height);
+        let price = candlestick_data(
+            ohlc,
+            style,
+            ranges[0].0,
+            ranges[0].1,
+            price_rect.width,
+            price_rect.height,
+        );
+        let volume = volume_data(
+            ohlc,
+            style,
+            ranges[1].0,
+            ranges[1].1,
+            volume_rect.width,
+            volume_rect.height,
+        );
+
+        Self { grid, price, volume, width, height }
+    }
+
+    /// Pixel rectangle of the price panel, for `RenderPass::set_viewport`
+    pub fn price_rect(&self) -> CellRect {
+        self.grid.cell_rect(0, self.width, self.height)
+    }
+
+    /// Pixel rectangle of the volume panel, for `RenderPass::set_viewport`
+    pub fn volume_rect(&self) -> CellRect {
+        self.grid.cell_rect(1, self.width, self.height)
+    }
+}
@@ -0,0 +1,305 @@
+use crate::colormap::ColormapLut;
+use crate::data::{ChartData, Color, Point2D};
+use crate::error::HelionError;
+use crate::layout::Extent;
+use crate::norm::Normalization;
+use crate::polygon::{polygon_data, polygons_extent, Polygon};
+use serde_json::{Map, Value};
+
+/// One region of a GeoJSON file: the polygons of its geometry and its
+/// properties
+///
+/// Point and line geometries have no area to fill and are left out, so a
+/// feature may have no polygons; it still keeps its place in the list.
+#[derive(Debug, Clone, Default)]
+pub struct Feature {
+    pub polygons: Vec<Polygon>,
+    pub properties: Map<String, Value>,
+}
+
+impl Feature {
+    /// Property `key` as a number; numeric strings are parsed
+    pub fn number(&self, key: &str) -> Option<f32> {
+        match self.properties.get(key)? {
+            Value::Number(n) => n.as_f64().map(|n| n as f32),
+            Value::String(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Property `key` as text, e.g. a region name
+    pub fn text(&self, key: &str) -> Option<&str> {
+        self.properties.get(key)?.as_str()
+    }
+
+    /// Whether `point`, in the file's coordinates, is inside any polygon
+    pub fn contains(&self, point: Point2D) -> bool {
+        self.polygons.iter().any(|polygon| polygon.contains(point))
+    }
+}
+
+/// Read the features of a GeoJSON document
+///
+/// Accepts a FeatureCollection, a single Feature or a bare geometry.
+/// Polygon, MultiPolygon and GeometryCollection geometries are read;
+/// coordinates stay as written (usually longitude and latitude), so maps
+/// come out in the equirectangular projection.
+///
+/// # Returns
+/// One `Feature` per input feature, in order, or
+/// `HelionError::InvalidOption` describing the first malformed part
+///
+/// # Example
+/// ```
+/// use helion_core::geo::read_geojson;
+///
+/// let json = r#"{"type": "FeatureCollection", "features": [{
+///     "type": "Feature",
+///     "properties": {"name": "Square", "population": 120},
+///     "geometry": {"type": "Polygon", "coordinates": [[[0, 0], [1, 0], [1, 1], [0, 1], [0, 0]]]}
+/// }]}"#;
+/// let features = read_geojson(json).unwrap();
+/// assert_eq!(features[0].text("name"), Some("Square"));
+/// assert_eq!(features[0].number("population"), Some(120.0));
+/// assert_eq!(features[0].polygons[0].area(), 1.0);
+/// ```
+pub fn read_geojson(json: &str) -> Result<Vec<Feature>, HelionError> {
+    let invalid = |e: String| HelionError::InvalidOption(format!("Invalid GeoJSON: {}", e));
+    let root: Value = serde_json::from_str(json).map_err(|e| invalid(e.to_string()))?;
+    match type_of(&root).map_err(invalid)? {
+        "FeatureCollection" => root
+            .get("features")
+            .and_then(Value::as_array)
+            .ok_or_else(|| invalid("a FeatureCollection needs a \"features\" array".to_string()))?
+            .iter()
+            .enumerate()
+            .map(|(i, feature)| read_feature(feature).map_err(|e| invalid(format!("feature {}: {}", i, e))))
+            .collect(),
+        "Feature" => Ok(vec![read_feature(&root).map_err(invalid)?]),
+        _ => {
+            let mut polygons = Vec::new();
+            read_geometry(&root, &mut polygons).map_err(invalid)?;
+            Ok(vec![Feature {
+                polygons,
+                properties: Map::new(),
+            }])
+        }
+    }
+}
+
+fn type_of(object: &Value) -> Result<&str, String> {
+    object
+        .get("type")
+        .and_then(Value::as_str)
+        .ok_or_else(|| "every object needs a \"type\"".to_string())
+}
+
+fn read_feature(feature: &Value) -> Result<Feature, String> {
+    if type_of(feature)? != "Feature" {
+        return Err(format!("expected a Feature, got {}", type_of(feature)?));
+    }
+    let mut polygons = Vec::new();
+    match feature.get("geometry") {
+        None | Some(Value::Null) => {}
+        Some(geometry) => read_geometry(geometry, &mut polygons)?,
+    }
+    let properties = match feature.get("properties") {
+        Some(Value::Object(properties)) => properties.clone(),
+        _ => Map::new(),
+    };
+    Ok(Feature { polygons, properties })
+}
+
+/// Append the polygons of `geometry` to `out`
+fn read_geometry(geometry: &Value, out: &mut Vec<Polygon>) -> Result<(), String> {
+    let coordinates = || geometry.get("coordinates").ok_or_else(|| "geometry without \"coordinates\"".to_string());
+    match type_of(geometry)? {
+        "Polygon" => out.push(read_polygon(coordinates()?)?),
+        "MultiPolygon" => {
+            for polygon in as_array(coordinates()?)? {
+                out.push(read_polygon(polygon)?);
+            }
+        }
+        "GeometryCollection" => {
+            let geometries = geometry
+                .get("geometries")
+                .ok_or_else(|| "GeometryCollection without \"geometries\"".to_string())?;
+            for geometry in as_array(geometries)? {
+                read_geometry(geometry, out)?;
+            }
+        }
+        "Point" | "MultiPoint" | "LineString" | "MultiLineString" => {}
+        other => return Err(format!("unknown geometry type {}", other)),
+    }
+    Ok(())
+}
+
+/// A polygon from its rings: the exterior first, then holes
+fn read_polygon(rings: &Value) -> Result<Polygon, String> {
+    let mut rings = as_array(rings)?.iter().map(read_ring);
+    let exterior = rings.next().ok_or_else(|| "polygon without rings".to_string())??;
+    rings.try_fold(Polygon::new(exterior), |polygon, hole| Ok(polygon.with_hole(hole?)))
+}
+
+fn read_ring(ring: &Value) -> Result<Vec<Point2D>, String> {
+    as_array(ring)?
+        .iter()
+        .map(|position| {
+            let xy = as_array(position)?;
+            match (xy.first().and_then(Value::as_f64), xy.get(1).and_then(Value::as_f64)) {
+                (Some(x), Some(y)) => Ok(Point2D::new(x as f32, y as f32)),
+                _ => Err(format!("a position needs two numbers, got {}", position)),
+            }
+        })
+        .collect()
+}
+
+fn as_array(value: &Value) -> Result<&Vec<Value>, String> {
+    value.as_array().ok_or_else(|| format!("expected an array, got {}", value))
+}
+
+/// Regions filled by value through a colormap
+///
+/// # Example
+/// ```
+/// use helion_core::data::Point2D;
+/// use helion_core::geo::{Choropleth, Feature};
+/// use helion_core::polygon::Polygon;
+///
+/// let square = |x: f32| Feature {
+///     polygons: vec![Polygon::new(vec![
+///         Point2D::new(x, 0.0), Point2D::new(x + 1.0, 0.0), Point2D::new(x + 1.0, 1.0), Point2D::new(x, 1.0),
+///     ])],
+///     ..Default::default()
+/// };
+/// let map = Choropleth::new(vec![square(0.0), square(1.0)], vec![10.0, 20.0]).unwrap();
+/// assert_eq!(map.feature_at(Point2D::new(1.5, 0.5)), Some(1));
+/// assert_eq!(map.chart_data(800.0, 400.0).vertices.len(), 12);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Choropleth {
+    features: Vec<Feature>,
+    values: Vec<f32>,
+    colormap: ColormapLut,
+    normalization: Normalization,
+    range: Option<(f32, f32)>,
+    missing_color: Color,
+}
+
+impl Choropleth {
+    /// Color `features[i]` by `values[i]`
+    ///
+    /// # Returns
+    /// `HelionError::ShapeMismatch` unless there is one value per feature
+    pub fn new(features: Vec<Feature>, values: Vec<f32>) -> Result<Self, HelionError> {
+        if values.len() != features.len() {
+            return Err(HelionError::ShapeMismatch {
+                what: "choropleth values, one per feature".to_string(),
+                expected: features.len(),
+                actual: values.len(),
+            });
+        }
+        Ok(Self {
+            features,
+            values,
+            colormap: ColormapLut::default(),
+            normalization: Normalization::default(),
+            range: None,
+            missing_color: Color::new(0.8, 0.8, 0.8, 1.0),
+        })
+    }
+
+    /// Color each feature by its numeric property `key`; features without
+    /// one get the missing color
+    pub fn from_property(features: Vec<Feature>, key: &str) -> Self {
+        let values = features.iter().map(|f| f.number(key).unwrap_or(f32::NAN)).collect();
+        Self::new(features, values).expect("one value per feature")
+    }
+
+    pub fn with_colormap(mut self, colormap: ColormapLut) -> Self {
+        self.colormap = colormap;
+        self
+    }
+
+    /// How values map onto the colormap; `Normalization::Linear` by default
+    pub fn with_normalization(mut self, normalization: Normalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Values at the two ends of the colormap, instead of the data's extent
+    pub fn with_range(mut self, vmin: f32, vmax: f32) -> Self {
+        self.range = Some((vmin, vmax));
+        self
+    }
+
+    /// Fill of features whose value is NaN or that the normalization can't
+    /// place; light gray by default
+    pub fn with_missing_color(mut self, color: Color) -> Self {
+        self.missing_color = color;
+        self
+    }
+
+    pub fn features(&self) -> &[Feature] {
+        &self.features
+    }
+
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+
+    /// Fill color of each feature
+    pub fn fill_colors(&self) -> Vec<Color> {
+        let norm = self.normalization.fit(&self.values, self.range);
+        self.colormap
+            .colorize(&self.values, &norm)
+            .into_iter()
+            .map(|[r, g, b, a]| match a {
+                0 => self.missing_color,
+                _ => Color::new(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, a as f32 / 255.0),
+            })
+            .collect()
+    }
+
+    /// Union of the features' extents
+    pub fn extent(&self) -> Extent {
+        let polygons: Vec<Polygon> = self.features.iter().flat_map(|f| f.polygons.iter().cloned()).collect();
+        polygons_extent(&polygons)
+    }
+
+    /// Index of the first feature containing `point`, given in the file's
+    /// coordinates
+    pub fn feature_at(&self, point: Point2D) -> Option<usize> {
+        self.features.iter().position(|feature| feature.contains(point))
+    }
+
+    /// Output ranges showing the whole map in a `width` x `height`
+    /// viewport with equal units on both axes, centered on the shorter one
+    pub fn output_ranges(&self, width: f32, height: f32) -> ((f32, f32), (f32, f32)) {
+        let ((x_min, x_max), (y_min, y_max)) = self.extent();
+        let (span_x, span_y) = ((x_max - x_min) * height, (y_max - y_min) * width);
+        if !(span_x > 0.0 && span_y > 0.0) {
+            return ((-1.0, 1.0), (-1.0, 1.0));
+        }
+        if span_x >= span_y {
+            let half = span_y / span_x;
+            ((-1.0, 1.0), (-half, half))
+        } else {
+            let half = span_x / span_y;
+            ((-half, half), (-1.0, 1.0))
+        }
+    }
+
+    /// Filled regions for `PolygonRenderer` in a `width` x `height`
+    /// viewport, laid out by `output_ranges`
+    pub fn chart_data(&self, width: f32, height: f32) -> ChartData {
+        let mut polygons = Vec::new();
+        let mut colors = Vec::new();
+        for (feature, color) in self.features.iter().zip(self.fill_colors()) {
+            polygons.extend(feature.polygons.iter().cloned());
+            colors.extend(std::iter::repeat_n(color, feature.polygons.len()));
+        }
+        let (x_out, y_out) = self.output_ranges(width, height);
+        polygon_data(&polygons, &colors, polygons_extent(&polygons), x_out, y_out, width, height)
+    }
+}
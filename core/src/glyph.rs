@@ -0,0 +1,327 @@
+use crate::chunked::ChunkedBuffer;
+use crate::data::{ChartData, Vertex};
+use crate::renderer::{RenderOptions, Renderer, WindowRenderer};
+use crate::shaders::{GLYPH_VERTEX_SHADER, SIMPLE_FRAGMENT_SHADER};
+use crate::stats::DrawStats;
+use wgpu::util::DeviceExt;
+
+/// Half-width of the cross marker's arms, in unit coordinates
+const CROSS_HALF_WIDTH: f32 = 0.3;
+
+/// Marker shape drawn at every point by `GlyphRenderer`
+///
+/// Shapes are triangle lists in unit coordinates: the marker is scaled so
+/// that x and y in [-1, 1] span the point's size in pixels.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GlyphShape {
+    /// Regular polygon approximating a circle
+    Circle { segments: u32 },
+    Square,
+    Diamond,
+    /// Upward-pointing triangle inscribed in the unit circle
+    Triangle,
+    /// Plus sign
+    Cross,
+    /// Any marker, as a triangle list; a trailing partial triangle is ignored
+    Custom(Vec<[f32; 2]>),
+}
+
+impl Default for GlyphShape {
+    fn default() -> Self {
+        GlyphShape::Circle { segments: 16 }
+    }
+}
+
+impl GlyphShape {
+    /// Triangle-list vertices of the marker in unit coordinates
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::glyph::GlyphShape;
+    ///
+    /// assert_eq!(GlyphShape::Square.mesh().len(), 6);
+    /// assert_eq!(GlyphShape::Circle { segments: 8 }.mesh().len(), 24);
+    /// ```
+    pub fn mesh(&self) -> Vec<[f32; 2]> {
+        match self {
+            GlyphShape::Circle { segments } => {
+                let segments = (*segments).max(3);
+                let corner = |k: u32| {
+                    let angle = std::f32::consts::TAU * k as f32 / segments as f32;
+                    [angle.cos(), angle.sin()]
+                };
+                (0..segments)
+                    .flat_map(|k| [[0.0, 0.0], corner(k), corner(k + 1)])
+                    .collect()
+            }
+            GlyphShape::Square => rect([-1.0, -1.0], [1.0, 1.0]),
+            GlyphShape::Diamond => vec![
+                [0.0, -1.0], [1.0, 0.0], [0.0, 1.0],
+                [0.0, -1.0], [0.0, 1.0], [-1.0, 0.0],
+            ],
+            GlyphShape::Triangle => {
+                let half = 3.0f32.sqrt() / 2.0;
+                vec![[0.0, 1.0], [-half, -0.5], [half, -0.5]]
+            }
+            GlyphShape::Cross => {
+                let w = CROSS_HALF_WIDTH;
+                // Vertical bar plus two side arms, so no area is covered twice
+                let mut mesh = rect([-w, -1.0], [w, 1.0]);
+                mesh.extend(rect([-1.0, -w], [-w, w]));
+                mesh.extend(rect([w, -w], [1.0, w]));
+                mesh
+            }
+            GlyphShape::Custom(vertices) => {
+                let whole = vertices.len() - vertices.len() % 3;
+                vertices[..whole].to_vec()
+            }
+        }
+    }
+}
+
+/// Two triangles covering the axis-aligned rectangle `min`..`max`
+fn rect(min: [f32; 2], max: [f32; 2]) -> Vec<[f32; 2]> {
+    vec![
+        [min[0], min[1]], [max[0], min[1]], [max[0], max[1]],
+        [min[0], min[1]], [max[0], max[1]], [min[0], max[1]],
+    ]
+}
+
+/// Uniform block layout shared with `GLYPH_VERTEX_SHADER`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct GlyphUniforms {
+    pub viewport: [f32; 2],
+    pub _padding: [f32; 2],
+}
+
+impl GlyphUniforms {
+    pub fn new(width: f32, height: f32) -> Self {
+        Self {
+            viewport: [width, height],
+            _padding: [0.0; 2],
+        }
+    }
+}
+
+/// Scatter renderer that draws every point as an instance of one marker mesh
+///
+/// The marker is uploaded once and each point only contributes a 40-byte
+/// instance (position, color, size), so even a 64-segment circle costs no
+/// extra upload per point. As with `ScatterRenderer`, `size` is the marker's
+/// width in pixels.
+///
+/// ```ignore
+/// let mut glyphs = GlyphRenderer::with_shape(&device, &config, data, GlyphShape::Triangle, &options);
+/// glyphs.render_to_pass(&mut render_pass);
+/// ```
+pub struct GlyphRenderer {
+    render_pipeline: wgpu::RenderPipeline,
+    uniform_buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+    mesh_buffer: Option<wgpu::Buffer>,
+    mesh_vertex_count: u32,
+    instance_buffer: Option<ChunkedBuffer>,
+    uniforms: GlyphUniforms,
+    shape: GlyphShape,
+    uploaded_bytes: u64,
+}
+
+impl GlyphRenderer {
+    /// Create a renderer drawing `shape` at every point of `chart_data`
+    pub fn with_shape(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        chart_data: ChartData,
+        shape: GlyphShape,
+        options: &RenderOptions,
+    ) -> Self {
+        let uniforms = GlyphUniforms::new(config.width as f32, config.height as f32);
+        let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Glyph Uniform Buffer"),
+            contents: bytemuck::bytes_of(&uniforms),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Glyph Bind Group Layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Glyph Bind Group"),
+            layout: &bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: uniform_buffer.as_entire_binding(),
+            }],
+        });
+
+        let vertex_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Glyph Vertex Shader"),
+            source: wgpu::ShaderSource::Wgsl(GLYPH_VERTEX_SHADER.into()),
+        });
+
+        let fragment_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Glyph Fragment Shader"),
+            source: wgpu::ShaderSource::Wgsl(SIMPLE_FRAGMENT_SHADER.into()),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Glyph Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        // Slot 1: one unit-space corner per mesh vertex
+        let mesh_layout = wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<[f32; 2]>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &[wgpu::VertexAttribute {
+                offset: 0,
+                shader_location: 3,
+                format: wgpu::VertexFormat::Float32x2,
+            }],
+        };
+
+        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Glyph Render Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &vertex_shader,
+                entry_point: "vs_main",
+                buffers: &[Vertex::instance_desc(), mesh_layout],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: config.format,
+                    blend: options.blend_state(),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: options.depth_stencil_state(),
+            multisample: options.multisample_state(),
+            multiview: None,
+            cache: None,
+        });
+
+        let instance_buffer = Self::create_instance_buffer(device, &chart_data.vertices);
+        let mut renderer = GlyphRenderer {
+            render_pipeline,
+            uniform_buffer,
+            bind_group,
+            mesh_buffer: None,
+            mesh_vertex_count: 0,
+            uploaded_bytes: instance_buffer.as_ref().map_or(0, ChunkedBuffer::byte_size),
+            instance_buffer,
+            uniforms,
+            shape: GlyphShape::default(),
+        };
+        renderer.set_shape(device, shape);
+        renderer
+    }
+
+    /// Swap the marker shape; only the small mesh buffer is re-uploaded
+    pub fn set_shape(&mut self, device: &wgpu::Device, shape: GlyphShape) {
+        let mesh = shape.mesh();
+        self.mesh_vertex_count = mesh.len() as u32;
+        self.uploaded_bytes += std::mem::size_of_val(mesh.as_slice()) as u64;
+        self.mesh_buffer = (!mesh.is_empty()).then(|| {
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Glyph Mesh Buffer"),
+                contents: bytemuck::cast_slice(&mesh),
+                usage: wgpu::BufferUsages::VERTEX,
+            })
+        });
+        self.shape = shape;
+    }
+
+    pub fn shape(&self) -> &GlyphShape {
+        &self.shape
+    }
+
+    /// Keep marker sizes in pixels correct after the surface is resized
+    pub fn resize(&mut self, queue: &wgpu::Queue, width: u32, height: u32) {
+        self.uniforms.viewport = [width as f32, height as f32];
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&self.uniforms));
+        self.uploaded_bytes += std::mem::size_of::<GlyphUniforms>() as u64;
+    }
+
+    /// Number of points (instances) drawn
+    pub fn instance_count(&self) -> u64 {
+        self.instance_buffer.as_ref().map_or(0, ChunkedBuffer::len)
+    }
+
+    fn create_instance_buffer(device: &wgpu::Device, vertices: &[Vertex]) -> Option<ChunkedBuffer> {
+        ChunkedBuffer::upload_or_log(device, "Glyph Instance Buffer", vertices, wgpu::BufferUsages::VERTEX, 1)
+    }
+}
+
+impl Renderer for GlyphRenderer {
+    fn render_to_pass<'rpass>(&'rpass mut self, render_pass: &mut wgpu::RenderPass<'rpass>) {
+        if let (Some(mesh), Some(instances)) = (&self.mesh_buffer, &self.instance_buffer) {
+            render_pass.set_pipeline(&self.render_pipeline);
+            render_pass.set_bind_group(0, &self.bind_group, &[]);
+            render_pass.set_vertex_buffer(1, mesh.slice(..));
+            instances.draw_instanced(render_pass, 0, 0..self.mesh_vertex_count);
+        }
+    }
+
+    fn frame_stats(&mut self) -> DrawStats {
+        let stats = match (&self.mesh_buffer, &self.instance_buffer) {
+            (Some(_), Some(instances)) => instances.instanced_stats(self.mesh_vertex_count),
+            _ => DrawStats::default(),
+        };
+        stats.with_bytes_uploaded(std::mem::take(&mut self.uploaded_bytes))
+    }
+}
+
+impl WindowRenderer for GlyphRenderer {
+    /// Create a renderer drawing circles; see `with_shape` for other markers
+    fn with_options(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        chart_data: ChartData,
+        options: &RenderOptions,
+    ) -> Self {
+        Self::with_shape(device, config, chart_data, GlyphShape::default(), options)
+    }
+
+    fn update_data(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, chart_data: &ChartData) {
+        self.uploaded_bytes += ChunkedBuffer::update_or_log(
+            &mut self.instance_buffer,
+            device,
+            queue,
+            "Glyph Instance Buffer",
+            &chart_data.vertices,
+            wgpu::BufferUsages::VERTEX,
+            1,
+        );
+    }
+
+    fn resize(&mut self, queue: &wgpu::Queue, width: u32, height: u32) {
+        GlyphRenderer::resize(self, queue, width, height);
+    }
+}
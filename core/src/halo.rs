@@ -0,0 +1,235 @@
+use crate::brush::Selection;
+use crate::chunked::ChunkedBuffer;
+use crate::data::{ChartData, Vertex};
+use crate::renderer::{BlendMode, RenderOptions, Renderer, WindowRenderer};
+use crate::shaders::{HALO_FRAGMENT_SHADER, HALO_VERTEX_SHADER};
+use crate::stats::DrawStats;
+use wgpu::util::DeviceExt;
+
+/// Number of vertices in one halo quad (two triangles)
+const QUAD_VERTICES: u32 = 6;
+
+/// Appearance of the halo overlay
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HaloOptions {
+    /// Halo radius as a multiple of each point's size
+    pub radius_scale: f32,
+    /// Multiplier applied to each point's alpha (values > 1.0 brighten the glow)
+    pub intensity: f32,
+}
+
+impl Default for HaloOptions {
+    fn default() -> Self {
+        Self {
+            radius_scale: 4.0,
+            intensity: 1.0,
+        }
+    }
+}
+
+/// Uniform block layout shared with `HALO_VERTEX_SHADER`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct HaloUniforms {
+    pub viewport: [f32; 2],
+    pub radius_scale: f32,
+    pub intensity: f32,
+}
+
+impl HaloUniforms {
+    pub fn new(width: f32, height: f32, options: &HaloOptions) -> Self {
+        Self {
+            viewport: [width, height],
+            radius_scale: options.radius_scale,
+            intensity: options.intensity,
+        }
+    }
+}
+
+/// Glow/halo overlay for highlighted or flagged points
+///
+/// Renders each point of its `ChartData` as a soft, additively blended sprite.
+/// Build it from only the points that need emphasis (e.g. alert markers) and
+/// call `render_to_pass` after the main series so the glow sits on top:
+///
+/// ```ignore
+/// scatter.render_to_pass(&mut render_pass);
+/// halo.render_to_pass(&mut render_pass);
+/// ```
+pub struct HaloRenderer {
+    render_pipeline: wgpu::RenderPipeline,
+    uniform_buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+    instance_buffer: Option<ChunkedBuffer>,
+    uniforms: HaloUniforms,
+    uploaded_bytes: u64,
+}
+
+impl HaloRenderer {
+    /// Change radius/intensity without rebuilding the pipeline
+    pub fn set_options(&mut self, queue: &wgpu::Queue, options: &HaloOptions) {
+        self.uniforms.radius_scale = options.radius_scale;
+        self.uniforms.intensity = options.intensity;
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&self.uniforms));
+        self.uploaded_bytes += std::mem::size_of::<HaloUniforms>() as u64;
+    }
+
+    /// Keep halo sizes in pixels correct after the surface is resized
+    pub fn resize(&mut self, queue: &wgpu::Queue, width: u32, height: u32) {
+        self.uniforms.viewport = [width as f32, height as f32];
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&self.uniforms));
+        self.uploaded_bytes += std::mem::size_of::<HaloUniforms>() as u64;
+    }
+
+    fn create_instance_buffer(device: &wgpu::Device, vertices: &[Vertex]) -> Option<ChunkedBuffer> {
+        ChunkedBuffer::upload_or_log(device, "Halo Instance Buffer", vertices, wgpu::BufferUsages::VERTEX, 1)
+    }
+}
+
+impl Renderer for HaloRenderer {
+    fn render_to_pass<'rpass>(&'rpass mut self, render_pass: &mut wgpu::RenderPass<'rpass>) {
+        if let Some(ref buffer) = self.instance_buffer {
+            render_pass.set_pipeline(&self.render_pipeline);
+            render_pass.set_bind_group(0, &self.bind_group, &[]);
+            buffer.draw_instanced(render_pass, 0, 0..QUAD_VERTICES);
+        }
+    }
+
+    fn frame_stats(&mut self) -> DrawStats {
+        let stats = self
+            .instance_buffer
+            .as_ref()
+            .map_or_else(DrawStats::default, |buffer| buffer.instanced_stats(QUAD_VERTICES));
+        stats.with_bytes_uploaded(std::mem::take(&mut self.uploaded_bytes))
+    }
+}
+
+impl WindowRenderer for HaloRenderer {
+    fn with_options(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        chart_data: ChartData,
+        options: &RenderOptions,
+    ) -> Self {
+        let vertex_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Halo Vertex Shader"),
+            source: wgpu::ShaderSource::Wgsl(HALO_VERTEX_SHADER.into()),
+        });
+
+        let fragment_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Halo Fragment Shader"),
+            source: wgpu::ShaderSource::Wgsl(HALO_FRAGMENT_SHADER.into()),
+        });
+
+        let uniforms = HaloUniforms::new(
+            config.width as f32,
+            config.height as f32,
+            &HaloOptions::default(),
+        );
+        let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Halo Uniform Buffer"),
+            contents: bytemuck::bytes_of(&uniforms),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Halo Bind Group Layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Halo Bind Group"),
+            layout: &bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: uniform_buffer.as_entire_binding(),
+            }],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Halo Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Halo Render Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &vertex_shader,
+                entry_point: "vs_main",
+                buffers: &[Vertex::instance_desc()],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: config.format,
+                    // Always additive: overlapping halos brighten instead of
+                    // occluding, whatever `options.blend` says
+                    blend: BlendMode::Additive.blend_state(),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: options.depth_stencil_state(),
+            multisample: options.multisample_state(),
+            multiview: None,
+            cache: None,
+        });
+
+        HaloRenderer {
+            render_pipeline,
+            uniform_buffer,
+            bind_group,
+            instance_buffer: Self::create_instance_buffer(device, &chart_data.vertices),
+            uniforms,
+            uploaded_bytes: std::mem::size_of_val(chart_data.vertices.as_slice()) as u64,
+        }
+    }
+
+    /// Replace the set of glowing points
+    fn update_data(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, chart_data: &ChartData) {
+        self.uploaded_bytes += ChunkedBuffer::update_or_log(
+            &mut self.instance_buffer,
+            device,
+            queue,
+            "Halo Instance Buffer",
+            &chart_data.vertices,
+            wgpu::BufferUsages::VERTEX,
+            1,
+        );
+    }
+}
+
+/// Extract the points of `data` listed in `selection` as halo input
+///
+/// Indices beyond the number of vertices are ignored.
+pub fn flagged_points(data: &ChartData, selection: &Selection) -> ChartData {
+    let mut flagged = ChartData::new(data.viewport_width, data.viewport_height);
+    flagged.vertices = selection
+        .indices()
+        .iter()
+        .filter_map(|&i| data.vertices.get(i).copied())
+        .collect();
+    flagged
+}
@@ -0,0 +1,301 @@
+use crate::backend::limits_for_adapter;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::data::ChartData;
+use crate::error::HelionError;
+use crate::figure::{ChartKind, Figure, SeriesLayer};
+use crate::heatmap::HeatmapRenderer;
+use crate::histogram::HistogramRenderer;
+use crate::line::LineRenderer;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::picking::GpuPicker;
+use crate::readback::RenderTarget;
+use crate::renderer::{RenderAttachments, RenderOptions, Renderer, WindowRenderer};
+use crate::scatter::ScatterRenderer;
+
+/// Texture format of headless renders: RGBA so pixels go straight to image
+/// encoders, sRGB so colors match what a window shows
+pub const HEADLESS_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+
+/// Renders charts without a window, for scripts, servers and CI
+///
+/// Owns its own device and an offscreen `RenderTarget`. Any `WindowRenderer`
+/// can draw into it; the result is read back as RGBA pixels or encoded as a
+/// PNG.
+///
+/// # Example
+/// ```no_run
+/// use helion_core::data::ChartData;
+/// use helion_core::headless::HeadlessRenderer;
+/// use helion_core::renderer::RenderOptions;
+/// use helion_core::scatter::ScatterRenderer;
+///
+/// let data = ChartData::from_scatter(&[0.0, 1.0], &[0.0, 1.0], None, Some(8.0), 640.0, 480.0);
+/// let mut headless = HeadlessRenderer::new(640, 480, RenderOptions::default()).unwrap();
+/// let png = headless.render_png::<ScatterRenderer>(data).unwrap();
+/// std::fs::write("scatter.png", png).unwrap();
+/// ```
+pub struct HeadlessRenderer {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    target: RenderTarget,
+    attachments: RenderAttachments,
+    options: RenderOptions,
+}
+
+impl HeadlessRenderer {
+    /// Create a device and a `width` x `height` target
+    ///
+    /// MSAA falls back to a single sample if the adapter can't multisample
+    /// `HEADLESS_FORMAT` at the requested count.
+    pub async fn new_async(width: u32, height: u32, mut options: RenderOptions) -> Result<Self, HelionError> {
+        options.validate()?;
+
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+            backends: wgpu::Backends::all(),
+            ..Default::default()
+        });
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                compatible_surface: None,
+                force_fallback_adapter: false,
+            })
+            .await
+            .ok_or(HelionError::AdapterNotFound)?;
+        let (device, queue) = adapter
+            .request_device(
+                &wgpu::DeviceDescriptor {
+                    label: Some("Helion Headless Device"),
+                    required_features: wgpu::Features::empty(),
+                    required_limits: limits_for_adapter(&adapter),
+                    memory_hints: Default::default(),
+                },
+                None,
+            )
+            .await
+            .map_err(|e| HelionError::DeviceRequest(e.to_string()))?;
+
+        let format_flags = adapter.get_texture_format_features(HEADLESS_FORMAT).flags;
+        if !format_flags.sample_count_supported(options.effective_sample_count()) {
+            log::warn!(
+                "{}x MSAA is not supported for {:?}; rendering without it",
+                options.sample_count,
+                HEADLESS_FORMAT
+            );
+            options.sample_count = 1;
+        }
+
+        let target = RenderTarget::new(&device, width, height, HEADLESS_FORMAT);
+        let attachments = RenderAttachments::new(&device, target.width, target.height, HEADLESS_FORMAT, &options);
+        Ok(Self {
+            device,
+            queue,
+            target,
+            attachments,
+            options,
+        })
+    }
+
+    /// Blocking version of `new_async` (native only)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn new(width: u32, height: u32, options: RenderOptions) -> Result<Self, HelionError> {
+        futures::executor::block_on(Self::new_async(width, height, options))
+    }
+
+    pub fn device(&self) -> &wgpu::Device {
+        &self.device
+    }
+
+    pub fn queue(&self) -> &wgpu::Queue {
+        &self.queue
+    }
+
+    /// Options pipelines should be built with (after any MSAA fallback)
+    pub fn options(&self) -> &RenderOptions {
+        &self.options
+    }
+
+    pub fn width(&self) -> u32 {
+        self.target.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.target.height
+    }
+
+    /// Surface configuration describing the target, for creating renderers
+    /// with `WindowRenderer::with_options`
+    pub fn surface_config(&self) -> wgpu::SurfaceConfiguration {
+        wgpu::SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            format: HEADLESS_FORMAT,
+            width: self.target.width,
+            height: self.target.height,
+            present_mode: wgpu::PresentMode::Fifo,
+            alpha_mode: wgpu::CompositeAlphaMode::Opaque,
+            view_formats: vec![],
+            desired_maximum_frame_latency: 2,
+        }
+    }
+
+    /// Change the output size; renderers made for the old size should be
+    /// `resize`d too
+    pub fn resize(&mut self, width: u32, height: u32) {
+        if width.max(1) == self.target.width && height.max(1) == self.target.height {
+            return;
+        }
+        self.target = RenderTarget::new(&self.device, width, height, HEADLESS_FORMAT);
+        self.attachments =
+            RenderAttachments::new(&self.device, self.target.width, self.target.height, HEADLESS_FORMAT, &self.options);
+    }
+
+    /// Clear the target and draw `renderer` into it
+    pub fn draw(&mut self, renderer: &mut dyn Renderer) {
+        self.draw_layers(&mut [renderer]);
+    }
+
+    /// Clear the target and draw `layers` into it back to front, in one pass
+    pub fn draw_layers(&mut self, layers: &mut [&mut dyn Renderer]) {
+        let mut encoder = self.device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Headless Encoder"),
+        });
+        {
+            let mut render_pass = self.attachments.begin_pass(&mut encoder, &self.target.view, &self.options);
+            for layer in layers.iter_mut() {
+                layer.render_to_pass(&mut render_pass);
+            }
+            self.attachments.end_pass(&mut render_pass);
+        }
+        self.queue.submit(std::iter::once(encoder.finish()));
+    }
+
+    /// Read the target back as tightly packed RGBA rows, top to bottom
+    pub async fn read_rgba_async(&self) -> Result<Vec<u8>, HelionError> {
+        self.target
+            .read_pixels_async(&self.device, &self.queue)
+            .await
+            .map_err(HelionError::Readback)
+    }
+
+    /// Blocking version of `read_rgba_async` (native only)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn read_rgba(&self) -> Result<Vec<u8>, HelionError> {
+        self.target.read_pixels(&self.device, &self.queue).map_err(HelionError::Readback)
+    }
+
+    /// Draw `data` with a fresh `R` and return the RGBA pixels (native only)
+    ///
+    /// # Parameters
+    /// * `data` - Chart to draw; its viewport size is ignored in favor of
+    ///   the target's
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn render_rgba<R: WindowRenderer>(&mut self, mut data: ChartData) -> Result<Vec<u8>, HelionError> {
+        data.viewport_width = self.target.width as f32;
+        data.viewport_height = self.target.height as f32;
+        let mut renderer = R::with_options(&self.device, &self.surface_config(), data, &self.options);
+        self.draw(&mut renderer);
+        self.read_rgba()
+    }
+
+    /// Draw `data` with a fresh `R` and return PNG file bytes (native only)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn render_png<R: WindowRenderer>(&mut self, data: ChartData) -> Result<Vec<u8>, HelionError> {
+        let pixels = self.render_rgba::<R>(data)?;
+        encode_png(&pixels, self.target.width, self.target.height)
+    }
+
+    /// Draw the heatmap and every series of `figure` and return PNG file
+    /// bytes (native only)
+    ///
+    /// Series are stretched over the whole target like in
+    /// `render_png`; axes, text and the legend aren't drawn.
+    ///
+    /// # Returns
+    /// The PNG file bytes, or `HelionError::EmptyData` if the figure has
+    /// neither series nor a heatmap
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn render_figure_png(&mut self, figure: &Figure) -> Result<Vec<u8>, HelionError> {
+        if figure.is_empty() && figure.heatmap().is_none() {
+            return Err(HelionError::EmptyData);
+        }
+        let mut heatmap = self.heatmap_renderer(figure)?;
+        let mut series: Vec<Box<dyn WindowRenderer>> =
+            figure.layers().into_iter().map(|layer| self.series_renderer(layer)).collect();
+
+        let mut layers: Vec<&mut dyn Renderer> = Vec::with_capacity(series.len() + 1);
+        if let Some(heatmap) = &mut heatmap {
+            layers.push(heatmap);
+        }
+        for renderer in &mut series {
+            layers.push(renderer.as_mut());
+        }
+        self.draw_layers(&mut layers);
+        let pixels = self.read_rgba()?;
+        encode_png(&pixels, self.target.width, self.target.height)
+    }
+
+    /// Series and index of the scatter point drawn at pixel (`x`, `y`) of
+    /// the target when `layers` are drawn, if any (native only)
+    ///
+    /// Line and bar layers can't be picked and are skipped.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn pick(&self, layers: &[SeriesLayer], x: f32, y: f32) -> Result<Option<(usize, usize)>, HelionError> {
+        let mut picker = GpuPicker::new(&self.device, self.target.width, self.target.height);
+        for layer in layers.iter().filter(|layer| layer.kind == ChartKind::Scatter) {
+            picker.set_series(&self.device, &self.queue, layer.series, &layer.data);
+        }
+        picker.pick(&self.device, &self.queue, x, y)
+    }
+
+    /// Renderer drawing `layer` as its chart kind, stretched over the
+    /// whole target
+    pub fn series_renderer(&self, layer: SeriesLayer) -> Box<dyn WindowRenderer> {
+        let config = self.surface_config();
+        let mut data = layer.data;
+        data.viewport_width = config.width as f32;
+        data.viewport_height = config.height as f32;
+        match layer.kind {
+            ChartKind::Scatter => Box::new(ScatterRenderer::with_options(&self.device, &config, data, &self.options)),
+            ChartKind::Line => Box::new(LineRenderer::with_options(&self.device, &config, data, &self.options)),
+            ChartKind::Bars => Box::new(HistogramRenderer::with_options(&self.device, &config, data, &self.options)),
+        }
+    }
+
+    /// Renderer for the heatmap of `figure`, if it has one
+    pub fn heatmap_renderer(&self, figure: &Figure) -> Result<Option<HeatmapRenderer>, HelionError> {
+        let Some(heatmap) = figure.heatmap() else {
+            return Ok(None);
+        };
+        let mut renderer = HeatmapRenderer::new(&self.device, HEADLESS_FORMAT, &self.options);
+        renderer.set_heatmap(&self.device, &self.queue, heatmap, &figure.data_range())?;
+        Ok(Some(renderer))
+    }
+}
+
+/// Encode tightly packed 8-bit RGBA rows as a PNG file
+///
+/// # Returns
+/// The PNG file bytes, or `HelionError::ShapeMismatch` if `rgba` isn't
+/// `width * height * 4` bytes long
+pub fn encode_png(rgba: &[u8], width: u32, height: u32) -> Result<Vec<u8>, HelionError> {
+    let expected = width as usize * height as usize * 4;
+    if rgba.len() != expected {
+        return Err(HelionError::ShapeMismatch {
+            what: format!("RGBA bytes for a {}x{} image", width, height),
+            expected,
+            actual: rgba.len(),
+        });
+    }
+
+    let mut bytes = Vec::new();
+    let mut encoder = png::Encoder::new(&mut bytes, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_source_srgb(png::SrgbRenderingIntent::Perceptual);
+    let mut writer = encoder.write_header().map_err(|e| HelionError::Encoding(e.to_string()))?;
+    writer
+        .write_image_data(rgba)
+        .map_err(|e| HelionError::Encoding(e.to_string()))?;
+    writer.finish().map_err(|e| HelionError::Encoding(e.to_string()))?;
+    Ok(bytes)
+}
@@ -0,0 +1,645 @@
+use crate::aggregate::{aggregate_counts, counts_as_values, BinGrid, Rebinner};
+use crate::camera::{Camera2D, CameraBinding, CAMERA_GROUP};
+use crate::colormap::{create_lut_texture, write_lut, ColormapLut, LUT_SIZE};
+use crate::compute::Aggregator;
+use crate::data::Colormap;
+use crate::error::HelionError;
+use crate::norm::{Normalization, Normalizer};
+use crate::renderer::{RenderOptions, Renderer};
+use crate::shaders::{HEATMAP_FRAGMENT_SHADER, HEATMAP_VERTEX_SHADER};
+use crate::stats::DrawStats;
+use crate::transition::ViewRange;
+use std::borrow::Cow;
+use wgpu::util::DeviceExt;
+
+/// A grid of values drawn as colored cells
+///
+/// `values` is row-major with `nx` values per row; row 0 is the bottom row,
+/// at the low end of `y_range`, as in `BinGrid`. NaN cells are not drawn.
+///
+/// # Example
+/// ```
+/// use helion_core::heatmap::Heatmap;
+///
+/// let heatmap = Heatmap::new(vec![0.0, 1.0, 2.0, f32::NAN, 4.0, 5.0], 3, 2)
+///     .unwrap()
+///     .with_extent((0.0, 30.0), (-1.0, 1.0));
+/// assert_eq!(heatmap.value_range(), (0.0, 5.0));
+/// assert_eq!(heatmap.extent().x, (0.0, 30.0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heatmap {
+    pub values: Vec<f32>,
+    pub nx: u32,
+    pub ny: u32,
+    /// Data range covered by the columns; `(0, nx)` by default
+    pub x_range: (f32, f32),
+    /// Data range covered by the rows; `(0, ny)` by default
+    pub y_range: (f32, f32),
+    pub cmap: Colormap,
+    /// Value given the first color; the smallest finite value if `None`
+    pub vmin: Option<f32>,
+    /// Value given the last color; the largest finite value if `None`
+    pub vmax: Option<f32>,
+    /// How values between `vmin` and `vmax` are spread over the colormap
+    pub norm: Normalization,
+    /// Value given the middle color, for diverging colormaps
+    pub center: Option<f32>,
+}
+
+impl Heatmap {
+    /// Heatmap of `nx` x `ny` cells covering `(0, nx)` x `(0, ny)`
+    ///
+    /// # Returns
+    /// `HelionError::ShapeMismatch` if `values` doesn't hold `nx * ny`
+    /// values, `HelionError::EmptyData` if the grid has no cells
+    pub fn new(values: Vec<f32>, nx: u32, ny: u32) -> Result<Self, HelionError> {
+        if nx == 0 || ny == 0 {
+            return Err(HelionError::EmptyData);
+        }
+        let cells = nx as usize * ny as usize;
+        if values.len() != cells {
+            return Err(HelionError::ShapeMismatch {
+                what: format!("heatmap values ({} x {} cells)", nx, ny),
+                expected: cells,
+                actual: values.len(),
+            });
+        }
+        Ok(Self {
+            values,
+            nx,
+            ny,
+            x_range: (0.0, nx as f32),
+            y_range: (0.0, ny as f32),
+            cmap: Colormap::default(),
+            vmin: None,
+            vmax: None,
+            norm: Normalization::default(),
+            center: None,
+        })
+    }
+
+    /// Point counts per cell of `grid`, empty cells left undrawn
+    ///
+    /// # Returns
+    /// `HelionError::ShapeMismatch` if there isn't one count per cell
+    pub fn from_counts(grid: &BinGrid, counts: &[u32]) -> Result<Self, HelionError> {
+        Ok(Self::new(counts_as_values(counts), grid.nx, grid.ny)?.with_extent(grid.x_range, grid.y_range))
+    }
+
+    /// 2D histogram of the points (`x[i]`, `y[i]`) over `grid`, counted on
+    /// the CPU
+    pub fn hist2d(x: &[f32], y: &[f32], grid: &BinGrid) -> Self {
+        Self::from_counts(grid, &aggregate_counts(x, y, grid)).expect("one count per grid cell")
+    }
+
+    /// `hist2d` counted by `aggregator`, on the GPU where it has compute
+    ///
+    /// The points must already be set on the aggregator; re-binning after
+    /// a zoom is then one dispatch.
+    ///
+    /// # Returns
+    /// `HelionError::Readback` if the counts can't be read back from the GPU
+    pub async fn hist2d_with(
+        aggregator: &Aggregator,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        grid: &BinGrid,
+    ) -> Result<Self, HelionError> {
+        let counts = aggregator.bin(device, queue, grid).await.map_err(HelionError::Readback)?;
+        Self::from_counts(grid, &counts)
+    }
+
+    /// `hist2d` counted on the GPU when one with compute support is
+    /// available and on the CPU otherwise
+    ///
+    /// Opens a headless device for the dispatch, so it suits one-off
+    /// binning of large point sets (e.g. `helion.hist2d` in Python); keep an
+    /// `Aggregator` and use `hist2d_with` to re-bin the same points.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn hist2d_auto(x: &[f32], y: &[f32], grid: &BinGrid) -> Self {
+        let Some(headless) = crate::compute::compute_device() else {
+            return Self::hist2d(x, y, grid);
+        };
+        let (device, queue) = (headless.device(), headless.queue());
+        let mut aggregator = Aggregator::Gpu(crate::compute::GpuBinner::new(device));
+        aggregator.set_points(device, x, y);
+        futures::executor::block_on(Self::hist2d_with(&aggregator, device, queue, grid)).unwrap_or_else(|e| {
+            log::warn!("GPU 2D histogram failed ({}); binning on the CPU", e);
+            Self::hist2d(x, y, grid)
+        })
+    }
+
+    /// Point counts per cell of `grid`, keeping this heatmap's colormap,
+    /// value range, normalization and center
+    ///
+    /// For replacing a 2D histogram with one binned for another view.
+    ///
+    /// # Returns
+    /// `HelionError::ShapeMismatch` if there isn't one count per cell
+    pub fn recount(&self, grid: &BinGrid, counts: &[u32]) -> Result<Self, HelionError> {
+        Ok(Self {
+            cmap: self.cmap.clone(),
+            vmin: self.vmin,
+            vmax: self.vmax,
+            norm: self.norm,
+            center: self.center,
+            ..Self::from_counts(grid, counts)?
+        })
+    }
+
+    /// Place the grid over `x_range` x `y_range` in data coordinates
+    pub fn with_extent(mut self, x_range: (f32, f32), y_range: (f32, f32)) -> Self {
+        self.x_range = x_range;
+        self.y_range = y_range;
+        self
+    }
+
+    pub fn with_colormap(mut self, cmap: Colormap) -> Self {
+        self.cmap = cmap;
+        self
+    }
+
+    /// Fix the values given the ends of the colormap
+    pub fn with_range(mut self, vmin: Option<f32>, vmax: Option<f32>) -> Self {
+        self.vmin = vmin;
+        self.vmax = vmax;
+        self
+    }
+
+    /// Spread the values over the colormap with `norm`, e.g.
+    /// `Normalization::Log` for counts spanning orders of magnitude
+    pub fn with_normalization(mut self, norm: Normalization) -> Self {
+        self.norm = norm;
+        self
+    }
+
+    /// Give `center` the middle color of a diverging colormap such as
+    /// coolwarm, however far the range reaches on either side of it
+    ///
+    /// See `Normalizer::with_center`.
+    pub fn with_center(mut self, center: f32) -> Self {
+        self.center = Some(center);
+        self
+    }
+
+    /// Data range covered by the grid
+    pub fn extent(&self) -> ViewRange {
+        ViewRange::new(self.x_range, self.y_range)
+    }
+
+    /// Values mapped onto the ends of the colormap: `vmin`/`vmax` where
+    /// set, else the finite range of the values ((0, 1) if there is none)
+    pub fn value_range(&self) -> (f32, f32) {
+        let (min, max) = self
+            .values
+            .iter()
+            .filter(|v| v.is_finite())
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+        let (min, max) = if min <= max { (min, max) } else { (0.0, 1.0) };
+        (self.vmin.unwrap_or(min), self.vmax.unwrap_or(max))
+    }
+
+    /// `norm` fitted to the values and `vmin`/`vmax`, pivoting on `center`
+    pub fn normalizer(&self) -> Normalizer {
+        let norm = self.norm.fit_range(&self.values, self.vmin, self.vmax);
+        match self.center {
+            Some(center) => norm.with_center(center),
+            None => norm,
+        }
+    }
+
+    /// Cell values as uploaded for the shader, which maps its range
+    /// linearly onto the colormap
+    ///
+    /// Linear heatmaps without a center upload their values as they are
+    /// with `value_range()`; others upload each cell's colormap position
+    /// with a range of (0, 1). NaN stays NaN and is left undrawn.
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::heatmap::Heatmap;
+    /// use helion_core::norm::Normalization;
+    ///
+    /// let heatmap = Heatmap::new(vec![1.0, 10.0, 100.0, 0.0], 2, 2)
+    ///     .unwrap()
+    ///     .with_normalization(Normalization::Log);
+    /// let (cells, range) = heatmap.cell_values();
+    /// assert_eq!(&cells[..3], &[0.0, 0.5, 1.0]);
+    /// assert!(cells[3].is_nan());
+    /// assert_eq!(range, (0.0, 1.0));
+    /// ```
+    pub fn cell_values(&self) -> (Cow<'_, [f32]>, (f32, f32)) {
+        if self.norm == Normalization::Linear && self.center.is_none() {
+            (Cow::Borrowed(&self.values), self.value_range())
+        } else {
+            (Cow::Owned(self.normalizer().apply(&self.values)), (0.0, 1.0))
+        }
+    }
+}
+
+/// Points shown as a 2D histogram, kept so they can be counted again for
+/// whatever range is visible
+///
+/// Zooming a fixed grid only magnifies its cells. A window showing a figure
+/// with these points asks `regrid` for a new grid after every pan, zoom or
+/// resize, and when the view moved by more than a fraction of a cell counts
+/// the points into it (on the GPU where it has compute), so detail emerges
+/// as the user zooms in.
+///
+/// # Example
+/// ```
+/// use helion_core::heatmap::AggregatedPoints;
+/// use helion_core::transition::ViewRange;
+///
+/// let x: Vec<f32> = (0..100).map(|i| i as f32).collect();
+/// let mut points = AggregatedPoints::new(x.clone(), x, 4.0);
+/// let full = points.regrid(&ViewRange::new((0.0, 100.0), (0.0, 100.0)), 400.0, 400.0).unwrap();
+/// let zoomed = points.regrid(&ViewRange::new((40.0, 60.0), (40.0, 60.0)), 400.0, 400.0).unwrap();
+/// assert!(zoomed.cell_size().0 < full.cell_size().0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AggregatedPoints {
+    pub x: Vec<f32>,
+    pub y: Vec<f32>,
+    rebinner: Rebinner,
+}
+
+impl AggregatedPoints {
+    /// Points (`x[i]`, `y[i]`), the longer column cut to the shorter
+    ///
+    /// # Parameters
+    /// * `cell_px` - Cell size in screen pixels after re-binning
+    pub fn new(mut x: Vec<f32>, mut y: Vec<f32>, cell_px: f32) -> Self {
+        let len = x.len().min(y.len());
+        x.truncate(len);
+        y.truncate(len);
+        Self {
+            x,
+            y,
+            rebinner: Rebinner::new(cell_px),
+        }
+    }
+
+    /// Cell size targeted after re-binning, in screen pixels
+    pub fn cell_px(&self) -> f32 {
+        self.rebinner.cell_px
+    }
+
+    /// Grid to count the points into for `view` on a `width` x `height`
+    /// pixel viewport, or `None` if the last one returned still fits it
+    pub fn regrid(&mut self, view: &ViewRange, width: f32, height: f32) -> Option<BinGrid> {
+        self.rebinner.update(view, width, height)
+    }
+
+    /// Counts per cell of `grid`, on the CPU
+    pub fn counts(&self, grid: &BinGrid) -> Vec<u32> {
+        aggregate_counts(&self.x, &self.y, grid)
+    }
+}
+
+/// Uniform block layout shared with the heatmap shaders
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct HeatmapUniforms {
+    /// Clip-space corners of the grid: min x, min y, max x, max y
+    pub rect: [f32; 4],
+    pub vmin: f32,
+    pub vmax: f32,
+    pub opacity: f32,
+    pub _padding: f32,
+}
+
+impl Default for HeatmapUniforms {
+    fn default() -> Self {
+        Self {
+            rect: [-1.0, -1.0, 1.0, 1.0],
+            vmin: 0.0,
+            vmax: 1.0,
+            opacity: 1.0,
+            _padding: 0.0,
+        }
+    }
+}
+
+/// Draws a `Heatmap` as a texture colored through a colormap
+///
+/// Cell values are uploaded once as an R32Float texture and colored in the
+/// fragment shader, so changing the colormap or the value range only
+/// rewrites a 1 KB lookup texture or a 32-byte uniform. The whole grid is a
+/// single quad, panned and zoomed by the camera like the series.
+pub struct HeatmapRenderer {
+    render_pipeline: wgpu::RenderPipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+    uniform_buffer: wgpu::Buffer,
+    lut_texture: wgpu::Texture,
+    lut_view: wgpu::TextureView,
+    sampler: wgpu::Sampler,
+    camera: CameraBinding,
+    /// `None` until `set_heatmap`
+    bind_group: Option<wgpu::BindGroup>,
+    uniforms: HeatmapUniforms,
+    uploaded_bytes: u64,
+}
+
+impl HeatmapRenderer {
+    /// Renderer with no grid yet
+    ///
+    /// # Parameters
+    /// * `format` - Format of the target the pass renders to
+    /// * `options` - Must match the options the pass is begun with
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat, options: &RenderOptions) -> Self {
+        let uniforms = HeatmapUniforms::default();
+        let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Heatmap Uniform Buffer"),
+            contents: bytemuck::bytes_of(&uniforms),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+        let lut_texture = create_lut_texture(device);
+        let lut_view = lut_texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Heatmap Colormap Sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let texture = |binding, filterable| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Texture {
+                sample_type: wgpu::TextureSampleType::Float { filterable },
+                view_dimension: wgpu::TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        };
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Heatmap Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // R32Float is only filterable with an optional feature
+                texture(1, false),
+                texture(2, true),
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        let camera = CameraBinding::new(device);
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Heatmap Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout, camera.layout()],
+            push_constant_ranges: &[],
+        });
+
+        let vertex_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Heatmap Vertex Shader"),
+            source: wgpu::ShaderSource::Wgsl(HEATMAP_VERTEX_SHADER.into()),
+        });
+        let fragment_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Heatmap Fragment Shader"),
+            source: wgpu::ShaderSource::Wgsl(HEATMAP_FRAGMENT_SHADER.into()),
+        });
+
+        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Heatmap Render Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &vertex_shader,
+                entry_point: "vs_main",
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: options.blend_state(),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: options.depth_stencil_state(),
+            multisample: options.multisample_state(),
+            multiview: None,
+            cache: None,
+        });
+
+        HeatmapRenderer {
+            render_pipeline,
+            bind_group_layout,
+            uniform_buffer,
+            lut_texture,
+            lut_view,
+            sampler,
+            camera,
+            bind_group: None,
+            uniforms,
+            uploaded_bytes: std::mem::size_of::<HeatmapUniforms>() as u64,
+        }
+    }
+
+    /// Upload `heatmap`, its colormap, value range and normalization
+    ///
+    /// # Parameters
+    /// * `data_range` - Data range mapped onto clip space, the range the
+    ///   series were normalized with (`heatmap.extent()` fills the target)
+    ///
+    /// # Returns
+    /// `HelionError::BufferTooLarge` if the grid is wider or taller than the
+    /// device's largest texture
+    pub fn set_heatmap(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        heatmap: &Heatmap,
+        data_range: &ViewRange,
+    ) -> Result<(), HelionError> {
+        let max = device.limits().max_texture_dimension_2d;
+        if heatmap.nx > max || heatmap.ny > max {
+            return Err(HelionError::BufferTooLarge(format!(
+                "Heatmap of {} x {} cells exceeds the device's texture limit of {} per side",
+                heatmap.nx, heatmap.ny, max
+            )));
+        }
+
+        let (cells, (vmin, vmax)) = heatmap.cell_values();
+        let size = wgpu::Extent3d {
+            width: heatmap.nx,
+            height: heatmap.ny,
+            depth_or_array_layers: 1,
+        };
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Heatmap Cell Texture"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::R32Float,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        queue.write_texture(
+            wgpu::ImageCopyTexture {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            bytemuck::cast_slice(&cells),
+            wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(heatmap.nx * 4),
+                rows_per_image: Some(heatmap.ny),
+            },
+            size,
+        );
+        self.uploaded_bytes += std::mem::size_of_val(cells.as_ref()) as u64;
+
+        let cells = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        self.bind_group = Some(device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Heatmap Bind Group"),
+            layout: &self.bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: self.uniform_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&cells),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::TextureView(&self.lut_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: wgpu::BindingResource::Sampler(&self.sampler),
+                },
+            ],
+        }));
+
+        self.set_colormap(queue, &heatmap.cmap.lut());
+        self.write_uniforms(
+            queue,
+            HeatmapUniforms {
+                rect: clip_rect(heatmap, data_range),
+                vmin,
+                vmax,
+                ..self.uniforms
+            },
+        );
+        Ok(())
+    }
+
+    /// Swap the colormap without touching the cells
+    pub fn set_colormap(&mut self, queue: &wgpu::Queue, lut: &ColormapLut) {
+        write_lut(queue, &self.lut_texture, lut);
+        self.uploaded_bytes += (LUT_SIZE * 4) as u64;
+    }
+
+    /// Change the values mapped onto the ends of the colormap
+    ///
+    /// Only for linear heatmaps without a center; the cells of others hold
+    /// colormap positions, so give the heatmap a new range and
+    /// `set_heatmap` it.
+    pub fn set_range(&mut self, queue: &wgpu::Queue, vmin: f32, vmax: f32) {
+        self.write_uniforms(queue, HeatmapUniforms { vmin, vmax, ..self.uniforms });
+    }
+
+    pub fn set_opacity(&mut self, queue: &wgpu::Queue, opacity: f32) {
+        self.write_uniforms(queue, HeatmapUniforms { opacity, ..self.uniforms });
+    }
+
+    /// Pan and zoom the grid along with the series
+    pub fn set_camera(&mut self, queue: &wgpu::Queue, camera: &Camera2D) {
+        self.uploaded_bytes += self.camera.set(queue, camera);
+    }
+
+    pub fn uniforms(&self) -> &HeatmapUniforms {
+        &self.uniforms
+    }
+
+    fn write_uniforms(&mut self, queue: &wgpu::Queue, uniforms: HeatmapUniforms) {
+        if uniforms != self.uniforms {
+            self.uniforms = uniforms;
+            queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&self.uniforms));
+            self.uploaded_bytes += std::mem::size_of::<HeatmapUniforms>() as u64;
+        }
+    }
+}
+
+impl Renderer for HeatmapRenderer {
+    fn render_to_pass<'rpass>(&'rpass mut self, render_pass: &mut wgpu::RenderPass<'rpass>) {
+        if let Some(bind_group) = &self.bind_group {
+            render_pass.set_pipeline(&self.render_pipeline);
+            render_pass.set_bind_group(0, bind_group, &[]);
+            render_pass.set_bind_group(CAMERA_GROUP, self.camera.bind_group(), &[]);
+            render_pass.draw(0..6, 0..1);
+        }
+    }
+
+    fn frame_stats(&mut self) -> DrawStats {
+        let stats = if self.bind_group.is_some() {
+            DrawStats::draws(6, 1)
+        } else {
+            DrawStats::default()
+        };
+        stats.with_bytes_uploaded(std::mem::take(&mut self.uploaded_bytes))
+    }
+}
+
+/// Clip-space corners of `heatmap` when `data_range` fills clip space
+///
+/// # Example
+/// ```
+/// use helion_core::heatmap::{clip_rect, Heatmap};
+/// use helion_core::transition::ViewRange;
+///
+/// let heatmap = Heatmap::new(vec![1.0; 4], 2, 2).unwrap().with_extent((0.0, 5.0), (0.0, 10.0));
+/// let rect = clip_rect(&heatmap, &ViewRange::new((0.0, 10.0), (0.0, 10.0)));
+/// assert_eq!(rect, [-1.0, -1.0, 0.0, 1.0]);
+/// ```
+pub fn clip_rect(heatmap: &Heatmap, data_range: &ViewRange) -> [f32; 4] {
+    let to_clip = |value: f32, (min, max): (f32, f32)| {
+        let span = max - min;
+        if span == 0.0 || !span.is_finite() {
+            0.0
+        } else {
+            (value - min) / span * 2.0 - 1.0
+        }
+    };
+    [
+        to_clip(heatmap.x_range.0, data_range.x),
+        to_clip(heatmap.y_range.0, data_range.y),
+        to_clip(heatmap.x_range.1, data_range.x),
+        to_clip(heatmap.y_range.1, data_range.y),
+    ]
+}
@@ -0,0 +1,166 @@
+use crate::error::HelionError;
+
+/// Input to `Hierarchy::new`: a labeled value, or a group of child nodes
+#[derive(Debug, Clone, PartialEq)]
+pub struct HierarchyNode {
+    pub label: String,
+    /// Size of a leaf; ignored for groups, whose size is the sum of their
+    /// children's
+    pub value: f32,
+    pub children: Vec<HierarchyNode>,
+}
+
+impl HierarchyNode {
+    pub fn leaf(label: impl Into<String>, value: f32) -> Self {
+        Self { label: label.into(), value, children: Vec::new() }
+    }
+
+    pub fn group(label: impl Into<String>, children: Vec<HierarchyNode>) -> Self {
+        Self { label: label.into(), value: 0.0, children }
+    }
+}
+
+/// One node of the tree, flattened in depth-first order
+#[derive(Debug, Clone, PartialEq)]
+struct FlatNode {
+    label: String,
+    value: f32,
+    parent: Option<usize>,
+    depth: usize,
+    children: Vec<usize>,
+}
+
+/// Tree of labeled values, as drawn by `Treemap` and `Sunburst`
+///
+/// Nodes are numbered in depth-first order, the root being 0, and each
+/// group's value is the total of its leaves.
+///
+/// # Example
+/// ```
+/// use helion_core::hierarchy::{Hierarchy, HierarchyNode};
+///
+/// let root = HierarchyNode::group("Disk", vec![
+///     HierarchyNode::group("src", vec![HierarchyNode::leaf("lib.rs", 6.0), HierarchyNode::leaf("main.rs", 2.0)]),
+///     HierarchyNode::leaf("README.md", 4.0),
+/// ]);
+/// let tree = Hierarchy::new(root).unwrap();
+/// assert_eq!(tree.len(), 5);
+/// assert_eq!(tree.label(1), "src");
+/// assert_eq!(tree.value(1), 8.0);
+/// assert_eq!(tree.parent(2), Some(1));
+/// assert_eq!(tree.branch(3), Some(0));
+/// assert_eq!(tree.branch(4), Some(1));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hierarchy {
+    nodes: Vec<FlatNode>,
+}
+
+impl Hierarchy {
+    /// Flatten `root` and its descendants
+    ///
+    /// # Returns
+    /// `HelionError::InvalidOption` if a leaf's value is negative or not
+    /// finite
+    pub fn new(root: HierarchyNode) -> Result<Self, HelionError> {
+        let mut nodes = Vec::new();
+        flatten(root, None, 0, &mut nodes)?;
+        Ok(Self { nodes })
+    }
+
+    /// Number of nodes, the root included
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Whether the root has no descendants
+    pub fn is_empty(&self) -> bool {
+        self.nodes.len() <= 1
+    }
+
+    pub fn label(&self, node: usize) -> &str {
+        &self.nodes[node].label
+    }
+
+    /// Value of a leaf, or the total of a group's leaves
+    pub fn value(&self, node: usize) -> f32 {
+        self.nodes[node].value
+    }
+
+    /// Group holding `node`, `None` for the root
+    pub fn parent(&self, node: usize) -> Option<usize> {
+        self.nodes[node].parent
+    }
+
+    /// Levels below the root, 1 for top-level nodes
+    pub fn depth(&self, node: usize) -> usize {
+        self.nodes[node].depth
+    }
+
+    pub fn children(&self, node: usize) -> &[usize] {
+        &self.nodes[node].children
+    }
+
+    /// Whether `node` is `ancestor` or lies below it
+    pub fn is_within(&self, node: usize, ancestor: usize) -> bool {
+        let mut current = Some(node);
+        while let Some(n) = current {
+            if n == ancestor {
+                return true;
+            }
+            current = self.nodes[n].parent;
+        }
+        false
+    }
+
+    /// Position among the root's children of the top-level node `node`
+    /// lies under, e.g. to pick its palette color; `None` for the root
+    pub fn branch(&self, node: usize) -> Option<usize> {
+        let mut top = node;
+        while let Some(parent) = self.nodes[top].parent {
+            if parent == 0 {
+                return self.nodes[0].children.iter().position(|&child| child == top);
+            }
+            top = parent;
+        }
+        None
+    }
+}
+
+/// Append `node` and its descendants to `nodes` depth first, totalling
+/// each group's value
+///
+/// # Returns
+/// Index of `node` in `nodes`
+fn flatten(
+    node: HierarchyNode,
+    parent: Option<usize>,
+    depth: usize,
+    nodes: &mut Vec<FlatNode>,
+) -> Result<usize, HelionError> {
+    let index = nodes.len();
+    if node.children.is_empty() && (!node.value.is_finite() || node.value < 0.0) {
+        return Err(HelionError::InvalidOption(format!(
+            "Value of '{}' must be finite and non-negative, got {}",
+            node.label, node.value
+        )));
+    }
+    nodes.push(FlatNode {
+        label: node.label,
+        value: node.value,
+        parent,
+        depth,
+        children: Vec::new(),
+    });
+    if node.children.is_empty() {
+        return Ok(index);
+    }
+    let mut total = 0.0;
+    for child in node.children {
+        let child = flatten(child, Some(index), depth + 1, nodes)?;
+        total += nodes[child].value;
+        nodes[index].children.push(child);
+    }
+    nodes[index].value = total;
+    Ok(index)
+}
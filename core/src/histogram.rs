@@ -0,0 +1,314 @@
+use crate::data::{ChartData, Color};
+use crate::figure::{Figure, Series};
+use crate::mesh::MeshRenderer;
+use crate::renderer::{RenderOptions, Renderer, WindowRenderer};
+use crate::stats::DrawStats;
+
+/// Most bins any strategy produces
+///
+/// Automatic and fixed-width strategies can ask for absurd counts, e.g. a
+/// tiny IQR next to one outlier, so they're capped here rather than
+/// allocating edges for every requested bin.
+pub const MAX_BINS: usize = 1 << 16;
+
+/// How histogram bin edges are chosen
+#[derive(Debug, Clone, PartialEq)]
+pub enum BinStrategy {
+    /// Fixed number of equal-width bins spanning the data range
+    Count(usize),
+    /// Equal-width bins of the given width, aligned to multiples of it
+    FixedWidth(f32),
+    /// Explicit, increasing bin edges (n + 1 edges for n bins)
+    Edges(Vec<f32>),
+    /// ceil(log2(n)) + 1 bins; good for small, roughly normal samples
+    Sturges,
+    /// Width = 2 * IQR / n^(1/3); robust to outliers and heavy tails
+    FreedmanDiaconis,
+}
+
+impl Default for BinStrategy {
+    fn default() -> Self {
+        BinStrategy::Count(64)
+    }
+}
+
+/// What each bin's value represents
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HistogramNorm {
+    /// Raw number of samples per bin
+    #[default]
+    Count,
+    /// Probability density: count / (total * bin width), integrating to 1
+    Density,
+}
+
+/// Histogram configuration
+#[derive(Debug, Clone, Default)]
+pub struct HistogramOptions {
+    pub bins: BinStrategy,
+    pub norm: HistogramNorm,
+    /// Accumulate bin values left to right (a density histogram then ends at 1.0)
+    pub cumulative: bool,
+}
+
+/// Binned data: `values[i]` covers `[edges[i], edges[i + 1])`
+///
+/// The last bin also includes its right edge, matching NumPy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    pub edges: Vec<f32>,
+    pub values: Vec<f32>,
+}
+
+impl Histogram {
+    /// Bin `data` according to `options`
+    ///
+    /// NaN and infinite samples are ignored, as are samples outside explicit
+    /// edges. Returns an empty histogram if there is nothing to bin.
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::histogram::{BinStrategy, Histogram, HistogramOptions};
+    ///
+    /// let options = HistogramOptions { bins: BinStrategy::Count(2), ..Default::default() };
+    /// let hist = Histogram::compute(&[0.0, 1.0, 1.5, 2.0], &options);
+    /// assert_eq!(hist.edges, vec![0.0, 1.0, 2.0]);
+    /// assert_eq!(hist.values, vec![1.0, 3.0]);
+    /// ```
+    pub fn compute(data: &[f32], options: &HistogramOptions) -> Self {
+        let finite: Vec<f32> = data.iter().copied().filter(|v| v.is_finite()).collect();
+        let edges = bin_edges(&finite, &options.bins);
+        if edges.len() < 2 {
+            return Self {
+                edges: Vec::new(),
+                values: Vec::new(),
+            };
+        }
+
+        let counts = count_into(&finite, &edges);
+        let mut hist = Self {
+            values: counts.iter().map(|&c| c as f32).collect(),
+            edges,
+        };
+        hist.apply_norm(options.norm, options.cumulative);
+        hist
+    }
+
+    /// Build a histogram from precomputed per-bin counts (e.g. GPU binning)
+    pub fn from_counts(edges: Vec<f32>, counts: &[u32], options: &HistogramOptions) -> Self {
+        let mut hist = Self {
+            values: counts.iter().map(|&c| c as f32).collect(),
+            edges,
+        };
+        hist.apply_norm(options.norm, options.cumulative);
+        hist
+    }
+
+    /// Number of bins
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Width of bin `i`
+    pub fn bin_width(&self, i: usize) -> f32 {
+        self.edges[i + 1] - self.edges[i]
+    }
+
+    fn apply_norm(&mut self, norm: HistogramNorm, cumulative: bool) {
+        let total: f32 = self.values.iter().sum();
+
+        if norm == HistogramNorm::Density && total > 0.0 {
+            for i in 0..self.values.len() {
+                let width = self.bin_width(i);
+                self.values[i] = if width > 0.0 { self.values[i] / (total * width) } else { 0.0 };
+            }
+        }
+
+        if cumulative {
+            // Cumulative density accumulates probability mass, not density
+            let mut running = 0.0;
+            for i in 0..self.values.len() {
+                let mass = match norm {
+                    HistogramNorm::Count => self.values[i],
+                    HistogramNorm::Density => self.values[i] * self.bin_width(i),
+                };
+                running += mass;
+                self.values[i] = running;
+            }
+        }
+    }
+}
+
+/// Compute bin edges for finite `data` using `strategy`
+///
+/// At most `MAX_BINS` bins are made. A `FixedWidth` that would need more
+/// falls back to `MAX_BINS` equal-width bins spanning the data instead.
+pub fn bin_edges(data: &[f32], strategy: &BinStrategy) -> Vec<f32> {
+    if let BinStrategy::Edges(edges) = strategy {
+        let increasing = edges.windows(2).all(|w| w[0] < w[1]);
+        return if increasing { edges.clone() } else { Vec::new() };
+    }
+    if data.is_empty() {
+        return Vec::new();
+    }
+
+    let min = data.iter().cloned().fold(f32::INFINITY, f32::min);
+    let max = data.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+    if max <= min {
+        // All samples equal: one unit-wide bin centered on the value
+        return vec![min - 0.5, min + 0.5];
+    }
+
+    let n = data.len() as f32;
+    let count = match strategy {
+        BinStrategy::Count(count) => (*count).clamp(1, MAX_BINS),
+        BinStrategy::Sturges => (n.log2().ceil() as usize) + 1,
+        BinStrategy::FreedmanDiaconis => {
+            let width = 2.0 * interquartile_range(data) / n.cbrt();
+            if width > 0.0 {
+                (((max - min) / width).ceil() as usize).clamp(1, MAX_BINS)
+            } else {
+                (n.log2().ceil() as usize) + 1 // Degenerate IQR: fall back to Sturges
+            }
+        }
+        BinStrategy::FixedWidth(width) => {
+            if !(*width > 0.0 && width.is_finite()) {
+                return Vec::new();
+            }
+            let start = (min / width).floor() * width;
+            // Float-to-int casts saturate, so a huge quotient can't overflow here
+            let bins = (((max - start) / width).floor() as usize).saturating_add(1);
+            if bins <= MAX_BINS {
+                return (0..=bins).map(|i| start + i as f32 * width).collect();
+            }
+            MAX_BINS
+        }
+        BinStrategy::Edges(_) => unreachable!(),
+    };
+
+    let width = (max - min) / count as f32;
+    let mut edges: Vec<f32> = (0..=count).map(|i| min + i as f32 * width).collect();
+    // Pin the last edge so rounding can't leave the maximum outside it
+    edges[count] = max;
+    edges
+}
+
+/// Bin edges for raw `data`, skipping NaN and infinite samples as
+/// `Histogram::compute` does
+///
+/// For binning elsewhere, e.g. on the GPU, with the same edges as the CPU.
+pub fn edges_for(data: &[f32], strategy: &BinStrategy) -> Vec<f32> {
+    if let BinStrategy::Edges(_) = strategy {
+        return bin_edges(&[], strategy);
+    }
+    let finite: Vec<f32> = data.iter().copied().filter(|v| v.is_finite()).collect();
+    bin_edges(&finite, strategy)
+}
+
+/// Count samples per bin; out-of-range samples are dropped
+fn count_into(data: &[f32], edges: &[f32]) -> Vec<u32> {
+    let bins = edges.len() - 1;
+    let mut counts = vec![0u32; bins];
+    let (first, last) = (edges[0], edges[bins]);
+
+    for &v in data {
+        if v < first || v > last {
+            continue;
+        }
+        // partition_point gives the number of edges <= v
+        let bin = edges.partition_point(|&e| e <= v).saturating_sub(1).min(bins - 1);
+        counts[bin] += 1;
+    }
+    counts
+}
+
+/// Interquartile range (Q3 - Q1) using linear interpolation between ranks
+fn interquartile_range(data: &[f32]) -> f32 {
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let quantile = |q: f32| {
+        let rank = q * (sorted.len() - 1) as f32;
+        let lo = rank.floor() as usize;
+        let hi = rank.ceil() as usize;
+        sorted[lo] + (sorted[hi] - sorted[lo]) * (rank - lo as f32)
+    };
+    quantile(0.75) - quantile(0.25)
+}
+
+/// Filled-bar renderer for `ChartKind::Bars` series
+///
+/// Draws the rectangles `Figure::chart_data` builds for bars, or those of
+/// `bar_data`, as a triangle list. Counting is separate: bin on the CPU with
+/// `Histogram::compute` or on the GPU with `compute::GpuHistogram`, then
+/// upload the result with `set_histogram`.
+pub struct HistogramRenderer {
+    mesh: MeshRenderer,
+    width: f32,
+    height: f32,
+}
+
+impl HistogramRenderer {
+    /// Replace the bars with `histogram`, filling the whole viewport
+    pub fn set_histogram(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, histogram: &Histogram, color: Color) {
+        let data = bar_data(histogram, color, self.width, self.height);
+        self.mesh.update_data(device, queue, &data);
+    }
+}
+
+impl Renderer for HistogramRenderer {
+    fn render_to_pass<'rpass>(&'rpass mut self, render_pass: &mut wgpu::RenderPass<'rpass>) {
+        self.mesh.render_to_pass(render_pass);
+    }
+
+    fn frame_stats(&mut self) -> DrawStats {
+        self.mesh.frame_stats()
+    }
+}
+
+impl WindowRenderer for HistogramRenderer {
+    fn with_options(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        chart_data: ChartData,
+        options: &RenderOptions,
+    ) -> Self {
+        HistogramRenderer {
+            mesh: MeshRenderer::with_options(device, config, chart_data, options),
+            width: config.width as f32,
+            height: config.height as f32,
+        }
+    }
+
+    fn update_data(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, chart_data: &ChartData) {
+        self.mesh.update_data(device, queue, chart_data);
+    }
+
+    fn resize(&mut self, queue: &wgpu::Queue, width: u32, height: u32) {
+        self.width = width as f32;
+        self.height = height as f32;
+        self.mesh.resize(queue, width, height);
+    }
+}
+
+/// Bars of `histogram` covering clip space: the edges span x in [-1, 1],
+/// heights rise from the bottom to the tallest bar at the top
+///
+/// # Example
+/// ```
+/// use helion_core::data::Color;
+/// use helion_core::histogram::{bar_data, Histogram};
+///
+/// let hist = Histogram { edges: vec![0.0, 1.0, 2.0], values: vec![2.0, 4.0] };
+/// let data = bar_data(&hist, Color::new(0.2, 0.4, 0.8, 1.0), 800.0, 600.0);
+/// assert_eq!(data.vertices.len(), 12);
+/// assert_eq!(data.vertices[8].position, [1.0, 1.0]);
+/// ```
+pub fn bar_data(histogram: &Histogram, color: Color, width: f32, height: f32) -> ChartData {
+    let mut figure = Figure::new(width, height);
+    let index = figure.add(Series::histogram(histogram.clone()).with_color(color));
+    figure.chart_data(index).unwrap_or_else(|| ChartData::new(width, height))
+}
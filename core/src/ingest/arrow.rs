@@ -0,0 +1,281 @@
+use super::source::{channel, ChannelSource, DataSource, Notify, SourceState};
+use crate::data::{ChartData, Color, Point2D};
+use crate::CancelToken;
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::{new_empty_array, Array, RecordBatch};
+use arrow_ipc::reader::StreamReader;
+use arrow_schema::{DataType, Schema, TimeUnit};
+use std::borrow::Cow;
+use std::io::{BufReader, Read};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::{Arc, Mutex};
+
+/// Points from an Arrow IPC stream, e.g. a live query result pushed over a
+/// socket
+///
+/// Record batches are decoded on a background thread as they arrive and
+/// queued for `poll`; subscribers are woken once per batch. Numeric, date and
+/// timestamp columns can be plotted (values are narrowed to f32, so raw
+/// timestamps lose sub-second precision). Rows with a null x or y are
+/// skipped. The source closes when the stream ends or fails; see `error`.
+///
+/// # Example
+/// ```no_run
+/// use helion_core::ingest::arrow::ArrowStreamSource;
+///
+/// let source = ArrowStreamSource::connect("127.0.0.1:9000", Some("time"), "price").unwrap();
+/// ```
+pub struct ArrowStreamSource {
+    source: ChannelSource,
+    error: Arc<Mutex<Option<String>>>,
+}
+
+impl ArrowStreamSource {
+    /// Connect to a TCP endpoint that writes an IPC stream on connection
+    pub fn connect(addr: impl ToSocketAddrs, x: Option<&str>, y: &str) -> Result<Self, String> {
+        let stream = TcpStream::connect(addr).map_err(|e| format!("Failed to connect to Arrow stream: {}", e))?;
+        Self::from_reader(stream, x, y)
+    }
+
+    /// Read an IPC stream from any byte source (Unix socket, pipe, file)
+    ///
+    /// Blocks until the stream's schema has been received.
+    ///
+    /// # Parameters
+    /// * `x` - Column holding x, or `None` to use the row number
+    /// * `y` - Column holding y
+    pub fn from_reader(reader: impl Read + Send + 'static, x: Option<&str>, y: &str) -> Result<Self, String> {
+        let reader = StreamReader::try_new(BufReader::new(reader), None)
+            .map_err(|e| format!("Invalid Arrow IPC stream: {}", e))?;
+        let schema = reader.schema();
+        let x_index = x.map(|name| plottable_column(&schema, name)).transpose()?;
+        let y_index = plottable_column(&schema, y)?;
+
+        let (sender, source) = channel();
+        let error = Arc::new(Mutex::new(None));
+        let thread_error = Arc::clone(&error);
+        std::thread::Builder::new()
+            .name("helion-arrow-stream".to_string())
+            .spawn(move || {
+                let mut row = 0;
+                for batch in reader {
+                    let points = batch
+                        .map_err(|e| format!("Arrow stream failed: {}", e))
+                        .and_then(|batch| batch_points(&batch, x_index, y_index, &mut row));
+                    match points {
+                        Ok(points) => {
+                            if sender.send_all(points).is_err() {
+                                // Nobody is listening any more
+                                return;
+                            }
+                        }
+                        Err(e) => {
+                            log::warn!("{}", e);
+                            *thread_error.lock().unwrap_or_else(|e| e.into_inner()) = Some(e);
+                            return;
+                        }
+                    }
+                }
+            })
+            .map_err(|e| format!("Failed to start Arrow stream thread: {}", e))?;
+
+        Ok(Self { source, error })
+    }
+
+    /// Why the stream stopped early, if it did
+    pub fn error(&self) -> Option<String> {
+        self.error.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+}
+
+impl DataSource for ArrowStreamSource {
+    fn poll(&mut self, out: &mut Vec<Point2D>) -> SourceState {
+        self.source.poll(out)
+    }
+
+    fn subscribe(&mut self, notify: Notify) -> bool {
+        self.source.subscribe(notify)
+    }
+}
+
+/// Index of column `name`, checking that it can be converted to f32
+fn plottable_column(schema: &Schema, name: &str) -> Result<usize, String> {
+    let index = schema
+        .index_of(name)
+        .map_err(|_| format!("Arrow stream has no column '{}'", name))?;
+    values_f32(&new_empty_array(schema.field(index).data_type()))
+        .map_err(|e| format!("Column '{}': {}", name, e))?;
+    Ok(index)
+}
+
+/// Convert one record batch into points, numbering rows from `row`
+fn batch_points(batch: &RecordBatch, x: Option<usize>, y: usize, row: &mut u64) -> Result<Vec<Point2D>, String> {
+    let ys = values_f32(batch.column(y))?;
+    let xs = match x {
+        Some(x) => values_f32(batch.column(x))?,
+        None => (0..batch.num_rows()).map(|i| Some((*row + i as u64) as f32)).collect(),
+    };
+    *row += batch.num_rows() as u64;
+
+    Ok(xs
+        .into_iter()
+        .zip(ys)
+        .filter_map(|(x, y)| Some(Point2D::new(x?, y?)))
+        .collect())
+}
+
+/// Apply `$convert` to the typed array behind `$array`, for every numeric
+/// and temporal type that can be plotted
+macro_rules! with_numeric {
+    ($array:expr, $convert:ident) => {
+        match $array.data_type() {
+            DataType::Float32 => $convert!(Float32Type),
+            DataType::Float64 => $convert!(Float64Type),
+            DataType::Int8 => $convert!(Int8Type),
+            DataType::Int16 => $convert!(Int16Type),
+            DataType::Int32 => $convert!(Int32Type),
+            DataType::Int64 => $convert!(Int64Type),
+            DataType::UInt8 => $convert!(UInt8Type),
+            DataType::UInt16 => $convert!(UInt16Type),
+            DataType::UInt32 => $convert!(UInt32Type),
+            DataType::UInt64 => $convert!(UInt64Type),
+            DataType::Date32 => $convert!(Date32Type),
+            DataType::Date64 => $convert!(Date64Type),
+            DataType::Timestamp(TimeUnit::Second, _) => $convert!(TimestampSecondType),
+            DataType::Timestamp(TimeUnit::Millisecond, _) => $convert!(TimestampMillisecondType),
+            DataType::Timestamp(TimeUnit::Microsecond, _) => $convert!(TimestampMicrosecondType),
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => $convert!(TimestampNanosecondType),
+            other => return Err(format!("type {} can't be plotted", other)),
+        }
+    };
+}
+
+/// Values of a numeric or temporal column as f32, `None` for nulls
+fn values_f32(array: &dyn Array) -> Result<Vec<Option<f32>>, String> {
+    macro_rules! convert {
+        ($t:ty) => {
+            array.as_primitive::<$t>().iter().map(|v| v.map(|v| v as f32)).collect()
+        };
+    }
+    Ok(with_numeric!(array, convert))
+}
+
+/// Values of a numeric or temporal column as f64, NaN for nulls
+fn values_f64(array: &dyn Array) -> Result<Vec<f64>, String> {
+    macro_rules! convert {
+        ($t:ty) => {
+            array.as_primitive::<$t>().iter().map(|v| v.map_or(f64::NAN, |v| v as f64)).collect()
+        };
+    }
+    Ok(with_numeric!(array, convert))
+}
+
+/// A column's values as coordinates, borrowed from the array where its
+/// buffer already holds them
+enum Coords<'a> {
+    F32(&'a [f32]),
+    F64(Cow<'a, [f64]>),
+}
+
+impl<'a> Coords<'a> {
+    /// Float columns without nulls are borrowed; everything else is
+    /// widened to f64, so timestamps keep their precision
+    fn new(array: &'a dyn Array) -> Result<Self, String> {
+        if array.null_count() == 0 {
+            match array.data_type() {
+                DataType::Float32 => return Ok(Coords::F32(array.as_primitive::<Float32Type>().values())),
+                DataType::Float64 => {
+                    return Ok(Coords::F64(Cow::Borrowed(array.as_primitive::<Float64Type>().values())))
+                }
+                _ => {}
+            }
+        }
+        values_f64(array).map(|values| Coords::F64(Cow::Owned(values)))
+    }
+
+    fn to_f64(&self) -> Cow<'a, [f64]> {
+        match self {
+            Coords::F32(values) => Cow::Owned(values.iter().map(|&v| v as f64).collect()),
+            Coords::F64(values) => values.clone(),
+        }
+    }
+}
+
+/// Column `name` of `batch`
+fn batch_column<'a>(batch: &'a RecordBatch, name: &str) -> Result<&'a dyn Array, String> {
+    batch
+        .column_by_name(name)
+        .map(|column| column.as_ref())
+        .ok_or_else(|| format!("Record batch has no column '{}'", name))
+}
+
+impl ChartData {
+    /// Create scatter plot data from columns `x` and `y` of an Arrow record
+    /// batch, e.g. a DataFusion or Polars query result
+    ///
+    /// Float32 and Float64 columns without nulls are normalized straight
+    /// from the batch's buffers, like `from_scatter` and `from_scatter_f64`
+    /// do with slices. Other numeric, date and timestamp columns, columns
+    /// with nulls, and a Float32 column paired with a Float64 one are
+    /// first widened to f64. Nulls count as missing values and are handled
+    /// like NaN, so by default those rows are left out and listed in
+    /// `dropped`.
+    ///
+    /// # Parameters
+    /// * `batch` - Record batch holding the columns
+    /// * `x` - Name of the column holding x
+    /// * `y` - Name of the column holding y
+    /// * `color` - Optional color for all points. If None, uses default blue color
+    /// * `size` - Optional size for all points in pixels. If None, defaults to 2.0
+    /// * `width` - Viewport width in pixels
+    /// * `height` - Viewport height in pixels
+    ///
+    /// # Returns
+    /// An error if either column is missing or has a type that can't be
+    /// plotted
+    ///
+    /// # Example
+    /// ```
+    /// use arrow_array::{Float32Array, Int64Array, RecordBatch};
+    /// use helion_core::data::ChartData;
+    /// use std::sync::Arc;
+    ///
+    /// let batch = RecordBatch::try_from_iter([
+    ///     ("step", Arc::new(Int64Array::from(vec![0, 1, 2])) as _),
+    ///     ("loss", Arc::new(Float32Array::from(vec![Some(1.0), None, Some(0.5)])) as _),
+    /// ])
+    /// .unwrap();
+    /// let data = ChartData::from_arrow(&batch, "step", "loss", None, None, 800.0, 600.0).unwrap();
+    /// assert_eq!(data.vertices.len(), 2);
+    /// assert_eq!(data.dropped(), &[1]);
+    /// ```
+    pub fn from_arrow(
+        batch: &RecordBatch,
+        x: &str,
+        y: &str,
+        color: Option<Color>,
+        size: Option<f32>,
+        width: f32,
+        height: f32,
+    ) -> Result<Self, String> {
+        let coords = |name: &str| {
+            let column = batch_column(batch, name)?;
+            Coords::new(column).map_err(|e| format!("Column '{}': {}", name, e))
+        };
+        let (xs, ys) = (coords(x)?, coords(y)?);
+        let mut data = Self::new(width, height);
+        let token = CancelToken::new();
+        match (&xs, &ys) {
+            (Coords::F32(xs), Coords::F32(ys)) => {
+                data.fill_scatter_cancelable(xs, ys, color, size, None, None, &token, &mut |_| {})
+            }
+            _ => {
+                let (xs, ys) = (xs.to_f64(), ys.to_f64());
+                data.fill_scatter_f64_cancelable(&xs, &ys, color, size, None, None, &token, &mut |_| {})
+            }
+        }
+        .map_err(|e| e.to_string())?;
+        Ok(data)
+    }
+}
@@ -0,0 +1,108 @@
+use crate::data::{ChartData, Color};
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+/// Cells read as a missing value rather than a number, besides empty ones
+const MISSING: [&str; 5] = ["na", "n/a", "nan", "null", "none"];
+
+/// Columns `names` of a CSV table whose first row is a header, parsed as
+/// numbers, in the order asked for
+///
+/// Empty cells and ones reading NA, NaN, null or none (in any case) become
+/// NaN, so plotting treats them as missing.
+///
+/// # Parameters
+/// * `reader` - CSV text, e.g. an open file
+/// * `delimiter` - Field separator, `b','` or `b'\t'` for TSV
+/// * `names` - Header names of the columns to read
+///
+/// # Returns
+/// An error if a column isn't in the header or a cell isn't a number
+///
+/// # Example
+/// ```
+/// use helion_core::ingest::csv::read_csv_columns;
+///
+/// let csv = "time,price,note\n0,1.5,open\n1,,\n2,2.5,\"close, late\"\n";
+/// let columns = read_csv_columns(csv.as_bytes(), b',', &["price", "time"]).unwrap();
+/// assert_eq!(columns[1], vec![0.0, 1.0, 2.0]);
+/// assert!(columns[0][1].is_nan());
+/// ```
+pub fn read_csv_columns(reader: impl Read, delimiter: u8, names: &[&str]) -> Result<Vec<Vec<f64>>, String> {
+    let mut reader = ::csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .trim(::csv::Trim::All)
+        .flexible(true)
+        .from_reader(reader);
+    let header = reader.headers().map_err(|e| format!("Invalid CSV header: {}", e))?.clone();
+    let indices = names
+        .iter()
+        .map(|name| {
+            header
+                .iter()
+                .position(|field| field == *name)
+                .ok_or_else(|| format!("CSV has no column '{}'; its columns are {:?}", name, header.iter().collect::<Vec<_>>()))
+        })
+        .collect::<Result<Vec<usize>, String>>()?;
+
+    let mut columns = vec![Vec::new(); names.len()];
+    for (row, record) in reader.records().enumerate() {
+        let record = record.map_err(|e| format!("Invalid CSV: {}", e))?;
+        for ((column, &index), name) in columns.iter_mut().zip(&indices).zip(names) {
+            let cell = record.get(index).unwrap_or("");
+            let value = match cell.parse::<f64>() {
+                Ok(value) => value,
+                Err(_) if cell.is_empty() || MISSING.iter().any(|m| cell.eq_ignore_ascii_case(m)) => f64::NAN,
+                // Rows are numbered as in the file, the header being row 1
+                Err(_) => return Err(format!("Row {}, column '{}': '{}' isn't a number", row + 2, name, cell)),
+            };
+            column.push(value);
+        }
+    }
+    Ok(columns)
+}
+
+impl ChartData {
+    /// Create scatter plot data from columns `x` and `y` of a CSV file with
+    /// a header row
+    ///
+    /// Files ending in `.tsv` or `.tab` are read as tab-separated. Values
+    /// are normalized in f64 like `from_scatter_f64`; missing cells (see
+    /// `read_csv_columns`) leave their row out, listed in `dropped`.
+    ///
+    /// # Parameters
+    /// * `path` - File to read
+    /// * `x` - Header name of the column holding x
+    /// * `y` - Header name of the column holding y
+    /// * `color` - Optional color for all points. If None, uses default blue color
+    /// * `size` - Optional size for all points in pixels. If None, defaults to 2.0
+    /// * `width` - Viewport width in pixels
+    /// * `height` - Viewport height in pixels
+    ///
+    /// # Example
+    /// ```no_run
+    /// use helion_core::ChartData;
+    ///
+    /// let data = ChartData::from_csv("prices.csv", "time", "close", None, None, 800.0, 600.0).unwrap();
+    /// ```
+    pub fn from_csv(
+        path: impl AsRef<Path>,
+        x: &str,
+        y: &str,
+        color: Option<Color>,
+        size: Option<f32>,
+        width: f32,
+        height: f32,
+    ) -> Result<Self, String> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+        let delimiter = match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("tsv") || ext.eq_ignore_ascii_case("tab") => b'\t',
+            _ => b',',
+        };
+        let columns =
+            read_csv_columns(file, delimiter, &[x, y]).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Ok(Self::from_scatter_f64(&columns[0], &columns[1], color, size, width, height))
+    }
+}
@@ -0,0 +1,203 @@
+use super::{decode_f32, Dtype, Endian, NpyHeader};
+use crate::cancel::{CancelToken, PREPARE_CHUNK};
+use crate::chunked::ChunkedBuffer;
+use crate::data::{PositionVertex, ScatterTransform};
+use crate::error::HelionError;
+use memmap2::Mmap;
+use std::fs::File;
+use std::ops::Range;
+use std::path::Path;
+
+/// Points decoded and uploaded per step by `stream_positions`
+pub const STREAM_CHUNK: usize = 1 << 20;
+
+/// A 1-D array of numbers backed by a memory-mapped file
+///
+/// Nothing is read up front: pages are faulted in as ranges are decoded and
+/// can be evicted again by the OS, so arrays far larger than RAM can be
+/// scanned and uploaded piece by piece.
+///
+/// The file must not be modified or truncated while it is mapped.
+pub struct MappedArray {
+    mmap: Mmap,
+    offset: usize,
+    len: usize,
+    dtype: Dtype,
+    endian: Endian,
+}
+
+impl MappedArray {
+    /// Map a headerless file of packed elements
+    ///
+    /// Trailing bytes that don't form a whole element are ignored.
+    pub fn open_raw(path: impl AsRef<Path>, dtype: Dtype, endian: Endian) -> Result<Self, String> {
+        let mmap = map_file(path.as_ref())?;
+        let len = mmap.len() / dtype.item_size();
+        Ok(Self {
+            mmap,
+            offset: 0,
+            len,
+            dtype,
+            endian,
+        })
+    }
+
+    /// Map the data of a `.npy` file holding float or integer values
+    ///
+    /// Multi-dimensional arrays are read in storage order, i.e. flattened.
+    pub fn open_npy(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let mmap = map_file(path)?;
+        let header = NpyHeader::parse(&mmap).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let (dtype, endian) = header.dtype()?;
+        let len = header.len().map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        let needed = len
+            .checked_mul(dtype.item_size())
+            .and_then(|size| size.checked_add(header.data_offset))
+            .unwrap_or(usize::MAX);
+        if mmap.len() < needed {
+            return Err(format!(
+                "{}: file is {} bytes but its header describes {} bytes",
+                path.display(),
+                mmap.len(),
+                needed
+            ));
+        }
+
+        Ok(Self {
+            mmap,
+            offset: header.data_offset,
+            len,
+            dtype,
+            endian,
+        })
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn dtype(&self) -> Dtype {
+        self.dtype
+    }
+
+    /// Decode elements `range` as f32, appending to `out`
+    ///
+    /// The range is clamped to the array.
+    pub fn read_into(&self, range: Range<usize>, out: &mut Vec<f32>) {
+        let end = range.end.min(self.len);
+        let start = range.start.min(end);
+        let size = self.dtype.item_size();
+        let bytes = &self.mmap[self.offset + start * size..self.offset + end * size];
+        decode_f32(bytes, self.dtype, self.endian, out);
+    }
+
+    /// Decode elements `range` as f32
+    pub fn read(&self, range: Range<usize>) -> Vec<f32> {
+        let mut out = Vec::with_capacity(range.len().min(self.len));
+        self.read_into(range, &mut out);
+        out
+    }
+
+    /// Minimum and maximum finite value, checking `token` between chunks
+    ///
+    /// # Returns
+    /// `(inf, -inf)` for an array with no finite values
+    pub fn bounds(&self, token: &CancelToken) -> Result<(f32, f32), HelionError> {
+        let mut buffer = Vec::with_capacity(PREPARE_CHUNK);
+        let (mut min, mut max) = (f32::INFINITY, f32::NEG_INFINITY);
+        for start in (0..self.len).step_by(PREPARE_CHUNK) {
+            token.check()?;
+            buffer.clear();
+            self.read_into(start..start + PREPARE_CHUNK, &mut buffer);
+            for &v in buffer.iter().filter(|v| v.is_finite()) {
+                min = min.min(v);
+                max = max.max(v);
+            }
+        }
+        Ok((min, max))
+    }
+}
+
+fn map_file(path: &Path) -> Result<Mmap, String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    // SAFETY: the mapping is read-only; as documented on MappedArray, callers
+    // must not modify the file while it is mapped
+    let mmap = unsafe { Mmap::map(&file) }.map_err(|e| format!("Failed to map {}: {}", path.display(), e))?;
+    #[cfg(unix)]
+    let _ = mmap.advise(memmap2::Advice::Sequential);
+    Ok(mmap)
+}
+
+/// Normalize memory-mapped x/y columns to clip space and upload them as
+/// `PositionVertex` data, one `STREAM_CHUNK` at a time
+///
+/// Points are placed as `ScatterTransform` places them: a column whose
+/// finite values are all equal is centered and infinities are pinned to
+/// the edge of clip space.
+///
+/// Makes two passes over the files (bounds, then upload) and never holds
+/// more than one chunk in RAM; staged writes are submitted after every chunk
+/// so wgpu's staging memory stays bounded too. Draw the result with
+/// `UniformScatterRenderer::set_position_buffer`.
+///
+/// # Parameters
+/// * `x`, `y` - Coordinate columns; the shorter length is used
+/// * `token` - Checked between chunks
+/// * `progress` - Called with the fraction of work done (0.0 to 1.0)
+pub fn stream_positions(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    x: &MappedArray,
+    y: &MappedArray,
+    token: &CancelToken,
+    progress: &mut dyn FnMut(f32),
+) -> Result<ChunkedBuffer, HelionError> {
+    let len = x.len().min(y.len());
+    let (x_min, x_max) = x.bounds(token)?;
+    progress(0.25);
+    let (y_min, y_max) = y.bounds(token)?;
+    progress(0.5);
+    let transform = ScatterTransform::new((x_min as f64, x_max as f64), (y_min as f64, y_max as f64), None, None);
+
+    let buffer = ChunkedBuffer::new_empty::<PositionVertex>(
+        device,
+        "Mapped Position Buffer",
+        len as u64,
+        wgpu::BufferUsages::VERTEX,
+        1,
+    )?;
+
+    let (mut xs, mut ys) = (Vec::with_capacity(STREAM_CHUNK), Vec::with_capacity(STREAM_CHUNK));
+    let mut positions = Vec::with_capacity(STREAM_CHUNK);
+    for start in (0..len).step_by(STREAM_CHUNK) {
+        token.check()?;
+        let end = (start + STREAM_CHUNK).min(len);
+        xs.clear();
+        ys.clear();
+        x.read_into(start..end, &mut xs);
+        y.read_into(start..end, &mut ys);
+
+        positions.clear();
+        positions.extend(xs.iter().zip(&ys).map(|(&px, &py)| {
+            let point = transform.apply(px, py);
+            PositionVertex {
+                position: [point.x, point.y],
+            }
+        }));
+        buffer.write(queue, start as u64, &positions)?;
+        queue.submit(std::iter::empty());
+        device.poll(wgpu::Maintain::Poll);
+
+        progress(0.5 + 0.5 * end as f32 / len as f32);
+    }
+
+    progress(1.0);
+    Ok(buffer)
+}
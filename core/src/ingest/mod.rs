@@ -0,0 +1,92 @@
+//! Getting plot data into Helion from files and live sources without going
+//! through the language bindings
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "io")]
+pub mod csv;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod mmap;
+pub mod npy;
+pub mod npz;
+#[cfg(feature = "io")]
+pub mod parquet;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod shm;
+pub mod source;
+pub mod tail;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use mmap::MappedArray;
+pub use npy::{read_npy, NpyArray, NpyHeader};
+pub use npz::NpzArchive;
+#[cfg(not(target_arch = "wasm32"))]
+pub use shm::{SharedMemorySource, SharedMemoryWriter};
+pub use source::{DataSource, SourceState};
+pub use tail::TailSource;
+
+/// Element type of a raw or `.npy` array that can be plotted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dtype {
+    F32,
+    F64,
+    I8,
+    I16,
+    I32,
+    I64,
+    U8,
+    U16,
+    U32,
+    U64,
+}
+
+/// Byte order of stored elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Endian {
+    #[default]
+    Little,
+    Big,
+}
+
+impl Dtype {
+    /// Size of one element in bytes
+    pub fn item_size(self) -> usize {
+        match self {
+            Dtype::I8 | Dtype::U8 => 1,
+            Dtype::I16 | Dtype::U16 => 2,
+            Dtype::F32 | Dtype::I32 | Dtype::U32 => 4,
+            Dtype::F64 | Dtype::I64 | Dtype::U64 => 8,
+        }
+    }
+}
+
+/// Append every whole `$t` in `$bytes` to `$out` as f32
+macro_rules! decode_as {
+    ($t:ty, $bytes:expr, $endian:expr, $out:expr) => {{
+        const SIZE: usize = std::mem::size_of::<$t>();
+        let chunks = $bytes.chunks_exact(SIZE).map(|b| <[u8; SIZE]>::try_from(b).unwrap());
+        match $endian {
+            Endian::Little => $out.extend(chunks.map(|b| <$t>::from_le_bytes(b) as f32)),
+            Endian::Big => $out.extend(chunks.map(|b| <$t>::from_be_bytes(b) as f32)),
+        }
+    }};
+}
+
+/// Decode packed elements into f32, appending to `out`
+///
+/// Wider types are narrowed, so 64-bit integers above 2^24 lose precision.
+/// A trailing partial element is ignored.
+pub fn decode_f32(bytes: &[u8], dtype: Dtype, endian: Endian, out: &mut Vec<f32>) {
+    match dtype {
+        Dtype::F32 => decode_as!(f32, bytes, endian, out),
+        Dtype::F64 => decode_as!(f64, bytes, endian, out),
+        Dtype::I8 => decode_as!(i8, bytes, endian, out),
+        Dtype::I16 => decode_as!(i16, bytes, endian, out),
+        Dtype::I32 => decode_as!(i32, bytes, endian, out),
+        Dtype::I64 => decode_as!(i64, bytes, endian, out),
+        Dtype::U8 => decode_as!(u8, bytes, endian, out),
+        Dtype::U16 => decode_as!(u16, bytes, endian, out),
+        Dtype::U32 => decode_as!(u32, bytes, endian, out),
+        Dtype::U64 => decode_as!(u64, bytes, endian, out),
+    }
+}
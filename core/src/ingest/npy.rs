@@ -0,0 +1,258 @@
+use super::{decode_f32, Dtype, Endian};
+use std::path::Path;
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// Header of a NumPy `.npy` file
+///
+/// See the format description in `numpy.lib.format`: a magic string, a
+/// version, then a Python dict literal such as
+/// `{'descr': '<f4', 'fortran_order': False, 'shape': (1000,), }`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NpyHeader {
+    /// Array protocol type string, e.g. `<f4`
+    pub descr: String,
+    pub fortran_order: bool,
+    pub shape: Vec<usize>,
+    /// Byte offset of the array data from the start of the file
+    pub data_offset: usize,
+}
+
+impl NpyHeader {
+    /// Parse the header at the start of `bytes`
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::ingest::NpyHeader;
+    ///
+    /// let dict = b"{'descr': '<f4', 'fortran_order': False, 'shape': (3,), }\n";
+    /// let mut file = b"\x93NUMPY\x01\x00".to_vec();
+    /// file.extend_from_slice(&(dict.len() as u16).to_le_bytes());
+    /// file.extend_from_slice(dict);
+    ///
+    /// let header = NpyHeader::parse(&file).unwrap();
+    /// assert_eq!(header.shape, vec![3]);
+    /// assert_eq!(header.data_offset, 10 + dict.len());
+    /// ```
+    pub fn parse(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.len() < 10 || &bytes[..6] != MAGIC {
+            return Err("Not a .npy file (bad magic string)".to_string());
+        }
+        let major = bytes[6];
+        let (header_len, header_start) = match major {
+            1 => (u16::from_le_bytes([bytes[8], bytes[9]]) as usize, 10),
+            2 | 3 => {
+                if bytes.len() < 12 {
+                    return Err("Truncated .npy header".to_string());
+                }
+                (u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize, 12)
+            }
+            _ => return Err(format!("Unsupported .npy format version {}", major)),
+        };
+        let data_offset = header_start + header_len;
+        let dict = bytes
+            .get(header_start..data_offset)
+            .ok_or("Truncated .npy header")?;
+        let dict = std::str::from_utf8(dict).map_err(|_| "Invalid .npy header encoding".to_string())?;
+
+        Ok(Self {
+            descr: parse_descr(dict)?,
+            fortran_order: parse_fortran_order(dict)?,
+            shape: parse_shape(dict)?,
+            data_offset,
+        })
+    }
+
+    /// Number of elements (1 for a 0-d array)
+    ///
+    /// # Returns
+    /// An error if the shape holds more elements than fit in a `usize`, as
+    /// a corrupt or hostile header can claim
+    pub fn len(&self) -> Result<usize, String> {
+        self.shape
+            .iter()
+            .try_fold(1usize, |len, &dim| len.checked_mul(dim))
+            .ok_or_else(|| format!("Array shape {:?} has too many elements", self.shape))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shape.contains(&0)
+    }
+
+    /// Element type and byte order, if plottable
+    pub fn dtype(&self) -> Result<(Dtype, Endian), String> {
+        let (order, kind) = self.descr.split_at(1.min(self.descr.len()));
+        let endian = match order {
+            // '|' marks single-byte types where order doesn't apply
+            "<" | "=" | "|" => Endian::Little,
+            ">" => Endian::Big,
+            _ => return Err(format!("Unsupported .npy dtype '{}'", self.descr)),
+        };
+        let dtype = match kind {
+            "f4" => Dtype::F32,
+            "f8" => Dtype::F64,
+            "i1" => Dtype::I8,
+            "i2" => Dtype::I16,
+            "i4" => Dtype::I32,
+            "i8" => Dtype::I64,
+            "u1" => Dtype::U8,
+            "u2" => Dtype::U16,
+            "u4" => Dtype::U32,
+            "u8" => Dtype::U64,
+            _ => {
+                return Err(format!(
+                    "Unsupported .npy dtype '{}' (expected a float or integer type)",
+                    self.descr
+                ))
+            }
+        };
+        Ok((dtype, endian))
+    }
+}
+
+/// A whole NumPy array decoded to f32
+///
+/// `data` is always in row-major (C) order; Fortran-ordered files are
+/// transposed while reading.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NpyArray {
+    pub shape: Vec<usize>,
+    pub data: Vec<f32>,
+}
+
+impl NpyArray {
+    /// Decode a complete `.npy` file held in memory
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let header = NpyHeader::parse(bytes)?;
+        let (dtype, endian) = header.dtype()?;
+        let len = header.len()?;
+
+        let end = len
+            .checked_mul(dtype.item_size())
+            .and_then(|size| size.checked_add(header.data_offset));
+        let body = end
+            .and_then(|end| bytes.get(header.data_offset..end))
+            .ok_or_else(|| format!("Truncated .npy data: expected {} elements", len))?;
+        let mut data = Vec::with_capacity(len);
+        decode_f32(body, dtype, endian, &mut data);
+
+        if header.fortran_order && header.shape.len() > 1 {
+            data = fortran_to_c(&data, &header.shape);
+        }
+
+        Ok(Self {
+            shape: header.shape,
+            data,
+        })
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Column `index` of a 2-D array, e.g. the y values of an `(n, 2)` array
+    ///
+    /// A 1-D array is treated as a single column.
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::ingest::NpyArray;
+    ///
+    /// let points = NpyArray { shape: vec![3, 2], data: vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0] };
+    /// assert_eq!(points.column(1).unwrap(), vec![1.0, 3.0, 5.0]);
+    /// ```
+    pub fn column(&self, index: usize) -> Result<Vec<f32>, String> {
+        let columns = match self.shape.as_slice() {
+            [_] => 1,
+            [_, columns] => *columns,
+            _ => return Err(format!("Expected a 1-D or 2-D array, got shape {:?}", self.shape)),
+        };
+        if index >= columns {
+            return Err(format!("Column {} out of range for shape {:?}", index, self.shape));
+        }
+        Ok(self.data.iter().skip(index).step_by(columns).copied().collect())
+    }
+}
+
+/// Read and decode a `.npy` file
+///
+/// Loads the whole array into memory; use `MappedArray::open_npy` for files
+/// larger than RAM.
+pub fn read_npy(path: impl AsRef<Path>) -> Result<NpyArray, String> {
+    let path = path.as_ref();
+    let bytes = std::fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    NpyArray::from_bytes(&bytes).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Reorder column-major `data` of the given shape into row-major order
+fn fortran_to_c(data: &[f32], shape: &[usize]) -> Vec<f32> {
+    // Stride of each axis in the Fortran layout
+    let mut strides = vec![1; shape.len()];
+    for axis in 1..shape.len() {
+        strides[axis] = strides[axis - 1] * shape[axis - 1];
+    }
+
+    let mut out = Vec::with_capacity(data.len());
+    let mut index = vec![0; shape.len()];
+    for _ in 0..data.len() {
+        let offset: usize = index.iter().zip(&strides).map(|(i, s)| i * s).sum();
+        out.push(data[offset]);
+
+        // Advance the multi-index with the last axis fastest
+        for axis in (0..shape.len()).rev() {
+            index[axis] += 1;
+            if index[axis] < shape[axis] {
+                break;
+            }
+            index[axis] = 0;
+        }
+    }
+    out
+}
+
+/// Text following `'key':` in the header dict
+fn value_of<'a>(dict: &'a str, key: &str) -> Result<&'a str, String> {
+    let pattern = format!("'{}':", key);
+    let start = dict
+        .find(&pattern)
+        .ok_or_else(|| format!("Missing '{}' in .npy header", key))?;
+    Ok(dict[start + pattern.len()..].trim_start())
+}
+
+fn parse_descr(dict: &str) -> Result<String, String> {
+    let value = value_of(dict, "descr")?;
+    let quote = value.chars().next().filter(|c| *c == '\'' || *c == '"');
+    let quote = quote.ok_or("Structured .npy dtypes are not supported")?;
+    let end = value[1..].find(quote).ok_or("Unterminated dtype in .npy header")?;
+    Ok(value[1..1 + end].to_string())
+}
+
+fn parse_fortran_order(dict: &str) -> Result<bool, String> {
+    let value = value_of(dict, "fortran_order")?;
+    if value.starts_with("True") {
+        Ok(true)
+    } else if value.starts_with("False") {
+        Ok(false)
+    } else {
+        Err("Invalid fortran_order in .npy header".to_string())
+    }
+}
+
+fn parse_shape(dict: &str) -> Result<Vec<usize>, String> {
+    let value = value_of(dict, "shape")?;
+    let inner = value
+        .strip_prefix('(')
+        .and_then(|v| v.split(')').next())
+        .ok_or("Invalid shape in .npy header")?;
+    inner
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| dim.parse().map_err(|_| format!("Invalid dimension '{}' in .npy shape", dim)))
+        .collect()
+}
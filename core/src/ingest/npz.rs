@@ -0,0 +1,66 @@
+use super::NpyArray;
+use std::fs::File;
+use std::io::{Read, Seek};
+use std::path::Path;
+use zip::ZipArchive;
+
+/// A NumPy `.npz` archive, as written by `np.savez` or `np.savez_compressed`
+///
+/// Each member is a `.npy` file; members are decoded on demand by name.
+///
+/// # Example
+/// ```no_run
+/// use helion_core::ingest::NpzArchive;
+/// use helion_core::ChartData;
+///
+/// let mut archive = NpzArchive::open("samples.npz").unwrap();
+/// let x = archive.array("x").unwrap();
+/// let y = archive.array("y").unwrap();
+/// let data = ChartData::from_scatter(&x.data, &y.data, None, None, 800.0, 600.0);
+/// ```
+pub struct NpzArchive<R> {
+    zip: ZipArchive<R>,
+}
+
+impl NpzArchive<File> {
+    /// Open an archive on disk
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+        Self::new(file).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+}
+
+impl<R: Read + Seek> NpzArchive<R> {
+    /// Read an archive from any seekable source, e.g. a `Cursor` over bytes
+    pub fn new(reader: R) -> Result<Self, String> {
+        let zip = ZipArchive::new(reader).map_err(|e| format!("Invalid .npz archive: {}", e))?;
+        Ok(Self { zip })
+    }
+
+    /// Array names in archive order, without the `.npy` suffix
+    pub fn names(&self) -> Vec<String> {
+        self.zip
+            .file_names()
+            .map(|name| name.strip_suffix(".npy").unwrap_or(name).to_string())
+            .collect()
+    }
+
+    /// Decode the array called `name` (with or without the `.npy` suffix)
+    pub fn array(&mut self, name: &str) -> Result<NpyArray, String> {
+        let member = if name.ends_with(".npy") {
+            name.to_string()
+        } else {
+            format!("{}.npy", name)
+        };
+        let mut file = self
+            .zip
+            .by_name(&member)
+            .map_err(|_| format!("No array named '{}' in .npz archive", name))?;
+
+        let mut bytes = Vec::with_capacity(file.size() as usize);
+        file.read_to_end(&mut bytes)
+            .map_err(|e| format!("Failed to read '{}' from .npz archive: {}", name, e))?;
+        NpyArray::from_bytes(&bytes).map_err(|e| format!("'{}': {}", name, e))
+    }
+}
@@ -0,0 +1,78 @@
+use crate::data::{ChartData, Color};
+use ::parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use ::parquet::arrow::ProjectionMask;
+use arrow_array::{RecordBatch, RecordBatchReader};
+use arrow_select::concat::concat_batches;
+use std::fs::File;
+use std::path::Path;
+
+/// Columns `names` of a Parquet file as one record batch, in the order
+/// they're stored
+///
+/// Only the columns asked for are read and decompressed, so picking two
+/// columns out of a wide table is cheap.
+///
+/// # Returns
+/// An error if the file can't be read or lacks one of the columns
+pub fn read_parquet_columns(path: impl AsRef<Path>, names: &[&str]) -> Result<RecordBatch, String> {
+    let path = path.as_ref();
+    let fail = |e: &dyn std::fmt::Display| format!("{}: {}", path.display(), e);
+    let file = File::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    let builder = ParquetRecordBatchReaderBuilder::try_new(file).map_err(|e| fail(&e))?;
+
+    let schema = builder.schema();
+    let mut roots = names
+        .iter()
+        .map(|name| {
+            schema.index_of(name).map_err(|_| {
+                let columns: Vec<&String> = schema.fields().iter().map(|field| field.name()).collect();
+                fail(&format!("no column '{}'; its columns are {:?}", name, columns))
+            })
+        })
+        .collect::<Result<Vec<usize>, String>>()?;
+    roots.sort_unstable();
+    roots.dedup();
+
+    let mask = ProjectionMask::roots(builder.parquet_schema(), roots);
+    let reader = builder.with_projection(mask).build().map_err(|e| fail(&e))?;
+    let schema = reader.schema();
+    let batches = reader.collect::<Result<Vec<_>, _>>().map_err(|e| fail(&e))?;
+    concat_batches(&schema, &batches).map_err(|e| fail(&e))
+}
+
+impl ChartData {
+    /// Create scatter plot data from columns `x` and `y` of a Parquet file
+    ///
+    /// Reads just those two columns, then builds the points like
+    /// `from_arrow`: numeric, date and timestamp columns can be plotted,
+    /// and rows with a null are left out, listed in `dropped`.
+    ///
+    /// # Parameters
+    /// * `path` - File to read
+    /// * `x` - Name of the column holding x
+    /// * `y` - Name of the column holding y
+    /// * `color` - Optional color for all points. If None, uses default blue color
+    /// * `size` - Optional size for all points in pixels. If None, defaults to 2.0
+    /// * `width` - Viewport width in pixels
+    /// * `height` - Viewport height in pixels
+    ///
+    /// # Example
+    /// ```no_run
+    /// use helion_core::ChartData;
+    ///
+    /// let data = ChartData::from_parquet("trips.parquet", "pickup_time", "fare", None, None, 800.0, 600.0).unwrap();
+    /// ```
+    pub fn from_parquet(
+        path: impl AsRef<Path>,
+        x: &str,
+        y: &str,
+        color: Option<Color>,
+        size: Option<f32>,
+        width: f32,
+        height: f32,
+    ) -> Result<Self, String> {
+        let path = path.as_ref();
+        let batch = read_parquet_columns(path, &[x, y])?;
+        Self::from_arrow(&batch, x, y, color, size, width, height).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+}
@@ -0,0 +1,319 @@
+//! Point streams shared between processes through a memory-mapped ring
+//!
+//! A producer process (a simulator, a data acquisition loop, a Python script)
+//! writes points straight into a shared region and Helion reads them from
+//! there, with no sockets or serialization in between. The region is a file
+//! or a POSIX shared-memory object laid out as follows, in native byte order:
+//!
+//! | Offset | Type     | Field                                              |
+//! |--------|----------|----------------------------------------------------|
+//! | 0      | [u8; 8]  | magic, `b"HELIOSHM"`                               |
+//! | 8      | u32      | version, currently 1                               |
+//! | 12     | u32      | closed: set to 1 when the producer is done         |
+//! | 16     | u64      | capacity: number of point slots                    |
+//! | 24     | u64      | claimed: points written or being written           |
+//! | 32     | u64      | published: points completely written              |
+//! | 64     | [f32; 2] | slots: point `i` is stored at slot `i % capacity`  |
+//!
+//! To append `n` points the producer stores `claimed + n` in `claimed`, fills
+//! the slots, then stores the same value in `published`. Readers take points
+//! up to `published` and use `claimed` to detect slots overwritten while they
+//! were copying, so a slow reader loses the oldest points rather than seeing
+//! torn ones.
+
+use super::source::{DataSource, SourceState};
+use crate::data::Point2D;
+use memmap2::{Mmap, MmapMut};
+use std::fs::{File, OpenOptions};
+use std::path::Path;
+use std::sync::atomic::{fence, AtomicU32, AtomicU64, Ordering};
+
+pub const SHM_MAGIC: &[u8; 8] = b"HELIOSHM";
+pub const SHM_VERSION: u32 = 1;
+/// Bytes before the first point slot
+pub const SHM_HEADER_SIZE: usize = 64;
+
+const CLOSED: usize = 12;
+const CAPACITY: usize = 16;
+const CLAIMED: usize = 24;
+const PUBLISHED: usize = 32;
+
+/// Size of a region holding `capacity` points
+pub fn region_size(capacity: u64) -> u64 {
+    SHM_HEADER_SIZE as u64 + capacity * 8
+}
+
+/// Atomic views of a mapped region
+///
+/// Shared memory is only ever accessed through atomics, since the other
+/// process may write to it at any time.
+///
+/// `ptr` is writable only when it came from a writable mapping: the
+/// writer's, taken with `as_mut_ptr`. Readers map the region read-only and
+/// never store through it.
+struct Region {
+    ptr: *mut u8,
+    capacity: u64,
+}
+
+// SAFETY: the pointer targets a mapping owned alongside the region, and all
+// accesses through it are atomic
+unsafe impl Send for Region {}
+
+impl Region {
+    fn u32_at(&self, offset: usize) -> &AtomicU32 {
+        // SAFETY: offsets are inside the validated region and 4-aligned (the
+        // mapping is page-aligned); the region outlives `self`'s owner's map
+        unsafe { &*(self.ptr.add(offset) as *mut AtomicU32) }
+    }
+
+    fn u64_at(&self, offset: usize) -> &AtomicU64 {
+        // SAFETY: as above, with 8-aligned header offsets
+        unsafe { &*(self.ptr.add(offset) as *mut AtomicU64) }
+    }
+
+    fn slot(&self, index: u64) -> (&AtomicU32, &AtomicU32) {
+        let offset = SHM_HEADER_SIZE + (index % self.capacity) as usize * 8;
+        (self.u32_at(offset), self.u32_at(offset + 4))
+    }
+}
+
+/// Check the header of a mapped region, returning its capacity
+fn validate(bytes: &[u8]) -> Result<u64, String> {
+    if bytes.len() < SHM_HEADER_SIZE || &bytes[..8] != SHM_MAGIC {
+        return Err("Not a Helion shared-memory region (bad magic)".to_string());
+    }
+    let version = u32::from_ne_bytes(bytes[8..12].try_into().unwrap());
+    if version != SHM_VERSION {
+        return Err(format!("Unsupported shared-memory version {}", version));
+    }
+    let capacity = u64::from_ne_bytes(bytes[CAPACITY..CAPACITY + 8].try_into().unwrap());
+    if capacity == 0 || region_size(capacity) > bytes.len() as u64 {
+        return Err(format!(
+            "Shared-memory region of {} bytes can't hold {} points",
+            bytes.len(),
+            capacity
+        ));
+    }
+    Ok(capacity)
+}
+
+/// Reads points published by another process into a shared region
+///
+/// # Example
+/// ```no_run
+/// use helion_core::ingest::shm::SharedMemorySource;
+///
+/// // e.g. created by Python's multiprocessing.shared_memory.SharedMemory("sim")
+/// let source = SharedMemorySource::open_shm("sim").unwrap();
+/// ```
+pub struct SharedMemorySource {
+    // Kept alive for `region`
+    _map: Mmap,
+    region: Region,
+    read: u64,
+    dropped: u64,
+}
+
+impl SharedMemorySource {
+    /// Map a region stored in a file
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+        Self::from_file(&file).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Map a POSIX shared-memory object by name (without the leading `/`)
+    #[cfg(unix)]
+    pub fn open_shm(name: &str) -> Result<Self, String> {
+        let file = shm_file(name, false)?;
+        Self::from_file(&file).map_err(|e| format!("Shared memory '{}': {}", name, e))
+    }
+
+    fn from_file(file: &File) -> Result<Self, String> {
+        // SAFETY: the region is only read through atomics (see `Region`), so
+        // concurrent writes by the producer are expected and sound
+        let map = unsafe { Mmap::map(file) }.map_err(|e| format!("Failed to map: {}", e))?;
+        let capacity = validate(&map)?;
+        let region = Region {
+            // Only loaded from, never stored to, on the reader side
+            ptr: map.as_ptr().cast_mut(),
+            capacity,
+        };
+        Ok(Self {
+            _map: map,
+            region,
+            read: 0,
+            dropped: 0,
+        })
+    }
+
+    /// Points lost because the producer overwrote them before they were read
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+}
+
+impl DataSource for SharedMemorySource {
+    fn poll(&mut self, out: &mut Vec<Point2D>) -> SourceState {
+        let region = &self.region;
+        let closed = region.u32_at(CLOSED).load(Ordering::Acquire) != 0;
+        let published = region.u64_at(PUBLISHED).load(Ordering::Acquire);
+        if published < self.read {
+            // The producer restarted with a fresh region
+            self.read = 0;
+        }
+
+        let start = self.read.max(published.saturating_sub(region.capacity));
+        self.dropped += start - self.read;
+        let first = out.len();
+        for index in start..published {
+            let (x, y) = region.slot(index);
+            out.push(Point2D::new(
+                f32::from_bits(x.load(Ordering::Relaxed)),
+                f32::from_bits(y.load(Ordering::Relaxed)),
+            ));
+        }
+
+        // Anything the producer claimed past `capacity` ahead of a slot may
+        // have been overwritten mid-copy
+        fence(Ordering::Acquire);
+        let claimed = region.u64_at(CLAIMED).load(Ordering::Relaxed);
+        let overwritten = claimed.saturating_sub(region.capacity).saturating_sub(start);
+        let overwritten = overwritten.min(published - start) as usize;
+        out.drain(first..first + overwritten);
+        self.dropped += overwritten as u64;
+        self.read = published;
+
+        if closed {
+            SourceState::Closed
+        } else {
+            SourceState::Open
+        }
+    }
+}
+
+/// Producer side of a shared region, for feeding a Helion window from
+/// another Rust process
+///
+/// The region is marked closed when the writer is dropped.
+pub struct SharedMemoryWriter {
+    map: MmapMut,
+    region: Region,
+    written: u64,
+}
+
+impl SharedMemoryWriter {
+    /// Create (or replace) a region in a file
+    pub fn create(path: impl AsRef<Path>, capacity: u64) -> Result<Self, String> {
+        let path = path.as_ref();
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)
+            .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+        Self::from_file(&file, capacity).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Create (or replace) a POSIX shared-memory object
+    #[cfg(unix)]
+    pub fn create_shm(name: &str, capacity: u64) -> Result<Self, String> {
+        let file = shm_file(name, true)?;
+        Self::from_file(&file, capacity).map_err(|e| format!("Shared memory '{}': {}", name, e))
+    }
+
+    fn from_file(file: &File, capacity: u64) -> Result<Self, String> {
+        if capacity == 0 {
+            return Err("Shared-memory capacity must be at least 1 point".to_string());
+        }
+        file.set_len(region_size(capacity))
+            .map_err(|e| format!("Failed to size region: {}", e))?;
+        // SAFETY: as for readers, shared contents are only touched through
+        // atomics once the header is written
+        let mut map = unsafe { MmapMut::map_mut(file) }.map_err(|e| format!("Failed to map: {}", e))?;
+
+        map[..SHM_HEADER_SIZE].fill(0);
+        map[..8].copy_from_slice(SHM_MAGIC);
+        map[8..12].copy_from_slice(&SHM_VERSION.to_ne_bytes());
+        map[CAPACITY..CAPACITY + 8].copy_from_slice(&capacity.to_ne_bytes());
+
+        let region = Region {
+            ptr: map.as_mut_ptr(),
+            capacity,
+        };
+        Ok(Self {
+            map,
+            region,
+            written: 0,
+        })
+    }
+
+    /// Publish points, overwriting the oldest ones once the ring is full
+    pub fn push(&mut self, points: &[Point2D]) {
+        let region = &self.region;
+        let end = self.written + points.len() as u64;
+        region.u64_at(CLAIMED).store(end, Ordering::Relaxed);
+        fence(Ordering::Release);
+
+        // Points that would be overwritten within this same call are skipped
+        let skip = points.len().saturating_sub(region.capacity as usize);
+        for (i, point) in points.iter().enumerate().skip(skip) {
+            let (x, y) = region.slot(self.written + i as u64);
+            x.store(point.x.to_bits(), Ordering::Relaxed);
+            y.store(point.y.to_bits(), Ordering::Relaxed);
+        }
+
+        region.u64_at(PUBLISHED).store(end, Ordering::Release);
+        self.written = end;
+    }
+
+    /// Tell readers no more points are coming
+    pub fn close(&self) {
+        self.region.u32_at(CLOSED).store(1, Ordering::Release);
+    }
+
+    /// Flush a file-backed region to disk
+    pub fn flush(&self) -> Result<(), String> {
+        self.map.flush().map_err(|e| format!("Failed to flush: {}", e))
+    }
+}
+
+impl Drop for SharedMemoryWriter {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+/// Remove a POSIX shared-memory object; mappings that are open stay valid
+#[cfg(unix)]
+pub fn remove_shm(name: &str) -> Result<(), String> {
+    let c_name = shm_name(name)?;
+    // SAFETY: `c_name` is a valid NUL-terminated string
+    if unsafe { libc::shm_unlink(c_name.as_ptr()) } != 0 {
+        return Err(format!("Failed to remove shared memory '{}': {}", name, std::io::Error::last_os_error()));
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn shm_name(name: &str) -> Result<std::ffi::CString, String> {
+    std::ffi::CString::new(format!("/{}", name.trim_start_matches('/')))
+        .map_err(|_| format!("Invalid shared-memory name '{}'", name))
+}
+
+#[cfg(unix)]
+fn shm_file(name: &str, create: bool) -> Result<File, String> {
+    use std::os::fd::FromRawFd;
+
+    let c_name = shm_name(name)?;
+    let flags = if create { libc::O_RDWR | libc::O_CREAT } else { libc::O_RDONLY };
+    // SAFETY: `c_name` is a valid NUL-terminated string
+    let fd = unsafe { libc::shm_open(c_name.as_ptr(), flags, 0o600) };
+    if fd < 0 {
+        return Err(format!("Failed to open shared memory '{}': {}", name, std::io::Error::last_os_error()));
+    }
+    // SAFETY: `fd` was just opened and is owned by nothing else
+    Ok(unsafe { File::from_raw_fd(fd) })
+}
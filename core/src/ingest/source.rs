@@ -0,0 +1,194 @@
+use crate::data::Point2D;
+use std::sync::mpsc::{self, Receiver, SendError, Sender, TryRecvError};
+use std::sync::{Arc, Mutex};
+
+/// Callback run by a source when new points become available
+pub type Notify = Arc<dyn Fn() + Send + Sync>;
+
+/// Whether a source may still produce points
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceState {
+    /// More points may arrive later
+    Open,
+    /// The producer is gone; every remaining point has been returned
+    Closed,
+}
+
+/// A live feed of points that streaming renderers pull from
+///
+/// Sockets, file tails and sensors all look the same to the consumer: call
+/// `poll` once per frame to collect whatever has arrived. Sources that know
+/// when data arrives also accept a `subscribe` callback, so an idle window
+/// can sleep until there is something new to draw.
+///
+/// # Example
+/// ```
+/// use helion_core::ingest::source::{channel, DataSource, SourceState};
+/// use helion_core::Point2D;
+///
+/// let (sender, mut source) = channel();
+/// std::thread::spawn(move || {
+///     for i in 0..100 {
+///         sender.send(Point2D::new(i as f32, (i as f32).sin())).unwrap();
+///     }
+/// })
+/// .join()
+/// .unwrap();
+///
+/// let mut points = Vec::new();
+/// assert_eq!(source.poll(&mut points), SourceState::Closed);
+/// assert_eq!(points.len(), 100);
+/// ```
+pub trait DataSource {
+    /// Append every point that arrived since the last call to `out`
+    ///
+    /// Never blocks.
+    fn poll(&mut self, out: &mut Vec<Point2D>) -> SourceState;
+
+    /// Ask to be told when new points can be polled
+    ///
+    /// # Returns
+    /// `false` if this source can't notify, in which case the caller should
+    /// poll every frame
+    fn subscribe(&mut self, notify: Notify) -> bool {
+        let _ = notify;
+        false
+    }
+}
+
+impl<S: DataSource + ?Sized> DataSource for Box<S> {
+    fn poll(&mut self, out: &mut Vec<Point2D>) -> SourceState {
+        (**self).poll(out)
+    }
+
+    fn subscribe(&mut self, notify: Notify) -> bool {
+        (**self).subscribe(notify)
+    }
+}
+
+/// A plain channel receiver; polling drains it without blocking
+impl DataSource for Receiver<Point2D> {
+    fn poll(&mut self, out: &mut Vec<Point2D>) -> SourceState {
+        loop {
+            match self.try_recv() {
+                Ok(point) => out.push(point),
+                Err(TryRecvError::Empty) => return SourceState::Open,
+                Err(TryRecvError::Disconnected) => return SourceState::Closed,
+            }
+        }
+    }
+}
+
+/// Create a channel whose receiving end is a subscribable `DataSource`
+///
+/// Like `std::sync::mpsc::channel`, but every send also wakes subscribers.
+pub fn channel() -> (SourceSender, ChannelSource) {
+    let (sender, receiver) = mpsc::channel();
+    let subscribers = Arc::new(Mutex::new(Vec::new()));
+    (
+        SourceSender {
+            sender,
+            subscribers: Arc::clone(&subscribers),
+        },
+        ChannelSource {
+            receiver,
+            subscribers,
+        },
+    )
+}
+
+/// Sending half of `channel`; clone it to feed the source from several threads
+#[derive(Clone)]
+pub struct SourceSender {
+    sender: Sender<Point2D>,
+    subscribers: Arc<Mutex<Vec<Notify>>>,
+}
+
+impl SourceSender {
+    /// Send one point
+    ///
+    /// # Returns
+    /// An error holding the point if the source has been dropped
+    pub fn send(&self, point: Point2D) -> Result<(), SendError<Point2D>> {
+        self.sender.send(point)?;
+        self.notify();
+        Ok(())
+    }
+
+    /// Send a batch of points, waking subscribers once
+    pub fn send_all(&self, points: impl IntoIterator<Item = Point2D>) -> Result<(), SendError<Point2D>> {
+        for point in points {
+            self.sender.send(point)?;
+        }
+        self.notify();
+        Ok(())
+    }
+
+    fn notify(&self) {
+        let subscribers = self.subscribers.lock().unwrap_or_else(|e| e.into_inner());
+        for notify in subscribers.iter() {
+            notify();
+        }
+    }
+}
+
+/// Receiving half of `channel`
+pub struct ChannelSource {
+    receiver: Receiver<Point2D>,
+    subscribers: Arc<Mutex<Vec<Notify>>>,
+}
+
+impl DataSource for ChannelSource {
+    fn poll(&mut self, out: &mut Vec<Point2D>) -> SourceState {
+        self.receiver.poll(out)
+    }
+
+    fn subscribe(&mut self, notify: Notify) -> bool {
+        self.subscribers.lock().unwrap_or_else(|e| e.into_inner()).push(notify);
+        true
+    }
+}
+
+/// Default number of points an `IterSource` returns per poll
+pub const DEFAULT_BATCH_SIZE: usize = 1024;
+
+/// Points taken from an iterator, at most `batch_size` per poll
+///
+/// The cap keeps endless iterators (simulated sensors, generators) from
+/// stalling a frame; each poll returns the next slice of the sequence.
+pub struct IterSource<I> {
+    iter: I,
+    batch_size: usize,
+    done: bool,
+}
+
+impl<I: Iterator<Item = Point2D>> IterSource<I> {
+    pub fn new(iter: impl IntoIterator<IntoIter = I>) -> Self {
+        Self {
+            iter: iter.into_iter(),
+            batch_size: DEFAULT_BATCH_SIZE,
+            done: false,
+        }
+    }
+
+    /// Change how many points each poll may return (at least 1)
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+}
+
+impl<I: Iterator<Item = Point2D>> DataSource for IterSource<I> {
+    fn poll(&mut self, out: &mut Vec<Point2D>) -> SourceState {
+        if !self.done {
+            let before = out.len();
+            out.extend(self.iter.by_ref().take(self.batch_size));
+            self.done = out.len() - before < self.batch_size;
+        }
+        if self.done {
+            SourceState::Closed
+        } else {
+            SourceState::Open
+        }
+    }
+}
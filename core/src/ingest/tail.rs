@@ -0,0 +1,307 @@
+use super::source::{DataSource, SourceState};
+use crate::data::Point2D;
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+/// Most bytes a single poll reads, so catching up on a large file is spread
+/// over several frames
+pub const MAX_READ_PER_POLL: u64 = 8 << 20;
+
+/// A CSV column, by position or by header name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Column {
+    Index(usize),
+    Name(String),
+}
+
+impl From<usize> for Column {
+    fn from(index: usize) -> Self {
+        Column::Index(index)
+    }
+}
+
+impl From<&str> for Column {
+    fn from(name: &str) -> Self {
+        Column::Name(name.to_string())
+    }
+}
+
+enum Format {
+    Csv {
+        delimiter: u8,
+        x: Option<Column>,
+        y: Column,
+        /// Header fields, once the first line has been read
+        header: Option<Vec<String>>,
+    },
+    Ndjson {
+        x: Option<String>,
+        y: String,
+    },
+}
+
+impl Format {
+    fn needs_header(&self) -> bool {
+        match self {
+            Format::Csv { x, y, header, .. } => {
+                header.is_none() && (matches!(x, Some(Column::Name(_))) || matches!(y, Column::Name(_)))
+            }
+            Format::Ndjson { .. } => false,
+        }
+    }
+}
+
+/// Follows a growing CSV or NDJSON file, like `tail -f`, yielding one point
+/// per appended line
+///
+/// Each poll reads whatever was appended since the last one. A trailing line
+/// without a newline is held back until it is complete. When no x column is
+/// given, x is the running row number, which suits metrics logged once per
+/// step. Lines that don't parse (including a header when columns are chosen
+/// by index) are skipped and counted.
+///
+/// If the file shrinks, e.g. when a log is rotated or truncated, reading
+/// restarts from its beginning. A file that doesn't exist yet is waited for.
+///
+/// # Example
+/// ```no_run
+/// use helion_core::ingest::source::DataSource;
+/// use helion_core::ingest::tail::TailSource;
+///
+/// let mut source = TailSource::csv("train.csv", Some("step".into()), "loss".into());
+/// let mut points = Vec::new();
+/// source.poll(&mut points);
+/// ```
+pub struct TailSource {
+    path: PathBuf,
+    format: Format,
+    offset: u64,
+    pending: Vec<u8>,
+    start_at_end: bool,
+    started: bool,
+    /// Drop the first line read, because following began partway through it
+    skip_partial: bool,
+    row: u64,
+    skipped: usize,
+}
+
+impl TailSource {
+    /// Follow a comma-separated file
+    ///
+    /// # Parameters
+    /// * `x` - Column holding x, or `None` to use the row number
+    /// * `y` - Column holding y
+    ///
+    /// Naming a column makes the file's first line its header.
+    pub fn csv(path: impl AsRef<Path>, x: Option<Column>, y: Column) -> Self {
+        Self::new(
+            path.as_ref(),
+            Format::Csv {
+                delimiter: b',',
+                x,
+                y,
+                header: None,
+            },
+        )
+    }
+
+    /// Follow a file of newline-delimited JSON objects
+    ///
+    /// # Parameters
+    /// * `x` - Key holding x, or `None` to use the row number
+    /// * `y` - Key holding y
+    pub fn ndjson(path: impl AsRef<Path>, x: Option<&str>, y: &str) -> Self {
+        Self::new(
+            path.as_ref(),
+            Format::Ndjson {
+                x: x.map(str::to_string),
+                y: y.to_string(),
+            },
+        )
+    }
+
+    fn new(path: &Path, format: Format) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            format,
+            offset: 0,
+            pending: Vec::new(),
+            start_at_end: false,
+            started: false,
+            skip_partial: false,
+            row: 0,
+            skipped: 0,
+        }
+    }
+
+    /// Use another field separator for CSV, e.g. `b'\t'`
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        if let Format::Csv { delimiter: d, .. } = &mut self.format {
+            *d = delimiter;
+        }
+        self
+    }
+
+    /// Ignore lines already in the file and only follow new ones
+    pub fn from_end(mut self) -> Self {
+        self.start_at_end = true;
+        self
+    }
+
+    /// Lines skipped so far because they couldn't be parsed
+    pub fn skipped_lines(&self) -> usize {
+        self.skipped
+    }
+
+    fn read_new(&mut self, out: &mut Vec<Point2D>) -> io::Result<()> {
+        let mut file = File::open(&self.path)?;
+        let len = file.metadata()?.len();
+
+        if !self.started {
+            self.started = true;
+            if self.start_at_end {
+                self.skip_to_end(&mut file, len)?;
+            }
+        }
+
+        if len < self.offset {
+            log::info!("{} shrank, reading it again from the start", self.path.display());
+            self.offset = 0;
+            self.pending.clear();
+            self.skip_partial = false;
+            if let Format::Csv { header, .. } = &mut self.format {
+                *header = None;
+            }
+        }
+        if len == self.offset {
+            return Ok(());
+        }
+
+        file.seek(SeekFrom::Start(self.offset))?;
+        let read = file
+            .take((len - self.offset).min(MAX_READ_PER_POLL))
+            .read_to_end(&mut self.pending)?;
+        self.offset += read as u64;
+
+        let Some(last_newline) = self.pending.iter().rposition(|&b| b == b'\n') else {
+            return Ok(());
+        };
+        let complete: Vec<u8> = self.pending.drain(..=last_newline).collect();
+        for line in complete.split(|&b| b == b'\n') {
+            if std::mem::take(&mut self.skip_partial) {
+                continue;
+            }
+            self.parse_line(line, out);
+        }
+        Ok(())
+    }
+
+    /// Position at the current end of the file, reading the CSV header first
+    /// if columns are named
+    fn skip_to_end(&mut self, file: &mut File, len: u64) -> io::Result<()> {
+        if len == 0 {
+            return Ok(());
+        }
+
+        if self.format.needs_header() {
+            let mut head = Vec::new();
+            file.take(MAX_READ_PER_POLL).read_to_end(&mut head)?;
+            match head.iter().position(|&b| b == b'\n') {
+                Some(end) => self.parse_line(&head[..end], &mut Vec::new()),
+                // The header itself is still being written; nothing to skip
+                None => return Ok(()),
+            }
+        }
+
+        let mut last = [0u8];
+        file.seek(SeekFrom::Start(len - 1))?;
+        file.read_exact(&mut last)?;
+        self.skip_partial = last[0] != b'\n';
+        self.offset = len;
+        Ok(())
+    }
+
+    fn read_header(&mut self, line: &str) {
+        if let Format::Csv { delimiter, x, y, header } = &mut self.format {
+            let fields: Vec<String> = split_csv(line, *delimiter).map(str::to_string).collect();
+            for column in x.iter().chain(std::iter::once(&*y)) {
+                if let Column::Name(name) = column {
+                    if !fields.contains(name) {
+                        log::warn!("Column '{}' not found in header of {}", name, self.path.display());
+                    }
+                }
+            }
+            *header = Some(fields);
+        }
+    }
+
+    fn parse_line(&mut self, line: &[u8], out: &mut Vec<Point2D>) {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        if line.iter().all(u8::is_ascii_whitespace) {
+            return;
+        }
+        let Ok(line) = std::str::from_utf8(line) else {
+            self.skipped += 1;
+            return;
+        };
+
+        if self.format.needs_header() {
+            self.read_header(line);
+            return;
+        }
+
+        let parsed = match &self.format {
+            Format::Csv { delimiter, x, y, header } => {
+                let fields: Vec<&str> = split_csv(line, *delimiter).collect();
+                let field = |column: &Column| {
+                    let index = match column {
+                        Column::Index(index) => Some(*index),
+                        Column::Name(name) => header.as_ref()?.iter().position(|h| h == name),
+                    };
+                    fields.get(index?)?.parse::<f32>().ok()
+                };
+                let x = match x {
+                    Some(column) => field(column),
+                    None => Some(self.row as f32),
+                };
+                x.zip(field(y))
+            }
+            Format::Ndjson { x, y } => {
+                let value: Option<serde_json::Value> = serde_json::from_str(line).ok();
+                let field = |key: &str| Some(value.as_ref()?.get(key)?.as_f64()? as f32);
+                let x = match x {
+                    Some(key) => field(key),
+                    None => Some(self.row as f32),
+                };
+                x.zip(field(y))
+            }
+        };
+
+        match parsed {
+            Some((x, y)) => {
+                out.push(Point2D::new(x, y));
+                self.row += 1;
+            }
+            None => self.skipped += 1,
+        }
+    }
+}
+
+/// Trimmed fields of one CSV line, with surrounding quotes removed
+fn split_csv(line: &str, delimiter: u8) -> impl Iterator<Item = &str> {
+    line.split(delimiter as char).map(|field| field.trim().trim_matches('"'))
+}
+
+impl DataSource for TailSource {
+    /// Read what was appended since the last poll; a file can always grow,
+    /// so the source never closes
+    fn poll(&mut self, out: &mut Vec<Point2D>) -> SourceState {
+        match self.read_new(out) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => log::warn!("Failed to read {}: {}", self.path.display(), e),
+        }
+        SourceState::Open
+    }
+}
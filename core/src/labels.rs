@@ -0,0 +1,206 @@
+use crate::data::{ChartData, Color};
+use crate::text::{HAlign, TextSection, VAlign};
+
+/// Label layout options
+#[derive(Debug, Clone)]
+pub struct LabelOptions {
+    /// Font size in pixels (label height)
+    pub font_size: f32,
+    /// Approximate glyph advance as a fraction of `font_size`
+    pub char_width: f32,
+    /// Gap between the point and its label in pixels
+    pub offset: f32,
+    /// Labels are skipped entirely above this many points, since per-point
+    /// text is unreadable (and expensive to lay out) for large datasets
+    pub max_labels: usize,
+}
+
+impl Default for LabelOptions {
+    fn default() -> Self {
+        Self {
+            font_size: 12.0,
+            char_width: 0.6,
+            offset: 4.0,
+            max_labels: 1000,
+        }
+    }
+}
+
+/// Side of the point a label was placed on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LabelPlacement {
+    Right,
+    Above,
+    Left,
+    Below,
+}
+
+/// What a label is attached to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LabelTarget {
+    /// A point; its label goes right of, above, left of or below it
+    Point([f32; 2]),
+    /// The free end of a bar; its label is centered beyond it, above a bar
+    /// rising from its baseline or below one hanging from it (`down`), and
+    /// just inside the bar if that side is taken
+    BarEnd { position: [f32; 2], down: bool },
+}
+
+impl LabelTarget {
+    pub fn position(&self) -> [f32; 2] {
+        match *self {
+            LabelTarget::Point(position) | LabelTarget::BarEnd { position, .. } => position,
+        }
+    }
+
+    /// The same target at `map(position)`
+    pub fn map(self, map: impl Fn([f32; 2]) -> [f32; 2]) -> Self {
+        match self {
+            LabelTarget::Point(position) => LabelTarget::Point(map(position)),
+            LabelTarget::BarEnd { position, down } => LabelTarget::BarEnd { position: map(position), down },
+        }
+    }
+}
+
+/// A placed label, in pixel coordinates (origin top-left, y down)
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataLabel {
+    /// Index of the labelled point in `ChartData::vertices`
+    pub index: usize,
+    pub text: String,
+    /// Top-left corner of the label box
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub placement: LabelPlacement,
+}
+
+impl DataLabel {
+    /// The label as text for a `TextRenderer`
+    pub fn section(&self, color: Color) -> TextSection {
+        TextSection::new(self.text.clone(), [self.x, self.y], self.height)
+            .with_color(color)
+            .with_align(HAlign::Left, VAlign::Top)
+    }
+
+    fn overlaps(&self, other: &DataLabel) -> bool {
+        self.x < other.x + other.width
+            && other.x < self.x + self.width
+            && self.y < other.y + other.height
+            && other.y < self.y + self.height
+    }
+}
+
+/// Format numeric values as label text with a fixed number of decimals
+pub fn value_labels(values: &[f32], precision: usize) -> Vec<String> {
+    values.iter().map(|v| format!("{:.*}", precision, v)).collect()
+}
+
+/// Place one label per point, avoiding overlaps between labels
+///
+/// Each label tries the right, above, left and below positions around its
+/// point in turn and takes the first that does not collide with an already
+/// placed label or leave the viewport. Labels with no free position are
+/// dropped, so earlier points take priority.
+///
+/// # Parameters
+/// * `data` - Chart whose vertex positions (clip space) are labelled
+/// * `texts` - Label text per point; extra points or texts are ignored
+/// * `options` - Font metrics and limits
+///
+/// # Returns
+/// The labels that could be placed, in point order. Empty if the chart has
+/// more than `options.max_labels` points.
+pub fn layout_labels(data: &ChartData, texts: &[String], options: &LabelOptions) -> Vec<DataLabel> {
+    if data.vertices.len() > options.max_labels {
+        return Vec::new();
+    }
+    // Clip space [-1, 1] -> pixels, flipping y so the origin is top-left
+    let targets: Vec<LabelTarget> = data
+        .vertices
+        .iter()
+        .map(|v| {
+            LabelTarget::Point([
+                (v.position[0] + 1.0) * 0.5 * data.viewport_width,
+                (1.0 - v.position[1]) * 0.5 * data.viewport_height,
+            ])
+        })
+        .collect();
+    place_labels(&targets, texts, options, data.viewport_width, data.viewport_height)
+}
+
+/// Place one label per target in a `viewport_width` x `viewport_height`
+/// pixel viewport, avoiding overlaps between labels
+///
+/// As `layout_labels`, but for targets already in pixels and for bars as
+/// well as points; `DataLabel::index` is the target's index.
+///
+/// # Example
+/// ```
+/// use helion_core::labels::{place_labels, LabelOptions, LabelPlacement, LabelTarget};
+///
+/// let bars = [
+///     LabelTarget::BarEnd { position: [100.0, 50.0], down: false },
+///     LabelTarget::BarEnd { position: [200.0, 150.0], down: true },
+/// ];
+/// let texts = ["12".to_string(), "-3".to_string()];
+/// let labels = place_labels(&bars, &texts, &LabelOptions::default(), 400.0, 300.0);
+/// assert_eq!(labels[0].placement, LabelPlacement::Above);
+/// assert_eq!(labels[1].placement, LabelPlacement::Below);
+/// // Centered on the bar
+/// assert_eq!(labels[0].x + labels[0].width / 2.0, 100.0);
+/// ```
+pub fn place_labels(
+    targets: &[LabelTarget],
+    texts: &[String],
+    options: &LabelOptions,
+    viewport_width: f32,
+    viewport_height: f32,
+) -> Vec<DataLabel> {
+    if targets.len() > options.max_labels {
+        return Vec::new();
+    }
+
+    let mut placed: Vec<DataLabel> = Vec::new();
+    for (index, (target, text)) in targets.iter().zip(texts).enumerate() {
+        if text.is_empty() {
+            continue;
+        }
+
+        let [px, py] = target.position();
+        let width = text.chars().count() as f32 * options.font_size * options.char_width;
+        let height = options.font_size;
+        let gap = options.offset;
+
+        let right = (LabelPlacement::Right, px + gap, py - height / 2.0);
+        let above = (LabelPlacement::Above, px - width / 2.0, py - gap - height);
+        let left = (LabelPlacement::Left, px - gap - width, py - height / 2.0);
+        let below = (LabelPlacement::Below, px - width / 2.0, py + gap);
+        let candidates = match *target {
+            LabelTarget::Point(_) => vec![right, above, left, below],
+            LabelTarget::BarEnd { down: false, .. } => vec![above, below],
+            LabelTarget::BarEnd { down: true, .. } => vec![below, above],
+        };
+
+        let label = candidates.iter().find_map(|&(placement, x, y)| {
+            let label = DataLabel {
+                index,
+                text: text.clone(),
+                x,
+                y,
+                width,
+                height,
+                placement,
+            };
+            let inside = x >= 0.0 && y >= 0.0 && x + width <= viewport_width && y + height <= viewport_height;
+            (inside && !placed.iter().any(|p| p.overlaps(&label))).then_some(label)
+        });
+
+        if let Some(label) = label {
+            placed.push(label);
+        }
+    }
+
+    placed
+}
@@ -0,0 +1,339 @@
+use crate::data::{ChartData, Color, Point2D, ScatterTransform};
+use crate::renderer::ScissorRect;
+
+/// Which subplots share an axis range
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShareAxis {
+    /// Each subplot keeps its own range
+    #[default]
+    None,
+    /// All subplots in the grid share one range
+    All,
+    /// Subplots in the same row share a range
+    Row,
+    /// Subplots in the same column share a range
+    Col,
+}
+
+/// Pixel rectangle of one subplot cell (origin top-left)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CellRect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// Data extent (min, max) of a subplot along x and y
+pub type Extent = ((f32, f32), (f32, f32));
+
+/// Compute the (min, max) extent of a data array
+pub fn data_extent(values: &[f32]) -> (f32, f32) {
+    let min = values.iter().cloned().fold(f32::INFINITY, f32::min);
+    let max = values.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+    (min, max)
+}
+
+/// Grid of subplots with shared and/or inverted axes
+///
+/// Subplots are indexed row-major. Sharing and inversion are resolved into
+/// per-subplot output ranges for `ChartData::from_scatter_with_range`, so each
+/// panel still normalizes its own data but lands on a common scale.
+///
+/// # Example
+/// ```
+/// use helion_core::data::ChartData;
+/// use helion_core::layout::{data_extent, ShareAxis, SubplotGrid};
+///
+/// let (x1, y1) = (vec![0.0, 10.0], vec![0.0, 1.0]);
+/// let (x2, y2) = (vec![0.0, 5.0], vec![0.0, 1.0]);
+///
+/// let grid = SubplotGrid::new(2, 1).with_sharex(ShareAxis::All);
+/// let ranges = grid.output_ranges(&[
+///     (data_extent(&x1), data_extent(&y1)),
+///     (data_extent(&x2), data_extent(&y2)),
+/// ]);
+///
+/// // The second panel's x = 5 lines up with the first panel's x = 5
+/// let bottom = ChartData::from_scatter_with_range(
+///     &x2, &y2, None, None, 800.0, 300.0, Some(ranges[1].0), Some(ranges[1].1),
+/// );
+/// assert_eq!(bottom.vertices[1].position[0], 0.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SubplotGrid {
+    pub rows: usize,
+    pub cols: usize,
+    pub sharex: ShareAxis,
+    pub sharey: ShareAxis,
+    /// Flip the x axis of every subplot (max on the left)
+    pub invert_x: bool,
+    /// Flip the y axis of every subplot (max at the bottom)
+    pub invert_y: bool,
+    /// Gap between cells in pixels
+    pub spacing: f32,
+    /// Relative row heights; empty means equal rows
+    pub height_ratios: Vec<f32>,
+}
+
+impl SubplotGrid {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows: rows.max(1),
+            cols: cols.max(1),
+            sharex: ShareAxis::None,
+            sharey: ShareAxis::None,
+            invert_x: false,
+            invert_y: false,
+            spacing: 8.0,
+            height_ratios: Vec::new(),
+        }
+    }
+
+    pub fn with_sharex(mut self, share: ShareAxis) -> Self {
+        self.sharex = share;
+        self
+    }
+
+    pub fn with_sharey(mut self, share: ShareAxis) -> Self {
+        self.sharey = share;
+        self
+    }
+
+    pub fn invert_xaxis(mut self, invert: bool) -> Self {
+        self.invert_x = invert;
+        self
+    }
+
+    pub fn invert_yaxis(mut self, invert: bool) -> Self {
+        self.invert_y = invert;
+        self
+    }
+
+    /// Give rows unequal heights, e.g. `[3.0, 1.0]` for a main panel over a
+    /// quarter-height indicator panel
+    ///
+    /// Ignored unless there is exactly one positive ratio per row.
+    pub fn with_height_ratios(mut self, ratios: Vec<f32>) -> Self {
+        self.height_ratios = ratios;
+        self
+    }
+
+    /// Number of subplots in the grid
+    pub fn len(&self) -> usize {
+        self.rows * self.cols
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Pixel rectangle of subplot `index` inside a `width` x `height` figure
+    pub fn cell_rect(&self, index: usize, width: f32, height: f32) -> CellRect {
+        let (row, col) = (index / self.cols, index % self.cols);
+        let cell_w = (width - self.spacing * (self.cols - 1) as f32) / self.cols as f32;
+        let available = height - self.spacing * (self.rows - 1) as f32;
+        let fraction = |r: usize| self.row_fraction(r);
+        let above: f32 = (0..row).map(fraction).sum();
+        CellRect {
+            x: col as f32 * (cell_w + self.spacing),
+            y: above * available + row as f32 * self.spacing,
+            width: cell_w.max(0.0),
+            height: (fraction(row) * available).max(0.0),
+        }
+    }
+
+    /// Share of the available height taken by `row`
+    fn row_fraction(&self, row: usize) -> f32 {
+        let valid = self.height_ratios.len() == self.rows && self.height_ratios.iter().all(|&r| r > 0.0);
+        if valid {
+            self.height_ratios[row] / self.height_ratios.iter().sum::<f32>()
+        } else {
+            1.0 / self.rows as f32
+        }
+    }
+
+    /// Resolve sharing and inversion into output ranges per subplot
+    ///
+    /// # Parameters
+    /// * `extents` - Data extent of each subplot, row-major
+    ///
+    /// # Returns
+    /// `(x_range, y_range)` per subplot, to pass to
+    /// `ChartData::from_scatter_with_range`
+    pub fn output_ranges(&self, extents: &[Extent]) -> Vec<((f32, f32), (f32, f32))> {
+        let x_extents: Vec<(f32, f32)> = extents.iter().map(|e| e.0).collect();
+        let y_extents: Vec<(f32, f32)> = extents.iter().map(|e| e.1).collect();
+
+        (0..extents.len())
+            .map(|i| {
+                let x_shared = self.shared_extent(&x_extents, i, self.sharex);
+                let y_shared = self.shared_extent(&y_extents, i, self.sharey);
+                (
+                    output_range(x_extents[i], x_shared, self.invert_x),
+                    output_range(y_extents[i], y_shared, self.invert_y),
+                )
+            })
+            .collect()
+    }
+
+    fn shared_extent(&self, extents: &[(f32, f32)], index: usize, share: ShareAxis) -> (f32, f32) {
+        let (row, col) = (index / self.cols, index % self.cols);
+        extents
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| match share {
+                ShareAxis::None => *j == index,
+                ShareAxis::All => true,
+                ShareAxis::Row => j / self.cols == row,
+                ShareAxis::Col => j % self.cols == col,
+            })
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |acc, (_, e)| {
+                (acc.0.min(e.0), acc.1.max(e.1))
+            })
+    }
+}
+
+/// Output range that places `own` within `shared` mapped onto [-1, 1]
+fn output_range(own: (f32, f32), shared: (f32, f32), invert: bool) -> (f32, f32) {
+    let span = shared.1 - shared.0;
+    let (lo, hi) = if span > 0.0 {
+        (
+            -1.0 + 2.0 * (own.0 - shared.0) / span,
+            -1.0 + 2.0 * (own.1 - shared.0) / span,
+        )
+    } else {
+        (-1.0, 1.0)
+    };
+    if invert {
+        (-lo, -hi)
+    } else {
+        (lo, hi)
+    }
+}
+
+/// Small axes drawn inside a parent panel, e.g. a zoomed detail box
+///
+/// The inset is placed by fractions of the parent's rectangle, so it
+/// follows resizes. Draw it after the parent: `background_data` first, to
+/// hide the parent's points under it, then the inset's data built with
+/// `output_ranges`, both in the `rect` viewport and clipped to `scissor`
+/// so points wider than a pixel don't spill over its edges.
+///
+/// # Example
+/// ```
+/// use helion_core::data::{ChartData, Color, ScatterTransform};
+/// use helion_core::layout::{data_extent, CellRect, InsetAxes};
+///
+/// let x: Vec<f32> = (0..=100).map(|i| i as f32).collect();
+/// let parent = CellRect { x: 0.0, y: 0.0, width: 800.0, height: 600.0 };
+///
+/// // Top-right quarter, zoomed on 40 <= x, y <= 60
+/// let inset = InsetAxes::new([0.7, 0.05, 0.25, 0.25], (40.0, 60.0), (40.0, 60.0));
+/// assert_eq!(inset.rect(parent), CellRect { x: 560.0, y: 30.0, width: 200.0, height: 150.0 });
+///
+/// let (x_range, y_range) = inset.output_ranges((data_extent(&x), data_extent(&x)));
+/// let detail = ChartData::from_scatter_with_range(&x, &x, None, None, 200.0, 150.0, Some(x_range), Some(y_range));
+/// assert_eq!(detail.vertices[50].position, [0.0, 0.0]);
+/// assert!(detail.vertices[70].position[0] > 1.0);
+///
+/// // Outline of the zoomed region on the parent
+/// let transform = ScatterTransform::fit(&x, &x, None, None);
+/// let outline = inset.zoom_outline(&transform, parent, Color::new(0.0, 0.0, 0.0, 1.0), 1.0);
+/// assert_eq!(outline.vertices.len(), 4 * 6);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsetAxes {
+    /// Left, top, width and height as fractions of the parent
+    pub bounds: [f32; 4],
+    /// Data ranges shown
+    pub xlim: (f32, f32),
+    pub ylim: (f32, f32),
+    /// Fill behind the inset's data
+    pub background: Color,
+}
+
+impl InsetAxes {
+    /// Place an inset showing `xlim` by `ylim`, on a white background
+    ///
+    /// # Parameters
+    /// * `bounds` - `[left, top, width, height]` as fractions (0.0-1.0) of
+    ///   the parent, measured from its top-left corner
+    /// * `xlim`, `ylim` - Data ranges the inset shows
+    pub fn new(bounds: [f32; 4], xlim: (f32, f32), ylim: (f32, f32)) -> Self {
+        Self {
+            bounds,
+            xlim,
+            ylim,
+            background: Color::new(1.0, 1.0, 1.0, 1.0),
+        }
+    }
+
+    pub fn with_background(mut self, background: Color) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Pixel rectangle of the inset inside `parent`, for
+    /// `RenderPass::set_viewport`
+    pub fn rect(&self, parent: CellRect) -> CellRect {
+        let [left, top, width, height] = self.bounds;
+        CellRect {
+            x: parent.x + left * parent.width,
+            y: parent.y + top * parent.height,
+            width: (width * parent.width).max(0.0),
+            height: (height * parent.height).max(0.0),
+        }
+    }
+
+    /// `rect` rounded to whole pixels, for `RenderOptions::with_scissor`
+    pub fn scissor(&self, parent: CellRect) -> ScissorRect {
+        let rect = self.rect(parent);
+        let (left, top) = (rect.x.max(0.0).round(), rect.y.max(0.0).round());
+        let (right, bottom) = ((rect.x + rect.width).max(0.0).round(), (rect.y + rect.height).max(0.0).round());
+        ScissorRect::new(left as u32, top as u32, (right - left) as u32, (bottom - top) as u32)
+    }
+
+    /// Output ranges for `ChartData::from_scatter_with_range` that map
+    /// `xlim` and `ylim` onto the inset's viewport
+    ///
+    /// # Parameters
+    /// * `extent` - Data extent of the points drawn in the inset; those
+    ///   outside the limits land outside clip space and aren't drawn
+    pub fn output_ranges(&self, extent: Extent) -> ((f32, f32), (f32, f32)) {
+        (output_range(extent.0, self.xlim, false), output_range(extent.1, self.ylim, false))
+    }
+
+    /// Rectangle filling the inset with `background`, for `MeshRenderer`
+    /// in the inset's viewport
+    pub fn background_data(&self, parent: CellRect) -> ChartData {
+        let rect = self.rect(parent);
+        let mut data = ChartData::new(rect.width, rect.height);
+        data.add_rect(Point2D::new(-1.0, -1.0), Point2D::new(1.0, 1.0), self.background);
+        data
+    }
+
+    /// Outline around the zoomed region on the parent, for `MeshRenderer`
+    /// in the parent's viewport
+    ///
+    /// # Parameters
+    /// * `transform` - How the parent maps data to clip space, e.g.
+    ///   `ScatterTransform::fit` over its data
+    /// * `parent` - The parent's pixel rectangle, to size the lines
+    /// * `thickness` - Line thickness in pixels
+    pub fn zoom_outline(&self, transform: &ScatterTransform, parent: CellRect, color: Color, thickness: f32) -> ChartData {
+        let mut data = ChartData::new(parent.width, parent.height);
+        let a = transform.apply(self.xlim.0, self.ylim.0);
+        let b = transform.apply(self.xlim.1, self.ylim.1);
+        let (x0, x1) = (a.x.min(b.x), a.x.max(b.x));
+        let (y0, y1) = (a.y.min(b.y), a.y.max(b.y));
+        // Clip space spans 2 units across the parent
+        let dx = thickness / parent.width.max(1.0);
+        let dy = thickness / parent.height.max(1.0);
+        data.add_rect(Point2D::new(x0 - dx, y0 - dy), Point2D::new(x1 + dx, y0 + dy), color);
+        data.add_rect(Point2D::new(x0 - dx, y1 - dy), Point2D::new(x1 + dx, y1 + dy), color);
+        data.add_rect(Point2D::new(x0 - dx, y0 + dy), Point2D::new(x0 + dx, y1 - dy), color);
+        data.add_rect(Point2D::new(x1 - dx, y0 + dy), Point2D::new(x1 + dx, y1 - dy), color);
+        data
+    }
+}
@@ -0,0 +1,341 @@
+use crate::data::{ChartData, Color, Point2D};
+use crate::text::{HAlign, TextSection, VAlign};
+
+/// Alpha multiplier for series that aren't hovered while another entry is
+pub const DIMMED_ALPHA: f32 = 0.25;
+
+/// Copy of `data` with every point's alpha scaled by `alpha`, for drawing
+/// a series at its `Legend::series_alpha`
+///
+/// The copy has no `AppendLog`, so renderers upload it in full.
+pub fn faded(data: &ChartData, alpha: f32) -> ChartData {
+    let mut faded = data.clone();
+    for vertex in &mut faded.vertices {
+        vertex.color[3] *= alpha;
+    }
+    faded
+}
+
+/// Corner of the plot the legend is anchored to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LegendLocation {
+    #[default]
+    UpperRight,
+    UpperLeft,
+    LowerLeft,
+    LowerRight,
+}
+
+impl LegendLocation {
+    /// Look a corner up by matplotlib-style name: "upper right",
+    /// "upper left", "lower left" or "lower right"
+    ///
+    /// Words may also be joined by '_' or '-'.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().replace(['_', '-'], " ").as_str() {
+            "upper right" => Some(Self::UpperRight),
+            "upper left" => Some(Self::UpperLeft),
+            "lower left" => Some(Self::LowerLeft),
+            "lower right" => Some(Self::LowerRight),
+            _ => None,
+        }
+    }
+}
+
+/// Legend layout options, in pixels
+#[derive(Debug, Clone)]
+pub struct LegendOptions {
+    pub location: LegendLocation,
+    /// Font size of the labels (entry text height)
+    pub font_size: f32,
+    /// Approximate glyph advance as a fraction of `font_size`
+    pub char_width: f32,
+    /// Side of the square color swatch
+    pub swatch: f32,
+    /// Gap between the legend box and the plot edge
+    pub margin: f32,
+    /// Space inside the legend box around the entries
+    pub padding: f32,
+    /// Vertical gap between entries
+    pub spacing: f32,
+    /// Fill of the legend box
+    pub background: Color,
+    pub text_color: Color,
+}
+
+impl Default for LegendOptions {
+    fn default() -> Self {
+        Self {
+            location: LegendLocation::UpperRight,
+            font_size: 12.0,
+            char_width: 0.6,
+            swatch: 10.0,
+            margin: 10.0,
+            padding: 6.0,
+            spacing: 4.0,
+            background: Color::new(1.0, 1.0, 1.0, 0.85),
+            text_color: Color::new(0.0, 0.0, 0.0, 1.0),
+        }
+    }
+}
+
+/// One series in the legend
+#[derive(Debug, Clone, PartialEq)]
+pub struct LegendEntry {
+    pub label: String,
+    pub color: Color,
+    pub visible: bool,
+}
+
+/// Pixel rectangle of one legend entry (origin top-left, y down), covering
+/// its swatch and label
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LegendItemRect {
+    pub index: usize,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl LegendItemRect {
+    fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x < self.x + self.width && y >= self.y && y < self.y + self.height
+    }
+}
+
+/// Series legend with click-to-hide and hover-to-highlight
+///
+/// Holds the entries and their interaction state. Renderers draw the box
+/// and swatches of `quad_data()` with a `MeshRenderer` and the labels of
+/// `text_sections()` with a `TextRenderer`, and multiply each series' alpha
+/// by `series_alpha()`. Pointer input comes in through `pointer_moved`
+/// and `click` in pixel coordinates, so a native window and a browser canvas
+/// drive it the same way.
+///
+/// # Example
+/// ```
+/// use helion_core::data::Color;
+/// use helion_core::legend::Legend;
+///
+/// let mut legend = Legend::new(400.0, 300.0);
+/// legend.add("train", Color::new(0.0, 0.0, 1.0, 1.0));
+/// legend.add("validation", Color::new(1.0, 0.5, 0.0, 1.0));
+///
+/// // Click on the second entry to hide that series
+/// let item = legend.layout()[1];
+/// assert_eq!(legend.click(item.x + 1.0, item.y + 1.0), Some(1));
+/// assert_eq!(legend.series_alpha(1), 0.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Legend {
+    entries: Vec<LegendEntry>,
+    options: LegendOptions,
+    viewport: [f32; 2],
+    hovered: Option<usize>,
+}
+
+impl Legend {
+    /// Empty legend for a `width` x `height` pixel plot
+    pub fn new(width: f32, height: f32) -> Self {
+        Self {
+            entries: Vec::new(),
+            options: LegendOptions::default(),
+            viewport: [width, height],
+            hovered: None,
+        }
+    }
+
+    pub fn with_options(mut self, options: LegendOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn with_location(mut self, location: LegendLocation) -> Self {
+        self.options.location = location;
+        self
+    }
+
+    /// Add a visible entry; returns its index, which is the series index
+    /// `series_alpha` and `click` refer to
+    pub fn add(&mut self, label: impl Into<String>, color: Color) -> usize {
+        self.entries.push(LegendEntry {
+            label: label.into(),
+            color,
+            visible: true,
+        });
+        self.entries.len() - 1
+    }
+
+    pub fn entries(&self) -> &[LegendEntry] {
+        &self.entries
+    }
+
+    pub fn options(&self) -> &LegendOptions {
+        &self.options
+    }
+
+    /// Keep the legend in its corner after the plot is resized
+    pub fn resize(&mut self, width: f32, height: f32) {
+        self.viewport = [width, height];
+    }
+
+    /// Entry whose series is highlighted, if the pointer is over one
+    pub fn hovered(&self) -> Option<usize> {
+        self.hovered
+    }
+
+    pub fn is_visible(&self, index: usize) -> bool {
+        self.entries.get(index).is_some_and(|e| e.visible)
+    }
+
+    pub fn set_visible(&mut self, index: usize, visible: bool) {
+        if let Some(entry) = self.entries.get_mut(index) {
+            entry.visible = visible;
+        }
+    }
+
+    /// Alpha multiplier for series `index`: 0 when hidden, `DIMMED_ALPHA`
+    /// while a different entry is hovered, 1 otherwise
+    pub fn series_alpha(&self, index: usize) -> f32 {
+        match self.hovered {
+            _ if !self.is_visible(index) => 0.0,
+            Some(hovered) if hovered != index => DIMMED_ALPHA,
+            _ => 1.0,
+        }
+    }
+
+    fn row_height(&self) -> f32 {
+        self.options.font_size.max(self.options.swatch)
+    }
+
+    fn item_width(&self) -> f32 {
+        let o = &self.options;
+        let label_chars = self.entries.iter().map(|e| e.label.chars().count()).max().unwrap_or(0);
+        o.swatch + o.padding + label_chars as f32 * o.font_size * o.char_width
+    }
+
+    /// Pixel rectangle of the legend box around all entries; its `index`
+    /// is the number of entries
+    pub fn bounds(&self) -> LegendItemRect {
+        let o = &self.options;
+        let count = self.entries.len() as f32;
+        let width = self.item_width() + 2.0 * o.padding;
+        let height = count * self.row_height() + (count - 1.0).max(0.0) * o.spacing + 2.0 * o.padding;
+        let x = match o.location {
+            LegendLocation::UpperLeft | LegendLocation::LowerLeft => o.margin,
+            LegendLocation::UpperRight | LegendLocation::LowerRight => self.viewport[0] - o.margin - width,
+        };
+        let y = match o.location {
+            LegendLocation::UpperLeft | LegendLocation::UpperRight => o.margin,
+            LegendLocation::LowerLeft | LegendLocation::LowerRight => self.viewport[1] - o.margin - height,
+        };
+        LegendItemRect {
+            index: self.entries.len(),
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Entry rectangles, stacked top to bottom in the configured corner
+    pub fn layout(&self) -> Vec<LegendItemRect> {
+        let o = &self.options;
+        let bounds = self.bounds();
+        let (row_height, item_width) = (self.row_height(), self.item_width());
+        (0..self.entries.len())
+            .map(|index| LegendItemRect {
+                index,
+                x: bounds.x + o.padding,
+                y: bounds.y + o.padding + index as f32 * (row_height + o.spacing),
+                width: item_width,
+                height: row_height,
+            })
+            .collect()
+    }
+
+    /// Box and color swatches as a triangle list for `MeshRenderer`
+    ///
+    /// Swatches of hidden series are faded to `DIMMED_ALPHA`; an empty
+    /// legend draws nothing.
+    pub fn quad_data(&self) -> ChartData {
+        let [width, height] = self.viewport;
+        let mut data = ChartData::new(width, height);
+        if self.entries.is_empty() {
+            return data;
+        }
+        // Pixels (origin top left, y down) to clip space
+        let mut add_rect = |x: f32, y: f32, w: f32, h: f32, color: Color| {
+            let clip = |px: f32, py: f32| Point2D::new(px / width * 2.0 - 1.0, 1.0 - py / height * 2.0);
+            let (a, b) = (clip(x, y + h), clip(x + w, y));
+            data.add_rect(a, b, color);
+        };
+        let bounds = self.bounds();
+        add_rect(bounds.x, bounds.y, bounds.width, bounds.height, self.options.background);
+
+        let swatch = self.options.swatch;
+        for (item, entry) in self.layout().iter().zip(&self.entries) {
+            let color = self.entry_color(entry.color, entry.visible);
+            add_rect(item.x, item.y + (item.height - swatch) / 2.0, swatch, swatch, color);
+        }
+        data
+    }
+
+    /// Labels placed right of their swatches, for `TextRenderer`
+    ///
+    /// Labels of hidden series are faded like their swatches.
+    pub fn text_sections(&self) -> Vec<TextSection> {
+        let o = &self.options;
+        self.layout()
+            .iter()
+            .zip(&self.entries)
+            .map(|(item, entry)| {
+                let position = [item.x + o.swatch + o.padding, item.y + item.height / 2.0];
+                TextSection::new(entry.label.clone(), position, o.font_size)
+                    .with_color(self.entry_color(o.text_color, entry.visible))
+                    .with_align(HAlign::Left, VAlign::Middle)
+            })
+            .collect()
+    }
+
+    fn entry_color(&self, color: Color, visible: bool) -> Color {
+        match visible {
+            true => color,
+            false => Color { a: color.a * DIMMED_ALPHA, ..color },
+        }
+    }
+
+    /// Entry under pixel (`x`, `y`), if any
+    pub fn hit_test(&self, x: f32, y: f32) -> Option<usize> {
+        self.layout().iter().find(|item| item.contains(x, y)).map(|item| item.index)
+    }
+
+    /// The pointer moved to pixel (`x`, `y`)
+    ///
+    /// # Returns
+    /// Whether the highlighted series changed, i.e. the plot needs a redraw
+    pub fn pointer_moved(&mut self, x: f32, y: f32) -> bool {
+        let hovered = self.hit_test(x, y);
+        let changed = hovered != self.hovered;
+        self.hovered = hovered;
+        changed
+    }
+
+    /// The pointer left the plot; clears the highlight
+    pub fn pointer_left(&mut self) -> bool {
+        self.hovered.take().is_some()
+    }
+
+    /// Click at pixel (`x`, `y`), toggling the visibility of the entry there
+    ///
+    /// # Returns
+    /// The index of the toggled entry, or `None` if the click missed the
+    /// legend and should be handled by the plot (e.g. to start panning)
+    pub fn click(&mut self, x: f32, y: f32) -> Option<usize> {
+        let index = self.hit_test(x, y)?;
+        let entry = &mut self.entries[index];
+        entry.visible = !entry.visible;
+        Some(index)
+    }
+}
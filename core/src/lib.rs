@@ -1,19 +1,129 @@
+pub mod aggregate;
+pub mod axes;
 pub mod backend;
+pub mod brush;
+pub mod calendar;
+pub mod camera;
+pub mod cancel;
+pub mod chunked;
+pub mod cluster;
+pub mod colormap;
+pub mod colorspace;
+pub mod compute;
 pub mod data;
+pub mod error;
+pub mod events;
+pub mod figure;
+pub mod finance;
+pub mod geo;
+pub mod glyph;
+pub mod halo;
+pub mod headless;
+pub mod heatmap;
+pub mod hierarchy;
+pub mod histogram;
+pub mod ingest;
+pub mod labels;
+pub mod layout;
+pub mod legend;
+pub mod line;
+pub mod logging;
+pub mod mesh;
+pub mod named_colors;
+pub mod norm;
+pub mod overview;
+pub mod parallel;
+pub mod picking;
+pub mod polygon;
+pub mod pool;
+pub mod readback;
 pub mod renderer;
+pub mod ring;
+pub mod sankey;
+pub mod scale;
 pub mod scatter;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod serve;
 pub mod shaders;
+pub mod spec;
+pub mod stats;
+pub mod style;
+pub mod sunburst;
+pub mod sweep;
+pub mod text;
+pub mod timeseries;
+pub mod tooltip;
+pub mod trajectory;
+pub mod transition;
+pub mod treemap;
+pub mod waveform;
+#[cfg(target_arch = "wasm32")]
+pub mod web;
 
-#[cfg(feature = "python")]
+#[cfg(feature = "window")]
 pub mod window;
+pub mod windrose;
 
-pub use backend::{GPUBackend, BackendType};
-pub use data::{Point2D, Color, ChartData};
-pub use renderer::{Renderer, RenderOptions};
-pub use scatter::ScatterRenderer;
+pub use aggregate::{BinGrid, Rebinner};
+pub use axes::{AxesOptions, AxesRenderer, GridOptions, GridStyle, LabelRotation, LineDash, TickLabelOptions};
+pub use backend::{BackendType, Capabilities, FeaturePath, GPUBackend};
+pub use brush::{BrushLink, DataSourceId, Selection};
+pub use calendar::CalendarHeatmap;
+pub use camera::{Camera2D, CameraBinding, PanZoom};
+pub use cancel::CancelToken;
+pub use cluster::{Cluster, ClusterMode, ClusterOptions};
+pub use colormap::{register_colormap, register_colormap_in, ColormapLut, ColormapRenderer};
+pub use colorspace::ColorSpace;
+pub use compute::{Aggregator, GpuHistogram};
+pub use data::{AlphaTransfer, AppendLog, Point2D, Color, ChartData, Colormap, MissingDataPolicy, PositionVertex, ScatterTransform, VertexLayout};
+pub use error::HelionError;
+pub use events::{EventBus, FigureEvent, FigureEventKind};
+pub use figure::{ChartKind, Figure, FigureText, Series};
+pub use finance::{CandleKind, CandleStyle, FinanceFigure, Ohlc};
+pub use geo::{read_geojson, Choropleth};
+pub use glyph::{GlyphRenderer, GlyphShape};
+pub use halo::{HaloOptions, HaloRenderer};
+pub use headless::{encode_png, HeadlessRenderer};
+pub use heatmap::{AggregatedPoints, Heatmap, HeatmapRenderer};
+pub use hierarchy::{Hierarchy, HierarchyNode};
+pub use histogram::{BinStrategy, Histogram, HistogramNorm, HistogramOptions, HistogramRenderer};
+pub use ingest::{read_npy, DataSource, NpyArray, NpzArchive};
+#[cfg(not(target_arch = "wasm32"))]
+pub use ingest::MappedArray;
+pub use labels::{DataLabel, LabelOptions, LabelTarget};
+pub use layout::{InsetAxes, ShareAxis, SubplotGrid};
+pub use legend::{Legend, LegendLocation, LegendOptions};
+pub use line::LineRenderer;
+pub use mesh::MeshRenderer;
+pub use norm::{Normalization, Normalizer};
+pub use overview::{BrushHandle, OverviewDetail};
+pub use polygon::{Polygon, PolygonRenderer};
+pub use pool::ChartDataPool;
+pub use readback::RenderTarget;
+pub use renderer::{BlendMode, Renderer, RenderAttachments, RenderOptions, ScissorRect};
+pub use sankey::{Sankey, SankeyLink};
+pub use scale::{Axis, AxisBreak, AxisScale, BrokenAxis};
+pub use scatter::{ScatterRenderer, UniformScatterRenderer};
+#[cfg(not(target_arch = "wasm32"))]
+pub use serve::{FrameServer, FrameSession, ViewInput};
+pub use spec::FigureSpec;
+pub use stats::{DrawStats, FrameRecorder, FrameReport};
+pub use style::{Marker, SeriesStyle};
+pub use sunburst::Sunburst;
+pub use sweep::{Sweep, SweepRenderer};
+pub use text::{GlyphAtlas, HAlign, TextRenderer, TextSection, VAlign};
+pub use timeseries::GapThreshold;
+pub use tooltip::Tooltip;
+pub use trajectory::{Playback, Trail, TrajectoryRenderer};
+pub use transition::{Easing, FrameClock, Transition, ViewRange};
+pub use treemap::Treemap;
+pub use waveform::Waveform;
+#[cfg(target_arch = "wasm32")]
+pub use web::HelionChart;
 
-#[cfg(feature = "python")]
-pub use window::{RenderWindow, run_window};
+#[cfg(feature = "window")]
+pub use window::{RenderWindow, run_figure, run_window, run_window_with_kind, run_window_with_events, run_window_with_options, run_window_with_source, try_run_figure, try_run_window, try_run_window_with_events, try_run_windows, WindowQueue, WindowSpec};
+pub use windrose::Windrose;
 
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
@@ -22,7 +132,7 @@ use wasm_bindgen::prelude::*;
 #[wasm_bindgen(start)]
 pub fn init() {
     console_error_panic_hook::set_once();
-    console_log::init_with_level(log::Level::Info).expect("Failed to initialize logger");
+    logging::init();
     log::info!("Helion initialized");
 }
 
@@ -0,0 +1,297 @@
+use crate::backend::GPUBackend;
+use crate::camera::{Camera2D, CameraBinding};
+use crate::chunked::ChunkedBuffer;
+use crate::data::{ChartData, Vertex};
+use crate::error::HelionError;
+use crate::renderer::{RenderAttachments, RenderOptions, Renderer, WebRenderer, WindowRenderer};
+use crate::shaders::{LINE_VERTEX_SHADER, SIMPLE_FRAGMENT_SHADER};
+use crate::stats::DrawStats;
+use crate::timeseries::segment_runs;
+use std::ops::Range;
+
+/// Line chart renderer - connects consecutive vertices with a `LineStrip`
+///
+/// Vertices are drawn in the order they appear in `ChartData`, so sort by x
+/// first for a time series. Lines are one pixel wide; per-vertex colors are
+/// interpolated along each segment and `size` is ignored. Build the data with
+/// `ChartData::from_line`. `set_camera` pans and zooms without re-uploading.
+///
+/// A vertex with a NaN position breaks the line: the runs on either side are
+/// drawn separately instead of being joined (see `Series::gaps`).
+///
+/// Like `ScatterRenderer` it works in both window and web contexts. Very long
+/// series are split over several buffers that share their end vertices, so
+/// the line stays continuous.
+pub struct LineRenderer {
+    render_pipeline: wgpu::RenderPipeline,
+    camera: CameraBinding,
+    vertex_buffer: Option<ChunkedBuffer>,
+    /// Runs of vertices between breaks, each drawn as its own strip; `None`
+    /// if the line is unbroken
+    runs: Option<Vec<Range<u64>>>,
+    /// Options the pipeline was built with
+    options: RenderOptions,
+    /// MSAA/depth textures for the web path, which owns its render pass
+    attachments: Option<RenderAttachments>,
+    /// Bytes uploaded since the last `frame_stats`
+    uploaded_bytes: u64,
+}
+
+impl LineRenderer {
+    pub(crate) fn create_pipeline(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        camera_layout: &wgpu::BindGroupLayout,
+        options: &RenderOptions,
+    ) -> wgpu::RenderPipeline {
+        let vertex_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Line Vertex Shader"),
+            source: wgpu::ShaderSource::Wgsl(LINE_VERTEX_SHADER.into()),
+        });
+
+        let fragment_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Line Fragment Shader"),
+            source: wgpu::ShaderSource::Wgsl(SIMPLE_FRAGMENT_SHADER.into()),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Line Pipeline Layout"),
+            bind_group_layouts: &[camera_layout],
+            push_constant_ranges: &[],
+        });
+
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Line Render Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &vertex_shader,
+                entry_point: "vs_main",
+                buffers: &[Vertex::desc()],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: options.blend_state(),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::LineStrip,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: options.depth_stencil_state(),
+            multisample: options.multisample_state(),
+            multiview: None,
+            cache: None,
+        })
+    }
+
+    fn create_vertex_buffer(device: &wgpu::Device, vertices: &[Vertex]) -> Result<Option<ChunkedBuffer>, HelionError> {
+        if vertices.is_empty() {
+            return Ok(None);
+        }
+        ChunkedBuffer::new_strip(device, "Line Vertex Buffer", vertices, wgpu::BufferUsages::VERTEX).map(Some)
+    }
+
+    fn create_vertex_buffer_or_log(device: &wgpu::Device, vertices: &[Vertex]) -> Option<ChunkedBuffer> {
+        Self::create_vertex_buffer(device, vertices).unwrap_or_else(|e| {
+            log::error!("{}", e);
+            None
+        })
+    }
+
+    /// Runs between vertices with a NaN position, or `None` if there are none
+    fn line_runs(vertices: &[Vertex]) -> Option<Vec<Range<u64>>> {
+        if !vertices.iter().any(|v| v.position[0].is_nan() || v.position[1].is_nan()) {
+            return None;
+        }
+        let (x, y): (Vec<f32>, Vec<f32>) = vertices.iter().map(|v| (v.position[0], v.position[1])).unzip();
+        let runs = segment_runs(&x, &y, None)
+            .into_iter()
+            .filter(|run| run.len() > 1)
+            .map(|run| run.start as u64..run.end as u64)
+            .collect();
+        Some(runs)
+    }
+
+    /// Write `vertices` into the vertex buffer, reallocating only when they
+    /// don't fit
+    fn upload_vertices(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, vertices: &[Vertex]) -> Result<(), HelionError> {
+        self.runs = Self::line_runs(vertices);
+        self.uploaded_bytes += ChunkedBuffer::update_strip(
+            &mut self.vertex_buffer,
+            device,
+            queue,
+            "Line Vertex Buffer",
+            vertices,
+            wgpu::BufferUsages::VERTEX,
+        )?;
+        Ok(())
+    }
+
+    /// Options the render pipeline was built with
+    pub fn options(&self) -> &RenderOptions {
+        &self.options
+    }
+
+    /// Pan or zoom by uploading a new view matrix; vertices are untouched
+    pub fn set_camera(&mut self, queue: &wgpu::Queue, camera: &Camera2D) {
+        self.uploaded_bytes += self.camera.set(queue, camera);
+    }
+
+    /// Whether switching from `built` to `requested` needs a new pipeline
+    fn needs_rebuild(built: &RenderOptions, requested: &RenderOptions) -> bool {
+        built.effective_sample_count() != requested.effective_sample_count()
+            || built.blend != requested.blend
+            || built.depth_test != requested.depth_test
+    }
+}
+
+impl Renderer for LineRenderer {
+    fn render_to_pass<'rpass>(&'rpass mut self, render_pass: &mut wgpu::RenderPass<'rpass>) {
+        render_pass.set_pipeline(&self.render_pipeline);
+        render_pass.set_bind_group(0, self.camera.bind_group(), &[]);
+
+        match (&self.vertex_buffer, &self.runs) {
+            (Some(buffer), Some(runs)) => buffer.draw_ranges(render_pass, 0, runs),
+            (Some(buffer), None) => buffer.draw(render_pass, 0),
+            (None, _) => {}
+        }
+    }
+
+    fn frame_stats(&mut self) -> DrawStats {
+        let stats = match (&self.vertex_buffer, &self.runs) {
+            (Some(buffer), Some(runs)) => buffer.range_stats(runs),
+            (Some(buffer), None) => buffer.draw_stats(),
+            (None, _) => DrawStats::default(),
+        };
+        stats.with_bytes_uploaded(std::mem::take(&mut self.uploaded_bytes))
+    }
+}
+
+impl WindowRenderer for LineRenderer {
+    fn with_options(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        chart_data: ChartData,
+        options: &RenderOptions,
+    ) -> Self {
+        let camera = CameraBinding::new(device);
+        let render_pipeline = Self::create_pipeline(device, config.format, camera.layout(), options);
+        let vertex_buffer = Self::create_vertex_buffer_or_log(device, &chart_data.vertices);
+
+        LineRenderer {
+            render_pipeline,
+            camera,
+            uploaded_bytes: vertex_buffer.as_ref().map_or(0, ChunkedBuffer::byte_size),
+            vertex_buffer,
+            runs: Self::line_runs(&chart_data.vertices),
+            options: options.clone(),
+            attachments: None,
+        }
+    }
+
+    fn update_data(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, chart_data: &ChartData) {
+        if let Err(e) = self.upload_vertices(device, queue, &chart_data.vertices) {
+            log::error!("{}", e);
+            self.vertex_buffer = None;
+        }
+    }
+
+    fn set_camera(&mut self, queue: &wgpu::Queue, camera: &Camera2D) {
+        LineRenderer::set_camera(self, queue, camera);
+    }
+}
+
+impl WebRenderer for LineRenderer {
+    fn new(backend: &GPUBackend) -> Result<Self, HelionError> {
+        let device = backend.device()?;
+        let config = backend.config.as_ref().ok_or(HelionError::NotInitialized("surface configuration"))?;
+
+        let options = RenderOptions::default();
+        let camera = CameraBinding::new(device);
+        let render_pipeline = Self::create_pipeline(device, config.format, camera.layout(), &options);
+
+        Ok(LineRenderer {
+            render_pipeline,
+            camera,
+            vertex_buffer: None,
+            runs: None,
+            options,
+            attachments: None,
+            uploaded_bytes: 0,
+        })
+    }
+
+    fn render_with_backend(
+        &mut self,
+        backend: &GPUBackend,
+        data: &ChartData,
+        options: &RenderOptions,
+    ) -> Result<(), HelionError> {
+        options.validate()?;
+
+        <Self as WebRenderer>::update_data(self, backend, data)?;
+
+        let device = backend.device()?;
+        let queue = backend.queue()?;
+        let (surface, config) = backend.surface()?;
+
+        if Self::needs_rebuild(&self.options, options) {
+            self.render_pipeline = Self::create_pipeline(device, config.format, self.camera.layout(), options);
+        }
+        self.options = options.clone();
+
+        let attachments_stale = self
+            .attachments
+            .as_ref()
+            .is_none_or(|a| !a.matches(config.width, config.height, config.format, options));
+        if attachments_stale {
+            self.attachments = Some(RenderAttachments::new(
+                device,
+                config.width,
+                config.height,
+                config.format,
+                options,
+            ));
+        }
+
+        let frame = surface.get_current_texture()?;
+        let view = frame.texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Line Render Encoder"),
+        });
+
+        if let Some(attachments) = self.attachments.take() {
+            {
+                let mut render_pass = attachments.begin_pass(&mut encoder, &view, options);
+                self.render_to_pass(&mut render_pass);
+                attachments.end_pass(&mut render_pass);
+            }
+            self.attachments = Some(attachments);
+        }
+
+        queue.submit(std::iter::once(encoder.finish()));
+        frame.present();
+
+        Ok(())
+    }
+
+    fn update_data(&mut self, backend: &GPUBackend, data: &ChartData) -> Result<(), HelionError> {
+        if data.vertices.is_empty() {
+            return Ok(());
+        }
+
+        self.upload_vertices(backend.device()?, backend.queue()?, &data.vertices)
+    }
+}
@@ -0,0 +1,79 @@
+//! Logger setup shared by the native window and the WASM module
+//!
+//! The logger is installed at most once per process; after that only the
+//! level changes. Installing again would fail (the `log` crate accepts a
+//! single logger), so every entry point goes through `init` instead of
+//! calling `env_logger::init` or `console_log::init_with_level` directly.
+
+use std::sync::Once;
+
+/// Level used until `set_log_level` is called: warnings and errors only,
+/// so embedding pages and notebooks aren't flooded with per-frame messages
+pub const DEFAULT_LOG_LEVEL: log::LevelFilter = log::LevelFilter::Warn;
+
+static INSTALL: Once = Once::new();
+
+/// Parse a level name: "off", "error", "warn" (or "warning"), "info",
+/// "debug" or "trace", ignoring case
+///
+/// # Example
+/// ```
+/// use helion_core::logging::parse_log_level;
+///
+/// assert_eq!(parse_log_level("WARN"), Ok(log::LevelFilter::Warn));
+/// assert!(parse_log_level("loud").is_err());
+/// ```
+pub fn parse_log_level(name: &str) -> Result<log::LevelFilter, String> {
+    match name.trim().to_ascii_lowercase().as_str() {
+        "off" | "none" => Ok(log::LevelFilter::Off),
+        "error" => Ok(log::LevelFilter::Error),
+        "warn" | "warning" => Ok(log::LevelFilter::Warn),
+        "info" => Ok(log::LevelFilter::Info),
+        "debug" => Ok(log::LevelFilter::Debug),
+        "trace" => Ok(log::LevelFilter::Trace),
+        _ => Err(format!(
+            "Unknown log level '{}': expected off, error, warn, info, debug or trace",
+            name
+        )),
+    }
+}
+
+/// Install Helion's logger if no logger is installed yet
+///
+/// Safe to call any number of times. On the web, messages go to the browser
+/// console at `DEFAULT_LOG_LEVEL`; natively, `env_logger` reads `RUST_LOG`
+/// as usual. If the host application installed its own logger first, that
+/// one is kept.
+pub fn init() {
+    INSTALL.call_once(|| {
+        #[cfg(target_arch = "wasm32")]
+        {
+            // Install at the most verbose level and filter with
+            // `log::set_max_level`, so `set_log_level` can raise it later
+            if console_log::init_with_level(log::Level::Trace).is_ok() {
+                log::set_max_level(DEFAULT_LOG_LEVEL);
+            }
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = env_logger::try_init();
+        }
+    });
+}
+
+/// Change how much Helion logs, installing the logger first if needed
+///
+/// Natively the level can't go above what `RUST_LOG` allows.
+pub fn set_log_level(level: &str) -> Result<(), String> {
+    let level = parse_log_level(level)?;
+    init();
+    log::set_max_level(level);
+    Ok(())
+}
+
+/// `helion.setLogLevel("warn")` from JavaScript
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen::prelude::wasm_bindgen(js_name = setLogLevel)]
+pub fn set_log_level_js(level: &str) -> Result<(), wasm_bindgen::JsValue> {
+    set_log_level(level).map_err(|e| wasm_bindgen::JsValue::from_str(&e))
+}
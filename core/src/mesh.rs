@@ -0,0 +1,125 @@
+use crate::chunked::ChunkedBuffer;
+use crate::data::{ChartData, Vertex};
+use crate::renderer::{RenderOptions, Renderer, WindowRenderer};
+use crate::shaders::{SIMPLE_FRAGMENT_SHADER, SIMPLE_VERTEX_SHADER};
+use crate::stats::DrawStats;
+
+/// Vertices per triangle; chunks must not split one
+const TRIANGLE_VERTICES: u32 = 3;
+
+/// Solid-color triangle renderer for filled shapes
+///
+/// Draws `ChartData::vertices` as a `TriangleList` (every three vertices form
+/// one triangle) using the same simple shaders as the scatter renderer. Used
+/// for bars, candle bodies and any other geometry built with
+/// `ChartData::add_rect`.
+pub struct MeshRenderer {
+    render_pipeline: wgpu::RenderPipeline,
+    vertex_buffer: Option<ChunkedBuffer>,
+    uploaded_bytes: u64,
+}
+
+impl MeshRenderer {
+    fn create_vertex_buffer(device: &wgpu::Device, vertices: &[Vertex]) -> Option<ChunkedBuffer> {
+        ChunkedBuffer::upload_or_log(
+            device,
+            "Mesh Vertex Buffer",
+            vertices,
+            wgpu::BufferUsages::VERTEX,
+            TRIANGLE_VERTICES,
+        )
+    }
+}
+
+impl Renderer for MeshRenderer {
+    fn render_to_pass<'rpass>(&'rpass mut self, render_pass: &mut wgpu::RenderPass<'rpass>) {
+        render_pass.set_pipeline(&self.render_pipeline);
+
+        if let Some(ref buffer) = self.vertex_buffer {
+            buffer.draw(render_pass, 0);
+        }
+    }
+
+    fn frame_stats(&mut self) -> DrawStats {
+        let stats = self.vertex_buffer.as_ref().map_or_else(DrawStats::default, ChunkedBuffer::draw_stats);
+        stats.with_bytes_uploaded(std::mem::take(&mut self.uploaded_bytes))
+    }
+}
+
+impl WindowRenderer for MeshRenderer {
+    fn with_options(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        chart_data: ChartData,
+        options: &RenderOptions,
+    ) -> Self {
+        let vertex_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Mesh Vertex Shader"),
+            source: wgpu::ShaderSource::Wgsl(SIMPLE_VERTEX_SHADER.into()),
+        });
+
+        let fragment_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Mesh Fragment Shader"),
+            source: wgpu::ShaderSource::Wgsl(SIMPLE_FRAGMENT_SHADER.into()),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Mesh Pipeline Layout"),
+            bind_group_layouts: &[],
+            push_constant_ranges: &[],
+        });
+
+        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Mesh Render Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &vertex_shader,
+                entry_point: "vs_main",
+                buffers: &[Vertex::desc()],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: config.format,
+                    blend: options.blend_state(),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: options.depth_stencil_state(),
+            multisample: options.multisample_state(),
+            multiview: None,
+            cache: None,
+        });
+
+        let vertex_buffer = Self::create_vertex_buffer(device, &chart_data.vertices);
+        MeshRenderer {
+            render_pipeline,
+            uploaded_bytes: vertex_buffer.as_ref().map_or(0, ChunkedBuffer::byte_size),
+            vertex_buffer,
+        }
+    }
+
+    fn update_data(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, chart_data: &ChartData) {
+        self.uploaded_bytes += ChunkedBuffer::update_or_log(
+            &mut self.vertex_buffer,
+            device,
+            queue,
+            "Mesh Vertex Buffer",
+            &chart_data.vertices,
+            wgpu::BufferUsages::VERTEX,
+            TRIANGLE_VERTICES,
+        );
+    }
+}
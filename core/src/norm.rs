@@ -0,0 +1,265 @@
+/// Most quantiles kept for histogram equalization; larger inputs are
+/// summarized, which keeps `Normalizer` small and lookups cheap
+pub const EQ_HIST_QUANTILES: usize = 4096;
+
+/// How values are mapped onto [0, 1] before a colormap lookup
+///
+/// Shared by everything that colors aggregated or scalar data (heatmaps,
+/// hexbins, datashaded scatter, colormapped points), so one choice gives the
+/// same result everywhere.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Normalization {
+    /// Proportional to the value
+    #[default]
+    Linear,
+    /// Proportional to log10 of the value. Values <= 0 have no logarithm and
+    /// are left uncolored.
+    Log,
+    /// Linear position raised to `gamma`; below 1 brightens low values
+    Power { gamma: f32 },
+    /// Histogram equalization: each color covers about the same number of
+    /// values, which reveals structure in heavily skewed data such as point
+    /// densities
+    EqHist,
+}
+
+impl Normalization {
+    /// Normalization called `name`: "linear", "log", "sqrt" or "cbrt"
+    /// (powers of 1/2 and 1/3) or "eq_hist", ignoring case
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "linear" => Some(Self::Linear),
+            "log" => Some(Self::Log),
+            "sqrt" => Some(Self::Power { gamma: 0.5 }),
+            "cbrt" => Some(Self::Power { gamma: 1.0 / 3.0 }),
+            "eq_hist" | "eqhist" => Some(Self::EqHist),
+            _ => None,
+        }
+    }
+
+    /// Fit the normalization to `values`
+    ///
+    /// # Parameters
+    /// * `values` - Data that will be colored; NaN and infinities are ignored
+    /// * `range` - Explicit (vmin, vmax), or `None` for the data's extent
+    ///
+    /// # Returns
+    /// A `Normalizer` mapping any value onto [0, 1]
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::norm::Normalization;
+    ///
+    /// let log = Normalization::Log.fit(&[1.0, 10.0, 100.0], None);
+    /// assert_eq!(log.normalize(10.0), 0.5);
+    ///
+    /// // Equalization spreads clustered values evenly
+    /// let eq = Normalization::EqHist.fit(&[1.0, 2.0, 3.0, 1000.0], None);
+    /// assert!((eq.normalize(3.0) - 2.0 / 3.0).abs() < 1e-6);
+    /// ```
+    pub fn fit(&self, values: &[f32], range: Option<(f32, f32)>) -> Normalizer {
+        let (vmin, vmax) = range.unwrap_or_else(|| self.extent(values));
+        let (vmin, vmax) = if vmin <= vmax { (vmin, vmax) } else { (0.0, 1.0) };
+
+        let quantiles = if *self == Normalization::EqHist {
+            let mut sorted: Vec<f32> = values
+                .iter()
+                .copied()
+                .filter(|v| v.is_finite() && *v >= vmin && *v <= vmax)
+                .collect();
+            sorted.sort_by(f32::total_cmp);
+            summarize(&sorted)
+        } else {
+            Vec::new()
+        };
+
+        Normalizer {
+            kind: *self,
+            vmin,
+            vmax,
+            center: None,
+            quantiles,
+        }
+    }
+
+    /// `fit` with either end of the range fixed, the other taken from the
+    /// data, as for the `vmin`/`vmax` arguments of plotting functions
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::norm::Normalization;
+    ///
+    /// let norm = Normalization::Linear.fit_range(&[0.0, 5.0, 10.0], None, Some(20.0));
+    /// assert_eq!((norm.vmin(), norm.vmax()), (0.0, 20.0));
+    /// ```
+    pub fn fit_range(&self, values: &[f32], vmin: Option<f32>, vmax: Option<f32>) -> Normalizer {
+        let range = match (vmin, vmax) {
+            (None, None) => None,
+            (Some(lo), Some(hi)) => Some((lo, hi)),
+            _ => {
+                let (lo, hi) = self.extent(values);
+                Some((vmin.unwrap_or(lo), vmax.unwrap_or(hi)))
+            }
+        };
+        self.fit(values, range)
+    }
+
+    /// Smallest and largest value this normalization can place; empty
+    /// (min > max) if there are none
+    fn extent(&self, values: &[f32]) -> (f32, f32) {
+        values
+            .iter()
+            .filter(|v| v.is_finite() && (*self != Normalization::Log || **v > 0.0))
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &v| (lo.min(v), hi.max(v)))
+    }
+}
+
+/// Evenly spaced order statistics of `sorted`, at most `EQ_HIST_QUANTILES`
+fn summarize(sorted: &[f32]) -> Vec<f32> {
+    if sorted.len() <= EQ_HIST_QUANTILES {
+        return sorted.to_vec();
+    }
+    let last = sorted.len() - 1;
+    (0..EQ_HIST_QUANTILES)
+        .map(|i| sorted[i * last / (EQ_HIST_QUANTILES - 1)])
+        .collect()
+}
+
+/// A `Normalization` fitted to a range (and, for `EqHist`, a distribution)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Normalizer {
+    kind: Normalization,
+    vmin: f32,
+    vmax: f32,
+    center: Option<f32>,
+    quantiles: Vec<f32>,
+}
+
+impl Normalizer {
+    pub fn kind(&self) -> Normalization {
+        self.kind
+    }
+
+    /// Map `center` to the middle of the colormap, for diverging colormaps
+    /// such as `ColormapLut::coolwarm`
+    ///
+    /// Each side of the center is stretched over its own half of the
+    /// colormap, so with a range of (-2, 10) and a center of 0, -2 is the
+    /// deepest blue and 10 the deepest red. A center outside the range widens
+    /// the range to include it. With `Log` the center must be positive.
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::norm::Normalization;
+    ///
+    /// let norm = Normalization::Linear.fit(&[-2.0, 10.0], None).with_center(0.0);
+    /// assert_eq!(norm.normalize(-2.0), 0.0);
+    /// assert_eq!(norm.normalize(0.0), 0.5);
+    /// assert_eq!(norm.normalize(5.0), 0.75);
+    /// ```
+    pub fn with_center(mut self, center: f32) -> Self {
+        if center.is_finite() {
+            self.vmin = self.vmin.min(center);
+            self.vmax = self.vmax.max(center);
+            self.center = Some(center);
+        }
+        self
+    }
+
+    /// Value mapped to the middle of the colormap, if centered
+    pub fn center(&self) -> Option<f32> {
+        self.center
+    }
+
+    /// Value mapped to the bottom of the colormap
+    pub fn vmin(&self) -> f32 {
+        self.vmin
+    }
+
+    /// Value mapped to the top of the colormap
+    pub fn vmax(&self) -> f32 {
+        self.vmax
+    }
+
+    /// Position of `value` on the colormap, in [0, 1]
+    ///
+    /// Values outside the range are clamped. NaN is returned for NaN and,
+    /// with `Log`, for values <= 0; renderers leave those uncolored. A
+    /// zero-width range maps to 0.5, like `ColormapLut::sample`.
+    pub fn normalize(&self, value: f32) -> f32 {
+        let t = self.uncentered(value);
+        let Some(center) = self.center else {
+            return t;
+        };
+        let tc = self.uncentered(center);
+        if t.is_nan() || tc.is_nan() {
+            f32::NAN
+        } else if t < tc {
+            0.5 * t / tc
+        } else if tc < 1.0 {
+            0.5 + 0.5 * (t - tc) / (1.0 - tc)
+        } else {
+            0.5
+        }
+    }
+
+    fn uncentered(&self, value: f32) -> f32 {
+        if value.is_nan() || (self.kind == Normalization::Log && value <= 0.0) {
+            return f32::NAN;
+        }
+        let value = value.clamp(self.vmin, self.vmax);
+
+        match self.kind {
+            Normalization::Linear => linear(value, self.vmin, self.vmax),
+            Normalization::Power { gamma } => linear(value, self.vmin, self.vmax).powf(gamma.max(0.0)),
+            Normalization::Log => {
+                if self.vmin <= 0.0 {
+                    return f32::NAN;
+                }
+                linear(value.log10(), self.vmin.log10(), self.vmax.log10())
+            }
+            Normalization::EqHist => self.equalize(value),
+        }
+    }
+
+    /// Normalize every value
+    pub fn apply(&self, values: &[f32]) -> Vec<f32> {
+        values.iter().map(|&v| self.normalize(v)).collect()
+    }
+
+    /// Rank of `value` among the fitted values, interpolated between
+    /// neighbours and rescaled so the smallest fitted value maps to 0 and the
+    /// largest to 1
+    fn equalize(&self, value: f32) -> f32 {
+        let q = &self.quantiles;
+        let Some(&lowest) = q.first() else {
+            return linear(value, self.vmin, self.vmax);
+        };
+        // Last index holding the lowest value; ties share the top of their run
+        let first = q.partition_point(|&v| v <= lowest) - 1;
+        let last = q.len() - 1;
+        if first == last {
+            return 0.5;
+        }
+
+        let below = q.partition_point(|&v| v <= value);
+        let rank = match below {
+            0 => 0.0,
+            i if i > last => last as f32,
+            i => {
+                let (lo, hi) = (q[i - 1], q[i]);
+                (i - 1) as f32 + (value - lo) / (hi - lo)
+            }
+        };
+        ((rank - first as f32) / (last - first) as f32).clamp(0.0, 1.0)
+    }
+}
+
+fn linear(value: f32, vmin: f32, vmax: f32) -> f32 {
+    let span = vmax - vmin;
+    if span != 0.0 {
+        ((value - vmin) / span).clamp(0.0, 1.0)
+    } else {
+        0.5
+    }
+}
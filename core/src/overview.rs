@@ -0,0 +1,215 @@
+use crate::data::{ChartData, Color, Point2D};
+use crate::layout::{data_extent, CellRect, SubplotGrid};
+use crate::timeseries::decimate_minmax;
+
+/// Part of the zoom window under the cursor in the overview strip
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrushHandle {
+    /// Left edge: dragging resizes the window's start
+    Left,
+    /// Right edge: dragging resizes the window's end
+    Right,
+    /// Inside the window: dragging pans it
+    Body,
+}
+
+/// In-progress drag on the overview strip
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    handle: BrushHandle,
+    /// Cursor position (data units) when the drag started
+    anchor: f32,
+    /// Window when the drag started
+    window: (f32, f32),
+}
+
+/// Detail panel above a downsampled overview strip with a zoom window
+///
+/// The overview always shows the full series (min/max decimated so it stays
+/// cheap for long series); the detail panel shows the part of the series
+/// inside `window`. Pointer events on the overview move or resize the
+/// window, and `detail_range()` gives the x range to render the detail panel
+/// with.
+///
+/// Cursor positions are in pixels relative to the overview panel's left edge.
+///
+/// # Example
+/// ```
+/// use helion_core::overview::OverviewDetail;
+///
+/// let x: Vec<f32> = (0..=100).map(|i| i as f32).collect();
+/// let mut view = OverviewDetail::new(&x, 200.0);
+/// view.set_window(0.0, 20.0);
+///
+/// // Drag the window body 100 px (half the strip) to the right
+/// view.pointer_down(20.0);
+/// view.pointer_move(120.0);
+/// view.pointer_up();
+/// assert_eq!(view.detail_range(), (50.0, 70.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct OverviewDetail {
+    /// Detail panel (subplot 0) above the overview strip (subplot 1)
+    pub grid: SubplotGrid,
+    /// Full x extent of the series
+    extent: (f32, f32),
+    /// Visible x range of the detail panel (data units)
+    window: (f32, f32),
+    /// Overview strip width in pixels
+    strip_width: f32,
+    /// Edge grab tolerance in pixels
+    pub handle_tolerance: f32,
+    /// Narrowest allowed window as a fraction of the full extent
+    pub min_window: f32,
+    drag: Option<Drag>,
+}
+
+impl OverviewDetail {
+    /// Create a layout for a series with x values `x`, initially showing all of it
+    ///
+    /// # Parameters
+    /// * `x` - The series' x values (only the extent is kept)
+    /// * `strip_width` - Overview panel width in pixels
+    pub fn new(x: &[f32], strip_width: f32) -> Self {
+        let extent = data_extent(x);
+        let extent = if extent.0.is_finite() && extent.1 > extent.0 {
+            extent
+        } else {
+            (0.0, 1.0)
+        };
+
+        Self {
+            grid: SubplotGrid::new(2, 1).with_height_ratios(vec![4.0, 1.0]),
+            extent,
+            window: extent,
+            strip_width: strip_width.max(1.0),
+            handle_tolerance: 4.0,
+            min_window: 0.001,
+            drag: None,
+        }
+    }
+
+    /// Full x extent of the series
+    pub fn extent(&self) -> (f32, f32) {
+        self.extent
+    }
+
+    /// X range the detail panel should show
+    pub fn detail_range(&self) -> (f32, f32) {
+        self.window
+    }
+
+    /// Set the zoom window, clamped to the series extent
+    pub fn set_window(&mut self, start: f32, end: f32) {
+        let (start, end) = (start.min(end), start.max(end));
+        let span = self.extent.1 - self.extent.0;
+        let width = (end - start).clamp(span * self.min_window, span);
+        let start = start.clamp(self.extent.0, self.extent.1 - width);
+        self.window = (start, start + width);
+    }
+
+    /// Update the overview strip width after a resize
+    pub fn set_strip_width(&mut self, width: f32) {
+        self.strip_width = width.max(1.0);
+    }
+
+    /// Which part of the window is under a cursor at `px`
+    pub fn hit_test(&self, px: f32) -> Option<BrushHandle> {
+        let left = self.to_pixels(self.window.0);
+        let right = self.to_pixels(self.window.1);
+        let tol = self.handle_tolerance;
+
+        if (px - left).abs() <= tol {
+            Some(BrushHandle::Left)
+        } else if (px - right).abs() <= tol {
+            Some(BrushHandle::Right)
+        } else if px > left && px < right {
+            Some(BrushHandle::Body)
+        } else {
+            None
+        }
+    }
+
+    /// Start a drag; clicking outside the window re-centers it on the cursor
+    pub fn pointer_down(&mut self, px: f32) {
+        let handle = match self.hit_test(px) {
+            Some(handle) => handle,
+            None => {
+                let half = (self.window.1 - self.window.0) / 2.0;
+                let center = self.to_data(px);
+                self.set_window(center - half, center + half);
+                BrushHandle::Body
+            }
+        };
+        self.drag = Some(Drag {
+            handle,
+            anchor: self.to_data(px),
+            window: self.window,
+        });
+    }
+
+    /// Continue a drag; returns true if the window changed
+    pub fn pointer_move(&mut self, px: f32) -> bool {
+        let Some(drag) = self.drag else {
+            return false;
+        };
+        let before = self.window;
+        let cursor = self.to_data(px);
+        let delta = cursor - drag.anchor;
+        let (start, end) = drag.window;
+
+        match drag.handle {
+            BrushHandle::Body => {
+                let width = end - start;
+                let start = (start + delta).clamp(self.extent.0, self.extent.1 - width);
+                self.window = (start, start + width);
+            }
+            BrushHandle::Left => {
+                let min_width = (self.extent.1 - self.extent.0) * self.min_window;
+                self.window.0 = cursor.clamp(self.extent.0, end - min_width);
+            }
+            BrushHandle::Right => {
+                let min_width = (self.extent.1 - self.extent.0) * self.min_window;
+                self.window.1 = cursor.clamp(start + min_width, self.extent.1);
+            }
+        }
+        self.window != before
+    }
+
+    /// Finish the current drag
+    pub fn pointer_up(&mut self) {
+        self.drag = None;
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Decimated copy of the series for the overview strip
+    ///
+    /// Keeps roughly two samples per pixel of strip width.
+    pub fn overview_series(&self, x: &[f32], y: &[f32]) -> (Vec<f32>, Vec<f32>) {
+        decimate_minmax(x, y, self.strip_width.ceil() as usize)
+    }
+
+    /// Shaded rectangle marking the window on the overview strip (clip space),
+    /// for drawing with `MeshRenderer`
+    pub fn window_overlay(&self, color: Color, rect: CellRect) -> ChartData {
+        let mut data = ChartData::new(rect.width, rect.height);
+        let to_clip = |v: f32| (v - self.extent.0) / (self.extent.1 - self.extent.0) * 2.0 - 1.0;
+        data.add_rect(
+            Point2D::new(to_clip(self.window.0), -1.0),
+            Point2D::new(to_clip(self.window.1), 1.0),
+            color,
+        );
+        data
+    }
+
+    fn to_pixels(&self, value: f32) -> f32 {
+        (value - self.extent.0) * self.strip_width / (self.extent.1 - self.extent.0)
+    }
+
+    fn to_data(&self, px: f32) -> f32 {
+        self.extent.0 + px * (self.extent.1 - self.extent.0) / self.strip_width
+    }
+}
@@ -0,0 +1,200 @@
+//! Data-parallel kernels for the CPU preparation paths
+//!
+//! Normalization (`ChartData::fill_scatter_cancelable`) and aggregation
+//! (`aggregate::aggregate_counts`) run through these helpers. With the
+//! `threads` feature they split the work across rayon's global pool;
+//! without it they run on the calling thread. Both give identical results:
+//! min/max and integer counts don't depend on the order of the reduction,
+//! and normalization maps each element independently.
+//!
+//! # WebAssembly
+//! Browsers only give WASM threads to builds with shared memory, which
+//! needs a nightly toolchain and, on the page, cross-origin isolation
+//! (`Cross-Origin-Opener-Policy: same-origin` and
+//! `Cross-Origin-Embedder-Policy: require-corp`) for `SharedArrayBuffer`:
+//!
+//! ```text
+//! RUSTFLAGS="-C target-feature=+atomics,+bulk-memory,+mutable-globals" \
+//!     wasm-pack build core --target web -- --features wasm,threads -Z build-std=panic_abort,std
+//! ```
+//!
+//! The pool's threads are Web Workers, which only JavaScript can start:
+//! call `initThreadPool(n, spawn)` once, where `spawn(module, memory, ptr)`
+//! starts a worker that instantiates the module with that memory and calls
+//! `startWorker(ptr)` (see `bindings/web/vanilla/src/threads.ts`). The
+//! browser's main thread may not block, so run data preparation from a
+//! worker, not from the main thread.
+
+/// Smallest slice worth splitting across threads; below this the
+/// scheduling costs more than it saves
+pub const MIN_PARALLEL_LEN: usize = 1 << 12;
+
+/// Whether the kernels in this module run on more than one thread
+pub fn is_parallel() -> bool {
+    thread_count() > 1
+}
+
+/// Threads the kernels can use: rayon's pool size with the `threads`
+/// feature, otherwise 1
+pub fn thread_count() -> usize {
+    #[cfg(feature = "threads")]
+    {
+        rayon::current_num_threads()
+    }
+    #[cfg(not(feature = "threads"))]
+    {
+        1
+    }
+}
+
+/// Smallest and largest value of `values`, ignoring NaN
+///
+/// Returns (inf, -inf) for an empty slice, like a sequential fold would.
+///
+/// # Example
+/// ```
+/// use helion_core::parallel::min_max;
+///
+/// assert_eq!(min_max(&[3.0, -1.0, f32::NAN, 7.5]), (-1.0, 7.5));
+/// assert_eq!(min_max(&[]), (f32::INFINITY, f32::NEG_INFINITY));
+/// ```
+pub fn min_max(values: &[f32]) -> (f32, f32) {
+    let fold = |(lo, hi): (f32, f32), &v: &f32| (lo.min(v), hi.max(v));
+    let empty = (f32::INFINITY, f32::NEG_INFINITY);
+    #[cfg(feature = "threads")]
+    if values.len() >= MIN_PARALLEL_LEN {
+        use rayon::prelude::*;
+        return values
+            .par_iter()
+            .with_min_len(MIN_PARALLEL_LEN)
+            .fold(|| empty, fold)
+            .reduce(|| empty, |(a_lo, a_hi), (b_lo, b_hi)| (a_lo.min(b_lo), a_hi.max(b_hi)));
+    }
+    values.iter().fold(empty, fold)
+}
+
+/// Smallest and largest finite value of `values`, ignoring NaN and
+/// infinities
+///
+/// # Example
+/// ```
+/// use helion_core::parallel::finite_min_max;
+///
+/// assert_eq!(finite_min_max(&[3.0, f32::NEG_INFINITY, f32::NAN, 7.5]), (3.0, 7.5));
+/// ```
+pub fn finite_min_max(values: &[f32]) -> (f32, f32) {
+    let fold = |(lo, hi): (f32, f32), &v: &f32| if v.is_finite() { (lo.min(v), hi.max(v)) } else { (lo, hi) };
+    let empty = (f32::INFINITY, f32::NEG_INFINITY);
+    #[cfg(feature = "threads")]
+    if values.len() >= MIN_PARALLEL_LEN {
+        use rayon::prelude::*;
+        return values
+            .par_iter()
+            .with_min_len(MIN_PARALLEL_LEN)
+            .fold(|| empty, fold)
+            .reduce(|| empty, |(a_lo, a_hi), (b_lo, b_hi)| (a_lo.min(b_lo), a_hi.max(b_hi)));
+    }
+    values.iter().fold(empty, fold)
+}
+
+/// `finite_min_max` for f64 values, e.g. nanosecond timestamps that f32
+/// can't hold exactly
+pub fn finite_min_max_f64(values: &[f64]) -> (f64, f64) {
+    let fold = |(lo, hi): (f64, f64), &v: &f64| if v.is_finite() { (lo.min(v), hi.max(v)) } else { (lo, hi) };
+    let empty = (f64::INFINITY, f64::NEG_INFINITY);
+    #[cfg(feature = "threads")]
+    if values.len() >= MIN_PARALLEL_LEN {
+        use rayon::prelude::*;
+        return values
+            .par_iter()
+            .with_min_len(MIN_PARALLEL_LEN)
+            .fold(|| empty, fold)
+            .reduce(|| empty, |(a_lo, a_hi), (b_lo, b_hi)| (a_lo.min(b_lo), a_hi.max(b_hi)));
+    }
+    values.iter().fold(empty, fold)
+}
+
+/// Append `f(i)` for every `i` in `range` to `out`, in order
+pub fn extend_mapped<T, F>(out: &mut Vec<T>, range: std::ops::Range<usize>, f: F)
+where
+    T: Send,
+    F: Fn(usize) -> T + Sync + Send,
+{
+    #[cfg(feature = "threads")]
+    if range.len() >= MIN_PARALLEL_LEN {
+        use rayon::prelude::*;
+        out.par_extend(range.into_par_iter().with_min_len(MIN_PARALLEL_LEN).map(f));
+        return;
+    }
+    out.extend(range.map(f));
+}
+
+/// Count how many items of `0..len` fall in each of `bins` bins, where
+/// `bin(i)` gives the bin of item `i` (or `None` to skip it)
+pub fn count_bins<F>(len: usize, bins: usize, bin: F) -> Vec<u32>
+where
+    F: Fn(usize) -> Option<usize> + Sync + Send,
+{
+    let count = |mut counts: Vec<u32>, i: usize| {
+        if let Some(b) = bin(i) {
+            counts[b] += 1;
+        }
+        counts
+    };
+    #[cfg(feature = "threads")]
+    if len >= MIN_PARALLEL_LEN {
+        use rayon::prelude::*;
+        // One histogram per split, summed pairwise
+        return (0..len)
+            .into_par_iter()
+            .with_min_len(MIN_PARALLEL_LEN)
+            .fold(|| vec![0u32; bins], count)
+            .reduce(
+                || vec![0u32; bins],
+                |mut a, b| {
+                    for (a, b) in a.iter_mut().zip(b) {
+                        *a += b;
+                    }
+                    a
+                },
+            );
+    }
+    (0..len).fold(vec![0u32; bins], count)
+}
+
+/// `helion.initThreadPool(n, spawn)` from JavaScript: start a pool of `n`
+/// Web Worker threads
+///
+/// `spawn(module, memory, ptr)` is called once per thread and must start a
+/// worker that instantiates `module` with the shared `memory`, then calls
+/// `startWorker(ptr)`.
+#[cfg(all(target_arch = "wasm32", feature = "threads"))]
+#[wasm_bindgen::prelude::wasm_bindgen(js_name = initThreadPool)]
+pub fn init_thread_pool(num_threads: usize, spawn: &js_sys::Function) -> Result<(), wasm_bindgen::JsValue> {
+    use wasm_bindgen::JsValue;
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .spawn_handler(|thread| {
+            // Ownership passes to the worker, which rebuilds the box in
+            // `start_worker`
+            let ptr = Box::into_raw(Box::new(thread)) as usize as u32;
+            spawn
+                .call3(&JsValue::NULL, &wasm_bindgen::module(), &wasm_bindgen::memory(), &ptr.into())
+                .map(|_| ())
+                .map_err(|e| std::io::Error::other(format!("{:?}", e)))
+        })
+        .build_global()
+        .map_err(|e| JsValue::from_str(&format!("Failed to start thread pool: {}", e)))
+}
+
+/// Worker entry point: run the pool thread handed over by `initThreadPool`
+///
+/// # Safety
+/// `ptr` must come from `initThreadPool`'s `spawn` callback, and each one
+/// may be passed here only once.
+#[cfg(all(target_arch = "wasm32", feature = "threads"))]
+#[wasm_bindgen::prelude::wasm_bindgen(js_name = startWorker)]
+pub unsafe fn start_worker(ptr: u32) {
+    let thread = Box::from_raw(ptr as usize as *mut rayon::ThreadBuilder);
+    thread.run();
+}
@@ -0,0 +1,491 @@
+use crate::camera::{Camera2D, CameraBinding, CAMERA_GROUP};
+use crate::chunked::ChunkedBuffer;
+use crate::data::ChartData;
+use crate::error::HelionError;
+use crate::readback::RenderTarget;
+use crate::shaders::{PICK_FRAGMENT_SHADER, PICK_VERTEX_SHADER};
+use bytemuck::{Pod, Zeroable};
+use wgpu::util::DeviceExt;
+
+/// Format of the id buffer `GpuPicker` draws into: one point id per pixel,
+/// 0 where no point is drawn
+pub const PICK_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::R32Uint;
+
+/// Number of vertices in one marker quad (two triangles)
+const QUAD_VERTICES: u32 = 6;
+
+/// Bytes read back per pick; one texel, padded to the copy row alignment
+const READBACK_BYTES: u64 = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT as u64;
+
+/// Index of the point drawn nearest to pixel (`x`, `y`), if any is within
+/// reach
+///
+/// CPU picking: projects every vertex through `camera` like the vertex
+/// shader does and keeps the closest one. A point is in reach if the cursor
+/// is within `radius` pixels of its edge (half its `size` from its center),
+/// so large markers can be hit anywhere on them. Used where the device has
+/// no storage buffers for GPU picking (`Capabilities::picking`).
+///
+/// # Parameters
+/// * `x`, `y` - Cursor in window pixels, origin at the top left
+/// * `radius` - Extra slack around each marker, in pixels
+///
+/// # Example
+/// ```
+/// use helion_core::camera::Camera2D;
+/// use helion_core::data::ChartData;
+/// use helion_core::picking::pick_nearest;
+///
+/// let data = ChartData::from_scatter(&[0.0, 1.0], &[0.0, 1.0], None, Some(4.0), 100.0, 100.0);
+/// // The second point sits at the top right corner, pixel (100, 0)
+/// assert_eq!(pick_nearest(&data, &Camera2D::identity(), 97.0, 3.0, 3.0), Some(1));
+/// assert_eq!(pick_nearest(&data, &Camera2D::identity(), 50.0, 50.0, 3.0), None);
+/// ```
+pub fn pick_nearest(data: &ChartData, camera: &Camera2D, x: f32, y: f32, radius: f32) -> Option<usize> {
+    let (width, height) = (data.viewport_width.max(1.0), data.viewport_height.max(1.0));
+    data.vertices
+        .iter()
+        .enumerate()
+        .filter_map(|(i, vertex)| {
+            let [cx, cy] = camera.apply(vertex.position);
+            let (px, py) = ((cx + 1.0) / 2.0 * width, (1.0 - cy) / 2.0 * height);
+            let distance = ((px - x).powi(2) + (py - y).powi(2)).sqrt();
+            let reach = vertex.size.max(0.0) / 2.0 + radius;
+            (distance <= reach).then_some((i, distance))
+        })
+        // First of equally close points wins, matching draw order ties
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(i, _)| i)
+}
+
+/// Per-point input of the picking pass, stepped per instance
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Pod, Zeroable)]
+pub struct PickInstance {
+    pub position: [f32; 2],
+    pub size: f32,
+    /// Index of the point in its series
+    pub index: u32,
+}
+
+impl PickInstance {
+    /// Picking input for every vertex of `data`, in order
+    pub fn from_data(data: &ChartData) -> Vec<Self> {
+        data.vertices
+            .iter()
+            .enumerate()
+            .map(|(i, vertex)| Self {
+                position: vertex.position,
+                size: vertex.size,
+                index: i as u32,
+            })
+            .collect()
+    }
+
+    pub fn desc<'a>() -> wgpu::VertexBufferLayout<'a> {
+        const ATTRIBUTES: [wgpu::VertexAttribute; 3] =
+            wgpu::vertex_attr_array![0 => Float32x2, 1 => Float32, 2 => Uint32];
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<PickInstance>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: &ATTRIBUTES,
+        }
+    }
+}
+
+/// Uniform block layout shared with `PICK_VERTEX_SHADER`, one per series
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Pod, Zeroable)]
+pub struct PickUniforms {
+    pub viewport: [f32; 2],
+    /// Slack around each marker, in pixels
+    pub radius: f32,
+    /// Ids handed out to the series drawn before this one
+    pub first_id: u32,
+}
+
+/// Point ids handed out to each series of a `GpuPicker`
+///
+/// Ids are consecutive in drawing order: the first series' points get
+/// 1..=n, the next series continues from n + 1, and 0 stands for no
+/// point.
+///
+/// # Example
+/// ```
+/// use helion_core::picking::PickIds;
+///
+/// let mut ids = PickIds::default();
+/// ids.set_len(3, 2);
+/// ids.set_len(0, 5);
+/// assert_eq!(ids.first_id(1), 2);
+/// assert_eq!(ids.decode(2), Some((3, 1)));
+/// assert_eq!(ids.decode(3), Some((0, 0)));
+/// assert_eq!(ids.decode(0), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PickIds {
+    /// (series, number of points) in drawing order
+    layers: Vec<(usize, u32)>,
+}
+
+impl PickIds {
+    /// Set the number of points of `series`, adding it last if it is new
+    ///
+    /// # Returns
+    /// The slot of `series` in drawing order
+    pub fn set_len(&mut self, series: usize, len: usize) -> usize {
+        let len = u32::try_from(len).unwrap_or(u32::MAX);
+        match self.slot(series) {
+            Some(slot) => {
+                self.layers[slot].1 = len;
+                slot
+            }
+            None => {
+                self.layers.push((series, len));
+                self.layers.len() - 1
+            }
+        }
+    }
+
+    /// Position of `series` in drawing order
+    pub fn slot(&self, series: usize) -> Option<usize> {
+        self.layers.iter().position(|&(s, _)| s == series)
+    }
+
+    /// Number of ids taken by the series before `slot`, the value of its
+    /// `PickUniforms::first_id`
+    pub fn first_id(&self, slot: usize) -> u32 {
+        self.layers[..slot].iter().fold(0u32, |sum, &(_, len)| sum.saturating_add(len))
+    }
+
+    /// Series and point index an id read from the buffer stands for
+    pub fn decode(&self, id: u32) -> Option<(usize, usize)> {
+        let mut rest = id.checked_sub(1)?;
+        for &(series, len) in &self.layers {
+            if rest < len {
+                return Some((series, rest as usize));
+            }
+            rest -= len;
+        }
+        None
+    }
+}
+
+/// Points of one series in the picking pass
+struct PickLayer {
+    instances: Option<ChunkedBuffer>,
+    uniform_buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+    visible: bool,
+}
+
+/// GPU picking: which point is drawn at a pixel
+///
+/// Draws every series' markers into an offscreen `PICK_FORMAT` target,
+/// writing point ids instead of colors, and reads back the one texel under
+/// the cursor. Later series are drawn over earlier ones, so the topmost
+/// marker wins as on screen. The id buffer is only redrawn on the next pick
+/// after the points, camera or size change.
+///
+/// Scatter markers only: line and bar series have no points to pick.
+/// `pick_nearest` is the CPU fallback.
+pub struct GpuPicker {
+    target: RenderTarget,
+    pipeline: wgpu::RenderPipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+    camera: CameraBinding,
+    ids: PickIds,
+    /// In the order of `ids`
+    layers: Vec<PickLayer>,
+    radius: f32,
+    readback: wgpu::Buffer,
+    /// The id buffer no longer shows the current points, camera or size
+    stale: bool,
+}
+
+impl GpuPicker {
+    /// Picker for a `width` x `height` viewport, with no series yet
+    pub fn new(device: &wgpu::Device, width: u32, height: u32) -> Self {
+        let vertex_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Pick Vertex Shader"),
+            source: wgpu::ShaderSource::Wgsl(PICK_VERTEX_SHADER.into()),
+        });
+        let fragment_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Pick Fragment Shader"),
+            source: wgpu::ShaderSource::Wgsl(PICK_FRAGMENT_SHADER.into()),
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Pick Bind Group Layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+        let camera = CameraBinding::new(device);
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Pick Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout, camera.layout()],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Pick Render Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &vertex_shader,
+                entry_point: "vs_main",
+                buffers: &[PickInstance::desc()],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader,
+                entry_point: "fs_main",
+                // Integer targets can't blend; the last marker drawn wins
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: PICK_FORMAT,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        });
+
+        let readback = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Pick Readback Buffer"),
+            size: READBACK_BYTES,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        Self {
+            target: RenderTarget::new(device, width, height, PICK_FORMAT),
+            pipeline,
+            bind_group_layout,
+            camera,
+            ids: PickIds::default(),
+            layers: Vec::new(),
+            radius: 0.0,
+            readback,
+            stale: true,
+        }
+    }
+
+    /// Replace the points of `series`, adding it over the others if new
+    pub fn set_series(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, series: usize, data: &ChartData) {
+        let instances = PickInstance::from_data(data);
+        let slot = self.ids.set_len(series, instances.len());
+        if slot == self.layers.len() {
+            let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Pick Uniform Buffer"),
+                contents: bytemuck::bytes_of(&PickUniforms::zeroed()),
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            });
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Pick Bind Group"),
+                layout: &self.bind_group_layout,
+                entries: &[wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: uniform_buffer.as_entire_binding(),
+                }],
+            });
+            self.layers.push(PickLayer {
+                instances: None,
+                uniform_buffer,
+                bind_group,
+                visible: true,
+            });
+        }
+        ChunkedBuffer::update_or_log(
+            &mut self.layers[slot].instances,
+            device,
+            queue,
+            "Pick Instance Buffer",
+            &instances,
+            wgpu::BufferUsages::VERTEX,
+            1,
+        );
+        // Later series' ids shift when this one changes length
+        self.write_uniforms(queue);
+    }
+
+    /// Add the points of `data` past those `series` already has, as a live
+    /// series grows, writing only the new ones when they fit in place
+    ///
+    /// Like the scatter ring, this assumes the points held are still the
+    /// first ones of `data`; anything else goes through `set_series`.
+    pub fn append_series(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, series: usize, data: &ChartData) {
+        let Some(slot) = self.ids.slot(series) else {
+            return self.set_series(device, queue, series, data);
+        };
+        let held = self.layers[slot].instances.as_ref().map_or(0, ChunkedBuffer::len) as usize;
+        let Some(new) = data.vertices.get(held..) else {
+            return self.set_series(device, queue, series, data);
+        };
+        let instances: Vec<PickInstance> = new
+            .iter()
+            .enumerate()
+            .map(|(i, vertex)| PickInstance {
+                position: vertex.position,
+                size: vertex.size,
+                index: (held + i) as u32,
+            })
+            .collect();
+        let appended = match &mut self.layers[slot].instances {
+            Some(buffer) => buffer.append(queue, &instances).is_ok(),
+            None => false,
+        };
+        if !appended {
+            return self.set_series(device, queue, series, data);
+        }
+        self.ids.set_len(series, data.vertices.len());
+        self.write_uniforms(queue);
+    }
+
+    /// Leave `series` out of picking, e.g. while it is hidden in the legend
+    pub fn set_visible(&mut self, series: usize, visible: bool) {
+        if let Some(layer) = self.ids.slot(series).and_then(|slot| self.layers.get_mut(slot)) {
+            self.stale |= layer.visible != visible;
+            layer.visible = visible;
+        }
+    }
+
+    /// Follow the view the series are drawn with
+    pub fn set_camera(&mut self, queue: &wgpu::Queue, camera: &Camera2D) {
+        self.stale |= self.camera.set(queue, camera) > 0;
+    }
+
+    /// Pixels of slack around each marker, so small points are easier to
+    /// hit; 0 by default
+    pub fn set_radius(&mut self, queue: &wgpu::Queue, radius: f32) {
+        self.radius = radius.max(0.0);
+        self.write_uniforms(queue);
+    }
+
+    pub fn resize(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, width: u32, height: u32) {
+        self.target = RenderTarget::new(device, width, height, PICK_FORMAT);
+        self.write_uniforms(queue);
+    }
+
+    /// Ids handed out to each series
+    pub fn ids(&self) -> &PickIds {
+        &self.ids
+    }
+
+    fn write_uniforms(&mut self, queue: &wgpu::Queue) {
+        for (slot, layer) in self.layers.iter().enumerate() {
+            let uniforms = PickUniforms {
+                viewport: [self.target.width as f32, self.target.height as f32],
+                radius: self.radius,
+                first_id: self.ids.first_id(slot),
+            };
+            queue.write_buffer(&layer.uniform_buffer, 0, bytemuck::bytes_of(&uniforms));
+        }
+        self.stale = true;
+    }
+
+    /// Draw the ids of every visible series, clearing the rest to 0
+    fn encode_ids(&self, encoder: &mut wgpu::CommandEncoder) {
+        let mut render_pass = self.target.begin_pass(encoder, wgpu::Color::TRANSPARENT);
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_bind_group(CAMERA_GROUP, self.camera.bind_group(), &[]);
+        for layer in self.layers.iter().filter(|layer| layer.visible) {
+            if let Some(instances) = &layer.instances {
+                render_pass.set_bind_group(0, &layer.bind_group, &[]);
+                instances.draw_instanced(&mut render_pass, 0, 0..QUAD_VERTICES);
+            }
+        }
+    }
+
+    /// Series and index of the point drawn at pixel (`x`, `y`), origin at
+    /// the top left, or `None` over the background
+    ///
+    /// Resolves after the GPU has drawn the ids, when they were stale, and
+    /// copied back the texel.
+    pub async fn pick_async(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        x: f32,
+        y: f32,
+    ) -> Result<Option<(usize, usize)>, HelionError> {
+        let inside = x >= 0.0 && y >= 0.0 && x < self.target.width as f32 && y < self.target.height as f32;
+        if !inside {
+            return Ok(None);
+        }
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Pick Encoder"),
+        });
+        if self.stale {
+            self.encode_ids(&mut encoder);
+            self.stale = false;
+        }
+        encoder.copy_texture_to_buffer(
+            wgpu::ImageCopyTexture {
+                texture: &self.target.texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d {
+                    x: x as u32,
+                    y: y as u32,
+                    z: 0,
+                },
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::ImageCopyBuffer {
+                buffer: &self.readback,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(READBACK_BYTES as u32),
+                    rows_per_image: None,
+                },
+            },
+            wgpu::Extent3d {
+                width: 1,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+        );
+        queue.submit(Some(encoder.finish()));
+
+        let (sender, receiver) = futures::channel::oneshot::channel();
+        self.readback.slice(..).map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        device.poll(wgpu::Maintain::Wait);
+        receiver
+            .await
+            .map_err(|_| HelionError::Readback("Pick readback was cancelled".to_string()))?
+            .map_err(|e| HelionError::Readback(format!("Failed to map pick buffer: {}", e)))?;
+
+        let id = {
+            let bytes = self.readback.slice(..4).get_mapped_range();
+            u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+        };
+        self.readback.unmap();
+        Ok(self.ids.decode(id))
+    }
+
+    /// Blocking version of `pick_async` (native only)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn pick(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        x: f32,
+        y: f32,
+    ) -> Result<Option<(usize, usize)>, HelionError> {
+        futures::executor::block_on(self.pick_async(device, queue, x, y))
+    }
+}
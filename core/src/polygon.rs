@@ -0,0 +1,412 @@
+use crate::data::{ChartData, Color, Point2D};
+use crate::layout::Extent;
+use crate::mesh::MeshRenderer;
+use crate::renderer::{RenderOptions, Renderer, WindowRenderer};
+use crate::stats::DrawStats;
+
+/// Filled shape: an outer ring with optional holes
+///
+/// Rings are lists of vertices in either winding order; a closing vertex
+/// equal to the first, as GeoJSON writes them, is allowed. Shapes are drawn
+/// by triangulating them with `earcut`.
+///
+/// # Example
+/// ```
+/// use helion_core::data::Point2D;
+/// use helion_core::polygon::Polygon;
+///
+/// let square = |min: f32, max: f32| {
+///     vec![Point2D::new(min, min), Point2D::new(max, min), Point2D::new(max, max), Point2D::new(min, max)]
+/// };
+/// let frame = Polygon::new(square(0.0, 4.0)).with_hole(square(1.0, 3.0));
+/// assert_eq!(frame.area(), 12.0);
+/// assert!(frame.contains(Point2D::new(0.5, 0.5)));
+/// assert!(!frame.contains(Point2D::new(2.0, 2.0)));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Polygon {
+    pub exterior: Vec<Point2D>,
+    pub holes: Vec<Vec<Point2D>>,
+}
+
+impl Polygon {
+    pub fn new(exterior: Vec<Point2D>) -> Self {
+        Self {
+            exterior,
+            holes: Vec::new(),
+        }
+    }
+
+    /// Cut `hole` out of the shape; it must lie inside the exterior
+    pub fn with_hole(mut self, hole: Vec<Point2D>) -> Self {
+        self.holes.push(hole);
+        self
+    }
+
+    /// Enclosed area: the exterior's minus the holes'
+    pub fn area(&self) -> f32 {
+        let ring_area = |ring: &[Point2D]| {
+            let points: Vec<(f64, f64)> = ring.iter().map(|p| (p.x as f64, p.y as f64)).collect();
+            signed_area(&points, &(0..points.len()).collect::<Vec<_>>()).abs()
+        };
+        let holes: f64 = self.holes.iter().map(|hole| ring_area(hole)).sum();
+        (ring_area(&self.exterior) - holes) as f32
+    }
+
+    /// Whether `point` is inside the exterior and outside every hole
+    ///
+    /// Uses the even-odd rule, so points exactly on an edge may fall on
+    /// either side.
+    pub fn contains(&self, point: Point2D) -> bool {
+        std::iter::once(&self.exterior)
+            .chain(&self.holes)
+            .filter(|ring| ring_crosses(ring, point))
+            .count()
+            % 2
+            == 1
+    }
+
+    /// (x, y) extent of the exterior; (inf, -inf) per axis when it's empty
+    pub fn extent(&self) -> Extent {
+        self.exterior.iter().fold(
+            ((f32::INFINITY, f32::NEG_INFINITY), (f32::INFINITY, f32::NEG_INFINITY)),
+            |(x, y), p| ((x.0.min(p.x), x.1.max(p.x)), (y.0.min(p.y), y.1.max(p.y))),
+        )
+    }
+
+    /// Triangles covering the shape, three vertices each
+    pub fn triangulate(&self) -> Vec<Point2D> {
+        let vertices: Vec<Point2D> = self.exterior.iter().chain(self.holes.iter().flatten()).copied().collect();
+        let coords: Vec<f32> = vertices.iter().flat_map(|p| [p.x, p.y]).collect();
+        let mut hole_starts = Vec::with_capacity(self.holes.len());
+        let mut start = self.exterior.len();
+        for hole in &self.holes {
+            hole_starts.push(start);
+            start += hole.len();
+        }
+        earcut(&coords, &hole_starts)
+            .into_iter()
+            .map(|i| vertices[i as usize])
+            .collect()
+    }
+}
+
+/// Whether a ray from `point` towards +x crosses an edge of `ring` an odd
+/// number of times
+fn ring_crosses(ring: &[Point2D], point: Point2D) -> bool {
+    let mut inside = false;
+    for (i, a) in ring.iter().enumerate() {
+        let b = ring[(i + 1) % ring.len()];
+        if (a.y > point.y) != (b.y > point.y) {
+            let x = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+            if point.x < x {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+/// Triangulate a polygon with holes by ear clipping
+///
+/// Follows the interface of the earcut.js library: holes are joined to the
+/// outer ring through bridge edges, then ears are clipped from the joined
+/// ring. Self-intersecting input still yields triangles, but they may not
+/// cover the shape exactly.
+///
+/// # Parameters
+/// * `coords` - Flat `[x0, y0, x1, y1, ...]` vertices of the outer ring,
+///   followed by those of each hole
+/// * `hole_starts` - Ascending vertex (not coordinate) index where each
+///   hole begins
+///
+/// # Returns
+/// Vertex indices, three per triangle, all wound counter-clockwise; empty
+/// if the outer ring has fewer than three distinct vertices
+///
+/// # Example
+/// ```
+/// use helion_core::polygon::earcut;
+///
+/// // An L shape: six vertices, four triangles
+/// let coords = [0.0, 0.0, 2.0, 0.0, 2.0, 1.0, 1.0, 1.0, 1.0, 2.0, 0.0, 2.0];
+/// assert_eq!(earcut(&coords, &[]).len(), 4 * 3);
+/// ```
+pub fn earcut(coords: &[f32], hole_starts: &[usize]) -> Vec<u32> {
+    let points: Vec<(f64, f64)> = coords.chunks_exact(2).map(|p| (p[0] as f64, p[1] as f64)).collect();
+    let n = points.len();
+    let mut bounds = Vec::with_capacity(hole_starts.len() + 2);
+    bounds.push(0);
+    bounds.extend(hole_starts.iter().map(|&start| start.min(n)));
+    bounds.push(n);
+
+    let mut outer = ring(&points, bounds[0], bounds[1], true);
+    if outer.len() < 3 {
+        return Vec::new();
+    }
+    let mut holes: Vec<Vec<usize>> = bounds[1..]
+        .windows(2)
+        .map(|w| ring(&points, w[0], w[1].max(w[0]), false))
+        .filter(|hole| hole.len() >= 3)
+        .collect();
+
+    // Joining holes from right to left guarantees the rightmost vertex of
+    // the next hole sees some vertex of the ring built so far
+    let rightmost = |hole: &[usize]| {
+        (0..hole.len())
+            .max_by(|&a, &b| points[hole[a]].0.total_cmp(&points[hole[b]].0))
+            .unwrap_or(0)
+    };
+    holes.sort_by(|a, b| points[b[rightmost(b)]].0.total_cmp(&points[a[rightmost(a)]].0));
+    for h in 0..holes.len() {
+        let hole = &holes[h];
+        let m = rightmost(hole);
+        let Some(k) = bridge(&points, &outer, &holes[h..], hole[m]) else {
+            continue;
+        };
+        let mut joined = Vec::with_capacity(outer.len() + hole.len() + 2);
+        joined.extend_from_slice(&outer[..=k]);
+        joined.extend_from_slice(&hole[m..]);
+        joined.extend_from_slice(&hole[..=m]);
+        joined.extend_from_slice(&outer[k..]);
+        outer = joined;
+    }
+
+    clip_ears(&points, outer)
+}
+
+/// Vertex indices `start..end` without repeats, wound counter-clockwise if
+/// `ccw` and clockwise otherwise
+fn ring(points: &[(f64, f64)], start: usize, end: usize, ccw: bool) -> Vec<usize> {
+    let mut ring: Vec<usize> = Vec::with_capacity(end - start);
+    for i in start..end {
+        if ring.last().is_none_or(|&j| points[j] != points[i]) {
+            ring.push(i);
+        }
+    }
+    while ring.len() > 1 && points[ring[0]] == points[ring[ring.len() - 1]] {
+        ring.pop();
+    }
+    if (signed_area(points, &ring) > 0.0) != ccw {
+        ring.reverse();
+    }
+    ring
+}
+
+/// Shoelace area of `ring`, positive when it winds counter-clockwise
+fn signed_area(points: &[(f64, f64)], ring: &[usize]) -> f64 {
+    let mut sum = 0.0;
+    for (i, &a) in ring.iter().enumerate() {
+        let (a, b) = (points[a], points[ring[(i + 1) % ring.len()]]);
+        sum += a.0 * b.1 - b.0 * a.1;
+    }
+    sum / 2.0
+}
+
+/// Twice the signed area of triangle `o`, `a`, `b`; positive for a left turn
+fn cross(o: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+}
+
+/// Whether segments `p`-`q` and `a`-`b` touch or cross
+fn segments_intersect(p: (f64, f64), q: (f64, f64), a: (f64, f64), b: (f64, f64)) -> bool {
+    let on_segment = |s: (f64, f64), e: (f64, f64), x: (f64, f64)| {
+        x.0 >= s.0.min(e.0) && x.0 <= s.0.max(e.0) && x.1 >= s.1.min(e.1) && x.1 <= s.1.max(e.1)
+    };
+    let (d1, d2) = (cross(p, q, a), cross(p, q, b));
+    let (d3, d4) = (cross(a, b, p), cross(a, b, q));
+    if ((d1 > 0.0 && d2 < 0.0) || (d1 < 0.0 && d2 > 0.0)) && ((d3 > 0.0 && d4 < 0.0) || (d3 < 0.0 && d4 > 0.0)) {
+        return true;
+    }
+    (d1 == 0.0 && on_segment(p, q, a))
+        || (d2 == 0.0 && on_segment(p, q, b))
+        || (d3 == 0.0 && on_segment(a, b, p))
+        || (d4 == 0.0 && on_segment(a, b, q))
+}
+
+/// Position in `outer` of the closest vertex `hole_vertex` can be joined to
+/// without crossing `outer` or any of `holes`
+fn bridge(points: &[(f64, f64)], outer: &[usize], holes: &[Vec<usize>], hole_vertex: usize) -> Option<usize> {
+    let m = points[hole_vertex];
+    let len = outer.len();
+    let mut candidates: Vec<usize> = (0..len).collect();
+    let distance = |k: usize| {
+        let p = points[outer[k]];
+        (p.0 - m.0).powi(2) + (p.1 - m.1).powi(2)
+    };
+    candidates.sort_by(|&a, &b| distance(a).total_cmp(&distance(b)));
+
+    candidates.into_iter().find(|&k| {
+        let (prev, p, next) = (points[outer[(k + len - 1) % len]], points[outer[k]], points[outer[(k + 1) % len]]);
+        // The bridge must leave `p` into the shape, not along or across
+        // its neighbouring edges
+        let inside = if cross(prev, p, next) > 0.0 {
+            cross(p, next, m) > 0.0 && cross(p, m, prev) > 0.0
+        } else {
+            !(cross(p, prev, m) >= 0.0 && cross(p, m, next) >= 0.0)
+        };
+        if !inside {
+            return false;
+        }
+        let blocked = |ring: &[usize]| {
+            (0..ring.len()).any(|i| {
+                let (a, b) = (points[ring[i]], points[ring[(i + 1) % ring.len()]]);
+                let shares_end = [a, b].iter().any(|&e| e == m || e == p);
+                !shares_end && segments_intersect(m, p, a, b)
+            })
+        };
+        !blocked(outer) && !holes.iter().any(|hole| blocked(hole))
+    })
+}
+
+/// Cut triangles off the counter-clockwise `ring` until none are left
+fn clip_ears(points: &[(f64, f64)], mut ring: Vec<usize>) -> Vec<u32> {
+    let mut triangles = Vec::with_capacity(ring.len().saturating_sub(2) * 3);
+    let mut i = 0;
+    let mut stalled = 0;
+    while ring.len() > 3 {
+        let len = ring.len();
+        let (a, b, c) = (ring[(i + len - 1) % len], ring[i], ring[(i + 1) % len]);
+        let turn = cross(points[a], points[b], points[c]);
+        if turn == 0.0 {
+            // Collinear or a spike: dropping the vertex loses no area
+            ring.remove(i);
+        } else if turn > 0.0 && is_ear(points, &ring, i) {
+            triangles.extend([a as u32, b as u32, c as u32]);
+            ring.remove(i);
+        } else if stalled < len {
+            i = (i + 1) % len;
+            stalled += 1;
+            continue;
+        } else {
+            // No clean ear, e.g. for self-intersecting input; clip the first
+            // convex vertex so the loop ends
+            let Some(convex) = (0..len).find(|&j| {
+                cross(points[ring[(j + len - 1) % len]], points[ring[j]], points[ring[(j + 1) % len]]) > 0.0
+            }) else {
+                break;
+            };
+            triangles.extend([ring[(convex + len - 1) % len], ring[convex], ring[(convex + 1) % len]].map(|v| v as u32));
+            ring.remove(convex);
+        }
+        stalled = 0;
+        // The previous vertex may have become an ear
+        i = (i + ring.len() - 1) % ring.len();
+    }
+    if ring.len() == 3 && cross(points[ring[0]], points[ring[1]], points[ring[2]]) > 0.0 {
+        triangles.extend(ring.iter().map(|&v| v as u32));
+    }
+    triangles
+}
+
+/// Whether no other vertex of `ring` lies in the triangle at position `i`
+fn is_ear(points: &[(f64, f64)], ring: &[usize], i: usize) -> bool {
+    let len = ring.len();
+    let (a, b, c) = (points[ring[(i + len - 1) % len]], points[ring[i]], points[ring[(i + 1) % len]]);
+    ring.iter().map(|&v| points[v]).all(|p| {
+        p == a || p == b || p == c || cross(a, b, p) < 0.0 || cross(b, c, p) < 0.0 || cross(c, a, p) < 0.0
+    })
+}
+
+/// Build filled polygons as a triangle list
+///
+/// # Parameters
+/// * `polygons` - Shapes in data units
+/// * `colors` - Fill per polygon, cycled when shorter; a single color fills
+///   them all
+/// * `extent` - Data extent mapped onto the output ranges, e.g. the union
+///   of `Polygon::extent` or a figure's data range for highlight regions
+/// * `x_out`, `y_out` - Output ranges, e.g. `(-1.0, 1.0)` for the whole
+///   viewport
+/// * `width`, `height` - Viewport size in pixels
+///
+/// # Returns
+/// `ChartData` to draw with `PolygonRenderer` or `MeshRenderer`
+pub fn polygon_data(
+    polygons: &[Polygon],
+    colors: &[Color],
+    extent: Extent,
+    x_out: (f32, f32),
+    y_out: (f32, f32),
+    width: f32,
+    height: f32,
+) -> ChartData {
+    let mut data = ChartData::new(width, height);
+    if colors.is_empty() {
+        return data;
+    }
+    let normalize = |value: f32, extent: (f32, f32), out: (f32, f32)| {
+        let span = extent.1 - extent.0;
+        let t = if span > 0.0 { (value - extent.0) / span } else { 0.5 };
+        out.0 + t * (out.1 - out.0)
+    };
+    for (polygon, &color) in polygons.iter().zip(colors.iter().cycle()) {
+        for vertex in polygon.triangulate() {
+            let position = Point2D::new(normalize(vertex.x, extent.0, x_out), normalize(vertex.y, extent.1, y_out));
+            data.add_point(position, color, 0.0);
+        }
+    }
+    data
+}
+
+/// Union of the extents of `polygons`
+pub fn polygons_extent(polygons: &[Polygon]) -> Extent {
+    polygons.iter().map(Polygon::extent).fold(
+        ((f32::INFINITY, f32::NEG_INFINITY), (f32::INFINITY, f32::NEG_INFINITY)),
+        |(x, y), (px, py)| ((x.0.min(px.0), x.1.max(px.1)), (y.0.min(py.0), y.1.max(py.1))),
+    )
+}
+
+/// Filled-shape renderer for region plots and highlight areas
+///
+/// Draws the triangles of `polygon_data` with a `MeshRenderer`; replace
+/// the shapes with `set_polygons`.
+pub struct PolygonRenderer {
+    mesh: MeshRenderer,
+    width: f32,
+    height: f32,
+}
+
+impl PolygonRenderer {
+    /// Replace the shapes with `polygons`, fitted to the whole viewport
+    pub fn set_polygons(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, polygons: &[Polygon], colors: &[Color]) {
+        let extent = polygons_extent(polygons);
+        let data = polygon_data(polygons, colors, extent, (-1.0, 1.0), (-1.0, 1.0), self.width, self.height);
+        self.mesh.update_data(device, queue, &data);
+    }
+}
+
+impl Renderer for PolygonRenderer {
+    fn render_to_pass<'rpass>(&'rpass mut self, render_pass: &mut wgpu::RenderPass<'rpass>) {
+        self.mesh.render_to_pass(render_pass);
+    }
+
+    fn frame_stats(&mut self) -> DrawStats {
+        self.mesh.frame_stats()
+    }
+}
+
+impl WindowRenderer for PolygonRenderer {
+    fn with_options(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        chart_data: ChartData,
+        options: &RenderOptions,
+    ) -> Self {
+        PolygonRenderer {
+            mesh: MeshRenderer::with_options(device, config, chart_data, options),
+            width: config.width as f32,
+            height: config.height as f32,
+        }
+    }
+
+    fn update_data(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, chart_data: &ChartData) {
+        self.mesh.update_data(device, queue, chart_data);
+    }
+
+    fn resize(&mut self, queue: &wgpu::Queue, width: u32, height: u32) {
+        self.width = width as f32;
+        self.height = height as f32;
+        self.mesh.resize(queue, width, height);
+    }
+}
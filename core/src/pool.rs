@@ -0,0 +1,102 @@
+use crate::data::{ChartData, MissingDataPolicy, Vertex};
+
+/// Recycles `ChartData` vertex allocations between frames
+///
+/// Animation loops that rebuild their data every frame would otherwise
+/// allocate and free a vertex `Vec` each time, which for millions of points
+/// means hundreds of MB of allocator traffic per frame. Take data from the
+/// pool, fill it, upload it, and give it back.
+///
+/// # Example
+/// ```
+/// use helion_core::pool::ChartDataPool;
+///
+/// let mut pool = ChartDataPool::new();
+/// let x: Vec<f32> = (0..1000).map(|i| i as f32).collect();
+/// for frame in 0..10 {
+///     let y: Vec<f32> = x.iter().map(|v| (v * 0.01 + frame as f32).sin()).collect();
+///     let mut data = pool.acquire(800.0, 600.0);
+///     data.fill_scatter(&x, &y, None, None, None, None);
+///     // renderer.update_data(&device, &queue, &data);
+///     pool.release(data);
+/// }
+/// assert_eq!(pool.idle(), 1);
+/// ```
+pub struct ChartDataPool {
+    free: Vec<ChartData>,
+    max_idle: usize,
+}
+
+impl ChartDataPool {
+    /// Idle entries kept by default: enough for double or triple buffering
+    pub const DEFAULT_MAX_IDLE: usize = 3;
+
+    pub fn new() -> Self {
+        Self {
+            free: Vec::new(),
+            max_idle: Self::DEFAULT_MAX_IDLE,
+        }
+    }
+
+    /// Keep at most `max_idle` released entries; extra ones are freed
+    pub fn with_max_idle(mut self, max_idle: usize) -> Self {
+        self.max_idle = max_idle;
+        self.free.truncate(max_idle);
+        self
+    }
+
+    /// Empty data for the given viewport, reusing the largest idle
+    /// allocation if there is one
+    pub fn acquire(&mut self, width: f32, height: f32) -> ChartData {
+        let largest = self
+            .free
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, data)| data.vertices.capacity())
+            .map(|(index, _)| index);
+
+        match largest {
+            Some(index) => {
+                let mut data = self.free.swap_remove(index);
+                data.clear();
+                data.set_max_points(None);
+                data.set_missing_data(MissingDataPolicy::default());
+                data.viewport_width = width;
+                data.viewport_height = height;
+                data
+            }
+            None => ChartData::new(width, height),
+        }
+    }
+
+    /// Return data to the pool for a later `acquire`
+    pub fn release(&mut self, data: ChartData) {
+        if self.free.len() < self.max_idle {
+            self.free.push(data);
+        }
+    }
+
+    /// Number of entries waiting to be reused
+    pub fn idle(&self) -> usize {
+        self.free.len()
+    }
+
+    /// Bytes held by idle entries
+    pub fn idle_bytes(&self) -> usize {
+        self.free
+            .iter()
+            .map(|data| data.vertices.capacity() * std::mem::size_of::<Vertex>())
+            .sum()
+    }
+
+    /// Free every idle entry
+    pub fn clear(&mut self) {
+        self.free.clear();
+    }
+}
+
+impl Default for ChartDataPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,166 @@
+/// Bytes per row of a texture-to-buffer copy, padded to wgpu's 256-byte
+/// `COPY_BYTES_PER_ROW_ALIGNMENT`
+pub fn padded_bytes_per_row(width: u32, bytes_per_pixel: u32) -> u32 {
+    let unpadded = width * bytes_per_pixel;
+    let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+    unpadded.div_ceil(align) * align
+}
+
+/// Strip row padding from a texture copy, giving tightly packed rows
+///
+/// # Parameters
+/// * `padded` - Mapped buffer contents, `padded_row * height` bytes
+/// * `width`, `height` - Image size in pixels
+/// * `bytes_per_pixel` - 4 for RGBA8/BGRA8 formats
+pub fn unpad_rows(padded: &[u8], width: u32, height: u32, bytes_per_pixel: u32) -> Vec<u8> {
+    let row = (width * bytes_per_pixel) as usize;
+    let padded_row = padded_bytes_per_row(width, bytes_per_pixel) as usize;
+    let mut pixels = Vec::with_capacity(row * height as usize);
+    for chunk in padded.chunks(padded_row).take(height as usize) {
+        pixels.extend_from_slice(&chunk[..row]);
+    }
+    pixels
+}
+
+/// Swap the red and blue channels of packed 4-byte pixels in place
+///
+/// Surfaces are often `Bgra8*`; call this to hand RGBA to image encoders.
+pub fn bgra_to_rgba(pixels: &mut [u8]) {
+    for pixel in pixels.chunks_exact_mut(4) {
+        pixel.swap(0, 2);
+    }
+}
+
+/// Offscreen texture that renderers can draw into and read back
+///
+/// Used for PNG export, array snapshots and rendering tests, where there is
+/// no window surface. The texture is created with `COPY_SRC` so its contents
+/// can be copied into a mappable buffer.
+pub struct RenderTarget {
+    pub texture: wgpu::Texture,
+    pub view: wgpu::TextureView,
+    pub width: u32,
+    pub height: u32,
+    pub format: wgpu::TextureFormat,
+}
+
+impl RenderTarget {
+    pub fn new(device: &wgpu::Device, width: u32, height: u32, format: wgpu::TextureFormat) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Offscreen Render Target"),
+            size: wgpu::Extent3d {
+                width: width.max(1),
+                height: height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        Self {
+            texture,
+            view,
+            width: width.max(1),
+            height: height.max(1),
+            format,
+        }
+    }
+
+    /// Begin a render pass that clears the target to `clear_color`
+    pub fn begin_pass<'e>(
+        &'e self,
+        encoder: &'e mut wgpu::CommandEncoder,
+        clear_color: wgpu::Color,
+    ) -> wgpu::RenderPass<'e> {
+        encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Offscreen Render Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &self.view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(clear_color),
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        })
+    }
+
+    /// Bytes per pixel of the target format
+    pub fn bytes_per_pixel(&self) -> Result<u32, String> {
+        self.format
+            .block_copy_size(None)
+            .ok_or_else(|| format!("Cannot read back pixels of format {:?}", self.format))
+    }
+
+    /// Copy the target's contents to the CPU as tightly packed rows
+    ///
+    /// Resolves after the GPU has finished all previously submitted work.
+    /// Rows are top to bottom in the target's own format (call `bgra_to_rgba`
+    /// for BGRA targets).
+    pub async fn read_pixels_async(&self, device: &wgpu::Device, queue: &wgpu::Queue) -> Result<Vec<u8>, String> {
+        let bytes_per_pixel = self.bytes_per_pixel()?;
+        let padded_row = padded_bytes_per_row(self.width, bytes_per_pixel);
+
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Readback Buffer"),
+            size: padded_row as u64 * self.height as u64,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Readback Encoder"),
+        });
+        encoder.copy_texture_to_buffer(
+            wgpu::ImageCopyTexture {
+                texture: &self.texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_row),
+                    rows_per_image: Some(self.height),
+                },
+            },
+            wgpu::Extent3d {
+                width: self.width,
+                height: self.height,
+                depth_or_array_layers: 1,
+            },
+        );
+        queue.submit(Some(encoder.finish()));
+
+        let (sender, receiver) = futures::channel::oneshot::channel();
+        buffer.slice(..).map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        device.poll(wgpu::Maintain::Wait);
+
+        receiver
+            .await
+            .map_err(|_| "Pixel readback was cancelled".to_string())?
+            .map_err(|e| format!("Failed to map readback buffer: {}", e))?;
+
+        let pixels = unpad_rows(&buffer.slice(..).get_mapped_range(), self.width, self.height, bytes_per_pixel);
+        buffer.unmap();
+        Ok(pixels)
+    }
+
+    /// Blocking version of `read_pixels_async` (native only)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn read_pixels(&self, device: &wgpu::Device, queue: &wgpu::Queue) -> Result<Vec<u8>, String> {
+        futures::executor::block_on(self.read_pixels_async(device, queue))
+    }
+}
@@ -1,10 +1,121 @@
+use crate::error::HelionError;
 use wgpu;
 
+/// How a renderer's output is combined with what is already in the target
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlendMode {
+    /// Standard "over" compositing for straight (non-premultiplied) alpha
+    #[default]
+    Alpha,
+    /// Colors that already have alpha multiplied in
+    Premultiplied,
+    /// Overlapping fragments add up, so dense regions brighten
+    Additive,
+    /// Overwrite the target, ignoring alpha
+    Replace,
+}
+
+impl BlendMode {
+    /// The wgpu blend state for this mode (`None` disables blending)
+    pub fn blend_state(self) -> Option<wgpu::BlendState> {
+        match self {
+            BlendMode::Alpha => Some(wgpu::BlendState::ALPHA_BLENDING),
+            BlendMode::Premultiplied => Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+            BlendMode::Additive => {
+                let add = wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::One,
+                    dst_factor: wgpu::BlendFactor::One,
+                    operation: wgpu::BlendOperation::Add,
+                };
+                Some(wgpu::BlendState { color: add, alpha: add })
+            }
+            BlendMode::Replace => None,
+        }
+    }
+}
+
+/// Pixel rectangle that limits drawing, e.g. to one subplot's plot area
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScissorRect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl ScissorRect {
+    pub fn new(x: u32, y: u32, width: u32, height: u32) -> Self {
+        Self { x, y, width, height }
+    }
+
+    /// Intersection with a `target_width` x `target_height` target
+    ///
+    /// wgpu rejects scissor rects that extend past the target, so rects are
+    /// clipped before use. Returns `None` if nothing is left.
+    pub fn clamped(&self, target_width: u32, target_height: u32) -> Option<ScissorRect> {
+        let x = self.x.min(target_width);
+        let y = self.y.min(target_height);
+        let width = self.width.min(target_width - x);
+        let height = self.height.min(target_height - y);
+        if width == 0 || height == 0 {
+            None
+        } else {
+            Some(ScissorRect { x, y, width, height })
+        }
+    }
+}
+
+/// Depth buffer format used when `RenderOptions::depth_test` is on
+pub const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
+
 /// Render options - shared across all renderer types
-#[derive(Debug, Clone)]
+///
+/// Pipeline-level settings (`sample_count`, `blend`, `depth_test`,
+/// `deterministic`) are baked
+/// into render pipelines when a renderer is created with
+/// `WindowRenderer::with_options`; pass-level settings (`clear_color`,
+/// `scissor`) are applied by whoever begins the render pass, usually through
+/// `RenderAttachments`. Both sides must be built from the same options.
+///
+/// # Example
+/// ```
+/// use helion_core::renderer::{BlendMode, RenderOptions};
+///
+/// let options = RenderOptions::default()
+///     .with_sample_count(4)
+///     .with_blend(BlendMode::Additive);
+/// assert!(options.validate().is_ok());
+/// assert_eq!(options.multisample_state().count, 4);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
 pub struct RenderOptions {
+    /// Background the target is cleared to at the start of each frame
     pub clear_color: wgpu::Color,
-    pub point_size: f32, // TODO: Not currently used, will be implemented in future versions
+    /// MSAA samples per pixel: 1 (off) or 4
+    pub sample_count: u32,
+    /// How fragments are composited
+    pub blend: BlendMode,
+    /// Size in pixels for series that don't specify one
+    pub point_size: f32,
+    /// Attach a depth buffer and depth-test fragments. Geometry is drawn at
+    /// z = 0 with `LessEqual`, so later draws still land on top; this mainly
+    /// lets renderers that write depth share a pass with ones that don't.
+    pub depth_test: bool,
+    /// Restrict drawing to this pixel rectangle
+    pub scissor: Option<ScissorRect>,
+    /// Produce bit-identical images across runs, for snapshot tests and
+    /// reproducible figures
+    ///
+    /// Forces single sampling, since MSAA sample positions and resolve
+    /// filters differ between drivers. Drawing order is already fixed (series
+    /// in submission order, chunks in index order, GPU binning with integer
+    /// atomics), so blended results don't depend on scheduling. Windows
+    /// time animations with `FrameClock::for_options`, which in this mode
+    /// advances a fixed step per frame instead of following the wall clock.
+    pub deterministic: bool,
+    /// Keep the data's proportions when a window is resized, leaving
+    /// margins rather than stretching it (see `PanZoom::with_equal_aspect`)
+    pub equal_aspect: bool,
 }
 
 impl Default for RenderOptions {
@@ -16,15 +127,278 @@ impl Default for RenderOptions {
                 b: 1.0,
                 a: 1.0,
             },
+            sample_count: 1,
+            blend: BlendMode::Alpha,
             point_size: 2.0,
+            depth_test: false,
+            scissor: None,
+            deterministic: false,
+            equal_aspect: false,
+        }
+    }
+}
+
+impl RenderOptions {
+    pub fn with_clear_color(mut self, clear_color: wgpu::Color) -> Self {
+        self.clear_color = clear_color;
+        self
+    }
+
+    pub fn with_sample_count(mut self, sample_count: u32) -> Self {
+        self.sample_count = sample_count;
+        self
+    }
+
+    pub fn with_blend(mut self, blend: BlendMode) -> Self {
+        self.blend = blend;
+        self
+    }
+
+    pub fn with_point_size(mut self, point_size: f32) -> Self {
+        self.point_size = point_size;
+        self
+    }
+
+    pub fn with_depth_test(mut self, depth_test: bool) -> Self {
+        self.depth_test = depth_test;
+        self
+    }
+
+    pub fn with_scissor(mut self, scissor: Option<ScissorRect>) -> Self {
+        self.scissor = scissor;
+        self
+    }
+
+    pub fn with_deterministic(mut self, deterministic: bool) -> Self {
+        self.deterministic = deterministic;
+        self
+    }
+
+    pub fn with_equal_aspect(mut self, equal_aspect: bool) -> Self {
+        self.equal_aspect = equal_aspect;
+        self
+    }
+
+    /// Samples per pixel actually used: `sample_count`, or 1 in
+    /// deterministic mode
+    pub fn effective_sample_count(&self) -> u32 {
+        if self.deterministic {
+            1
+        } else {
+            self.sample_count.max(1)
+        }
+    }
+
+    /// Check the options before they are baked into pipelines
+    ///
+    /// Only sample counts of 1 and 4 are guaranteed by WebGPU; other counts
+    /// would fail pipeline validation on most devices.
+    pub fn validate(&self) -> Result<(), HelionError> {
+        if self.sample_count != 1 && self.sample_count != 4 {
+            return Err(HelionError::InvalidOption(format!(
+                "sample_count must be 1 or 4, got {}",
+                self.sample_count
+            )));
+        }
+        if !(self.point_size.is_finite() && self.point_size > 0.0) {
+            return Err(HelionError::InvalidOption(format!(
+                "point_size must be positive, got {}",
+                self.point_size
+            )));
+        }
+        Ok(())
+    }
+
+    /// Blend state for color targets
+    pub fn blend_state(&self) -> Option<wgpu::BlendState> {
+        self.blend.blend_state()
+    }
+
+    pub fn multisample_state(&self) -> wgpu::MultisampleState {
+        wgpu::MultisampleState {
+            count: self.effective_sample_count(),
+            mask: !0,
+            alpha_to_coverage_enabled: false,
+        }
+    }
+
+    /// Depth state for pipelines, `None` when depth testing is off
+    pub fn depth_stencil_state(&self) -> Option<wgpu::DepthStencilState> {
+        self.depth_test.then(|| wgpu::DepthStencilState {
+            format: DEPTH_FORMAT,
+            depth_write_enabled: true,
+            depth_compare: wgpu::CompareFunction::LessEqual,
+            stencil: wgpu::StencilState::default(),
+            bias: wgpu::DepthBiasState::default(),
+        })
+    }
+
+    /// Set the scissor rect on `render_pass`, if one is configured
+    ///
+    /// A rect entirely outside the target becomes an empty scissor, so
+    /// nothing is drawn.
+    pub fn apply_scissor(&self, render_pass: &mut wgpu::RenderPass<'_>, target_width: u32, target_height: u32) {
+        if let Some(scissor) = self.scissor {
+            match scissor.clamped(target_width, target_height) {
+                Some(rect) => render_pass.set_scissor_rect(rect.x, rect.y, rect.width, rect.height),
+                None => render_pass.set_scissor_rect(0, 0, 0, 0),
+            }
         }
     }
 }
 
+/// Extra textures a render pass needs for a given `RenderOptions`
+///
+/// Holds the multisampled color texture (resolved into the real target) when
+/// MSAA is on and the depth texture when depth testing is on. Recreate it
+/// when the target is resized; `matches` tells whether that is needed.
+pub struct RenderAttachments {
+    msaa_view: Option<wgpu::TextureView>,
+    depth_view: Option<wgpu::TextureView>,
+    width: u32,
+    height: u32,
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+    depth_test: bool,
+}
+
+impl RenderAttachments {
+    pub fn new(
+        device: &wgpu::Device,
+        width: u32,
+        height: u32,
+        format: wgpu::TextureFormat,
+        options: &RenderOptions,
+    ) -> Self {
+        let width = width.max(1);
+        let height = height.max(1);
+        let sample_count = options.effective_sample_count();
+        let create_view = |label: &str, format: wgpu::TextureFormat| {
+            device
+                .create_texture(&wgpu::TextureDescriptor {
+                    label: Some(label),
+                    size: wgpu::Extent3d {
+                        width,
+                        height,
+                        depth_or_array_layers: 1,
+                    },
+                    mip_level_count: 1,
+                    sample_count,
+                    dimension: wgpu::TextureDimension::D2,
+                    format,
+                    usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+                    view_formats: &[],
+                })
+                .create_view(&wgpu::TextureViewDescriptor::default())
+        };
+
+        Self {
+            msaa_view: (sample_count > 1).then(|| create_view("MSAA Color Texture", format)),
+            depth_view: options.depth_test.then(|| create_view("Depth Texture", DEPTH_FORMAT)),
+            width,
+            height,
+            format,
+            sample_count,
+            depth_test: options.depth_test,
+        }
+    }
+
+    /// Whether these attachments can be reused for the given target and options
+    pub fn matches(&self, width: u32, height: u32, format: wgpu::TextureFormat, options: &RenderOptions) -> bool {
+        self.width == width.max(1)
+            && self.height == height.max(1)
+            && self.format == format
+            && self.sample_count == options.effective_sample_count()
+            && self.depth_test == options.depth_test
+    }
+
+    /// Begin a pass drawing into `target`, cleared to `options.clear_color`
+    /// and scissored to `options.scissor`
+    pub fn begin_pass<'e>(
+        &'e self,
+        encoder: &'e mut wgpu::CommandEncoder,
+        target: &'e wgpu::TextureView,
+        options: &RenderOptions,
+    ) -> wgpu::RenderPass<'e> {
+        // With MSAA, draw into the multisampled texture and resolve into the target
+        let (view, resolve_target) = match &self.msaa_view {
+            Some(msaa_view) => (msaa_view, Some(target)),
+            None => (target, None),
+        };
+
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Render Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(options.clear_color),
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: self.depth_view.as_ref().map(|depth_view| {
+                wgpu::RenderPassDepthStencilAttachment {
+                    view: depth_view,
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(1.0),
+                        store: wgpu::StoreOp::Discard,
+                    }),
+                    stencil_ops: None,
+                }
+            }),
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        options.apply_scissor(&mut render_pass, self.width, self.height);
+        render_pass
+    }
+
+    /// Reset the scissor to the whole target before `render_pass` is dropped
+    ///
+    /// Some drivers clip the MSAA resolve to the last scissor rect, which
+    /// would leave everything outside it unresolved (not even cleared). Call
+    /// this after the last draw of a pass begun with `begin_pass`.
+    pub fn end_pass(&self, render_pass: &mut wgpu::RenderPass<'_>) {
+        render_pass.set_scissor_rect(0, 0, self.width, self.height);
+    }
+}
+
 /// Base Renderer trait - common interface for all renderer implementations
 pub trait Renderer {
     /// Render to the provided render pass
     fn render_to_pass<'rpass>(&'rpass mut self, render_pass: &mut wgpu::RenderPass<'rpass>);
+
+    /// What the next `render_to_pass` will draw, plus the bytes uploaded
+    /// since the previous call
+    ///
+    /// Resets the upload counter, so call it once per frame (usually right
+    /// after drawing, into a `stats::FrameRecorder`).
+    fn frame_stats(&mut self) -> crate::stats::DrawStats {
+        crate::stats::DrawStats::default()
+    }
+
+    /// Render one frame into an offscreen target and read it back
+    ///
+    /// # Returns
+    /// Tightly packed rows, top to bottom, in the target's format
+    #[cfg(not(target_arch = "wasm32"))]
+    fn render_to_pixels(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        target: &crate::readback::RenderTarget,
+        clear_color: wgpu::Color,
+    ) -> Result<Vec<u8>, HelionError> {
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Offscreen Render Encoder"),
+        });
+        {
+            let mut render_pass = target.begin_pass(&mut encoder, clear_color);
+            self.render_to_pass(&mut render_pass);
+        }
+        queue.submit(std::iter::once(encoder.finish()));
+        target.read_pixels(device, queue).map_err(HelionError::Readback)
+    }
 }
 
 // ============================================================================
@@ -38,17 +412,51 @@ pub trait Renderer {
 /// - You have direct access to device/queue/surface
 /// - You want simple, self-contained rendering
 pub trait WindowRenderer: Renderer {
-    /// Create a new renderer for window context
+    /// Create a new renderer for window context with default options
     fn new(
         device: &wgpu::Device,
         config: &wgpu::SurfaceConfiguration,
         chart_data: crate::data::ChartData,
     ) -> Self
+    where
+        Self: Sized,
+    {
+        Self::with_options(device, config, chart_data, &RenderOptions::default())
+    }
+
+    /// Create a new renderer whose pipelines use `options`' sample count,
+    /// blend mode and depth settings
+    fn with_options(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        chart_data: crate::data::ChartData,
+        options: &RenderOptions,
+    ) -> Self
     where
         Self: Sized;
 
     /// Update the chart data
-    fn update_data(&mut self, device: &wgpu::Device, chart_data: &crate::data::ChartData);
+    ///
+    /// Renderers that keep their buffers write into them with `queue`
+    /// instead of allocating new ones where they can.
+    fn update_data(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, chart_data: &crate::data::ChartData);
+
+    /// React to the surface being resized; renderers that size things in
+    /// pixels update their viewport here
+    fn resize(&mut self, _queue: &wgpu::Queue, _width: u32, _height: u32) {}
+
+    /// Show a different part of the data; renderers with a camera uniform
+    /// update it here instead of re-uploading their vertices
+    fn set_camera(&mut self, _queue: &wgpu::Queue, _camera: &crate::camera::Camera2D) {}
+
+    /// Advance time-based effects to `time` seconds on the render loop's
+    /// `FrameClock`, called once per frame before drawing
+    ///
+    /// # Returns
+    /// Whether the renderer is still animating, i.e. wants another frame
+    fn set_time(&mut self, _queue: &wgpu::Queue, _time: f32) -> bool {
+        false
+    }
 }
 
 /// WebRenderer trait - specialized for web/WASM contexts
@@ -59,7 +467,7 @@ pub trait WindowRenderer: Renderer {
 /// - You want full control over the render loop
 pub trait WebRenderer: Renderer {
     /// Create a new renderer with GPUBackend
-    fn new(backend: &crate::backend::GPUBackend) -> Result<Self, String>
+    fn new(backend: &crate::backend::GPUBackend) -> Result<Self, HelionError>
     where
         Self: Sized;
 
@@ -69,8 +477,8 @@ pub trait WebRenderer: Renderer {
         backend: &crate::backend::GPUBackend,
         data: &crate::data::ChartData,
         options: &RenderOptions,
-    ) -> Result<(), String>;
+    ) -> Result<(), HelionError>;
 
     /// Update data using backend
-    fn update_data(&mut self, backend: &crate::backend::GPUBackend, data: &crate::data::ChartData) -> Result<(), String>;
+    fn update_data(&mut self, backend: &crate::backend::GPUBackend, data: &crate::data::ChartData) -> Result<(), HelionError>;
 }
@@ -0,0 +1,178 @@
+use crate::error::HelionError;
+use crate::stats::DrawStats;
+use std::ops::Range;
+
+/// Pre-allocated vertex buffer that streamed items are written into in place
+///
+/// Holds up to `capacity` items in one `wgpu::Buffer` created with
+/// `COPY_DST`. `replace` rewrites the contents from the start and `append`
+/// writes after the newest item with `queue.write_buffer`, so neither
+/// allocates. Once full, `append` wraps around and overwrites the oldest
+/// items, which keeps the last `capacity` points of a live series. Items
+/// end up out of order after wrapping, so only order-independent draws
+/// (scatter instances) should use it.
+pub struct RingBuffer {
+    buffer: wgpu::Buffer,
+    capacity: u64,
+    len: u64,
+    /// Slot the next appended item goes to
+    head: u64,
+    item_size: u64,
+}
+
+impl RingBuffer {
+    /// Allocate room for `capacity` items of type `T`
+    ///
+    /// # Returns
+    /// An error if `capacity` is 0, the buffer would exceed the device's
+    /// `max_buffer_size`, or `T` isn't a multiple of 4 bytes (queue writes
+    /// must be aligned)
+    pub fn new<T: bytemuck::Pod>(
+        device: &wgpu::Device,
+        label: &str,
+        capacity: u64,
+        usage: wgpu::BufferUsages,
+    ) -> Result<Self, HelionError> {
+        let item_size = std::mem::size_of::<T>() as u64;
+        if capacity == 0 {
+            return Err(HelionError::InvalidOption(format!("{}: capacity must be at least 1", label)));
+        }
+        if !item_size.is_multiple_of(wgpu::COPY_BUFFER_ALIGNMENT) {
+            return Err(HelionError::InvalidOption(format!(
+                "{}: {}-byte items can't be written in place",
+                label, item_size
+            )));
+        }
+        let size = capacity.saturating_mul(item_size);
+        let max_buffer_size = device.limits().max_buffer_size;
+        if size > max_buffer_size {
+            return Err(HelionError::BufferTooLarge(format!(
+                "{}: {} items of {} bytes exceed the device buffer limit of {} bytes",
+                label, capacity, item_size, max_buffer_size
+            )));
+        }
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(label),
+            size,
+            usage: usage | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        Ok(Self {
+            buffer,
+            capacity,
+            len: 0,
+            head: 0,
+            item_size,
+        })
+    }
+
+    /// Items the buffer can hold
+    pub fn capacity(&self) -> u64 {
+        self.capacity
+    }
+
+    /// Items currently held
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Size of the allocation in bytes
+    pub fn byte_size(&self) -> u64 {
+        self.capacity * self.item_size
+    }
+
+    /// Replace the contents with `items`
+    ///
+    /// # Returns
+    /// Bytes written, or an error if `items` don't fit or aren't the item
+    /// type the buffer was created with
+    pub fn replace<T: bytemuck::Pod>(&mut self, queue: &wgpu::Queue, items: &[T]) -> Result<u64, HelionError> {
+        self.check_type::<T>()?;
+        if items.len() as u64 > self.capacity {
+            return Err(HelionError::InvalidOption(format!(
+                "{} items don't fit in a ring of {}",
+                items.len(),
+                self.capacity
+            )));
+        }
+        queue.write_buffer(&self.buffer, 0, bytemuck::cast_slice(items));
+        self.len = items.len() as u64;
+        self.head = self.len % self.capacity;
+        Ok(self.len * self.item_size)
+    }
+
+    /// Add `items` after the newest one, overwriting the oldest once full
+    ///
+    /// Of more than `capacity` items only the last `capacity` are kept.
+    ///
+    /// # Returns
+    /// Bytes written, or an error if `items` aren't the buffer's item type
+    pub fn append<T: bytemuck::Pod>(&mut self, queue: &wgpu::Queue, items: &[T]) -> Result<u64, HelionError> {
+        self.check_type::<T>()?;
+        let skip = items.len().saturating_sub(self.capacity as usize);
+        let items = &items[skip..];
+        if skip > 0 {
+            self.head = 0;
+        }
+        let mut written = 0;
+        let (tail, wrapped) = wrapped_ranges(self.head, items.len() as u64, self.capacity);
+        for slots in [tail, wrapped].into_iter().filter(|slots| !slots.is_empty()) {
+            let count = (slots.end - slots.start) as usize;
+            let piece = &items[written..written + count];
+            queue.write_buffer(&self.buffer, slots.start * self.item_size, bytemuck::cast_slice(piece));
+            written += count;
+        }
+        self.head = (self.head + items.len() as u64) % self.capacity;
+        self.len = (self.len + items.len() as u64).min(self.capacity);
+        Ok(items.len() as u64 * self.item_size)
+    }
+
+    /// Stats for `draw_instanced` with a `mesh_vertices`-vertex mesh
+    pub fn instanced_stats(&self, mesh_vertices: u32) -> DrawStats {
+        DrawStats::instanced(mesh_vertices as u64, self.len, 1)
+    }
+
+    /// Draw every item held as an instance of `vertices`, binding the
+    /// buffer to `slot`
+    pub fn draw_instanced<'a>(&'a self, render_pass: &mut wgpu::RenderPass<'a>, slot: u32, vertices: Range<u32>) {
+        if self.len == 0 {
+            return;
+        }
+        render_pass.set_vertex_buffer(slot, self.buffer.slice(..self.len * self.item_size));
+        render_pass.draw(vertices, 0..self.len as u32);
+    }
+
+    fn check_type<T>(&self) -> Result<(), HelionError> {
+        let size = std::mem::size_of::<T>() as u64;
+        if size != self.item_size {
+            return Err(HelionError::ShapeMismatch {
+                what: "Ring buffer item size in bytes".to_string(),
+                expected: self.item_size as usize,
+                actual: size as usize,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Slots written when `count` items are appended at `head` of a ring with
+/// `capacity` slots: up to the end, then from the start if the write wraps
+/// around (empty otherwise)
+///
+/// `count` must not exceed `capacity`.
+///
+/// # Example
+/// ```
+/// use helion_core::ring::wrapped_ranges;
+///
+/// assert_eq!(wrapped_ranges(2, 3, 8), (2..5, 0..0));
+/// assert_eq!(wrapped_ranges(6, 4, 8), (6..8, 0..2));
+/// ```
+pub fn wrapped_ranges(head: u64, count: u64, capacity: u64) -> (Range<u64>, Range<u64>) {
+    let end = head + count;
+    (head..end.min(capacity), 0..end.saturating_sub(capacity))
+}
@@ -0,0 +1,407 @@
+use crate::colorspace::ColorSpace;
+use crate::data::{ChartData, Color, Point2D};
+use crate::error::HelionError;
+use crate::figure::DEFAULT_COLOR_CYCLE;
+use crate::text::{HAlign, TextSection, VAlign};
+
+/// Steps along each band's curve, enough for a smooth S at full-screen size
+const BAND_SEGMENTS: usize = 32;
+
+/// A flow of `weight` from node `source` to node `target`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SankeyLink {
+    pub source: usize,
+    pub target: usize,
+    pub weight: f32,
+}
+
+impl SankeyLink {
+    pub fn new(source: usize, target: usize, weight: f32) -> Self {
+        Self { source, target, weight }
+    }
+}
+
+/// Where one link is drawn, in pixels, y down
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SankeyBand {
+    /// Index of the link in `Sankey::links`
+    pub link: usize,
+    /// Right edge of the source node and the top of the band there
+    pub start: [f32; 2],
+    /// Left edge of the target node and the top of the band there
+    pub end: [f32; 2],
+    /// Vertical thickness, proportional to the link's weight
+    pub thickness: f32,
+}
+
+impl SankeyBand {
+    /// Top edge of the band at `t` in [0, 1] along it: a cubic Bézier
+    /// leaving and entering the nodes horizontally
+    pub fn top(&self, t: f32) -> [f32; 2] {
+        let [x0, y0] = self.start;
+        let [x1, y1] = self.end;
+        let u = 1.0 - t;
+        // Control points at the horizontal midpoint, level with each end
+        let xm = (x0 + x1) / 2.0;
+        let x = u * u * u * x0 + 3.0 * u * u * t * xm + 3.0 * u * t * t * xm + t * t * t * x1;
+        let y = (u * u * u + 3.0 * u * u * t) * y0 + (3.0 * u * t * t + t * t * t) * y1;
+        [x, y]
+    }
+}
+
+/// Node rectangles and link bands of a `Sankey` at one viewport size
+#[derive(Debug, Clone, PartialEq)]
+pub struct SankeyLayout {
+    /// `[x, y, width, height]` of each node in pixels, y down
+    pub nodes: Vec<[f32; 4]>,
+    /// One per link, in the order of `Sankey::links`
+    pub bands: Vec<SankeyBand>,
+}
+
+/// Flow diagram: weighted links between nodes arranged in columns, each
+/// drawn as a band as thick as its weight
+///
+/// Nodes are placed in columns by the longest chain of links leading to
+/// them; nodes without outgoing links go in the last column, so every flow
+/// ends at the right edge. A node is as tall as the larger of its inflow
+/// and outflow. Bands fade from the source node's color to the target's.
+///
+/// Links must not form a cycle.
+///
+/// # Example
+/// ```
+/// use helion_core::sankey::{Sankey, SankeyLink};
+///
+/// let labels = ["Coal", "Gas", "Electricity", "Homes", "Industry"];
+/// let links = [
+///     SankeyLink::new(0, 2, 4.0),
+///     SankeyLink::new(1, 2, 2.0),
+///     SankeyLink::new(1, 4, 1.0),
+///     SankeyLink::new(2, 3, 5.0),
+///     SankeyLink::new(2, 4, 1.0),
+/// ];
+/// let sankey = Sankey::new(&labels, &links).unwrap();
+/// assert_eq!(sankey.columns(), [0, 0, 1, 2, 2]);
+/// assert_eq!(sankey.node_values(), vec![4.0, 3.0, 6.0, 5.0, 2.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sankey {
+    labels: Vec<String>,
+    links: Vec<SankeyLink>,
+    columns: Vec<usize>,
+    colors: Vec<Color>,
+    node_width: f32,
+    node_padding: f32,
+    link_alpha: f32,
+    color_space: ColorSpace,
+    font_size: f32,
+}
+
+impl Sankey {
+    /// Diagram of `links` between the nodes named by `labels`
+    ///
+    /// # Parameters
+    /// * `labels` - One per node; a node's index is its position here
+    /// * `links` - Flows between nodes; several links may join the same
+    ///   pair of nodes
+    ///
+    /// # Returns
+    /// `HelionError::InvalidOption` if a link names a node that doesn't
+    /// exist, joins a node to itself, has a negative or non-finite weight,
+    /// or the links form a cycle
+    pub fn new(labels: &[impl AsRef<str>], links: &[SankeyLink]) -> Result<Self, HelionError> {
+        let nodes = labels.len();
+        for (i, link) in links.iter().enumerate() {
+            if link.source >= nodes || link.target >= nodes {
+                return Err(HelionError::InvalidOption(format!(
+                    "Sankey link {} joins nodes {} and {}, but there are {} nodes",
+                    i, link.source, link.target, nodes
+                )));
+            }
+            if link.source == link.target {
+                return Err(HelionError::InvalidOption(format!(
+                    "Sankey link {} joins node {} to itself",
+                    i, link.source
+                )));
+            }
+            if !link.weight.is_finite() || link.weight < 0.0 {
+                return Err(HelionError::InvalidOption(format!(
+                    "Sankey link {} has weight {}; weights must be finite and not negative",
+                    i, link.weight
+                )));
+            }
+        }
+        let columns = assign_columns(nodes, links).ok_or_else(|| {
+            HelionError::InvalidOption("Sankey links must not form a cycle".to_string())
+        })?;
+
+        Ok(Self {
+            labels: labels.iter().map(|label| label.as_ref().to_string()).collect(),
+            links: links.to_vec(),
+            columns,
+            colors: DEFAULT_COLOR_CYCLE.iter().map(|hex| Color::from_hex(hex)).collect(),
+            node_width: 16.0,
+            node_padding: 12.0,
+            link_alpha: 0.45,
+            color_space: ColorSpace::default(),
+            font_size: 12.0,
+        })
+    }
+
+    /// Node fills, cycled when there are fewer colors than nodes; the
+    /// Tableau 10 palette by default
+    pub fn with_colors(mut self, colors: Vec<Color>) -> Self {
+        if !colors.is_empty() {
+            self.colors = colors;
+        }
+        self
+    }
+
+    /// Width of the node bars in pixels, 16 by default
+    pub fn with_node_width(mut self, width: f32) -> Self {
+        self.node_width = width.max(1.0);
+        self
+    }
+
+    /// Vertical gap between nodes of a column in pixels, 12 by default
+    pub fn with_node_padding(mut self, padding: f32) -> Self {
+        self.node_padding = padding.max(0.0);
+        self
+    }
+
+    /// Opacity of the bands, 0.45 by default so crossing flows stay visible
+    pub fn with_link_alpha(mut self, alpha: f32) -> Self {
+        self.link_alpha = alpha.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Space the band gradients are blended in, Oklab by default
+    pub fn with_color_space(mut self, color_space: ColorSpace) -> Self {
+        self.color_space = color_space;
+        self
+    }
+
+    /// Size of the node labels in pixels, 12 by default
+    pub fn with_font_size(mut self, size: f32) -> Self {
+        self.font_size = size;
+        self
+    }
+
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    pub fn links(&self) -> &[SankeyLink] {
+        &self.links
+    }
+
+    /// Column of each node, 0 at the left
+    pub fn columns(&self) -> &[usize] {
+        &self.columns
+    }
+
+    /// Flow through each node: the larger of its inflow and outflow
+    pub fn node_values(&self) -> Vec<f32> {
+        let mut inflow = vec![0.0; self.labels.len()];
+        let mut outflow = vec![0.0; self.labels.len()];
+        for link in &self.links {
+            outflow[link.source] += link.weight;
+            inflow[link.target] += link.weight;
+        }
+        inflow.iter().zip(&outflow).map(|(i, o)| i.max(*o)).collect()
+    }
+
+    /// Fill of node `node`
+    pub fn node_color(&self, node: usize) -> Color {
+        self.colors[node % self.colors.len()]
+    }
+
+    /// Place the nodes and bands in a `width` x `height` viewport
+    ///
+    /// Columns are spread evenly from the left to the right edge and each
+    /// is centered vertically, its nodes in the order they were given.
+    /// Pixels per unit of flow are set by the fullest column. Within a
+    /// node, bands are stacked in the order of the nodes at their other
+    /// end, which keeps them from crossing there.
+    pub fn layout(&self, width: f32, height: f32) -> SankeyLayout {
+        let values = self.node_values();
+        let column_count = self.columns.iter().max().map_or(0, |&c| c + 1);
+        let mut column_nodes = vec![Vec::new(); column_count];
+        for (node, &column) in self.columns.iter().enumerate() {
+            column_nodes[column].push(node);
+        }
+
+        let scale = column_nodes
+            .iter()
+            .filter_map(|nodes| {
+                let total: f32 = nodes.iter().map(|&n| values[n]).sum();
+                let free = height - self.node_padding * nodes.len().saturating_sub(1) as f32;
+                (total > 0.0).then(|| free.max(0.0) / total)
+            })
+            .fold(f32::INFINITY, f32::min);
+        let scale = if scale.is_finite() { scale } else { 0.0 };
+
+        let node_width = self.node_width.min(width);
+        let column_x = |column: usize| match column_count {
+            0 | 1 => (width - node_width) / 2.0,
+            _ => column as f32 * (width - node_width) / (column_count - 1) as f32,
+        };
+        let mut nodes = vec![[0.0; 4]; self.labels.len()];
+        for (column, members) in column_nodes.iter().enumerate() {
+            let used: f32 = members.iter().map(|&n| values[n] * scale).sum::<f32>()
+                + self.node_padding * members.len().saturating_sub(1) as f32;
+            let mut y = (height - used) / 2.0;
+            for &node in members {
+                let node_height = values[node] * scale;
+                nodes[node] = [column_x(column), y, node_width, node_height];
+                y += node_height + self.node_padding;
+            }
+        }
+
+        // Stack bands leaving a node by where they arrive, and bands
+        // arriving by where they leave, so they fan out without crossing
+        let top = |node: usize| nodes[node][1];
+        let mut outgoing: Vec<usize> = (0..self.links.len()).collect();
+        outgoing.sort_by(|&a, &b| top(self.links[a].target).total_cmp(&top(self.links[b].target)));
+        let mut incoming: Vec<usize> = (0..self.links.len()).collect();
+        incoming.sort_by(|&a, &b| top(self.links[a].source).total_cmp(&top(self.links[b].source)));
+
+        let mut start_y = vec![0.0; self.links.len()];
+        let mut cursor: Vec<f32> = nodes.iter().map(|node| node[1]).collect();
+        for &link in &outgoing {
+            let source = self.links[link].source;
+            start_y[link] = cursor[source];
+            cursor[source] += self.links[link].weight * scale;
+        }
+        let mut end_y = vec![0.0; self.links.len()];
+        let mut cursor: Vec<f32> = nodes.iter().map(|node| node[1]).collect();
+        for &link in &incoming {
+            let target = self.links[link].target;
+            end_y[link] = cursor[target];
+            cursor[target] += self.links[link].weight * scale;
+        }
+
+        let bands = self
+            .links
+            .iter()
+            .enumerate()
+            .map(|(i, link)| {
+                let [sx, _, sw, _] = nodes[link.source];
+                let [tx, ..] = nodes[link.target];
+                SankeyBand {
+                    link: i,
+                    start: [sx + sw, start_y[i]],
+                    end: [tx, end_y[i]],
+                    thickness: link.weight * scale,
+                }
+            })
+            .collect();
+        SankeyLayout { nodes, bands }
+    }
+
+    /// Node under pixel (`x`, `y`) of a `width` x `height` viewport, if any
+    pub fn node_at(&self, x: f32, y: f32, width: f32, height: f32) -> Option<usize> {
+        self.layout(width, height)
+            .nodes
+            .iter()
+            .position(|&[nx, ny, nw, nh]| x >= nx && x <= nx + nw && y >= ny && y <= ny + nh)
+    }
+
+    /// Bands, then nodes over them, for `MeshRenderer` in a `width` x
+    /// `height` viewport
+    pub fn chart_data(&self, width: f32, height: f32) -> ChartData {
+        let layout = self.layout(width, height);
+        let mut data = ChartData::new(width, height);
+        let clip = |[x, y]: [f32; 2]| Point2D::new(x / width * 2.0 - 1.0, 1.0 - y / height * 2.0);
+
+        for (band, link) in layout.bands.iter().zip(&self.links) {
+            if band.thickness <= 0.0 {
+                continue;
+            }
+            let from = self.node_color(link.source);
+            let to = self.node_color(link.target);
+            let edge = |t: f32| {
+                let [x, y] = band.top(t);
+                let color = self.color_space.mix(from, to, t);
+                let color = Color { a: color.a * self.link_alpha, ..color };
+                (clip([x, y]), clip([x, y + band.thickness]), color)
+            };
+            let mut previous = edge(0.0);
+            for step in 1..=BAND_SEGMENTS {
+                let next = edge(step as f32 / BAND_SEGMENTS as f32);
+                let (top0, bottom0, color0) = previous;
+                let (top1, bottom1, color1) = next;
+                data.add_point(top0, color0, 0.0);
+                data.add_point(top1, color1, 0.0);
+                data.add_point(bottom1, color1, 0.0);
+                data.add_point(top0, color0, 0.0);
+                data.add_point(bottom1, color1, 0.0);
+                data.add_point(bottom0, color0, 0.0);
+                previous = next;
+            }
+        }
+
+        for (node, &[x, y, w, h]) in layout.nodes.iter().enumerate() {
+            data.add_rect(clip([x, y + h]), clip([x + w, y]), self.node_color(node));
+        }
+        data
+    }
+
+    /// Node labels for `TextRenderer`: beside each node, facing into the
+    /// diagram, so the last column's labels sit to the left of its nodes
+    pub fn text_sections(&self, width: f32, height: f32) -> Vec<TextSection> {
+        let layout = self.layout(width, height);
+        let last_column = self.columns.iter().copied().max().unwrap_or(0);
+        let gap = self.font_size / 2.0;
+        self.labels
+            .iter()
+            .zip(&layout.nodes)
+            .zip(&self.columns)
+            .map(|((label, &[x, y, w, h]), &column)| {
+                let (anchor_x, align) = match column == last_column && last_column > 0 {
+                    true => (x - gap, HAlign::Right),
+                    false => (x + w + gap, HAlign::Left),
+                };
+                TextSection::new(label.clone(), [anchor_x, y + h / 2.0], self.font_size)
+                    .with_align(align, VAlign::Middle)
+            })
+            .collect()
+    }
+}
+
+/// Column of each node: the longest chain of links leading to it, with
+/// nodes that have no outgoing links moved to the last column
+///
+/// `None` if the links form a cycle.
+fn assign_columns(nodes: usize, links: &[SankeyLink]) -> Option<Vec<usize>> {
+    let mut indegree = vec![0usize; nodes];
+    let mut has_outgoing = vec![false; nodes];
+    for link in links {
+        indegree[link.target] += 1;
+        has_outgoing[link.source] = true;
+    }
+
+    // Kahn's algorithm, relaxing each node's column as it is visited
+    let mut columns = vec![0usize; nodes];
+    let mut ready: Vec<usize> = (0..nodes).filter(|&n| indegree[n] == 0).collect();
+    let mut visited = 0;
+    while let Some(node) = ready.pop() {
+        visited += 1;
+        for link in links.iter().filter(|link| link.source == node) {
+            columns[link.target] = columns[link.target].max(columns[node] + 1);
+            indegree[link.target] -= 1;
+            if indegree[link.target] == 0 {
+                ready.push(link.target);
+            }
+        }
+    }
+    if visited < nodes {
+        return None;
+    }
+
+    let last = columns.iter().copied().max().unwrap_or(0);
+    for (column, _) in columns.iter_mut().zip(&has_outgoing).filter(|(_, &out)| !out) {
+        *column = last;
+    }
+    Some(columns)
+}
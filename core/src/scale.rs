@@ -0,0 +1,556 @@
+use crate::axes::{compute_ticks, format_ticks, Ticks};
+
+/// Chart axis identifier
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    X,
+    Y,
+}
+
+/// A data range removed from an axis
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AxisBreak {
+    pub start: f32,
+    pub end: f32,
+}
+
+impl AxisBreak {
+    pub fn new(start: f32, end: f32) -> Self {
+        Self {
+            start: start.min(end),
+            end: start.max(end),
+        }
+    }
+}
+
+/// Axis with one or more discontinuities ("broken axis")
+///
+/// Each break's data range is collapsed to `gap` data units, so a large empty
+/// stretch (e.g. a weekend in intraday data, or an outlier far from the bulk)
+/// no longer wastes screen space. The transform is monotonic: values inside a
+/// break are squeezed linearly into the gap.
+///
+/// Use `compress()` on raw values before normalization, and `expand()` to map
+/// a compressed coordinate back to data space when labelling ticks.
+///
+/// # Example
+/// ```
+/// use helion_core::scale::{AxisBreak, BrokenAxis};
+///
+/// let axis = BrokenAxis::new(vec![AxisBreak::new(10.0, 90.0)], 0.0);
+/// assert_eq!(axis.compress(5.0), 5.0);
+/// assert_eq!(axis.compress(95.0), 15.0); // 80 units removed
+/// assert_eq!(axis.expand(15.0), 95.0);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BrokenAxis {
+    breaks: Vec<AxisBreak>,
+    gap: f32,
+}
+
+impl BrokenAxis {
+    /// Create a broken axis; overlapping or touching breaks are merged
+    pub fn new(mut breaks: Vec<AxisBreak>, gap: f32) -> Self {
+        breaks.sort_by(|a, b| a.start.total_cmp(&b.start));
+
+        let mut merged: Vec<AxisBreak> = Vec::with_capacity(breaks.len());
+        for b in breaks {
+            match merged.last_mut() {
+                Some(last) if b.start <= last.end => last.end = last.end.max(b.end),
+                _ => merged.push(b),
+            }
+        }
+
+        // A break cannot be narrower than the gap it leaves behind
+        let gap = gap.max(0.0);
+        merged.retain(|b| b.end - b.start > gap);
+
+        Self { breaks: merged, gap }
+    }
+
+    pub fn breaks(&self) -> &[AxisBreak] {
+        &self.breaks
+    }
+
+    /// Data units each break is collapsed to
+    pub fn gap(&self) -> f32 {
+        self.gap
+    }
+
+    /// Compressed (start, end) of each break's gap, in order
+    pub fn gaps(&self) -> Vec<(f32, f32)> {
+        self.breaks
+            .iter()
+            .map(|b| {
+                let start = self.compress(b.start);
+                (start, start + self.gap)
+            })
+            .collect()
+    }
+
+    /// Map a data value onto the continuous (compressed) axis
+    pub fn compress(&self, value: f32) -> f32 {
+        let mut removed = 0.0;
+        for b in &self.breaks {
+            if value >= b.end {
+                removed += b.end - b.start - self.gap;
+            } else if value > b.start {
+                let t = (value - b.start) / (b.end - b.start);
+                return b.start - removed + t * self.gap;
+            } else {
+                break;
+            }
+        }
+        value - removed
+    }
+
+    /// Map a compressed coordinate back to data space
+    ///
+    /// Coordinates inside a gap map back into the corresponding break.
+    pub fn expand(&self, compressed: f32) -> f32 {
+        let mut removed = 0.0;
+        for b in &self.breaks {
+            let gap_start = b.start - removed;
+            if compressed <= gap_start {
+                break;
+            }
+            if compressed < gap_start + self.gap {
+                let t = (compressed - gap_start) / self.gap;
+                return b.start + t * (b.end - b.start);
+            }
+            removed += b.end - b.start - self.gap;
+        }
+        compressed + removed
+    }
+
+    /// Compress every value in `values`
+    pub fn compress_all(&self, values: &[f32]) -> Vec<f32> {
+        values.iter().map(|&v| self.compress(v)).collect()
+    }
+
+    /// Output-space positions of the break markers
+    ///
+    /// # Parameters
+    /// * `values` - The raw data plotted on this axis (to find its extent)
+    /// * `out_range` - Output range the compressed data is normalized to,
+    ///   e.g. (-1.0, 1.0) for GPU clip space
+    ///
+    /// # Returns
+    /// The center of each break's gap that falls inside the data extent
+    pub fn marker_positions(&self, values: &[f32], out_range: (f32, f32)) -> Vec<f32> {
+        let compressed = self.compress_all(values);
+        let min = compressed.iter().cloned().fold(f32::INFINITY, f32::min);
+        let max = compressed.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+        let span = max - min;
+        if span.is_nan() || span <= 0.0 {
+            return Vec::new();
+        }
+
+        self.gaps()
+            .into_iter()
+            .map(|(start, end)| (start + end) / 2.0)
+            .filter(|&c| c > min && c < max)
+            .map(|c| (c - min) / span * (out_range.1 - out_range.0) + out_range.0)
+            .collect()
+    }
+}
+
+/// Conventional "//" break marker as two short parallel diagonal strokes
+///
+/// # Parameters
+/// * `axis` - Axis the break is on (strokes cross this axis)
+/// * `position` - Marker position along the axis (output space)
+/// * `cross` - Position of the axis line on the other coordinate
+/// * `size` - Stroke half-length (output space)
+///
+/// # Returns
+/// Two line segments as `[start, end]` point pairs
+pub fn break_marker_segments(axis: Axis, position: f32, cross: f32, size: f32) -> [[[f32; 2]; 2]; 2] {
+    let spacing = size * 0.6;
+    let stroke = |along: f32| -> [[f32; 2]; 2] {
+        match axis {
+            Axis::X => [[along - size / 2.0, cross - size], [along + size / 2.0, cross + size]],
+            Axis::Y => [[cross - size, along - size / 2.0], [cross + size, along + size / 2.0]],
+        }
+    };
+    [stroke(position - spacing / 2.0), stroke(position + spacing / 2.0)]
+}
+
+/// How data values are laid out along an axis
+///
+/// Values go through `forward` before they are normalized, so ranges,
+/// limits and tick positions of a scaled axis are all in forward-mapped
+/// units; `inverse` maps a position back to data for labelling.
+///
+/// # Example
+/// ```
+/// use helion_core::scale::AxisScale;
+///
+/// let log = AxisScale::from_name("log").unwrap();
+/// assert_eq!(log.forward(1000.0), 3.0);
+/// assert!(log.forward(0.0).is_nan()); // not drawn
+/// assert_eq!(log.inverse(-2.0), 0.01);
+///
+/// // Symmetric log keeps zero and negative values
+/// let symlog = AxisScale::from_name("symlog").unwrap();
+/// assert_eq!(symlog.forward(0.0), 0.0);
+/// assert_eq!(symlog.forward(-9.0), -1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AxisScale {
+    #[default]
+    Linear,
+    /// Decades equally spaced; values <= 0 map to NaN and aren't drawn
+    Log10,
+    /// Powers of two equally spaced, e.g. for sizes in bytes; values <= 0
+    /// map to NaN and aren't drawn
+    Log2,
+    /// Logarithmic away from zero and close to linear within `linthresh`
+    /// of it, for data spanning decades on both sides of zero
+    ///
+    /// Maps `v` to `sign(v) * log10(1 + |v| / linthresh)`.
+    #[serde(rename = "symlog")]
+    SymLog { linthresh: f32 },
+    /// Linear in seconds, with ticks on whole minutes, hours or days
+    /// labelled as UTC clock times and dates
+    ///
+    /// Values are seconds after `origin`, itself seconds since the Unix
+    /// epoch: f32 can't hold present-day timestamps to the second, so they
+    /// are stored relative to the start of the data.
+    Time { origin: f64 },
+}
+
+/// Steps a time axis may use below a day, in seconds
+const TIME_STEPS: [f64; 17] = [
+    1.0, 2.0, 5.0, 10.0, 15.0, 30.0, 60.0, 120.0, 300.0, 600.0, 900.0, 1800.0, 3600.0, 7200.0, 10800.0, 21600.0,
+    43200.0,
+];
+
+pub(crate) const SECONDS_PER_DAY: f64 = 86400.0;
+
+impl AxisScale {
+    /// Scale for a name as used by the bindings: "linear", "log", "log2"
+    /// or "symlog" (with a `linthresh` of 1)
+    ///
+    /// Time axes are chosen separately since they need an origin.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "linear" => Some(AxisScale::Linear),
+            "log" | "log10" => Some(AxisScale::Log10),
+            "log2" => Some(AxisScale::Log2),
+            "symlog" => Some(AxisScale::symlog(1.0)),
+            _ => None,
+        }
+    }
+
+    /// Symmetric log axis, roughly linear within `linthresh` of zero; a
+    /// `linthresh` that isn't positive becomes 1
+    pub fn symlog(linthresh: f32) -> Self {
+        let linthresh = if linthresh > 0.0 && linthresh.is_finite() { linthresh } else { 1.0 };
+        AxisScale::SymLog { linthresh }
+    }
+
+    /// Time axis for values that are offsets from the Unix timestamp
+    /// `origin`
+    pub fn time(origin: f64) -> Self {
+        AxisScale::Time { origin }
+    }
+
+    /// Map a data value to its position along the axis
+    pub fn forward(self, value: f32) -> f32 {
+        match self {
+            AxisScale::Linear | AxisScale::Time { .. } => value,
+            AxisScale::Log10 if value > 0.0 => value.log10(),
+            AxisScale::Log2 if value > 0.0 => value.log2(),
+            AxisScale::Log10 | AxisScale::Log2 => f32::NAN,
+            AxisScale::SymLog { linthresh } => value.signum() * (value.abs() / linthresh).ln_1p() / std::f32::consts::LN_10,
+        }
+    }
+
+    /// Map an axis position back to a data value
+    pub fn inverse(self, position: f32) -> f32 {
+        match self {
+            AxisScale::Linear | AxisScale::Time { .. } => position,
+            AxisScale::Log10 => 10f32.powf(position),
+            AxisScale::Log2 => position.exp2(),
+            AxisScale::SymLog { linthresh } => position.signum() * linthresh * (position.abs() * std::f32::consts::LN_10).exp_m1(),
+        }
+    }
+
+    /// `forward` every value
+    pub fn forward_all(self, values: &[f32]) -> Vec<f32> {
+        values.iter().map(|&v| self.forward(v)).collect()
+    }
+
+    /// Tick positions covering the axis positions [min, max]
+    ///
+    /// Linear axes use `compute_ticks`. Log axes put major ticks on whole
+    /// decades (powers of two for `Log2`), with minor ticks at 2-9 times
+    /// each decade when `minor_subdivisions` > 1; ranges narrower than a
+    /// decade get linear ticks in data units instead. Symmetric log axes
+    /// tick zero and whole decades on either side of it. Time axes step by
+    /// whole seconds, minutes, hours or days, aligned to UTC.
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::scale::AxisScale;
+    ///
+    /// // 1 to 100000
+    /// let ticks = AxisScale::Log10.ticks(0.0, 5.0, 6, 0);
+    /// assert_eq!(ticks.major, [0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+    /// assert_eq!(AxisScale::Log10.tick_labels(&ticks), ["1", "10", "100", "1000", "10000", "100000"]);
+    /// ```
+    pub fn ticks(self, min: f32, max: f32, target: usize, minor_subdivisions: usize) -> Ticks {
+        match self {
+            AxisScale::Linear => compute_ticks(min, max, target, minor_subdivisions),
+            AxisScale::Log10 | AxisScale::Log2 => log_ticks(self, min, max, target, minor_subdivisions),
+            AxisScale::SymLog { .. } => symlog_ticks(self, min, max, target, minor_subdivisions),
+            AxisScale::Time { origin } => time_ticks(origin, min, max, target, minor_subdivisions),
+        }
+    }
+
+    /// Labels for the major ticks of `ticks`, computed with `ticks`
+    pub fn tick_labels(self, ticks: &Ticks) -> Vec<String> {
+        match self {
+            AxisScale::Linear => format_ticks(&ticks.major, ticks.step),
+            AxisScale::Log10 | AxisScale::Log2 => {
+                let decades = ticks.step >= 1.0 && ticks.major.iter().all(|v| v.fract() == 0.0);
+                if decades && self == AxisScale::Log2 {
+                    ticks.major.iter().map(|&v| format_power_of_two(v as i32)).collect()
+                } else if decades {
+                    ticks.major.iter().map(|&v| format_decade(v as i32)).collect()
+                } else {
+                    let values: Vec<f32> = ticks.major.iter().map(|&v| self.inverse(v)).collect();
+                    format_ticks(&values, ticks.step)
+                }
+            }
+            AxisScale::SymLog { .. } => {
+                let values: Vec<f32> = ticks.major.iter().map(|&v| self.inverse(v)).collect();
+                values
+                    .iter()
+                    .map(|&v| format_signed_decade(v))
+                    .collect::<Option<Vec<_>>>()
+                    .unwrap_or_else(|| format_ticks(&values, ticks.step))
+            }
+            AxisScale::Time { origin } => ticks
+                .major
+                .iter()
+                .map(|&v| format_time(origin + v as f64, ticks.step as f64))
+                .collect(),
+        }
+    }
+}
+
+/// Ticks of a `Log10` or `Log2` axis, whose positions count decades or
+/// powers of two
+fn log_ticks(scale: AxisScale, min: f32, max: f32, target: usize, minor_subdivisions: usize) -> Ticks {
+    if !min.is_finite() || !max.is_finite() || max <= min {
+        return Ticks::default();
+    }
+    if max - min < 1.0 {
+        // Under a decade, decades would leave at most one tick
+        let linear = compute_ticks(scale.inverse(min), scale.inverse(max), target, minor_subdivisions);
+        return Ticks {
+            major: linear.major.iter().map(|&v| scale.forward(v)).collect(),
+            minor: linear.minor.iter().map(|&v| scale.forward(v)).collect(),
+            step: linear.step,
+        };
+    }
+
+    let step = ((max - min) / target.max(1) as f32).ceil().max(1.0) as i32;
+    let (first, last) = (min.ceil() as i32, max.floor() as i32);
+    let major = (first..=last)
+        .filter(|decade| decade.rem_euclid(step) == 0)
+        .map(|decade| decade as f32)
+        .collect();
+    let mut minor = Vec::new();
+    // Between powers of two the only whole multiple is 1.5x, so only
+    // decades get minor ticks
+    if step == 1 && minor_subdivisions > 1 && scale == AxisScale::Log10 {
+        for decade in min.floor() as i32..=last {
+            minor.extend(
+                (2..10)
+                    .map(|k| decade as f32 + (k as f32).log10())
+                    .filter(|&v| v >= min && v <= max),
+            );
+        }
+    }
+    Ticks {
+        major,
+        minor,
+        step: step as f32,
+    }
+}
+
+/// Ticks of a `SymLog` axis: zero and whole decades of either sign, at
+/// least `linthresh` from zero
+///
+/// Ranges holding fewer than two such values get linear ticks in data
+/// units instead.
+fn symlog_ticks(scale: AxisScale, min: f32, max: f32, target: usize, minor_subdivisions: usize) -> Ticks {
+    let AxisScale::SymLog { linthresh } = scale else {
+        return Ticks::default();
+    };
+    if !min.is_finite() || !max.is_finite() || max <= min {
+        return Ticks::default();
+    }
+    let (low, high) = (scale.inverse(min), scale.inverse(max));
+    let first = linthresh.log10().ceil() as i32;
+    // Decades reached on each side of zero, nearest to zero first
+    let side = |extent: f32| -> Vec<i32> {
+        if extent < 10f32.powi(first) {
+            return Vec::new();
+        }
+        (first..=extent.log10().floor() as i32).collect()
+    };
+    let (negative, positive) = (side(-low), side(high));
+    let zero = low <= 0.0 && high >= 0.0;
+    let count = negative.len() + positive.len() + usize::from(zero);
+    if count < 2 {
+        let linear = compute_ticks(low, high, target, minor_subdivisions);
+        return Ticks {
+            major: linear.major.iter().map(|&v| scale.forward(v)).collect(),
+            minor: linear.minor.iter().map(|&v| scale.forward(v)).collect(),
+            step: linear.step,
+        };
+    }
+
+    let step = count.div_ceil(target.max(1)) as i32;
+    let kept = |decades: &[i32]| -> Vec<i32> { decades.iter().copied().filter(|d| d.rem_euclid(step) == 0).collect() };
+    let position = |sign: f32, decade: i32| scale.forward(sign * 10f32.powi(decade));
+    let mut major: Vec<f32> = kept(&negative).into_iter().rev().map(|d| position(-1.0, d)).collect();
+    if zero {
+        major.push(0.0);
+    }
+    major.extend(kept(&positive).into_iter().map(|d| position(1.0, d)));
+
+    let mut minor = Vec::new();
+    if step == 1 && minor_subdivisions > 1 {
+        for (sign, decades) in [(-1.0, &negative), (1.0, &positive)] {
+            // The decade below the first tick still has minor ticks in range
+            let below = decades.first().map(|d| d - 1).into_iter();
+            for decade in below.chain(decades.iter().copied()) {
+                minor.extend(
+                    (2..10)
+                        .map(|k| sign * k as f32 * 10f32.powi(decade))
+                        .filter(|&v| v.abs() >= linthresh && v >= low && v <= high)
+                        .map(|v| scale.forward(v)),
+                );
+            }
+        }
+        minor.sort_by(f32::total_cmp);
+    }
+    Ticks {
+        major,
+        minor,
+        step: step as f32,
+    }
+}
+
+fn time_ticks(origin: f64, min: f32, max: f32, target: usize, minor_subdivisions: usize) -> Ticks {
+    if !min.is_finite() || !max.is_finite() || max <= min {
+        return Ticks::default();
+    }
+    let raw = (max - min) as f64 / target.max(1) as f64;
+    if raw < 1.0 {
+        // Sub-second spans: plain decimal steps, still aligned to the epoch
+        let shifted = compute_ticks((origin.fract() + min as f64) as f32, (origin.fract() + max as f64) as f32, target, minor_subdivisions);
+        let offset = origin.fract() as f32;
+        return Ticks {
+            major: shifted.major.iter().map(|v| v - offset).collect(),
+            minor: shifted.minor.iter().map(|v| v - offset).collect(),
+            step: shifted.step,
+        };
+    }
+    let step = match TIME_STEPS.iter().find(|&&step| step >= raw) {
+        Some(&step) => step,
+        None => (raw / SECONDS_PER_DAY).ceil() * SECONDS_PER_DAY,
+    };
+    let (start, end) = (origin + min as f64, origin + max as f64);
+    let multiples = |step: f64| ((start / step).ceil() as i64)..=((end / step).floor() as i64);
+    let major = multiples(step).map(|i| (i as f64 * step - origin) as f32).collect();
+    let mut minor = Vec::new();
+    if minor_subdivisions > 1 {
+        let minor_step = step / minor_subdivisions as f64;
+        minor = multiples(minor_step)
+            .filter(|i| i % minor_subdivisions as i64 != 0)
+            .map(|i| (i as f64 * minor_step - origin) as f32)
+            .collect();
+    }
+    Ticks {
+        major,
+        minor,
+        step: step as f32,
+    }
+}
+
+/// 10^decade written out for small exponents, as "1e-5" beyond
+fn format_decade(decade: i32) -> String {
+    match decade {
+        0..=5 => format!("1{}", "0".repeat(decade as usize)),
+        -4..=-1 => format!("0.{}1", "0".repeat((-decade - 1) as usize)),
+        _ => format!("1e{}", decade),
+    }
+}
+
+/// 2^power written out for small exponents, as "2^20" beyond
+fn format_power_of_two(power: i32) -> String {
+    match power {
+        0..=16 => (1u32 << power).to_string(),
+        -4..=-1 => format!("{}", 0.5f32.powi(-power)),
+        _ => format!("2^{}", power),
+    }
+}
+
+/// Zero or a value within rounding of plus or minus a whole decade,
+/// written like `format_decade`; None for any other value
+fn format_signed_decade(value: f32) -> Option<String> {
+    if value.abs() < f32::EPSILON {
+        return Some("0".to_string());
+    }
+    let exponent = value.abs().log10();
+    if (exponent - exponent.round()).abs() > 1e-3 {
+        return None;
+    }
+    let sign = if value < 0.0 { "-" } else { "" };
+    Some(format!("{}{}", sign, format_decade(exponent.round() as i32)))
+}
+
+/// A Unix timestamp as a UTC date for day steps, else as a time of day
+/// with as many second decimals as `step` needs
+fn format_time(timestamp: f64, step: f64) -> String {
+    // Round to the tick spacing first so f32 error doesn't show as 11:59:59
+    let timestamp = if step >= 1.0 { timestamp.round() } else { timestamp };
+    let days = (timestamp / SECONDS_PER_DAY).floor();
+    if step >= SECONDS_PER_DAY {
+        let (year, month, day) = civil_from_days(days as i64);
+        return format!("{:04}-{:02}-{:02}", year, month, day);
+    }
+    let seconds = timestamp - days * SECONDS_PER_DAY;
+    let (hours, minutes) = ((seconds / 3600.0) as u32, (seconds % 3600.0 / 60.0) as u32);
+    if step >= 60.0 {
+        return format!("{:02}:{:02}", hours, minutes);
+    }
+    let secs = seconds % 60.0;
+    if step >= 1.0 {
+        return format!("{:02}:{:02}:{:02}", hours, minutes, secs as u32);
+    }
+    let decimals = (-step.log10().floor()).max(0.0) as usize;
+    format!("{:02}:{:02}:{:0width$.*}", hours, minutes, decimals, secs, width = decimals + 3)
+}
+
+/// (year, month, day) of the proleptic Gregorian calendar for days since
+/// 1970-01-01, after Howard Hinnant's `civil_from_days`
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
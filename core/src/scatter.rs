@@ -1,91 +1,172 @@
-use crate::data::{ChartData, Vertex};
-use crate::renderer::{Renderer, WindowRenderer, WebRenderer, RenderOptions};
+use crate::data::{AppendLog, ChartData, Color, PositionVertex, Vertex};
+use crate::renderer::{BlendMode, Renderer, WindowRenderer, WebRenderer, RenderAttachments, RenderOptions};
 use crate::backend::GPUBackend;
-use crate::shaders::{SIMPLE_VERTEX_SHADER, SIMPLE_FRAGMENT_SHADER};
+use crate::camera::{Camera2D, CameraBinding, CAMERA_GROUP};
+use crate::chunked::ChunkedBuffer;
+use crate::error::HelionError;
+use crate::ring::RingBuffer;
+use crate::shaders::{POSITION_ONLY_VERTEX_SHADER, SCATTER_FRAGMENT_SHADER, SCATTER_VERTEX_SHADER};
+use crate::stats::DrawStats;
 use wgpu::util::DeviceExt;
 
+/// Corners of the two triangles drawn for every point
+const QUAD_VERTICES: u32 = 6;
+
+/// Smallest instance ring allocated, so short streams don't regrow often
+const MIN_RING_CAPACITY: u64 = 1024;
+
+/// Where a `ScatterRenderer` keeps its instances
+enum Instances {
+    /// Rewritten in place; `synced` is the append log of the data last
+    /// written, to upload only points appended since
+    Ring { buffer: RingBuffer, synced: Option<AppendLog> },
+    /// Split over several buffers, for data too large for one
+    Chunked(ChunkedBuffer),
+}
+
+impl Instances {
+    fn draw<'a>(&'a self, render_pass: &mut wgpu::RenderPass<'a>) {
+        match self {
+            Instances::Ring { buffer, .. } => buffer.draw_instanced(render_pass, 0, 0..QUAD_VERTICES),
+            Instances::Chunked(buffer) => buffer.draw_instanced(render_pass, 0, 0..QUAD_VERTICES),
+        }
+    }
+
+    fn stats(&self) -> DrawStats {
+        match self {
+            Instances::Ring { buffer, .. } => buffer.instanced_stats(QUAD_VERTICES),
+            Instances::Chunked(buffer) => buffer.instanced_stats(QUAD_VERTICES),
+        }
+    }
+}
+
+/// Uniform block layout shared with `SCATTER_VERTEX_SHADER` and
+/// `SCATTER_FRAGMENT_SHADER`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct ScatterUniforms {
+    pub viewport: [f32; 2],
+    /// 1.0 if edge coverage scales the color as well as alpha
+    pub premultiplied: f32,
+    /// 1.0 to cut edges at half coverage instead of fading them
+    pub hard_edge: f32,
+}
+
+impl ScatterUniforms {
+    /// Uniforms for a `width` x `height` target whose pipeline blends with `blend`
+    pub fn new(width: f32, height: f32, blend: BlendMode) -> Self {
+        let flag = |on: bool| if on { 1.0 } else { 0.0 };
+        Self {
+            viewport: [width, height],
+            premultiplied: flag(matches!(blend, BlendMode::Premultiplied | BlendMode::Additive)),
+            hard_edge: flag(blend == BlendMode::Replace),
+        }
+    }
+}
+
 /// Scatter plot renderer - implements both WindowRenderer and WebRenderer traits
 /// 
 /// This dual implementation allows the same renderer to work in:
 /// - Native window contexts (Python bindings, desktop apps)
 /// - Web contexts (WASM, browser-based apps)
 /// 
+/// Every point is drawn as an anti-aliased circle `size` pixels across (at
+/// least one pixel), on an instanced quad, so per-point sizes from
+/// `ChartData::set_sizes` give bubble charts. Call `resize` when the surface
+/// size changes to keep sizes in pixels, and `set_camera` to pan or zoom
+/// without re-uploading vertices.
+///
+/// `update_data` writes into a pre-allocated ring buffer with
+/// `queue.write_buffer`, reallocating only when the data outgrows it. For
+/// data grown with `ChartData::append_points` only the new points are
+/// written, and with `ChartData::set_max_points` the ring holds exactly
+/// that rolling window, overwriting the oldest points in place.
+/// 
 /// Design principles:
 /// - Trait composition: Implements multiple specialized interfaces
 /// - Context-agnostic core: Same rendering logic for all platforms
 /// - Resource encapsulation: Manages its own GPU resources
 pub struct ScatterRenderer {
     render_pipeline: wgpu::RenderPipeline,
-    vertex_buffer: Option<wgpu::Buffer>,
-    vertex_count: u32,
+    bind_group_layout: wgpu::BindGroupLayout,
+    uniform_buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+    uniforms: ScatterUniforms,
+    camera: CameraBinding,
+    /// One instance per point
+    instances: Option<Instances>,
+    /// Options the pipeline was built with
+    options: RenderOptions,
+    /// MSAA/depth textures for the web path, which owns its render pass
+    attachments: Option<RenderAttachments>,
+    /// Bytes uploaded since the last `frame_stats`
+    uploaded_bytes: u64,
 }
 
-// ============================================================================
-// Base Renderer Implementation - Common to all contexts
-// ============================================================================
-
-impl Renderer for ScatterRenderer {
-    fn render_to_pass<'rpass>(&'rpass mut self, render_pass: &mut wgpu::RenderPass<'rpass>) {
-        render_pass.set_pipeline(&self.render_pipeline);
-        
-        if let Some(ref buffer) = self.vertex_buffer {
-            render_pass.set_vertex_buffer(0, buffer.slice(..));
-            render_pass.draw(0..self.vertex_count, 0..1);
-        }
+impl ScatterRenderer {
+    fn create_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+        device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Scatter Bind Group Layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        })
     }
-}
-
-// ============================================================================
-// WindowRenderer Implementation - For native window contexts
-// ============================================================================
 
-impl WindowRenderer for ScatterRenderer {
-    /// Create a new scatter renderer for window context
-    fn new(
+    fn create_pipeline(
         device: &wgpu::Device,
-        config: &wgpu::SurfaceConfiguration,
-        chart_data: ChartData,
-    ) -> Self {
+        format: wgpu::TextureFormat,
+        bind_group_layout: &wgpu::BindGroupLayout,
+        camera_layout: &wgpu::BindGroupLayout,
+        options: &RenderOptions,
+    ) -> wgpu::RenderPipeline {
         // Create shader modules
         let vertex_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("Scatter Vertex Shader"),
-            source: wgpu::ShaderSource::Wgsl(SIMPLE_VERTEX_SHADER.into()),
+            source: wgpu::ShaderSource::Wgsl(SCATTER_VERTEX_SHADER.into()),
         });
 
         let fragment_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("Scatter Fragment Shader"),
-            source: wgpu::ShaderSource::Wgsl(SIMPLE_FRAGMENT_SHADER.into()),
+            source: wgpu::ShaderSource::Wgsl(SCATTER_FRAGMENT_SHADER.into()),
         });
 
         // Create pipeline layout
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("Scatter Pipeline Layout"),
-            bind_group_layouts: &[],
+            bind_group_layouts: &[bind_group_layout, camera_layout],
             push_constant_ranges: &[],
         });
 
         // Create render pipeline with the surface's texture format
-        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
             label: Some("Scatter Render Pipeline"),
             layout: Some(&pipeline_layout),
             vertex: wgpu::VertexState {
                 module: &vertex_shader,
                 entry_point: "vs_main",
-                buffers: &[Vertex::desc()],
+                buffers: &[Vertex::instance_desc()],
                 compilation_options: Default::default(),
             },
             fragment: Some(wgpu::FragmentState {
                 module: &fragment_shader,
                 entry_point: "fs_main",
                 targets: &[Some(wgpu::ColorTargetState {
-                    format: config.format,  // Use surface format
-                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    format,
+                    blend: options.blend_state(),
                     write_mask: wgpu::ColorWrites::ALL,
                 })],
                 compilation_options: Default::default(),
             }),
             primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::PointList,
+                topology: wgpu::PrimitiveTopology::TriangleList,
                 strip_index_format: None,
                 front_face: wgpu::FrontFace::Ccw,
                 cull_mode: None,
@@ -93,86 +174,483 @@ impl WindowRenderer for ScatterRenderer {
                 unclipped_depth: false,
                 conservative: false,
             },
-            depth_stencil: None,
-            multisample: wgpu::MultisampleState {
-                count: 1,
-                mask: !0,
-                alpha_to_coverage_enabled: false,
-            },
+            depth_stencil: options.depth_stencil_state(),
+            multisample: options.multisample_state(),
             multiview: None,
             cache: None,
+        })
+    }
+
+    /// Pipeline, uniform buffer and bind group for a `width` x `height` target
+    fn build(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        width: u32,
+        height: u32,
+        options: &RenderOptions,
+    ) -> Self {
+        let bind_group_layout = Self::create_bind_group_layout(device);
+        let camera = CameraBinding::new(device);
+        let render_pipeline = Self::create_pipeline(device, format, &bind_group_layout, camera.layout(), options);
+
+        let uniforms = ScatterUniforms::new(width as f32, height as f32, options.blend);
+        let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Scatter Uniform Buffer"),
+            contents: bytemuck::bytes_of(&uniforms),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
         });
 
-        // Create vertex buffer with initial data
-        let vertices = &chart_data.vertices;
-        let vertex_buffer = if !vertices.is_empty() {
-            Some(device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                label: Some("Scatter Vertex Buffer"),
-                contents: bytemuck::cast_slice(&vertices),
-                usage: wgpu::BufferUsages::VERTEX,
-            }))
-        } else {
-            None
-        };
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Scatter Bind Group"),
+            layout: &bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: uniform_buffer.as_entire_binding(),
+            }],
+        });
 
         ScatterRenderer {
             render_pipeline,
-            vertex_buffer,
-            vertex_count: vertices.len() as u32,
+            bind_group_layout,
+            uniform_buffer,
+            bind_group,
+            uniforms,
+            camera,
+            instances: None,
+            options: options.clone(),
+            attachments: None,
+            uploaded_bytes: 0,
+        }
+    }
+
+    /// Bring the instances up to date with `data`
+    ///
+    /// Writes into the current ring if the data fits, only the points
+    /// appended since the last upload if its `AppendLog` says so, and
+    /// allocates a new ring (or chunked buffers, past one buffer's size)
+    /// otherwise.
+    fn upload(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, data: &ChartData) -> Result<(), HelionError> {
+        let vertices = &data.vertices;
+        let len = vertices.len() as u64;
+        let log = data.append_log();
+        let fits = |ring: &RingBuffer| match data.max_points() {
+            Some(max) => ring.capacity() == max as u64,
+            None => len <= ring.capacity(),
+        };
+
+        if let Some(Instances::Ring { buffer, synced }) = &mut self.instances {
+            if fits(buffer) {
+                let appended = match (log, *synced) {
+                    (Some(log), Some(seen)) if log.stream == seen.stream && log.appended >= seen.appended => {
+                        Some(log.appended - seen.appended)
+                    }
+                    _ => None,
+                };
+                self.uploaded_bytes += match appended {
+                    // The new points are the last ones still held
+                    Some(new) if new <= len => buffer.append(queue, &vertices[(len - new) as usize..])?,
+                    _ => buffer.replace(queue, vertices)?,
+                };
+                *synced = log;
+                return Ok(());
+            }
+        }
+        if len == 0 && data.max_points().is_none() {
+            self.instances = None;
+            return Ok(());
+        }
+
+        let item_size = std::mem::size_of::<Vertex>() as u64;
+        let max_items = device.limits().max_buffer_size / item_size;
+        let capacity = match data.max_points() {
+            Some(max) => (max as u64).max(1),
+            None => len.next_power_of_two().max(MIN_RING_CAPACITY).min(max_items),
+        };
+        if capacity < len || capacity > max_items {
+            let mut chunked = match self.instances.take() {
+                Some(Instances::Chunked(buffer)) => Some(buffer),
+                _ => None,
+            };
+            self.uploaded_bytes += ChunkedBuffer::update(
+                &mut chunked,
+                device,
+                queue,
+                "Scatter Vertex Buffer",
+                vertices,
+                wgpu::BufferUsages::VERTEX,
+                1,
+            )?;
+            self.instances = chunked.map(Instances::Chunked);
+            return Ok(());
         }
+        let mut buffer = RingBuffer::new::<Vertex>(device, "Scatter Vertex Buffer", capacity, wgpu::BufferUsages::VERTEX)?;
+        self.uploaded_bytes += buffer.replace(queue, vertices)?;
+        self.instances = Some(Instances::Ring { buffer, synced: log });
+        Ok(())
+    }
+
+    /// Instances currently allocated, in points: the capacity of the ring
+    /// or of the chunked buffers
+    pub fn capacity(&self) -> u64 {
+        match &self.instances {
+            Some(Instances::Ring { buffer, .. }) => buffer.capacity(),
+            Some(Instances::Chunked(buffer)) => buffer.capacity(),
+            None => 0,
+        }
+    }
+
+    /// Options the render pipeline was built with
+    pub fn options(&self) -> &RenderOptions {
+        &self.options
+    }
+
+    /// Keep point sizes in pixels correct after the surface is resized
+    pub fn resize(&mut self, queue: &wgpu::Queue, width: u32, height: u32) {
+        let uniforms = ScatterUniforms::new(width as f32, height as f32, self.options.blend);
+        self.write_uniforms(queue, uniforms);
     }
 
-    /// Update the vertex data
-    fn update_data(&mut self, device: &wgpu::Device, chart_data: &ChartData) {
-        let vertices = &chart_data.vertices;
+    /// Pan or zoom by uploading a new view matrix; vertices are untouched
+    pub fn set_camera(&mut self, queue: &wgpu::Queue, camera: &Camera2D) {
+        self.uploaded_bytes += self.camera.set(queue, camera);
+    }
+
+    /// Camera the points are currently drawn with
+    pub fn camera(&self) -> &Camera2D {
+        self.camera.camera()
+    }
+
+    fn write_uniforms(&mut self, queue: &wgpu::Queue, uniforms: ScatterUniforms) {
+        if uniforms != self.uniforms {
+            self.uniforms = uniforms;
+            queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&self.uniforms));
+            self.uploaded_bytes += std::mem::size_of::<ScatterUniforms>() as u64;
+        }
+    }
+
+    /// Whether switching from `built` to `requested` needs a new pipeline
+    fn needs_rebuild(built: &RenderOptions, requested: &RenderOptions) -> bool {
+        built.effective_sample_count() != requested.effective_sample_count()
+            || built.blend != requested.blend
+            || built.depth_test != requested.depth_test
+    }
+}
+
+// ============================================================================
+// Base Renderer Implementation - Common to all contexts
+// ============================================================================
+
+impl Renderer for ScatterRenderer {
+    fn render_to_pass<'rpass>(&'rpass mut self, render_pass: &mut wgpu::RenderPass<'rpass>) {
+        render_pass.set_pipeline(&self.render_pipeline);
+        render_pass.set_bind_group(0, &self.bind_group, &[]);
+        render_pass.set_bind_group(CAMERA_GROUP, self.camera.bind_group(), &[]);
         
-        if !vertices.is_empty() {
-            self.vertex_buffer = Some(device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-                label: Some("Scatter Vertex Buffer"),
-                contents: bytemuck::cast_slice(&vertices),
-                usage: wgpu::BufferUsages::VERTEX,
-            }));
-            self.vertex_count = vertices.len() as u32;
-        } else {
-            self.vertex_buffer = None;
-            self.vertex_count = 0;
+        if let Some(ref instances) = self.instances {
+            instances.draw(render_pass);
+        }
+    }
+
+    fn frame_stats(&mut self) -> DrawStats {
+        let stats = self.instances.as_ref().map_or_else(DrawStats::default, Instances::stats);
+        stats.with_bytes_uploaded(std::mem::take(&mut self.uploaded_bytes))
+    }
+}
+
+// ============================================================================
+// WindowRenderer Implementation - For native window contexts
+// ============================================================================
+
+impl WindowRenderer for ScatterRenderer {
+    /// Create a new scatter renderer for window context
+    fn with_options(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        chart_data: ChartData,
+        options: &RenderOptions,
+    ) -> Self {
+        let mut renderer = Self::build(device, config.format, config.width, config.height, options);
+
+        // Create vertex buffer(s) with initial data; there's no queue to
+        // fill a ring with yet, so the first update_data allocates one
+        let buffer = ChunkedBuffer::upload_or_log(
+            device,
+            "Scatter Vertex Buffer",
+            &chart_data.vertices,
+            wgpu::BufferUsages::VERTEX,
+            1,
+        );
+        renderer.uploaded_bytes = buffer.as_ref().map_or(0, ChunkedBuffer::byte_size);
+        renderer.instances = buffer.map(Instances::Chunked);
+        renderer
+    }
+
+    /// Update the vertex data, in place where it fits
+    fn update_data(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, chart_data: &ChartData) {
+        if let Err(e) = self.upload(device, queue, chart_data) {
+            log::error!("{}", e);
         }
     }
+
+    fn resize(&mut self, queue: &wgpu::Queue, width: u32, height: u32) {
+        ScatterRenderer::resize(self, queue, width, height);
+    }
+
+    fn set_camera(&mut self, queue: &wgpu::Queue, camera: &Camera2D) {
+        ScatterRenderer::set_camera(self, queue, camera);
+    }
 }
 
 // ============================================================================
 // WebRenderer Implementation - For web/WASM contexts
 // ============================================================================
 
+impl ScatterRenderer {
+    /// Everything `render_with_backend` does before drawing: upload `data`,
+    /// rebuild the pipeline for `options` and size the points for the
+    /// surface
+    ///
+    /// For drawing the points into a pass shared with other renderers, e.g.
+    /// a legend on top, with `render_to_pass`.
+    pub fn prepare(&mut self, backend: &GPUBackend, data: &ChartData, options: &RenderOptions) -> Result<(), HelionError> {
+        options.validate()?;
+
+        // Update vertex buffer if data changed
+        <Self as WebRenderer>::update_data(self, backend, data)?;
+
+        let device = backend.device()?;
+        let queue = backend.queue()?;
+        let (_, config) = backend.surface()?;
+
+        if Self::needs_rebuild(&self.options, options) {
+            self.render_pipeline = Self::create_pipeline(
+                device,
+                config.format,
+                &self.bind_group_layout,
+                self.camera.layout(),
+                options,
+            );
+        }
+        self.options = options.clone();
+        self.write_uniforms(queue, ScatterUniforms::new(config.width as f32, config.height as f32, options.blend));
+        Ok(())
+    }
+}
+
 impl WebRenderer for ScatterRenderer {
-    fn new(backend: &GPUBackend) -> Result<Self, String> {
+    fn new(backend: &GPUBackend) -> Result<Self, HelionError> {
         let device = backend.device()?;
-        let config = backend.config.as_ref().ok_or("Backend not configured")?;
+        let config = backend.config.as_ref().ok_or(HelionError::NotInitialized("surface configuration"))?;
         
-        // Reuse the same initialization logic
+        // Reuse the same initialization logic; render_with_backend rebuilds
+        // the pipeline if it is handed different options
+        Ok(Self::build(device, config.format, config.width, config.height, &RenderOptions::default()))
+    }
+
+    fn render_with_backend(
+        &mut self,
+        backend: &GPUBackend,
+        data: &ChartData,
+        options: &RenderOptions,
+    ) -> Result<(), HelionError> {
+        self.prepare(backend, data, options)?;
+
+        let device = backend.device()?;
+        let queue = backend.queue()?;
+        let (surface, config) = backend.surface()?;
+
+        let attachments_stale = self
+            .attachments
+            .as_ref()
+            .is_none_or(|a| !a.matches(config.width, config.height, config.format, options));
+        if attachments_stale {
+            self.attachments = Some(RenderAttachments::new(
+                device,
+                config.width,
+                config.height,
+                config.format,
+                options,
+            ));
+        }
+
+        // Get current texture
+        let frame = surface.get_current_texture()?;
+
+        let view = frame
+            .texture
+            .create_view(&wgpu::TextureViewDescriptor::default());
+
+        // Create command encoder
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Render Encoder"),
+        });
+
+        // Render pass
+        if let Some(attachments) = self.attachments.take() {
+            {
+                let mut render_pass = attachments.begin_pass(&mut encoder, &view, options);
+                self.render_to_pass(&mut render_pass);
+                attachments.end_pass(&mut render_pass);
+            }
+            self.attachments = Some(attachments);
+        }
+
+        // Submit commands
+        queue.submit(std::iter::once(encoder.finish()));
+        frame.present();
+
+        Ok(())
+    }
+
+    fn update_data(&mut self, backend: &GPUBackend, data: &ChartData) -> Result<(), HelionError> {
+        if data.vertices.is_empty() {
+            return Ok(());
+        }
+
+        // Write into the ring, or create vertex buffer(s)
+        self.upload(backend.device()?, backend.queue()?, data)
+    }
+}
+
+// ============================================================================
+// Position-only fast path - single-style series
+// ============================================================================
+
+/// Uniform block layout shared with `POSITION_ONLY_VERTEX_SHADER`, at
+/// binding 1 beside the `ScatterUniforms`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct SeriesUniforms {
+    pub color: [f32; 4],
+    pub size: f32,
+    pub _padding: [f32; 3],
+}
+
+impl SeriesUniforms {
+    pub fn new(color: Color, size: f32) -> Self {
+        Self {
+            color: [color.r, color.g, color.b, color.a],
+            size,
+            _padding: [0.0; 3],
+        }
+    }
+}
+
+/// Scatter renderer for series where every point shares one color and size
+///
+/// Uploads only `PositionVertex` data (8 bytes per point, versus 40 for the
+/// full `Vertex`), with color and size in a per-series uniform. That fits
+/// five times as many points in the same VRAM, and restyling the series is a
+/// 32-byte uniform write instead of a full re-upload.
+///
+/// Points are drawn exactly like `ScatterRenderer`'s, as anti-aliased
+/// circles `size` pixels across on instanced quads, so call `resize` when
+/// the surface size changes to keep that size in pixels.
+///
+/// Use `ChartData::vertex_layout()` to decide between this and
+/// `ScatterRenderer`. When built from `ChartData` the style is taken from the
+/// first point.
+pub struct UniformScatterRenderer {
+    render_pipeline: wgpu::RenderPipeline,
+    uniform_buffer: wgpu::Buffer,
+    /// Viewport and edge handling for `SCATTER_FRAGMENT_SHADER`
+    scatter_buffer: wgpu::Buffer,
+    scatter_uniforms: ScatterUniforms,
+    bind_group: wgpu::BindGroup,
+    camera: CameraBinding,
+    /// One instance per point
+    vertex_buffer: Option<ChunkedBuffer>,
+    uniforms: SeriesUniforms,
+    blend: BlendMode,
+    uploaded_bytes: u64,
+    /// Reused by `update_data` so re-uploads don't allocate
+    positions: Vec<PositionVertex>,
+}
+
+impl UniformScatterRenderer {
+    /// Create a renderer straight from positions, without building full vertices
+    pub fn from_positions(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        positions: &[PositionVertex],
+        color: Color,
+        size: f32,
+        options: &RenderOptions,
+    ) -> Self {
+        let uniforms = SeriesUniforms::new(color, size);
+        let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Series Uniform Buffer"),
+            contents: bytemuck::bytes_of(&uniforms),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let scatter_uniforms = ScatterUniforms::new(config.width as f32, config.height as f32, options.blend);
+        let scatter_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Position-Only Scatter Uniform Buffer"),
+            contents: bytemuck::bytes_of(&scatter_uniforms),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let uniform_entry = |binding, visibility| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Series Bind Group Layout"),
+            entries: &[
+                uniform_entry(0, wgpu::ShaderStages::VERTEX_FRAGMENT),
+                uniform_entry(1, wgpu::ShaderStages::VERTEX),
+            ],
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Series Bind Group"),
+            layout: &bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: scatter_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: uniform_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let camera = CameraBinding::new(device);
+
         let vertex_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
-            label: Some("Scatter Vertex Shader"),
-            source: wgpu::ShaderSource::Wgsl(SIMPLE_VERTEX_SHADER.into()),
+            label: Some("Position-Only Vertex Shader"),
+            source: wgpu::ShaderSource::Wgsl(POSITION_ONLY_VERTEX_SHADER.into()),
         });
 
+        // Same circles as ScatterRenderer; the fragment stage only reads the
+        // scatter uniform at binding 0
         let fragment_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("Scatter Fragment Shader"),
-            source: wgpu::ShaderSource::Wgsl(SIMPLE_FRAGMENT_SHADER.into()),
+            source: wgpu::ShaderSource::Wgsl(SCATTER_FRAGMENT_SHADER.into()),
         });
 
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
-            label: Some("Scatter Pipeline Layout"),
-            bind_group_layouts: &[],
+            label: Some("Position-Only Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout, camera.layout()],
             push_constant_ranges: &[],
         });
 
         let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Scatter Render Pipeline"),
+            label: Some("Position-Only Render Pipeline"),
             layout: Some(&pipeline_layout),
             vertex: wgpu::VertexState {
                 module: &vertex_shader,
                 entry_point: "vs_main",
-                buffers: &[Vertex::desc()],
+                buffers: &[PositionVertex::instance_desc()],
                 compilation_options: Default::default(),
             },
             fragment: Some(wgpu::FragmentState {
@@ -180,13 +658,13 @@ impl WebRenderer for ScatterRenderer {
                 entry_point: "fs_main",
                 targets: &[Some(wgpu::ColorTargetState {
                     format: config.format,
-                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    blend: options.blend_state(),
                     write_mask: wgpu::ColorWrites::ALL,
                 })],
                 compilation_options: Default::default(),
             }),
             primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::PointList,
+                topology: wgpu::PrimitiveTopology::TriangleList,
                 strip_index_format: None,
                 front_face: wgpu::FrontFace::Ccw,
                 cull_mode: None,
@@ -194,92 +672,139 @@ impl WebRenderer for ScatterRenderer {
                 unclipped_depth: false,
                 conservative: false,
             },
-            depth_stencil: None,
-            multisample: wgpu::MultisampleState {
-                count: 1,
-                mask: !0,
-                alpha_to_coverage_enabled: false,
-            },
+            depth_stencil: options.depth_stencil_state(),
+            multisample: options.multisample_state(),
             multiview: None,
             cache: None,
         });
 
-        Ok(ScatterRenderer {
+        let mut renderer = UniformScatterRenderer {
             render_pipeline,
+            uniform_buffer,
+            scatter_buffer,
+            scatter_uniforms,
+            bind_group,
+            camera,
             vertex_buffer: None,
-            vertex_count: 0,
-        })
+            uniforms,
+            blend: options.blend,
+            uploaded_bytes: 0,
+            positions: Vec::new(),
+        };
+        renderer.set_positions(device, positions);
+        renderer
     }
 
-    fn render_with_backend(
-        &mut self,
-        backend: &GPUBackend,
-        data: &ChartData,
-        options: &RenderOptions,
-    ) -> Result<(), String> {
-        // Update vertex buffer if data changed
-        <Self as WebRenderer>::update_data(self, backend, data)?;
+    /// Replace the point positions
+    pub fn set_positions(&mut self, device: &wgpu::Device, positions: &[PositionVertex]) {
+        self.vertex_buffer = ChunkedBuffer::upload_or_log(
+            device,
+            "Position-Only Vertex Buffer",
+            positions,
+            wgpu::BufferUsages::VERTEX,
+            1,
+        );
+        self.uploaded_bytes += self.vertex_buffer.as_ref().map_or(0, ChunkedBuffer::byte_size);
+    }
 
-        let device = backend.device()?;
-        let queue = backend.queue()?;
-        let surface = backend.surface.as_ref().ok_or("Surface not configured")?;
+    /// Draw positions that are already on the GPU, e.g. streamed from a
+    /// memory-mapped file with `ingest::mmap::stream_positions`
+    pub fn set_position_buffer(&mut self, buffer: ChunkedBuffer) {
+        self.uploaded_bytes += buffer.byte_size();
+        self.vertex_buffer = Some(buffer);
+    }
 
-        // Get current texture
-        let frame = surface
-            .get_current_texture()
-            .map_err(|e| format!("Failed to get current texture: {}", e))?;
+    /// Restyle the whole series without touching point data
+    pub fn set_style(&mut self, queue: &wgpu::Queue, color: Color, size: f32) {
+        self.uniforms = SeriesUniforms::new(color, size);
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&self.uniforms));
+        self.uploaded_bytes += std::mem::size_of::<SeriesUniforms>() as u64;
+    }
 
-        let view = frame
-            .texture
-            .create_view(&wgpu::TextureViewDescriptor::default());
+    /// Keep point sizes in pixels correct after the surface is resized
+    pub fn resize(&mut self, queue: &wgpu::Queue, width: u32, height: u32) {
+        let uniforms = ScatterUniforms::new(width as f32, height as f32, self.blend);
+        if uniforms != self.scatter_uniforms {
+            self.scatter_uniforms = uniforms;
+            queue.write_buffer(&self.scatter_buffer, 0, bytemuck::bytes_of(&uniforms));
+            self.uploaded_bytes += std::mem::size_of::<ScatterUniforms>() as u64;
+        }
+    }
 
-        // Create command encoder
-        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
-            label: Some("Render Encoder"),
-        });
+    /// Pan or zoom by uploading a new view matrix; positions are untouched
+    pub fn set_camera(&mut self, queue: &wgpu::Queue, camera: &Camera2D) {
+        self.uploaded_bytes += self.camera.set(queue, camera);
+    }
 
-        // Render pass
-        {
-            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                label: Some("Render Pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &view,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(options.clear_color),
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
-                depth_stencil_attachment: None,
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            });
+    pub fn uniforms(&self) -> &SeriesUniforms {
+        &self.uniforms
+    }
+}
 
-            self.render_to_pass(&mut render_pass);
+impl Renderer for UniformScatterRenderer {
+    fn render_to_pass<'rpass>(&'rpass mut self, render_pass: &mut wgpu::RenderPass<'rpass>) {
+        if let Some(ref buffer) = self.vertex_buffer {
+            render_pass.set_pipeline(&self.render_pipeline);
+            render_pass.set_bind_group(0, &self.bind_group, &[]);
+            render_pass.set_bind_group(CAMERA_GROUP, self.camera.bind_group(), &[]);
+            buffer.draw_instanced(render_pass, 0, 0..QUAD_VERTICES);
         }
+    }
 
-        // Submit commands
-        queue.submit(std::iter::once(encoder.finish()));
-        frame.present();
+    fn frame_stats(&mut self) -> DrawStats {
+        let stats = self
+            .vertex_buffer
+            .as_ref()
+            .map_or_else(DrawStats::default, |buffer| buffer.instanced_stats(QUAD_VERTICES));
+        stats.with_bytes_uploaded(std::mem::take(&mut self.uploaded_bytes))
+    }
+}
 
-        Ok(())
+impl WindowRenderer for UniformScatterRenderer {
+    fn with_options(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        chart_data: ChartData,
+        options: &RenderOptions,
+    ) -> Self {
+        let (color, size) = style_of(&chart_data, options);
+        Self::from_positions(device, config, &chart_data.position_vertices(), color, size, options)
     }
 
-    fn update_data(&mut self, backend: &GPUBackend, data: &ChartData) -> Result<(), String> {
-        if data.vertices.is_empty() {
-            return Ok(());
-        }
+    /// Upload new positions; per-point colors and sizes are ignored, so call
+    /// `set_style` if the series style changed too
+    fn update_data(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, chart_data: &ChartData) {
+        let mut positions = std::mem::take(&mut self.positions);
+        chart_data.position_vertices_into(&mut positions);
+        self.uploaded_bytes += ChunkedBuffer::update_or_log(
+            &mut self.vertex_buffer,
+            device,
+            queue,
+            "Position-Only Vertex Buffer",
+            &positions,
+            wgpu::BufferUsages::VERTEX,
+            1,
+        );
+        self.positions = positions;
+    }
 
-        let device = backend.device()?;
+    fn resize(&mut self, queue: &wgpu::Queue, width: u32, height: u32) {
+        UniformScatterRenderer::resize(self, queue, width, height);
+    }
 
-        // Create or update vertex buffer
-        self.vertex_buffer = Some(device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-            label: Some("Vertex Buffer"),
-            contents: bytemuck::cast_slice(&data.vertices),
-            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
-        }));
-        self.vertex_count = data.vertices.len() as u32;
+    fn set_camera(&mut self, queue: &wgpu::Queue, camera: &Camera2D) {
+        UniformScatterRenderer::set_camera(self, queue, camera);
+    }
+}
 
-        Ok(())
+/// Style of the first point, or the default color and `options.point_size`
+/// for an empty series
+fn style_of(chart_data: &ChartData, options: &RenderOptions) -> (Color, f32) {
+    match chart_data.vertices.first() {
+        Some(v) => {
+            let [r, g, b, a] = v.color;
+            (Color::new(r, g, b, a), v.size)
+        }
+        None => (Color::default(), options.point_size),
     }
 }
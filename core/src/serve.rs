@@ -0,0 +1,405 @@
+use crate::camera::PanZoom;
+use crate::data::ChartData;
+use crate::error::HelionError;
+use crate::figure::{ChartKind, Figure, SeriesLayer};
+use crate::headless::HeadlessRenderer;
+use crate::heatmap::HeatmapRenderer;
+use crate::renderer::{RenderOptions, Renderer, WindowRenderer};
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::time::Duration;
+
+/// How long the server sleeps between checks for new connections
+const IDLE_POLL: Duration = Duration::from_millis(20);
+
+/// Largest request head read; the page only sends short GET requests
+const MAX_REQUEST: usize = 8 * 1024;
+
+/// A pan or zoom sent by the browser page, in image pixels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ViewInput {
+    /// The pointer moved to (`x`, `y`), with the button held if `pressed`
+    Pointer { x: f32, y: f32, pressed: bool },
+    /// `steps` wheel steps (positive zooms in) with the pointer at (`x`, `y`)
+    Scroll { x: f32, y: f32, steps: f32 },
+    /// The pointer left the image
+    Leave,
+    /// Back to the unzoomed view
+    Reset,
+}
+
+impl ViewInput {
+    /// Parse the query string of an `/input` request
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::serve::ViewInput;
+    ///
+    /// assert_eq!(
+    ///     ViewInput::from_query("kind=scroll&steps=2&x=10&y=20"),
+    ///     Some(ViewInput::Scroll { x: 10.0, y: 20.0, steps: 2.0 })
+    /// );
+    /// assert_eq!(ViewInput::from_query("kind=reset"), Some(ViewInput::Reset));
+    /// assert_eq!(ViewInput::from_query("kind=pointer&x=1"), None);
+    /// ```
+    pub fn from_query(query: &str) -> Option<Self> {
+        let number = |key: &str| query_value(query, key)?.parse::<f32>().ok().filter(|v| v.is_finite());
+        match query_value(query, "kind")? {
+            "pointer" => Some(ViewInput::Pointer {
+                x: number("x")?,
+                y: number("y")?,
+                pressed: query_value(query, "pressed") == Some("1"),
+            }),
+            "scroll" => Some(ViewInput::Scroll {
+                x: number("x")?,
+                y: number("y")?,
+                steps: number("steps")?,
+            }),
+            "leave" => Some(ViewInput::Leave),
+            "reset" => Some(ViewInput::Reset),
+            _ => None,
+        }
+    }
+}
+
+/// Value of `key` in a `a=1&b=2` query string
+fn query_value<'a>(query: &'a str, key: &str) -> Option<&'a str> {
+    query.split('&').find_map(|pair| {
+        let (name, value) = pair.split_once('=')?;
+        (name == key).then_some(value)
+    })
+}
+
+/// A chart drawn offscreen that can be panned and zoomed, for showing plots
+/// where no window can open
+///
+/// Renderers are created once; view changes only move their cameras, and
+/// the PNG of the current view is kept until the view changes again.
+pub struct FrameSession {
+    headless: HeadlessRenderer,
+    heatmap: Option<HeatmapRenderer>,
+    series: Vec<Box<dyn WindowRenderer>>,
+    pan_zoom: PanZoom,
+    /// PNG of the current view, once rendered
+    frame: Option<Vec<u8>>,
+    /// Bumped on every view change, so the page knows to fetch a new frame
+    version: u64,
+}
+
+impl FrameSession {
+    /// Session drawing `chart_data` as `kind` at its viewport size
+    pub fn chart(chart_data: ChartData, kind: ChartKind, options: RenderOptions) -> Result<Self, HelionError> {
+        let size = (chart_data.viewport_width as u32, chart_data.viewport_height as u32);
+        let layer = SeriesLayer {
+            series: 0,
+            kind,
+            data: chart_data,
+        };
+        let headless = HeadlessRenderer::new(size.0.max(1), size.1.max(1), options)?;
+        let series = vec![headless.series_renderer(layer)];
+        Ok(Self::with_renderers(headless, None, series))
+    }
+
+    /// Session drawing the heatmap and every series of `figure` at its size
+    ///
+    /// As with `HeadlessRenderer::render_figure_png`, axes, text and the
+    /// legend aren't drawn.
+    ///
+    /// # Returns
+    /// The session, or `HelionError::EmptyData` for an empty figure
+    pub fn figure(figure: &Figure, options: RenderOptions) -> Result<Self, HelionError> {
+        if figure.is_empty() && figure.heatmap().is_none() {
+            return Err(HelionError::EmptyData);
+        }
+        let headless = HeadlessRenderer::new(figure.width().max(1.0) as u32, figure.height().max(1.0) as u32, options)?;
+        let heatmap = headless.heatmap_renderer(figure)?;
+        let series = figure.layers().into_iter().map(|layer| headless.series_renderer(layer)).collect();
+        Ok(Self::with_renderers(headless, heatmap, series))
+    }
+
+    fn with_renderers(
+        headless: HeadlessRenderer,
+        heatmap: Option<HeatmapRenderer>,
+        series: Vec<Box<dyn WindowRenderer>>,
+    ) -> Self {
+        let pan_zoom = PanZoom::new(headless.width() as f32, headless.height() as f32);
+        Self {
+            headless,
+            heatmap,
+            series,
+            pan_zoom,
+            frame: None,
+            version: 0,
+        }
+    }
+
+    /// Frame size in pixels
+    pub fn size(&self) -> (u32, u32) {
+        (self.headless.width(), self.headless.height())
+    }
+
+    /// Number of view changes so far
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Pan or zoom the view
+    ///
+    /// # Returns
+    /// Whether the view changed, in which case the next `frame_png`
+    /// renders a new frame
+    pub fn input(&mut self, input: ViewInput) -> bool {
+        let changed = match input {
+            ViewInput::Pointer { x, y, pressed } => {
+                let panned = self.pan_zoom.cursor_moved(x, y);
+                self.pan_zoom.set_dragging(pressed);
+                panned
+            }
+            ViewInput::Scroll { x, y, steps } => {
+                self.pan_zoom.cursor_moved(x, y);
+                self.pan_zoom.scroll(steps)
+            }
+            ViewInput::Leave => {
+                self.pan_zoom.cursor_left();
+                self.pan_zoom.set_dragging(false);
+                false
+            }
+            ViewInput::Reset => self.pan_zoom.reset(),
+        };
+        if changed {
+            let camera = *self.pan_zoom.camera();
+            let queue = self.headless.queue();
+            for renderer in &mut self.series {
+                renderer.set_camera(queue, &camera);
+            }
+            if let Some(heatmap) = &mut self.heatmap {
+                heatmap.set_camera(queue, &camera);
+            }
+            self.frame = None;
+            self.version += 1;
+        }
+        changed
+    }
+
+    /// PNG of the current view, rendered only if the view changed since
+    /// the last call
+    pub fn frame_png(&mut self) -> Result<&[u8], HelionError> {
+        if self.frame.is_none() {
+            let mut layers: Vec<&mut dyn Renderer> = Vec::with_capacity(self.series.len() + 1);
+            if let Some(heatmap) = &mut self.heatmap {
+                layers.push(heatmap);
+            }
+            for renderer in &mut self.series {
+                layers.push(renderer.as_mut());
+            }
+            self.headless.draw_layers(&mut layers);
+            let (width, height) = self.size();
+            let pixels = self.headless.read_rgba()?;
+            self.frame = Some(crate::headless::encode_png(&pixels, width, height)?);
+        }
+        Ok(self.frame.as_deref().unwrap_or_default())
+    }
+}
+
+/// Serves a `FrameSession` to a browser over HTTP, for machines without a
+/// display such as a server reached over SSH
+///
+/// The page at `url()` shows the current frame; dragging pans, the wheel
+/// zooms and a double click resets the view. Each change is sent to the
+/// server, which renders a new frame only when the view actually moved.
+/// The "close" link on the page ends `run`. Requests are handled one at a
+/// time on the calling thread, which also owns the GPU work.
+///
+/// # Example
+/// ```no_run
+/// use helion_core::serve::{FrameServer, FrameSession};
+/// use helion_core::{ChartData, ChartKind, RenderOptions};
+///
+/// let data = ChartData::from_scatter(&[0.0, 1.0], &[0.0, 1.0], None, Some(8.0), 640.0, 480.0);
+/// let session = FrameSession::chart(data, ChartKind::Scatter, RenderOptions::default()).unwrap();
+/// let mut server = FrameServer::bind(8000, session, "My plot").unwrap();
+/// println!("Open {}", server.url());
+/// server.run(&mut || true).unwrap();
+/// ```
+pub struct FrameServer {
+    listener: TcpListener,
+    session: FrameSession,
+    title: String,
+}
+
+impl FrameServer {
+    /// Listen on `port` of the loopback interface; 0 picks a free port
+    ///
+    /// Only local connections are accepted, so remote users forward the
+    /// port, e.g. `ssh -L 8000:localhost:8000 server`.
+    pub fn bind(port: u16, session: FrameSession, title: &str) -> Result<Self, HelionError> {
+        let listener = TcpListener::bind(("127.0.0.1", port))
+            .and_then(|listener| listener.set_nonblocking(true).map(|_| listener))
+            .map_err(|e| HelionError::Serve(format!("can't listen on port {}: {}", port, e)))?;
+        Ok(Self {
+            listener,
+            session,
+            title: title.to_string(),
+        })
+    }
+
+    /// Address the server listens on
+    pub fn local_addr(&self) -> Result<SocketAddr, HelionError> {
+        self.listener.local_addr().map_err(|e| HelionError::Serve(e.to_string()))
+    }
+
+    /// URL of the page showing the plot
+    pub fn url(&self) -> String {
+        match self.listener.local_addr() {
+            Ok(addr) => format!("http://localhost:{}/", addr.port()),
+            Err(_) => "http://localhost/".to_string(),
+        }
+    }
+
+    /// Serve until the page is closed or `keep_going` returns false
+    ///
+    /// `keep_going` is called whenever the server is idle, e.g. to stop on
+    /// Ctrl+C.
+    pub fn run(&mut self, keep_going: &mut dyn FnMut() -> bool) -> Result<(), HelionError> {
+        loop {
+            match self.listener.accept() {
+                Ok((stream, _)) => {
+                    if self.respond(stream) == Route::Close {
+                        return Ok(());
+                    }
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                    if !keep_going() {
+                        return Ok(());
+                    }
+                    std::thread::sleep(IDLE_POLL);
+                }
+                Err(e) => return Err(HelionError::Serve(e.to_string())),
+            }
+        }
+    }
+
+    /// Answer one request; a client that hangs up or sends garbage only
+    /// loses its own request
+    fn respond(&mut self, mut stream: TcpStream) -> Route {
+        let Some(target) = read_request_target(&mut stream) else {
+            return Route::Other;
+        };
+        let (path, query) = target.split_once('?').unwrap_or((&target, ""));
+        let (route, result) = match path {
+            "/" => (Route::Other, send(&mut stream, "200 OK", "text/html; charset=utf-8", self.page().as_bytes())),
+            "/frame.png" => match self.session.frame_png() {
+                Ok(png) => (Route::Other, send(&mut stream, "200 OK", "image/png", png)),
+                Err(e) => {
+                    log::error!("Failed to render frame: {}", e);
+                    (Route::Other, send(&mut stream, "500 Internal Server Error", "text/plain", e.to_string().as_bytes()))
+                }
+            },
+            "/input" => {
+                if let Some(input) = ViewInput::from_query(query) {
+                    self.session.input(input);
+                }
+                let version = self.session.version().to_string();
+                (Route::Other, send(&mut stream, "200 OK", "text/plain", version.as_bytes()))
+            }
+            "/close" => (Route::Close, send(&mut stream, "200 OK", "text/plain", b"closed")),
+            _ => (Route::Other, send(&mut stream, "404 Not Found", "text/plain", b"not found")),
+        };
+        if let Err(e) = result {
+            log::debug!("Dropped response to {}: {}", path, e);
+        }
+        route
+    }
+
+    fn page(&self) -> String {
+        let (width, height) = self.session.size();
+        PAGE.replace("{title}", &escape_html(&self.title))
+            .replace("{width}", &width.to_string())
+            .replace("{height}", &height.to_string())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Route {
+    Close,
+    Other,
+}
+
+/// Path and query of a GET request, e.g. `/input?kind=reset`
+fn read_request_target(stream: &mut TcpStream) -> Option<String> {
+    stream.set_nonblocking(false).ok()?;
+    stream.set_read_timeout(Some(Duration::from_secs(2))).ok()?;
+    let mut head = Vec::new();
+    let mut buffer = [0u8; 1024];
+    while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+        let read = stream.read(&mut buffer).ok()?;
+        if read == 0 || head.len() + read > MAX_REQUEST {
+            return None;
+        }
+        head.extend_from_slice(&buffer[..read]);
+    }
+    let head = String::from_utf8_lossy(&head);
+    let mut request_line = head.lines().next()?.split(' ');
+    match (request_line.next()?, request_line.next()?) {
+        ("GET", target) => Some(target.to_string()),
+        _ => None,
+    }
+}
+
+fn send(stream: &mut TcpStream, status: &str, content_type: &str, body: &[u8]) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n",
+        status,
+        content_type,
+        body.len()
+    )?;
+    stream.write_all(body)?;
+    stream.flush()
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// The page showing the plot; input is sent one request at a time, and
+/// moves made while one is in flight collapse into the latest
+const PAGE: &str = r##"<!doctype html>
+<meta charset="utf-8">
+<title>{title}</title>
+<body style="margin:0;padding:16px;background:#f4f4f4;font:13px sans-serif;color:#555">
+<img id="frame" src="/frame.png?v=0" width="{width}" height="{height}" draggable="false"
+     style="display:block;max-width:100%;height:auto;background:#fff;cursor:grab;touch-action:none">
+<p>Drag to pan, scroll to zoom, double-click to reset. <a href="#" id="close">Close</a></p>
+<script>
+const img = document.getElementById('frame');
+let busy = false, queued = null, shown = '0';
+function send(query) {
+  if (busy) { queued = query; return; }
+  busy = true;
+  fetch('/input?' + query).then(r => r.text()).then(version => {
+    if (version !== shown) { shown = version; img.src = '/frame.png?v=' + version; } else { done(); }
+  }).catch(() => { busy = false; });
+}
+function done() {
+  busy = false;
+  if (queued !== null) { const query = queued; queued = null; send(query); }
+}
+function at(e) {
+  const r = img.getBoundingClientRect();
+  return '&x=' + (e.clientX - r.left) * img.naturalWidth / r.width + '&y=' + (e.clientY - r.top) * img.naturalHeight / r.height;
+}
+img.onload = done;
+img.onpointerdown = e => { img.setPointerCapture(e.pointerId); send('kind=pointer&pressed=1' + at(e)); };
+img.onpointermove = e => send('kind=pointer&pressed=' + (e.buttons & 1) + at(e));
+img.onpointerup = e => send('kind=pointer&pressed=0' + at(e));
+img.onpointerleave = () => send('kind=leave');
+img.onwheel = e => { e.preventDefault(); send('kind=scroll&steps=' + (-e.deltaY / 100) + at(e)); };
+img.ondblclick = () => send('kind=reset');
+document.getElementById('close').onclick = e => {
+  e.preventDefault();
+  fetch('/close');
+  document.body.textContent = 'Plot closed.';
+};
+</script>
+"##;
@@ -23,14 +23,36 @@
 // - Each point becomes one or more pixels on screen
 // - Fragment shader can create circular shapes from square pixels
 
-/// Vertex shader for scatter plots (advanced, with circular point support)
+/// Vertex shader for scatter plots: one screen-aligned quad per point
 ///
-/// This shader prepares point data for rendering circles. The point_coord
-/// will be used by the fragment shader to create smooth circular points.
+/// Point primitives are always one pixel wide in WebGPU, so each point is
+/// drawn as an instance of two triangles instead. The six corners come from
+/// the vertex index (no mesh buffer). The quad covers a circle `size` pixels
+/// across plus half a pixel of margin for the anti-aliased edge, converted to
+/// clip space with the viewport from the uniform. Point centers go through
+/// the camera view matrix at group 1 first (see `camera::Camera2D`).
 ///
-/// Note: Currently not used - requires geometry shader or point sprite setup.
+/// Slot 0 is the Rust Vertex struct stepped per instance (locations 0-2).
+/// The fragment shader gets each pixel's offset from the point center and
+/// the circle's radius, both in pixels.
 pub const SCATTER_VERTEX_SHADER: &str = r#"
-struct VertexInput {
+struct ScatterUniforms {
+    viewport: vec2<f32>,
+    premultiplied: f32,
+    hard_edge: f32,
+}
+
+@group(0) @binding(0)
+var<uniform> scatter: ScatterUniforms;
+
+struct CameraUniforms {
+    view: mat4x4<f32>,
+}
+
+@group(1) @binding(0)
+var<uniform> camera: CameraUniforms;
+
+struct InstanceInput {
     @location(0) position: vec2<f32>,
     @location(1) color: vec4<f32>,
     @location(2) size: f32,
@@ -39,51 +61,79 @@ struct VertexInput {
 struct VertexOutput {
     @builtin(position) clip_position: vec4<f32>,
     @location(0) color: vec4<f32>,
-    @location(1) point_coord: vec2<f32>,
+    @location(1) offset: vec2<f32>,
+    @location(2) radius: f32,
 }
 
 @vertex
-fn vs_main(vertex: VertexInput) -> VertexOutput {
+fn vs_main(instance: InstanceInput, @builtin(vertex_index) index: u32) -> VertexOutput {
+    var corners = array<vec2<f32>, 6>(
+        vec2<f32>(-1.0, -1.0), vec2<f32>(1.0, -1.0), vec2<f32>(1.0, 1.0),
+        vec2<f32>(-1.0, -1.0), vec2<f32>(1.0, 1.0), vec2<f32>(-1.0, 1.0),
+    );
+
+    // At least one pixel across, so tiny points stay visible
+    let radius = max(instance.size, 1.0) * 0.5;
+    let offset = corners[index] * (radius + 0.5);
+
     var out: VertexOutput;
-    out.clip_position = vec4<f32>(vertex.position, 0.0, 1.0);
-    out.color = vertex.color;
-    out.point_coord = vec2<f32>(0.0, 0.0); // Will be set in geometry shader alternative
+    // Clip space spans 2 units across the viewport; the offset is added
+    // after the camera so zooming doesn't scale the marker
+    let center = (camera.view * vec4<f32>(instance.position, 0.0, 1.0)).xy;
+    out.clip_position = vec4<f32>(center + offset * 2.0 / scatter.viewport, 0.0, 1.0);
+    out.color = instance.color;
+    out.offset = offset;
+    out.radius = radius;
     return out;
 }
 "#;
 
-/// Fragment shader for scatter plots (advanced, with anti-aliased circles)
+/// Fragment shader for scatter plots: anti-aliased circles
 ///
 /// Creates smooth circular points using distance field rendering:
-/// - Calculates distance from pixel to point center
-/// - Uses smoothstep for anti-aliased edges (no jagged pixels)
-/// - Pixels far from center are transparent (creates circle shape)
-///
-/// This produces much nicer looking scatter plots compared to square pixels.
+/// - Calculates the distance from the pixel to the point center, in pixels
+/// - Uses smoothstep over one pixel at the rim for anti-aliased edges
+/// - Pixels outside the circle are discarded (creates circle shape)
 ///
-/// Note: Currently not used - requires corresponding vertex shader setup.
+/// Coverage scales alpha for straight-alpha blending and all channels for
+/// premultiplied and additive blending. Without blending there is nothing to
+/// fade into, so `hard_edge` cuts the circle at half coverage instead.
 pub const SCATTER_FRAGMENT_SHADER: &str = r#"
+struct ScatterUniforms {
+    viewport: vec2<f32>,
+    premultiplied: f32,
+    hard_edge: f32,
+}
+
+@group(0) @binding(0)
+var<uniform> scatter: ScatterUniforms;
+
 struct VertexOutput {
     @builtin(position) clip_position: vec4<f32>,
     @location(0) color: vec4<f32>,
-    @location(1) point_coord: vec2<f32>,
+    @location(1) offset: vec2<f32>,
+    @location(2) radius: f32,
 }
 
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
-    // Simple circular points - can be enhanced with distance field
-    let center = vec2<f32>(0.5, 0.5);
-    let dist = distance(in.point_coord, center);
-    
-    // Anti-aliased circle
-    let radius = 0.5;
-    let alpha = smoothstep(radius, radius - 0.05, dist);
-    
-    return vec4<f32>(in.color.rgb, in.color.a * alpha);
+    let dist = distance(in.offset, vec2<f32>(0.0, 0.0));
+
+    // Fraction of this pixel inside the circle
+    var coverage = 1.0 - smoothstep(in.radius - 0.5, in.radius + 0.5, dist);
+    if scatter.hard_edge > 0.0 {
+        coverage = step(0.5, coverage);
+    }
+    if coverage <= 0.0 {
+        discard;
+    }
+
+    let rgb = in.color.rgb * mix(1.0, coverage, scatter.premultiplied);
+    return vec4<f32>(rgb, in.color.a * coverage);
 }
 "#;
 
-/// Simple vertex shader (currently used for basic point rendering)
+/// Simple vertex shader (used for lines and meshes)
 ///
 /// Pipeline Stage 1: VERTEX PROCESSING
 /// - Takes each vertex (point) from our Rust Vertex struct
@@ -93,7 +143,7 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
 /// Input layout matches our Rust Vertex struct:
 /// - @location(0): position [x, y]
 /// - @location(1): color [r, g, b, a]
-/// - @location(2): size (not currently used)
+/// - @location(2): size (ignored: lines and meshes have no point size)
 ///
 /// This shader does minimal work - just format conversion.
 /// Perfect for rendering millions of points quickly.
@@ -140,3 +190,694 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     return in.color;
 }
 "#;
+
+/// Vertex shader for the halo/glow overlay
+///
+/// Draws every flagged point as a screen-space quad (6 vertices per instance,
+/// generated from `vertex_index`) so the glow can be much larger than the
+/// point itself. The quad is sized in pixels, then converted to clip space
+/// using the viewport size from the uniform buffer.
+///
+/// Instance input layout matches our Rust Vertex struct, stepped per instance.
+pub const HALO_VERTEX_SHADER: &str = r#"
+struct HaloUniforms {
+    viewport: vec2<f32>,
+    radius_scale: f32,
+    intensity: f32,
+}
+
+@group(0) @binding(0)
+var<uniform> halo: HaloUniforms;
+
+struct InstanceInput {
+    @location(0) position: vec2<f32>,
+    @location(1) color: vec4<f32>,
+    @location(2) size: f32,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+    @location(1) local: vec2<f32>,
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32, instance: InstanceInput) -> VertexOutput {
+    var corners = array<vec2<f32>, 6>(
+        vec2<f32>(-1.0, -1.0),
+        vec2<f32>(1.0, -1.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(-1.0, -1.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(-1.0, 1.0),
+    );
+    let corner = corners[vertex_index];
+
+    // Pixels -> clip space: one pixel spans 2 / viewport clip units
+    let radius_px = instance.size * halo.radius_scale;
+    let offset = corner * radius_px * 2.0 / halo.viewport;
+
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(instance.position + offset, 0.0, 1.0);
+    out.color = vec4<f32>(instance.color.rgb, instance.color.a * halo.intensity);
+    out.local = corner;
+    return out;
+}
+"#;
+
+/// Fragment shader for the halo/glow overlay
+///
+/// Approximates a blurred sprite with a gaussian falloff from the quad
+/// center, faded to zero at the quad edge so no square outline shows.
+/// Output is premultiplied so it can be composited with additive blending.
+pub const HALO_FRAGMENT_SHADER: &str = r#"
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+    @location(1) local: vec2<f32>,
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let dist = length(in.local);
+    let falloff = exp(-4.0 * dist * dist) * smoothstep(1.0, 0.8, dist);
+    let alpha = in.color.a * falloff;
+    return vec4<f32>(in.color.rgb * alpha, alpha);
+}
+"#;
+
+/// Compute shader for 2D point binning (heat/hexbin/datashade aggregation)
+///
+/// One invocation per point: the point's cell is found from the grid origin
+/// and cell size, and that cell's counter is bumped atomically. Points outside
+/// the grid are skipped, so binning only the visible range after a zoom is a
+/// matter of re-dispatching with new parameters.
+///
+/// Dispatches may be 2D (x capped at 65535 workgroups); the flat point index
+/// is rebuilt from `num_workgroups`.
+pub const BIN2D_COMPUTE_SHADER: &str = r#"
+struct BinParams {
+    origin: vec2<f32>,
+    inv_cell: vec2<f32>,
+    dims: vec2<u32>,
+    count: u32,
+    _padding: u32,
+}
+
+@group(0) @binding(0)
+var<uniform> params: BinParams;
+
+@group(0) @binding(1)
+var<storage, read> points: array<vec2<f32>>;
+
+@group(0) @binding(2)
+var<storage, read_write> bins: array<atomic<u32>>;
+
+@compute @workgroup_size(256)
+fn cs_main(
+    @builtin(global_invocation_id) id: vec3<u32>,
+    @builtin(num_workgroups) groups: vec3<u32>,
+) {
+    let i = id.x + id.y * groups.x * 256u;
+    if (i >= params.count) {
+        return;
+    }
+
+    let cell = floor((points[i] - params.origin) * params.inv_cell);
+    if (cell.x < 0.0 || cell.y < 0.0 || cell.x >= f32(params.dims.x) || cell.y >= f32(params.dims.y)) {
+        return;
+    }
+
+    atomicAdd(&bins[u32(cell.y) * params.dims.x + u32(cell.x)], 1u);
+}
+"#;
+
+/// Compute shader counting values into histogram bins
+///
+/// One invocation per value. The bin is found by binary search over the
+/// bin edges, so uneven edges work as well as equal-width ones; like
+/// `Histogram::compute`, each bin covers `[edges[i], edges[i + 1])` and the
+/// last one also its right edge. Values outside the edges, and NaN (which
+/// fails both range comparisons), are skipped.
+///
+/// Dispatches may be 2D, as for `BIN2D_COMPUTE_SHADER`.
+pub const HIST1D_COMPUTE_SHADER: &str = r#"
+struct HistParams {
+    count: u32,
+    bins: u32,
+    _padding: vec2<u32>,
+}
+
+@group(0) @binding(0)
+var<uniform> params: HistParams;
+
+@group(0) @binding(1)
+var<storage, read> values: array<f32>;
+
+@group(0) @binding(2)
+var<storage, read> edges: array<f32>;
+
+@group(0) @binding(3)
+var<storage, read_write> counts: array<atomic<u32>>;
+
+@compute @workgroup_size(256)
+fn cs_main(
+    @builtin(global_invocation_id) id: vec3<u32>,
+    @builtin(num_workgroups) groups: vec3<u32>,
+) {
+    let i = id.x + id.y * groups.x * 256u;
+    if (i >= params.count) {
+        return;
+    }
+
+    let v = values[i];
+    if (!(v >= edges[0] && v <= edges[params.bins])) {
+        return;
+    }
+
+    // edges[lo] <= v holds throughout; the bin is the last such edge
+    var lo = 0u;
+    var hi = params.bins;
+    while (hi - lo > 1u) {
+        let mid = (lo + hi) / 2u;
+        if (edges[mid] <= v) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    atomicAdd(&counts[lo], 1u);
+}
+"#;
+
+/// Vertex shader for colormapped points
+///
+/// Reads the position from the regular vertex buffer (slot 0) and a scalar
+/// value from a separate buffer (slot 1), passing the raw value through so
+/// the fragment shader can map it.
+pub const COLORMAP_VERTEX_SHADER: &str = r#"
+struct VertexInput {
+    @location(0) position: vec2<f32>,
+    @location(1) value: f32,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) value: f32,
+}
+
+@vertex
+fn vs_main(in: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(in.position, 0.0, 1.0);
+    out.value = in.value;
+    return out;
+}
+"#;
+
+/// Fragment shader for colormapped points
+///
+/// Normalizes the value with vmin/vmax from the uniform buffer and looks the
+/// color up in a 256x1 colormap texture. Coordinates are remapped onto texel
+/// centers so the first and last entries are hit exactly. NaN values are
+/// discarded.
+pub const COLORMAP_FRAGMENT_SHADER: &str = r#"
+struct ColormapUniforms {
+    vmin: f32,
+    vmax: f32,
+    opacity: f32,
+    _padding: f32,
+}
+
+@group(0) @binding(0)
+var<uniform> colormap: ColormapUniforms;
+
+@group(0) @binding(1)
+var lut: texture_2d<f32>;
+
+@group(0) @binding(2)
+var lut_sampler: sampler;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) value: f32,
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    if (in.value != in.value) {
+        discard;
+    }
+
+    let span = colormap.vmax - colormap.vmin;
+    var t = 0.5;
+    if (span != 0.0) {
+        t = clamp((in.value - colormap.vmin) / span, 0.0, 1.0);
+    }
+
+    let u = (t * 255.0 + 0.5) / 256.0;
+    let color = textureSampleLevel(lut, lut_sampler, vec2<f32>(u, 0.5), 0.0);
+    return vec4<f32>(color.rgb, color.a * colormap.opacity);
+}
+"#;
+
+/// Vertex shader for heatmaps: one quad covering the grid
+///
+/// Six vertices from the vertex index (no vertex buffer) span `rect`, the
+/// grid's clip-space corners (min x, min y, max x, max y), then go through
+/// the camera at group 1. `uv` runs from (0, 0) at the grid's (x min, y min)
+/// corner to (1, 1).
+pub const HEATMAP_VERTEX_SHADER: &str = r#"
+struct HeatmapUniforms {
+    rect: vec4<f32>,
+    vmin: f32,
+    vmax: f32,
+    opacity: f32,
+    _padding: f32,
+}
+
+@group(0) @binding(0)
+var<uniform> heatmap: HeatmapUniforms;
+
+struct CameraUniforms {
+    view: mat4x4<f32>,
+}
+
+@group(1) @binding(0)
+var<uniform> camera: CameraUniforms;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> VertexOutput {
+    var corners = array<vec2<f32>, 6>(
+        vec2<f32>(0.0, 0.0), vec2<f32>(1.0, 0.0), vec2<f32>(1.0, 1.0),
+        vec2<f32>(0.0, 0.0), vec2<f32>(1.0, 1.0), vec2<f32>(0.0, 1.0),
+    );
+    let uv = corners[index];
+    let position = mix(heatmap.rect.xy, heatmap.rect.zw, uv);
+
+    var out: VertexOutput;
+    out.clip_position = camera.view * vec4<f32>(position, 0.0, 1.0);
+    out.uv = uv;
+    return out;
+}
+"#;
+
+/// Fragment shader for heatmaps
+///
+/// Reads the cell under the pixel from an R32Float texture with
+/// `textureLoad` (float32 textures aren't filterable everywhere, and cells
+/// should stay crisp anyway), then colors it through the 256x1 colormap
+/// texture like `COLORMAP_FRAGMENT_SHADER`. Row 0 of the texture is the
+/// bottom row of the grid. NaN cells are discarded.
+pub const HEATMAP_FRAGMENT_SHADER: &str = r#"
+struct HeatmapUniforms {
+    rect: vec4<f32>,
+    vmin: f32,
+    vmax: f32,
+    opacity: f32,
+    _padding: f32,
+}
+
+@group(0) @binding(0)
+var<uniform> heatmap: HeatmapUniforms;
+
+@group(0) @binding(1)
+var cells: texture_2d<f32>;
+
+@group(0) @binding(2)
+var lut: texture_2d<f32>;
+
+@group(0) @binding(3)
+var lut_sampler: sampler;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let dims = vec2<i32>(textureDimensions(cells));
+    let cell = clamp(vec2<i32>(floor(in.uv * vec2<f32>(dims))), vec2<i32>(0), dims - 1);
+    let value = textureLoad(cells, cell, 0).r;
+    if (value != value) {
+        discard;
+    }
+
+    let span = heatmap.vmax - heatmap.vmin;
+    var t = 0.5;
+    if (span != 0.0) {
+        t = clamp((value - heatmap.vmin) / span, 0.0, 1.0);
+    }
+    let u = (t * 255.0 + 0.5) / 256.0;
+    let color = textureSampleLevel(lut, lut_sampler, vec2<f32>(u, 0.5), 0.0);
+    return vec4<f32>(color.rgb, color.a * heatmap.opacity);
+}
+"#;
+
+/// Vertex shader for line charts: `SIMPLE_VERTEX_SHADER` plus the camera
+///
+/// Same per-vertex input; positions go through the view matrix (see
+/// `camera::Camera2D`) so lines can be panned and zoomed without
+/// re-uploading them. Lines have no other uniforms, so the camera is group 0.
+pub const LINE_VERTEX_SHADER: &str = r#"
+struct CameraUniforms {
+    view: mat4x4<f32>,
+}
+
+@group(0) @binding(0)
+var<uniform> camera: CameraUniforms;
+
+struct VertexInput {
+    @location(0) position: vec2<f32>,
+    @location(1) color: vec4<f32>,
+    @location(2) size: f32,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+}
+
+@vertex
+fn vs_main(vertex: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.clip_position = camera.view * vec4<f32>(vertex.position, 0.0, 1.0);
+    out.color = vertex.color;
+    return out;
+}
+"#;
+
+/// Vertex shader for trajectories whose older points fade out
+/// (`TrajectoryRenderer`)
+///
+/// Each vertex carries its time in the `size` slot, which lines don't use
+/// otherwise, and leaves with its age behind the trail's head, interpolated
+/// along each segment. Positions go through the camera at group 0 as for
+/// lines; the trail uniform is group 1.
+pub const TRAJECTORY_VERTEX_SHADER: &str = r#"
+struct CameraUniforms {
+    view: mat4x4<f32>,
+}
+
+struct TrailUniforms {
+    head: f32,
+    inv_length: f32,
+    premultiplied: f32,
+    _padding: f32,
+}
+
+@group(0) @binding(0)
+var<uniform> camera: CameraUniforms;
+
+@group(1) @binding(0)
+var<uniform> trail: TrailUniforms;
+
+struct VertexInput {
+    @location(0) position: vec2<f32>,
+    @location(1) color: vec4<f32>,
+    @location(2) time: f32,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+    @location(1) age: f32,
+}
+
+@vertex
+fn vs_main(vertex: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.clip_position = camera.view * vec4<f32>(vertex.position, 0.0, 1.0);
+    out.color = vertex.color;
+    out.age = trail.head - vertex.time;
+    return out;
+}
+"#;
+
+/// Fragment shader for trajectories: fades the line linearly with age
+///
+/// Pixels newer than the head or older than the trail are discarded, so the
+/// trail ends exactly at the head even mid segment. `inv_length` is 0 for a
+/// trail that never fades. As in the scatter shader, `premultiplied` scales
+/// the color along with alpha.
+pub const TRAJECTORY_FRAGMENT_SHADER: &str = r#"
+struct TrailUniforms {
+    head: f32,
+    inv_length: f32,
+    premultiplied: f32,
+    _padding: f32,
+}
+
+@group(1) @binding(0)
+var<uniform> trail: TrailUniforms;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+    @location(1) age: f32,
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let opacity = 1.0 - in.age * trail.inv_length;
+    if in.age < 0.0 || opacity <= 0.0 {
+        discard;
+    }
+    let rgb = in.color.rgb * mix(1.0, opacity, trail.premultiplied);
+    return vec4<f32>(rgb, in.color.a * opacity);
+}
+"#;
+
+/// Vertex shader for the position-only fast path
+///
+/// The instance buffer holds nothing but positions (8 bytes per point);
+/// color and size are shared by the whole series and read from the series
+/// uniform at binding 1. Otherwise each point becomes the same quad as in
+/// `SCATTER_VERTEX_SHADER`, with the viewport from the scatter uniform at
+/// binding 0, so `SCATTER_FRAGMENT_SHADER` draws the circle.
+pub const POSITION_ONLY_VERTEX_SHADER: &str = r#"
+struct ScatterUniforms {
+    viewport: vec2<f32>,
+    premultiplied: f32,
+    hard_edge: f32,
+}
+
+@group(0) @binding(0)
+var<uniform> scatter: ScatterUniforms;
+
+struct SeriesUniforms {
+    color: vec4<f32>,
+    size: f32,
+    _padding0: f32,
+    _padding1: f32,
+    _padding2: f32,
+}
+
+@group(0) @binding(1)
+var<uniform> series: SeriesUniforms;
+
+struct CameraUniforms {
+    view: mat4x4<f32>,
+}
+
+@group(1) @binding(0)
+var<uniform> camera: CameraUniforms;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+    @location(1) offset: vec2<f32>,
+    @location(2) radius: f32,
+}
+
+@vertex
+fn vs_main(@location(0) position: vec2<f32>, @builtin(vertex_index) index: u32) -> VertexOutput {
+    var corners = array<vec2<f32>, 6>(
+        vec2<f32>(-1.0, -1.0), vec2<f32>(1.0, -1.0), vec2<f32>(1.0, 1.0),
+        vec2<f32>(-1.0, -1.0), vec2<f32>(1.0, 1.0), vec2<f32>(-1.0, 1.0),
+    );
+
+    let radius = max(series.size, 1.0) * 0.5;
+    let offset = corners[index] * (radius + 0.5);
+
+    var out: VertexOutput;
+    let center = (camera.view * vec4<f32>(position, 0.0, 1.0)).xy;
+    out.clip_position = vec4<f32>(center + offset * 2.0 / scatter.viewport, 0.0, 1.0);
+    out.color = series.color;
+    out.offset = offset;
+    out.radius = radius;
+    return out;
+}
+"#;
+
+/// Vertex shader for instanced glyph markers
+///
+/// Every point is one instance of a small marker mesh (circle, triangle,
+/// custom shape...) defined in unit coordinates. The mesh is scaled so the
+/// unit circle spans `size` pixels and is placed at the point's position, so
+/// complex markers cost one mesh upload rather than a mesh per point.
+///
+/// Slot 0 is the Rust Vertex struct stepped per instance (locations 0-2);
+/// slot 1 holds the mesh corners (location 3).
+pub const GLYPH_VERTEX_SHADER: &str = r#"
+struct GlyphUniforms {
+    viewport: vec2<f32>,
+}
+
+@group(0) @binding(0)
+var<uniform> glyph: GlyphUniforms;
+
+struct InstanceInput {
+    @location(0) position: vec2<f32>,
+    @location(1) color: vec4<f32>,
+    @location(2) size: f32,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+}
+
+@vertex
+fn vs_main(instance: InstanceInput, @location(3) corner: vec2<f32>) -> VertexOutput {
+    // Unit mesh -> pixels (radius size / 2) -> clip space (2 / viewport per pixel)
+    let offset = corner * instance.size / glyph.viewport;
+
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(instance.position + offset, 0.0, 1.0);
+    out.color = instance.color;
+    return out;
+}
+"#;
+
+/// Vertex shader for text
+///
+/// Each glyph is a quad (two triangles) already laid out in clip space by
+/// `text::TextRenderer`; this only passes the atlas coordinates and color on.
+pub const TEXT_VERTEX_SHADER: &str = r#"
+struct VertexInput {
+    @location(0) position: vec2<f32>,
+    @location(1) uv: vec2<f32>,
+    @location(2) color: vec4<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+    @location(1) color: vec4<f32>,
+}
+
+@vertex
+fn vs_main(vertex: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(vertex.position, 0.0, 1.0);
+    out.uv = vertex.uv;
+    out.color = vertex.color;
+    return out;
+}
+"#;
+
+/// Fragment shader for text
+///
+/// The glyph atlas stores coverage (0-1) in its red channel; it scales the
+/// text color's alpha, so edges are antialiased by ordinary alpha blending.
+pub const TEXT_FRAGMENT_SHADER: &str = r#"
+@group(0) @binding(0)
+var atlas: texture_2d<f32>;
+
+@group(0) @binding(1)
+var atlas_sampler: sampler;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+    @location(1) color: vec4<f32>,
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let coverage = textureSample(atlas, atlas_sampler, in.uv).r;
+    return vec4<f32>(in.color.rgb, in.color.a * coverage);
+}
+"#;
+
+/// Vertex shader for the picking pass: scatter quads carrying point ids
+///
+/// Places markers exactly like `SCATTER_VERTEX_SHADER`, grown by `radius`
+/// pixels of slack so small points are easy to hover. Each instance's id is
+/// the layer's `first_id` plus its index in the series plus one, so that 0
+/// means "no point" in the cleared id buffer.
+pub const PICK_VERTEX_SHADER: &str = r#"
+struct PickUniforms {
+    viewport: vec2<f32>,
+    radius: f32,
+    first_id: u32,
+}
+
+@group(0) @binding(0)
+var<uniform> pick: PickUniforms;
+
+struct CameraUniforms {
+    view: mat4x4<f32>,
+}
+
+@group(1) @binding(0)
+var<uniform> camera: CameraUniforms;
+
+struct InstanceInput {
+    @location(0) position: vec2<f32>,
+    @location(1) size: f32,
+    @location(2) index: u32,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) offset: vec2<f32>,
+    @location(1) radius: f32,
+    @location(2) @interpolate(flat) id: u32,
+}
+
+@vertex
+fn vs_main(instance: InstanceInput, @builtin(vertex_index) index: u32) -> VertexOutput {
+    var corners = array<vec2<f32>, 6>(
+        vec2<f32>(-1.0, -1.0), vec2<f32>(1.0, -1.0), vec2<f32>(1.0, 1.0),
+        vec2<f32>(-1.0, -1.0), vec2<f32>(1.0, 1.0), vec2<f32>(-1.0, 1.0),
+    );
+
+    let radius = max(instance.size, 1.0) * 0.5 + pick.radius;
+    let offset = corners[index] * radius;
+
+    var out: VertexOutput;
+    let center = (camera.view * vec4<f32>(instance.position, 0.0, 1.0)).xy;
+    out.clip_position = vec4<f32>(center + offset * 2.0 / pick.viewport, 0.0, 1.0);
+    out.offset = offset;
+    out.radius = radius;
+    out.id = pick.first_id + instance.index + 1u;
+    return out;
+}
+"#;
+
+/// Fragment shader for the picking pass: writes the point id inside the
+/// marker's circle to an `R32Uint` target
+pub const PICK_FRAGMENT_SHADER: &str = r#"
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) offset: vec2<f32>,
+    @location(1) radius: f32,
+    @location(2) @interpolate(flat) id: u32,
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) u32 {
+    if length(in.offset) > in.radius {
+        discard;
+    }
+    return in.id;
+}
+"#;
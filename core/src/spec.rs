@@ -0,0 +1,198 @@
+//! Serialized figure specs
+//!
+//! A `FigureSpec` is a `Figure` as plain JSON: size, title, axis labels,
+//! limits and every series with its data and style. A server (Python or
+//! Rust) builds a figure and sends `to_json`; a browser hands the text to
+//! `HelionChart.fromSpec` and only renders it. The format is versioned so
+//! newer specs are rejected clearly instead of half-drawn.
+
+use crate::error::HelionError;
+use crate::figure::{ChartKind, Figure};
+use crate::scale::AxisScale;
+use serde::{Deserialize, Serialize};
+
+/// Version written by `FigureSpec::from_figure`; specs with a higher
+/// version are refused
+pub const SPEC_VERSION: u32 = 1;
+
+/// One series of a spec
+///
+/// For `"bars"`, `x` holds the bar edges, one more than the heights in `y`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SeriesSpec {
+    #[serde(default)]
+    pub kind: ChartKind,
+    pub x: Vec<f32>,
+    pub y: Vec<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// `SeriesStyle` keys to set; the others keep what `Figure::scatter` or
+    /// `Figure::line` would give, including the next color of the cycle
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<serde_json::Value>,
+}
+
+/// A whole figure as JSON
+///
+/// # Example
+/// ```
+/// use helion_core::spec::FigureSpec;
+///
+/// let json = r#"{
+///     "width": 640, "height": 480, "title": "Demo",
+///     "series": [{"kind": "line", "x": [0, 1, 2], "y": [1, 0, 1], "style": {"color": "tomato"}}]
+/// }"#;
+/// let figure = FigureSpec::from_json(json).unwrap().into_figure().unwrap();
+/// assert_eq!(figure.len(), 1);
+/// assert_eq!(figure.text().title.as_deref(), Some("Demo"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FigureSpec {
+    #[serde(default = "default_version")]
+    pub version: u32,
+    pub width: f32,
+    pub height: f32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub xlabel: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ylabel: Option<String>,
+    /// `[min, max]`; fitted to the data when missing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub xlim: Option<(f32, f32)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ylim: Option<(f32, f32)>,
+    /// `"linear"`, `"log10"` or `{"time": {"origin": <unix seconds>}}`
+    #[serde(default, skip_serializing_if = "is_linear")]
+    pub xscale: AxisScale,
+    #[serde(default, skip_serializing_if = "is_linear")]
+    pub yscale: AxisScale,
+    /// Draw gridlines, ticks and frame with the default options
+    #[serde(default = "default_axes")]
+    pub axes: bool,
+    #[serde(default)]
+    pub series: Vec<SeriesSpec>,
+}
+
+fn default_version() -> u32 {
+    SPEC_VERSION
+}
+
+fn default_axes() -> bool {
+    true
+}
+
+fn is_linear(scale: &AxisScale) -> bool {
+    *scale == AxisScale::Linear
+}
+
+impl FigureSpec {
+    /// Parse a spec
+    ///
+    /// # Returns
+    /// `HelionError::InvalidOption` describing the first problem: malformed
+    /// JSON, an unknown key, a version newer than `SPEC_VERSION`, or a
+    /// symlog scale without a positive `linthresh`
+    pub fn from_json(json: &str) -> Result<Self, HelionError> {
+        let spec: Self =
+            serde_json::from_str(json).map_err(|e| HelionError::InvalidOption(format!("Invalid figure spec: {}", e)))?;
+        if spec.version > SPEC_VERSION {
+            return Err(HelionError::InvalidOption(format!(
+                "Figure spec version {} is newer than the supported version {}",
+                spec.version, SPEC_VERSION
+            )));
+        }
+        for (name, scale) in [("xscale", spec.xscale), ("yscale", spec.yscale)] {
+            if let AxisScale::SymLog { linthresh } = scale {
+                if !(linthresh > 0.0 && linthresh.is_finite()) {
+                    return Err(HelionError::InvalidOption(format!(
+                        "{} linthresh must be a positive number, got {}",
+                        name, linthresh
+                    )));
+                }
+            }
+        }
+        Ok(spec)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("FigureSpec always serializes")
+    }
+
+    /// Spec describing `figure`; axes options other than on/off and the
+    /// heatmap are not kept
+    pub fn from_figure(figure: &Figure) -> Self {
+        let text = figure.text();
+        Self {
+            version: SPEC_VERSION,
+            width: figure.width(),
+            height: figure.height(),
+            title: text.title.clone(),
+            xlabel: text.xlabel.clone(),
+            ylabel: text.ylabel.clone(),
+            xlim: figure.xlim(),
+            ylim: figure.ylim(),
+            xscale: figure.xscale(),
+            yscale: figure.yscale(),
+            axes: figure.axes().is_some(),
+            series: figure
+                .series()
+                .iter()
+                .map(|series| SeriesSpec {
+                    kind: series.kind,
+                    x: series.x().to_vec(),
+                    y: series.y().to_vec(),
+                    label: series.label.clone(),
+                    style: Some(serde_json::to_value(&series.style).expect("SeriesStyle always serializes")),
+                })
+                .collect(),
+        }
+    }
+
+    /// Build the figure
+    ///
+    /// # Returns
+    /// `HelionError::ShapeMismatch` if a series has more x than y values or
+    /// the other way round, `HelionError::InvalidOption` for an invalid
+    /// style
+    pub fn into_figure(self) -> Result<Figure, HelionError> {
+        let mut figure = Figure::new(self.width, self.height);
+        figure.set_title(self.title);
+        figure.set_xlabel(self.xlabel);
+        figure.set_ylabel(self.ylabel);
+        figure.set_xlim(self.xlim);
+        figure.set_ylim(self.ylim);
+        figure.set_xscale(self.xscale);
+        figure.set_yscale(self.yscale);
+        if !self.axes {
+            figure.set_axes(None);
+        }
+        for spec in self.series {
+            let series = match spec.kind {
+                ChartKind::Scatter => figure.scatter(spec.x, spec.y)?,
+                ChartKind::Line => figure.line(spec.x, spec.y)?,
+                ChartKind::Bars => figure.bars(spec.x, spec.y)?,
+            };
+            if let Some(style) = spec.style {
+                series.style = series.style.patched(&style.to_string()).map_err(HelionError::InvalidOption)?;
+            }
+            series.label = spec.label;
+        }
+        Ok(figure)
+    }
+}
+
+/// `helion.normalizeFigureSpec(json)` from JavaScript: validate a spec and
+/// return it with every default filled in (series colors from the cycle,
+/// full styles), so the page renders exactly what Rust would
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen::prelude::wasm_bindgen(js_name = normalizeFigureSpec)]
+pub fn normalize_figure_spec_js(json: &str) -> Result<String, wasm_bindgen::JsValue> {
+    FigureSpec::from_json(json)
+        .and_then(FigureSpec::into_figure)
+        .map(|figure| FigureSpec::from_figure(&figure).to_json())
+        .map_err(|e| wasm_bindgen::JsValue::from_str(&e.to_string()))
+}
@@ -0,0 +1,170 @@
+/// What one renderer drew in a frame
+///
+/// `vertices` counts vertex shader invocations, so an instanced draw of a
+/// 48-vertex marker at 1000 points reports 48 000 vertices and 1000
+/// instances. Plain (non-instanced) draws report 0 instances.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DrawStats {
+    pub vertices: u64,
+    pub instances: u64,
+    pub draw_calls: u32,
+    /// Bytes written to GPU buffers since the previous frame
+    pub bytes_uploaded: u64,
+}
+
+impl DrawStats {
+    /// Stats for plain draws of `vertices` split over `draw_calls` calls
+    pub fn draws(vertices: u64, draw_calls: u32) -> Self {
+        Self {
+            vertices,
+            draw_calls,
+            ..Default::default()
+        }
+    }
+
+    /// Stats for instanced draws of a `mesh_vertices`-vertex mesh
+    pub fn instanced(mesh_vertices: u64, instances: u64, draw_calls: u32) -> Self {
+        Self {
+            vertices: mesh_vertices * instances,
+            instances,
+            draw_calls,
+            bytes_uploaded: 0,
+        }
+    }
+
+    pub fn with_bytes_uploaded(mut self, bytes: u64) -> Self {
+        self.bytes_uploaded = bytes;
+        self
+    }
+}
+
+impl std::ops::AddAssign for DrawStats {
+    fn add_assign(&mut self, other: DrawStats) {
+        self.vertices += other.vertices;
+        self.instances += other.instances;
+        self.draw_calls += other.draw_calls;
+        self.bytes_uploaded += other.bytes_uploaded;
+    }
+}
+
+/// Per-series draw statistics for one frame
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FrameReport {
+    /// Index of the frame, counting from 0
+    pub frame: u64,
+    /// Stats of each recorded series, in draw order
+    pub series: Vec<(String, DrawStats)>,
+}
+
+impl FrameReport {
+    /// Sum over all series
+    pub fn total(&self) -> DrawStats {
+        let mut total = DrawStats::default();
+        for (_, stats) in &self.series {
+            total += *stats;
+        }
+        total
+    }
+
+    /// Stats of the series recorded under `label`
+    pub fn get(&self, label: &str) -> Option<&DrawStats> {
+        self.series.iter().find(|(l, _)| l == label).map(|(_, s)| s)
+    }
+
+    /// Text lines for a stats overlay: a totals line, then one per series
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::stats::{DrawStats, FrameReport};
+    ///
+    /// let report = FrameReport {
+    ///     frame: 7,
+    ///     series: vec![("points".into(), DrawStats::draws(1500, 1).with_bytes_uploaded(60_000))],
+    /// };
+    /// assert_eq!(report.overlay_lines()[0], "frame 7: 1 draws, 1.5K vertices, 58.6 KiB uploaded");
+    /// ```
+    pub fn overlay_lines(&self) -> Vec<String> {
+        let total = self.total();
+        let mut lines = vec![format!(
+            "frame {}: {} draws, {} vertices, {} uploaded",
+            self.frame,
+            total.draw_calls,
+            format_count(total.vertices),
+            format_bytes(total.bytes_uploaded)
+        )];
+        for (label, stats) in &self.series {
+            let mut line = format!("  {}: {} vertices", label, format_count(stats.vertices));
+            if stats.instances > 0 {
+                line.push_str(&format!(", {} instances", format_count(stats.instances)));
+            }
+            lines.push(line);
+        }
+        lines
+    }
+}
+
+/// Collects `DrawStats` as a frame is drawn and keeps the last finished report
+///
+/// ```ignore
+/// recorder.begin_frame();
+/// scatter.render_to_pass(&mut render_pass);
+/// recorder.record("scatter", scatter.frame_stats());
+/// recorder.end_frame();
+/// println!("{:?}", recorder.last_report());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FrameRecorder {
+    next_frame: u64,
+    current: FrameReport,
+    last: Option<FrameReport>,
+}
+
+impl FrameRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a new frame, discarding anything recorded but not ended
+    pub fn begin_frame(&mut self) {
+        self.current = FrameReport {
+            frame: self.next_frame,
+            series: Vec::new(),
+        };
+    }
+
+    pub fn record(&mut self, label: &str, stats: DrawStats) {
+        self.current.series.push((label.to_string(), stats));
+    }
+
+    /// Finish the frame and make it the `last_report`
+    pub fn end_frame(&mut self) -> &FrameReport {
+        self.next_frame += 1;
+        self.last.insert(std::mem::take(&mut self.current))
+    }
+
+    /// Report of the most recently finished frame
+    pub fn last_report(&self) -> Option<&FrameReport> {
+        self.last.as_ref()
+    }
+}
+
+fn format_count(n: u64) -> String {
+    match n {
+        0..=999 => n.to_string(),
+        1_000..=999_999 => format!("{:.1}K", n as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.1}M", n as f64 / 1e6),
+        _ => format!("{:.1}B", n as f64 / 1e9),
+    }
+}
+
+fn format_bytes(n: u64) -> String {
+    const KIB: f64 = 1024.0;
+    let n = n as f64;
+    if n < KIB {
+        format!("{} B", n)
+    } else if n < KIB * KIB {
+        format!("{:.1} KiB", n / KIB)
+    } else {
+        format!("{:.1} MiB", n / (KIB * KIB))
+    }
+}
@@ -0,0 +1,189 @@
+use crate::axes::LineDash;
+use crate::data::{ChartData, Color};
+use crate::glyph::GlyphShape;
+use serde::{Deserialize, Serialize};
+
+/// Marker drawn at each point of a series
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Marker {
+    #[default]
+    Circle,
+    Square,
+    Diamond,
+    Triangle,
+    Cross,
+}
+
+impl Marker {
+    /// Shape `GlyphRenderer` draws for this marker
+    pub fn glyph(self) -> GlyphShape {
+        match self {
+            Marker::Circle => GlyphShape::default(),
+            Marker::Square => GlyphShape::Square,
+            Marker::Diamond => GlyphShape::Diamond,
+            Marker::Triangle => GlyphShape::Triangle,
+            Marker::Cross => GlyphShape::Cross,
+        }
+    }
+}
+
+/// Appearance of one series
+///
+/// The single representation shared by chart specs, themes and the
+/// programmatic APIs. Every field has a default, so a JSON style only needs
+/// the keys it changes; unknown keys are rejected so typos don't pass
+/// silently.
+///
+/// # Example
+/// ```
+/// use helion_core::axes::LineDash;
+/// use helion_core::style::{Marker, SeriesStyle};
+///
+/// let style = SeriesStyle::from_json(
+///     r#"{"marker": "diamond", "color": "tomato", "dash": {"dashed": {"on": 4, "off": 2}}}"#,
+/// )
+/// .unwrap();
+/// assert_eq!(style.marker, Marker::Diamond);
+/// assert_eq!(style.dash, LineDash::Dashed { on: 4.0, off: 2.0 });
+/// assert_eq!(style.size, SeriesStyle::default().size);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SeriesStyle {
+    pub marker: Marker,
+    /// Marker size in pixels
+    pub size: f32,
+    /// Hex string, CSS name or [r, g, b(, a)] array in JSON
+    pub color: Color,
+    /// Line width in pixels, for series drawn as lines
+    pub line_width: f32,
+    pub dash: LineDash,
+    /// Multiplied into `color`'s alpha
+    pub opacity: f32,
+    /// Draw order; series with higher values are drawn on top
+    pub z_order: i32,
+}
+
+impl Default for SeriesStyle {
+    fn default() -> Self {
+        Self {
+            marker: Marker::Circle,
+            size: 2.0,
+            color: Color::default(),
+            line_width: 1.0,
+            dash: LineDash::Solid,
+            opacity: 1.0,
+            z_order: 0,
+        }
+    }
+}
+
+impl SeriesStyle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_marker(mut self, marker: Marker) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    pub fn with_size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn with_line_width(mut self, line_width: f32) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    pub fn with_dash(mut self, dash: LineDash) -> Self {
+        self.dash = dash;
+        self
+    }
+
+    pub fn with_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    pub fn with_z_order(mut self, z_order: i32) -> Self {
+        self.z_order = z_order;
+        self
+    }
+
+    /// Parse a style from JSON, filling missing keys with defaults
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Invalid series style: {}", e))
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("SeriesStyle always serializes")
+    }
+
+    /// Copy of this style with the keys present in `json` overridden
+    ///
+    /// Lets a spec or a user override only parts of a theme's style, where
+    /// `from_json` would reset the missing keys to the defaults instead.
+    ///
+    /// # Example
+    /// ```
+    /// use helion_core::style::SeriesStyle;
+    ///
+    /// let theme = SeriesStyle::new().with_size(6.0).with_opacity(0.5);
+    /// let series = theme.patched(r#"{"opacity": 0.8}"#).unwrap();
+    /// assert_eq!((series.size, series.opacity), (6.0, 0.8));
+    /// ```
+    pub fn patched(&self, json: &str) -> Result<Self, String> {
+        let overrides: serde_json::Value =
+            serde_json::from_str(json).map_err(|e| format!("Invalid series style: {}", e))?;
+        let serde_json::Value::Object(overrides) = overrides else {
+            return Err("Invalid series style: expected a JSON object".to_string());
+        };
+
+        fn field<T: serde::de::DeserializeOwned>(key: &str, value: serde_json::Value) -> Result<T, String> {
+            serde_json::from_value(value).map_err(|e| format!("Invalid series style '{}': {}", key, e))
+        }
+
+        // Field by field rather than through a serialized copy of `self`, so
+        // the base color isn't rounded to 8 bits on the way
+        let mut style = self.clone();
+        for (key, value) in overrides {
+            match key.as_str() {
+                "marker" => style.marker = field(&key, value)?,
+                "size" => style.size = field(&key, value)?,
+                "color" => style.color = field(&key, value)?,
+                "line_width" => style.line_width = field(&key, value)?,
+                "dash" => style.dash = field(&key, value)?,
+                "opacity" => style.opacity = field(&key, value)?,
+                "z_order" => style.z_order = field(&key, value)?,
+                _ => return Err(format!("Invalid series style: unknown field '{}'", key)),
+            }
+        }
+        Ok(style)
+    }
+
+    /// Color with `opacity` folded into its alpha, as it is uploaded
+    pub fn effective_color(&self) -> Color {
+        let mut color = self.color;
+        color.a = (color.a * self.opacity).clamp(0.0, 1.0);
+        color
+    }
+
+    /// Set every point's color and size in `data` from this style
+    pub fn apply(&self, data: &mut ChartData) {
+        let color = self.effective_color();
+        let size = if self.size.is_finite() { self.size.max(0.0) } else { 0.0 };
+        for vertex in &mut data.vertices {
+            vertex.color = [color.r, color.g, color.b, color.a];
+            vertex.size = size;
+        }
+    }
+}
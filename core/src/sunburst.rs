@@ -0,0 +1,298 @@
+use crate::colorspace::ColorSpace;
+use crate::data::{ChartData, Color};
+use crate::error::HelionError;
+use crate::figure::DEFAULT_COLOR_CYCLE;
+use crate::hierarchy::{Hierarchy, HierarchyNode};
+use crate::polygon::{polygon_data, Polygon};
+use crate::text::{HAlign, TextSection, VAlign};
+use crate::windrose::wedge;
+
+/// Arc segments of a full circle; wedges get their share, at least one
+const CIRCLE_SEGMENTS: f32 = 128.0;
+
+/// Degrees trimmed off each side of a wedge so neighbours of the same
+/// color stay apart
+const WEDGE_GAP: f32 = 0.25;
+
+/// Radius left empty inside each ring, as a fraction of the chart's radius
+const RING_GAP: f32 = 0.004;
+
+/// How much lighter each level below the top is drawn, as a mix towards
+/// white
+const DEPTH_LIGHTEN: f32 = 0.15;
+
+/// Where one node is drawn: bearings in degrees clockwise from north and
+/// radii as fractions of the chart's radius
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SunburstArc {
+    /// Index of the node in `Sunburst::hierarchy`
+    pub node: usize,
+    pub start: f32,
+    pub end: f32,
+    pub inner: f32,
+    pub outer: f32,
+}
+
+impl SunburstArc {
+    /// Whether the point at `bearing` and `radius` falls on the arc
+    pub fn contains(&self, bearing: f32, radius: f32) -> bool {
+        radius >= self.inner && radius <= self.outer && bearing >= self.start && bearing <= self.end
+    }
+}
+
+/// Hierarchy of values drawn as rings of wedges around a center: each
+/// node's children share out its angle in proportion to their values, one
+/// ring further out
+///
+/// The node in focus fills the center disc and its descendants the rings
+/// around it, so at first the root is in the middle. `click` zooms in to a
+/// group clicked on, making it the focus with its subtree spread around
+/// the full circle, and clicking the center zooms back out a level. Each
+/// top-level group takes a color from the palette, and levels below it get
+/// lighter shades of it.
+///
+/// # Example
+/// ```
+/// use helion_core::hierarchy::HierarchyNode;
+/// use helion_core::sunburst::Sunburst;
+///
+/// let root = HierarchyNode::group("Budget", vec![
+///     HierarchyNode::group("Staff", vec![HierarchyNode::leaf("Salaries", 6.0), HierarchyNode::leaf("Travel", 2.0)]),
+///     HierarchyNode::leaf("Rent", 8.0),
+/// ]);
+/// let mut sunburst = Sunburst::new(root).unwrap();
+/// // "Staff" takes the first half turn of the inner ring
+/// let staff = sunburst.arcs()[1];
+/// assert_eq!((staff.node, staff.start, staff.end), (1, 0.0, 180.0));
+///
+/// // Clicking it, right of the center, zooms in to it
+/// assert_eq!(sunburst.node_at(250.0, 100.0, 400.0, 200.0), Some(1));
+/// assert_eq!(sunburst.click(250.0, 100.0, 400.0, 200.0), Some(1));
+/// assert_eq!(sunburst.focus(), 1);
+/// // and clicking the center zooms back out
+/// assert_eq!(sunburst.click(200.0, 100.0, 400.0, 200.0), Some(0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sunburst {
+    tree: Hierarchy,
+    focus: usize,
+    max_depth: Option<usize>,
+    colors: Vec<Color>,
+    color_space: ColorSpace,
+    font_size: f32,
+}
+
+impl Sunburst {
+    /// Sunburst of `root` and its descendants
+    ///
+    /// # Returns
+    /// `HelionError::InvalidOption` if a leaf's value is negative or not
+    /// finite, as for `Hierarchy::new`
+    pub fn new(root: HierarchyNode) -> Result<Self, HelionError> {
+        Ok(Self {
+            tree: Hierarchy::new(root)?,
+            focus: 0,
+            max_depth: None,
+            colors: DEFAULT_COLOR_CYCLE.iter().map(|hex| Color::from_hex(hex)).collect(),
+            color_space: ColorSpace::default(),
+            font_size: 12.0,
+        })
+    }
+
+    /// Show at most `levels` rings around the center, or every level for
+    /// `None` (the default)
+    pub fn with_max_depth(mut self, levels: Option<usize>) -> Self {
+        self.max_depth = levels.map(|levels| levels.max(1));
+        self
+    }
+
+    /// Fills of the top-level nodes, cycled when there are fewer colors
+    /// than nodes; the Tableau 10 palette by default
+    pub fn with_colors(mut self, colors: Vec<Color>) -> Self {
+        if !colors.is_empty() {
+            self.colors = colors;
+        }
+        self
+    }
+
+    /// Space the shades of deeper levels are mixed in, Oklab by default
+    pub fn with_color_space(mut self, color_space: ColorSpace) -> Self {
+        self.color_space = color_space;
+        self
+    }
+
+    /// Size of the wedge labels in pixels, 12 by default
+    pub fn with_font_size(mut self, size: f32) -> Self {
+        self.font_size = size;
+        self
+    }
+
+    /// The nodes drawn, with their labels and values
+    pub fn hierarchy(&self) -> &Hierarchy {
+        &self.tree
+    }
+
+    /// Node in the center, 0 (the root) until zoomed in
+    pub fn focus(&self) -> usize {
+        self.focus
+    }
+
+    /// Put `node` in the center, its descendants around it
+    ///
+    /// # Returns
+    /// Whether the focus changed; leaves and nodes that don't exist can't
+    /// be focused
+    pub fn zoom_to(&mut self, node: usize) -> bool {
+        let focusable = node < self.tree.len() && !self.tree.children(node).is_empty();
+        let changed = focusable && node != self.focus;
+        if changed {
+            self.focus = node;
+        }
+        changed
+    }
+
+    /// Zoom in response to a click at pixel (`x`, `y`) of a `width` x
+    /// `height` viewport: into the group clicked on, or out to the focus's
+    /// parent when the center is clicked
+    ///
+    /// # Returns
+    /// The new focus, or `None` if the click changed nothing
+    pub fn click(&mut self, x: f32, y: f32, width: f32, height: f32) -> Option<usize> {
+        let node = self.node_at(x, y, width, height)?;
+        let target = match node == self.focus {
+            true => self.tree.parent(node)?,
+            false => node,
+        };
+        self.zoom_to(target).then_some(target)
+    }
+
+    /// Fill of `node`: its top-level ancestor's color, lighter for each
+    /// level below it; the root is light gray
+    pub fn node_color(&self, node: usize) -> Color {
+        let Some(rank) = self.tree.branch(node) else {
+            return Color::new(0.88, 0.88, 0.88, 1.0);
+        };
+        let base = self.colors[rank % self.colors.len()];
+        let lighten = (DEPTH_LIGHTEN * self.tree.depth(node).saturating_sub(1) as f32).min(0.6);
+        self.color_space.mix(base, Color::new(1.0, 1.0, 1.0, base.a), lighten)
+    }
+
+    /// Rings shown around the center disc
+    fn levels(&self) -> usize {
+        let below = (0..self.tree.len())
+            .filter(|&node| self.tree.is_within(node, self.focus))
+            .map(|node| self.tree.depth(node) - self.tree.depth(self.focus))
+            .max()
+            .unwrap_or(0);
+        self.max_depth.map_or(below, |limit| below.min(limit))
+    }
+
+    /// The focus as a full disc, then every node shown under it, parents
+    /// before their children
+    ///
+    /// Rings are equally thick, the center disc as wide as one of them.
+    /// Nodes with no value get no arc.
+    pub fn arcs(&self) -> Vec<SunburstArc> {
+        let ring = 1.0 / (self.levels() + 1) as f32;
+        let mut arcs = vec![SunburstArc { node: self.focus, start: 0.0, end: 360.0, inner: 0.0, outer: ring }];
+        self.child_arcs(self.focus, 0.0, 360.0, 1, ring, &mut arcs);
+        arcs
+    }
+
+    fn child_arcs(&self, node: usize, start: f32, end: f32, level: usize, ring: f32, arcs: &mut Vec<SunburstArc>) {
+        let total = self.tree.value(node);
+        if level > self.levels() || total <= 0.0 {
+            return;
+        }
+        let mut from = start;
+        for &child in self.tree.children(node) {
+            let value = self.tree.value(child);
+            if value <= 0.0 {
+                continue;
+            }
+            let to = from + (end - start) * value / total;
+            arcs.push(SunburstArc {
+                node: child,
+                start: from,
+                end: to,
+                inner: level as f32 * ring,
+                outer: (level + 1) as f32 * ring,
+            });
+            self.child_arcs(child, from, to, level + 1, ring, arcs);
+            from = to;
+        }
+    }
+
+    /// Radius of the chart and its center in pixels: a circle filling the
+    /// shorter side of a `width` x `height` viewport
+    fn frame(width: f32, height: f32) -> (f32, [f32; 2]) {
+        (width.min(height) / 2.0, [width / 2.0, height / 2.0])
+    }
+
+    /// Node drawn under pixel (`x`, `y`) of a `width` x `height` viewport,
+    /// if any; the focus for the center disc
+    pub fn node_at(&self, x: f32, y: f32, width: f32, height: f32) -> Option<usize> {
+        let (radius, [cx, cy]) = Self::frame(width, height);
+        if radius <= 0.0 {
+            return None;
+        }
+        let (dx, dy) = ((x - cx) / radius, (cy - y) / radius);
+        let bearing = dx.atan2(dy).to_degrees().rem_euclid(360.0);
+        let distance = dx.hypot(dy);
+        self.arcs().iter().find(|arc| arc.contains(bearing, distance)).map(|arc| arc.node)
+    }
+
+    /// Wedges for `PolygonRenderer` in a `width` x `height` viewport, as a
+    /// circle centered in the shorter dimension
+    pub fn chart_data(&self, width: f32, height: f32) -> ChartData {
+        let (polygons, colors): (Vec<Polygon>, Vec<Color>) = self
+            .arcs()
+            .iter()
+            .map(|arc| {
+                let span = arc.end - arc.start;
+                let gap = if span >= 360.0 { 0.0 } else { WEDGE_GAP.min(span / 4.0) };
+                let inner = if arc.inner > 0.0 { arc.inner + RING_GAP } else { 0.0 };
+                let segments = (span / 360.0 * CIRCLE_SEGMENTS).ceil().max(1.0) as usize;
+                let shape = wedge(arc.start + gap, arc.end - gap, inner, arc.outer, segments);
+                (shape, self.node_color(arc.node))
+            })
+            .unzip();
+        let (x_half, y_half) = match width >= height {
+            true => (height / width.max(1.0), 1.0),
+            false => (1.0, width / height.max(1.0)),
+        };
+        let unit = ((-1.0, 1.0), (-1.0, 1.0));
+        polygon_data(&polygons, &colors, unit, (-x_half, x_half), (-y_half, y_half), width, height)
+    }
+
+    /// Labels for `TextRenderer`, centered on each wedge wide and long
+    /// enough to hold them and on the center disc
+    pub fn text_sections(&self, width: f32, height: f32) -> Vec<TextSection> {
+        let (radius, [cx, cy]) = Self::frame(width, height);
+        // Rough width of a character, short of measuring with the atlas
+        let char_width = self.font_size * 0.6;
+        self.arcs()
+            .iter()
+            .filter_map(|arc| {
+                let label = self.tree.label(arc.node);
+                let thickness = (arc.outer - arc.inner) * radius;
+                let (position, room) = match arc.inner > 0.0 {
+                    true => {
+                        let middle = (arc.inner + arc.outer) / 2.0;
+                        let bearing = ((arc.start + arc.end) / 2.0).to_radians();
+                        let position = [cx + middle * radius * bearing.sin(), cy - middle * radius * bearing.cos()];
+                        let length = (arc.end - arc.start).to_radians() * middle * radius;
+                        (position, length.min(thickness))
+                    }
+                    false => ([cx, cy], 2.0 * thickness),
+                };
+                let fits = thickness >= self.font_size * 1.2 && room >= label.chars().count() as f32 * char_width;
+                fits.then(|| {
+                    TextSection::new(label, position, self.font_size)
+                        .with_color(Color::new(0.1, 0.1, 0.1, 1.0))
+                        .with_align(HAlign::Center, VAlign::Middle)
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,266 @@
+use crate::camera::{Camera2D, CameraBinding};
+use crate::data::{Color, Point2D, Vertex};
+use crate::error::HelionError;
+use crate::line::LineRenderer;
+use crate::renderer::{RenderOptions, Renderer};
+use crate::ring::wrapped_ranges;
+use crate::stats::DrawStats;
+use std::ops::Range;
+
+/// Slot layout of a sweeping strip chart, as drawn by `SweepRenderer`
+///
+/// The panel holds `capacity` samples at fixed x positions, left to right.
+/// Sample `n` goes to slot `n % capacity`, so once the trace reaches the
+/// right edge it starts again at the left, overwriting the oldest samples
+/// as it goes, like an ECG monitor or an oscilloscope in roll-free mode.
+/// The slots just ahead of the newest sample are left blank: the erase
+/// bar, which shows where the trace is and keeps it from joining onto the
+/// previous pass.
+///
+/// Each sample is written once and never moves, so a renderer only uploads
+/// the new samples, however fast they arrive.
+///
+/// # Example
+/// ```
+/// use helion_core::sweep::Sweep;
+///
+/// let mut sweep = Sweep::new(10).unwrap().with_erase_width(0.2);
+/// let (first, vertices) = sweep.advance(&[0.0; 12]);
+/// // Only the last pass is kept, starting from where sample 2 goes
+/// assert_eq!((first, vertices.len()), (2, 10));
+/// // Two samples into the second pass; slots 2 and 3 are being erased
+/// assert_eq!(sweep.head(), 2);
+/// assert_eq!(sweep.visible_slots(), [0..2, 4..10]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sweep {
+    capacity: usize,
+    /// Samples pushed so far
+    written: u64,
+    y_range: (f32, f32),
+    erase: usize,
+    color: Color,
+    /// Value a NaN sample repeats
+    last: f32,
+}
+
+impl Sweep {
+    /// Sweep across `capacity` samples, e.g. the sample rate times the
+    /// seconds shown
+    ///
+    /// # Returns
+    /// `HelionError::InvalidOption` for fewer than 2 samples
+    pub fn new(capacity: usize) -> Result<Self, HelionError> {
+        if capacity < 2 {
+            return Err(HelionError::InvalidOption(format!(
+                "A sweep needs room for at least 2 samples, got {}",
+                capacity
+            )));
+        }
+        Ok(Self {
+            capacity,
+            written: 0,
+            y_range: (-1.0, 1.0),
+            erase: capacity.div_ceil(40),
+            color: Color::default(),
+            last: 0.0,
+        })
+    }
+
+    /// Sample values at the bottom and top of the panel, -1 to 1 by
+    /// default; values outside are clipped at the panel's edge
+    pub fn with_y_range(mut self, min: f32, max: f32) -> Self {
+        self.y_range = (min, max);
+        self.last = (min + max) / 2.0;
+        self
+    }
+
+    /// Width of the erase bar as a fraction of the panel, 0.025 by default
+    pub fn with_erase_width(mut self, fraction: f32) -> Self {
+        let slots = (fraction.clamp(0.0, 1.0) * self.capacity as f32).ceil() as usize;
+        self.erase = slots.min(self.capacity - 1);
+        self
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Samples shown across the panel
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Samples pushed so far, including overwritten ones
+    pub fn written(&self) -> u64 {
+        self.written
+    }
+
+    /// Slot the next sample goes to
+    pub fn head(&self) -> usize {
+        (self.written % self.capacity as u64) as usize
+    }
+
+    /// Slots left blank ahead of the newest sample
+    pub fn erase_slots(&self) -> usize {
+        self.erase
+    }
+
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    /// Clip-space x of `slot`, from -1 at the left edge to 1 at the right
+    pub fn slot_x(&self, slot: usize) -> f32 {
+        slot as f32 / (self.capacity - 1) as f32 * 2.0 - 1.0
+    }
+
+    /// Record `samples` and build their vertices
+    ///
+    /// Of more than `capacity` samples only the last `capacity` are kept,
+    /// the rest being overwritten within the same call. A NaN sample
+    /// repeats the value before it.
+    ///
+    /// # Returns
+    /// The slot the first vertex goes to, and the vertices, to be written
+    /// into consecutive slots wrapping around at the end
+    pub fn advance(&mut self, samples: &[f32]) -> (usize, Vec<Vertex>) {
+        let skip = samples.len().saturating_sub(self.capacity);
+        if let Some(&last) = samples[..skip].iter().rev().find(|sample| !sample.is_nan()) {
+            self.last = last;
+        }
+        self.written += skip as u64;
+        let first = self.head();
+        let (low, high) = self.y_range;
+        let span = if high != low { high - low } else { 1.0 };
+        let mut vertices = Vec::with_capacity(samples.len() - skip);
+        for &sample in &samples[skip..] {
+            if !sample.is_nan() {
+                self.last = sample;
+            }
+            let y = (self.last - low) / span * 2.0 - 1.0;
+            vertices.push(Vertex::new(Point2D::new(self.slot_x(self.head()), y), self.color, 1.0));
+            self.written += 1;
+        }
+        (first, vertices)
+    }
+
+    /// Runs of slots to draw as lines: from the left edge up to the newest
+    /// sample, and after the first pass, from past the erase bar to the
+    /// right edge
+    pub fn visible_slots(&self) -> [Range<usize>; 2] {
+        let head = self.head();
+        if self.written < self.capacity as u64 {
+            return [0..head, 0..0];
+        }
+        let resume = (head + self.erase).min(self.capacity);
+        [0..head, resume..self.capacity]
+    }
+}
+
+/// Strip chart renderer that sweeps new samples across the panel,
+/// overwriting old ones in place
+///
+/// Samples are written into a fixed vertex buffer at the slots `Sweep`
+/// gives them, so pushing a batch uploads only that batch, 40 bytes a
+/// sample, and each frame draws at most two line strips. That keeps
+/// signals sampled at tens of kHz cheap to show live, where scrolling the
+/// whole trace would re-upload every sample every frame.
+///
+/// # Example
+/// ```ignore
+/// let sweep = Sweep::new(5000)?.with_y_range(-2.0, 2.0);
+/// let mut ecg = SweepRenderer::new(device, format, sweep, &RenderOptions::default())?;
+/// // each time samples arrive
+/// ecg.push(queue, &samples);
+/// ```
+pub struct SweepRenderer {
+    render_pipeline: wgpu::RenderPipeline,
+    camera: CameraBinding,
+    /// One vertex per slot
+    vertex_buffer: wgpu::Buffer,
+    sweep: Sweep,
+    /// Bytes uploaded since the last `frame_stats`
+    uploaded_bytes: u64,
+}
+
+impl SweepRenderer {
+    /// Allocate the slots of `sweep` on the GPU
+    ///
+    /// # Returns
+    /// `HelionError::BufferTooLarge` if the buffer would exceed the device's
+    /// buffer size limit
+    pub fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        sweep: Sweep,
+        options: &RenderOptions,
+    ) -> Result<Self, HelionError> {
+        let size = (sweep.capacity() as u64).saturating_mul(std::mem::size_of::<Vertex>() as u64);
+        let max_buffer_size = device.limits().max_buffer_size;
+        if size > max_buffer_size {
+            return Err(HelionError::BufferTooLarge(format!(
+                "A sweep of {} samples needs {} bytes, over the device buffer limit of {} bytes",
+                sweep.capacity(),
+                size,
+                max_buffer_size
+            )));
+        }
+        let vertex_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Sweep Vertex Buffer"),
+            size,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let camera = CameraBinding::new(device);
+        let render_pipeline = LineRenderer::create_pipeline(device, format, camera.layout(), options);
+        Ok(Self {
+            render_pipeline,
+            camera,
+            vertex_buffer,
+            sweep,
+            uploaded_bytes: 0,
+        })
+    }
+
+    /// Write `samples` at the sweep's head, moving it and the erase bar on
+    pub fn push(&mut self, queue: &wgpu::Queue, samples: &[f32]) {
+        let (first, vertices) = self.sweep.advance(samples);
+        let item_size = std::mem::size_of::<Vertex>() as u64;
+        let (tail, wrapped) = wrapped_ranges(first as u64, vertices.len() as u64, self.sweep.capacity() as u64);
+        let split = (tail.end - tail.start) as usize;
+        for (slots, piece) in [(tail, &vertices[..split]), (wrapped, &vertices[split..])] {
+            if !piece.is_empty() {
+                queue.write_buffer(&self.vertex_buffer, slots.start * item_size, bytemuck::cast_slice(piece));
+            }
+        }
+        self.uploaded_bytes += vertices.len() as u64 * item_size;
+    }
+
+    pub fn sweep(&self) -> &Sweep {
+        &self.sweep
+    }
+
+    /// Pan or zoom by uploading a new view matrix; samples are untouched
+    pub fn set_camera(&mut self, queue: &wgpu::Queue, camera: &Camera2D) {
+        self.uploaded_bytes += self.camera.set(queue, camera);
+    }
+}
+
+impl Renderer for SweepRenderer {
+    fn render_to_pass<'rpass>(&'rpass mut self, render_pass: &mut wgpu::RenderPass<'rpass>) {
+        render_pass.set_pipeline(&self.render_pipeline);
+        render_pass.set_bind_group(0, self.camera.bind_group(), &[]);
+        render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        for slots in self.sweep.visible_slots().into_iter().filter(|slots| !slots.is_empty()) {
+            render_pass.draw(slots.start as u32..slots.end as u32, 0..1);
+        }
+    }
+
+    fn frame_stats(&mut self) -> DrawStats {
+        let runs = self.sweep.visible_slots().into_iter().filter(|slots| !slots.is_empty());
+        let (vertices, draws) = runs.fold((0, 0), |(vertices, draws), slots| (vertices + slots.len() as u64, draws + 1));
+        DrawStats::draws(vertices, draws).with_bytes_uploaded(std::mem::take(&mut self.uploaded_bytes))
+    }
+}
@@ -0,0 +1,684 @@
+use crate::data::Color;
+use crate::error::HelionError;
+use crate::renderer::{RenderOptions, Renderer};
+use crate::shaders::{TEXT_FRAGMENT_SHADER, TEXT_VERTEX_SHADER};
+use crate::stats::DrawStats;
+use ab_glyph::{point, Font, FontArc, GlyphId, PxScale, ScaleFont};
+use bytemuck::{Pod, Zeroable};
+use std::collections::HashMap;
+use wgpu::util::DeviceExt;
+
+/// Font used unless another is given: Cantarell Regular, under the SIL Open
+/// Font License 1.1 (see `assets/fonts/OFL.txt`)
+///
+/// Embedded because the web has no system fonts to fall back on.
+pub const DEFAULT_FONT: &[u8] = include_bytes!("../assets/fonts/Cantarell-Regular.ttf");
+
+/// Side of the square glyph atlas in pixels; within WebGL2's 2048 limit
+pub const ATLAS_SIZE: u32 = 1024;
+
+/// Empty pixels around each glyph so linear sampling doesn't bleed in
+/// neighbours
+const GLYPH_PADDING: u32 = 1;
+
+/// Horizontal placement of text relative to its anchor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+/// Vertical placement of text relative to its anchor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VAlign {
+    /// Anchor on the top of the first line
+    Top,
+    /// Anchor halfway down the block
+    Middle,
+    /// Anchor on the first line's baseline
+    #[default]
+    Baseline,
+    /// Anchor below the last line's descenders
+    Bottom,
+}
+
+/// A piece of text placed in the viewport
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextSection {
+    /// May span several lines separated by '\n'
+    pub text: String,
+    /// Anchor in pixels, origin at the top left
+    pub position: [f32; 2],
+    /// Font size in pixels
+    pub size: f32,
+    pub color: Color,
+    pub h_align: HAlign,
+    pub v_align: VAlign,
+    /// Counter-clockwise rotation about the anchor, in degrees
+    pub rotation: f32,
+}
+
+impl TextSection {
+    /// Black, left-aligned text with its baseline at `position`
+    pub fn new(text: impl Into<String>, position: [f32; 2], size: f32) -> Self {
+        Self {
+            text: text.into(),
+            position,
+            size,
+            color: Color::new(0.0, 0.0, 0.0, 1.0),
+            h_align: HAlign::Left,
+            v_align: VAlign::Baseline,
+            rotation: 0.0,
+        }
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn with_align(mut self, h_align: HAlign, v_align: VAlign) -> Self {
+        self.h_align = h_align;
+        self.v_align = v_align;
+        self
+    }
+
+    pub fn with_rotation(mut self, degrees: f32) -> Self {
+        self.rotation = degrees;
+        self
+    }
+}
+
+/// Where a rasterized glyph sits in the atlas
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AtlasGlyph {
+    pub uv_min: [f32; 2],
+    pub uv_max: [f32; 2],
+    /// Bitmap size in pixels
+    pub size: [f32; 2],
+    /// Top left of the bitmap relative to the pen position on the baseline
+    pub offset: [f32; 2],
+}
+
+/// One glyph laid out on screen
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GlyphQuad {
+    /// Top left, top right, bottom right, bottom left, in pixels
+    pub corners: [[f32; 2]; 4],
+    pub uv_min: [f32; 2],
+    pub uv_max: [f32; 2],
+    pub color: [f32; 4],
+}
+
+/// Glyph coverage bitmaps packed into one texture, rasterized on first use
+///
+/// Glyphs are cached per pixel size and packed in rows ("shelves"). When the
+/// atlas fills up, `overflowed` is set; the owner clears it and lays the
+/// text out again, which only rasterizes what is still shown.
+///
+/// # Example
+/// ```
+/// use helion_core::text::GlyphAtlas;
+///
+/// let atlas = GlyphAtlas::default();
+/// let [w, h] = atlas.measure("Helion", 12.0);
+/// assert!(w > 20.0 && w < 60.0);
+/// assert!(h >= 12.0);
+/// // A second line doubles the height, not the width
+/// assert_eq!(atlas.measure("Helion\nHelion", 12.0)[0], w);
+/// ```
+pub struct GlyphAtlas {
+    font: FontArc,
+    size: u32,
+    /// One coverage byte per texel
+    pixels: Vec<u8>,
+    glyphs: HashMap<(GlyphId, u32), Option<AtlasGlyph>>,
+    /// Next free position on the current shelf
+    cursor: [u32; 2],
+    shelf_height: u32,
+    /// Rows written since the last `take_dirty_rows`
+    dirty: Option<(u32, u32)>,
+    overflowed: bool,
+}
+
+impl Default for GlyphAtlas {
+    fn default() -> Self {
+        Self::new(DEFAULT_FONT.to_vec(), ATLAS_SIZE).expect("embedded font is valid")
+    }
+}
+
+impl GlyphAtlas {
+    /// Atlas of `size` x `size` texels for a TrueType or OpenType font
+    ///
+    /// # Returns
+    /// `HelionError::InvalidOption` if `font` can't be parsed
+    pub fn new(font: Vec<u8>, size: u32) -> Result<Self, HelionError> {
+        let font = FontArc::try_from_vec(font).map_err(|e| HelionError::InvalidOption(format!("Invalid font: {}", e)))?;
+        Ok(Self {
+            font,
+            size,
+            pixels: vec![0; (size * size) as usize],
+            glyphs: HashMap::new(),
+            cursor: [0, 0],
+            shelf_height: 0,
+            dirty: None,
+            overflowed: false,
+        })
+    }
+
+    /// Side of the atlas in texels
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Whether a glyph didn't fit since the last `clear`
+    pub fn overflowed(&self) -> bool {
+        self.overflowed
+    }
+
+    /// Forget every glyph; the whole texture needs uploading again
+    pub fn clear(&mut self) {
+        self.pixels.fill(0);
+        self.glyphs.clear();
+        self.cursor = [0, 0];
+        self.shelf_height = 0;
+        self.dirty = Some((0, self.size));
+        self.overflowed = false;
+    }
+
+    /// Range of texel rows changed since the last call, to upload
+    pub fn take_dirty_rows(&mut self) -> Option<(u32, u32)> {
+        self.dirty.take()
+    }
+
+    /// Width of the widest line and height of the block, in pixels
+    pub fn measure(&self, text: &str, size: f32) -> [f32; 2] {
+        let font = self.font.as_scaled(PxScale::from(size));
+        let lines: Vec<&str> = text.split('\n').collect();
+        let width = lines.iter().map(|line| self.line_width(line, size)).fold(0.0, f32::max);
+        let height = font.ascent() - font.descent() + (lines.len() - 1) as f32 * line_height(&font);
+        [width, height]
+    }
+
+    fn line_width(&self, line: &str, size: f32) -> f32 {
+        let font = self.font.as_scaled(PxScale::from(size));
+        let mut width = 0.0;
+        let mut previous = None;
+        for c in line.chars() {
+            let id = font.glyph_id(c);
+            if let Some(previous) = previous {
+                width += font.kern(previous, id);
+            }
+            width += font.h_advance(id);
+            previous = Some(id);
+        }
+        width
+    }
+
+    /// The glyph at `px` pixels, rasterizing it if needed; `None` for blank
+    /// glyphs (spaces) and glyphs that didn't fit
+    pub fn glyph(&mut self, id: GlyphId, px: u32) -> Option<AtlasGlyph> {
+        if let Some(&cached) = self.glyphs.get(&(id, px)) {
+            return cached;
+        }
+        let glyph = id.with_scale_and_position(PxScale::from(px as f32), point(0.0, 0.0));
+        let Some(outlined) = self.font.outline_glyph(glyph) else {
+            self.glyphs.insert((id, px), None);
+            return None;
+        };
+        let bounds = outlined.px_bounds();
+        let (w, h) = (bounds.width() as u32, bounds.height() as u32);
+        let [x, y] = self.allocate(w, h)?;
+
+        let stride = self.size as usize;
+        outlined.draw(|gx, gy, coverage| {
+            let index = (y + gy) as usize * stride + (x + gx) as usize;
+            self.pixels[index] = (coverage.clamp(0.0, 1.0) * 255.0).round() as u8;
+        });
+        let (first, last) = self.dirty.unwrap_or((y, y + h));
+        self.dirty = Some((first.min(y), last.max(y + h)));
+
+        let texel = 1.0 / self.size as f32;
+        let placed = AtlasGlyph {
+            uv_min: [x as f32 * texel, y as f32 * texel],
+            uv_max: [(x + w) as f32 * texel, (y + h) as f32 * texel],
+            size: [w as f32, h as f32],
+            offset: [bounds.min.x, bounds.min.y],
+        };
+        self.glyphs.insert((id, px), Some(placed));
+        Some(placed)
+    }
+
+    /// Reserve a `w` x `h` texel spot on a shelf
+    fn allocate(&mut self, w: u32, h: u32) -> Option<[u32; 2]> {
+        if self.cursor[0] + w + GLYPH_PADDING > self.size {
+            self.cursor = [0, self.cursor[1] + self.shelf_height + GLYPH_PADDING];
+            self.shelf_height = 0;
+        }
+        if self.cursor[1] + h > self.size || w + GLYPH_PADDING > self.size {
+            self.overflowed = true;
+            return None;
+        }
+        let spot = self.cursor;
+        self.cursor[0] += w + GLYPH_PADDING;
+        self.shelf_height = self.shelf_height.max(h);
+        Some(spot)
+    }
+
+    /// Place every glyph of `section`, rasterizing the ones not cached yet
+    pub fn layout(&mut self, section: &TextSection) -> Vec<GlyphQuad> {
+        let px = section.size.round().max(1.0) as u32;
+        let font = self.font.as_scaled(PxScale::from(px as f32));
+        let (ascent, descent, line_height) = (font.ascent(), font.descent(), line_height(&font));
+        let lines: Vec<&str> = section.text.split('\n').collect();
+        let block_height = ascent - descent + (lines.len() - 1) as f32 * line_height;
+        // First baseline relative to the anchor (y down)
+        let first_baseline = match section.v_align {
+            VAlign::Top => ascent,
+            VAlign::Middle => ascent - block_height / 2.0,
+            VAlign::Baseline => 0.0,
+            VAlign::Bottom => descent - (lines.len() - 1) as f32 * line_height,
+        };
+
+        let (sin, cos) = section.rotation.to_radians().sin_cos();
+        let upright = section.rotation == 0.0;
+        let color = [section.color.r, section.color.g, section.color.b, section.color.a];
+        // Rotate about the anchor; counter-clockwise on screen, where y points down
+        let place = |[x, y]: [f32; 2]| {
+            [
+                section.position[0] + x * cos + y * sin,
+                section.position[1] - x * sin + y * cos,
+            ]
+        };
+
+        let mut quads = Vec::new();
+        for (i, line) in lines.iter().enumerate() {
+            let width = self.line_width(line, px as f32);
+            let mut pen = match section.h_align {
+                HAlign::Left => 0.0,
+                HAlign::Center => -width / 2.0,
+                HAlign::Right => -width,
+            };
+            let mut baseline = first_baseline + i as f32 * line_height;
+            if upright {
+                // Whole pixels keep upright text crisp
+                pen = (section.position[0] + pen).round() - section.position[0];
+                baseline = (section.position[1] + baseline).round() - section.position[1];
+            }
+
+            let mut previous = None;
+            for c in line.chars() {
+                let font = self.font.as_scaled(PxScale::from(px as f32));
+                let id = font.glyph_id(c);
+                if let Some(previous) = previous {
+                    pen += font.kern(previous, id);
+                }
+                let advance = font.h_advance(id);
+                previous = Some(id);
+
+                if let Some(glyph) = self.glyph(id, px) {
+                    let left = if upright { pen.round() } else { pen } + glyph.offset[0];
+                    let top = baseline + glyph.offset[1];
+                    let (right, bottom) = (left + glyph.size[0], top + glyph.size[1]);
+                    quads.push(GlyphQuad {
+                        corners: [
+                            place([left, top]),
+                            place([right, top]),
+                            place([right, bottom]),
+                            place([left, bottom]),
+                        ],
+                        uv_min: glyph.uv_min,
+                        uv_max: glyph.uv_max,
+                        color,
+                    });
+                }
+                pen += advance;
+            }
+        }
+        quads
+    }
+}
+
+fn line_height<F: Font>(font: &impl ScaleFont<F>) -> f32 {
+    font.ascent() - font.descent() + font.line_gap()
+}
+
+/// Vertex of a glyph quad
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+pub struct TextVertex {
+    /// Clip space
+    pub position: [f32; 2],
+    /// Atlas coordinates
+    pub uv: [f32; 2],
+    pub color: [f32; 4],
+}
+
+impl TextVertex {
+    pub fn desc<'a>() -> wgpu::VertexBufferLayout<'a> {
+        const ATTRIBUTES: [wgpu::VertexAttribute; 3] =
+            wgpu::vertex_attr_array![0 => Float32x2, 1 => Float32x2, 2 => Float32x4];
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<TextVertex>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &ATTRIBUTES,
+        }
+    }
+}
+
+/// Two triangles per glyph, in clip space for a `width` x `height` viewport
+pub fn quad_vertices(quads: &[GlyphQuad], width: f32, height: f32) -> Vec<TextVertex> {
+    let to_clip = |[x, y]: [f32; 2]| [x / width.max(1.0) * 2.0 - 1.0, 1.0 - y / height.max(1.0) * 2.0];
+    let mut vertices = Vec::with_capacity(quads.len() * 6);
+    for quad in quads {
+        let [u0, v0] = quad.uv_min;
+        let [u1, v1] = quad.uv_max;
+        let uvs = [[u0, v0], [u1, v0], [u1, v1], [u0, v1]];
+        for corner in [0, 1, 2, 0, 2, 3] {
+            vertices.push(TextVertex {
+                position: to_clip(quad.corners[corner]),
+                uv: uvs[corner],
+                color: quad.color,
+            });
+        }
+    }
+    vertices
+}
+
+/// Draws titles, axis labels and tick labels
+///
+/// Needs only a device and a target format, so the same renderer works in a
+/// `RenderWindow`, headless and on the web (with a `GPUBackend`'s device).
+/// Text is laid out on the CPU whenever `set_sections` is called; glyphs are
+/// rasterized once per size into a single-channel atlas texture, and each
+/// frame is one draw call.
+pub struct TextRenderer {
+    render_pipeline: wgpu::RenderPipeline,
+    bind_group: wgpu::BindGroup,
+    atlas_texture: wgpu::Texture,
+    atlas: GlyphAtlas,
+    sections: Vec<TextSection>,
+    vertex_buffer: Option<wgpu::Buffer>,
+    vertex_count: u32,
+    width: f32,
+    height: f32,
+    uploaded_bytes: u64,
+}
+
+impl TextRenderer {
+    /// Renderer using `DEFAULT_FONT`
+    ///
+    /// # Parameters
+    /// * `format` - Format of the target the pass renders to
+    /// * `options` - Must match the options the pass is begun with
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat, options: &RenderOptions) -> Self {
+        Self::with_atlas(device, format, options, GlyphAtlas::default())
+    }
+
+    /// Renderer using a TrueType or OpenType `font`
+    pub fn with_font(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        options: &RenderOptions,
+        font: Vec<u8>,
+    ) -> Result<Self, HelionError> {
+        Ok(Self::with_atlas(device, format, options, GlyphAtlas::new(font, ATLAS_SIZE)?))
+    }
+
+    fn with_atlas(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        options: &RenderOptions,
+        mut atlas: GlyphAtlas,
+    ) -> Self {
+        let atlas_texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Text Atlas Texture"),
+            size: wgpu::Extent3d {
+                width: atlas.size(),
+                height: atlas.size(),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::R8Unorm,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        // New textures are zeroed, matching the empty atlas
+        atlas.take_dirty_rows();
+
+        let atlas_view = atlas_texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Text Atlas Sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Text Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Text Bind Group"),
+            layout: &bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&atlas_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&sampler),
+                },
+            ],
+        });
+
+        let vertex_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Text Vertex Shader"),
+            source: wgpu::ShaderSource::Wgsl(TEXT_VERTEX_SHADER.into()),
+        });
+
+        let fragment_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Text Fragment Shader"),
+            source: wgpu::ShaderSource::Wgsl(TEXT_FRAGMENT_SHADER.into()),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Text Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Text Render Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &vertex_shader,
+                entry_point: "vs_main",
+                buffers: &[TextVertex::desc()],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    // Coverage lives in alpha, so text always alpha-blends
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: options.depth_stencil_state(),
+            multisample: options.multisample_state(),
+            multiview: None,
+            cache: None,
+        });
+
+        TextRenderer {
+            render_pipeline,
+            bind_group,
+            atlas_texture,
+            atlas,
+            sections: Vec::new(),
+            vertex_buffer: None,
+            vertex_count: 0,
+            width: 1.0,
+            height: 1.0,
+            uploaded_bytes: 0,
+        }
+    }
+
+    pub fn atlas(&self) -> &GlyphAtlas {
+        &self.atlas
+    }
+
+    pub fn sections(&self) -> &[TextSection] {
+        &self.sections
+    }
+
+    /// Width and height of `text` at `size` pixels
+    pub fn measure(&self, text: &str, size: f32) -> [f32; 2] {
+        self.atlas.measure(text, size)
+    }
+
+    /// Replace the text drawn in a `width` x `height` viewport
+    pub fn set_sections(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        sections: Vec<TextSection>,
+        width: f32,
+        height: f32,
+    ) {
+        self.sections = sections;
+        self.width = width;
+        self.height = height;
+        self.rebuild(device, queue);
+    }
+
+    /// Lay the same text out again for a resized viewport
+    pub fn resize(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, width: f32, height: f32) {
+        self.width = width;
+        self.height = height;
+        self.rebuild(device, queue);
+    }
+
+    fn rebuild(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        let mut quads = self.layout_all();
+        if self.atlas.overflowed() {
+            // Start over with only the glyphs still in use
+            self.atlas.clear();
+            quads = self.layout_all();
+            if self.atlas.overflowed() {
+                log::warn!("Text needs more glyphs than fit in the atlas; some are not drawn");
+            }
+        }
+        self.upload_atlas(queue);
+
+        let vertices = quad_vertices(&quads, self.width, self.height);
+        self.vertex_count = vertices.len() as u32;
+        self.vertex_buffer = (!vertices.is_empty()).then(|| {
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Text Vertex Buffer"),
+                contents: bytemuck::cast_slice(&vertices),
+                usage: wgpu::BufferUsages::VERTEX,
+            })
+        });
+        self.uploaded_bytes += std::mem::size_of_val(vertices.as_slice()) as u64;
+    }
+
+    fn layout_all(&mut self) -> Vec<GlyphQuad> {
+        let mut quads = Vec::new();
+        for section in &self.sections {
+            quads.extend(self.atlas.layout(section));
+        }
+        quads
+    }
+
+    /// Copy the atlas rows changed since the last upload to the texture
+    fn upload_atlas(&mut self, queue: &wgpu::Queue) {
+        let Some((first, last)) = self.atlas.take_dirty_rows() else {
+            return;
+        };
+        let size = self.atlas.size();
+        let rows = &self.atlas.pixels()[(first * size) as usize..(last * size) as usize];
+        queue.write_texture(
+            wgpu::ImageCopyTexture {
+                texture: &self.atlas_texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d { x: 0, y: first, z: 0 },
+                aspect: wgpu::TextureAspect::All,
+            },
+            rows,
+            wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(size),
+                rows_per_image: Some(last - first),
+            },
+            wgpu::Extent3d {
+                width: size,
+                height: last - first,
+                depth_or_array_layers: 1,
+            },
+        );
+        self.uploaded_bytes += rows.len() as u64;
+    }
+}
+
+impl Renderer for TextRenderer {
+    fn render_to_pass<'rpass>(&'rpass mut self, render_pass: &mut wgpu::RenderPass<'rpass>) {
+        if let Some(ref buffer) = self.vertex_buffer {
+            render_pass.set_pipeline(&self.render_pipeline);
+            render_pass.set_bind_group(0, &self.bind_group, &[]);
+            render_pass.set_vertex_buffer(0, buffer.slice(..));
+            render_pass.draw(0..self.vertex_count, 0..1);
+        }
+    }
+
+    fn frame_stats(&mut self) -> DrawStats {
+        let stats = if self.vertex_buffer.is_some() {
+            DrawStats::draws(self.vertex_count as u64, 1)
+        } else {
+            DrawStats::default()
+        };
+        stats.with_bytes_uploaded(std::mem::take(&mut self.uploaded_bytes))
+    }
+}
@@ -0,0 +1,151 @@
+use std::ops::Range;
+
+/// When the step between consecutive timestamps counts as a gap
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GapThreshold {
+    /// Break wherever the step exceeds this many time units
+    Absolute(f32),
+    /// Break wherever the step exceeds this multiple of the median step, so
+    /// the same setting works for second- and day-resolution data
+    MedianMultiple(f32),
+}
+
+impl Default for GapThreshold {
+    fn default() -> Self {
+        GapThreshold::MedianMultiple(3.0)
+    }
+}
+
+/// Resolve a threshold into time units for the given timestamps
+///
+/// Returns `None` if no finite threshold can be derived (fewer than two
+/// timestamps, or a non-positive threshold).
+pub fn gap_threshold(time: &[f32], threshold: GapThreshold) -> Option<f32> {
+    let limit = match threshold {
+        GapThreshold::Absolute(dt) => dt,
+        GapThreshold::MedianMultiple(factor) => {
+            let mut steps: Vec<f32> = time
+                .windows(2)
+                .map(|w| w[1] - w[0])
+                .filter(|d| d.is_finite() && *d > 0.0)
+                .collect();
+            if steps.is_empty() {
+                return None;
+            }
+            steps.sort_by(|a, b| a.total_cmp(b));
+            steps[steps.len() / 2] * factor
+        }
+    };
+    (limit.is_finite() && limit > 0.0).then_some(limit)
+}
+
+/// Split a series into contiguous runs that can each be drawn as one line
+///
+/// A run ends where the timestamp step exceeds the threshold or where a
+/// timestamp or value is NaN (the NaN sample itself is left out). Runs of a
+/// single sample are kept so isolated points can still be drawn as markers.
+///
+/// # Parameters
+/// * `time` - Timestamps in ascending order
+/// * `values` - Sample values (same length as `time`)
+/// * `threshold` - Gap rule; `None` only breaks on NaN
+///
+/// # Returns
+/// Index ranges into the series, in order
+///
+/// # Example
+/// ```
+/// use helion_core::timeseries::{segment_runs, GapThreshold};
+///
+/// let time = [0.0, 1.0, 2.0, 10.0, 11.0];
+/// let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+/// let runs = segment_runs(&time, &values, Some(GapThreshold::Absolute(2.0)));
+/// assert_eq!(runs, vec![0..3, 3..5]);
+/// ```
+pub fn segment_runs(time: &[f32], values: &[f32], threshold: Option<GapThreshold>) -> Vec<Range<usize>> {
+    let n = time.len().min(values.len());
+    let limit = threshold.and_then(|t| gap_threshold(&time[..n], t));
+    let valid = |i: usize| !time[i].is_nan() && !values[i].is_nan();
+
+    let mut runs = Vec::new();
+    let mut start: Option<usize> = None;
+
+    for i in 0..n {
+        if !valid(i) {
+            if let Some(s) = start.take() {
+                runs.push(s..i);
+            }
+            continue;
+        }
+        match start {
+            Some(s) if limit.is_some_and(|l| time[i] - time[i - 1] > l) => {
+                runs.push(s..i);
+                start = Some(i);
+            }
+            Some(_) => {}
+            None => start = Some(i),
+        }
+    }
+    if let Some(s) = start {
+        runs.push(s..n);
+    }
+    runs
+}
+
+/// Indices where a new run starts because of a timestamp gap
+///
+/// Useful for placing gap markers; NaN breaks are not reported.
+pub fn gap_indices(time: &[f32], threshold: GapThreshold) -> Vec<usize> {
+    match gap_threshold(time, threshold) {
+        Some(limit) => (1..time.len())
+            .filter(|&i| time[i] - time[i - 1] > limit)
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Decimate a series to at most `2 * buckets` samples, keeping each bucket's
+/// minimum and maximum
+///
+/// Unlike plain striding, min/max decimation preserves spikes, so an overview
+/// of millions of samples still shows every outlier. Samples are bucketed by
+/// index and emitted in their original order; NaN values are skipped.
+///
+/// # Returns
+/// `(x, y)` of the retained samples
+pub fn decimate_minmax(x: &[f32], y: &[f32], buckets: usize) -> (Vec<f32>, Vec<f32>) {
+    let n = x.len().min(y.len());
+    if buckets == 0 || n <= buckets * 2 {
+        return (x[..n].to_vec(), y[..n].to_vec());
+    }
+
+    let mut out_x = Vec::with_capacity(buckets * 2);
+    let mut out_y = Vec::with_capacity(buckets * 2);
+    for b in 0..buckets {
+        let start = b * n / buckets;
+        let end = (b + 1) * n / buckets;
+
+        let mut lo: Option<usize> = None;
+        let mut hi: Option<usize> = None;
+        for i in start..end {
+            if y[i].is_nan() {
+                continue;
+            }
+            if lo.is_none_or(|j| y[i] < y[j]) {
+                lo = Some(i);
+            }
+            if hi.is_none_or(|j| y[i] > y[j]) {
+                hi = Some(i);
+            }
+        }
+
+        let mut picks: Vec<usize> = lo.into_iter().chain(hi).collect();
+        picks.sort_unstable();
+        picks.dedup();
+        for i in picks {
+            out_x.push(x[i]);
+            out_y.push(y[i]);
+        }
+    }
+    (out_x, out_y)
+}
@@ -0,0 +1,170 @@
+use crate::data::{ChartData, Color, Point2D};
+use crate::text::{HAlign, TextSection, VAlign};
+
+/// Significant digits of the default tooltip values
+const SIGNIFICANT_DIGITS: i32 = 6;
+
+/// The point a tooltip describes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TooltipPoint {
+    pub series: usize,
+    /// Index of the point's vertex in its series
+    pub index: usize,
+    /// Data coordinates of the point, as `ChartData::original_point` gives
+    /// them
+    pub value: Option<[f64; 2]>,
+}
+
+/// Text of the tooltip for a point, or `None` to show none for it
+pub type TooltipFormatter = Box<dyn FnMut(&TooltipPoint) -> Option<String> + Send>;
+
+/// Tooltip look, in pixels
+#[derive(Debug, Clone)]
+pub struct TooltipOptions {
+    pub font_size: f32,
+    /// Space inside the box around the text
+    pub padding: f32,
+    /// Distance from the cursor to the box on each axis
+    pub offset: f32,
+    pub background: Color,
+    pub text_color: Color,
+}
+
+impl Default for TooltipOptions {
+    fn default() -> Self {
+        Self {
+            font_size: 12.0,
+            padding: 5.0,
+            offset: 12.0,
+            background: Color::new(0.15, 0.15, 0.15, 0.9),
+            text_color: Color::new(1.0, 1.0, 1.0, 1.0),
+        }
+    }
+}
+
+/// Box of text shown next to the cursor while it is over a point
+///
+/// By default the box lists the point's x and y data values, before they
+/// were normalized for drawing; `with_formatter` replaces the text. The box
+/// sits below and right of the cursor, flipping to the other side near the
+/// viewport's right and bottom edges.
+///
+/// # Example
+/// ```
+/// use helion_core::tooltip::{Tooltip, TooltipPoint};
+///
+/// let point = TooltipPoint { series: 0, index: 3, value: Some([1.5, 2e-7]) };
+/// assert_eq!(Tooltip::default_text(&point).unwrap(), "x: 1.5\ny: 2e-7");
+///
+/// let mut tooltip = Tooltip::new().with_formatter(|p| Some(format!("#{}", p.index)));
+/// assert_eq!(tooltip.text(&point).unwrap(), "#3");
+/// ```
+#[derive(Default)]
+pub struct Tooltip {
+    options: TooltipOptions,
+    formatter: Option<TooltipFormatter>,
+}
+
+impl Tooltip {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_options(mut self, options: TooltipOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Build each point's text with `formatter` instead of `default_text`
+    pub fn with_formatter(mut self, formatter: impl FnMut(&TooltipPoint) -> Option<String> + Send + 'static) -> Self {
+        self.formatter = Some(Box::new(formatter));
+        self
+    }
+
+    pub fn options(&self) -> &TooltipOptions {
+        &self.options
+    }
+
+    /// Text shown for `point`, if any
+    pub fn text(&mut self, point: &TooltipPoint) -> Option<String> {
+        match &mut self.formatter {
+            Some(formatter) => formatter(point),
+            None => Self::default_text(point),
+        }
+    }
+
+    /// "x: …" and "y: …" lines with the point's data values to six
+    /// significant digits, or its index when they aren't known
+    pub fn default_text(point: &TooltipPoint) -> Option<String> {
+        Some(match point.value {
+            Some([x, y]) => format!("x: {}\ny: {}", format_value(x), format_value(y)),
+            None => format!("Point {}", point.index),
+        })
+    }
+
+    /// Pixel rectangle `[x, y, width, height]` of the box around text of
+    /// `text_size`, for the cursor at `cursor` in a `viewport`-sized view
+    pub fn bounds(&self, text_size: [f32; 2], cursor: [f32; 2], viewport: [f32; 2]) -> [f32; 4] {
+        let o = &self.options;
+        let size = [text_size[0] + 2.0 * o.padding, text_size[1] + 2.0 * o.padding];
+        let place = |axis: usize| {
+            let after = cursor[axis] + o.offset;
+            let start = match after + size[axis] > viewport[axis] {
+                true => cursor[axis] - o.offset - size[axis],
+                false => after,
+            };
+            start.max(0.0)
+        };
+        [place(0), place(1), size[0], size[1]]
+    }
+
+    /// The box as a triangle list for `MeshRenderer`
+    pub fn quad_data(&self, bounds: [f32; 4], viewport: [f32; 2]) -> ChartData {
+        let [width, height] = viewport;
+        let [x, y, w, h] = bounds;
+        let clip = |px: f32, py: f32| Point2D::new(px / width * 2.0 - 1.0, 1.0 - py / height * 2.0);
+        let mut data = ChartData::new(width, height);
+        data.add_rect(clip(x, y + h), clip(x + w, y), self.options.background);
+        data
+    }
+
+    /// `text` placed inside `bounds`, for `TextRenderer`
+    pub fn text_section(&self, text: &str, bounds: [f32; 4]) -> TextSection {
+        let o = &self.options;
+        TextSection::new(text, [bounds[0] + o.padding, bounds[1] + o.padding], o.font_size)
+            .with_color(o.text_color)
+            .with_align(HAlign::Left, VAlign::Top)
+    }
+}
+
+impl std::fmt::Debug for Tooltip {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tooltip")
+            .field("options", &self.options)
+            .field("formatter", &self.formatter.is_some())
+            .finish()
+    }
+}
+
+/// `value` to `SIGNIFICANT_DIGITS` digits, without trailing zeros;
+/// scientific notation for very large and very small magnitudes
+fn format_value(value: f64) -> String {
+    if value == 0.0 || !value.is_finite() {
+        return value.to_string();
+    }
+    let magnitude = value.abs().log10().floor() as i32;
+    if !(-4..SIGNIFICANT_DIGITS).contains(&magnitude) {
+        let text = format!("{:.*e}", (SIGNIFICANT_DIGITS - 1) as usize, value);
+        let (mantissa, exponent) = text.split_once('e').unwrap_or((&text, "0"));
+        return format!("{}e{}", trim_zeros(mantissa), exponent);
+    }
+    let decimals = (SIGNIFICANT_DIGITS - 1 - magnitude).max(0) as usize;
+    trim_zeros(&format!("{:.*}", decimals, value)).to_string()
+}
+
+fn trim_zeros(number: &str) -> &str {
+    match number.contains('.') {
+        true => number.trim_end_matches('0').trim_end_matches('.'),
+        false => number,
+    }
+}
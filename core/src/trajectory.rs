@@ -0,0 +1,446 @@
+use crate::camera::{Camera2D, CameraBinding};
+use crate::chunked::ChunkedBuffer;
+use crate::data::{ChartData, Vertex};
+use crate::error::HelionError;
+use crate::renderer::{BlendMode, RenderOptions, Renderer, WindowRenderer};
+use crate::shaders::{TRAJECTORY_FRAGMENT_SHADER, TRAJECTORY_VERTEX_SHADER};
+use crate::stats::DrawStats;
+use wgpu::util::DeviceExt;
+
+/// Which part of a trajectory is shown, and how it fades
+///
+/// Times are whatever the points are stamped with: their index by default,
+/// or timestamps passed to `TrajectoryRenderer::update_data_with_times`.
+/// Points newer than `head` are hidden, and older ones fade linearly until
+/// they are `length` behind it. Moving `head` plays the trajectory back
+/// without touching its vertices.
+///
+/// # Example
+/// ```
+/// use helion_core::trajectory::Trail;
+///
+/// let trail = Trail { head: 100.0, length: 20.0 };
+/// assert_eq!(trail.opacity(100.0), 1.0);
+/// assert_eq!(trail.opacity(90.0), 0.5);
+/// assert_eq!(trail.opacity(70.0), 0.0);
+/// // Not reached yet
+/// assert_eq!(trail.opacity(101.0), 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Trail {
+    /// Time of the newest point shown
+    pub head: f32,
+    /// Age at which points have faded out; `f32::INFINITY` shows the whole
+    /// path behind the head at full opacity
+    pub length: f32,
+}
+
+/// Everything, at full opacity
+impl Default for Trail {
+    fn default() -> Self {
+        Self {
+            head: f32::INFINITY,
+            length: f32::INFINITY,
+        }
+    }
+}
+
+impl Trail {
+    /// Multiplier applied to the alpha of a point stamped `time`, as the
+    /// fragment shader computes it
+    pub fn opacity(&self, time: f32) -> f32 {
+        if time > self.head {
+            return 0.0;
+        }
+        match inverse_length(self.length) {
+            0.0 => 1.0,
+            inv_length => (1.0 - (self.head - time) * inv_length).max(0.0),
+        }
+    }
+}
+
+/// A trajectory drawing itself over time
+///
+/// While playing, the head moves from the first point's time at `speed` time
+/// units per second of the render loop's `FrameClock`, so in deterministic
+/// mode frame N always shows the same part of the path.
+///
+/// # Example
+/// ```
+/// use helion_core::trajectory::{Playback, Trail};
+///
+/// let playback = Playback { speed: 10.0, length: 5.0 };
+/// assert_eq!(playback.trail_at(0.5, (0.0, 100.0)), Trail { head: 5.0, length: 5.0 });
+/// // The head stops at the last point
+/// assert_eq!(playback.trail_at(60.0, (0.0, 100.0)).head, 100.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Playback {
+    /// Point time units the head advances per second
+    pub speed: f32,
+    /// Trail length while playing; `f32::INFINITY` keeps the whole path
+    /// behind the head
+    pub length: f32,
+}
+
+impl Playback {
+    /// Trail `elapsed` seconds into playing a path whose points are stamped
+    /// `span.0` to `span.1`
+    pub fn trail_at(&self, elapsed: f32, span: (f32, f32)) -> Trail {
+        Trail {
+            head: (span.0 + elapsed.max(0.0) * self.speed).min(span.1),
+            length: self.length,
+        }
+    }
+}
+
+/// `1 / length`, 0 for a trail that never fades
+fn inverse_length(length: f32) -> f32 {
+    if length.is_finite() && length > 0.0 {
+        1.0 / length
+    } else if length == 0.0 {
+        // Only the head itself; its age of 0 still gets full opacity
+        f32::MAX
+    } else {
+        0.0
+    }
+}
+
+/// Uniform block layout shared with the trajectory shaders
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct TrailUniforms {
+    pub head: f32,
+    pub inv_length: f32,
+    /// 1.0 if fading scales the color as well as alpha
+    pub premultiplied: f32,
+    pub _padding: f32,
+}
+
+impl TrailUniforms {
+    pub fn new(trail: &Trail, blend: BlendMode) -> Self {
+        let premultiplied = matches!(blend, BlendMode::Premultiplied | BlendMode::Additive);
+        Self {
+            // The shader can't compare against infinity reliably
+            head: trail.head.min(f32::MAX),
+            inv_length: inverse_length(trail.length),
+            premultiplied: if premultiplied { 1.0 } else { 0.0 },
+            _padding: 0.0,
+        }
+    }
+}
+
+/// Copy of `vertices` with each one's `size` replaced by its time: `times[i]`
+/// if given, else its index
+///
+/// # Returns
+/// `HelionError::ShapeMismatch` if `times` doesn't have one entry per vertex
+///
+/// # Example
+/// ```
+/// use helion_core::trajectory::stamp_times;
+/// use helion_core::ChartData;
+///
+/// let data = ChartData::from_line(&[0.0, 1.0, 2.0], &[0.0, 1.0, 0.0], None, 800.0, 600.0);
+/// let stamped = stamp_times(&data.vertices, None).unwrap();
+/// assert_eq!(stamped[2].size, 2.0);
+/// assert!(stamp_times(&data.vertices, Some(&[0.0, 0.5])).is_err());
+/// ```
+pub fn stamp_times(vertices: &[Vertex], times: Option<&[f32]>) -> Result<Vec<Vertex>, HelionError> {
+    match times {
+        Some(times) if times.len() != vertices.len() => Err(HelionError::ShapeMismatch {
+            what: "Trajectory times, one per point".to_string(),
+            expected: vertices.len(),
+            actual: times.len(),
+        }),
+        Some(times) => Ok(vertices.iter().zip(times).map(|(v, &time)| Vertex { size: time, ..*v }).collect()),
+        None => Ok(vertices.iter().enumerate().map(|(i, v)| Vertex { size: i as f32, ..*v }).collect()),
+    }
+}
+
+/// Trajectory renderer: ordered points joined by a line whose older part
+/// fades out, for phase portraits of dynamical systems and tracked objects
+///
+/// Points are joined in order like `LineRenderer`, each stamped with a time,
+/// and the fade is computed on the GPU from a 16-byte `Trail` uniform. So
+/// animating a recorded path uploads only that uniform per frame, while a
+/// live track re-uploads its points and moves the head to the newest one.
+///
+/// Fresh data shows the whole path, fading from the first point to the
+/// last; call `set_trail` to shorten the tail or scrub through time. Keep
+/// times relative to the start of the recording: stamps are f32, so epoch
+/// seconds would lose their fractional part.
+///
+/// To animate it with the render loop instead, `play` it and pass each
+/// frame's `FrameClock` time to `set_time`, as windows do.
+///
+/// # Example
+/// ```ignore
+/// let data = ChartData::from_line(&x, &v, Some(Color::parse("teal")?), 800.0, 600.0);
+/// let mut orbit = TrajectoryRenderer::with_options(device, &config, data, &options);
+/// // every frame: the last 500 steps up to the current one
+/// orbit.set_trail(queue, Trail { head: step as f32, length: 500.0 });
+/// ```
+pub struct TrajectoryRenderer {
+    render_pipeline: wgpu::RenderPipeline,
+    camera: CameraBinding,
+    uniform_buffer: wgpu::Buffer,
+    trail_bind_group: wgpu::BindGroup,
+    vertex_buffer: Option<ChunkedBuffer>,
+    trail: Trail,
+    /// Times of the first and last points
+    span: (f32, f32),
+    playback: Option<Playback>,
+    /// Frame time `playback` started at, set on the first `set_time`
+    played_from: Option<f32>,
+    blend: BlendMode,
+    /// Bytes uploaded since the last `frame_stats`
+    uploaded_bytes: u64,
+}
+
+impl TrajectoryRenderer {
+    /// Times of the first and last of `vertices`
+    fn time_span(vertices: &[Vertex]) -> (f32, f32) {
+        match (vertices.first(), vertices.last()) {
+            (Some(first), Some(last)) => (first.size, last.size),
+            _ => (0.0, 0.0),
+        }
+    }
+
+    /// Trail showing all of `vertices`, stamped as `stamp_times` does
+    fn whole_path(vertices: &[Vertex]) -> Trail {
+        match (vertices.first(), vertices.last()) {
+            (Some(first), Some(last)) if last.size > first.size => Trail {
+                head: last.size,
+                length: last.size - first.size,
+            },
+            (_, Some(last)) => Trail {
+                head: last.size,
+                length: f32::INFINITY,
+            },
+            _ => Trail::default(),
+        }
+    }
+
+    /// Replace the points, stamping them with `times` (one per vertex,
+    /// increasing) instead of their indices, and show the whole path
+    ///
+    /// # Returns
+    /// `HelionError::ShapeMismatch` if `times` doesn't match the vertices,
+    /// or the buffer's error if it can't be allocated
+    pub fn update_data_with_times(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        chart_data: &ChartData,
+        times: &[f32],
+    ) -> Result<(), HelionError> {
+        let vertices = stamp_times(&chart_data.vertices, Some(times))?;
+        self.upload(device, queue, &vertices)
+    }
+
+    fn upload(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, vertices: &[Vertex]) -> Result<(), HelionError> {
+        self.uploaded_bytes += ChunkedBuffer::update_strip(
+            &mut self.vertex_buffer,
+            device,
+            queue,
+            "Trajectory Vertex Buffer",
+            vertices,
+            wgpu::BufferUsages::VERTEX,
+        )?;
+        self.span = Self::time_span(vertices);
+        // A playing trajectory keeps playing over the new points
+        if self.playback.is_none() {
+            self.set_trail(queue, Self::whole_path(vertices));
+        }
+        Ok(())
+    }
+
+    pub fn trail(&self) -> Trail {
+        self.trail
+    }
+
+    /// Play the path from its first point on the next `set_time`, or with
+    /// `None` stop and show it whole
+    pub fn play(&mut self, queue: &wgpu::Queue, playback: Option<Playback>) {
+        self.playback = playback;
+        self.played_from = None;
+        if playback.is_none() {
+            let (first, last) = self.span;
+            let length = if last > first { last - first } else { f32::INFINITY };
+            self.set_trail(queue, Trail { head: last, length });
+        }
+    }
+
+    /// Move the head or change the trail's length; vertices are untouched
+    pub fn set_trail(&mut self, queue: &wgpu::Queue, trail: Trail) {
+        self.trail = trail;
+        let uniforms = TrailUniforms::new(&trail, self.blend);
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&uniforms));
+        self.uploaded_bytes += std::mem::size_of::<TrailUniforms>() as u64;
+    }
+
+    /// Pan or zoom by uploading a new view matrix; vertices are untouched
+    pub fn set_camera(&mut self, queue: &wgpu::Queue, camera: &Camera2D) {
+        self.uploaded_bytes += self.camera.set(queue, camera);
+    }
+}
+
+impl Renderer for TrajectoryRenderer {
+    fn render_to_pass<'rpass>(&'rpass mut self, render_pass: &mut wgpu::RenderPass<'rpass>) {
+        if let Some(ref buffer) = self.vertex_buffer {
+            render_pass.set_pipeline(&self.render_pipeline);
+            render_pass.set_bind_group(0, self.camera.bind_group(), &[]);
+            render_pass.set_bind_group(1, &self.trail_bind_group, &[]);
+            buffer.draw(render_pass, 0);
+        }
+    }
+
+    fn frame_stats(&mut self) -> DrawStats {
+        let stats = self.vertex_buffer.as_ref().map_or_else(DrawStats::default, ChunkedBuffer::draw_stats);
+        stats.with_bytes_uploaded(std::mem::take(&mut self.uploaded_bytes))
+    }
+}
+
+impl WindowRenderer for TrajectoryRenderer {
+    /// Renderer for the points of `chart_data`, stamped with their indices
+    fn with_options(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        chart_data: ChartData,
+        options: &RenderOptions,
+    ) -> Self {
+        let vertex_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Trajectory Vertex Shader"),
+            source: wgpu::ShaderSource::Wgsl(TRAJECTORY_VERTEX_SHADER.into()),
+        });
+
+        let fragment_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Trajectory Fragment Shader"),
+            source: wgpu::ShaderSource::Wgsl(TRAJECTORY_FRAGMENT_SHADER.into()),
+        });
+
+        let vertices = stamp_times(&chart_data.vertices, None).expect("no times to mismatch");
+        let trail = Self::whole_path(&vertices);
+        let uniforms = TrailUniforms::new(&trail, options.blend);
+        let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Trail Uniform Buffer"),
+            contents: bytemuck::bytes_of(&uniforms),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let trail_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Trail Bind Group Layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+
+        let trail_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Trail Bind Group"),
+            layout: &trail_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: uniform_buffer.as_entire_binding(),
+            }],
+        });
+
+        let camera = CameraBinding::new(device);
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Trajectory Pipeline Layout"),
+            bind_group_layouts: &[camera.layout(), &trail_layout],
+            push_constant_ranges: &[],
+        });
+
+        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Trajectory Render Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &vertex_shader,
+                entry_point: "vs_main",
+                buffers: &[Vertex::desc()],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &fragment_shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: config.format,
+                    blend: options.blend_state(),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::LineStrip,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: options.depth_stencil_state(),
+            multisample: options.multisample_state(),
+            multiview: None,
+            cache: None,
+        });
+
+        let vertex_buffer = if vertices.is_empty() {
+            None
+        } else {
+            ChunkedBuffer::new_strip(device, "Trajectory Vertex Buffer", &vertices, wgpu::BufferUsages::VERTEX)
+                .map_err(|e| log::error!("{}", e))
+                .ok()
+        };
+
+        TrajectoryRenderer {
+            render_pipeline,
+            camera,
+            uniform_buffer,
+            trail_bind_group,
+            uploaded_bytes: vertex_buffer.as_ref().map_or(0, ChunkedBuffer::byte_size),
+            vertex_buffer,
+            trail,
+            span: Self::time_span(&vertices),
+            playback: None,
+            played_from: None,
+            blend: options.blend,
+        }
+    }
+
+    /// Replace the points, stamped with their indices, and show the whole
+    /// path
+    fn update_data(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, chart_data: &ChartData) {
+        let vertices = stamp_times(&chart_data.vertices, None).expect("no times to mismatch");
+        if let Err(e) = self.upload(device, queue, &vertices) {
+            log::error!("{}", e);
+            self.vertex_buffer = None;
+        }
+    }
+
+    fn set_camera(&mut self, queue: &wgpu::Queue, camera: &Camera2D) {
+        TrajectoryRenderer::set_camera(self, queue, camera);
+    }
+
+    /// Move the head of a playing trajectory; returns whether it has yet
+    /// to reach the last point
+    fn set_time(&mut self, queue: &wgpu::Queue, time: f32) -> bool {
+        let Some(playback) = self.playback else {
+            return false;
+        };
+        let started = *self.played_from.get_or_insert(time);
+        let trail = playback.trail_at(time - started, self.span);
+        if trail != self.trail {
+            self.set_trail(queue, trail);
+        }
+        trail.head < self.span.1
+    }
+}
@@ -0,0 +1,278 @@
+use crate::data::ChartData;
+
+/// Easing curve applied to transition progress
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    Linear,
+    EaseIn,
+    EaseOut,
+    #[default]
+    EaseInOut,
+}
+
+impl Easing {
+    /// Map linear progress `t` in [0, 1] onto the eased curve
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// Visible data range of a view: (min, max) along each axis
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ViewRange {
+    pub x: (f32, f32),
+    pub y: (f32, f32),
+}
+
+impl ViewRange {
+    pub fn new(x: (f32, f32), y: (f32, f32)) -> Self {
+        Self { x, y }
+    }
+
+    /// Linearly interpolate towards `other` by `t` (0.0 = self, 1.0 = other)
+    pub fn lerp(&self, other: &ViewRange, t: f32) -> Self {
+        Self {
+            x: (lerp(self.x.0, other.x.0, t), lerp(self.x.1, other.x.1, t)),
+            y: (lerp(self.y.0, other.y.0, t), lerp(self.y.1, other.y.1, t)),
+        }
+    }
+}
+
+impl Default for ViewRange {
+    fn default() -> Self {
+        Self::new((-1.0, 1.0), (-1.0, 1.0)) // GPU clip space
+    }
+}
+
+/// Supplies the frame time passed to `Transition` and other animated effects
+///
+/// A real-time clock follows the wall clock, so an animation takes the same
+/// time however fast frames are drawn. A fixed clock advances by exactly
+/// `1 / fps` per frame, so the Nth frame always shows the same moment of an
+/// animation; use it in deterministic mode and when exporting video frames.
+///
+/// # Example
+/// ```
+/// use helion_core::transition::FrameClock;
+///
+/// let mut clock = FrameClock::fixed(4.0);
+/// assert_eq!(clock.tick(), 0.0);
+/// assert_eq!(clock.tick(), 0.25);
+/// assert_eq!(clock.now(), 0.25);
+/// ```
+#[derive(Debug, Clone)]
+pub enum FrameClock {
+    #[cfg(not(target_arch = "wasm32"))]
+    Realtime {
+        start: Option<std::time::Instant>,
+        now: f32,
+    },
+    Fixed {
+        step: f64,
+        frame: u64,
+        now: f32,
+    },
+}
+
+impl FrameClock {
+    /// Clock that follows wall time, starting at 0 on the first tick
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn realtime() -> Self {
+        FrameClock::Realtime { start: None, now: 0.0 }
+    }
+
+    /// Clock that advances by `1 / fps` seconds per tick, regardless of how
+    /// long frames take
+    pub fn fixed(fps: f32) -> Self {
+        FrameClock::Fixed {
+            step: 1.0 / fps.max(f32::MIN_POSITIVE) as f64,
+            frame: 0,
+            now: 0.0,
+        }
+    }
+
+    /// Fixed clock at `fps` in deterministic mode, real-time otherwise
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn for_options(options: &crate::renderer::RenderOptions, fps: f32) -> Self {
+        if options.deterministic {
+            Self::fixed(fps)
+        } else {
+            Self::realtime()
+        }
+    }
+
+    /// Start a new frame and return its time in seconds
+    pub fn tick(&mut self) -> f32 {
+        match self {
+            #[cfg(not(target_arch = "wasm32"))]
+            FrameClock::Realtime { start, now } => {
+                *now = start.get_or_insert_with(std::time::Instant::now).elapsed().as_secs_f32();
+                *now
+            }
+            FrameClock::Fixed { step, frame, now } => {
+                // Multiply rather than accumulate, so frame N's time never drifts
+                *now = (*frame as f64 * *step) as f32;
+                *frame += 1;
+                *now
+            }
+        }
+    }
+
+    /// Time of the current frame, as returned by the last `tick`
+    pub fn now(&self) -> f32 {
+        match self {
+            #[cfg(not(target_arch = "wasm32"))]
+            FrameClock::Realtime { now, .. } => *now,
+            FrameClock::Fixed { now, .. } => *now,
+        }
+    }
+
+    /// Whether frame times are independent of the wall clock
+    pub fn is_fixed(&self) -> bool {
+        matches!(self, FrameClock::Fixed { .. })
+    }
+}
+
+/// Animated transition between two views, and optionally between two datasets
+///
+/// Time is expressed in seconds on whatever clock drives the render loop, so
+/// the loop only has to pass its current frame time to `view_at()` /
+/// `apply_positions()` each frame.
+///
+/// # Example
+/// ```
+/// use helion_core::transition::{Easing, Transition, ViewRange};
+///
+/// let mut transition = Transition::new(
+///     ViewRange::new((0.0, 10.0), (0.0, 10.0)),
+///     ViewRange::new((2.0, 4.0), (2.0, 4.0)),
+///     0.5,
+/// )
+/// .with_easing(Easing::Linear);
+///
+/// transition.start(0.0);
+/// assert_eq!(transition.view_at(0.25).x, (1.0, 7.0));
+/// assert!(transition.is_finished(0.5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Transition {
+    from: ViewRange,
+    to: ViewRange,
+    duration: f32,
+    easing: Easing,
+    start_time: Option<f32>,
+    from_positions: Vec<[f32; 2]>,
+    to_positions: Vec<[f32; 2]>,
+}
+
+impl Transition {
+    /// Create a view transition lasting `duration` seconds
+    pub fn new(from: ViewRange, to: ViewRange, duration: f32) -> Self {
+        Self {
+            from,
+            to,
+            duration: duration.max(0.0),
+            easing: Easing::default(),
+            start_time: None,
+            from_positions: Vec::new(),
+            to_positions: Vec::new(),
+        }
+    }
+
+    /// Set the easing curve (defaults to `Easing::EaseInOut`)
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Also morph per-point positions from `from` to `to`
+    ///
+    /// Point morphing only makes sense when both datasets describe the same
+    /// points, so it is skipped (with a warning) when the lengths differ and
+    /// only the view range is animated.
+    pub fn with_positions(mut self, from: &ChartData, to: &ChartData) -> Self {
+        if from.vertices.len() == to.vertices.len() {
+            self.from_positions = from.vertices.iter().map(|v| v.position).collect();
+            self.to_positions = to.vertices.iter().map(|v| v.position).collect();
+        } else {
+            log::warn!(
+                "Transition datasets differ in length ({} vs {}); animating view only",
+                from.vertices.len(),
+                to.vertices.len()
+            );
+        }
+        self
+    }
+
+    /// Start the transition at `time` seconds
+    pub fn start(&mut self, time: f32) {
+        self.start_time = Some(time);
+    }
+
+    /// Whether `start()` has been called
+    pub fn is_started(&self) -> bool {
+        self.start_time.is_some()
+    }
+
+    /// Whether per-point positions are being interpolated
+    pub fn has_positions(&self) -> bool {
+        !self.from_positions.is_empty()
+    }
+
+    /// Eased progress in [0, 1] at `time` (0.0 before `start()` is called)
+    pub fn progress(&self, time: f32) -> f32 {
+        let Some(start) = self.start_time else {
+            return 0.0;
+        };
+        if self.duration <= 0.0 {
+            return 1.0;
+        }
+        self.easing.apply((time - start) / self.duration)
+    }
+
+    /// Whether the transition has run to completion at `time`
+    pub fn is_finished(&self, time: f32) -> bool {
+        match self.start_time {
+            Some(start) => time - start >= self.duration,
+            None => false,
+        }
+    }
+
+    /// Interpolated view range at `time`
+    pub fn view_at(&self, time: f32) -> ViewRange {
+        self.from.lerp(&self.to, self.progress(time))
+    }
+
+    /// Write interpolated positions at `time` into `data`
+    ///
+    /// Does nothing if positions are not being animated or `data` holds a
+    /// different number of points than the transition was built with.
+    pub fn apply_positions(&self, time: f32, data: &mut ChartData) {
+        if !self.has_positions() || data.vertices.len() != self.from_positions.len() {
+            return;
+        }
+
+        let t = self.progress(time);
+        let pairs = self.from_positions.iter().zip(&self.to_positions);
+        for (vertex, (a, b)) in data.vertices.iter_mut().zip(pairs) {
+            vertex.position = [lerp(a[0], b[0], t), lerp(a[1], b[1], t)];
+        }
+        data.mark_changed();
+    }
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
@@ -0,0 +1,309 @@
+use crate::colorspace::ColorSpace;
+use crate::data::{ChartData, Color, Point2D};
+use crate::error::HelionError;
+use crate::figure::DEFAULT_COLOR_CYCLE;
+use crate::hierarchy::{Hierarchy, HierarchyNode};
+use crate::text::{HAlign, TextSection, VAlign};
+
+/// How much lighter each level below the top is drawn, as a mix towards
+/// white
+const DEPTH_LIGHTEN: f32 = 0.18;
+
+/// Pixels each tile is drawn inset from its cell, so neighbours of the same
+/// color stay apart
+const TILE_INSET: f32 = 0.5;
+
+/// Where one node is drawn, in pixels, y down
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TreemapTile {
+    /// Index of the node, as `Treemap::label` and friends take it
+    pub node: usize,
+    /// `[x, y, width, height]`
+    pub rect: [f32; 4],
+}
+
+/// Hierarchy of values drawn as nested rectangles with areas proportional
+/// to the values
+///
+/// Uses the squarified layout (Bruls, Huizing and van Wijk), which keeps
+/// tiles close to square so their areas are easy to compare. Groups are
+/// drawn as a frame around their children with their label in a header
+/// strip; leaves are labeled in their top left corner when there is room.
+/// Each top-level group takes a color from the palette, and levels below
+/// it get lighter shades of it.
+///
+/// Nodes are numbered as in `Hierarchy`, the root being 0. A node's number
+/// is also its pick id: drawing `pick_id` into an id buffer and
+/// reading it back with `node_for_pick_id` finds the node under a pixel,
+/// 0 standing for the background as in `GpuPicker`. `node_at` does the
+/// same on the CPU.
+///
+/// # Example
+/// ```
+/// use helion_core::hierarchy::HierarchyNode;
+/// use helion_core::treemap::Treemap;
+///
+/// let root = HierarchyNode::group("Disk", vec![
+///     HierarchyNode::group("src", vec![HierarchyNode::leaf("lib.rs", 6.0), HierarchyNode::leaf("main.rs", 2.0)]),
+///     HierarchyNode::leaf("README.md", 4.0),
+/// ]);
+/// let treemap = Treemap::new(root).unwrap();
+/// assert_eq!(treemap.hierarchy().label(2), "lib.rs");
+///
+/// // "lib.rs" fills the left of the "src" tile
+/// let lib = treemap.pick_id(2);
+/// assert_eq!(treemap.node_for_pick_id(lib), Some(2));
+/// assert_eq!(treemap.node_at(40.0, 150.0, 300.0, 200.0), Some(2));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Treemap {
+    tree: Hierarchy,
+    colors: Vec<Color>,
+    padding: f32,
+    font_size: f32,
+    color_space: ColorSpace,
+}
+
+impl Treemap {
+    /// Treemap of `root` and its descendants; the root fills the viewport
+    /// and isn't drawn itself
+    ///
+    /// # Returns
+    /// `HelionError::InvalidOption` if a leaf's value is negative or not
+    /// finite, as for `Hierarchy::new`
+    pub fn new(root: HierarchyNode) -> Result<Self, HelionError> {
+        Ok(Self {
+            tree: Hierarchy::new(root)?,
+            colors: DEFAULT_COLOR_CYCLE.iter().map(|hex| Color::from_hex(hex)).collect(),
+            padding: 3.0,
+            font_size: 12.0,
+            color_space: ColorSpace::default(),
+        })
+    }
+
+    /// Fills of the top-level nodes, cycled when there are fewer colors
+    /// than nodes; the Tableau 10 palette by default
+    pub fn with_colors(mut self, colors: Vec<Color>) -> Self {
+        if !colors.is_empty() {
+            self.colors = colors;
+        }
+        self
+    }
+
+    /// Gap between a group's frame and its children in pixels, 3 by
+    /// default
+    pub fn with_padding(mut self, padding: f32) -> Self {
+        self.padding = padding.max(0.0);
+        self
+    }
+
+    /// Size of the labels in pixels, 12 by default; group headers are as
+    /// tall as one line
+    pub fn with_font_size(mut self, size: f32) -> Self {
+        self.font_size = size;
+        self
+    }
+
+    /// Space the shades of deeper levels are mixed in, Oklab by default
+    pub fn with_color_space(mut self, color_space: ColorSpace) -> Self {
+        self.color_space = color_space;
+        self
+    }
+
+    /// The nodes drawn, with their labels and values
+    pub fn hierarchy(&self) -> &Hierarchy {
+        &self.tree
+    }
+
+    /// Id `node` is drawn with in a picking pass
+    pub fn pick_id(&self, node: usize) -> u32 {
+        node as u32
+    }
+
+    /// Node an id read back from a picking pass stands for; `None` for 0,
+    /// the background, and for ids of no node
+    pub fn node_for_pick_id(&self, id: u32) -> Option<usize> {
+        let node = id as usize;
+        (node > 0 && node < self.tree.len()).then_some(node)
+    }
+
+    /// Fill of `node`: its top-level ancestor's color, lighter for each
+    /// level below it
+    pub fn node_color(&self, node: usize) -> Color {
+        let rank = self.tree.branch(node).unwrap_or(0);
+        let base = self.colors[rank % self.colors.len()];
+        let lighten = (DEPTH_LIGHTEN * self.tree.depth(node).saturating_sub(1) as f32).min(0.6);
+        self.color_space.mix(base, Color::new(1.0, 1.0, 1.0, base.a), lighten)
+    }
+
+    /// Place every node with a nonzero value in a `width` x `height`
+    /// viewport, parents before their children
+    ///
+    /// Children are placed largest first. A group's children fill its tile
+    /// less `padding` on each side and, when the tile is tall enough, a
+    /// header strip for its label; groups too small to leave any room are
+    /// drawn without their children.
+    pub fn layout(&self, width: f32, height: f32) -> Vec<TreemapTile> {
+        let mut tiles = Vec::new();
+        self.layout_children(0, [0.0, 0.0, width, height], &mut tiles);
+        tiles
+    }
+
+    fn layout_children(&self, node: usize, rect: [f32; 4], tiles: &mut Vec<TreemapTile>) {
+        let mut children: Vec<usize> = self.tree
+            .children(node)
+            .iter()
+            .copied()
+            .filter(|&child| self.tree.value(child) > 0.0)
+            .collect();
+        children.sort_by(|&a, &b| self.tree.value(b).total_cmp(&self.tree.value(a)));
+        let values: Vec<f32> = children.iter().map(|&child| self.tree.value(child)).collect();
+        for (child, rect) in children.into_iter().zip(squarify(&values, rect)) {
+            tiles.push(TreemapTile { node: child, rect });
+            if let Some(inner) = self.inner_rect(child, rect) {
+                self.layout_children(child, inner, tiles);
+            }
+        }
+    }
+
+    /// Area left for the children of group `node` drawn at `rect`, if any
+    fn inner_rect(&self, node: usize, [x, y, w, h]: [f32; 4]) -> Option<[f32; 4]> {
+        if self.tree.children(node).is_empty() {
+            return None;
+        }
+        let p = self.padding;
+        let header = self.header_height();
+        let top = if h - header > 2.0 * header { header } else { p };
+        let inner = [x + p, y + top, w - 2.0 * p, h - top - p];
+        (inner[2] > 0.0 && inner[3] > 0.0).then_some(inner)
+    }
+
+    fn header_height(&self) -> f32 {
+        self.font_size + self.padding * 2.0
+    }
+
+    /// Deepest node drawn under pixel (`x`, `y`) of a `width` x `height`
+    /// viewport, if any
+    pub fn node_at(&self, x: f32, y: f32, width: f32, height: f32) -> Option<usize> {
+        self.layout(width, height)
+            .iter()
+            .rev()
+            .find(|tile| {
+                let [tx, ty, tw, th] = tile.rect;
+                x >= tx && x <= tx + tw && y >= ty && y <= ty + th
+            })
+            .map(|tile| tile.node)
+    }
+
+    /// Tiles, groups before their children, for `MeshRenderer` in a
+    /// `width` x `height` viewport
+    pub fn chart_data(&self, width: f32, height: f32) -> ChartData {
+        let clip = |x: f32, y: f32| Point2D::new(x / width * 2.0 - 1.0, 1.0 - y / height * 2.0);
+        let mut data = ChartData::new(width, height);
+        for tile in self.layout(width, height) {
+            let [x, y, w, h] = tile.rect;
+            let inset = TILE_INSET.min(w / 4.0).min(h / 4.0);
+            let (x, y, w, h) = (x + inset, y + inset, w - 2.0 * inset, h - 2.0 * inset);
+            data.add_rect(clip(x, y + h), clip(x + w, y), self.node_color(tile.node));
+        }
+        data
+    }
+
+    /// Labels for `TextRenderer`: in the header of each group with one,
+    /// and in the top left corner of leaves big enough to hold a line
+    ///
+    /// Text is dark on light tiles and white on dark ones.
+    pub fn text_sections(&self, width: f32, height: f32) -> Vec<TextSection> {
+        let p = self.padding;
+        let header = self.header_height();
+        self.layout(width, height)
+            .into_iter()
+            .filter(|tile| {
+                let [_, _, w, h] = tile.rect;
+                let room = match self.tree.children(tile.node).is_empty() {
+                    true => h >= header,
+                    false => h - header > 2.0 * header,
+                };
+                room && w >= 2.0 * self.font_size
+            })
+            .map(|tile| {
+                let [x, y, _, _] = tile.rect;
+                let fill = self.node_color(tile.node);
+                let luminance = 0.2126 * fill.r + 0.7152 * fill.g + 0.0722 * fill.b;
+                let text_color = match luminance > 0.55 {
+                    true => Color::new(0.1, 0.1, 0.1, 1.0),
+                    false => Color::new(1.0, 1.0, 1.0, 1.0),
+                };
+                TextSection::new(self.tree.label(tile.node), [x + p, y + p], self.font_size)
+                    .with_color(text_color)
+                    .with_align(HAlign::Left, VAlign::Top)
+            })
+            .collect()
+    }
+}
+
+/// Squarified rectangles for `values`, sorted largest first, filling
+/// `rect` with areas proportional to them
+///
+/// Values are laid in rows along the shorter side of the space left, a row
+/// growing while that brings its worst aspect ratio closer to 1.
+fn squarify(values: &[f32], rect: [f32; 4]) -> Vec<[f32; 4]> {
+    let total: f32 = values.iter().sum();
+    let [mut x, mut y, mut w, mut h] = rect;
+    if total <= 0.0 || w <= 0.0 || h <= 0.0 {
+        return Vec::new();
+    }
+    let areas: Vec<f32> = values.iter().map(|v| v / total * w * h).collect();
+    let mut rects = Vec::with_capacity(values.len());
+    let mut start = 0;
+    while start < areas.len() {
+        let side = w.min(h);
+        let mut end = start + 1;
+        let mut best = worst_ratio(&areas[start..end], side);
+        while end < areas.len() {
+            let ratio = worst_ratio(&areas[start..=end], side);
+            if ratio > best {
+                break;
+            }
+            best = ratio;
+            end += 1;
+        }
+
+        // Lay the row against the shorter side, then shrink the space left
+        let row_area: f32 = areas[start..end].iter().sum();
+        if w >= h {
+            let thickness = row_area / h;
+            let mut offset = y;
+            for area in &areas[start..end] {
+                let length = area / thickness;
+                rects.push([x, offset, thickness, length]);
+                offset += length;
+            }
+            x += thickness;
+            w -= thickness;
+        } else {
+            let thickness = row_area / w;
+            let mut offset = x;
+            for area in &areas[start..end] {
+                let length = area / thickness;
+                rects.push([offset, y, length, thickness]);
+                offset += length;
+            }
+            y += thickness;
+            h -= thickness;
+        }
+        start = end;
+    }
+    rects
+}
+
+/// Largest aspect ratio (long side over short) of a row of `areas` laid
+/// along a side of length `side`
+fn worst_ratio(areas: &[f32], side: f32) -> f32 {
+    let sum: f32 = areas.iter().sum();
+    let side2 = side * side;
+    areas
+        .iter()
+        .map(|&area| (side2 * area / (sum * sum)).max(sum * sum / (side2 * area)))
+        .fold(0.0, f32::max)
+}
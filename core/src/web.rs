@@ -0,0 +1,428 @@
+use crate::axes::{AxesOptions, AxesRenderer};
+use crate::backend::GPUBackend;
+use crate::data::{ChartData, Color, ScatterTransform};
+use crate::error::HelionError;
+use crate::figure::FigureText;
+use crate::legend::{faded, Legend};
+use crate::mesh::MeshRenderer;
+use crate::renderer::{RenderAttachments, RenderOptions, Renderer, WebRenderer, WindowRenderer};
+use crate::scatter::ScatterRenderer;
+use crate::text::TextRenderer;
+use crate::transition::ViewRange;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// A scatter plot drawn on a page's `<canvas>`, for use from JavaScript
+///
+/// ```js
+/// import init, { HelionChart } from './pkg/helion_core.js';
+///
+/// await init();
+/// const chart = await HelionChart.new('plot');
+/// chart.setData(new Float32Array([0, 1, 2]), new Float32Array([2, 0, 1]));
+/// chart.render();
+/// ```
+///
+/// The canvas is drawn at its current `width` x `height`. WebGPU is used
+/// where the browser has it, WebGL2 otherwise.
+///
+/// Naming the series with `setLabel` shows a legend; forward the canvas's
+/// pointer events to it and redraw when they say so:
+///
+/// ```js
+/// chart.setLabel('samples');
+/// canvas.addEventListener('pointermove', (e) => chart.pointerMove(e.offsetX, e.offsetY) && chart.render());
+/// canvas.addEventListener('pointerleave', () => chart.pointerLeave() && chart.render());
+/// canvas.addEventListener('click', (e) => chart.click(e.offsetX, e.offsetY) && chart.render());
+/// ```
+///
+/// Axes with tick labels, a title and axis labels are drawn on the GPU as
+/// well:
+///
+/// ```js
+/// chart.showAxes(true);
+/// chart.setTitle('Latency');
+/// chart.setXLabel('time (s)');
+/// chart.setYLabel('ms');
+/// ```
+#[wasm_bindgen]
+pub struct HelionChart {
+    backend: GPUBackend,
+    renderer: ScatterRenderer,
+    data: Option<ChartData>,
+    /// Data range of the points, which fills the canvas
+    view: ViewRange,
+    options: RenderOptions,
+    /// MSAA/depth textures of the pass the points, text and legend share
+    attachments: Option<RenderAttachments>,
+    text: Option<WebText>,
+    legend: Option<WebLegend>,
+}
+
+/// Axes behind the points; tick labels, title and axis labels over them
+struct WebText {
+    /// Gridlines, ticks and frame, shown with `showAxes`
+    axes: Option<AxesRenderer>,
+    renderer: TextRenderer,
+    text: FigureText,
+    /// Whether the text or view changed since the text was last laid out
+    stale: bool,
+}
+
+impl WebText {
+    fn new(backend: &GPUBackend, options: &RenderOptions) -> Result<Self, HelionError> {
+        let (_, config) = backend.surface()?;
+        Ok(Self {
+            axes: None,
+            renderer: TextRenderer::new(backend.device()?, config.format, options),
+            text: FigureText::default(),
+            stale: true,
+        })
+    }
+
+    /// The chart's text, created on first use
+    fn get_or_create<'a>(
+        slot: &'a mut Option<WebText>,
+        backend: &GPUBackend,
+        options: &RenderOptions,
+    ) -> Result<&'a mut WebText, HelionError> {
+        if slot.is_none() {
+            *slot = Some(Self::new(backend, options)?);
+        }
+        Ok(slot.as_mut().expect("text was just created"))
+    }
+
+    /// Show or hide the axes, built for `options` and showing `view`
+    fn show_axes(
+        &mut self,
+        backend: &GPUBackend,
+        show: bool,
+        view: ViewRange,
+        options: &RenderOptions,
+    ) -> Result<(), HelionError> {
+        self.axes = if show {
+            let (_, config) = backend.surface()?;
+            Some(AxesRenderer::new(
+                backend.device()?,
+                config.format,
+                options,
+                AxesOptions::default(),
+                view,
+                config.width as f32,
+                config.height as f32,
+            ))
+        } else {
+            None
+        };
+        self.stale = true;
+        Ok(())
+    }
+
+    /// Move the axes to `view` and lay the text out again if either changed
+    fn refresh(&mut self, backend: &GPUBackend, view: &ViewRange) -> Result<(), HelionError> {
+        let (device, queue) = (backend.device()?, backend.queue()?);
+        if let Some(axes) = &mut self.axes {
+            axes.set_view(device, *view);
+        }
+        if std::mem::take(&mut self.stale) {
+            let (_, config) = backend.surface()?;
+            let (width, height) = (config.width as f32, config.height as f32);
+            let sections = self.text.sections(view, self.axes.as_ref().map(AxesRenderer::options), width, height);
+            self.renderer.set_sections(device, queue, sections, width, height);
+        }
+        Ok(())
+    }
+}
+
+/// Legend box, swatch and label drawn over the points
+struct WebLegend {
+    legend: Legend,
+    quads: MeshRenderer,
+    labels: TextRenderer,
+    /// Whether the box or label changed since they were last uploaded
+    stale: bool,
+}
+
+impl WebLegend {
+    fn new(backend: &GPUBackend, legend: Legend, options: &RenderOptions) -> Result<Self, HelionError> {
+        let device = backend.device()?;
+        let (_, config) = backend.surface()?;
+        Ok(Self {
+            quads: MeshRenderer::with_options(device, config, legend.quad_data(), options),
+            labels: TextRenderer::new(device, config.format, options),
+            legend,
+            stale: true,
+        })
+    }
+
+    /// Upload the box and label if they changed
+    fn refresh(&mut self, backend: &GPUBackend) -> Result<(), HelionError> {
+        if std::mem::take(&mut self.stale) {
+            let (device, queue) = (backend.device()?, backend.queue()?);
+            let (_, config) = backend.surface()?;
+            self.quads.update_data(device, queue, &self.legend.quad_data());
+            let sections = self.legend.text_sections();
+            self.labels.set_sections(device, queue, sections, config.width as f32, config.height as f32);
+        }
+        Ok(())
+    }
+}
+
+#[wasm_bindgen]
+impl HelionChart {
+    /// Attach to the canvas with id `canvas_id`
+    ///
+    /// Async because getting a GPU device is; in JavaScript it's
+    /// `await HelionChart.new(id)`.
+    pub async fn new(canvas_id: String) -> Result<HelionChart, JsValue> {
+        let canvas = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.get_element_by_id(&canvas_id))
+            .ok_or_else(|| JsValue::from_str(&format!("No element with id '{}'", canvas_id)))?
+            .dyn_into::<web_sys::HtmlCanvasElement>()
+            .map_err(|_| JsValue::from_str(&format!("Element '{}' is not a canvas", canvas_id)))?;
+        let (width, height) = (canvas.width().max(1), canvas.height().max(1));
+        let backend = GPUBackend::for_surface(Canvas(canvas), width, height)
+            .await
+            .map_err(to_js)?;
+        let renderer = <ScatterRenderer as WebRenderer>::new(&backend).map_err(to_js)?;
+        Ok(HelionChart {
+            backend,
+            renderer,
+            data: None,
+            view: ViewRange::new((0.0, 1.0), (0.0, 1.0)),
+            options: RenderOptions::default(),
+            attachments: None,
+            text: None,
+            legend: None,
+        })
+    }
+
+    /// Replace the plotted points; `render` draws them
+    ///
+    /// The canvas, and the axes if shown, are fitted to the new points.
+    #[wasm_bindgen(js_name = setData)]
+    pub fn set_data(&mut self, x: js_sys::Float32Array, y: js_sys::Float32Array) -> Result<(), JsValue> {
+        if x.length() != y.length() {
+            return Err(JsValue::from_str(&format!(
+                "x has {} values but y has {}",
+                x.length(),
+                y.length()
+            )));
+        }
+        let config = self
+            .backend
+            .config
+            .as_ref()
+            .ok_or_else(|| to_js(HelionError::NotInitialized("surface configuration")))?;
+        let (x, y) = (x.to_vec(), y.to_vec());
+        self.data = Some(ChartData::from_scatter(&x, &y, None, None, config.width as f32, config.height as f32));
+        let transform = ScatterTransform::fit(&x, &y, None, None);
+        let range = |(min, max): (f64, f64)| (min as f32, max as f32);
+        self.view = ViewRange::new(range(transform.x_in), range(transform.y_in));
+        if let Some(text) = &mut self.text {
+            text.stale = true;
+        }
+        Ok(())
+    }
+
+    /// Draw the current points on the canvas
+    ///
+    /// Fails if `setData` hasn't been called yet.
+    pub fn render(&mut self) -> Result<(), JsValue> {
+        let data = self.data.as_ref().ok_or_else(|| JsValue::from_str("No data set. Call setData() first."))?;
+        // Points hidden in the legend are neither uploaded nor drawn
+        let alpha = self.legend.as_ref().map_or(1.0, |legend| legend.legend.series_alpha(0));
+        if alpha == 1.0 {
+            self.renderer.prepare(&self.backend, data, &self.options).map_err(to_js)?;
+        } else if alpha > 0.0 {
+            self.renderer.prepare(&self.backend, &faded(data, alpha), &self.options).map_err(to_js)?;
+        }
+        if let Some(text) = &mut self.text {
+            text.refresh(&self.backend, &self.view).map_err(to_js)?;
+        }
+        if let Some(legend) = &mut self.legend {
+            legend.refresh(&self.backend).map_err(to_js)?;
+        }
+        self.draw(alpha > 0.0).map_err(to_js)
+    }
+
+    /// Draw gridlines, ticks, a frame and tick labels around the points,
+    /// or remove them
+    #[wasm_bindgen(js_name = showAxes)]
+    pub fn show_axes(&mut self, show: bool) -> Result<(), JsValue> {
+        let text = WebText::get_or_create(&mut self.text, &self.backend, &self.options).map_err(to_js)?;
+        text.show_axes(&self.backend, show, self.view, &self.options).map_err(to_js)
+    }
+
+    /// Title centered along the top; `null` removes it
+    #[wasm_bindgen(js_name = setTitle)]
+    pub fn set_title(&mut self, title: Option<String>) -> Result<(), JsValue> {
+        self.update_text(|text| text.title = title)
+    }
+
+    /// Label along the x axis; `null` removes it
+    #[wasm_bindgen(js_name = setXLabel)]
+    pub fn set_xlabel(&mut self, label: Option<String>) -> Result<(), JsValue> {
+        self.update_text(|text| text.xlabel = label)
+    }
+
+    /// Label along the y axis, turned upright; `null` removes it
+    #[wasm_bindgen(js_name = setYLabel)]
+    pub fn set_ylabel(&mut self, label: Option<String>) -> Result<(), JsValue> {
+        self.update_text(|text| text.ylabel = label)
+    }
+
+    /// Name the series and show it in a legend; clicking the entry hides
+    /// or shows the points
+    #[wasm_bindgen(js_name = setLabel)]
+    pub fn set_label(&mut self, label: String) -> Result<(), JsValue> {
+        let (_, config) = self.backend.surface().map_err(to_js)?;
+        let mut legend = Legend::new(config.width as f32, config.height as f32);
+        let color = self.data.as_ref().and_then(|data| data.vertices.first()).map_or_else(Color::default, |v| {
+            let [r, g, b, a] = v.color;
+            Color::new(r, g, b, a)
+        });
+        legend.add(label, color);
+        self.legend = Some(WebLegend::new(&self.backend, legend, &self.options).map_err(to_js)?);
+        Ok(())
+    }
+
+    /// The pointer moved to canvas pixel (`x`, `y`)
+    ///
+    /// Returns whether the legend highlight changed, i.e. the chart needs
+    /// a `render`.
+    #[wasm_bindgen(js_name = pointerMove)]
+    pub fn pointer_move(&mut self, x: f32, y: f32) -> bool {
+        self.legend.as_mut().is_some_and(|legend| {
+            let changed = legend.legend.pointer_moved(x, y);
+            legend.stale |= changed;
+            changed
+        })
+    }
+
+    /// The pointer left the canvas; returns whether the chart needs a
+    /// `render`
+    #[wasm_bindgen(js_name = pointerLeave)]
+    pub fn pointer_leave(&mut self) -> bool {
+        self.legend.as_mut().is_some_and(|legend| {
+            let changed = legend.legend.pointer_left();
+            legend.stale |= changed;
+            changed
+        })
+    }
+
+    /// Click at canvas pixel (`x`, `y`), toggling the series if it hit its
+    /// legend entry
+    ///
+    /// Returns whether it did, i.e. the chart needs a `render`.
+    pub fn click(&mut self, x: f32, y: f32) -> bool {
+        self.legend.as_mut().is_some_and(|legend| {
+            let toggled = legend.legend.click(x, y).is_some();
+            legend.stale |= toggled;
+            toggled
+        })
+    }
+
+    /// MSAA samples per pixel: 1 (off, the default) or 4
+    ///
+    /// Four samples smooth the edges of points and lines; the multisampled
+    /// texture is recreated on the next `render`.
+    #[wasm_bindgen(getter, js_name = sampleCount)]
+    pub fn sample_count(&self) -> u32 {
+        self.options.sample_count
+    }
+
+    #[wasm_bindgen(setter, js_name = sampleCount)]
+    pub fn set_sample_count(&mut self, sample_count: u32) -> Result<(), JsValue> {
+        let options = self.options.clone().with_sample_count(sample_count);
+        options.validate().map_err(to_js)?;
+        // The text's and legend's pipelines are built for one sample count
+        if let Some(old) = self.text.take() {
+            let mut text = WebText::new(&self.backend, &options).map_err(to_js)?;
+            text.text = old.text;
+            text.show_axes(&self.backend, old.axes.is_some(), self.view, &options).map_err(to_js)?;
+            self.text = Some(text);
+        }
+        if let Some(legend) = self.legend.take() {
+            self.legend = Some(WebLegend::new(&self.backend, legend.legend, &options).map_err(to_js)?);
+        }
+        self.options = options;
+        Ok(())
+    }
+
+    /// "WebGPU" or "WebGL2", e.g. to show a fallback notice
+    #[wasm_bindgen(getter, js_name = backendType)]
+    pub fn backend_type(&self) -> String {
+        self.backend.backend_type().to_string()
+    }
+}
+
+impl HelionChart {
+    fn update_text(&mut self, update: impl FnOnce(&mut FigureText)) -> Result<(), JsValue> {
+        let text = WebText::get_or_create(&mut self.text, &self.backend, &self.options).map_err(to_js)?;
+        update(&mut text.text);
+        text.stale = true;
+        Ok(())
+    }
+
+    /// Draw the axes, the prepared points (unless `points` is false because
+    /// the legend hides them), the text and the legend into the next frame
+    fn draw(&mut self, points: bool) -> Result<(), HelionError> {
+        let device = self.backend.device()?;
+        let queue = self.backend.queue()?;
+        let (surface, config) = self.backend.surface()?;
+
+        let attachments = match self.attachments.take() {
+            Some(a) if a.matches(config.width, config.height, config.format, &self.options) => a,
+            _ => RenderAttachments::new(device, config.width, config.height, config.format, &self.options),
+        };
+
+        let frame = surface.get_current_texture()?;
+        let view = frame.texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Render Encoder"),
+        });
+        {
+            let mut render_pass = attachments.begin_pass(&mut encoder, &view, &self.options);
+            let (axes, labels) = match &mut self.text {
+                Some(text) => (text.axes.as_mut(), Some(&mut text.renderer)),
+                None => (None, None),
+            };
+            if let Some(axes) = axes {
+                axes.render_to_pass(&mut render_pass);
+            }
+            if points {
+                self.renderer.render_to_pass(&mut render_pass);
+            }
+            if let Some(labels) = labels {
+                labels.render_to_pass(&mut render_pass);
+            }
+            if let Some(legend) = &mut self.legend {
+                legend.quads.render_to_pass(&mut render_pass);
+                legend.labels.render_to_pass(&mut render_pass);
+            }
+            attachments.end_pass(&mut render_pass);
+        }
+        self.attachments = Some(attachments);
+
+        queue.submit(std::iter::once(encoder.finish()));
+        frame.present();
+        Ok(())
+    }
+}
+
+/// A canvas as a surface target; `for_surface` needs a cloneable target to
+/// probe each backend with, which `wgpu::SurfaceTarget` isn't
+#[derive(Clone)]
+struct Canvas(web_sys::HtmlCanvasElement);
+
+impl From<Canvas> for wgpu::SurfaceTarget<'static> {
+    fn from(canvas: Canvas) -> Self {
+        wgpu::SurfaceTarget::Canvas(canvas.0)
+    }
+}
+
+fn to_js(e: HelionError) -> JsValue {
+    JsValue::from_str(&e.to_string())
+}
@@ -1,37 +1,291 @@
 use winit::{
     application::ApplicationHandler,
-    event::WindowEvent,
-    event_loop::{ActiveEventLoop, EventLoop},
+    event::{ElementState, MouseButton, MouseScrollDelta, WindowEvent},
+    keyboard::ModifiersState,
+    error::EventLoopError,
+    event_loop::{ActiveEventLoop, EventLoop, EventLoopProxy},
+    keyboard::{Key, NamedKey},
     window::{Window, WindowId},
 };
 use wgpu::{Device, Queue, Surface, SurfaceConfiguration};
-use crate::{ChartData, ScatterRenderer};
-use crate::renderer::{Renderer, WindowRenderer};
-use std::sync::Arc;
+use crate::{Camera2D, ChartData, Color, HistogramRenderer, LineRenderer, MeshRenderer, PanZoom, Point2D, ScatterRenderer};
+use crate::axes::{AxesOptions, AxesRenderer};
+use crate::backend::{limits_for_adapter, Capabilities};
+use crate::brush::{BrushShape, Selection};
+use crate::compute::Aggregator;
+use crate::data::ScatterTransform;
+use crate::figure::{ChartKind, Figure, FigureText, SeriesLayer};
+use crate::heatmap::{AggregatedPoints, Heatmap, HeatmapRenderer};
+use crate::events::{EventBus, FigureEvent, FigureEventKind};
+use crate::error::HelionError;
+use crate::ingest::DataSource;
+use crate::legend::{faded, Legend};
+use crate::picking::GpuPicker;
+use crate::tooltip::{Tooltip, TooltipPoint};
+use crate::renderer::{RenderAttachments, RenderOptions, Renderer, WindowRenderer};
+use crate::stats::{FrameRecorder, FrameReport};
+use crate::text::TextRenderer;
+use crate::transition::{FrameClock, Transition, ViewRange};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
 
+/// Pixels of touchpad scrolling that count as one mouse-wheel step
+const PIXELS_PER_SCROLL_STEP: f32 = 50.0;
+
+/// Frame rate a deterministic window's clock assumes
+const ANIMATION_FPS: f32 = 60.0;
+
+/// Seconds the view takes to glide back when reset
+const RESET_TRANSITION_SECS: f32 = 0.25;
+
+/// Clip space, the range `Camera2D::visible_range` reports camera views in
+/// for view transitions
+const CLIP_RANGE: ViewRange = ViewRange {
+    x: (-1.0, 1.0),
+    y: (-1.0, 1.0),
+};
+
+/// A renderer drawing one series of the window's figure
+struct Layer {
+    series: usize,
+    kind: ChartKind,
+    renderer: Box<dyn WindowRenderer>,
+    /// The series' points at full opacity, to fade while the legend dims
+    /// the series
+    data: ChartData,
+    /// Legend alpha the renderer's points were last uploaded with
+    alpha: f32,
+}
+
+impl Layer {
+    /// Upload `data`, faded by `alpha`
+    fn upload(&mut self, device: &Device, queue: &Queue) {
+        if self.alpha < 1.0 {
+            self.renderer.update_data(device, queue, &faded(&self.data, self.alpha));
+        } else {
+            self.renderer.update_data(device, queue, &self.data);
+        }
+    }
+}
+
+/// Axes drawn behind the series, following the camera
+struct WindowAxes {
+    renderer: AxesRenderer,
+    /// Range the series' vertices were normalized against
+    data_range: ViewRange,
+}
+
+/// Points behind a 2D-histogram heatmap, counted again for the visible
+/// range after pans, zooms and resizes
+struct WindowHist2d {
+    points: AggregatedPoints,
+    /// Holds the points on the GPU where the device has compute
+    aggregator: Aggregator,
+    /// Colormap and value range the recounted heatmaps keep
+    style: Heatmap,
+    /// Range the series' vertices were normalized against
+    data_range: ViewRange,
+}
+
+/// The first series' points moving to new positions
+struct DataMorph {
+    transition: Transition,
+    /// Where the points end up
+    target: ChartData,
+}
+
+/// Tick labels, data labels, title and axis labels drawn over the series
+struct WindowText {
+    renderer: TextRenderer,
+    text: FigureText,
+    /// Range the series' vertices were normalized against, for placing the
+    /// data labels when there are no axes to follow the view
+    data_range: ViewRange,
+}
+
+/// Legend box, swatches and labels drawn over everything else
+struct WindowLegend {
+    legend: Legend,
+    quads: MeshRenderer,
+    labels: TextRenderer,
+}
+
+/// Clip-space positions and data values of a scatter series' points, for
+/// box and lasso selection and the tooltip
+struct ScatterPoints {
+    positions: Vec<[f32; 2]>,
+    values: Vec<[f64; 2]>,
+}
+
+impl ScatterPoints {
+    fn new(data: &ChartData) -> Self {
+        Self {
+            positions: data.vertices.iter().map(|vertex| vertex.position).collect(),
+            values: data.original_points().unwrap_or_default().to_vec(),
+        }
+    }
+
+    /// Take in the points appended to `data` since these were read from it
+    fn extend_from(&mut self, data: &ChartData) {
+        let held = self.positions.len();
+        let Some(new) = data.vertices.get(held..) else {
+            *self = Self::new(data);
+            return;
+        };
+        self.positions.extend(new.iter().map(|vertex| vertex.position));
+        match data.original_points() {
+            Some(original) if self.values.len() == held => self.values.extend_from_slice(&original[held..]),
+            _ => self.values.clear(),
+        }
+    }
+}
+
+/// Box or lasso being dragged out, drawn over the series
+struct WindowBrush {
+    shape: BrushShape,
+    overlay: MeshRenderer,
+}
+
+/// Box next to the cursor describing the point under it
+struct WindowTooltip {
+    tooltip: Tooltip,
+    quads: MeshRenderer,
+    label: TextRenderer,
+    /// Whether the box is drawn, i.e. the cursor is over a point with text
+    shown: bool,
+}
+
+/// A window drawing one chart, made of one or more series
+///
+/// Drag with the left mouse button to pan and scroll to zoom around the
+/// cursor; `R` or `Home` goes back to the full view. Panning and zooming
+/// only update the renderer's camera uniform, never the vertex data.
+///
+/// With a legend set, clicking an entry hides or shows its series and
+/// hovering one highlights it by fading the others to `legend::DIMMED_ALPHA`;
+/// clicks on the legend don't start a pan.
+///
+/// Hovering a scatter point shows a tooltip with its data values; see
+/// `set_tooltip`.
+///
+/// Shift+drag selects the scatter points inside a box and Alt+drag those
+/// inside a freehand lasso; releasing the button publishes a
+/// `SelectionChanged` event per scatter series and `Escape` clears the
+/// selection.
+///
+/// View changes and streamed-in points are published to the window's
+/// `EventBus`, as are the scatter point under the cursor and clicked points
+/// while something subscribes to them.
 pub struct RenderWindow {
     window: Arc<Window>,
     surface: Surface<'static>,
     device: Device,
     queue: Queue,
     config: SurfaceConfiguration,
-    renderer: ScatterRenderer,
+    /// Back to front
+    layers: Vec<Layer>,
+    /// Drawn over the axes, behind the series
+    heatmap: Option<HeatmapRenderer>,
+    /// Set when the heatmap is a 2D histogram to re-bin on zoom
+    hist2d: Option<WindowHist2d>,
+    axes: Option<WindowAxes>,
+    text: Option<WindowText>,
+    options: RenderOptions,
+    attachments: RenderAttachments,
+    recorder: FrameRecorder,
+    /// Times animations; steps a fixed 1/60 s per frame in deterministic
+    /// mode
+    clock: FrameClock,
+    /// Animated view change in progress, between clip-space ranges
+    view_transition: Option<Transition>,
+    morph: Option<DataMorph>,
+    pan_zoom: PanZoom,
+    legend: Option<WindowLegend>,
+    /// Last cursor position in pixels
+    cursor: Option<(f32, f32)>,
+    /// Ids of the scatter series' points, when there are any
+    picker: Option<GpuPicker>,
+    /// Last point under the cursor
+    hovered: Option<(usize, usize)>,
+    /// Whether the cursor, view or legend changed since `hovered` was
+    /// picked; picking waits for the GPU, so it happens once per frame
+    hover_stale: bool,
+    points: HashMap<usize, ScatterPoints>,
+    tooltip: Option<WindowTooltip>,
+    modifiers: ModifiersState,
+    brush: Option<WindowBrush>,
+    events: EventBus,
 }
 
 impl RenderWindow {
-    pub async fn new(event_loop: &ActiveEventLoop, chart_data: ChartData, title: &str) -> Self {
+    pub async fn new(
+        event_loop: &ActiveEventLoop,
+        chart_data: ChartData,
+        title: &str,
+        kind: ChartKind,
+        options: RenderOptions,
+    ) -> Self {
+        let size = (chart_data.viewport_width, chart_data.viewport_height);
+        let layer = SeriesLayer {
+            series: 0,
+            kind,
+            data: chart_data,
+        };
+        Self::with_layers(event_loop, vec![layer], size, title, options).await
+    }
+
+    /// Window drawing every series of `figure`, with its legend if any
+    /// series is labeled
+    pub async fn from_figure(
+        event_loop: &ActiveEventLoop,
+        figure: &Figure,
+        title: &str,
+        options: RenderOptions,
+    ) -> Self {
+        let size = (figure.width(), figure.height());
+        let mut window = Self::with_layers(event_loop, figure.layers(), size, title, options).await;
+        if let Some(axes) = figure.axes() {
+            window.set_axes(figure.data_range(), axes.clone());
+        }
+        match (figure.heatmap(), figure.aggregated()) {
+            (Some(heatmap), Some(points)) => window.set_hist2d(heatmap, points.clone(), &figure.data_range()),
+            (Some(heatmap), None) => window.set_heatmap(heatmap, &figure.data_range()),
+            _ => {}
+        }
+        let text = figure.text_with_data_labels();
+        if figure.axes().is_some_and(|axes| axes.tick_labels.is_some()) || !text.is_empty() {
+            window.set_text(text, figure.data_range());
+        }
+        if let Some(legend) = figure.legend() {
+            window.set_legend(legend);
+        }
+        window
+    }
+
+    /// Window drawing `layers` back to front
+    ///
+    /// # Parameters
+    /// * `size` - Initial inner size in pixels
+    pub async fn with_layers(
+        event_loop: &ActiveEventLoop,
+        layers: Vec<SeriesLayer>,
+        size: (f32, f32),
+        title: &str,
+        mut options: RenderOptions,
+    ) -> Self {
         // Create window
         let window_attributes = winit::window::Window::default_attributes()
             .with_title(title)
-            .with_inner_size(winit::dpi::PhysicalSize::new(
-                chart_data.viewport_width as u32,
-                chart_data.viewport_height as u32,
-            ));
+            .with_inner_size(winit::dpi::PhysicalSize::new(size.0 as u32, size.1 as u32));
         
         let window = Arc::new(event_loop
             .create_window(window_attributes)
             .expect("Failed to create window"));
 
+        // Vertices are normalized for the requested size, which the
+        // window may not get
+        let data_size = size;
         let size = window.inner_size();
 
         // Create wgpu instance
@@ -61,7 +315,7 @@ impl RenderWindow {
                 &wgpu::DeviceDescriptor {
                     label: Some("Helion Device"),
                     required_features: wgpu::Features::empty(),
-                    required_limits: wgpu::Limits::default(),
+                    required_limits: limits_for_adapter(&adapter),
                     memory_hints: Default::default(),
                 },
                 None,
@@ -91,20 +345,111 @@ impl RenderWindow {
 
         surface.configure(&device, &config);
 
-        // Create renderer using WindowRenderer trait
-        let renderer = ScatterRenderer::new(&device, &config, chart_data);
+        // Fall back to no MSAA if the adapter can't multisample this format
+        let format_flags = adapter.get_texture_format_features(config.format).flags;
+        if let Err(e) = options.validate() {
+            log::warn!("{}; falling back to default render options", e);
+            options = RenderOptions::default();
+        } else if !format_flags.sample_count_supported(options.effective_sample_count()) {
+            log::warn!(
+                "{}x MSAA is not supported for {:?}; rendering without it",
+                options.sample_count,
+                config.format
+            );
+            options.sample_count = 1;
+        }
+        let attachments = RenderAttachments::new(&device, config.width, config.height, config.format, &options);
 
-        Self {
+        // Scatter points can be picked; upload them before the renderers
+        // take the data
+        let mut picker = None;
+        let mut points = HashMap::new();
+        for layer in layers.iter().filter(|layer| layer.kind == ChartKind::Scatter) {
+            picker
+                .get_or_insert_with(|| GpuPicker::new(&device, config.width, config.height))
+                .set_series(&device, &queue, layer.series, &layer.data);
+            points.insert(layer.series, ScatterPoints::new(&layer.data));
+        }
+
+        // One renderer per series, using the WindowRenderer trait
+        let layers = layers
+            .into_iter()
+            .map(|layer| {
+                let data = layer.data.clone();
+                let renderer: Box<dyn WindowRenderer> = match layer.kind {
+                    ChartKind::Scatter => {
+                        Box::new(ScatterRenderer::with_options(&device, &config, layer.data, &options))
+                    }
+                    ChartKind::Line => Box::new(LineRenderer::with_options(&device, &config, layer.data, &options)),
+                    ChartKind::Bars => {
+                        Box::new(HistogramRenderer::with_options(&device, &config, layer.data, &options))
+                    }
+                };
+                Layer {
+                    series: layer.series,
+                    kind: layer.kind,
+                    renderer,
+                    data,
+                    alpha: 1.0,
+                }
+            })
+            .collect();
+
+        let mut pan_zoom = PanZoom::new(size.width as f32, size.height as f32);
+        if options.equal_aspect {
+            pan_zoom = pan_zoom.with_equal_aspect(data_size.0, data_size.1);
+        }
+
+        let mut window = Self {
             window,
             surface,
             device,
             queue,
             config,
-            renderer,
+            layers,
+            axes: None,
+            heatmap: None,
+            hist2d: None,
+            text: None,
+            clock: FrameClock::for_options(&options, ANIMATION_FPS),
+            view_transition: None,
+            morph: None,
+            options,
+            attachments,
+            recorder: FrameRecorder::new(),
+            pan_zoom,
+            legend: None,
+            cursor: None,
+            picker,
+            hovered: None,
+            hover_stale: false,
+            points,
+            tooltip: None,
+            modifiers: ModifiersState::empty(),
+            brush: None,
+            events: EventBus::new(),
+        };
+        if window.picker.is_some() {
+            window.set_tooltip(Some(Tooltip::new()));
         }
+        if *window.pan_zoom.camera() != Camera2D::identity() {
+            window.apply_camera();
+        }
+        window
     }
 
     pub fn render(&mut self) -> Result<(), wgpu::SurfaceError> {
+        if std::mem::take(&mut self.hover_stale) && self.brush.is_none() {
+            self.update_hovered();
+        }
+        let time = self.clock.tick();
+        let mut animating = self.advance_transitions(time);
+        for layer in &mut self.layers {
+            animating |= layer.renderer.set_time(&self.queue, time);
+        }
+        if animating {
+            self.window.request_redraw();
+        }
         let output = self.surface.get_current_texture()?;
         let view = output
             .texture
@@ -117,29 +462,63 @@ impl RenderWindow {
             });
 
         {
-            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                label: Some("Render Pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &view,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color {
-                            r: 1.0,
-                            g: 1.0,
-                            b: 1.0,
-                            a: 1.0,
-                        }),
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
-                depth_stencil_attachment: None,
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            });
+            let mut render_pass = self.attachments.begin_pass(&mut encoder, &view, &self.options);
 
-            // Use the Renderer trait's render_to_pass method
-            self.renderer.render_to_pass(&mut render_pass);
+            if let Some(axes) = &mut self.axes {
+                axes.renderer.render_to_pass(&mut render_pass);
+            }
+            if let Some(heatmap) = &mut self.heatmap {
+                heatmap.render_to_pass(&mut render_pass);
+            }
+            // Use the Renderer trait's render_to_pass method; series hidden
+            // in the legend are skipped
+            for layer in &mut self.layers {
+                if self.legend.as_ref().is_none_or(|legend| legend.legend.is_visible(layer.series)) {
+                    layer.renderer.render_to_pass(&mut render_pass);
+                }
+            }
+            if let Some(text) = &mut self.text {
+                text.renderer.render_to_pass(&mut render_pass);
+            }
+            if let Some(legend) = &mut self.legend {
+                legend.quads.render_to_pass(&mut render_pass);
+                legend.labels.render_to_pass(&mut render_pass);
+            }
+            if let Some(brush) = &mut self.brush {
+                brush.overlay.render_to_pass(&mut render_pass);
+            }
+            if let Some(tooltip) = self.tooltip.as_mut().filter(|tooltip| tooltip.shown) {
+                tooltip.quads.render_to_pass(&mut render_pass);
+                tooltip.label.render_to_pass(&mut render_pass);
+            }
+            self.attachments.end_pass(&mut render_pass);
+        }
+
+        self.recorder.begin_frame();
+        if let Some(axes) = &mut self.axes {
+            self.recorder.record("axes", axes.renderer.frame_stats());
+        }
+        if let Some(heatmap) = &mut self.heatmap {
+            self.recorder.record("heatmap", heatmap.frame_stats());
         }
+        for layer in &mut self.layers {
+            self.recorder.record(layer.kind.label(), layer.renderer.frame_stats());
+        }
+        if let Some(text) = &mut self.text {
+            self.recorder.record("text", text.renderer.frame_stats());
+        }
+        if let Some(legend) = &mut self.legend {
+            self.recorder.record("legend", legend.quads.frame_stats());
+            self.recorder.record("legend text", legend.labels.frame_stats());
+        }
+        if let Some(brush) = &mut self.brush {
+            self.recorder.record("brush", brush.overlay.frame_stats());
+        }
+        if let Some(tooltip) = self.tooltip.as_mut().filter(|tooltip| tooltip.shown) {
+            self.recorder.record("tooltip", tooltip.quads.frame_stats());
+            self.recorder.record("tooltip text", tooltip.label.frame_stats());
+        }
+        self.recorder.end_frame();
 
         self.queue.submit(std::iter::once(encoder.finish()));
         output.present();
@@ -152,82 +531,1175 @@ impl RenderWindow {
             self.config.width = new_size.width;
             self.config.height = new_size.height;
             self.surface.configure(&self.device, &self.config);
+            self.attachments = RenderAttachments::new(
+                &self.device,
+                self.config.width,
+                self.config.height,
+                self.config.format,
+                &self.options,
+            );
+            for layer in &mut self.layers {
+                layer.renderer.resize(&self.queue, self.config.width, self.config.height);
+            }
+            let camera_changed = self.pan_zoom.resize(self.config.width as f32, self.config.height as f32);
+            if let Some(picker) = &mut self.picker {
+                picker.resize(&self.device, &self.queue, self.config.width, self.config.height);
+            }
+            if let Some(axes) = &mut self.axes {
+                axes.renderer.resize(&self.device, self.config.width as f32, self.config.height as f32);
+            }
+            if camera_changed {
+                self.apply_camera();
+            } else {
+                // Same view at a new resolution
+                self.rebin();
+                self.refresh_text();
+            }
+            if let Some(legend) = &mut self.legend {
+                legend.legend.resize(self.config.width as f32, self.config.height as f32);
+            }
+            self.refresh_legend();
+            // Placed for the old size; the next cursor move shows it again
+            if let Some(tooltip) = &mut self.tooltip {
+                tooltip.shown = false;
+            }
+            self.brush = None;
+        }
+    }
+
+    /// Draw gridlines, ticks and frame behind the series
+    ///
+    /// # Parameters
+    /// * `data_range` - Range the series' vertices were normalized against;
+    ///   the ticks follow it through pans and zooms
+    pub fn set_axes(&mut self, data_range: ViewRange, options: AxesOptions) {
+        let view = self.pan_zoom.camera().visible_range(&data_range);
+        let renderer = AxesRenderer::new(
+            &self.device,
+            self.config.format,
+            &self.options,
+            options,
+            view,
+            self.config.width as f32,
+            self.config.height as f32,
+        );
+        self.axes = Some(WindowAxes { renderer, data_range });
+        self.refresh_text();
+        self.window.request_redraw();
+    }
+
+    /// Draw `heatmap` behind the series
+    ///
+    /// # Parameters
+    /// * `data_range` - Range the series' vertices were normalized against
+    pub fn set_heatmap(&mut self, heatmap: &Heatmap, data_range: &ViewRange) {
+        self.hist2d = None;
+        let mut renderer = HeatmapRenderer::new(&self.device, self.config.format, &self.options);
+        if let Err(e) = renderer.set_heatmap(&self.device, &self.queue, heatmap, data_range) {
+            log::error!("{}", e);
+            return;
+        }
+        renderer.set_camera(&self.queue, self.pan_zoom.camera());
+        self.heatmap = Some(renderer);
+        self.window.request_redraw();
+    }
+
+    /// Draw `heatmap`, a 2D histogram of `points`, behind the series and
+    /// count the points again for the visible range whenever a pan, zoom or
+    /// resize moves it by more than a fraction of a cell
+    ///
+    /// Counting runs on the GPU when the device has compute shaders, on
+    /// the CPU otherwise.
+    ///
+    /// # Parameters
+    /// * `data_range` - Range the series' vertices were normalized against
+    pub fn set_hist2d(&mut self, heatmap: &Heatmap, points: AggregatedPoints, data_range: &ViewRange) {
+        self.set_heatmap(heatmap, data_range);
+        let mut aggregator = Aggregator::new(&self.device, &Capabilities::of(&self.device));
+        aggregator.set_points(&self.device, &points.x, &points.y);
+        self.hist2d = Some(WindowHist2d {
+            points,
+            aggregator,
+            style: heatmap.clone(),
+            data_range: *data_range,
+        });
+        if *self.pan_zoom.camera() != Camera2D::identity() {
+            self.rebin();
+        }
+    }
+
+    /// Count the 2D histogram's points into a grid over the visible range,
+    /// if it moved or was resized enough to need one
+    fn rebin(&mut self) {
+        let (Some(hist2d), Some(renderer)) = (&mut self.hist2d, &mut self.heatmap) else {
+            return;
+        };
+        let view = self.pan_zoom.camera().visible_range(&hist2d.data_range);
+        let Some(grid) = hist2d.points.regrid(&view, self.config.width as f32, self.config.height as f32) else {
+            return;
+        };
+        // Waits for the dispatch, as picking does; only once the view has
+        // moved by part of a cell
+        let heatmap = futures::executor::block_on(hist2d.aggregator.bin(&self.device, &self.queue, &grid))
+            .map_err(HelionError::Readback)
+            .and_then(|counts| hist2d.style.recount(&grid, &counts))
+            .and_then(|heatmap| renderer.set_heatmap(&self.device, &self.queue, &heatmap, &hist2d.data_range));
+        if let Err(e) = heatmap {
+            log::error!("Failed to re-bin the 2D histogram: {}", e);
+        }
+    }
+
+    /// Draw tick labels (when axes are set), data labels, title and axis
+    /// labels over the series
+    ///
+    /// # Parameters
+    /// * `data_range` - Range the series' vertices were normalized against;
+    ///   the data labels follow it through pans and zooms
+    pub fn set_text(&mut self, text: FigureText, data_range: ViewRange) {
+        match &mut self.text {
+            Some(current) => {
+                current.text = text;
+                current.data_range = data_range;
+            }
+            None => {
+                let renderer = TextRenderer::new(&self.device, self.config.format, &self.options);
+                self.text = Some(WindowText { renderer, text, data_range });
+            }
+        }
+        self.refresh_text();
+        self.window.request_redraw();
+    }
+
+    /// Lay the text out again for the current view and size
+    fn refresh_text(&mut self) {
+        let Some(text) = &mut self.text else {
+            return;
+        };
+        let (width, height) = (self.config.width as f32, self.config.height as f32);
+        let sections = match &self.axes {
+            Some(axes) => text
+                .text
+                .sections(axes.renderer.view(), Some(axes.renderer.options()), width, height),
+            None => {
+                let view = self.pan_zoom.camera().visible_range(&text.data_range);
+                text.text.sections(&view, None, width, height)
+            }
+        };
+        text.renderer.set_sections(&self.device, &self.queue, sections, width, height);
+    }
+
+    /// Show `legend` and route clicks and hovers over it
+    pub fn set_legend(&mut self, mut legend: Legend) {
+        let (width, height) = (self.config.width as f32, self.config.height as f32);
+        legend.resize(width, height);
+        let quads = MeshRenderer::with_options(&self.device, &self.config, legend.quad_data(), &self.options);
+        let labels = TextRenderer::new(&self.device, self.config.format, &self.options);
+        self.legend = Some(WindowLegend { legend, quads, labels });
+        self.refresh_legend();
+        self.sync_picker_visibility();
+        self.apply_series_alpha();
+        self.window.request_redraw();
+    }
+
+    pub fn legend(&self) -> Option<&Legend> {
+        self.legend.as_ref().map(|legend| &legend.legend)
+    }
+
+    /// Upload the legend's box, swatches and labels again after a resize or
+    /// a change to its entries
+    fn refresh_legend(&mut self) {
+        let Some(legend) = &mut self.legend else {
+            return;
+        };
+        let (width, height) = (self.config.width as f32, self.config.height as f32);
+        legend.quads.update_data(&self.device, &self.queue, &legend.legend.quad_data());
+        let sections = legend.legend.text_sections();
+        legend.labels.set_sections(&self.device, &self.queue, sections, width, height);
+    }
+
+    /// Series and index of the scatter point drawn at pixel (`x`, `y`), if
+    /// any
+    ///
+    /// Series hidden in the legend can't be picked. Failures are logged and
+    /// treated as no point.
+    pub fn pick(&mut self, x: f32, y: f32) -> Option<(usize, usize)> {
+        let picker = self.picker.as_mut()?;
+        picker
+            .pick(&self.device, &self.queue, x, y)
+            .unwrap_or_else(|e| {
+                log::error!("{}", e);
+                None
+            })
+    }
+
+    /// Show `tooltip` over points under the cursor, or no tooltip for
+    /// `None`
+    ///
+    /// Windows with scatter series start with a default `Tooltip`.
+    pub fn set_tooltip(&mut self, tooltip: Option<Tooltip>) {
+        self.tooltip = tooltip.map(|tooltip| WindowTooltip {
+            tooltip,
+            quads: MeshRenderer::with_options(
+                &self.device,
+                &self.config,
+                ChartData::new(self.config.width as f32, self.config.height as f32),
+                &self.options,
+            ),
+            label: TextRenderer::new(&self.device, self.config.format, &self.options),
+            shown: false,
+        });
+        self.window.request_redraw();
+    }
+
+    /// Find the point under the cursor, publish it if it changed and move
+    /// the tooltip to it
+    ///
+    /// Called from `render` rather than per input event, as each pick reads
+    /// back from the GPU.
+    fn update_hovered(&mut self) {
+        let publish = self.events.has_subscribers(FigureEventKind::PointHovered);
+        if !publish && self.tooltip.is_none() {
+            return;
+        }
+        let over_legend = |(x, y): (f32, f32)| {
+            self.legend.as_ref().is_some_and(|legend| legend.legend.hit_test(x, y).is_some())
+        };
+        let point = match self.cursor {
+            Some(cursor) if !over_legend(cursor) => self.pick(cursor.0, cursor.1),
+            _ => None,
+        };
+        if publish && point != self.hovered {
+            self.events.publish(&FigureEvent::PointHovered { point });
+        }
+        self.hovered = point;
+        self.refresh_tooltip();
+    }
+
+    /// Lay the tooltip out for the hovered point at the cursor, or hide it
+    fn refresh_tooltip(&mut self) {
+        let Some(tooltip) = &mut self.tooltip else {
+            return;
+        };
+        let was_shown = tooltip.shown;
+        let text = match (self.hovered, self.cursor) {
+            (Some((series, index)), Some(_)) => {
+                let value = self.points.get(&series).and_then(|points| points.values.get(index)).copied();
+                tooltip.tooltip.text(&TooltipPoint { series, index, value })
+            }
+            _ => None,
+        };
+        tooltip.shown = false;
+        if let (Some(text), Some((x, y))) = (text, self.cursor) {
+            let viewport = [self.config.width as f32, self.config.height as f32];
+            let size = tooltip.label.measure(&text, tooltip.tooltip.options().font_size);
+            let bounds = tooltip.tooltip.bounds(size, [x, y], viewport);
+            tooltip.quads.update_data(&self.device, &self.queue, &tooltip.tooltip.quad_data(bounds, viewport));
+            let section = tooltip.tooltip.text_section(&text, bounds);
+            tooltip.label.set_sections(&self.device, &self.queue, vec![section], viewport[0], viewport[1]);
+            tooltip.shown = true;
+        }
+        if tooltip.shown || was_shown {
+            self.window.request_redraw();
+        }
+    }
+
+    /// Bus the window publishes its `FigureEvent`s to
+    pub fn events_mut(&mut self) -> &mut EventBus {
+        &mut self.events
+    }
+
+    /// Replace the window's event bus, e.g. with one whose subscribers were
+    /// set up before the window existed
+    pub fn set_events(&mut self, events: EventBus) {
+        self.events = events;
+    }
+
+    /// Pan, zoom, select points or toggle legend entries in response to
+    /// mouse and keyboard input
+    ///
+    /// # Returns
+    /// Whether the view changed (a redraw has then been requested)
+    pub fn handle_input(&mut self, event: &WindowEvent) -> bool {
+        let mut legend_changed = false;
+        let camera_changed = match event {
+            WindowEvent::ModifiersChanged(modifiers) => {
+                self.modifiers = modifiers.state();
+                false
+            }
+            WindowEvent::CursorMoved { position, .. } => {
+                let (x, y) = (position.x as f32, position.y as f32);
+                self.cursor = Some((x, y));
+                if let Some(brush) = &mut self.brush {
+                    if brush.shape.extend_to([x, y]) {
+                        self.refresh_brush();
+                    }
+                    return false;
+                }
+                if let Some(legend) = &mut self.legend {
+                    legend_changed = legend.legend.pointer_moved(x, y);
+                }
+                self.pan_zoom.cursor_moved(x, y)
+            }
+            WindowEvent::CursorLeft { .. } => {
+                self.cursor = None;
+                if let Some(legend) = &mut self.legend {
+                    legend_changed = legend.legend.pointer_left();
+                }
+                self.pan_zoom.cursor_left();
+                false
+            }
+            WindowEvent::MouseInput { state, button: MouseButton::Left, .. } => {
+                let pressed = *state == ElementState::Pressed;
+                let on_legend = match (&mut self.legend, self.cursor) {
+                    (Some(legend), Some((x, y))) if pressed => legend.legend.click(x, y).is_some(),
+                    _ => false,
+                };
+                legend_changed = on_legend;
+                if !pressed && self.brush.is_some() {
+                    self.finish_brush();
+                    return false;
+                }
+                let brush = match self.cursor {
+                    Some((x, y)) if pressed && !on_legend && self.picker.is_some() => {
+                        if self.modifiers.shift_key() {
+                            Some(BrushShape::rect([x, y]))
+                        } else if self.modifiers.alt_key() {
+                            Some(BrushShape::lasso([x, y]))
+                        } else {
+                            None
+                        }
+                    }
+                    _ => None,
+                };
+                if let Some(shape) = brush {
+                    self.start_brush(shape);
+                    return false;
+                }
+                self.pan_zoom.set_dragging(pressed && !on_legend);
+                if pressed && !on_legend && self.events.has_subscribers(FigureEventKind::PointPicked) {
+                    let point = self.cursor.and_then(|(x, y)| self.pick(x, y));
+                    if let Some((series, index)) = point {
+                        self.events.publish(&FigureEvent::PointPicked { series, index });
+                    }
+                }
+                false
+            }
+            WindowEvent::MouseWheel { delta, .. } => {
+                let steps = match delta {
+                    MouseScrollDelta::LineDelta(_, y) => *y,
+                    MouseScrollDelta::PixelDelta(p) => p.y as f32 / PIXELS_PER_SCROLL_STEP,
+                };
+                self.pan_zoom.scroll(steps)
+            }
+            WindowEvent::KeyboardInput { event, .. } if event.state == ElementState::Pressed => {
+                match event.logical_key.as_ref() {
+                    Key::Character("r") | Key::Character("R") | Key::Named(NamedKey::Home) => {
+                        let from = *self.pan_zoom.camera();
+                        if self.pan_zoom.reset() {
+                            let to = *self.pan_zoom.camera();
+                            self.pan_zoom.set_camera(from);
+                            self.transition_to(to, RESET_TRANSITION_SECS);
+                        }
+                        false
+                    }
+                    Key::Named(NamedKey::Escape) => {
+                        self.clear_selection();
+                        false
+                    }
+                    _ => false,
+                }
+            }
+            _ => false,
+        };
+
+        if camera_changed {
+            // The user's pan or zoom wins over an animation
+            self.view_transition = None;
+            self.apply_camera();
+        }
+        if legend_changed {
+            self.refresh_legend();
+            self.sync_picker_visibility();
+            self.apply_series_alpha();
+        }
+        let cursor_changed = matches!(event, WindowEvent::CursorMoved { .. } | WindowEvent::CursorLeft { .. });
+        if cursor_changed || camera_changed || legend_changed {
+            self.hover_stale = true;
+            self.window.request_redraw();
+        }
+        camera_changed || legend_changed
+    }
+
+    /// Glide from the current view to `camera`'s over `duration` seconds
+    /// of the window's `FrameClock`, eased in and out
+    ///
+    /// A pan or zoom by the user meanwhile stops it where it is.
+    pub fn transition_to(&mut self, camera: Camera2D, duration: f32) {
+        let from = self.pan_zoom.camera().visible_range(&CLIP_RANGE);
+        self.view_transition = Some(Transition::new(from, camera.visible_range(&CLIP_RANGE), duration));
+        self.window.request_redraw();
+    }
+
+    /// Replace the points of the first series added, moving each from its
+    /// old position to its new one over `duration` seconds
+    ///
+    /// Only the view's data changes shape smoothly; if the number of points
+    /// differs they are replaced at once, as by `set_data`.
+    pub fn transition_data(&mut self, chart_data: &ChartData, duration: f32) {
+        let from = self.layers.iter().find(|layer| layer.series == 0).map(|layer| layer.data.clone());
+        self.set_data(chart_data);
+        self.morph = None;
+        let Some(from) = from.filter(|from| from.vertices.len() == chart_data.vertices.len()) else {
+            return;
+        };
+        let transition = Transition::new(CLIP_RANGE, CLIP_RANGE, duration).with_positions(&from, chart_data);
+        if let Some(layer) = self.layers.iter_mut().find(|layer| layer.series == 0) {
+            layer.data = from;
+            layer.upload(&self.device, &self.queue);
+        }
+        self.morph = Some(DataMorph {
+            transition,
+            target: chart_data.clone(),
+        });
+        self.window.request_redraw();
+    }
+
+    /// Step the view transition and point morph to frame time `time`;
+    /// returns whether either is still running
+    fn advance_transitions(&mut self, time: f32) -> bool {
+        let mut running = false;
+        if let Some(transition) = &mut self.view_transition {
+            if !transition.is_started() {
+                transition.start(time);
+            }
+            let camera = Camera2D::from_view(&CLIP_RANGE, &transition.view_at(time));
+            let finished = transition.is_finished(time);
+            if finished {
+                self.view_transition = None;
+            }
+            running |= !finished;
+            if self.pan_zoom.set_camera(camera) {
+                self.apply_camera();
+                self.hover_stale = true;
+            }
+        }
+        if let Some(morph) = &mut self.morph {
+            if !morph.transition.is_started() {
+                morph.transition.start(time);
+            }
+            let finished = morph.transition.is_finished(time);
+            if let Some(layer) = self.layers.iter_mut().find(|layer| layer.series == 0) {
+                if finished {
+                    layer.data = morph.target.clone();
+                } else {
+                    morph.transition.apply_positions(time, &mut layer.data);
+                }
+                layer.upload(&self.device, &self.queue);
+            }
+            if finished {
+                self.morph = None;
+            }
+            running |= !finished;
+        }
+        running
+    }
+
+    /// Hand the pan/zoom camera to the series, heatmap, picker and axes
+    /// after it changed, re-bin a 2D histogram, and publish the new view
+    fn apply_camera(&mut self) {
+        let camera = *self.pan_zoom.camera();
+        for layer in &mut self.layers {
+            layer.renderer.set_camera(&self.queue, &camera);
+        }
+        if let Some(heatmap) = &mut self.heatmap {
+            heatmap.set_camera(&self.queue, &camera);
+        }
+        if let Some(picker) = &mut self.picker {
+            picker.set_camera(&self.queue, &camera);
+        }
+        if let Some(axes) = &mut self.axes {
+            axes.renderer.set_view(&self.device, camera.visible_range(&axes.data_range));
+        }
+        self.rebin();
+        self.refresh_text();
+        self.events.publish(&FigureEvent::ViewChanged { camera });
+    }
+
+    /// Start dragging out `shape`, hiding the tooltip meanwhile
+    fn start_brush(&mut self, shape: BrushShape) {
+        let overlay = MeshRenderer::with_options(
+            &self.device,
+            &self.config,
+            ChartData::new(self.config.width as f32, self.config.height as f32),
+            &self.options,
+        );
+        self.brush = Some(WindowBrush { shape, overlay });
+        self.hovered = None;
+        self.hover_stale = false;
+        self.refresh_tooltip();
+        self.refresh_brush();
+    }
+
+    /// Upload the brush outline again after it grew
+    fn refresh_brush(&mut self) {
+        if let Some(brush) = &mut self.brush {
+            let (width, height) = (self.config.width as f32, self.config.height as f32);
+            let data = brush.shape.chart_data(Color::new(0.2, 0.5, 1.0, 0.9), width, height);
+            brush.overlay.update_data(&self.device, &self.queue, &data);
+            self.window.request_redraw();
+        }
+    }
+
+    /// Select the points inside the released brush and publish them
+    fn finish_brush(&mut self) {
+        let Some(brush) = self.brush.take() else {
+            return;
+        };
+        let camera = *self.pan_zoom.camera();
+        let viewport = [self.config.width as f32, self.config.height as f32];
+        for series in self.selectable_series() {
+            let positions = self.points[&series].positions.iter().copied();
+            let selection = brush.shape.select_positions(positions, &camera, viewport);
+            self.events.publish(&FigureEvent::SelectionChanged { subplots: vec![series], selection });
+        }
+        self.window.request_redraw();
+    }
+
+    /// Drop any brush being dragged and publish empty selections
+    fn clear_selection(&mut self) {
+        if self.brush.take().is_some() {
+            self.window.request_redraw();
+        }
+        for series in self.selectable_series() {
+            let selection = Selection::new(Vec::new());
+            self.events.publish(&FigureEvent::SelectionChanged { subplots: vec![series], selection });
+        }
+    }
+
+    /// Scatter series shown in the legend, back to front
+    fn selectable_series(&self) -> Vec<usize> {
+        self.layers
+            .iter()
+            .filter(|layer| layer.kind == ChartKind::Scatter && self.points.contains_key(&layer.series))
+            .filter(|layer| self.legend.as_ref().is_none_or(|legend| legend.legend.is_visible(layer.series)))
+            .map(|layer| layer.series)
+            .collect()
+    }
+
+    /// Leave series hidden in the legend out of picking
+    fn sync_picker_visibility(&mut self) {
+        if let (Some(picker), Some(legend)) = (&mut self.picker, &self.legend) {
+            for layer in &self.layers {
+                picker.set_visible(layer.series, legend.legend.is_visible(layer.series));
+            }
+        }
+    }
+
+    /// Re-upload the series whose legend alpha changed, faded to it
+    ///
+    /// Hidden series aren't drawn, so they keep whatever they last held.
+    fn apply_series_alpha(&mut self) {
+        let Some(legend) = &self.legend else {
+            return;
+        };
+        for layer in &mut self.layers {
+            let alpha = legend.legend.series_alpha(layer.series);
+            if alpha == 0.0 || alpha == layer.alpha {
+                continue;
+            }
+            layer.alpha = alpha;
+            layer.upload(&self.device, &self.queue);
+        }
+    }
+
+    /// Replace the points of the first series added
+    pub fn set_data(&mut self, chart_data: &ChartData) {
+        self.morph = None;
+        if let Some(layer) = self.layers.iter_mut().find(|layer| layer.series == 0) {
+            layer.data = chart_data.clone();
+            layer.upload(&self.device, &self.queue);
+            if let (ChartKind::Scatter, Some(picker)) = (layer.kind, &mut self.picker) {
+                picker.set_series(&self.device, &self.queue, 0, chart_data);
+                self.points.insert(0, ScatterPoints::new(chart_data));
+            }
+        }
+    }
+
+    /// Show the points appended to the first series' data since it was
+    /// last passed here or to `set_data`, uploading only those
+    ///
+    /// Scatter renderers tell the new points apart by the data's
+    /// `AppendLog`. Data changed any other way since, or keeping a rolling
+    /// window with `set_max_points`, goes through `set_data`.
+    pub fn append_data(&mut self, chart_data: &ChartData) {
+        if chart_data.append_log().is_none() || chart_data.max_points().is_some() {
+            return self.set_data(chart_data);
+        }
+        if let Some(layer) = self.layers.iter_mut().find(|layer| layer.series == 0) {
+            let held = layer.data.vertices.len().min(chart_data.vertices.len());
+            layer.data.vertices.extend_from_slice(&chart_data.vertices[held..]);
+            if layer.alpha < 1.0 {
+                layer.renderer.update_data(&self.device, &self.queue, &faded(&layer.data, layer.alpha));
+            } else {
+                layer.renderer.update_data(&self.device, &self.queue, chart_data);
+            }
+            if let (ChartKind::Scatter, Some(picker)) = (layer.kind, &mut self.picker) {
+                picker.append_series(&self.device, &self.queue, 0, chart_data);
+                match self.points.get_mut(&0) {
+                    Some(points) => points.extend_from(chart_data),
+                    None => {
+                        self.points.insert(0, ScatterPoints::new(chart_data));
+                    }
+                }
+            }
         }
     }
 
     pub fn window(&self) -> &Window {
         &self.window
     }
+
+    /// Draw statistics of the last rendered frame
+    pub fn frame_report(&self) -> Option<&FrameReport> {
+        self.recorder.last_report()
+    }
 }
 
-struct App {
-    chart_data: Option<ChartData>,
+/// A `DataSource` feeding a window, with every point received so far
+struct LiveData {
+    source: Box<dyn DataSource>,
+    x: Vec<f32>,
+    y: Vec<f32>,
+    incoming: Vec<Point2D>,
+    /// Data bounds the vertices are normalized against, with headroom
+    transform: ScatterTransform,
+    /// Appended to as points arrive, rebuilt when they outgrow `transform`
+    data: ChartData,
+}
+
+impl LiveData {
+    fn new(source: Box<dyn DataSource>) -> Self {
+        let empty = (f64::INFINITY, f64::NEG_INFINITY);
+        Self {
+            source,
+            x: Vec::new(),
+            y: Vec::new(),
+            incoming: Vec::new(),
+            transform: ScatterTransform::new(empty, empty, None, None),
+            data: ChartData::new(800.0, 600.0),
+        }
+    }
+
+    /// Pull new points and upload them, or the whole series rescaled to
+    /// fit if they fall outside the current bounds
+    fn update(&mut self, window: &mut RenderWindow) {
+        self.incoming.clear();
+        self.source.poll(&mut self.incoming);
+        if self.incoming.is_empty() {
+            return;
+        }
+
+        let first = self.x.len();
+        self.x.extend(self.incoming.iter().map(|p| p.x));
+        self.y.extend(self.incoming.iter().map(|p| p.y));
+        let bounds = ScatterTransform::fit(&self.x[first..], &self.y[first..], None, None);
+        let (color, size) = (Color::default(), 2.0);
+        let appended = match self.transform.covering(bounds.x_in, bounds.y_in) {
+            Some(transform) => {
+                self.transform = transform;
+                let viewport = window.window().inner_size();
+                self.data = ChartData::new(viewport.width as f32, viewport.height as f32);
+                let appended = self.data.append_scatter(&self.x, &self.y, &self.transform, color, size);
+                window.set_data(&self.data);
+                appended
+            }
+            None => {
+                let appended =
+                    self.data.append_scatter(&self.x[first..], &self.y[first..], &self.transform, color, size);
+                window.append_data(&self.data);
+                appended
+            }
+        };
+        if let Err(e) = appended {
+            log::error!("{}", e);
+        }
+        window.events_mut().publish(&FigureEvent::DataAppended {
+            series: 0,
+            count: self.incoming.len(),
+            total: self.x.len(),
+        });
+    }
+}
+
+/// What a window shows, until the event loop creates it
+struct WindowContent {
+    layers: Vec<SeriesLayer>,
+    size: (f32, f32),
+    legend: Option<Legend>,
+}
+
+/// A window waiting to be opened by `try_run_windows`
+///
+/// # Example
+/// ```no_run
+/// use helion_core::{ChartData, ChartKind, WindowQueue, WindowSpec, try_run_windows};
+///
+/// let queue = WindowQueue::new();
+/// let x: Vec<f32> = (0..100).map(|i| i as f32).collect();
+/// queue.push(WindowSpec::chart(ChartData::from_scatter(&x, &x, None, None, 800.0, 600.0), ChartKind::Scatter, "Points"));
+/// queue.push(WindowSpec::chart(ChartData::from_line(&x, &x, None, 800.0, 600.0), ChartKind::Line, "Line"));
+/// // Both windows are open at once; returns when the last one closes
+/// try_run_windows(&queue).unwrap();
+/// ```
+pub struct WindowSpec {
+    content: WindowContent,
     title: String,
-    window: Option<RenderWindow>,
+    options: RenderOptions,
+    events: EventBus,
+    /// Replaces the default tooltip when set
+    tooltip: Option<Option<Tooltip>>,
+}
+
+impl WindowSpec {
+    /// Window drawing `chart_data` as `kind`
+    pub fn chart(chart_data: ChartData, kind: ChartKind, title: &str) -> Self {
+        let content = WindowContent {
+            size: (chart_data.viewport_width, chart_data.viewport_height),
+            layers: vec![SeriesLayer {
+                series: 0,
+                kind,
+                data: chart_data,
+            }],
+            legend: None,
+        };
+        Self::with_content(content, title)
+    }
+
+    /// Window drawing every series of `figure`, with its legend if any
+    /// series is labeled
+    pub fn figure(figure: &Figure, title: &str) -> Self {
+        let content = WindowContent {
+            layers: figure.layers(),
+            size: (figure.width(), figure.height()),
+            legend: figure.legend(),
+        };
+        Self::with_content(content, title)
+    }
+
+    fn with_content(content: WindowContent, title: &str) -> Self {
+        Self {
+            content,
+            title: title.to_string(),
+            options: RenderOptions::default(),
+            events: EventBus::new(),
+            tooltip: None,
+        }
+    }
+
+    /// Render with the given options instead of the defaults
+    pub fn with_options(mut self, options: RenderOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Publish the window's view changes and other `FigureEvent`s to `events`
+    pub fn with_events(mut self, events: EventBus) -> Self {
+        self.events = events;
+        self
+    }
+
+    /// Show `tooltip` over hovered points instead of the default one, or
+    /// no tooltip for `None`; see `RenderWindow::set_tooltip`
+    pub fn with_tooltip(mut self, tooltip: Option<Tooltip>) -> Self {
+        self.tooltip = Some(tooltip);
+        self
+    }
+}
+
+/// Windows waiting to be opened, shared between threads
+///
+/// Any thread can `push` a window; the thread running `try_run_windows`
+/// opens it, straight away if the event loop is already running. Clones
+/// share the same queue.
+#[derive(Clone, Default)]
+pub struct WindowQueue {
+    inner: Arc<Mutex<QueueState>>,
+}
+
+#[derive(Default)]
+struct QueueState {
+    pending: Vec<WindowSpec>,
+    /// Wakes the event loop while `try_run_windows` runs it
+    proxy: Option<EventLoopProxy<()>>,
+}
+
+impl WindowQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue `spec` to be opened
+    pub fn push(&self, spec: WindowSpec) {
+        let mut state = self.lock();
+        state.pending.push(spec);
+        if let Some(proxy) = &state.proxy {
+            // Fails only once the loop has stopped; the window then waits
+            // for the next run
+            let _ = proxy.send_event(());
+        }
+    }
+
+    /// Number of windows not opened yet
+    pub fn pending(&self) -> usize {
+        self.lock().pending.len()
+    }
+
+    fn take(&self) -> Vec<WindowSpec> {
+        std::mem::take(&mut self.lock().pending)
+    }
+
+    fn set_proxy(&self, proxy: Option<EventLoopProxy<()>>) {
+        self.lock().proxy = proxy;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, QueueState> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// A window the event loop has opened
+struct OpenWindow {
+    window: RenderWindow,
+    live: Option<LiveData>,
+    /// Position in the reports `App` returns
+    index: usize,
+}
+
+/// Opens every window queued and runs until the last one is closed
+struct App {
+    queue: WindowQueue,
+    windows: HashMap<WindowId, OpenWindow>,
+    /// Draw statistics of each window opened, in order, once it closes
+    reports: Vec<Option<FrameReport>>,
+    /// Streams into the first window opened
+    live: Option<LiveData>,
 }
 
 impl App {
-    fn new(chart_data: ChartData, title: String) -> Self {
+    fn new(queue: WindowQueue) -> Self {
         Self {
-            chart_data: Some(chart_data),
-            title,
-            window: None,
+            queue,
+            windows: HashMap::new(),
+            reports: Vec::new(),
+            live: None,
+        }
+    }
+
+    fn open_pending(&mut self, event_loop: &ActiveEventLoop) {
+        for spec in self.queue.take() {
+            let WindowSpec { content, title, options, events, tooltip } = spec;
+            let mut window = pollster::block_on(RenderWindow::with_layers(
+                event_loop,
+                content.layers,
+                content.size,
+                &title,
+                options,
+            ));
+            if let Some(legend) = content.legend {
+                window.set_legend(legend);
+            }
+            window.set_events(events);
+            if let Some(tooltip) = tooltip {
+                window.set_tooltip(tooltip);
+            }
+            let id = window.window().id();
+            let live = self.live.take();
+            self.windows.insert(id, OpenWindow { window, live, index: self.reports.len() });
+            self.reports.push(None);
+        }
+    }
+
+    /// Drop the window, which closes it, keeping its last frame's stats
+    fn close(&mut self, id: WindowId) {
+        if let Some(open) = self.windows.remove(&id) {
+            self.reports[open.index] = open.window.frame_report().cloned();
         }
     }
 }
 
 impl ApplicationHandler for App {
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
-        if self.window.is_none() {
-            if let Some(chart_data) = self.chart_data.take() {
-                self.window = Some(pollster::block_on(RenderWindow::new(
-                    event_loop,
-                    chart_data,
-                    &self.title,
-                )));
-            }
-        }
+        self.open_pending(event_loop);
+    }
+
+    fn user_event(&mut self, event_loop: &ActiveEventLoop, _event: ()) {
+        self.open_pending(event_loop);
     }
 
     fn window_event(
         &mut self,
         event_loop: &ActiveEventLoop,
-        _window_id: WindowId,
+        window_id: WindowId,
         event: WindowEvent,
     ) {
-        if let Some(window) = &mut self.window {
-            match event {
-                WindowEvent::CloseRequested => {
+        let Some(open) = self.windows.get_mut(&window_id) else {
+            return;
+        };
+        let window = &mut open.window;
+        match event {
+            WindowEvent::CloseRequested => {
+                self.close(window_id);
+                if self.windows.is_empty() && self.queue.pending() == 0 {
                     event_loop.exit();
                 }
-                WindowEvent::Resized(physical_size) => {
-                    window.resize(physical_size);
+            }
+            WindowEvent::Resized(physical_size) => {
+                window.resize(physical_size);
+            }
+            WindowEvent::RedrawRequested => {
+                if let Some(live) = &mut open.live {
+                    live.update(window);
                 }
-                WindowEvent::RedrawRequested => {
-                    match window.render() {
-                        Ok(_) => {}
-                        Err(wgpu::SurfaceError::Lost) => {
-                            let size = window.window().inner_size();
-                            window.resize(size);
-                        }
-                        Err(wgpu::SurfaceError::OutOfMemory) => {
-                            event_loop.exit();
-                        }
-                        Err(e) => eprintln!("Render error: {:?}", e),
+                match window.render() {
+                    Ok(_) => {}
+                    Err(wgpu::SurfaceError::Lost) => {
+                        let size = window.window().inner_size();
+                        window.resize(size);
                     }
-                    window.window().request_redraw();
+                    Err(wgpu::SurfaceError::OutOfMemory) => {
+                        event_loop.exit();
+                    }
+                    Err(e) => eprintln!("Render error: {:?}", e),
                 }
-                _ => {}
+                window.window().request_redraw();
+            }
+            other => {
+                window.handle_input(&other);
             }
         }
     }
+
+    fn exiting(&mut self, _event_loop: &ActiveEventLoop) {
+        // Close the windows now: the event loop outlives them and runs
+        // again for the next ones
+        let ids: Vec<WindowId> = self.windows.keys().copied().collect();
+        for id in ids {
+            self.close(id);
+        }
+    }
+}
+
+/// Open a window showing `chart_data` and block until it is closed
+///
+/// # Returns
+/// Draw statistics of the last frame rendered before the window closed
+pub fn run_window(chart_data: ChartData, title: &str) -> Option<FrameReport> {
+    run_window_with_options(chart_data, title, RenderOptions::default())
+}
+
+/// Open a window like `run_window`, rendering with the given options
+/// (background color, MSAA, blend mode, ...)
+pub fn run_window_with_options(chart_data: ChartData, title: &str, options: RenderOptions) -> Option<FrameReport> {
+    run_window_with_kind(chart_data, title, ChartKind::Scatter, options)
+}
+
+/// Open a window like `run_window_with_options`, drawing the data as `kind`
+/// (e.g. `ChartKind::Line` for data from `ChartData::from_line`)
+pub fn run_window_with_kind(
+    chart_data: ChartData,
+    title: &str,
+    kind: ChartKind,
+    options: RenderOptions,
+) -> Option<FrameReport> {
+    run_window_with_events(chart_data, title, kind, options, EventBus::new())
+}
+
+/// Open a window like `run_window_with_kind`, publishing its view changes
+/// and other `FigureEvent`s to `events`
+///
+/// # Panics
+/// If the event loop can't run; see `try_run_window_with_events`
+pub fn run_window_with_events(
+    chart_data: ChartData,
+    title: &str,
+    kind: ChartKind,
+    options: RenderOptions,
+    events: EventBus,
+) -> Option<FrameReport> {
+    try_run_window_with_events(chart_data, title, kind, options, events).expect("Event loop error")
+}
+
+/// `run_window_with_events`, returning an error instead of panicking when
+/// the event loop can't run
+///
+/// Windows can be opened one after another: the event loop is created by
+/// the first and reused by the rest, all on the same thread. On macOS that
+/// has to be the main thread; elsewhere any thread works.
+///
+/// # Returns
+/// Draw statistics of the last frame, or `HelionError::Window` if the event
+/// loop couldn't be created (no display, or an earlier window was shown
+/// from another thread)
+pub fn try_run_window_with_events(
+    chart_data: ChartData,
+    title: &str,
+    kind: ChartKind,
+    options: RenderOptions,
+    events: EventBus,
+) -> Result<Option<FrameReport>, HelionError> {
+    try_run_window(WindowSpec::chart(chart_data, kind, title).with_options(options).with_events(events))
+}
+
+/// Open a window showing every series of `figure` and block until it is
+/// closed
+///
+/// Clicking a legend entry (shown when any series is labeled) hides or
+/// shows that series. View changes and other `FigureEvent`s go to `events`.
+///
+/// # Panics
+/// If the event loop can't run; see `try_run_figure`
+pub fn run_figure(figure: &Figure, title: &str, options: RenderOptions, events: EventBus) -> Option<FrameReport> {
+    try_run_figure(figure, title, options, events).expect("Event loop error")
+}
+
+/// `run_figure`, returning an error instead of panicking when the event
+/// loop can't run, with the same threading rules as
+/// `try_run_window_with_events`
+pub fn try_run_figure(
+    figure: &Figure,
+    title: &str,
+    options: RenderOptions,
+    events: EventBus,
+) -> Result<Option<FrameReport>, HelionError> {
+    try_run_window(WindowSpec::figure(figure, title).with_options(options).with_events(events))
+}
+
+/// Open a window that plots points from `source` as they arrive, e.g. a
+/// `TailSource` following a training log, and block until it is closed
+///
+/// The source is polled every frame and only new points are uploaded. Axes
+/// rescale to fit all points received when some land outside them, leaving
+/// room for the series to keep growing.
+pub fn run_window_with_source(
+    source: impl DataSource + 'static,
+    title: &str,
+    options: RenderOptions,
+) -> Option<FrameReport> {
+    crate::logging::init();
+
+    let queue = WindowQueue::new();
+    queue.push(WindowSpec::chart(ChartData::new(800.0, 600.0), ChartKind::Scatter, title).with_options(options));
+    let mut app = App::new(queue);
+    app.live = Some(LiveData::new(Box::new(source)));
+
+    run_app(&mut app).expect("Event loop error");
+    app.reports.into_iter().next().flatten()
+}
+
+/// Open every window in `queue` at once and block until the last one is
+/// closed, including windows pushed from other threads meanwhile
+///
+/// The threading rules are those of `try_run_window_with_events`: this
+/// thread owns the event loop, so on macOS it has to be the main thread.
+///
+/// # Returns
+/// Draw statistics of each window's last frame, in the order they were
+/// opened; empty if the queue was
+pub fn try_run_windows(queue: &WindowQueue) -> Result<Vec<Option<FrameReport>>, HelionError> {
+    if queue.pending() == 0 {
+        return Ok(Vec::new());
+    }
+    crate::logging::init();
+
+    let mut app = App::new(queue.clone());
+    run_app(&mut app)?;
+    Ok(app.reports)
+}
+
+/// Open the window `spec` describes and block until it is closed, with
+/// the threading rules of `try_run_window_with_events`
+///
+/// # Returns
+/// Draw statistics of the last frame rendered
+pub fn try_run_window(spec: WindowSpec) -> Result<Option<FrameReport>, HelionError> {
+    let queue = WindowQueue::new();
+    queue.push(spec);
+    Ok(try_run_windows(&queue)?.into_iter().next().flatten())
+}
+
+thread_local! {
+    /// winit allows one event loop per process, so every window shown from
+    /// this thread runs on the same one
+    static EVENT_LOOP: RefCell<Option<EventLoop<()>>> = const { RefCell::new(None) };
+}
+
+/// Why creating the event loop failed, for later attempts, which winit
+/// refuses outright
+static CREATE_ERROR: OnceLock<String> = OnceLock::new();
+
+/// Run `app` on this thread's event loop until its window closes, creating
+/// the loop on first use
+fn run_app(app: &mut App) -> Result<(), HelionError> {
+    EVENT_LOOP.with(|slot| {
+        let event_loop = match slot.borrow_mut().take() {
+            Some(event_loop) => event_loop,
+            None => new_event_loop().map_err(|e| match e {
+                // winit counts failed attempts too, so report why the first one failed
+                EventLoopError::RecreationAttempt => HelionError::Window(match CREATE_ERROR.get() {
+                    Some(first) => first.clone(),
+                    None => "windows must be shown from the thread that showed the first one".to_string(),
+                }),
+                e => HelionError::Window(CREATE_ERROR.get_or_init(|| e.to_string()).clone()),
+            })?,
+        };
+        app.queue.set_proxy(Some(event_loop.create_proxy()));
+        let (event_loop, result) = run_until_closed(event_loop, app);
+        app.queue.set_proxy(None);
+        *slot.borrow_mut() = event_loop;
+        result.map_err(|e| HelionError::Window(e.to_string()))
+    })
+}
+
+/// A new event loop, allowed off the main thread where the platform
+/// permits it
+fn new_event_loop() -> Result<EventLoop<()>, EventLoopError> {
+    #[allow(unused_mut)]
+    let mut builder = EventLoop::builder();
+    #[cfg(target_os = "windows")]
+    winit::platform::windows::EventLoopBuilderExtWindows::with_any_thread(&mut builder, true);
+    #[cfg(all(unix, not(target_vendor = "apple"), not(target_os = "android")))]
+    {
+        winit::platform::x11::EventLoopBuilderExtX11::with_any_thread(&mut builder, true);
+        winit::platform::wayland::EventLoopBuilderExtWayland::with_any_thread(&mut builder, true);
+    }
+    builder.build()
+}
+
+/// Run the event loop until `app` exits, handing it back for the next
+/// window
+#[cfg(any(
+    target_os = "windows",
+    target_os = "macos",
+    all(unix, not(target_vendor = "apple"), not(target_os = "android"))
+))]
+fn run_until_closed(
+    mut event_loop: EventLoop<()>,
+    app: &mut App,
+) -> (Option<EventLoop<()>>, Result<(), EventLoopError>) {
+    use winit::platform::run_on_demand::EventLoopExtRunOnDemand;
+    let result = event_loop.run_app_on_demand(app);
+    (Some(event_loop), result)
 }
 
-pub fn run_window(chart_data: ChartData, title: &str) {
-    env_logger::init();
-    
-    let event_loop = EventLoop::new().expect("Failed to create event loop");
-    let mut app = App::new(chart_data, title.to_string());
-    
-    event_loop.run_app(&mut app).expect("Event loop error");
+/// Mobile event loops can't be restarted, so the first window is the only one
+#[cfg(not(any(
+    target_os = "windows",
+    target_os = "macos",
+    all(unix, not(target_vendor = "apple"), not(target_os = "android"))
+)))]
+fn run_until_closed(
+    event_loop: EventLoop<()>,
+    app: &mut App,
+) -> (Option<EventLoop<()>>, Result<(), EventLoopError>) {
+    (None, event_loop.run_app(app))
 }
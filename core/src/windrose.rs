@@ -0,0 +1,228 @@
+use crate::colormap::ColormapLut;
+use crate::data::{ChartData, Color, Point2D};
+use crate::error::HelionError;
+use crate::polygon::{polygon_data, Polygon};
+
+/// Arc segments per wedge, enough for a smooth edge at 8+ sectors
+const ARC_SEGMENTS: usize = 8;
+
+/// Point at compass `bearing` (degrees clockwise from north) and `radius`
+/// from the origin: north is +y, east is +x
+pub fn polar_point(bearing: f32, radius: f32) -> Point2D {
+    let theta = bearing.to_radians();
+    Point2D::new(radius * theta.sin(), radius * theta.cos())
+}
+
+/// Radial stacked histogram of observations binned by direction and
+/// magnitude, as used for wind speed and sound direction
+///
+/// `counts()[level][sector]` holds the observations of magnitude bin
+/// `level` in direction sector `sector`. Sector 0 is centered on north and
+/// sectors go clockwise; each sector's levels are stacked outwards,
+/// lowest magnitudes at the center.
+///
+/// # Example
+/// ```
+/// use helion_core::windrose::Windrose;
+///
+/// let directions = [0.0, 10.0, 90.0, 350.0];
+/// let speeds = [2.0, 7.0, 3.0, 4.0];
+/// let rose = Windrose::compute(&directions, &speeds, 4, &[0.0, 5.0, 10.0]).unwrap();
+/// // North (315..45 degrees) has two calm and one strong observation
+/// assert_eq!(rose.counts()[0], vec![2.0, 1.0, 0.0, 0.0]);
+/// assert_eq!(rose.counts()[1], vec![1.0, 0.0, 0.0, 0.0]);
+/// assert_eq!(rose.sector_totals(), vec![3.0, 1.0, 0.0, 0.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Windrose {
+    sectors: usize,
+    magnitude_edges: Vec<f32>,
+    counts: Vec<Vec<f32>>,
+    /// Fraction of each sector's angle its wedges fill
+    opening: f32,
+    colormap: ColormapLut,
+}
+
+impl Windrose {
+    /// Bin observations `(directions[i], magnitudes[i])`
+    ///
+    /// # Parameters
+    /// * `directions` - Compass bearings in degrees; any value is wrapped
+    ///   into [0, 360)
+    /// * `magnitudes` - e.g. wind speeds, as many as `directions`
+    /// * `sectors` - Number of direction bins, e.g. 16
+    /// * `magnitude_edges` - Increasing edges of the stacked levels, n + 1
+    ///   for n levels; the last level includes its right edge
+    ///
+    /// Observations with a NaN or infinite value, or a magnitude outside
+    /// the edges, are left out.
+    ///
+    /// # Returns
+    /// `HelionError::ShapeMismatch` if the inputs differ in length, or
+    /// `HelionError::InvalidOption` for no sectors or fewer than two edges
+    pub fn compute(
+        directions: &[f32],
+        magnitudes: &[f32],
+        sectors: usize,
+        magnitude_edges: &[f32],
+    ) -> Result<Self, HelionError> {
+        if directions.len() != magnitudes.len() {
+            return Err(HelionError::ShapeMismatch {
+                what: "windrose magnitudes, one per direction".to_string(),
+                expected: directions.len(),
+                actual: magnitudes.len(),
+            });
+        }
+        if sectors == 0 {
+            return Err(HelionError::InvalidOption("A windrose needs at least one sector".to_string()));
+        }
+        if magnitude_edges.len() < 2 || !magnitude_edges.windows(2).all(|w| w[0] < w[1]) {
+            return Err(HelionError::InvalidOption(
+                "Windrose magnitude edges must be at least two increasing values".to_string(),
+            ));
+        }
+
+        let levels = magnitude_edges.len() - 1;
+        let sector_width = 360.0 / sectors as f32;
+        let mut counts = vec![vec![0.0; sectors]; levels];
+        for (&direction, &magnitude) in directions.iter().zip(magnitudes) {
+            if !direction.is_finite() {
+                continue;
+            }
+            let Some(level) = level_of(magnitude, magnitude_edges) else {
+                continue;
+            };
+            // Shift by half a sector so sector 0 is centered on north
+            let bearing = (direction + sector_width / 2.0).rem_euclid(360.0);
+            let sector = ((bearing / sector_width) as usize).min(sectors - 1);
+            counts[level][sector] += 1.0;
+        }
+
+        Ok(Self {
+            sectors,
+            magnitude_edges: magnitude_edges.to_vec(),
+            counts,
+            opening: 0.9,
+            colormap: ColormapLut::default(),
+        })
+    }
+
+    /// Fraction of each sector's angle its wedges fill, in (0, 1]; 0.9 by
+    /// default, leaving a small gap between neighbours
+    pub fn with_opening(mut self, opening: f32) -> Self {
+        self.opening = opening.clamp(f32::EPSILON, 1.0);
+        self
+    }
+
+    /// Colormap the levels are colored from, lowest level at its start
+    pub fn with_colormap(mut self, colormap: ColormapLut) -> Self {
+        self.colormap = colormap;
+        self
+    }
+
+    /// Scale the counts to percentages of all observations binned
+    pub fn normalized(mut self) -> Self {
+        let total: f32 = self.counts.iter().flatten().sum();
+        if total > 0.0 {
+            for count in self.counts.iter_mut().flatten() {
+                *count *= 100.0 / total;
+            }
+        }
+        self
+    }
+
+    pub fn sectors(&self) -> usize {
+        self.sectors
+    }
+
+    pub fn magnitude_edges(&self) -> &[f32] {
+        &self.magnitude_edges
+    }
+
+    /// Per level, per sector values
+    pub fn counts(&self) -> &[Vec<f32>] {
+        &self.counts
+    }
+
+    /// Bearing at the middle of `sector`, in degrees
+    pub fn sector_center(&self, sector: usize) -> f32 {
+        sector as f32 * 360.0 / self.sectors as f32
+    }
+
+    /// Sum over all levels of each sector: the length of its stack
+    pub fn sector_totals(&self) -> Vec<f32> {
+        (0..self.sectors).map(|s| self.counts.iter().map(|level| level[s]).sum()).collect()
+    }
+
+    /// Fill color of each level
+    pub fn level_colors(&self) -> Vec<Color> {
+        let top = self.counts.len().saturating_sub(1) as f32;
+        (0..self.counts.len())
+            .map(|level| {
+                let [r, g, b, a] = self.colormap.sample(level as f32, 0.0, top);
+                Color::new(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, a as f32 / 255.0)
+            })
+            .collect()
+    }
+
+    /// One wedge per non-empty level of each sector, scaled so the longest
+    /// stack reaches radius 1, with the level each belongs to
+    pub fn wedges(&self) -> Vec<(Polygon, usize)> {
+        let longest = self.sector_totals().into_iter().fold(0.0, f32::max);
+        if longest <= 0.0 {
+            return Vec::new();
+        }
+        let half_angle = 180.0 / self.sectors as f32 * self.opening;
+        let mut wedges = Vec::new();
+        for sector in 0..self.sectors {
+            let center = self.sector_center(sector);
+            let mut inner = 0.0;
+            for (level, counts) in self.counts.iter().enumerate() {
+                if counts[sector] <= 0.0 {
+                    continue;
+                }
+                let outer = inner + counts[sector] / longest;
+                wedges.push((wedge(center - half_angle, center + half_angle, inner, outer, ARC_SEGMENTS), level));
+                inner = outer;
+            }
+        }
+        wedges
+    }
+
+    /// Stacked wedges for `PolygonRenderer` in a `width` x `height`
+    /// viewport, as a circle centered in the shorter dimension
+    pub fn chart_data(&self, width: f32, height: f32) -> ChartData {
+        let (polygons, levels): (Vec<Polygon>, Vec<usize>) = self.wedges().into_iter().unzip();
+        let level_colors = self.level_colors();
+        let colors: Vec<Color> = levels.iter().map(|&level| level_colors[level]).collect();
+        let (x_half, y_half) = match width >= height {
+            true => (height / width.max(1.0), 1.0),
+            false => (1.0, width / height.max(1.0)),
+        };
+        let unit = ((-1.0, 1.0), (-1.0, 1.0));
+        polygon_data(&polygons, &colors, unit, (-x_half, x_half), (-y_half, y_half), width, height)
+    }
+}
+
+/// Level of `magnitude` between increasing `edges`, the last one inclusive
+fn level_of(magnitude: f32, edges: &[f32]) -> Option<usize> {
+    let last = *edges.last()?;
+    if !magnitude.is_finite() || magnitude < edges[0] || magnitude > last {
+        return None;
+    }
+    let above = edges.partition_point(|&edge| edge <= magnitude);
+    Some(above.clamp(1, edges.len() - 1) - 1)
+}
+
+/// Annular wedge between bearings `from` and `to` and radii `inner` and
+/// `outer`, each arc made of `segments` straight steps; a triangle-fan
+/// shaped slice when `inner` is 0
+pub(crate) fn wedge(from: f32, to: f32, inner: f32, outer: f32, segments: usize) -> Polygon {
+    let arc = |radius: f32| (0..=segments).map(move |i| polar_point(from + (to - from) * i as f32 / segments as f32, radius));
+    let mut ring: Vec<Point2D> = arc(outer).collect();
+    match inner > 0.0 {
+        true => ring.extend(arc(inner).collect::<Vec<_>>().into_iter().rev()),
+        false => ring.push(Point2D::new(0.0, 0.0)),
+    }
+    Polygon::new(ring)
+}
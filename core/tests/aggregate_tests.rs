@@ -0,0 +1,71 @@
+use helion_core::aggregate::{aggregate_counts, BinGrid, Rebinner};
+use helion_core::compute::BinParams;
+use helion_core::transition::ViewRange;
+
+#[test]
+fn test_grid_for_view() {
+    let view = ViewRange::new((0.0, 10.0), (0.0, 5.0));
+    let grid = BinGrid::for_view(&view, 800.0, 400.0, 4.0);
+    assert_eq!((grid.nx, grid.ny), (200, 100));
+    assert_eq!(grid.len(), 20_000);
+    assert_eq!(grid.cell_size(), (0.05, 0.05));
+}
+
+#[test]
+fn test_cell_index_half_open() {
+    let grid = BinGrid::new((0.0, 4.0), (0.0, 2.0), 4, 2);
+    assert_eq!(grid.cell_index(0.0, 0.0), Some(0));
+    assert_eq!(grid.cell_index(3.5, 1.5), Some(7));
+    assert_eq!(grid.cell_index(4.0, 1.0), None);
+    assert_eq!(grid.cell_index(-0.1, 1.0), None);
+}
+
+#[test]
+fn test_aggregate_counts_only_visible_points() {
+    let grid = BinGrid::new((0.0, 2.0), (0.0, 2.0), 2, 2);
+    let x = [0.5, 0.5, 1.5, 1.5, 5.0];
+    let y = [0.5, 0.5, 0.5, 1.5, 5.0];
+    assert_eq!(aggregate_counts(&x, &y, &grid), vec![2, 1, 0, 1]);
+}
+
+#[test]
+fn test_zoom_reveals_detail() {
+    // Two clusters that share a cell at full zoom separate when zoomed in
+    let x = [10.0, 10.0, 11.0, 11.0];
+    let y = [10.0, 10.0, 10.0, 10.0];
+    let mut rebinner = Rebinner::new(100.0);
+
+    let full = rebinner.update(&ViewRange::new((0.0, 100.0), (0.0, 100.0)), 400.0, 400.0).unwrap();
+    assert_eq!(aggregate_counts(&x, &y, &full).iter().filter(|&&c| c > 0).count(), 1);
+
+    let zoomed = rebinner.update(&ViewRange::new((9.0, 13.0), (9.0, 13.0)), 400.0, 400.0).unwrap();
+    assert_eq!(aggregate_counts(&x, &y, &zoomed).iter().filter(|&&c| c > 0).count(), 2);
+}
+
+#[test]
+fn test_rebinner_tolerance_and_resolution() {
+    let mut rebinner = Rebinner::new(10.0);
+    let view = ViewRange::new((0.0, 100.0), (0.0, 100.0));
+    rebinner.update(&view, 100.0, 100.0);
+
+    // Sub-cell pan (cell = 10 units, tolerance = half a cell) is ignored
+    let nudged = ViewRange::new((2.0, 102.0), (0.0, 100.0));
+    assert!(rebinner.update(&nudged, 100.0, 100.0).is_none());
+
+    // Resize changes resolution
+    assert!(rebinner.update(&view, 200.0, 100.0).is_some());
+
+    rebinner.invalidate();
+    assert!(rebinner.current().is_none());
+    assert!(rebinner.update(&view, 200.0, 100.0).is_some());
+}
+
+#[test]
+fn test_bin_params_layout() {
+    assert_eq!(std::mem::size_of::<BinParams>(), 32);
+    let params = BinParams::new(&BinGrid::new((-1.0, 1.0), (0.0, 4.0), 4, 2), 10);
+    assert_eq!(params.origin, [-1.0, 0.0]);
+    assert_eq!(params.inv_cell, [2.0, 0.5]);
+    assert_eq!(params.dims, [4, 2]);
+    assert_eq!(params.count, 10);
+}
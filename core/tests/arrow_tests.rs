@@ -0,0 +1,199 @@
+#![cfg(feature = "arrow")]
+
+use arrow_array::{
+    ArrayRef, Float32Array, Float64Array, Int32Array, RecordBatch, StringArray, TimestampMillisecondArray,
+    TimestampNanosecondArray,
+};
+use arrow_ipc::writer::StreamWriter;
+use arrow_schema::{DataType, Field, Schema, TimeUnit};
+use helion_core::ingest::arrow::ArrowStreamSource;
+use helion_core::ingest::source::{DataSource, SourceState};
+use helion_core::{ChartData, Point2D};
+use std::io::Cursor;
+use std::net::TcpListener;
+use std::sync::Arc;
+
+fn schema() -> Arc<Schema> {
+    Arc::new(Schema::new(vec![
+        Field::new("step", DataType::Int32, false),
+        Field::new("loss", DataType::Float64, true),
+        Field::new("label", DataType::Utf8, false),
+    ]))
+}
+
+fn batch(steps: Vec<i32>, losses: Vec<Option<f64>>) -> RecordBatch {
+    let labels: Vec<&str> = steps.iter().map(|_| "run").collect();
+    RecordBatch::try_new(
+        schema(),
+        vec![
+            Arc::new(Int32Array::from(steps)),
+            Arc::new(Float64Array::from(losses)),
+            Arc::new(StringArray::from(labels)),
+        ],
+    )
+    .unwrap()
+}
+
+fn stream(batches: &[RecordBatch]) -> Vec<u8> {
+    let mut writer = StreamWriter::try_new(Vec::new(), &schema()).unwrap();
+    for batch in batches {
+        writer.write(batch).unwrap();
+    }
+    writer.into_inner().unwrap()
+}
+
+/// Poll until the background reader has delivered everything
+fn drain(source: &mut ArrowStreamSource) -> Vec<(f32, f32)> {
+    let mut out: Vec<Point2D> = Vec::new();
+    while source.poll(&mut out) == SourceState::Open {
+        std::thread::yield_now();
+    }
+    out.iter().map(|p| (p.x, p.y)).collect()
+}
+
+#[test]
+fn test_stream_batches_become_points() {
+    let bytes = stream(&[
+        batch(vec![1, 2], vec![Some(0.5), Some(0.25)]),
+        batch(vec![3, 4], vec![None, Some(0.125)]),
+    ]);
+    let mut source = ArrowStreamSource::from_reader(Cursor::new(bytes), Some("step"), "loss").unwrap();
+
+    // The null loss is skipped
+    assert_eq!(drain(&mut source), vec![(1.0, 0.5), (2.0, 0.25), (4.0, 0.125)]);
+    assert_eq!(source.error(), None);
+}
+
+#[test]
+fn test_row_number_as_x() {
+    let bytes = stream(&[batch(vec![7, 8], vec![Some(1.0), Some(2.0)]), batch(vec![9], vec![Some(3.0)])]);
+    let mut source = ArrowStreamSource::from_reader(Cursor::new(bytes), None, "step").unwrap();
+
+    assert_eq!(drain(&mut source), vec![(0.0, 7.0), (1.0, 8.0), (2.0, 9.0)]);
+}
+
+#[test]
+fn test_timestamp_columns() {
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "t",
+        DataType::Timestamp(TimeUnit::Millisecond, None),
+        false,
+    )]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(TimestampMillisecondArray::from(vec![1000, 2000]))]).unwrap();
+    let mut writer = StreamWriter::try_new(Vec::new(), &schema).unwrap();
+    writer.write(&batch).unwrap();
+    let bytes = writer.into_inner().unwrap();
+
+    let mut source = ArrowStreamSource::from_reader(Cursor::new(bytes), Some("t"), "t").unwrap();
+    assert_eq!(drain(&mut source), vec![(1000.0, 1000.0), (2000.0, 2000.0)]);
+}
+
+#[test]
+fn test_bad_columns_are_rejected() {
+    let bytes = stream(&[]);
+    assert!(ArrowStreamSource::from_reader(Cursor::new(bytes.clone()), None, "missing").is_err());
+    assert!(ArrowStreamSource::from_reader(Cursor::new(bytes.clone()), None, "label").is_err());
+    assert!(ArrowStreamSource::from_reader(Cursor::new(b"garbage".to_vec()), None, "loss").is_err());
+}
+
+#[test]
+fn test_truncated_stream_reports_error() {
+    let mut bytes = stream(&[batch(vec![1, 2, 3], vec![Some(1.0), Some(2.0), Some(3.0)])]);
+    let schema_only = stream(&[]).len() - 8;
+    bytes.truncate(schema_only + 20);
+
+    let mut source = ArrowStreamSource::from_reader(Cursor::new(bytes), None, "loss").unwrap();
+    assert!(drain(&mut source).is_empty());
+    assert!(source.error().is_some());
+}
+
+#[test]
+fn test_tcp_stream() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = std::thread::spawn(move || {
+        let (socket, _) = listener.accept().unwrap();
+        let mut writer = StreamWriter::try_new(socket, &schema()).unwrap();
+        for step in 0..5 {
+            writer.write(&batch(vec![step], vec![Some(step as f64 * 10.0)])).unwrap();
+        }
+        writer.finish().unwrap();
+    });
+
+    let mut source = ArrowStreamSource::connect(addr, Some("step"), "loss").unwrap();
+    let points = drain(&mut source);
+    server.join().unwrap();
+
+    assert_eq!(points.len(), 5);
+    assert_eq!(points[4], (4.0, 40.0));
+}
+
+fn positions(data: &ChartData) -> Vec<[f32; 2]> {
+    data.vertices.iter().map(|v| v.position).collect()
+}
+
+#[test]
+fn test_from_arrow_float_columns_match_slices() {
+    let (x, y) = (vec![0.0f32, 1.0, 4.0], vec![2.0f32, -1.0, 0.5]);
+    let batch = RecordBatch::try_from_iter([
+        ("x", Arc::new(Float32Array::from(x.clone())) as ArrayRef),
+        ("y", Arc::new(Float32Array::from(y.clone())) as ArrayRef),
+    ])
+    .unwrap();
+    let data = ChartData::from_arrow(&batch, "x", "y", None, Some(4.0), 800.0, 600.0).unwrap();
+    let expected = ChartData::from_scatter(&x, &y, None, Some(4.0), 800.0, 600.0);
+    assert_eq!(positions(&data), positions(&expected));
+    assert_eq!(data.vertices[0].size, 4.0);
+
+    // A Float32 column against a Float64 one is mapped in f64
+    let wide: Vec<f64> = y.iter().map(|&v| v as f64).collect();
+    let batch = RecordBatch::try_from_iter([
+        ("x", Arc::new(Float32Array::from(x.clone())) as ArrayRef),
+        ("y", Arc::new(Float64Array::from(wide.clone())) as ArrayRef),
+    ])
+    .unwrap();
+    let data = ChartData::from_arrow(&batch, "x", "y", None, None, 800.0, 600.0).unwrap();
+    let x64: Vec<f64> = x.iter().map(|&v| v as f64).collect();
+    let expected = ChartData::from_scatter_f64(&x64, &wide, None, None, 800.0, 600.0);
+    assert_eq!(positions(&data), positions(&expected));
+}
+
+#[test]
+fn test_from_arrow_nulls_are_dropped() {
+    let data = ChartData::from_arrow(
+        &batch(vec![1, 2, 3, 4], vec![Some(0.5), None, Some(0.25), Some(0.0)]),
+        "step",
+        "loss",
+        None,
+        None,
+        800.0,
+        600.0,
+    )
+    .unwrap();
+    assert_eq!(data.vertices.len(), 3);
+    assert_eq!(data.dropped(), &[1]);
+    assert_eq!(data.source_index(1), 2);
+    assert_eq!(data.original_point(1), Some([3.0, 0.25]));
+}
+
+#[test]
+fn test_from_arrow_keeps_timestamp_precision() {
+    // A microsecond apart in nanoseconds; as f32 these would be equal
+    let t = vec![1_700_000_000_000_000_000, 1_700_000_000_000_001_000, 1_700_000_000_000_002_000];
+    let batch = RecordBatch::try_from_iter([
+        ("t", Arc::new(TimestampNanosecondArray::from(t)) as ArrayRef),
+        ("v", Arc::new(Float64Array::from(vec![0.0, 1.0, 2.0])) as ArrayRef),
+    ])
+    .unwrap();
+    let data = ChartData::from_arrow(&batch, "t", "v", None, None, 800.0, 600.0).unwrap();
+    assert_eq!(positions(&data), vec![[-1.0, -1.0], [0.0, 0.0], [1.0, 1.0]]);
+}
+
+#[test]
+fn test_from_arrow_bad_columns() {
+    let batch = batch(vec![1], vec![Some(1.0)]);
+    let missing = ChartData::from_arrow(&batch, "step", "missing", None, None, 800.0, 600.0);
+    assert_eq!(missing.err(), Some("Record batch has no column 'missing'".to_string()));
+    let text = ChartData::from_arrow(&batch, "label", "loss", None, None, 800.0, 600.0);
+    assert_eq!(text.err(), Some("Column 'label': type Utf8 can't be plotted".to_string()));
+}
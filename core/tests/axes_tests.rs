@@ -0,0 +1,284 @@
+use helion_core::axes::{
+    axes_lines, compute_ticks, elide, format_ticks, grid_lines, layout_tick_labels, tick_label_sections, AxesOptions, AxesRenderer,
+    AxisGridOptions, GridOptions, GridStyle, LabelRotation, LineDash, TickLabelOptions,
+};
+use helion_core::scale::{Axis, AxisBreak, BrokenAxis};
+use helion_core::data::Color;
+use helion_core::transition::ViewRange;
+
+#[test]
+fn test_nice_major_ticks() {
+    let ticks = compute_ticks(0.0, 100.0, 5, 0);
+    assert_eq!(ticks.step, 20.0);
+    assert_eq!(ticks.major, vec![0.0, 20.0, 40.0, 60.0, 80.0, 100.0]);
+    assert!(ticks.minor.is_empty());
+
+    let ticks = compute_ticks(0.13, 0.97, 5, 0);
+    assert_eq!(ticks.step, 0.2);
+    assert_eq!(ticks.major, vec![0.2, 0.4, 0.6, 0.8]);
+}
+
+#[test]
+fn test_minor_ticks_fall_between_majors() {
+    let ticks = compute_ticks(0.0, 10.0, 2, 5);
+    assert_eq!(ticks.major, vec![0.0, 5.0, 10.0]);
+    assert_eq!(ticks.minor, vec![1.0, 2.0, 3.0, 4.0, 6.0, 7.0, 8.0, 9.0]);
+}
+
+#[test]
+fn test_invalid_range_has_no_ticks() {
+    assert!(compute_ticks(1.0, 1.0, 5, 5).major.is_empty());
+    assert!(compute_ticks(f32::NAN, 1.0, 5, 5).major.is_empty());
+}
+
+#[test]
+fn test_per_axis_enable_flags() {
+    let options = GridOptions {
+        x: AxisGridOptions { tick_target: 2, ..Default::default() },
+        y: AxisGridOptions { major: false, ..Default::default() },
+    };
+    let lines = grid_lines((0.0, 10.0), (0.0, 10.0), &options, 800.0, 600.0);
+
+    // Only vertical x gridlines at 0, 5, 10
+    assert_eq!(lines.len(), 3);
+    assert!(lines.iter().all(|l| l.start[0] == l.end[0]));
+    assert_eq!(lines[1].start, [0.0, -1.0]);
+}
+
+#[test]
+fn test_minor_style_and_dashes() {
+    let solid_minor = GridStyle {
+        color: Color::new(1.0, 0.0, 0.0, 1.0),
+        opacity: 0.5,
+        dash: LineDash::Solid,
+    };
+    let options = GridOptions {
+        x: AxisGridOptions {
+            major: false,
+            minor: true,
+            minor_style: solid_minor,
+            tick_target: 1,
+            minor_subdivisions: 2,
+            ..Default::default()
+        },
+        y: AxisGridOptions {
+            major: true,
+            major_style: GridStyle {
+                dash: LineDash::Dashed { on: 10.0, off: 10.0 },
+                ..GridStyle::major()
+            },
+            tick_target: 1,
+            ..Default::default()
+        },
+    };
+    let lines = grid_lines((0.0, 10.0), (0.0, 10.0), &options, 100.0, 100.0);
+
+    // One minor x line at 5 with opacity applied to alpha
+    let minor: Vec<_> = lines.iter().filter(|l| l.color == [1.0, 0.0, 0.0, 0.5]).collect();
+    assert_eq!(minor.len(), 1);
+    assert_eq!(minor[0].start[0], 0.0);
+
+    // Two y majors (0 and 10), each split into 5 dashes across 100px
+    let dashes: Vec<_> = lines.iter().filter(|l| l.start[1] == l.end[1]).collect();
+    assert_eq!(dashes.len(), 10);
+    assert_eq!(dashes[0].start[0], -1.0);
+    assert_eq!(dashes[0].end[0], -0.8);
+}
+
+#[test]
+fn test_gridline_vertices() {
+    let lines = grid_lines((0.0, 1.0), (0.0, 1.0), &GridOptions::default(), 800.0, 600.0);
+    let [a, b] = lines[0].vertices();
+    assert_eq!(a.position, lines[0].start);
+    assert_eq!(b.position, lines[0].end);
+    assert_eq!(a.color, lines[0].color);
+}
+
+fn strings(texts: &[&str]) -> Vec<String> {
+    texts.iter().map(|t| t.to_string()).collect()
+}
+
+#[test]
+fn test_format_ticks_uses_step_precision() {
+    assert_eq!(format_ticks(&[0.0, 50.0, 100.0], 50.0), strings(&["0", "50", "100"]));
+    assert_eq!(format_ticks(&[-0.05, 0.0, 0.05], 0.05), strings(&["-0.05", "0.00", "0.05"]));
+    // Negative zero from float noise prints as plain zero
+    assert_eq!(format_ticks(&[-0.0], 1.0), strings(&["0"]));
+}
+
+#[test]
+fn test_elide() {
+    assert_eq!(elide("Revenue", 10), "Revenue");
+    assert_eq!(elide("North America", 6), "North…");
+    assert_eq!(elide("Ünïcödé", 3), "Ün…");
+    assert_eq!(elide("abc", 0), "");
+}
+
+#[test]
+fn test_labels_stay_upright_when_they_fit() {
+    let texts = strings(&["0", "10", "20", "30"]);
+    let layout = layout_tick_labels(Axis::X, &[0.0, 100.0, 200.0, 300.0], &texts, &TickLabelOptions::default());
+    assert_eq!((layout.rotation, layout.stride), (0.0, 1));
+    assert_eq!(layout.labels.len(), 4);
+    assert_eq!(layout.labels[2].position, 200.0);
+}
+
+#[test]
+fn test_auto_rotation_avoids_thinning() {
+    let texts = strings(&["January", "February", "March", "April"]);
+    let positions = [0.0, 30.0, 60.0, 90.0];
+    let layout = layout_tick_labels(Axis::X, &positions, &texts, &TickLabelOptions::default());
+    assert_eq!(layout.rotation, 45.0);
+    assert_eq!(layout.stride, 1);
+
+    // Crowded enough that even vertical labels must be thinned
+    let positions = [0.0, 10.0, 20.0, 30.0];
+    let layout = layout_tick_labels(Axis::X, &positions, &texts, &TickLabelOptions::default());
+    assert_eq!(layout.rotation, 90.0);
+    assert_eq!(layout.stride, 2);
+    let kept: Vec<usize> = layout.labels.iter().map(|l| l.index).collect();
+    assert_eq!(kept, vec![0, 2]);
+}
+
+#[test]
+fn test_fixed_rotation_thins_labels() {
+    let options = TickLabelOptions {
+        rotation: LabelRotation::Degrees(0.0),
+        ..Default::default()
+    };
+    // "1000" is 28.8px wide; 20px apart only every other label fits
+    let texts = strings(&["1000", "2000", "3000", "4000", "5000"]);
+    let layout = layout_tick_labels(Axis::X, &[0.0, 20.0, 40.0, 60.0, 80.0], &texts, &options);
+    assert_eq!((layout.rotation, layout.stride), (0.0, 2));
+    assert_eq!(layout.labels.iter().map(|l| l.text.as_str()).collect::<Vec<_>>(), ["1000", "3000", "5000"]);
+}
+
+#[test]
+fn test_y_axis_labels_only_need_their_height() {
+    // Wide labels stacked 20px apart fit on a y axis, upright
+    let texts = strings(&["1,000,000", "2,000,000", "3,000,000"]);
+    let layout = layout_tick_labels(Axis::Y, &[100.0, 80.0, 60.0], &texts, &TickLabelOptions::default());
+    assert_eq!((layout.rotation, layout.stride), (0.0, 1));
+}
+
+#[test]
+fn test_long_category_labels_are_elided() {
+    let options = TickLabelOptions {
+        max_chars: Some(8),
+        ..Default::default()
+    };
+    let texts = strings(&["Electronics and appliances", "Books"]);
+    let layout = layout_tick_labels(Axis::X, &[0.0, 400.0], &texts, &options);
+    assert_eq!(layout.labels[0].text, "Electro…");
+    assert_eq!(layout.labels[0].width, 8.0 * 12.0 * 0.6);
+    assert_eq!(layout.labels[1].text, "Books");
+}
+
+#[test]
+fn test_tick_label_edge_cases() {
+    let options = TickLabelOptions::default();
+    let empty = layout_tick_labels(Axis::X, &[], &[], &options);
+    assert!(empty.labels.is_empty());
+    assert_eq!(empty.stride, 1);
+
+    let single = layout_tick_labels(Axis::X, &[5.0], &strings(&["only"]), &options);
+    assert_eq!(single.labels.len(), 1);
+    assert_eq!(single.stride, 1);
+}
+
+fn no_grid() -> GridOptions {
+    let off = AxisGridOptions { major: false, tick_target: 2, ..Default::default() };
+    GridOptions { x: off.clone(), y: off }
+}
+
+#[test]
+fn test_axes_lines_add_ticks_and_frame_after_grid() {
+    let view = ViewRange::new((0.0, 10.0), (0.0, 10.0));
+    let options = AxesOptions::default().with_grid(GridOptions {
+        x: AxisGridOptions { tick_target: 2, ..Default::default() },
+        y: AxisGridOptions { tick_target: 2, ..Default::default() },
+    });
+    let lines = axes_lines(&view, &options, 200.0, 100.0);
+    let grid = grid_lines(view.x, view.y, &options.grid, 200.0, 100.0);
+
+    // Ticks at 0, 5 and 10 on both axes, then two axis lines and two frame sides
+    assert_eq!(lines.len(), grid.len() + 6 + 4);
+    assert_eq!(&lines[..grid.len()], &grid[..]);
+    let frame = &lines[lines.len() - 4..];
+    assert_eq!(frame[0].start, [-1.0, -0.99]);
+    assert_eq!(frame[1].start, [-0.995, -1.0]);
+}
+
+#[test]
+fn test_tick_marks_point_inward_with_pixel_length() {
+    let view = ViewRange::new((0.0, 10.0), (0.0, 10.0));
+    let mut options = AxesOptions::default().with_grid(no_grid()).with_frame(false);
+    options.axis_lines = false;
+    let lines = axes_lines(&view, &options, 200.0, 100.0);
+
+    assert_eq!(lines.len(), 6);
+    // x = 5 tick: 5 px up from the bottom row (0.02 clip units per pixel)
+    assert_eq!(lines[1].start, [0.0, -0.99]);
+    assert!((lines[1].end[1] - (-0.99 + 0.1)).abs() < 1e-6);
+    // y ticks run right from the left column
+    assert!(lines[3..].iter().all(|l| l.start[0] == -0.995 && l.end[0] > l.start[0]));
+
+    options.minor_tick_length = 2.0;
+    assert_eq!(axes_lines(&view, &options, 200.0, 100.0).len(), 6 + 2 * 8);
+}
+
+#[test]
+fn test_broken_axis_ticks_skip_the_gap_and_mark_it() {
+    // 0-10 and 90-100 on x, with 10-90 collapsed to 2 units
+    let view = ViewRange::new((0.0, 22.0), (0.0, 10.0));
+    let breaks = BrokenAxis::new(vec![AxisBreak::new(10.0, 90.0)], 2.0);
+    let mut grid = no_grid();
+    grid.x.breaks = Some(breaks);
+    let (ticks, labels) = grid.x.labelled_ticks(view.x.0, view.x.1, 0);
+    assert_eq!(ticks.major, [0.0, 10.0, 12.0, 22.0]);
+    assert_eq!(labels, strings(&["0", "10", "90", "100"]));
+
+    let mut options = AxesOptions::default().with_grid(grid.clone()).with_ticks(false).with_frame(false);
+    let lines = axes_lines(&view, &options, 200.0, 100.0);
+    // Two axis lines, then two strokes across the bottom one at the gap (x = 11)
+    assert_eq!(lines.len(), 2 + 2);
+    let center = (lines[2].start[0] + lines[3].end[0]) / 2.0;
+    assert!((center - 0.0).abs() < 1e-6);
+    assert!(lines[2..].iter().all(|l| l.start[1] < -0.99 && l.end[1] > -0.99));
+
+    // The frame gets markers too; a gap scrolled out of view gets none
+    options.frame = true;
+    assert_eq!(axes_lines(&view, &options, 200.0, 100.0).len(), 4 + 4);
+    let view = ViewRange::new((12.0, 22.0), (0.0, 10.0));
+    assert_eq!(axes_lines(&view, &options, 200.0, 100.0).len(), 4);
+}
+
+#[test]
+fn test_axes_lines_for_empty_range_keep_frame() {
+    let view = ViewRange::new((3.0, 3.0), (f32::NAN, 1.0));
+    let lines = axes_lines(&view, &AxesOptions::default(), 100.0, 100.0);
+    assert_eq!(lines.len(), 4);
+}
+
+#[test]
+fn test_axes_renderer_struct_size() {
+    let size = std::mem::size_of::<AxesRenderer>();
+    assert!(size < 1024, "AxesRenderer is unexpectedly large: {} bytes", size);
+}
+
+#[test]
+fn test_tick_label_sections() {
+    let view = ViewRange::new((0.0, 10.0), (0.0, 5.0));
+    let options = AxesOptions::default();
+    let sections = tick_label_sections(&view, &options, 320.0, 200.0);
+    let texts: Vec<&str> = sections.iter().map(|s| s.text.as_str()).collect();
+    // Labels on the edges would be cut off, and the y label for 0 would run
+    // into the x labels
+    assert_eq!(texts, ["2", "4", "6", "8", "1", "2", "3", "4"]);
+    assert_eq!(sections[0].position, [64.0, 200.0 - options.label_inset()]);
+    assert_eq!(sections[4].position, [options.label_inset(), 160.0]);
+
+    assert!(tick_label_sections(&view, &options.clone().with_tick_labels(None), 320.0, 200.0).is_empty());
+    let empty = ViewRange::new((3.0, 3.0), (f32::NAN, 1.0));
+    assert!(tick_label_sections(&empty, &options, 100.0, 100.0).len() <= 2);
+}
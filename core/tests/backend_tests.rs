@@ -0,0 +1,49 @@
+use helion_core::backend::{BackendType, Capabilities, FeaturePath};
+use helion_core::compute::Aggregator;
+
+#[test]
+fn test_webgpu_is_tried_before_webgl2() {
+    assert_eq!(BackendType::ALL, [BackendType::WebGPU, BackendType::WebGL2]);
+}
+
+#[test]
+fn test_backend_types_cover_disjoint_apis() {
+    assert!(BackendType::WebGL2.backends().contains(wgpu::Backends::GL));
+    assert!(BackendType::WebGPU.backends().contains(wgpu::Backends::BROWSER_WEBGPU));
+    assert!(!BackendType::WebGPU.backends().intersects(BackendType::WebGL2.backends()));
+}
+
+#[test]
+fn test_backend_type_names() {
+    assert_eq!(BackendType::WebGPU.to_string(), "WebGPU");
+    assert_eq!(BackendType::WebGL2.name(), "WebGL2");
+}
+
+#[test]
+fn test_webgl2_limits_fall_back_to_cpu() {
+    let caps = Capabilities::from_limits(&wgpu::Limits::downlevel_webgl2_defaults());
+    assert!(caps.is_downlevel());
+    assert!(!caps.compute && !caps.storage_buffers);
+    assert_eq!(caps.aggregation(), FeaturePath::Cpu);
+    assert_eq!(caps.picking(), FeaturePath::Cpu);
+    assert!(caps.fits_texture(2048, 2048));
+    assert!(!caps.fits_texture(4096, 1));
+}
+
+#[test]
+fn test_default_limits_use_gpu_paths() {
+    let caps = Capabilities::from_limits(&wgpu::Limits::default());
+    assert!(!caps.is_downlevel());
+    assert_eq!(caps.aggregation(), FeaturePath::Gpu);
+    assert_eq!(caps.picking(), FeaturePath::Gpu);
+}
+
+#[test]
+fn test_cpu_aggregator_needs_no_device() {
+    let aggregator = Aggregator::cpu();
+    assert_eq!(aggregator.path(), FeaturePath::Cpu);
+    assert_eq!(aggregator.point_count(), 0);
+
+    let filled = Aggregator::Cpu { x: vec![0.5, 3.5], y: vec![0.5, 3.5] };
+    assert_eq!(filled.point_count(), 2);
+}
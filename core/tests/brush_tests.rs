@@ -0,0 +1,106 @@
+use helion_core::brush::{apply_highlight, BrushLink, BrushShape, DataSourceId, Selection};
+use helion_core::camera::Camera2D;
+use helion_core::data::{ChartData, Color};
+
+#[test]
+fn test_selection_is_sorted_and_deduplicated() {
+    let selection = Selection::new(vec![5, 1, 3, 1]);
+    assert_eq!(selection.indices(), &[1, 3, 5]);
+    assert!(selection.contains(3));
+    assert!(!selection.contains(2));
+}
+
+#[test]
+fn test_brush_propagates_to_shared_source_only() {
+    let mut link = BrushLink::new();
+    link.register(0, DataSourceId(7));
+    link.register(1, DataSourceId(7));
+    link.register(2, DataSourceId(8));
+
+    let affected = link.brush(1, Selection::new(vec![0, 2]));
+    assert_eq!(affected, vec![0, 1]);
+    assert_eq!(link.selection_for(0).unwrap().indices(), &[0, 2]);
+    assert!(link.selection_for(2).is_none());
+
+    let affected = link.clear(0);
+    assert_eq!(affected, vec![0, 1]);
+    assert!(link.selection_for(1).is_none());
+}
+
+#[test]
+fn test_brush_on_unregistered_subplot_is_ignored() {
+    let mut link = BrushLink::new();
+    link.register(0, DataSourceId(1));
+    assert!(link.brush(9, Selection::new(vec![1])).is_empty());
+
+    link.unregister(0);
+    assert!(link.brush(0, Selection::new(vec![1])).is_empty());
+}
+
+#[test]
+fn test_apply_highlight() {
+    let mut data = ChartData::from_scatter(&[0.0, 1.0, 2.0], &[0.0, 1.0, 2.0], None, None, 800.0, 600.0);
+    let red = Color::new(1.0, 0.0, 0.0, 1.0);
+
+    // Index 10 is out of bounds and must be ignored
+    apply_highlight(&mut data, &Selection::new(vec![1, 10]), red);
+
+    assert_eq!(data.vertices[1].color, [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(data.vertices[0].color, [0.0, 0.5, 1.0, 1.0]);
+    assert_eq!(data.vertices[2].color, [0.0, 0.5, 1.0, 1.0]);
+}
+
+#[test]
+fn test_rect_brush_accepts_corners_in_any_order() {
+    let mut brush = BrushShape::rect([60.0, 10.0]);
+    assert!(brush.extend_to([10.0, 60.0]));
+    assert!(!brush.extend_to([10.0, 60.0]));
+    assert!(brush.contains(30.0, 30.0));
+    assert!(brush.contains(10.0, 60.0));
+    assert!(!brush.contains(61.0, 30.0));
+}
+
+#[test]
+fn test_lasso_skips_tiny_steps_and_closes() {
+    let mut lasso = BrushShape::lasso([0.0, 0.0]);
+    assert!(!lasso.extend_to([0.5, 0.5]));
+    for point in [[100.0, 0.0], [100.0, 100.0]] {
+        assert!(lasso.extend_to(point));
+    }
+    assert_eq!(lasso.outline().len(), 3);
+    // Inside the triangle, then across its closing edge
+    assert!(lasso.contains(80.0, 20.0));
+    assert!(!lasso.contains(20.0, 80.0));
+}
+
+#[test]
+fn test_selection_follows_the_camera() {
+    // Clip-space points at pixels (25, 25) and (75, 75) of a 100x100 view
+    let positions = [[-0.5, 0.5], [0.5, -0.5]];
+    let mut brush = BrushShape::rect([0.0, 0.0]);
+    brush.extend_to([50.0, 50.0]);
+    let selection = brush.select_positions(positions, &Camera2D::identity(), [100.0, 100.0]);
+    assert_eq!(selection.indices(), &[0]);
+
+    // Panned right by half the view the first point draws at (75, 25) and
+    // the second off screen
+    let mut camera = Camera2D::identity();
+    camera.pan([1.0, 0.0]);
+    assert!(brush.select_positions(positions, &camera, [100.0, 100.0]).is_empty());
+    brush.extend_to([100.0, 100.0]);
+    let selection = brush.select_positions(positions, &camera, [100.0, 100.0]);
+    assert_eq!(selection.indices(), &[0]);
+}
+
+#[test]
+fn test_brush_chart_data_is_triangles() {
+    let mut lasso = BrushShape::lasso([10.0, 10.0]);
+    assert!(lasso.chart_data(Color::new(0.0, 0.0, 1.0, 1.0), 100.0, 100.0).vertices.is_empty());
+    for point in [[90.0, 10.0], [50.0, 90.0]] {
+        lasso.extend_to(point);
+    }
+    let data = lasso.chart_data(Color::new(0.0, 0.0, 1.0, 1.0), 100.0, 100.0);
+    // One fill triangle and three edge quads
+    assert_eq!(data.vertices.len(), 3 + 3 * 6);
+    assert!(data.vertices.iter().all(|v| v.position[0].abs() <= 1.0 && v.position[1].abs() <= 1.0));
+}
@@ -0,0 +1,85 @@
+use helion_core::calendar::CalendarHeatmap;
+use helion_core::colormap::ColormapLut;
+use helion_core::data::Color;
+use helion_core::error::HelionError;
+
+const DAY: f64 = 86400.0;
+/// 2024-01-01, a Monday
+const NEW_YEAR: f64 = 1_704_067_200.0;
+
+#[test]
+fn test_values_summed_by_utc_day() {
+    let timestamps = [NEW_YEAR + 10.0, NEW_YEAR + DAY - 1.0, NEW_YEAR + 2.0 * DAY, f64::NAN, NEW_YEAR];
+    let calendar = CalendarHeatmap::new(&timestamps, &[1.0, 2.0, 4.0, 8.0, f32::NAN]).unwrap();
+    assert_eq!(calendar.values(), [3.0, 0.0, 4.0]);
+    assert_eq!(calendar.timestamp(2), NEW_YEAR + 2.0 * DAY);
+}
+
+#[test]
+fn test_weeks_start_on_sunday_or_monday() {
+    let timestamps: Vec<f64> = (0..14).map(|d| NEW_YEAR + d as f64 * DAY).collect();
+    let calendar = CalendarHeatmap::new(&timestamps, &[1.0; 14]).unwrap();
+    assert_eq!(calendar.cell(0), (0, 1));
+    assert_eq!(calendar.cell(6), (1, 0));
+    assert_eq!(calendar.weeks(), 3);
+
+    let calendar = calendar.with_monday_first(true);
+    assert_eq!(calendar.cell(0), (0, 0));
+    assert_eq!(calendar.cell(13), (1, 6));
+    assert_eq!(calendar.weeks(), 2);
+}
+
+#[test]
+fn test_month_labels() {
+    // 2024-01-20 through 2024-03-10
+    let start = NEW_YEAR + 19.0 * DAY;
+    let calendar = CalendarHeatmap::new(&[start, start + 50.0 * DAY], &[1.0, 1.0]).unwrap();
+    let labels = calendar.month_labels();
+    assert_eq!(labels.iter().map(|l| l.1).collect::<Vec<_>>(), ["Jan", "Feb", "Mar"]);
+    // Saturday the 20th opens the first column; Thursday Feb 1 is in the
+    // week of Sunday Jan 28
+    assert_eq!(labels[0].0, 0);
+    assert_eq!(labels[1].0, 2);
+}
+
+#[test]
+fn test_colors_and_empty_days() {
+    let timestamps = [NEW_YEAR, NEW_YEAR + DAY * 2.0, NEW_YEAR + DAY * 3.0];
+    let gray = Color::new(0.5, 0.5, 0.5, 1.0);
+    let calendar = CalendarHeatmap::new(&timestamps, &[1.0, 5.0, 3.0])
+        .unwrap()
+        .with_colormap(ColormapLut::grayscale())
+        .with_empty_color(gray);
+    let colors = calendar.fill_colors();
+    assert_eq!(colors[0], Color::new(0.0, 0.0, 0.0, 1.0));
+    assert_eq!(colors[1], gray);
+    assert_eq!(colors[2], Color::new(1.0, 1.0, 1.0, 1.0));
+}
+
+#[test]
+fn test_cells_fit_viewport_and_hit_test() {
+    let timestamps: Vec<f64> = (0..366).map(|d| NEW_YEAR + d as f64 * DAY).collect();
+    let calendar = CalendarHeatmap::new(&timestamps, &vec![1.0; 366]).unwrap().with_gap(0.0);
+    assert_eq!(calendar.weeks(), 53);
+
+    let (width, height) = (530.0, 100.0);
+    let rect = calendar.cell_rect(0, width, height);
+    assert_eq!(rect, [0.0, 15.0 + 10.0, 10.0, 10.0]);
+    assert_eq!(calendar.day_at(rect[0] + 5.0, rect[1] + 5.0, width, height), Some(0));
+    // Sunday before the first day is outside the calendar
+    assert_eq!(calendar.day_at(5.0, 20.0, width, height), None);
+    assert_eq!(calendar.day_at(5.0, 5.0, width, height), None);
+
+    let data = calendar.chart_data(width, height);
+    assert_eq!(data.vertices.len(), 366 * 6);
+    assert!(data.vertices.iter().all(|v| v.position[0].abs() <= 1.0 && v.position[1].abs() <= 1.0));
+}
+
+#[test]
+fn test_invalid_calendar() {
+    assert!(matches!(
+        CalendarHeatmap::new(&[NEW_YEAR], &[]),
+        Err(HelionError::ShapeMismatch { expected: 1, actual: 0, .. })
+    ));
+    assert!(matches!(CalendarHeatmap::new(&[f64::NAN], &[1.0]), Err(HelionError::EmptyData)));
+}
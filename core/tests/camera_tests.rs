@@ -0,0 +1,213 @@
+use helion_core::camera::{Camera2D, CameraUniforms, PanZoom};
+use helion_core::transition::ViewRange;
+
+fn assert_close(actual: [f32; 2], expected: [f32; 2]) {
+    assert!(
+        (actual[0] - expected[0]).abs() < 1e-5 && (actual[1] - expected[1]).abs() < 1e-5,
+        "{:?} != {:?}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn test_identity_leaves_positions_alone() {
+    let camera = Camera2D::default();
+    assert_eq!(camera, Camera2D::identity());
+    assert_eq!(camera.apply([0.3, -0.7]), [0.3, -0.7]);
+}
+
+#[test]
+fn test_from_view_maps_view_edges_to_clip_edges() {
+    let data = ViewRange::new((0.0, 10.0), (-5.0, 5.0));
+    let view = ViewRange::new((2.0, 4.0), (0.0, 5.0));
+    let camera = Camera2D::from_view(&data, &view);
+
+    // x = 2 and x = 4 normalize to -0.6 and -0.2 against the data range
+    assert_close(camera.apply([-0.6, 0.0]), [-1.0, -1.0]);
+    assert_close(camera.apply([-0.2, 1.0]), [1.0, 1.0]);
+
+    // Showing the full data range is the identity
+    assert_eq!(Camera2D::from_view(&data, &data), Camera2D::identity());
+}
+
+#[test]
+fn test_from_view_ignores_empty_ranges() {
+    let data = ViewRange::new((0.0, 10.0), (0.0, 10.0));
+    let camera = Camera2D::from_view(&data, &ViewRange::new((3.0, 3.0), (0.0, 5.0)));
+    assert_eq!(camera.scale[0], 1.0);
+    assert_eq!(camera.translate[0], 0.0);
+    assert_eq!(camera.scale[1], 2.0);
+}
+
+#[test]
+fn test_pan_and_zoom_about_anchor() {
+    let mut camera = Camera2D::identity();
+    camera.pan([0.25, -0.5]);
+    assert_close(camera.apply([0.0, 0.0]), [0.25, -0.5]);
+
+    // The anchor stays put while everything else spreads out
+    let anchor = [0.5, 0.5];
+    let before = camera.apply([0.75, 0.0]);
+    camera.zoom_about([2.0, 2.0], anchor);
+    assert_close(camera.apply([0.75, 0.0]), [2.0 * before[0] - anchor[0], 2.0 * before[1] - anchor[1]]);
+
+    // Invalid factors leave the axis unchanged
+    let unchanged = camera;
+    camera.zoom_about([0.0, f32::NAN], [0.0, 0.0]);
+    assert_eq!(camera, unchanged);
+}
+
+#[test]
+fn test_matrix_is_column_major() {
+    let camera = Camera2D {
+        scale: [2.0, 3.0],
+        translate: [0.5, -0.5],
+    };
+    let m = camera.matrix();
+    assert_eq!(m[0], [2.0, 0.0, 0.0, 0.0]);
+    assert_eq!(m[1], [0.0, 3.0, 0.0, 0.0]);
+    assert_eq!(m[3], [0.5, -0.5, 0.0, 1.0]);
+
+    // Multiplying the matrix by (x, y, 0, 1) matches apply()
+    let [x, y] = [0.25, -1.0];
+    let clip = [m[0][0] * x + m[1][0] * y + m[3][0], m[0][1] * x + m[1][1] * y + m[3][1]];
+    assert_close(clip, camera.apply([x, y]));
+}
+
+#[test]
+fn test_camera_uniforms_are_64_bytes() {
+    assert_eq!(std::mem::size_of::<CameraUniforms>(), 64);
+    let uniforms = CameraUniforms::from(&Camera2D::identity());
+    assert_eq!(uniforms.view[2], [0.0, 0.0, 1.0, 0.0]);
+}
+
+#[test]
+fn test_pan_zoom_drag_pans_only_while_dragging() {
+    let mut controller = PanZoom::new(400.0, 200.0);
+    assert!(!controller.cursor_moved(100.0, 100.0));
+    assert!(!controller.cursor_moved(200.0, 100.0));
+    assert_eq!(controller.camera(), &Camera2D::identity());
+
+    controller.set_dragging(true);
+    // 100px right and 50px up is a quarter of the width and half the height
+    assert!(controller.cursor_moved(300.0, 50.0));
+    assert_close(controller.camera().translate, [0.5, 0.5]);
+
+    controller.set_dragging(false);
+    assert!(!controller.cursor_moved(0.0, 0.0));
+    assert_close(controller.camera().translate, [0.5, 0.5]);
+}
+
+#[test]
+fn test_pan_zoom_cursor_leaving_breaks_the_drag() {
+    let mut controller = PanZoom::new(100.0, 100.0);
+    controller.set_dragging(true);
+    controller.cursor_moved(10.0, 10.0);
+    controller.cursor_left();
+    // Re-entering far away must not jump the view
+    assert!(!controller.cursor_moved(90.0, 90.0));
+    assert_eq!(controller.camera(), &Camera2D::identity());
+}
+
+#[test]
+fn test_pan_zoom_scroll_keeps_cursor_point_fixed() {
+    let mut controller = PanZoom::new(200.0, 200.0);
+    // Cursor at clip (0.5, 0.5)
+    controller.cursor_moved(150.0, 50.0);
+    assert!(controller.scroll(2.0));
+    let camera = *controller.camera();
+    assert_close(camera.scale, [1.21, 1.21]);
+    assert_close(camera.apply([0.5, 0.5]), [0.5, 0.5]);
+
+    // Scrolling back out undoes it
+    assert!(controller.scroll(-2.0));
+    assert_close(controller.camera().scale, [1.0, 1.0]);
+    assert!(!controller.scroll(0.0));
+}
+
+#[test]
+fn test_pan_zoom_limits_and_reset() {
+    let mut controller = PanZoom::new(100.0, 100.0);
+    controller.scale_limits = (0.5, 2.0);
+    assert!(controller.scroll(100.0));
+    assert_close(controller.camera().scale, [2.0, 2.0]);
+    // Already at the limit
+    assert!(!controller.scroll(1.0));
+
+    assert!(controller.reset());
+    assert_eq!(controller.camera(), &Camera2D::identity());
+    assert!(!controller.reset());
+}
+
+#[test]
+fn test_pan_zoom_resize_changes_pixel_scale() {
+    let mut controller = PanZoom::new(100.0, 100.0);
+    controller.resize(200.0, 100.0);
+    controller.cursor_moved(0.0, 50.0);
+    controller.set_dragging(true);
+    controller.cursor_moved(50.0, 50.0);
+    assert_close(controller.camera().translate, [0.5, 0.0]);
+}
+
+#[test]
+fn test_pan_zoom_equal_aspect_follows_resize() {
+    // Data laid out for a 400x200 viewport, shown in a square window
+    let mut controller = PanZoom::new(400.0, 400.0).with_equal_aspect(400.0, 200.0);
+    assert_close(controller.camera().scale, [1.0, 0.5]);
+
+    // Back to the original shape, at any size, nothing is scaled
+    assert!(controller.resize(800.0, 400.0));
+    assert_close(controller.camera().scale, [1.0, 1.0]);
+    assert!(!controller.resize(800.0, 400.0));
+
+    // Zooms stack on top, and survive a resize
+    controller.scroll(2.0);
+    assert!(controller.resize(800.0, 200.0));
+    assert_close(controller.camera().scale, [0.605, 1.21]);
+    // Drags still follow the cursor: 200px is half the new width
+    controller.cursor_moved(400.0, 100.0);
+    controller.set_dragging(true);
+    controller.cursor_moved(600.0, 100.0);
+    assert_close(controller.camera().translate, [0.5, 0.0]);
+
+    // Reset drops the zoom and pan but keeps the proportions
+    assert!(controller.reset());
+    assert_eq!(controller.camera(), &Camera2D { scale: [0.5, 1.0], translate: [0.0, 0.0] });
+}
+
+#[test]
+fn test_pan_zoom_equal_aspect_resize_keeps_panned_view() {
+    let mut controller = PanZoom::new(400.0, 400.0).with_equal_aspect(400.0, 400.0);
+    // Drag the data point at clip (-0.5, 0.25) to the center
+    controller.cursor_moved(100.0, 150.0);
+    controller.set_dragging(true);
+    controller.cursor_moved(200.0, 200.0);
+    controller.set_dragging(false);
+    let centered = [-0.5, 0.25];
+    assert_close(controller.camera().apply(centered), [0.0, 0.0]);
+
+    // Twice as wide: the centered point stays centered, and the one a
+    // quarter viewport up and right of it stays 100px up and right
+    assert!(controller.resize(800.0, 400.0));
+    assert_close(controller.camera().apply(centered), [0.0, 0.0]);
+    assert_close(controller.camera().apply([0.0, 0.75]), [0.25, 0.5]);
+}
+
+#[test]
+fn test_pan_zoom_without_equal_aspect_stretches() {
+    let mut controller = PanZoom::new(400.0, 200.0);
+    assert!(!controller.resize(100.0, 300.0));
+    assert_eq!(controller.camera(), &Camera2D::identity());
+}
+
+#[test]
+fn test_visible_range_inverts_from_view() {
+    let data = ViewRange::new((0.0, 100.0), (-5.0, 5.0));
+    let view = ViewRange::new((25.0, 75.0), (0.0, 10.0));
+    let visible = Camera2D::from_view(&data, &view).visible_range(&data);
+    assert_close([visible.x.0, visible.x.1], [25.0, 75.0]);
+    assert_close([visible.y.0, visible.y.1], [0.0, 10.0]);
+
+    assert_eq!(Camera2D::identity().visible_range(&data), data);
+}
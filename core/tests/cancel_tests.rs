@@ -0,0 +1,77 @@
+use helion_core::cancel::{CancelToken, PREPARE_CHUNK};
+use helion_core::error::HelionError;
+use helion_core::ChartData;
+
+#[test]
+fn test_token_is_shared_between_clones() {
+    let token = CancelToken::new();
+    let worker = token.clone();
+    assert!(!worker.is_cancelled());
+    assert!(worker.check().is_ok());
+
+    token.cancel();
+    assert!(worker.is_cancelled());
+    assert_eq!(worker.check(), Err(HelionError::Cancelled));
+}
+
+#[test]
+fn test_cancelable_matches_plain_constructor() {
+    let x: Vec<f32> = (0..1000).map(|i| i as f32).collect();
+    let y: Vec<f32> = (0..1000).map(|i| (i * 7 % 13) as f32).collect();
+
+    let plain = ChartData::from_scatter_with_range(&x, &y, None, Some(3.0), 800.0, 600.0, Some((0.0, 1.0)), None);
+    let cancelable = ChartData::from_scatter_cancelable(
+        &x, &y, None, Some(3.0), 800.0, 600.0, Some((0.0, 1.0)), None,
+        &CancelToken::new(),
+        &mut |_| {},
+    )
+    .unwrap();
+
+    assert_eq!(plain.vertices.len(), cancelable.vertices.len());
+    for (a, b) in plain.vertices.iter().zip(&cancelable.vertices) {
+        assert_eq!(a.position, b.position);
+        assert_eq!(a.size, b.size);
+    }
+}
+
+#[test]
+fn test_progress_is_monotonic_and_completes() {
+    let n = PREPARE_CHUNK * 3 + 10;
+    let x = vec![1.0; n];
+    let mut reports = Vec::new();
+
+    let data = ChartData::from_scatter_cancelable(
+        &x, &x, None, None, 800.0, 600.0, None, None,
+        &CancelToken::new(),
+        &mut |p| reports.push(p),
+    )
+    .unwrap();
+
+    assert_eq!(data.vertices.len(), n);
+    assert!(reports.len() > 2);
+    assert!(reports.windows(2).all(|w| w[0] <= w[1]));
+    assert_eq!(*reports.last().unwrap(), 1.0);
+}
+
+#[test]
+fn test_cancel_from_progress_callback_stops_early() {
+    let n = PREPARE_CHUNK * 4;
+    let x = vec![0.5; n];
+    let token = CancelToken::new();
+    let mut calls = 0;
+
+    let result = ChartData::from_scatter_cancelable(
+        &x, &x, None, None, 800.0, 600.0, None, None,
+        &token,
+        &mut |p| {
+            calls += 1;
+            if p >= 0.5 {
+                token.cancel();
+            }
+        },
+    );
+
+    assert_eq!(result.err(), Some(HelionError::Cancelled));
+    // Stopped right after the bounds pass instead of finishing all chunks
+    assert!(calls < 8);
+}
@@ -0,0 +1,63 @@
+use helion_core::chunked::{chunk_ranges, grown_capacity, max_items_per_chunk, strip_ranges};
+
+#[test]
+fn test_small_data_is_one_chunk() {
+    let per_chunk = max_items_per_chunk(256 << 20, 40, 1);
+    assert_eq!(per_chunk, (256 << 20) / 40);
+    assert_eq!(chunk_ranges(1000, per_chunk as usize), vec![0..1000]);
+}
+
+#[test]
+fn test_buffer_limit_splits_data() {
+    // 1 KB buffers of 40-byte vertices hold 25 points each
+    let per_chunk = max_items_per_chunk(1024, 40, 1) as usize;
+    assert_eq!(per_chunk, 25);
+    let ranges = chunk_ranges(60, per_chunk);
+    assert_eq!(ranges, vec![0..25, 25..50, 50..60]);
+}
+
+#[test]
+fn test_chunks_keep_whole_triangles() {
+    // 25 vertices fit, but chunks must hold a multiple of 3
+    assert_eq!(max_items_per_chunk(1024, 40, 3), 24);
+    assert_eq!(max_items_per_chunk(1024, 40, 2), 24);
+}
+
+#[test]
+fn test_draw_count_capped_at_u32() {
+    // 8-byte vertices in a huge (e.g. 64 GB) buffer still draw at most u32::MAX
+    let per_chunk = max_items_per_chunk(1 << 36, 8, 1);
+    assert_eq!(per_chunk, u32::MAX as u64);
+    // ...rounded down to whole triangles
+    assert_eq!(max_items_per_chunk(1 << 36, 8, 3) % 3, 0);
+}
+
+#[test]
+fn test_oversized_primitive_is_rejected() {
+    assert_eq!(max_items_per_chunk(100, 40, 3), 0);
+    assert!(chunk_ranges(10, 0).is_empty());
+}
+
+#[test]
+fn test_strip_chunks_share_end_vertices() {
+    // Each chunk starts where the last ended, so no segment is dropped
+    assert_eq!(strip_ranges(60, 25), vec![0..25, 24..49, 48..60]);
+    assert_eq!(strip_ranges(25, 25), vec![0..25]);
+    assert_eq!(strip_ranges(1, 25), vec![0..1]);
+    assert!(strip_ranges(0, 25).is_empty());
+    // A chunk must hold at least one segment
+    assert!(strip_ranges(10, 1).is_empty());
+
+    let segments: usize = strip_ranges(1000, 7).iter().map(|r| r.len() - 1).sum();
+    assert_eq!(segments, 999);
+}
+
+#[test]
+fn test_grown_capacity_leaves_room() {
+    assert_eq!(grown_capacity(1, 25), 1);
+    assert_eq!(grown_capacity(17, 25), 25);
+    assert_eq!(grown_capacity(1000, 1 << 20), 1024);
+    assert_eq!(grown_capacity(1024, 1 << 20), 1024);
+    // Never less than what was asked for
+    assert_eq!(grown_capacity(30, 25), 30);
+}
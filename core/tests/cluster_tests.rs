@@ -0,0 +1,86 @@
+use helion_core::cluster::{cluster_chart_data, cluster_points, count_labels, ClusterMode, ClusterOptions};
+use helion_core::data::Color;
+use helion_core::transition::ViewRange;
+
+/// Two tight blobs of 10 points each, 1 data unit apart
+fn blobs() -> (Vec<f32>, Vec<f32>) {
+    let mut x = Vec::new();
+    let mut y = Vec::new();
+    for i in 0..10 {
+        let jitter = i as f32 * 0.01;
+        x.push(10.0 + jitter);
+        y.push(10.0 + jitter);
+        x.push(11.0 + jitter);
+        y.push(10.0 + jitter);
+    }
+    (x, y)
+}
+
+#[test]
+fn test_zoomed_out_merges_zoomed_in_splits() {
+    let (x, y) = blobs();
+    let options = ClusterOptions::default();
+
+    let far = ViewRange::new((0.0, 400.0), (0.0, 400.0));
+    let merged = cluster_points(&x, &y, &far, 400.0, 400.0, &options);
+    assert_eq!(merged.len(), 1);
+    assert_eq!(merged[0].count(), 20);
+
+    let near = ViewRange::new((9.0, 13.0), (9.0, 13.0));
+    let split = cluster_points(&x, &y, &near, 400.0, 400.0, &options);
+    assert_eq!(split.len(), 2);
+    assert!(split.iter().all(|c| c.count() == 10));
+}
+
+#[test]
+fn test_small_groups_show_raw_points() {
+    let x = [1.0, 1.01, 50.0];
+    let y = [1.0, 1.01, 50.0];
+    let view = ViewRange::new((0.0, 100.0), (0.0, 100.0));
+    let options = ClusterOptions { min_points: 3, ..Default::default() };
+
+    let clusters = cluster_points(&x, &y, &view, 400.0, 400.0, &options);
+    assert_eq!(clusters.len(), 3);
+    assert!(clusters.iter().all(|c| c.count() == 1));
+}
+
+#[test]
+fn test_points_outside_view_are_dropped() {
+    let (x, y) = blobs();
+    let view = ViewRange::new((9.0, 10.5), (9.0, 13.0));
+    let clusters = cluster_points(&x, &y, &view, 400.0, 400.0, &ClusterOptions::default());
+    let total: usize = clusters.iter().map(|c| c.count()).sum();
+    assert_eq!(total, 10);
+}
+
+#[test]
+fn test_kmeans_finds_blobs() {
+    let (x, y) = blobs();
+    let view = ViewRange::new((0.0, 20.0), (0.0, 20.0));
+    let options = ClusterOptions {
+        mode: ClusterMode::KMeans { k: 2, iterations: 5 },
+        ..Default::default()
+    };
+
+    let mut clusters = cluster_points(&x, &y, &view, 400.0, 400.0, &options);
+    clusters.sort_by(|a, b| a.x.total_cmp(&b.x));
+    assert_eq!(clusters.len(), 2);
+    assert!((clusters[0].x - 10.045).abs() < 1e-3);
+    assert!((clusters[1].x - 11.045).abs() < 1e-3);
+}
+
+#[test]
+fn test_glyph_size_and_labels() {
+    let (x, y) = blobs();
+    let view = ViewRange::new((0.0, 400.0), (0.0, 400.0));
+    let options = ClusterOptions::default();
+    let mut clusters = cluster_points(&x, &y, &view, 400.0, 400.0, &options);
+    clusters.extend(cluster_points(&[300.0], &[300.0], &view, 400.0, 400.0, &options));
+
+    let data = cluster_chart_data(&clusters, &view, 400.0, 400.0, Color::default(), &options);
+    assert_eq!(data.vertices.len(), 2);
+    assert_eq!(data.vertices[0].size, 4.0 * 20f32.sqrt());
+    assert_eq!(data.vertices[1].size, 4.0);
+
+    assert_eq!(count_labels(&clusters), vec!["20".to_string(), String::new()]);
+}
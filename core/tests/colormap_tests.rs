@@ -0,0 +1,110 @@
+use helion_core::colormap::{
+    colormap_names, register_colormap, register_colormaps_json, ColormapLut, ColormapUniforms, BUILTIN_COLORMAPS, LUT_SIZE,
+};
+use helion_core::data::Color;
+
+#[test]
+fn test_lut_has_256_entries() {
+    assert_eq!(ColormapLut::viridis().colors.len(), LUT_SIZE);
+    assert_eq!(ColormapLut::grayscale().colors.len(), LUT_SIZE);
+}
+
+#[test]
+fn test_lut_endpoints_match_stops() {
+    let lut = ColormapLut::viridis();
+    assert_eq!(lut.colors[0], [0x44, 0x01, 0x54, 255]);
+    assert_eq!(lut.colors[LUT_SIZE - 1], [0xfd, 0xe7, 0x25, 255]);
+}
+
+#[test]
+fn test_lut_interpolates_linearly() {
+    let lut = ColormapLut::grayscale();
+    assert_eq!(lut.colors[0], [0, 0, 0, 255]);
+    assert_eq!(lut.colors[128], [128, 128, 128, 255]);
+    assert!(lut.colors.windows(2).all(|w| w[0][0] <= w[1][0]));
+}
+
+#[test]
+fn test_degenerate_stops() {
+    assert!(ColormapLut::from_stops(&[]).colors.iter().all(|&c| c == [0, 0, 0, 255]));
+    let red = ColormapLut::from_stops(&[Color::new(1.0, 0.0, 0.0, 1.0)]);
+    assert!(red.colors.iter().all(|&c| c == [255, 0, 0, 255]));
+}
+
+#[test]
+fn test_sample_clamps_to_range() {
+    let lut = ColormapLut::grayscale();
+    assert_eq!(lut.sample(-5.0, 0.0, 10.0), lut.colors[0]);
+    assert_eq!(lut.sample(50.0, 0.0, 10.0), lut.colors[LUT_SIZE - 1]);
+    assert_eq!(lut.sample(5.0, 0.0, 10.0)[0], 128);
+    // Zero-width range maps to the middle, like the shader
+    assert_eq!(lut.sample(3.0, 3.0, 3.0)[0], 128);
+}
+
+#[test]
+fn test_colormap_uniforms_layout() {
+    assert_eq!(std::mem::size_of::<ColormapUniforms>(), 16);
+    let uniforms = ColormapUniforms::default();
+    assert_eq!((uniforms.vmin, uniforms.vmax, uniforms.opacity), (0.0, 1.0, 1.0));
+}
+
+#[test]
+fn test_diverging_colormaps() {
+    let coolwarm = ColormapLut::coolwarm();
+    assert_eq!(coolwarm.colors[0], [0x3b, 0x4c, 0xc0, 255]);
+    assert_eq!(coolwarm.colors[LUT_SIZE - 1], [0xb4, 0x04, 0x26, 255]);
+
+    let rd_bu = ColormapLut::rd_bu();
+    assert_eq!(rd_bu.colors[0], [0x67, 0x00, 0x1f, 255]);
+    // Near-white in the middle
+    assert!(rd_bu.colors[LUT_SIZE / 2].iter().all(|&c| c > 240));
+}
+
+#[test]
+fn test_builtin_colormaps_by_name() {
+    assert_eq!(ColormapLut::by_name("viridis"), Some(ColormapLut::viridis()));
+    assert_eq!(ColormapLut::by_name(" CoolWarm "), Some(ColormapLut::coolwarm()));
+    assert_eq!(ColormapLut::by_name("gray"), Some(ColormapLut::grayscale()));
+    assert_eq!(ColormapLut::by_name("viridis_r").unwrap().colors[0], ColormapLut::viridis().colors[LUT_SIZE - 1]);
+    assert!(ColormapLut::by_name("no_such_map").is_none());
+    for name in BUILTIN_COLORMAPS {
+        assert!(ColormapLut::by_name(name).is_some(), "{}", name);
+    }
+}
+
+#[test]
+fn test_register_custom_colormap() {
+    register_colormap("test_fire", &["#000000", "#ff0000", "#ffff00"]).unwrap();
+    let lut = ColormapLut::by_name("TEST_FIRE").unwrap();
+    assert_eq!(lut.colors[0], [0, 0, 0, 255]);
+    assert_eq!(lut.colors[LUT_SIZE - 1], [255, 255, 0, 255]);
+    assert!(colormap_names().contains(&"test_fire".to_string()));
+
+    // Re-registering replaces the previous definition
+    register_colormap("test_fire", &["#ffffff", "#00000080"]).unwrap();
+    assert_eq!(ColormapLut::by_name("test_fire").unwrap().colors[LUT_SIZE - 1], [0, 0, 0, 128]);
+}
+
+#[test]
+fn test_register_rejects_bad_input() {
+    assert!(register_colormap("", &["#000000", "#ffffff"]).is_err());
+    assert!(register_colormap("viridis", &["#000000", "#ffffff"]).is_err());
+    assert!(register_colormap("Gray", &["#000000", "#ffffff"]).is_err());
+    assert!(register_colormap("mine_r", &["#000000", "#ffffff"]).is_err());
+    assert!(register_colormap("test_single", &["#000000"]).is_err());
+    let err = register_colormap("test_bad_hex", &["#000000", "#fff"]).unwrap_err();
+    assert!(err.contains("#fff"), "{}", err);
+    assert!(ColormapLut::by_name("test_bad_hex").is_none());
+}
+
+#[test]
+fn test_register_from_json() {
+    let names = register_colormaps_json(r##"{"test_json_a": ["#000000", "#0000ff"], "test_json_b": ["#ffffff", "#00ff00"]}"##)
+        .unwrap();
+    assert_eq!(names.len(), 2);
+    assert_eq!(ColormapLut::by_name("test_json_a").unwrap().colors[LUT_SIZE - 1], [0, 0, 255, 255]);
+
+    assert!(register_colormaps_json("[]").is_err());
+    assert!(register_colormaps_json(r##"{"test_json_c": "#000000"}"##).is_err());
+    assert!(register_colormaps_json(r#"{"test_json_d": [1, 2]}"#).is_err());
+}
@@ -0,0 +1,98 @@
+use helion_core::colormap::{register_colormap_in, register_colormaps_json, ColormapLut, LUT_SIZE};
+use helion_core::colorspace::ColorSpace;
+use helion_core::data::Color;
+
+fn close(a: [f32; 3], b: [f32; 3], tol: f32) -> bool {
+    a.iter().zip(&b).all(|(x, y)| (x - y).abs() < tol)
+}
+
+#[test]
+fn test_oklab_reference_values() {
+    let white = Color::new(1.0, 1.0, 1.0, 1.0).to_oklab();
+    assert!(close(white, [1.0, 0.0, 0.0], 1e-3), "{:?}", white);
+    assert!(close(Color::new(0.0, 0.0, 0.0, 1.0).to_oklab(), [0.0, 0.0, 0.0], 1e-6));
+    // Published Oklab value for sRGB red
+    let red = Color::new(1.0, 0.0, 0.0, 1.0).to_oklab();
+    assert!(close(red, [0.6279, 0.2249, 0.1258], 1e-3), "{:?}", red);
+}
+
+#[test]
+fn test_lab_reference_values() {
+    assert!(close(Color::new(1.0, 1.0, 1.0, 1.0).to_lab(), [100.0, 0.0, 0.0], 1e-2));
+    let red = Color::new(1.0, 0.0, 0.0, 1.0).to_lab();
+    assert!(close(red, [53.24, 80.09, 67.20], 0.1), "{:?}", red);
+}
+
+#[test]
+fn test_round_trips() {
+    for &(r, g, b) in &[(0.2, 0.4, 0.6), (1.0, 0.5, 0.0), (0.01, 0.02, 0.9), (0.5, 0.5, 0.5)] {
+        let color = Color::new(r, g, b, 0.7);
+        for back in [Color::from_oklab(color.to_oklab(), 0.7), Color::from_lab(color.to_lab(), 0.7)] {
+            assert!(close([back.r, back.g, back.b], [r, g, b], 1e-4), "{:?} -> {:?}", color, back);
+            assert_eq!(back.a, 0.7);
+        }
+    }
+}
+
+#[test]
+fn test_mix_keeps_endpoints_and_blends_alpha_linearly() {
+    let a = Color::new(0.9, 0.1, 0.3, 0.0);
+    let b = Color::new(0.1, 0.6, 0.8, 1.0);
+    for space in [ColorSpace::Srgb, ColorSpace::Oklab, ColorSpace::Lab] {
+        let start = space.mix(a, b, 0.0);
+        let end = space.mix(a, b, 1.0);
+        assert!(close([start.r, start.g, start.b], [a.r, a.g, a.b], 1e-4), "{:?}", space);
+        assert!(close([end.r, end.g, end.b], [b.r, b.g, b.b], 1e-4), "{:?}", space);
+        assert_eq!(space.mix(a, b, 0.25).a, 0.25);
+    }
+}
+
+#[test]
+fn test_perceptual_lightness_is_even() {
+    let black = Color::new(0.0, 0.0, 0.0, 1.0);
+    let white = Color::new(1.0, 1.0, 1.0, 1.0);
+    for i in 0..=10 {
+        let t = i as f32 / 10.0;
+        let lightness = ColorSpace::Oklab.mix(black, white, t).to_oklab()[0];
+        assert!((lightness - t).abs() < 1e-3, "t={} L={}", t, lightness);
+    }
+    // Averaging sRGB values overshoots perceived mid-gray
+    assert!(ColorSpace::Srgb.mix(black, white, 0.5).to_oklab()[0] > 0.55);
+}
+
+#[test]
+fn test_space_names() {
+    assert_eq!(ColorSpace::from_name("OKLab").unwrap(), ColorSpace::Oklab);
+    assert_eq!(ColorSpace::from_name("rgb").unwrap(), ColorSpace::Srgb);
+    assert_eq!(ColorSpace::from_name("cielab").unwrap(), ColorSpace::Lab);
+    assert!(ColorSpace::from_name("hsv").is_err());
+    assert_eq!(ColorSpace::default(), ColorSpace::Oklab);
+}
+
+#[test]
+fn test_registered_colormaps_blend_perceptually() {
+    let stops = ["#000000", "#ffffff"];
+    register_colormap_in("test_space_rgb", &stops, ColorSpace::Srgb).unwrap();
+    helion_core::register_colormap("test_space_default", &stops).unwrap();
+
+    let rgb = ColormapLut::by_name("test_space_rgb").unwrap();
+    let oklab = ColormapLut::by_name("test_space_default").unwrap();
+    assert_eq!(rgb.colors[0], oklab.colors[0]);
+    assert_eq!(rgb.colors[LUT_SIZE - 1], oklab.colors[LUT_SIZE - 1]);
+    assert_eq!(rgb.colors[128][0], 128);
+    assert!(oklab.colors[128][0] < 110, "{:?}", oklab.colors[128]);
+
+    // Built-ins keep their published RGB tables
+    assert_eq!(ColormapLut::grayscale().colors[128][0], 128);
+}
+
+#[test]
+fn test_json_colormap_space() {
+    register_colormaps_json(r##"{"test_space_json": {"colors": ["#000000", "#ffffff"], "space": "srgb"}}"##).unwrap();
+    assert_eq!(ColormapLut::by_name("test_space_json").unwrap().colors[128][0], 128);
+
+    let err = register_colormaps_json(r##"{"test_space_bad": {"colors": ["#000000", "#ffffff"], "space": "hsv"}}"##)
+        .unwrap_err();
+    assert!(err.contains("hsv"), "{}", err);
+    assert!(register_colormaps_json(r##"{"test_space_nocolors": {"space": "lab"}}"##).is_err());
+}
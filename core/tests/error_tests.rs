@@ -0,0 +1,60 @@
+use helion_core::backend::BackendType;
+use helion_core::error::HelionError;
+use helion_core::renderer::RenderOptions;
+
+#[test]
+fn test_validate_reports_invalid_option() {
+    let err = RenderOptions::default().with_point_size(-1.0).validate().unwrap_err();
+    assert_eq!(err, HelionError::InvalidOption("point_size must be positive, got -1".to_string()));
+}
+
+#[test]
+fn test_no_backend_lists_each_failure() {
+    let err = HelionError::NoBackend(vec![
+        (BackendType::WebGPU, HelionError::AdapterNotFound),
+        (BackendType::WebGL2, HelionError::DeviceRequest("limits exceeded".to_string())),
+    ]);
+    assert_eq!(
+        err.to_string(),
+        "No GPU backend available: WebGPU failed: Failed to find GPU adapter; \
+         WebGL2 failed: Failed to create device: limits exceeded"
+    );
+}
+
+#[test]
+fn test_shape_mismatch_message() {
+    let err = HelionError::ShapeMismatch {
+        what: "y values".to_string(),
+        expected: 10,
+        actual: 9,
+    };
+    assert_eq!(err.to_string(), "y values: expected 10, got 9");
+}
+
+#[test]
+fn test_surface_errors_convert() {
+    let err = HelionError::from(wgpu::SurfaceError::Lost);
+    assert!(matches!(err, HelionError::Surface(_)));
+    assert!(err.to_string().contains("current texture"), "{}", err);
+}
+
+#[test]
+fn test_window_error_message() {
+    let err = HelionError::Window("windows must be shown from the thread that showed the first one".to_string());
+    assert_eq!(
+        err.to_string(),
+        "Failed to open window: windows must be shown from the thread that showed the first one"
+    );
+}
+
+#[test]
+fn test_serve_error_message() {
+    let err = HelionError::Serve("can't listen on port 80: permission denied".to_string());
+    assert_eq!(err.to_string(), "Failed to serve frames: can't listen on port 80: permission denied");
+}
+
+#[test]
+fn test_is_std_error() {
+    let err: Box<dyn std::error::Error> = Box::new(HelionError::EmptyData);
+    assert_eq!(err.to_string(), "No data to draw");
+}
@@ -0,0 +1,94 @@
+use helion_core::brush::Selection;
+use helion_core::camera::Camera2D;
+use helion_core::events::{EventBus, FigureEvent, FigureEventKind};
+use std::sync::{Arc, Mutex};
+
+fn recorder(bus: &mut EventBus, kind: Option<FigureEventKind>) -> Arc<Mutex<Vec<FigureEvent>>> {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&seen);
+    let handler = move |event: &FigureEvent| sink.lock().unwrap().push(event.clone());
+    match kind {
+        Some(kind) => bus.subscribe_to(kind, handler),
+        None => bus.subscribe(handler),
+    };
+    seen
+}
+
+#[test]
+fn test_publish_reaches_matching_subscribers() {
+    let mut bus = EventBus::new();
+    let all = recorder(&mut bus, None);
+    let views = recorder(&mut bus, Some(FigureEventKind::ViewChanged));
+
+    let zoomed = FigureEvent::ViewChanged {
+        camera: Camera2D {
+            scale: [2.0, 2.0],
+            translate: [0.0, 0.0],
+        },
+    };
+    assert_eq!(bus.publish(&zoomed), 2);
+    assert_eq!(bus.publish(&FigureEvent::PointPicked { series: 0, index: 7 }), 1);
+
+    assert_eq!(all.lock().unwrap().len(), 2);
+    assert_eq!(*views.lock().unwrap(), vec![zoomed]);
+}
+
+#[test]
+fn test_unsubscribe() {
+    let mut bus = EventBus::new();
+    assert!(bus.is_empty());
+    let id = bus.subscribe(|_| {});
+    let _other = bus.subscribe(|_| {});
+    assert_eq!(bus.len(), 2);
+
+    assert!(bus.unsubscribe(id));
+    assert!(!bus.unsubscribe(id));
+    assert_eq!(bus.publish(&FigureEvent::PointPicked { series: 0, index: 0 }), 1);
+}
+
+#[test]
+fn test_has_subscribers() {
+    let mut bus = EventBus::new();
+    assert!(!bus.has_subscribers(FigureEventKind::PointHovered));
+    bus.subscribe_to(FigureEventKind::ViewChanged, |_| {});
+    assert!(!bus.has_subscribers(FigureEventKind::PointHovered));
+    assert!(bus.has_subscribers(FigureEventKind::ViewChanged));
+
+    let hovered = recorder(&mut bus, None);
+    assert!(bus.has_subscribers(FigureEventKind::PointHovered));
+    bus.publish(&FigureEvent::PointHovered { point: None });
+    assert_eq!(*hovered.lock().unwrap(), vec![FigureEvent::PointHovered { point: None }]);
+    assert_eq!(FigureEventKind::PointHovered.name(), "point_hovered");
+}
+
+#[test]
+fn test_event_kinds_and_names() {
+    let selection = FigureEvent::SelectionChanged {
+        subplots: vec![0, 1],
+        selection: Selection::new(vec![4, 2]),
+    };
+    assert_eq!(selection.kind(), FigureEventKind::SelectionChanged);
+    assert_eq!(
+        FigureEvent::DataAppended { series: 0, count: 1, total: 1 }.kind().name(),
+        "data_appended"
+    );
+
+    for kind in FigureEventKind::ALL {
+        assert_eq!(FigureEventKind::from_name(kind.name()), Ok(kind));
+    }
+    let err = FigureEventKind::from_name("zoomed").unwrap_err();
+    assert!(err.contains("Unknown event 'zoomed'"));
+    assert!(err.contains("view_changed"));
+}
+
+#[test]
+fn test_bus_can_move_across_threads() {
+    let mut bus = EventBus::new();
+    let seen = recorder(&mut bus, Some(FigureEventKind::DataAppended));
+    std::thread::spawn(move || {
+        bus.publish(&FigureEvent::DataAppended { series: 0, count: 5, total: 5 });
+    })
+    .join()
+    .unwrap();
+    assert_eq!(seen.lock().unwrap().len(), 1);
+}
@@ -0,0 +1,264 @@
+use helion_core::axes::AxesOptions;
+use helion_core::data::Color;
+use helion_core::error::HelionError;
+use helion_core::figure::{ChartKind, Figure, Series, DEFAULT_COLOR_CYCLE};
+use helion_core::histogram::{BinStrategy, HistogramOptions};
+use helion_core::legend::LegendLocation;
+use helion_core::scale::{AxisBreak, AxisScale, BrokenAxis};
+use helion_core::style::SeriesStyle;
+use helion_core::text::HAlign;
+use helion_core::timeseries::GapThreshold;
+use helion_core::transition::ViewRange;
+
+#[test]
+fn test_series_share_one_range() {
+    let mut fig = Figure::new(400.0, 300.0);
+    fig.scatter(vec![0.0, 10.0], vec![0.0, 1.0]).unwrap();
+    fig.scatter(vec![5.0, 20.0], vec![-1.0, 0.0]).unwrap();
+    assert_eq!(fig.data_range(), ViewRange::new((0.0, 20.0), (-1.0, 1.0)));
+
+    let second = fig.chart_data(1).unwrap();
+    assert_eq!(second.vertices[0].position, [-0.5, -1.0]);
+    assert_eq!(second.vertices[1].position, [1.0, 0.0]);
+    assert_eq!((second.viewport_width, second.viewport_height), (400.0, 300.0));
+}
+
+#[test]
+fn test_limits_override_data_range() {
+    let mut fig = Figure::new(400.0, 300.0).with_xlim((0.0, 4.0));
+    fig.line(vec![1.0, 2.0], vec![3.0, 3.0]).unwrap();
+    // y has a single value: its range is empty and maps to the center
+    let data = fig.chart_data(0).unwrap();
+    assert_eq!(data.vertices[0].position, [-0.5, 0.0]);
+    assert!(data.vertices.iter().all(|v| v.position.iter().all(|p| p.is_finite())));
+}
+
+#[test]
+fn test_empty_figure_range() {
+    let fig = Figure::new(100.0, 100.0);
+    assert!(fig.is_empty());
+    assert_eq!(fig.data_range(), ViewRange::new((0.0, 1.0), (0.0, 1.0)));
+    assert!(fig.layers().is_empty());
+    assert!(fig.chart_data(0).is_none());
+}
+
+#[test]
+fn test_series_rejects_length_mismatch() {
+    let err = Series::scatter(vec![0.0, 1.0, 2.0], vec![0.0]).unwrap_err();
+    assert!(matches!(err, HelionError::ShapeMismatch { expected: 3, actual: 1, .. }));
+
+    let mut fig = Figure::new(100.0, 100.0);
+    assert!(fig.line(vec![0.0], vec![]).is_err());
+    assert!(fig.is_empty());
+}
+
+#[test]
+fn test_default_colors_cycle() {
+    let mut fig = Figure::new(100.0, 100.0);
+    for _ in 0..11 {
+        fig.scatter(vec![0.0], vec![0.0]).unwrap();
+    }
+    let colors: Vec<Color> = fig.series().iter().map(|s| s.style.color).collect();
+    assert_eq!(colors[0], Color::from_hex(DEFAULT_COLOR_CYCLE[0]));
+    assert_eq!(colors[1], Color::from_hex(DEFAULT_COLOR_CYCLE[1]));
+    assert_eq!(colors[10], colors[0]);
+
+    // `add` keeps the series' own style
+    let index = fig.add(Series::line(vec![0.0], vec![0.0]).unwrap());
+    assert_eq!(fig.series()[index].style, SeriesStyle::default());
+}
+
+#[test]
+fn test_layers_follow_z_order() {
+    let mut fig = Figure::new(100.0, 100.0);
+    fig.scatter(vec![0.0], vec![0.0]).unwrap().style.z_order = 2;
+    fig.line(vec![0.0, 1.0], vec![0.0, 1.0]).unwrap();
+    fig.scatter(vec![1.0], vec![1.0]).unwrap();
+
+    let layers = fig.layers();
+    let order: Vec<(usize, ChartKind)> = layers.iter().map(|l| (l.series, l.kind)).collect();
+    assert_eq!(
+        order,
+        [(1, ChartKind::Line), (2, ChartKind::Scatter), (0, ChartKind::Scatter)]
+    );
+}
+
+#[test]
+fn test_style_is_applied() {
+    let mut fig = Figure::new(100.0, 100.0);
+    let style = SeriesStyle::new()
+        .with_color(Color::new(1.0, 0.0, 0.0, 1.0))
+        .with_opacity(0.5)
+        .with_size(6.0);
+    fig.add(Series::scatter(vec![0.0, 1.0], vec![0.0, 1.0]).unwrap().with_style(style));
+
+    let data = fig.chart_data(0).unwrap();
+    assert_eq!(data.vertices[0].color, [1.0, 0.0, 0.0, 0.5]);
+    assert_eq!(data.vertices[0].size, 6.0);
+}
+
+#[test]
+fn test_legend_only_with_labels() {
+    let mut fig = Figure::new(400.0, 300.0);
+    fig.scatter(vec![0.0], vec![0.0]).unwrap();
+    assert!(fig.legend().is_none());
+
+    fig.line(vec![0.0, 1.0], vec![0.0, 1.0]).unwrap().label = Some("fit".to_string());
+    let legend = fig.legend().unwrap();
+    let labels: Vec<&str> = legend.entries().iter().map(|e| e.label.as_str()).collect();
+    assert_eq!(labels, ["", "fit"]);
+    assert_eq!(legend.entries()[1].color, fig.series()[1].style.color);
+}
+
+#[test]
+fn test_legend_location() {
+    let mut fig = Figure::new(400.0, 300.0);
+    fig.scatter(vec![0.0], vec![0.0]).unwrap().label = Some("points".to_string());
+    assert_eq!(fig.legend().unwrap().options().location, LegendLocation::UpperRight);
+
+    fig.set_legend_location(LegendLocation::LowerLeft);
+    assert_eq!(fig.legend_location(), LegendLocation::LowerLeft);
+    assert_eq!(fig.legend().unwrap().layout()[0].x, 16.0);
+}
+
+#[test]
+fn test_axes_shown_by_default() {
+    let fig = Figure::new(400.0, 300.0);
+    assert_eq!(fig.axes(), Some(&AxesOptions::default()));
+
+    let bare = fig.with_axes(None);
+    assert!(bare.axes().is_none());
+}
+
+#[test]
+fn test_title_and_axis_label_sections() {
+    let mut fig = Figure::new(400.0, 300.0).with_title("Latency");
+    fig.set_xlabel(Some("time".into()));
+    fig.set_ylabel(Some("ms".into()));
+    let view = ViewRange::new((0.0, 10.0), (0.0, 10.0));
+    let sections = fig.text().sections(&view, fig.axes(), 400.0, 300.0);
+
+    let find = |text: &str| sections.iter().find(|s| s.text == text).unwrap();
+    assert_eq!(find("Latency").position, [200.0, 6.0]);
+    let xlabel = find("time");
+    assert_eq!(xlabel.h_align, HAlign::Center);
+    // Above the x tick labels
+    assert!(xlabel.position[1] < 300.0 - AxesOptions::default().label_inset() - 12.0);
+    let ylabel = find("ms");
+    assert_eq!(ylabel.rotation, 90.0);
+    assert!(ylabel.position[0] > AxesOptions::default().label_inset());
+    // Tick labels come first, drawn under the titles
+    assert!(sections.len() > 3);
+
+    fig.set_title(None);
+    assert!(fig.text().title.is_none());
+    assert!(!fig.text().is_empty());
+}
+
+#[test]
+fn test_bars_include_baseline() {
+    let err = Series::bars(vec![0.0, 1.0], vec![1.0, 2.0]).unwrap_err();
+    assert!(matches!(err, HelionError::ShapeMismatch { expected: 3, actual: 2, .. }));
+
+    let mut fig = Figure::new(800.0, 600.0);
+    let options = HistogramOptions {
+        bins: BinStrategy::Count(4),
+        ..Default::default()
+    };
+    let bars = fig.hist(&[10.0, 11.0, 11.5, 14.0], &options);
+    assert_eq!(bars.kind, ChartKind::Bars);
+    assert_eq!(bars.len(), 4);
+    assert_eq!(bars.style.color, Color::from_hex(DEFAULT_COLOR_CYCLE[0]));
+
+    // Bars rise from zero, so the y range starts there
+    assert_eq!(fig.data_range().y, (0.0, 2.0));
+    assert_eq!(fig.data_range().x, (10.0, 14.0));
+    assert_eq!(fig.chart_data(0).unwrap().vertices.len(), 24);
+}
+
+#[test]
+fn test_log_scale_maps_series() {
+    let mut fig = Figure::new(800.0, 600.0);
+    fig.scatter(vec![0.0, 1.0, 10.0, 100.0], vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+    fig.set_xscale(AxisScale::Log10);
+    // 0 can't be shown on a log axis and doesn't stretch the range
+    assert_eq!(fig.data_range().x, (0.0, 2.0));
+    let data = fig.chart_data(0).unwrap();
+    assert!(data.vertices[0].position[0].is_nan());
+    assert_eq!(data.vertices[2].position[0], 0.0);
+    // The series keeps its data
+    assert_eq!(fig.series()[0].x(), &[0.0, 1.0, 10.0, 100.0]);
+
+    fig.set_xlim(Some((1.0, 1000.0)));
+    assert_eq!(fig.data_range().x, (0.0, 3.0));
+}
+
+#[test]
+fn test_scales_reach_the_axes() {
+    let mut fig = Figure::new(800.0, 600.0);
+    fig.set_yscale(AxisScale::Log10);
+    assert_eq!(fig.axes().unwrap().grid.y.scale, AxisScale::Log10);
+    assert_eq!(fig.axes().unwrap().grid.x.scale, AxisScale::Linear);
+
+    // Options set later take the figure's scales
+    fig.set_axes(Some(AxesOptions::default().with_ticks(false)));
+    assert_eq!(fig.axes().unwrap().grid.y.scale, AxisScale::Log10);
+}
+
+#[test]
+fn test_broken_axis_compresses_series() {
+    let mut fig = Figure::new(800.0, 600.0);
+    fig.scatter(vec![0.0, 10.0, 990.0, 1000.0], vec![0.0, 1.0, 2.0, 3.0]).unwrap();
+    fig.set_xbreaks(Some(BrokenAxis::new(vec![AxisBreak::new(10.0, 990.0)], 0.0)));
+    assert_eq!(fig.data_range().x, (0.0, 20.0));
+    let xs: Vec<f32> = fig.chart_data(0).unwrap().vertices.iter().map(|v| v.position[0]).collect();
+    assert_eq!(xs, [-1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(fig.axes().unwrap().grid.x.breaks.as_ref(), fig.xbreaks());
+
+    // Limits are given in data units
+    fig.set_xlim(Some((5.0, 995.0)));
+    assert_eq!(fig.data_range().x, (5.0, 15.0));
+}
+
+#[test]
+fn test_line_gaps_break_only_lines() {
+    let x = vec![0.0, 1.0, 2.0, 30.0, 31.0, 60.0];
+    let mut fig = Figure::new(800.0, 600.0);
+    fig.add(Series::line(x.clone(), vec![0.0; 6]).unwrap().with_gaps(GapThreshold::MedianMultiple(3.0)));
+    fig.add(Series::scatter(x, vec![0.0; 6]).unwrap().with_gaps(GapThreshold::MedianMultiple(3.0)));
+
+    let line = fig.chart_data(0).unwrap();
+    let breaks: Vec<usize> = (0..line.vertices.len()).filter(|&i| line.vertices[i].position[0].is_nan()).collect();
+    assert_eq!(breaks, [3, 6]);
+    assert_eq!(line.vertices.len(), 8);
+    assert_eq!(fig.chart_data(1).unwrap().vertices.len(), 6);
+}
+
+#[test]
+fn test_data_labels_follow_the_view() {
+    let mut fig = Figure::new(400.0, 300.0).with_axes(None);
+    fig.add(Series::scatter(vec![0.0, 10.0], vec![0.0, 10.0]).unwrap().with_data_labels(vec!["a".into(), String::new()]));
+    let text = fig.text_with_data_labels();
+    assert_eq!(text.data_labels.len(), 2);
+    assert!(fig.text().data_labels.is_empty());
+
+    // Only the non-empty label is drawn, right of its point in pixels
+    let sections = text.sections(&ViewRange::new((-10.0, 10.0), (-10.0, 10.0)), None, 400.0, 300.0);
+    assert_eq!(sections.len(), 1);
+    assert_eq!(sections[0].text, "a");
+    assert_eq!(sections[0].position[0], 204.0);
+    // Panned so the point sits on the right edge, the label moves left of it
+    let sections = text.sections(&ViewRange::new((-20.0, 0.0), (-10.0, 10.0)), None, 400.0, 300.0);
+    assert!((sections[0].position[0] - (400.0 - 4.0 - 12.0 * 0.6)).abs() < 1e-4);
+}
+
+#[test]
+fn test_bars_on_log_axis_rise_from_bottom() {
+    let mut fig = Figure::new(800.0, 600.0);
+    fig.bars(vec![0.0, 1.0], vec![1000.0]).unwrap();
+    fig.set_yscale(AxisScale::Log10);
+    fig.set_ylim(Some((1.0, 1000.0)));
+    let data = fig.chart_data(0).unwrap();
+    let ys: Vec<f32> = data.vertices.iter().map(|v| v.position[1]).collect();
+    assert!(ys.contains(&-1.0) && ys.contains(&1.0));
+}
@@ -0,0 +1,173 @@
+use helion_core::finance::{candlestick_data, volume_data, CandleKind, CandleStyle, FinanceFigure, Ohlc};
+
+fn sample() -> Ohlc {
+    Ohlc::new(
+        vec![0.0, 1.0, 2.0, 3.0],
+        vec![10.0, 12.0, 11.0, 11.5],
+        vec![13.0, 12.5, 12.0, 14.0],
+        vec![9.0, 10.5, 10.0, 11.0],
+        vec![12.0, 11.0, 11.5, 13.5],
+        vec![100.0, 200.0, 50.0, 400.0],
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_ohlc_length_mismatch() {
+    let result = Ohlc::new(vec![0.0, 1.0], vec![1.0], vec![1.0, 2.0], vec![1.0, 2.0], vec![1.0, 2.0], vec![1.0, 2.0]);
+    assert!(result.unwrap_err().contains("open: 1"));
+}
+
+#[test]
+fn test_candle_geometry() {
+    let ohlc = sample();
+    let style = CandleStyle::default();
+    let data = candlestick_data(&ohlc, &style, (-1.0, 1.0), (-1.0, 1.0), 800.0, 600.0);
+
+    // Wick + body per bar, six vertices each
+    assert_eq!(data.vertices.len(), ohlc.len() * 12);
+
+    // First wick spans the global low (9) to the bar's high (13)
+    let wick = &data.vertices[0..6];
+    let wick_min = wick.iter().map(|v| v.position[1]).fold(f32::INFINITY, f32::min);
+    assert_eq!(wick_min, -1.0);
+
+    // Edge candles sit fully inside the panel
+    let leftmost = data.vertices.iter().map(|v| v.position[0]).fold(f32::INFINITY, f32::min);
+    assert!(leftmost > -1.0);
+
+    // Up and down bars are colored differently
+    assert_eq!(data.vertices[6].color, [style.up.r, style.up.g, style.up.b, style.up.a]);
+    assert_eq!(data.vertices[18].color, [style.down.r, style.down.g, style.down.b, style.down.a]);
+}
+
+#[test]
+fn test_body_width_follows_spacing() {
+    let ohlc = sample();
+    let style = CandleStyle { body_width: 1.0, ..Default::default() };
+    let data = candlestick_data(&ohlc, &style, (-1.0, 1.0), (-1.0, 1.0), 800.0, 600.0);
+
+    // Four bars plus half-bar padding span 4 time units, so bars are 0.5 clip
+    // units apart; a full-width body touches its neighbours
+    let body = &data.vertices[6..12];
+    let left = body.iter().map(|v| v.position[0]).fold(f32::INFINITY, f32::min);
+    let right = body.iter().map(|v| v.position[0]).fold(f32::NEG_INFINITY, f32::max);
+    assert!((right - left - 0.5).abs() < 1e-5);
+}
+
+#[test]
+fn test_volume_bars_rise_from_zero() {
+    let ohlc = sample();
+    let style = CandleStyle::default();
+    let data = volume_data(&ohlc, &style, (-1.0, 1.0), (-1.0, 1.0), 800.0, 200.0);
+
+    assert_eq!(data.vertices.len(), ohlc.len() * 6);
+    for v in &data.vertices {
+        assert!(v.position[1] >= -1.0 && v.position[1] <= 1.0);
+        assert_eq!(v.color[3], style.up.a * style.volume_alpha);
+    }
+    // Largest volume reaches the top of the panel
+    let top = data.vertices[18..24].iter().map(|v| v.position[1]).fold(f32::NEG_INFINITY, f32::max);
+    assert_eq!(top, 1.0);
+}
+
+#[test]
+fn test_figure_panels_share_time_axis() {
+    let ohlc = sample();
+    let figure = FinanceFigure::new(&ohlc, &CandleStyle::default(), 800.0, 608.0);
+
+    // Each candle body is centered over its volume bar
+    for i in 0..ohlc.len() {
+        let body = &figure.price.vertices[i * 12 + 6..i * 12 + 12];
+        let bar = &figure.volume.vertices[i * 6..i * 6 + 6];
+        let center = |vs: &[helion_core::data::Vertex]| {
+            let min = vs.iter().map(|v| v.position[0]).fold(f32::INFINITY, f32::min);
+            let max = vs.iter().map(|v| v.position[0]).fold(f32::NEG_INFINITY, f32::max);
+            (min + max) / 2.0
+        };
+        assert!((center(body) - center(bar)).abs() < 1e-6);
+    }
+
+    let price = figure.price_rect();
+    let volume = figure.volume_rect();
+    assert_eq!(price.height, 450.0);
+    assert_eq!(volume.height, 150.0);
+    assert_eq!(figure.price.viewport_height, 450.0);
+}
+
+#[test]
+fn test_hollow_up_bodies_are_outlined() {
+    let ohlc = sample();
+    let style = CandleStyle { kind: CandleKind::Hollow, ..Default::default() };
+    let data = candlestick_data(&ohlc, &style, (-1.0, 1.0), (-1.0, 1.0), 800.0, 600.0);
+
+    // Up bars: two wick pieces and four outline edges; down bars stay filled
+    let up_bars = (0..ohlc.len()).filter(|&i| ohlc.is_up(i)).count();
+    let down_bars = ohlc.len() - up_bars;
+    assert_eq!(data.vertices.len(), up_bars * 36 + down_bars * 12);
+
+    // Nothing is drawn in the middle of the first (up) body
+    let first = &data.vertices[..36];
+    let center_x = (first.iter().map(|v| v.position[0]).fold(f32::INFINITY, f32::min)
+        + first.iter().map(|v| v.position[0]).fold(f32::NEG_INFINITY, f32::max))
+        / 2.0;
+    let bodies: Vec<[f32; 4]> = first
+        .chunks(6)
+        .map(|quad| {
+            let xs = quad.iter().map(|v| v.position[0]);
+            let ys = quad.iter().map(|v| v.position[1]);
+            [
+                xs.clone().fold(f32::INFINITY, f32::min),
+                ys.clone().fold(f32::INFINITY, f32::min),
+                xs.fold(f32::NEG_INFINITY, f32::max),
+                ys.fold(f32::NEG_INFINITY, f32::max),
+            ]
+        })
+        .collect();
+    // Body from open 10 to close 12 on a 9..14 scale: y = -0.6 to 0.2
+    let middle = (center_x, -0.2);
+    assert!(!bodies
+        .iter()
+        .any(|r| middle.0 > r[0] && middle.0 < r[2] && middle.1 > r[1] && middle.1 < r[3]));
+}
+
+#[test]
+fn test_ohlc_bars_have_open_and_close_ticks() {
+    let ohlc = sample();
+    let style = CandleStyle { kind: CandleKind::Bar, body_width: 1.0, ..Default::default() };
+    let data = candlestick_data(&ohlc, &style, (-1.0, 1.0), (-1.0, 1.0), 800.0, 600.0);
+    assert_eq!(data.vertices.len(), ohlc.len() * 18);
+
+    // First bar is centered at -0.75; the open tick reaches left, the close
+    // tick right, each as wide as half the bar
+    let open_tick = &data.vertices[6..12];
+    let close_tick = &data.vertices[12..18];
+    let x_range = |quad: &[helion_core::data::Vertex]| {
+        let xs = quad.iter().map(|v| v.position[0]);
+        (xs.clone().fold(f32::INFINITY, f32::min), xs.fold(f32::NEG_INFINITY, f32::max))
+    };
+    let ((ol, or), (cl, cr)) = (x_range(open_tick), x_range(close_tick));
+    assert!((ol + 1.0).abs() < 1e-5 && (or + 0.75).abs() < 1e-5);
+    assert!((cl + 0.75).abs() < 1e-5 && (cr + 0.5).abs() < 1e-5);
+    // The open tick sits at the open price, 10 on a 9..14 scale
+    let open_y = open_tick.iter().map(|v| v.position[1]).sum::<f32>() / 6.0;
+    assert!((open_y + 0.6).abs() < 1e-5);
+}
+
+#[test]
+fn test_heikin_ashi() {
+    let ohlc = sample();
+    let ha = ohlc.heikin_ashi();
+    assert_eq!(ha.len(), ohlc.len());
+    assert_eq!(ha.time, ohlc.time);
+    assert_eq!(ha.volume, ohlc.volume);
+
+    assert_eq!(ha.close[0], (10.0 + 13.0 + 9.0 + 12.0) / 4.0);
+    assert_eq!(ha.open[0], 11.0);
+    assert_eq!(ha.open[1], (ha.open[0] + ha.close[0]) / 2.0);
+    for i in 0..ha.len() {
+        assert!(ha.high[i] >= ha.open[i].max(ha.close[i]) && ha.high[i] >= ohlc.high[i]);
+        assert!(ha.low[i] <= ha.open[i].min(ha.close[i]) && ha.low[i] <= ohlc.low[i]);
+    }
+    assert!(Ohlc::default().heikin_ashi().is_empty());
+}
@@ -0,0 +1,122 @@
+use helion_core::colormap::ColormapLut;
+use helion_core::data::{Color, Point2D};
+use helion_core::error::HelionError;
+use helion_core::geo::{read_geojson, Choropleth};
+
+const REGIONS: &str = r#"{
+    "type": "FeatureCollection",
+    "features": [
+        {
+            "type": "Feature",
+            "properties": {"name": "West", "rate": 1.5},
+            "geometry": {"type": "Polygon", "coordinates": [
+                [[0, 0], [2, 0], [2, 2], [0, 2], [0, 0]],
+                [[0.5, 0.5], [1.5, 0.5], [1.5, 1.5], [0.5, 1.5], [0.5, 0.5]]
+            ]}
+        },
+        {
+            "type": "Feature",
+            "properties": {"name": "Islands", "rate": "4"},
+            "geometry": {"type": "MultiPolygon", "coordinates": [
+                [[[2, 0], [3, 0], [3, 1], [2, 0]]],
+                [[[3, 1], [4, 1], [4, 2], [3, 1]]]
+            ]}
+        },
+        {
+            "type": "Feature",
+            "properties": {"name": "Unknown"},
+            "geometry": null
+        }
+    ]
+}"#;
+
+#[test]
+fn test_read_feature_collection() {
+    let features = read_geojson(REGIONS).unwrap();
+    assert_eq!(features.len(), 3);
+
+    assert_eq!(features[0].text("name"), Some("West"));
+    assert_eq!(features[0].polygons.len(), 1);
+    assert_eq!(features[0].polygons[0].holes.len(), 1);
+    assert_eq!(features[0].polygons[0].area(), 3.0);
+    assert!(!features[0].contains(Point2D::new(1.0, 1.0)));
+
+    assert_eq!(features[1].polygons.len(), 2);
+    assert_eq!(features[1].number("rate"), Some(4.0));
+    assert!(features[2].polygons.is_empty());
+    assert_eq!(features[2].number("rate"), None);
+}
+
+#[test]
+fn test_read_bare_geometry_and_feature() {
+    let geometry = r#"{"type": "GeometryCollection", "geometries": [
+        {"type": "Point", "coordinates": [5, 5]},
+        {"type": "Polygon", "coordinates": [[[0, 0], [1, 0], [0, 1]]]}
+    ]}"#;
+    let features = read_geojson(geometry).unwrap();
+    assert_eq!(features.len(), 1);
+    assert_eq!(features[0].polygons.len(), 1);
+
+    let feature = r#"{"type": "Feature", "geometry": {"type": "Polygon", "coordinates": [[[0, 0, 9], [1, 0, 9], [0, 1, 9]]]}}"#;
+    assert_eq!(read_geojson(feature).unwrap()[0].polygons[0].area(), 0.5);
+}
+
+#[test]
+fn test_malformed_geojson() {
+    let message = |json: &str| match read_geojson(json) {
+        Err(HelionError::InvalidOption(message)) => message,
+        other => panic!("expected InvalidOption, got {:?}", other),
+    };
+    assert!(message("not json").starts_with("Invalid GeoJSON"));
+    assert!(message(r#"{"features": []}"#).contains("\"type\""));
+    let bad = r#"{"type": "FeatureCollection", "features": [
+        {"type": "Feature", "geometry": {"type": "Polygon", "coordinates": [[[0, 0], [1]]]}}
+    ]}"#;
+    assert!(message(bad).contains("feature 0"), "{}", message(bad));
+    assert!(message(r#"{"type": "Circle", "coordinates": []}"#).contains("Circle"));
+}
+
+#[test]
+fn test_choropleth_colors_by_value() {
+    let features = read_geojson(REGIONS).unwrap();
+    let gray = Color::new(0.5, 0.5, 0.5, 1.0);
+    let map = Choropleth::from_property(features, "rate")
+        .with_colormap(ColormapLut::grayscale())
+        .with_missing_color(gray);
+    assert_eq!(map.values()[..2], [1.5, 4.0]);
+    assert!(map.values()[2].is_nan());
+
+    let colors = map.fill_colors();
+    assert_eq!(colors[0], Color::new(0.0, 0.0, 0.0, 1.0));
+    assert_eq!(colors[1], Color::new(1.0, 1.0, 1.0, 1.0));
+    assert_eq!(colors[2], gray);
+
+    let colors = map.with_range(0.0, 8.0).fill_colors();
+    assert!(colors[1].r > 0.4 && colors[1].r < 0.6);
+}
+
+#[test]
+fn test_choropleth_geometry() {
+    let features = read_geojson(REGIONS).unwrap();
+    let map = Choropleth::from_property(features, "rate");
+    assert_eq!(map.extent(), ((0.0, 4.0), (0.0, 2.0)));
+    assert_eq!(map.feature_at(Point2D::new(3.6, 1.5)), Some(1));
+    assert_eq!(map.feature_at(Point2D::new(1.0, 1.0)), None);
+
+    // A 2:1 map in a square viewport fills the width and half the height
+    assert_eq!(map.output_ranges(500.0, 500.0), ((-1.0, 1.0), (-0.5, 0.5)));
+    assert_eq!(map.output_ranges(1000.0, 250.0), ((-0.5, 0.5), (-1.0, 1.0)));
+
+    let data = map.chart_data(500.0, 500.0);
+    // The framed square takes eight triangles, each island one
+    assert_eq!(data.vertices.len(), (8 + 2) * 3);
+    let ys = data.vertices.iter().map(|v| v.position[1]);
+    assert_eq!(ys.fold(f32::NEG_INFINITY, f32::max), 0.5);
+}
+
+#[test]
+fn test_choropleth_value_count_must_match() {
+    let features = read_geojson(REGIONS).unwrap();
+    let err = Choropleth::new(features, vec![1.0]).unwrap_err();
+    assert!(matches!(err, HelionError::ShapeMismatch { expected: 3, actual: 1, .. }));
+}
@@ -0,0 +1,67 @@
+use helion_core::glyph::{GlyphShape, GlyphUniforms};
+
+// Note: GlyphRenderer itself needs a GPU device; these tests cover the marker
+// meshes and the uniform layout shared with the shader.
+
+fn area(mesh: &[[f32; 2]]) -> f32 {
+    mesh.chunks_exact(3)
+        .map(|t| {
+            let (a, b, c) = (t[0], t[1], t[2]);
+            ((b[0] - a[0]) * (c[1] - a[1]) - (c[0] - a[0]) * (b[1] - a[1])).abs() / 2.0
+        })
+        .sum()
+}
+
+#[test]
+fn test_meshes_are_whole_triangles_in_unit_box() {
+    let shapes = [
+        GlyphShape::default(),
+        GlyphShape::Square,
+        GlyphShape::Diamond,
+        GlyphShape::Triangle,
+        GlyphShape::Cross,
+    ];
+    for shape in shapes {
+        let mesh = shape.mesh();
+        assert!(!mesh.is_empty(), "{:?}", shape);
+        assert_eq!(mesh.len() % 3, 0, "{:?}", shape);
+        assert!(
+            mesh.iter().all(|p| p[0].abs() <= 1.0 + 1e-6 && p[1].abs() <= 1.0 + 1e-6),
+            "{:?} leaves the unit box",
+            shape
+        );
+    }
+}
+
+#[test]
+fn test_mesh_areas() {
+    assert!((area(&GlyphShape::Square.mesh()) - 4.0).abs() < 1e-5);
+    assert!((area(&GlyphShape::Diamond.mesh()) - 2.0).abs() < 1e-5);
+
+    // A fine polygon approaches the unit circle's area from below
+    let circle = area(&GlyphShape::Circle { segments: 64 }.mesh());
+    assert!(circle < std::f32::consts::PI && circle > 3.13);
+
+    // Cross arms don't overlap: 2 x 0.6 bar + two 0.7 x 0.6 arms
+    assert!((area(&GlyphShape::Cross.mesh()) - 2.04).abs() < 1e-5);
+}
+
+#[test]
+fn test_circle_segments_are_clamped() {
+    assert_eq!(GlyphShape::Circle { segments: 0 }.mesh().len(), 9);
+    assert_eq!(GlyphShape::Circle { segments: 32 }.mesh().len(), 96);
+}
+
+#[test]
+fn test_custom_mesh_drops_partial_triangle() {
+    let shape = GlyphShape::Custom(vec![[0.0, 1.0], [-1.0, -1.0], [1.0, -1.0], [0.0, 0.0]]);
+    assert_eq!(shape.mesh().len(), 3);
+    assert!(GlyphShape::Custom(vec![[0.0, 0.0]; 2]).mesh().is_empty());
+}
+
+#[test]
+fn test_glyph_uniforms_layout() {
+    // WGSL uniform blocks must be a multiple of 16 bytes
+    assert_eq!(std::mem::size_of::<GlyphUniforms>(), 16);
+    assert_eq!(GlyphUniforms::new(640.0, 480.0).viewport, [640.0, 480.0]);
+}
@@ -0,0 +1,32 @@
+use helion_core::brush::Selection;
+use helion_core::data::{ChartData, Color, Point2D};
+use helion_core::halo::{flagged_points, HaloOptions, HaloUniforms};
+
+// Note: HaloRenderer itself needs a GPU device; these tests cover the CPU-side
+// data preparation and the uniform layout shared with the shader.
+
+#[test]
+fn test_flagged_points_extracts_selection() {
+    let mut data = ChartData::new(800.0, 600.0);
+    for i in 0..4 {
+        data.add_point(Point2D::new(i as f32 * 0.1, 0.0), Color::default(), 2.0 + i as f32);
+    }
+
+    let flagged = flagged_points(&data, &Selection::new(vec![3, 1, 99]));
+
+    assert_eq!(flagged.vertices.len(), 2);
+    assert_eq!(flagged.vertices[0].size, 3.0);
+    assert_eq!(flagged.vertices[1].size, 5.0);
+    assert_eq!(flagged.viewport_width, 800.0);
+}
+
+#[test]
+fn test_halo_uniforms_layout() {
+    // WGSL uniform blocks must be a multiple of 16 bytes
+    assert_eq!(std::mem::size_of::<HaloUniforms>(), 16);
+
+    let uniforms = HaloUniforms::new(1920.0, 1080.0, &HaloOptions::default());
+    assert_eq!(uniforms.viewport, [1920.0, 1080.0]);
+    assert_eq!(uniforms.radius_scale, 4.0);
+    assert_eq!(uniforms.intensity, 1.0);
+}
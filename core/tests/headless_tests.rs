@@ -0,0 +1,47 @@
+use helion_core::error::HelionError;
+use helion_core::headless::{encode_png, HEADLESS_FORMAT};
+
+fn decode(bytes: &[u8]) -> (png::OutputInfo, Vec<u8>) {
+    let mut reader = png::Decoder::new(std::io::Cursor::new(bytes)).read_info().unwrap();
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut pixels).unwrap();
+    pixels.truncate(info.buffer_size());
+    (info, pixels)
+}
+
+#[test]
+fn test_encode_png_round_trips() {
+    // 2x2: red, green / blue, half-transparent white
+    let rgba = vec![
+        255, 0, 0, 255, 0, 255, 0, 255, //
+        0, 0, 255, 255, 255, 255, 255, 128,
+    ];
+    let bytes = encode_png(&rgba, 2, 2).unwrap();
+    assert_eq!(&bytes[..8], b"\x89PNG\r\n\x1a\n");
+
+    let (info, pixels) = decode(&bytes);
+    assert_eq!((info.width, info.height), (2, 2));
+    assert_eq!(info.color_type, png::ColorType::Rgba);
+    assert_eq!(pixels, rgba);
+}
+
+#[test]
+fn test_encode_png_checks_length() {
+    let err = encode_png(&[0; 12], 2, 2).unwrap_err();
+    assert_eq!(
+        err,
+        HelionError::ShapeMismatch {
+            what: "RGBA bytes for a 2x2 image".to_string(),
+            expected: 16,
+            actual: 12,
+        }
+    );
+}
+
+#[test]
+fn test_headless_format_is_rgba() {
+    // Read back pixels go straight to encode_png without a channel swap
+    assert_eq!(HEADLESS_FORMAT.block_copy_size(None), Some(4));
+    assert!(HEADLESS_FORMAT.is_srgb());
+    assert!(matches!(HEADLESS_FORMAT, wgpu::TextureFormat::Rgba8UnormSrgb));
+}
@@ -0,0 +1,118 @@
+use helion_core::aggregate::BinGrid;
+use helion_core::data::Colormap;
+use helion_core::error::HelionError;
+use helion_core::figure::Figure;
+use helion_core::camera::Camera2D;
+use helion_core::heatmap::{clip_rect, AggregatedPoints, Heatmap};
+use helion_core::norm::Normalization;
+use helion_core::transition::ViewRange;
+
+#[test]
+fn test_heatmap_checks_shape() {
+    let err = Heatmap::new(vec![0.0; 5], 3, 2).unwrap_err();
+    assert!(matches!(err, HelionError::ShapeMismatch { expected: 6, actual: 5, .. }));
+    assert!(matches!(Heatmap::new(Vec::new(), 0, 4), Err(HelionError::EmptyData)));
+
+    let heatmap = Heatmap::new(vec![0.0; 6], 3, 2).unwrap();
+    assert_eq!(heatmap.extent(), ViewRange::new((0.0, 3.0), (0.0, 2.0)));
+    assert_eq!(heatmap.cmap, Colormap::Viridis);
+}
+
+#[test]
+fn test_value_range() {
+    let heatmap = Heatmap::new(vec![f32::NAN, -2.0, 8.0, f32::INFINITY], 2, 2).unwrap();
+    assert_eq!(heatmap.value_range(), (-2.0, 8.0));
+    assert_eq!(heatmap.clone().with_range(Some(0.0), None).value_range(), (0.0, 8.0));
+
+    let empty = Heatmap::new(vec![f32::NAN; 4], 2, 2).unwrap();
+    assert_eq!(empty.value_range(), (0.0, 1.0));
+}
+
+#[test]
+fn test_cell_values_follow_normalization() {
+    let heatmap = Heatmap::new(vec![0.0, 1.0, 2.0, 4.0], 2, 2).unwrap();
+    // Linear cells go up as they are, so the range can change on the GPU
+    let (cells, range) = heatmap.cell_values();
+    assert_eq!((cells.as_ref(), range), (heatmap.values.as_slice(), (0.0, 4.0)));
+
+    let sqrt = heatmap
+        .clone()
+        .with_range(None, Some(16.0))
+        .with_normalization(Normalization::Power { gamma: 0.5 });
+    let (cells, range) = sqrt.cell_values();
+    assert_eq!(cells.as_ref(), &[0.0, 0.25, 2.0f32.sqrt() / 4.0, 0.5]);
+    assert_eq!(range, (0.0, 1.0));
+}
+
+#[test]
+fn test_centered_heatmap() {
+    // Asymmetric range: zero still gets the middle color
+    let heatmap = Heatmap::new(vec![-2.0, 0.0, 4.0, 8.0], 2, 2).unwrap().with_center(0.0);
+    let (cells, range) = heatmap.cell_values();
+    assert_eq!(cells.as_ref(), &[0.0, 0.5, 0.75, 1.0]);
+    assert_eq!(range, (0.0, 1.0));
+}
+
+#[test]
+fn test_hist2d_counts_points() {
+    let x = [0.0, 0.5, 3.9, 1.0, f32::NAN];
+    let y = [0.0, 0.2, 3.9, 3.0, 1.0];
+    let grid = BinGrid::covering(&x, &y, 2, 2);
+    let heatmap = Heatmap::hist2d(&x, &y, &grid);
+    assert_eq!(heatmap.extent().x, grid.x_range);
+
+    // Row 0 is the bottom row; empty cells are NaN so they stay transparent
+    assert_eq!(heatmap.values[0], 2.0);
+    assert!(heatmap.values[1].is_nan());
+    assert_eq!(&heatmap.values[2..], &[1.0, 1.0]);
+    assert_eq!(Heatmap::hist2d_auto(&x, &y, &grid).values.len(), 4);
+}
+
+#[test]
+fn test_zoomed_hist2d_is_rebinned_finer() {
+    let x: Vec<f32> = (0..1000).map(|i| (i % 100) as f32).collect();
+    let y: Vec<f32> = (0..1000).map(|i| (i / 10) as f32).collect();
+    let grid = BinGrid::covering(&x, &y, 8, 8);
+    let heatmap = Heatmap::hist2d(&x, &y, &grid).with_colormap(Colormap::Magma);
+    let mut fig = Figure::new(400.0, 400.0);
+    fig.set_hist2d(heatmap.clone(), AggregatedPoints::new(x, y, 50.0));
+    let data_range = fig.data_range();
+
+    // What the window does on each view change
+    let mut points = fig.aggregated().unwrap().clone();
+    let mut camera = Camera2D::identity();
+    let full = points.regrid(&camera.visible_range(&data_range), 400.0, 400.0).unwrap();
+    assert!(points.regrid(&camera.visible_range(&data_range), 400.0, 400.0).is_none());
+
+    camera.zoom_about([4.0, 4.0], [0.0, 0.0]);
+    let zoomed = points.regrid(&camera.visible_range(&data_range), 400.0, 400.0).unwrap();
+    assert_eq!((zoomed.nx, zoomed.ny), (full.nx, full.ny));
+    assert!(zoomed.cell_size().0 < full.cell_size().0 / 3.0);
+    assert!(zoomed.cell_size().1 < full.cell_size().1 / 3.0);
+
+    let rebinned = heatmap.recount(&zoomed, &points.counts(&zoomed)).unwrap();
+    assert_eq!(rebinned.extent(), camera.visible_range(&data_range));
+    assert_eq!(rebinned.cmap, Colormap::Magma);
+    let log = heatmap.clone().with_normalization(Normalization::Log).with_center(10.0);
+    let recounted = log.recount(&zoomed, &points.counts(&zoomed)).unwrap();
+    assert_eq!((recounted.norm, recounted.center), (Normalization::Log, Some(10.0)));
+
+    // A plain heatmap replaces the points
+    fig.set_heatmap(Some(heatmap));
+    assert!(fig.aggregated().is_none());
+}
+
+#[test]
+fn test_figure_range_includes_heatmap() {
+    let mut fig = Figure::new(800.0, 600.0);
+    fig.scatter(vec![5.0], vec![5.0]).unwrap();
+    let heatmap = Heatmap::new(vec![1.0; 4], 2, 2).unwrap().with_extent((0.0, 10.0), (2.0, 4.0));
+    fig.set_heatmap(Some(heatmap.clone()));
+
+    let range = fig.data_range();
+    assert_eq!(range, ViewRange::new((0.0, 10.0), (2.0, 5.0)));
+    let rect = clip_rect(&heatmap, &range);
+    for (got, want) in rect.iter().zip([-1.0, -1.0, 1.0, 1.0 / 3.0]) {
+        assert!((got - want).abs() < 1e-6, "{rect:?}");
+    }
+}
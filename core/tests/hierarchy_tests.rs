@@ -0,0 +1,41 @@
+use helion_core::error::HelionError;
+use helion_core::hierarchy::{Hierarchy, HierarchyNode};
+
+fn disk() -> Hierarchy {
+    let root = HierarchyNode::group(
+        "Disk",
+        vec![
+            HierarchyNode::group("src", vec![HierarchyNode::leaf("lib.rs", 6.0), HierarchyNode::leaf("main.rs", 2.0)]),
+            HierarchyNode::leaf("README.md", 4.0),
+            HierarchyNode::leaf("empty", 0.0),
+        ],
+    );
+    Hierarchy::new(root).unwrap()
+}
+
+#[test]
+fn test_invalid_values_are_rejected() {
+    for value in [-1.0, f32::NAN, f32::INFINITY] {
+        let root = HierarchyNode::group("root", vec![HierarchyNode::leaf("a", 1.0), HierarchyNode::leaf("b", value)]);
+        let err = Hierarchy::new(root).unwrap_err();
+        assert!(matches!(err, HelionError::InvalidOption(_)), "{}", value);
+    }
+    // A group's own value is ignored
+    let root = HierarchyNode { value: f32::NAN, ..HierarchyNode::group("root", vec![HierarchyNode::leaf("a", 1.0)]) };
+    assert_eq!(Hierarchy::new(root).unwrap().value(0), 1.0);
+}
+
+#[test]
+fn test_nodes_are_numbered_depth_first() {
+    let tree = disk();
+    let labels: Vec<&str> = (0..tree.len()).map(|node| tree.label(node)).collect();
+    assert_eq!(labels, ["Disk", "src", "lib.rs", "main.rs", "README.md", "empty"]);
+    assert_eq!(tree.children(0), [1, 4, 5]);
+    assert_eq!(tree.depth(3), 2);
+    assert_eq!(tree.value(0), 12.0);
+    assert!(!tree.is_empty());
+    assert!(Hierarchy::new(HierarchyNode::leaf("alone", 1.0)).unwrap().is_empty());
+    assert!(tree.is_within(3, 1));
+    assert!(!tree.is_within(4, 1));
+    assert_eq!(tree.branch(0), None);
+}
@@ -0,0 +1,115 @@
+use helion_core::data::Color;
+use helion_core::histogram::{
+    bar_data, bin_edges, edges_for, BinStrategy, Histogram, HistogramNorm, HistogramOptions, MAX_BINS,
+};
+
+fn options(bins: BinStrategy, norm: HistogramNorm, cumulative: bool) -> HistogramOptions {
+    HistogramOptions { bins, norm, cumulative }
+}
+
+#[test]
+fn test_count_bins() {
+    let data = [0.0, 0.5, 1.0, 2.5, 3.0, 4.0];
+    let hist = Histogram::compute(&data, &options(BinStrategy::Count(4), HistogramNorm::Count, false));
+
+    assert_eq!(hist.edges, vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+    // Right edge of the last bin is inclusive
+    assert_eq!(hist.values, vec![2.0, 1.0, 1.0, 2.0]);
+}
+
+#[test]
+fn test_density_integrates_to_one() {
+    let data: Vec<f32> = (0..1000).map(|i| (i as f32 * 0.37).sin()).collect();
+    let hist = Histogram::compute(&data, &options(BinStrategy::Count(20), HistogramNorm::Density, false));
+
+    let area: f32 = (0..hist.len()).map(|i| hist.values[i] * hist.bin_width(i)).sum();
+    assert!((area - 1.0).abs() < 1e-4, "area = {}", area);
+}
+
+#[test]
+fn test_cumulative_modes() {
+    let data = [0.0, 1.0, 1.0, 3.0];
+    let counts = Histogram::compute(&data, &options(BinStrategy::Count(2), HistogramNorm::Count, true));
+    assert_eq!(counts.values, vec![3.0, 4.0]);
+
+    let density = Histogram::compute(&data, &options(BinStrategy::Count(2), HistogramNorm::Density, true));
+    assert_eq!(density.values, vec![0.75, 1.0]);
+}
+
+#[test]
+fn test_fixed_width_and_explicit_edges() {
+    let data = [0.3, 1.2, 2.7, 9.9];
+    let edges = bin_edges(&data, &BinStrategy::FixedWidth(2.5));
+    assert_eq!(edges, vec![0.0, 2.5, 5.0, 7.5, 10.0]);
+
+    let hist = Histogram::compute(
+        &data,
+        &options(BinStrategy::Edges(vec![0.0, 1.0, 3.0]), HistogramNorm::Count, false),
+    );
+    // 9.9 is outside the explicit edges and dropped
+    assert_eq!(hist.values, vec![1.0, 2.0]);
+
+    assert!(bin_edges(&data, &BinStrategy::Edges(vec![1.0, 0.0])).is_empty());
+}
+
+#[test]
+fn test_automatic_strategies() {
+    let data: Vec<f32> = (0..100).map(|i| i as f32).collect();
+    // Sturges: ceil(log2(100)) + 1 = 8 bins
+    assert_eq!(bin_edges(&data, &BinStrategy::Sturges).len(), 9);
+    // Freedman-Diaconis: IQR = 49.5, width = 99 / 100^(1/3) ~= 21.3 -> 5 bins
+    assert_eq!(bin_edges(&data, &BinStrategy::FreedmanDiaconis).len(), 6);
+}
+
+#[test]
+fn test_bin_count_is_capped() {
+    // A tight cluster next to one outlier: width ~ 1e-4 over a range of 1e6
+    let mut data: Vec<f32> = (0..1000).map(|i| 1.0 + i as f32 * 1e-6).collect();
+    data.push(1.0e6);
+    let edges = bin_edges(&data, &BinStrategy::FreedmanDiaconis);
+    assert_eq!(edges.len(), MAX_BINS + 1);
+    assert_eq!(edges[MAX_BINS], 1.0e6);
+
+    let edges = bin_edges(&[0.0, 1.0], &BinStrategy::FixedWidth(1e-9));
+    assert_eq!(edges.len(), MAX_BINS + 1);
+    assert_eq!((edges[0], edges[MAX_BINS]), (0.0, 1.0));
+
+    assert!(bin_edges(&[0.0, 1.0], &BinStrategy::FixedWidth(f32::NAN)).is_empty());
+}
+
+#[test]
+fn test_degenerate_inputs() {
+    let empty = Histogram::compute(&[], &HistogramOptions::default());
+    assert!(empty.is_empty());
+
+    let constant = Histogram::compute(&[2.0, 2.0, f32::NAN], &HistogramOptions::default());
+    assert_eq!(constant.edges, vec![1.5, 2.5]);
+    assert_eq!(constant.values, vec![2.0]);
+}
+
+#[test]
+fn test_edges_for_raw_data() {
+    let data = [f32::NAN, 0.0, f32::INFINITY, 4.0, -f32::INFINITY];
+    assert_eq!(edges_for(&data, &BinStrategy::Count(2)), vec![0.0, 2.0, 4.0]);
+    assert_eq!(
+        edges_for(&data, &BinStrategy::Count(2)),
+        Histogram::compute(&data, &options(BinStrategy::Count(2), HistogramNorm::Count, false)).edges
+    );
+    assert_eq!(edges_for(&[], &BinStrategy::Edges(vec![0.0, 1.0])), vec![0.0, 1.0]);
+}
+
+#[test]
+fn test_bar_data_fills_clip_space() {
+    let hist = Histogram {
+        edges: vec![-1.0, 0.0, 3.0],
+        values: vec![4.0, 2.0],
+    };
+    let data = bar_data(&hist, Color::new(1.0, 0.0, 0.0, 1.0), 800.0, 600.0);
+    // Two rectangles of two triangles each
+    assert_eq!(data.vertices.len(), 12);
+    // First bar: x in [-1, -0.5], from the bottom to the top
+    assert_eq!(data.vertices[0].position, [-1.0, -1.0]);
+    assert_eq!(data.vertices[2].position, [-0.5, 1.0]);
+    // Second bar is half as tall
+    assert_eq!(data.vertices[8].position, [1.0, 0.0]);
+}
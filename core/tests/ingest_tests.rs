@@ -0,0 +1,204 @@
+use helion_core::cancel::CancelToken;
+use helion_core::ingest::{decode_f32, read_npy, Dtype, Endian, MappedArray, NpyArray, NpyHeader, NpzArchive};
+use std::io::{Cursor, Write};
+use std::path::PathBuf;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+/// Build a version 1.0 .npy file around `data`
+fn npy_bytes(descr: &str, shape: &str, data: &[u8]) -> Vec<u8> {
+    npy_bytes_with_order(descr, false, shape, data)
+}
+
+fn npy_bytes_with_order(descr: &str, fortran_order: bool, shape: &str, data: &[u8]) -> Vec<u8> {
+    let order = if fortran_order { "True" } else { "False" };
+    let mut dict = format!(
+        "{{'descr': '{}', 'fortran_order': {}, 'shape': {}, }}",
+        descr, order, shape
+    );
+    // Pad so the data starts on a 64-byte boundary, like numpy does
+    while (10 + dict.len() + 1) % 64 != 0 {
+        dict.push(' ');
+    }
+    dict.push('\n');
+
+    let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+    bytes.extend_from_slice(&(dict.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(dict.as_bytes());
+    bytes.extend_from_slice(data);
+    bytes
+}
+
+fn temp_file(name: &str, bytes: &[u8]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("helion_ingest_{}_{}", std::process::id(), name));
+    std::fs::write(&path, bytes).unwrap();
+    path
+}
+
+#[test]
+fn test_parse_npy_header() {
+    let bytes = npy_bytes("<f8", "(2, 3)", &[]);
+    let header = NpyHeader::parse(&bytes).unwrap();
+
+    assert_eq!(header.descr, "<f8");
+    assert!(!header.fortran_order);
+    assert_eq!(header.shape, vec![2, 3]);
+    assert_eq!(header.len(), Ok(6));
+    assert_eq!(header.data_offset % 64, 0);
+    assert_eq!(header.data_offset, bytes.len());
+    assert_eq!(header.dtype(), Ok((Dtype::F64, Endian::Little)));
+}
+
+#[test]
+fn test_parse_npy_header_edge_cases() {
+    // 0-d array holds one element
+    let scalar = NpyHeader::parse(&npy_bytes(">f4", "()", &[])).unwrap();
+    assert_eq!(scalar.len(), Ok(1));
+    assert_eq!(scalar.dtype(), Ok((Dtype::F32, Endian::Big)));
+
+    let ints = NpyHeader::parse(&npy_bytes("|u1", "(4,)", &[])).unwrap();
+    assert_eq!(ints.dtype(), Ok((Dtype::U8, Endian::Little)));
+
+    let complex = NpyHeader::parse(&npy_bytes("<c16", "(4,)", &[])).unwrap();
+    assert!(complex.dtype().is_err());
+
+    // A shape whose element count overflows is an error, not a wrap-around
+    let huge = NpyHeader::parse(&npy_bytes("<f4", "(4294967296, 4294967296, 4)", &[])).unwrap();
+    assert!(huge.len().is_err());
+    assert!(NpyArray::from_bytes(&npy_bytes("<f4", "(4294967296, 4294967296, 4)", &[])).is_err());
+
+    assert!(NpyHeader::parse(b"not a numpy file").is_err());
+    assert!(NpyHeader::parse(b"\x93NUMPY\x01\x00\xff\x00{").is_err());
+}
+
+#[test]
+fn test_decode_f32() {
+    let mut out = Vec::new();
+    decode_f32(&1.5f32.to_le_bytes(), Dtype::F32, Endian::Little, &mut out);
+    decode_f32(&(-2.0f32).to_be_bytes(), Dtype::F32, Endian::Big, &mut out);
+    decode_f32(&0.25f64.to_le_bytes(), Dtype::F64, Endian::Little, &mut out);
+    decode_f32(&1e3f64.to_be_bytes(), Dtype::F64, Endian::Big, &mut out);
+    // A partial trailing element is dropped
+    decode_f32(&[0, 0, 128], Dtype::F32, Endian::Little, &mut out);
+
+    assert_eq!(out, vec![1.5, -2.0, 0.25, 1000.0]);
+}
+
+#[test]
+fn test_decode_integers() {
+    let mut out = Vec::new();
+    decode_f32(&[255, 7], Dtype::U8, Endian::Little, &mut out);
+    decode_f32(&(-3i16).to_be_bytes(), Dtype::I16, Endian::Big, &mut out);
+    decode_f32(&70_000i32.to_le_bytes(), Dtype::I32, Endian::Little, &mut out);
+    decode_f32(&(-5i64).to_le_bytes(), Dtype::I64, Endian::Little, &mut out);
+    decode_f32(&9u64.to_be_bytes(), Dtype::U64, Endian::Big, &mut out);
+
+    assert_eq!(out, vec![255.0, 7.0, -3.0, 70_000.0, -5.0, 9.0]);
+}
+
+#[test]
+fn test_npy_array_from_bytes() {
+    let data: Vec<u8> = [1i32, 2, 3, 4, 5, 6].iter().flat_map(|v| v.to_le_bytes()).collect();
+    let array = NpyArray::from_bytes(&npy_bytes("<i4", "(3, 2)", &data)).unwrap();
+
+    assert_eq!(array.shape, vec![3, 2]);
+    assert_eq!(array.data, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert_eq!(array.column(0), Ok(vec![1.0, 3.0, 5.0]));
+    assert_eq!(array.column(1), Ok(vec![2.0, 4.0, 6.0]));
+    assert!(array.column(2).is_err());
+
+    assert!(NpyArray::from_bytes(&npy_bytes("<i4", "(3, 3)", &data)).is_err());
+}
+
+#[test]
+fn test_fortran_order_is_transposed() {
+    // Column-major storage of [[1, 2, 3], [4, 5, 6]]
+    let data: Vec<u8> = [1.0f64, 4.0, 2.0, 5.0, 3.0, 6.0]
+        .iter()
+        .flat_map(|v| v.to_le_bytes())
+        .collect();
+    let array = NpyArray::from_bytes(&npy_bytes_with_order("<f8", true, "(2, 3)", &data)).unwrap();
+
+    assert_eq!(array.data, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert_eq!(array.column(2), Ok(vec![3.0, 6.0]));
+}
+
+#[test]
+fn test_read_npy_file() {
+    let data: Vec<u8> = [0.5f32, 1.5].iter().flat_map(|v| v.to_le_bytes()).collect();
+    let path = temp_file("read.npy", &npy_bytes("<f4", "(2,)", &data));
+
+    let array = read_npy(&path).unwrap();
+    assert_eq!(array.data, vec![0.5, 1.5]);
+    assert_eq!(array.column(0), Ok(vec![0.5, 1.5]));
+
+    std::fs::remove_file(&path).unwrap();
+    assert!(read_npy(&path).is_err());
+}
+
+#[test]
+fn test_npz_archive() {
+    let x: Vec<u8> = [1.0f32, 2.0, 3.0].iter().flat_map(|v| v.to_le_bytes()).collect();
+    let y: Vec<u8> = [10i64, 20, 30].iter().flat_map(|v| v.to_le_bytes()).collect();
+
+    // np.savez stores members, np.savez_compressed deflates them
+    let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+    let stored = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+    let deflated = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    writer.start_file("x.npy", stored).unwrap();
+    writer.write_all(&npy_bytes("<f4", "(3,)", &x)).unwrap();
+    writer.start_file("y.npy", deflated).unwrap();
+    writer.write_all(&npy_bytes("<i8", "(3,)", &y)).unwrap();
+    let bytes = writer.finish().unwrap().into_inner();
+
+    let mut archive = NpzArchive::new(Cursor::new(bytes)).unwrap();
+    assert_eq!(archive.names(), vec!["x", "y"]);
+    assert_eq!(archive.array("x").unwrap().data, vec![1.0, 2.0, 3.0]);
+    assert_eq!(archive.array("y.npy").unwrap().data, vec![10.0, 20.0, 30.0]);
+    assert!(archive.array("z").is_err());
+
+    assert!(NpzArchive::new(Cursor::new(b"not a zip".to_vec())).is_err());
+}
+
+#[test]
+fn test_mapped_raw_file() {
+    let values: Vec<f64> = (0..10).map(|i| i as f64 * 0.5).collect();
+    let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+    let path = temp_file("raw.bin", &bytes);
+
+    let array = MappedArray::open_raw(&path, Dtype::F64, Endian::Little).unwrap();
+    assert_eq!(array.len(), 10);
+    assert_eq!(array.read(2..5), vec![1.0, 1.5, 2.0]);
+    // Ranges past the end are clamped
+    assert_eq!(array.read(8..100), vec![4.0, 4.5]);
+    assert_eq!(array.bounds(&CancelToken::new()), Ok((0.0, 4.5)));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_mapped_npy_file() {
+    let values = [3.0f32, f32::NAN, -1.0, 7.0, f32::INFINITY];
+    let data: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+    let path = temp_file("array.npy", &npy_bytes("<f4", "(5,)", &data));
+
+    let array = MappedArray::open_npy(&path).unwrap();
+    assert_eq!(array.len(), 5);
+    assert_eq!(array.dtype(), Dtype::F32);
+    assert_eq!(array.read(2..4), vec![-1.0, 7.0]);
+    // NaN and infinities are skipped when finding bounds
+    assert_eq!(array.bounds(&CancelToken::new()), Ok((-1.0, 7.0)));
+
+    let token = CancelToken::new();
+    token.cancel();
+    assert!(array.bounds(&token).is_err());
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_truncated_npy_file_is_rejected() {
+    let path = temp_file("short.npy", &npy_bytes("<f4", "(100,)", &[0; 16]));
+    assert!(MappedArray::open_npy(&path).is_err());
+    std::fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "io")]
+
+use arrow_array::{ArrayRef, Float64Array, Int64Array, RecordBatch, StringArray};
+use helion_core::ingest::csv::read_csv_columns;
+use helion_core::ingest::parquet::read_parquet_columns;
+use helion_core::ChartData;
+use parquet::arrow::ArrowWriter;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("helion_io_{}_{}", std::process::id(), name))
+}
+
+fn positions(data: &ChartData) -> Vec<[f32; 2]> {
+    data.vertices.iter().map(|v| v.position).collect()
+}
+
+#[test]
+fn test_csv_columns_by_name() {
+    let csv = "a,b,c\n1,2,3\n 4 , NA ,6\n7,8\n";
+    let columns = read_csv_columns(csv.as_bytes(), b',', &["c", "b"]).unwrap();
+    // Short rows read as missing cells
+    assert_eq!(columns[0][..2], [3.0, 6.0]);
+    assert!(columns[0][2].is_nan());
+    assert_eq!(columns[1][0], 2.0);
+    assert!(columns[1][1].is_nan());
+    assert_eq!(columns[1][2], 8.0);
+}
+
+#[test]
+fn test_csv_errors() {
+    let missing = read_csv_columns("a,b\n1,2\n".as_bytes(), b',', &["a", "z"]);
+    assert_eq!(missing.unwrap_err(), "CSV has no column 'z'; its columns are [\"a\", \"b\"]");
+    let text = read_csv_columns("a,b\n1,2\n3,four\n".as_bytes(), b',', &["b"]);
+    assert_eq!(text.unwrap_err(), "Row 3, column 'b': 'four' isn't a number");
+}
+
+#[test]
+fn test_from_csv_and_tsv() {
+    let csv = temp_path("prices.csv");
+    std::fs::write(&csv, "time,close\n0,10\n1,\n2,30\n").unwrap();
+    let data = ChartData::from_csv(&csv, "time", "close", None, None, 800.0, 600.0).unwrap();
+    assert_eq!(positions(&data), vec![[-1.0, -1.0], [1.0, 1.0]]);
+    assert_eq!(data.dropped(), &[1]);
+
+    let tsv = temp_path("prices.tsv");
+    std::fs::write(&tsv, "time\tclose\n0\t10\n2\t30\n").unwrap();
+    let data = ChartData::from_csv(&tsv, "time", "close", None, None, 800.0, 600.0).unwrap();
+    assert_eq!(data.vertices.len(), 2);
+
+    let error = ChartData::from_csv(&csv, "time", "open", None, None, 800.0, 600.0).err().unwrap();
+    assert!(error.starts_with(&csv.display().to_string()));
+    assert!(ChartData::from_csv(temp_path("absent.csv"), "x", "y", None, None, 800.0, 600.0).is_err());
+    std::fs::remove_file(csv).unwrap();
+    std::fs::remove_file(tsv).unwrap();
+}
+
+#[test]
+fn test_from_parquet() {
+    let path = temp_path("trips.parquet");
+    let batch = RecordBatch::try_from_iter([
+        ("label", Arc::new(StringArray::from(vec!["a", "b", "c"])) as ArrayRef),
+        ("fare", Arc::new(Float64Array::from(vec![Some(5.0), None, Some(15.0)])) as ArrayRef),
+        ("minute", Arc::new(Int64Array::from(vec![0, 1, 2])) as ArrayRef),
+    ])
+    .unwrap();
+    let mut writer = ArrowWriter::try_new(std::fs::File::create(&path).unwrap(), batch.schema(), None).unwrap();
+    // Two row groups, joined back together on reading
+    writer.write(&batch.slice(0, 2)).unwrap();
+    writer.flush().unwrap();
+    writer.write(&batch.slice(2, 1)).unwrap();
+    writer.close().unwrap();
+
+    // Only the requested columns are read, in file order
+    let read = read_parquet_columns(&path, &["minute", "fare"]).unwrap();
+    assert_eq!(read.num_rows(), 3);
+    let names: Vec<&String> = read.schema_ref().fields().iter().map(|f| f.name()).collect();
+    assert_eq!(names, ["fare", "minute"]);
+
+    let data = ChartData::from_parquet(&path, "minute", "fare", None, None, 800.0, 600.0).unwrap();
+    assert_eq!(positions(&data), vec![[-1.0, -1.0], [1.0, 1.0]]);
+    assert_eq!(data.dropped(), &[1]);
+
+    let error = ChartData::from_parquet(&path, "minute", "tip", None, None, 800.0, 600.0).err().unwrap();
+    assert!(error.ends_with("no column 'tip'; its columns are [\"label\", \"fare\", \"minute\"]"));
+    let error = ChartData::from_parquet(&path, "minute", "label", None, None, 800.0, 600.0).err().unwrap();
+    assert!(error.ends_with("Column 'label': type Utf8 can't be plotted"));
+    std::fs::remove_file(path).unwrap();
+}
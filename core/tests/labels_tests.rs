@@ -0,0 +1,83 @@
+use helion_core::data::{ChartData, Color, Point2D};
+use helion_core::labels::{layout_labels, place_labels, value_labels, LabelOptions, LabelPlacement, LabelTarget};
+use helion_core::text::{HAlign, VAlign};
+
+fn chart_with_points(points: &[(f32, f32)]) -> ChartData {
+    let mut data = ChartData::new(200.0, 100.0);
+    for &(x, y) in points {
+        data.add_point(Point2D::new(x, y), Color::default(), 2.0);
+    }
+    data
+}
+
+#[test]
+fn test_value_labels_formatting() {
+    assert_eq!(value_labels(&[1.0, 2.345], 1), vec!["1.0", "2.3"]);
+}
+
+#[test]
+fn test_label_placed_right_of_point() {
+    let data = chart_with_points(&[(0.0, 0.0)]);
+    let labels = layout_labels(&data, &value_labels(&[5.0], 0), &LabelOptions::default());
+
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels[0].placement, LabelPlacement::Right);
+    // Center of a 200x100 viewport, offset by 4px
+    assert_eq!(labels[0].x, 104.0);
+    assert_eq!(labels[0].y, 44.0);
+}
+
+#[test]
+fn test_overlapping_labels_move_or_drop() {
+    // Three points at the same spot: right, above, then left
+    let data = chart_with_points(&[(0.0, 0.0), (0.0, 0.0), (0.0, 0.0)]);
+    let texts = value_labels(&[1.0, 2.0, 3.0], 0);
+    let labels = layout_labels(&data, &texts, &LabelOptions::default());
+
+    let placements: Vec<_> = labels.iter().map(|l| l.placement).collect();
+    assert_eq!(
+        placements,
+        vec![LabelPlacement::Right, LabelPlacement::Above, LabelPlacement::Left]
+    );
+    for (i, a) in labels.iter().enumerate() {
+        for b in &labels[i + 1..] {
+            let disjoint = a.x + a.width <= b.x
+                || b.x + b.width <= a.x
+                || a.y + a.height <= b.y
+                || b.y + b.height <= a.y;
+            assert!(disjoint, "labels {} and {} overlap", a.index, b.index);
+        }
+    }
+}
+
+#[test]
+fn test_labels_stay_inside_viewport() {
+    // Point at the right edge: the label must go left instead
+    let data = chart_with_points(&[(1.0, 0.0)]);
+    let labels = layout_labels(&data, &value_labels(&[7.0], 0), &LabelOptions::default());
+    assert_eq!(labels[0].placement, LabelPlacement::Left);
+}
+
+#[test]
+fn test_labels_skipped_for_large_datasets() {
+    let data = chart_with_points(&[(0.0, 0.0), (0.5, 0.5)]);
+    let options = LabelOptions {
+        max_labels: 1,
+        ..Default::default()
+    };
+    assert!(layout_labels(&data, &value_labels(&[1.0, 2.0], 0), &options).is_empty());
+}
+
+#[test]
+fn test_bar_labels_fall_back_inside_the_bar() {
+    // A bar reaching the top of the viewport has no room above it
+    let bars = [LabelTarget::BarEnd { position: [50.0, 2.0], down: false }];
+    let labels = place_labels(&bars, &value_labels(&[9.0], 0), &LabelOptions::default(), 200.0, 100.0);
+    assert_eq!(labels[0].placement, LabelPlacement::Below);
+    assert_eq!(labels[0].y, 6.0);
+
+    let section = labels[0].section(Color::new(0.0, 0.0, 0.0, 1.0));
+    assert_eq!(section.position, [labels[0].x, labels[0].y]);
+    assert_eq!((section.h_align, section.v_align), (HAlign::Left, VAlign::Top));
+    assert_eq!(section.size, LabelOptions::default().font_size);
+}
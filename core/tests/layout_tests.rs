@@ -0,0 +1,131 @@
+use helion_core::data::{ChartData, Color, ScatterTransform};
+use helion_core::layout::{data_extent, CellRect, InsetAxes, ShareAxis, SubplotGrid};
+use helion_core::renderer::ScissorRect;
+
+#[test]
+fn test_cell_rects_tile_the_figure() {
+    let grid = SubplotGrid::new(2, 2);
+    let first = grid.cell_rect(0, 808.0, 608.0);
+    let last = grid.cell_rect(3, 808.0, 608.0);
+
+    assert_eq!(first.x, 0.0);
+    assert_eq!(first.width, 400.0);
+    assert_eq!(first.height, 300.0);
+    assert_eq!(last.x, 408.0);
+    assert_eq!(last.y, 308.0);
+}
+
+#[test]
+fn test_unshared_axes_use_full_range() {
+    let grid = SubplotGrid::new(1, 2);
+    let ranges = grid.output_ranges(&[((0.0, 10.0), (0.0, 1.0)), ((0.0, 5.0), (0.0, 2.0))]);
+    assert_eq!(ranges[0], ((-1.0, 1.0), (-1.0, 1.0)));
+    assert_eq!(ranges[1], ((-1.0, 1.0), (-1.0, 1.0)));
+}
+
+#[test]
+fn test_sharex_aligns_panels() {
+    let grid = SubplotGrid::new(2, 1).with_sharex(ShareAxis::All);
+    let ranges = grid.output_ranges(&[((0.0, 10.0), (0.0, 1.0)), ((5.0, 10.0), (0.0, 2.0))]);
+
+    assert_eq!(ranges[0].0, (-1.0, 1.0));
+    assert_eq!(ranges[1].0, (0.0, 1.0));
+    // y is not shared
+    assert_eq!(ranges[1].1, (-1.0, 1.0));
+}
+
+#[test]
+fn test_share_by_row_and_column() {
+    let extents = [
+        ((0.0, 1.0), (0.0, 1.0)),
+        ((0.0, 2.0), (0.0, 4.0)),
+        ((0.0, 4.0), (0.0, 2.0)),
+        ((0.0, 1.0), (0.0, 1.0)),
+    ];
+    let grid = SubplotGrid::new(2, 2)
+        .with_sharex(ShareAxis::Col)
+        .with_sharey(ShareAxis::Row);
+    let ranges = grid.output_ranges(&extents);
+
+    // Column 0 shares x over [0, 4]; row 0 shares y over [0, 4]
+    assert_eq!(ranges[0].0, (-1.0, -0.5));
+    assert_eq!(ranges[0].1, (-1.0, -0.5));
+    // Column 1 shares x over [0, 2]; row 1 shares y over [0, 2]
+    assert_eq!(ranges[3].0, (-1.0, 0.0));
+    assert_eq!(ranges[3].1, (-1.0, 0.0));
+}
+
+#[test]
+fn test_inverted_axes() {
+    let x = vec![0.0, 10.0];
+    let y = vec![0.0, 10.0];
+    let grid = SubplotGrid::new(1, 1).invert_xaxis(true).invert_yaxis(true);
+    let ranges = grid.output_ranges(&[(data_extent(&x), data_extent(&y))]);
+
+    let data = ChartData::from_scatter_with_range(
+        &x, &y, None, None, 800.0, 600.0, Some(ranges[0].0), Some(ranges[0].1),
+    );
+    assert_eq!(data.vertices[0].position, [1.0, 1.0]);
+    assert_eq!(data.vertices[1].position, [-1.0, -1.0]);
+}
+
+#[test]
+fn test_height_ratios() {
+    let grid = SubplotGrid::new(2, 1).with_height_ratios(vec![3.0, 1.0]);
+    let top = grid.cell_rect(0, 800.0, 408.0);
+    let bottom = grid.cell_rect(1, 800.0, 408.0);
+
+    assert_eq!(top.height, 300.0);
+    assert_eq!(bottom.y, 308.0);
+    assert_eq!(bottom.height, 100.0);
+
+    // Wrong number of ratios falls back to equal rows
+    let grid = SubplotGrid::new(2, 1).with_height_ratios(vec![3.0]);
+    assert_eq!(grid.cell_rect(0, 800.0, 408.0).height, 200.0);
+}
+
+#[test]
+fn test_inset_follows_parent_cell() {
+    let grid = SubplotGrid::new(1, 2);
+    let parent = grid.cell_rect(1, 808.0, 600.0);
+    let inset = InsetAxes::new([0.5, 0.5, 0.5, 0.5], (0.0, 1.0), (0.0, 1.0));
+    let rect = inset.rect(parent);
+    assert_eq!((rect.x, rect.y, rect.width, rect.height), (608.0, 300.0, 200.0, 300.0));
+    assert_eq!(inset.scissor(parent), ScissorRect::new(608, 300, 200, 300));
+}
+
+#[test]
+fn test_inset_scissor_rounds_to_pixels() {
+    let parent = CellRect { x: 0.0, y: 0.0, width: 101.0, height: 101.0 };
+    let inset = InsetAxes::new([0.1, -0.1, 0.3, 0.3], (0.0, 1.0), (0.0, 1.0));
+    // 10.1..40.4 across, and the part above the parent is cut off
+    assert_eq!(inset.scissor(parent), ScissorRect::new(10, 0, 30, 20));
+}
+
+#[test]
+fn test_inset_zooms_to_limits() {
+    let inset = InsetAxes::new([0.0, 0.0, 0.5, 0.5], (2.0, 4.0), (10.0, 20.0));
+    let (x_range, y_range) = inset.output_ranges(((0.0, 8.0), (10.0, 30.0)));
+    assert_eq!(x_range, (-3.0, 5.0));
+    assert_eq!(y_range, (-1.0, 3.0));
+
+    let data = ChartData::from_scatter_with_range(
+        &[0.0, 3.0, 8.0], &[10.0, 15.0, 30.0], None, None, 400.0, 300.0, Some(x_range), Some(y_range),
+    );
+    assert_eq!(data.vertices[1].position, [0.0, 0.0]);
+}
+
+#[test]
+fn test_inset_zoom_outline_on_parent() {
+    let parent = CellRect { x: 0.0, y: 0.0, width: 200.0, height: 100.0 };
+    let transform = ScatterTransform::new((0.0, 10.0), (0.0, 10.0), None, None);
+    let inset = InsetAxes::new([0.6, 0.0, 0.4, 0.4], (2.5, 7.5), (5.0, 10.0))
+        .with_background(Color::new(0.9, 0.9, 0.9, 1.0));
+    let outline = inset.zoom_outline(&transform, parent, Color::new(0.0, 0.0, 0.0, 1.0), 2.0);
+    // Bottom edge: from x = -0.5 to 0.5 at y = 0, 2 px thick
+    let xs: Vec<f32> = outline.vertices[..6].iter().map(|v| v.position[0]).collect();
+    let ys: Vec<f32> = outline.vertices[..6].iter().map(|v| v.position[1]).collect();
+    assert_eq!(xs.iter().cloned().fold(f32::INFINITY, f32::min), -0.51);
+    assert_eq!(ys.iter().cloned().fold(f32::NEG_INFINITY, f32::max), 0.02);
+    assert_eq!(inset.background_data(parent).vertices[0].color, [0.9, 0.9, 0.9, 1.0]);
+}
@@ -0,0 +1,148 @@
+use helion_core::data::{ChartData, Color};
+use helion_core::text::VAlign;
+use helion_core::legend::{faded, Legend, LegendItemRect, LegendLocation, LegendOptions, DIMMED_ALPHA};
+
+fn two_series() -> Legend {
+    let mut legend = Legend::new(400.0, 300.0);
+    legend.add("train", Color::new(0.0, 0.0, 1.0, 1.0));
+    legend.add("validation", Color::new(1.0, 0.5, 0.0, 1.0));
+    legend
+}
+
+#[test]
+fn test_layout_upper_right() {
+    let items = two_series().layout();
+    // Widest label is 10 chars * 7.2px, after a 10px swatch and 6px gap
+    assert_eq!(
+        items[0],
+        LegendItemRect {
+            index: 0,
+            x: 296.0,
+            y: 16.0,
+            width: 88.0,
+            height: 12.0,
+        }
+    );
+    assert_eq!((items[1].x, items[1].y), (296.0, 32.0));
+}
+
+#[test]
+fn test_layout_follows_location_and_resize() {
+    let mut legend = two_series().with_location(LegendLocation::LowerLeft);
+    let items = legend.layout();
+    assert_eq!(items[0].x, 16.0);
+    // Box is 2 rows + 1 gap + padding = 40px tall, 10px above the bottom
+    assert_eq!(items[0].y, 300.0 - 10.0 - 40.0 + 6.0);
+
+    legend.resize(400.0, 600.0);
+    assert_eq!(legend.layout()[0].y, 600.0 - 10.0 - 40.0 + 6.0);
+}
+
+#[test]
+fn test_click_toggles_visibility() {
+    let mut legend = two_series();
+    let item = legend.layout()[0];
+    assert_eq!(legend.click(item.x + 2.0, item.y + 2.0), Some(0));
+    assert!(!legend.is_visible(0));
+    assert_eq!(legend.series_alpha(0), 0.0);
+    assert_eq!(legend.series_alpha(1), 1.0);
+
+    assert_eq!(legend.click(item.x + 2.0, item.y + 2.0), Some(0));
+    assert!(legend.is_visible(0));
+
+    // Clicks outside the legend belong to the plot
+    assert_eq!(legend.click(5.0, 5.0), None);
+}
+
+#[test]
+fn test_hover_dims_other_series() {
+    let mut legend = two_series();
+    let item = legend.layout()[1];
+    assert!(legend.pointer_moved(item.x + 1.0, item.y + 1.0));
+    assert_eq!(legend.hovered(), Some(1));
+    assert_eq!(legend.series_alpha(0), DIMMED_ALPHA);
+    assert_eq!(legend.series_alpha(1), 1.0);
+
+    // Moving within the same entry doesn't need a redraw
+    assert!(!legend.pointer_moved(item.x + 5.0, item.y + 1.0));
+
+    assert!(legend.pointer_left());
+    assert_eq!(legend.series_alpha(0), 1.0);
+    assert!(!legend.pointer_left());
+}
+
+#[test]
+fn test_faded_scales_point_alpha() {
+    let color = Color::new(1.0, 0.0, 0.0, 0.8);
+    let data = ChartData::from_scatter(&[0.0, 1.0], &[0.0, 1.0], Some(color), None, 100.0, 100.0);
+    let dimmed = faded(&data, DIMMED_ALPHA);
+
+    assert_eq!(dimmed.vertices[1].color, [1.0, 0.0, 0.0, 0.8 * DIMMED_ALPHA]);
+    assert_eq!(dimmed.vertices[1].position, data.vertices[1].position);
+    assert!(dimmed.append_log().is_none());
+    assert_eq!(data.vertices[1].color[3], 0.8);
+}
+
+#[test]
+fn test_hidden_series_stay_hidden_while_hovering() {
+    let mut legend = two_series();
+    legend.set_visible(0, false);
+    let item = legend.layout()[0];
+    legend.pointer_moved(item.x + 1.0, item.y + 1.0);
+    assert_eq!(legend.series_alpha(0), 0.0);
+    assert_eq!(legend.series_alpha(1), DIMMED_ALPHA);
+}
+
+#[test]
+fn test_empty_legend_and_unknown_index() {
+    let mut legend = Legend::new(100.0, 100.0).with_options(LegendOptions::default());
+    assert!(legend.layout().is_empty());
+    assert_eq!(legend.hit_test(90.0, 15.0), None);
+    assert!(!legend.is_visible(3));
+    assert_eq!(legend.series_alpha(3), 0.0);
+    legend.set_visible(3, true);
+    assert!(legend.entries().is_empty());
+}
+
+#[test]
+fn test_location_from_name() {
+    assert_eq!(LegendLocation::from_name("upper right"), Some(LegendLocation::UpperRight));
+    assert_eq!(LegendLocation::from_name("Lower_Left"), Some(LegendLocation::LowerLeft));
+    assert_eq!(LegendLocation::from_name("upper-left"), Some(LegendLocation::UpperLeft));
+    assert_eq!(LegendLocation::from_name("center"), None);
+}
+
+#[test]
+fn test_quad_data_draws_box_and_swatches() {
+    let mut legend = two_series();
+    let data = legend.quad_data();
+    // Box plus one swatch per entry, two triangles each
+    assert_eq!(data.vertices.len(), 3 * 6);
+
+    // The box spans pixels 290..390 x 10..50 of the 400 x 300 viewport
+    let close = |a: [f32; 2], b: [f32; 2]| (a[0] - b[0]).abs() < 1e-5 && (a[1] - b[1]).abs() < 1e-5;
+    assert!(close(data.vertices[0].position, [0.45, 1.0 - 100.0 / 300.0]));
+    assert!(close(data.vertices[2].position, [0.95, 1.0 - 20.0 / 300.0]));
+    assert_eq!(data.vertices[6].color, [0.0, 0.0, 1.0, 1.0]);
+
+    let item = legend.layout()[1];
+    legend.click(item.x + 1.0, item.y + 1.0);
+    assert_eq!(legend.quad_data().vertices[12].color[3], DIMMED_ALPHA);
+
+    assert!(Legend::new(100.0, 100.0).quad_data().vertices.is_empty());
+}
+
+#[test]
+fn test_text_sections_follow_swatches() {
+    let mut legend = two_series();
+    let sections = legend.text_sections();
+    assert_eq!(sections.len(), 2);
+    assert_eq!(sections[1].text, "validation");
+    // 6px right of the 10px swatch, centered on the row
+    assert_eq!(sections[0].position, [296.0 + 16.0, 22.0]);
+    assert_eq!(sections[0].v_align, VAlign::Middle);
+
+    let item = legend.layout()[0];
+    legend.click(item.x + 1.0, item.y + 1.0);
+    assert_eq!(legend.text_sections()[0].color.a, DIMMED_ALPHA);
+}
@@ -0,0 +1,33 @@
+use helion_core::data::{ChartData, Color};
+use helion_core::line::LineRenderer;
+
+#[test]
+fn test_line_renderer_struct_size() {
+    let size = std::mem::size_of::<LineRenderer>();
+    assert!(size < 1024, "LineRenderer is unexpectedly large: {} bytes", size);
+}
+
+#[test]
+fn test_from_line_matches_scatter_normalization() {
+    let x = [0.0, 1.0, 2.0, 3.0];
+    let y = [10.0, -10.0, 5.0, 0.0];
+    let color = Color::new(0.0, 0.5, 1.0, 1.0);
+
+    let line = ChartData::from_line(&x, &y, Some(color), 800.0, 600.0);
+    let scatter = ChartData::from_scatter(&x, &y, Some(color), None, 800.0, 600.0);
+
+    assert_eq!(line.vertices.len(), 4);
+    for (l, s) in line.vertices.iter().zip(&scatter.vertices) {
+        assert_eq!(l.position, s.position);
+        assert_eq!(l.color, s.color);
+        assert_eq!(l.size, 1.0);
+    }
+}
+
+#[test]
+fn test_from_line_keeps_drawing_order() {
+    // Unsorted x is drawn as given, doubling back on itself
+    let line = ChartData::from_line(&[0.0, 2.0, 1.0], &[0.0, 0.0, 0.0], None, 800.0, 600.0);
+    let xs: Vec<f32> = line.vertices.iter().map(|v| v.position[0]).collect();
+    assert_eq!(xs, vec![-1.0, 1.0, 0.0]);
+}
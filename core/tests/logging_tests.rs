@@ -0,0 +1,25 @@
+use helion_core::logging::{init, parse_log_level, set_log_level};
+
+#[test]
+fn test_parse_log_level() {
+    assert_eq!(parse_log_level("off"), Ok(log::LevelFilter::Off));
+    assert_eq!(parse_log_level(" Warning "), Ok(log::LevelFilter::Warn));
+    assert_eq!(parse_log_level("TRACE"), Ok(log::LevelFilter::Trace));
+
+    let err = parse_log_level("verbose").unwrap_err();
+    assert!(err.contains("'verbose'"), "{}", err);
+}
+
+#[test]
+fn test_init_is_idempotent_and_level_is_adjustable() {
+    init();
+    init();
+
+    set_log_level("error").unwrap();
+    assert_eq!(log::max_level(), log::LevelFilter::Error);
+    set_log_level("off").unwrap();
+    assert_eq!(log::max_level(), log::LevelFilter::Off);
+
+    assert!(set_log_level("chatty").is_err());
+    assert_eq!(log::max_level(), log::LevelFilter::Off);
+}
@@ -0,0 +1,140 @@
+use helion_core::aggregate::counts_as_values;
+use helion_core::colormap::{ColormapLut, LUT_SIZE};
+use helion_core::norm::{Normalization, EQ_HIST_QUANTILES};
+
+#[test]
+fn test_linear_matches_lut_sample() {
+    let values = [0.0, 2.5, 5.0, 10.0, -3.0];
+    let norm = Normalization::Linear.fit(&values, Some((0.0, 10.0)));
+    assert_eq!(norm.apply(&values), vec![0.0, 0.25, 0.5, 1.0, 0.0]);
+
+    let lut = ColormapLut::viridis();
+    let colors = lut.colorize(&values, &norm);
+    for (color, &v) in colors.iter().zip(&values) {
+        assert_eq!(*color, lut.sample(v, 0.0, 10.0));
+    }
+}
+
+#[test]
+fn test_range_defaults_to_finite_extent() {
+    let norm = Normalization::Linear.fit(&[f32::NAN, 2.0, f32::INFINITY, 6.0], None);
+    assert_eq!((norm.vmin(), norm.vmax()), (2.0, 6.0));
+    assert!(norm.normalize(f32::NAN).is_nan());
+
+    // Nothing usable: a unit range rather than an inverted one
+    let empty = Normalization::Linear.fit(&[], None);
+    assert_eq!((empty.vmin(), empty.vmax()), (0.0, 1.0));
+}
+
+#[test]
+fn test_partial_range_takes_other_end_from_data() {
+    let values = [f32::NAN, 2.0, 6.0];
+    let norm = Normalization::Linear.fit_range(&values, Some(0.0), None);
+    assert_eq!((norm.vmin(), norm.vmax()), (0.0, 6.0));
+    let norm = Normalization::Log.fit_range(&[-1.0, 10.0, 100.0], None, Some(1000.0));
+    assert_eq!((norm.vmin(), norm.vmax()), (10.0, 1000.0));
+    assert_eq!(Normalization::Linear.fit_range(&values, None, None), Normalization::Linear.fit(&values, None));
+}
+
+#[test]
+fn test_from_name() {
+    assert_eq!(Normalization::from_name("Log"), Some(Normalization::Log));
+    assert_eq!(Normalization::from_name("sqrt"), Some(Normalization::Power { gamma: 0.5 }));
+    assert_eq!(Normalization::from_name("eq_hist"), Some(Normalization::EqHist));
+    assert_eq!(Normalization::from_name("logarithmic"), None);
+}
+
+#[test]
+fn test_log_skips_non_positive() {
+    let norm = Normalization::Log.fit(&[-1.0, 0.0, 1.0, 1000.0], None);
+    assert_eq!((norm.vmin(), norm.vmax()), (1.0, 1000.0));
+    assert!(norm.normalize(0.0).is_nan());
+    assert!(norm.normalize(-5.0).is_nan());
+    assert!((norm.normalize(10.0) - 1.0 / 3.0).abs() < 1e-6);
+
+    // An explicit range that reaches zero can't be log-scaled
+    assert!(Normalization::Log.fit(&[], Some((0.0, 10.0))).normalize(5.0).is_nan());
+}
+
+#[test]
+fn test_power_gamma() {
+    let norm = Normalization::Power { gamma: 0.5 }.fit(&[0.0, 100.0], None);
+    assert_eq!(norm.normalize(25.0), 0.5);
+    assert_eq!(norm.normalize(100.0), 1.0);
+    let linear = Normalization::Power { gamma: 1.0 }.fit(&[0.0, 100.0], None);
+    assert_eq!(linear.normalize(25.0), 0.25);
+}
+
+#[test]
+fn test_eq_hist_spreads_skewed_data() {
+    // Mostly small values with one huge outlier: linear crushes them together
+    let values: Vec<f32> = (1..=99).map(|i| i as f32).chain([1.0e6]).collect();
+    let linear = Normalization::Linear.fit(&values, None);
+    assert!(linear.normalize(99.0) < 0.001);
+
+    let eq = Normalization::EqHist.fit(&values, None);
+    assert_eq!(eq.normalize(1.0), 0.0);
+    assert!((eq.normalize(50.0) - 49.0 / 99.0).abs() < 1e-6);
+    assert_eq!(eq.normalize(1.0e6), 1.0);
+
+    // Output is monotonic in the input
+    let t = eq.apply(&values);
+    assert!(t.windows(2).all(|w| w[0] <= w[1]));
+}
+
+#[test]
+fn test_eq_hist_large_input_is_summarized() {
+    let values: Vec<f32> = (0..100_000).map(|i| (i as f32).powi(2)).collect();
+    let eq = Normalization::EqHist.fit(&values, None);
+    let mid = eq.normalize(50_000.0f32.powi(2));
+    assert!((mid - 0.5).abs() < 2.0 / EQ_HIST_QUANTILES as f32, "{}", mid);
+}
+
+#[test]
+fn test_eq_hist_constant_data() {
+    let eq = Normalization::EqHist.fit(&[3.0; 10], None);
+    assert_eq!(eq.normalize(3.0), 0.5);
+}
+
+#[test]
+fn test_empty_cells_are_transparent() {
+    let values = counts_as_values(&[0, 1, 100]);
+    let norm = Normalization::EqHist.fit(&values, None);
+    let colors = ColormapLut::grayscale().colorize(&values, &norm);
+    assert_eq!(colors[0], [0, 0, 0, 0]);
+    assert_eq!(colors[1], ColormapLut::grayscale().colors[0]);
+    assert_eq!(colors[2], ColormapLut::grayscale().colors[LUT_SIZE - 1]);
+}
+
+#[test]
+fn test_center_splits_asymmetric_range() {
+    let values = [-2.0, -1.0, 0.0, 5.0, 10.0];
+    let norm = Normalization::Linear.fit(&values, None).with_center(0.0);
+    assert_eq!(norm.center(), Some(0.0));
+    assert_eq!(norm.apply(&values), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+
+    // Zero lands on the diverging map's neutral middle
+    let lut = ColormapLut::coolwarm();
+    let mid = lut.colorize(&[0.0], &norm)[0];
+    assert_eq!(mid, lut.sample(0.5, 0.0, 1.0));
+    assert!(mid[0].abs_diff(mid[2]) < 8);
+}
+
+#[test]
+fn test_center_outside_range_widens_it() {
+    let norm = Normalization::Linear.fit(&[1.0, 3.0], None).with_center(0.0);
+    assert_eq!((norm.vmin(), norm.vmax()), (0.0, 3.0));
+    assert_eq!(norm.normalize(0.0), 0.5);
+    assert_eq!(norm.normalize(3.0), 1.0);
+}
+
+#[test]
+fn test_center_with_other_normalizations() {
+    let log = Normalization::Log.fit(&[0.01, 1000.0], None).with_center(1.0);
+    assert_eq!(log.normalize(1.0), 0.5);
+    assert!((log.normalize(0.1) - 0.25).abs() < 1e-6);
+
+    let eq = Normalization::EqHist.fit(&[-3.0, -2.0, -1.0, 1.0, 100.0], None).with_center(0.0);
+    assert_eq!(eq.normalize(0.0), 0.5);
+    assert!(eq.normalize(-1.0) < 0.5 && eq.normalize(1.0) > 0.5);
+}
@@ -0,0 +1,96 @@
+use helion_core::layout::CellRect;
+use helion_core::overview::{BrushHandle, OverviewDetail};
+use helion_core::timeseries::decimate_minmax;
+use helion_core::data::Color;
+
+fn view() -> OverviewDetail {
+    let x: Vec<f32> = (0..=100).map(|i| i as f32).collect();
+    OverviewDetail::new(&x, 200.0)
+}
+
+#[test]
+fn test_starts_showing_everything() {
+    assert_eq!(view().detail_range(), (0.0, 100.0));
+}
+
+#[test]
+fn test_set_window_clamps_to_extent() {
+    let mut v = view();
+    v.set_window(90.0, 130.0);
+    assert_eq!(v.detail_range(), (60.0, 100.0));
+}
+
+#[test]
+fn test_hit_test() {
+    let mut v = view();
+    v.set_window(25.0, 50.0); // pixels 50..100
+    assert_eq!(v.hit_test(51.0), Some(BrushHandle::Left));
+    assert_eq!(v.hit_test(98.0), Some(BrushHandle::Right));
+    assert_eq!(v.hit_test(75.0), Some(BrushHandle::Body));
+    assert_eq!(v.hit_test(150.0), None);
+}
+
+#[test]
+fn test_drag_edges_resize() {
+    let mut v = view();
+    v.set_window(25.0, 50.0);
+
+    v.pointer_down(100.0);
+    assert!(v.pointer_move(160.0));
+    v.pointer_up();
+    assert_eq!(v.detail_range(), (25.0, 80.0));
+
+    // Left edge can't cross the right edge
+    v.pointer_down(50.0);
+    v.pointer_move(400.0);
+    v.pointer_up();
+    assert!(v.detail_range().0 < 80.0);
+    assert!(!v.is_dragging());
+}
+
+#[test]
+fn test_click_outside_recenters() {
+    let mut v = view();
+    v.set_window(0.0, 10.0);
+    v.pointer_down(100.0);
+    assert_eq!(v.detail_range(), (45.0, 55.0));
+    v.pointer_up();
+}
+
+#[test]
+fn test_body_drag_stops_at_edge() {
+    let mut v = view();
+    v.set_window(0.0, 20.0);
+    v.pointer_down(20.0);
+    v.pointer_move(1000.0);
+    assert_eq!(v.detail_range(), (80.0, 100.0));
+}
+
+#[test]
+fn test_window_overlay() {
+    let mut v = view();
+    v.set_window(25.0, 75.0);
+    let rect = CellRect { x: 0.0, y: 400.0, width: 200.0, height: 100.0 };
+    let overlay = v.window_overlay(Color::new(0.0, 0.0, 1.0, 0.2), rect);
+    assert_eq!(overlay.vertices.len(), 6);
+    assert_eq!(overlay.vertices[0].position, [-0.5, -1.0]);
+    assert_eq!(overlay.vertices[2].position, [0.5, 1.0]);
+}
+
+#[test]
+fn test_minmax_decimation_keeps_spikes() {
+    let x: Vec<f32> = (0..1000).map(|i| i as f32).collect();
+    let mut y = vec![0.0; 1000];
+    y[537] = 100.0;
+    y[812] = -50.0;
+
+    let (dx, dy) = decimate_minmax(&x, &y, 10);
+    assert!(dx.len() <= 20);
+    assert!(dy.contains(&100.0) && dy.contains(&-50.0));
+    // Order is preserved
+    assert!(dx.windows(2).all(|w| w[0] < w[1]));
+
+    // Short series pass through untouched
+    let (sx, _) = decimate_minmax(&x[..10], &y[..10], 10);
+    assert_eq!(sx.len(), 10);
+}
@@ -0,0 +1,88 @@
+use helion_core::aggregate::{aggregate_counts, BinGrid};
+use helion_core::cancel::CancelToken;
+use helion_core::data::ChartData;
+use helion_core::parallel::{count_bins, extend_mapped, finite_min_max, finite_min_max_f64, min_max, MIN_PARALLEL_LEN};
+
+/// Deterministic spread of values large enough to take the parallel path
+fn samples(len: usize, seed: u32) -> Vec<f32> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            (state >> 8) as f32 / (1 << 24) as f32 * 200.0 - 100.0
+        })
+        .collect()
+}
+
+#[test]
+fn test_min_max_matches_sequential() {
+    let mut values = samples(MIN_PARALLEL_LEN * 8 + 17, 1);
+    values[MIN_PARALLEL_LEN * 5] = f32::NAN;
+    values[3] = -250.0;
+    values[MIN_PARALLEL_LEN * 7] = 300.0;
+
+    assert_eq!(min_max(&values), (-250.0, 300.0));
+    values[MIN_PARALLEL_LEN * 6] = f32::INFINITY;
+    assert_eq!(finite_min_max(&values), (-250.0, 300.0));
+
+    let mut wide: Vec<f64> = values.iter().map(|&v| v as f64 + 1e12).collect();
+    wide[4] = 1e12 - 250.5;
+    assert_eq!(finite_min_max_f64(&wide), (1e12 - 250.5, 1e12 + 300.0));
+}
+
+#[test]
+fn test_extend_mapped_keeps_order() {
+    let mut out = vec![usize::MAX];
+    let len = MIN_PARALLEL_LEN * 3 + 5;
+    extend_mapped(&mut out, 10..10 + len, |i| i * 2);
+
+    assert_eq!(out.len(), len + 1);
+    assert!(out[1..].iter().enumerate().all(|(k, &v)| v == (k + 10) * 2));
+}
+
+#[test]
+fn test_count_bins_matches_sequential() {
+    let len = MIN_PARALLEL_LEN * 6 + 3;
+    let counts = count_bins(len, 7, |i| if i % 5 == 0 { None } else { Some(i % 7) });
+
+    let mut expected = vec![0u32; 7];
+    for i in (0..len).filter(|i| i % 5 != 0) {
+        expected[i % 7] += 1;
+    }
+    assert_eq!(counts, expected);
+}
+
+#[test]
+fn test_aggregate_counts_matches_sequential() {
+    let x = samples(MIN_PARALLEL_LEN * 4, 2);
+    let y = samples(MIN_PARALLEL_LEN * 4, 3);
+    let grid = BinGrid::new((-50.0, 50.0), (-50.0, 50.0), 16, 12);
+
+    let mut expected = vec![0u32; grid.len()];
+    for (&px, &py) in x.iter().zip(&y) {
+        if let Some(i) = grid.cell_index(px, py) {
+            expected[i] += 1;
+        }
+    }
+    assert_eq!(aggregate_counts(&x, &y, &grid), expected);
+}
+
+#[test]
+fn test_normalization_matches_sequential() {
+    let x = samples(MIN_PARALLEL_LEN * 5 + 1, 4);
+    let y = samples(MIN_PARALLEL_LEN * 5 + 1, 5);
+    let mut data = ChartData::new(100.0, 100.0);
+    data.fill_scatter_cancelable(&x, &y, None, None, None, None, &CancelToken::new(), &mut |_| {})
+        .unwrap();
+
+    let (x_min, x_max) = x.iter().fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+    let (y_min, y_max) = y.iter().fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+    assert_eq!(data.vertices.len(), x.len());
+    for (i, vertex) in data.vertices.iter().enumerate() {
+        let expected = [
+            ((x[i] - x_min) / (x_max - x_min)) * 2.0 - 1.0,
+            ((y[i] - y_min) / (y_max - y_min)) * 2.0 - 1.0,
+        ];
+        assert_eq!(vertex.position, expected, "vertex {}", i);
+    }
+}
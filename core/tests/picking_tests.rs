@@ -0,0 +1,64 @@
+use helion_core::camera::Camera2D;
+use helion_core::data::ChartData;
+use helion_core::picking::{pick_nearest, PickIds, PickInstance};
+
+fn data() -> ChartData {
+    // Points at pixels (0, 200), (100, 100) and (200, 0)
+    ChartData::from_scatter(&[0.0, 1.0, 2.0], &[0.0, 1.0, 2.0], None, Some(10.0), 200.0, 200.0)
+}
+
+#[test]
+fn test_picks_closest_point_within_marker() {
+    let data = data();
+    let camera = Camera2D::identity();
+    assert_eq!(pick_nearest(&data, &camera, 103.0, 98.0, 0.0), Some(1));
+    // Just outside the 5 px marker radius, but within the slack
+    assert_eq!(pick_nearest(&data, &camera, 107.0, 100.0, 0.0), None);
+    assert_eq!(pick_nearest(&data, &camera, 107.0, 100.0, 3.0), Some(1));
+}
+
+#[test]
+fn test_picking_follows_camera() {
+    let data = data();
+    let mut camera = Camera2D::identity();
+    // Pan right by half the viewport: the middle point moves to pixel (150, 100)
+    camera.pan([0.5, 0.0]);
+    assert_eq!(pick_nearest(&data, &camera, 100.0, 100.0, 2.0), None);
+    assert_eq!(pick_nearest(&data, &camera, 150.0, 100.0, 2.0), Some(1));
+}
+
+#[test]
+fn test_empty_data_picks_nothing() {
+    let data = ChartData::new(100.0, 100.0);
+    assert_eq!(pick_nearest(&data, &Camera2D::identity(), 50.0, 50.0, 100.0), None);
+}
+
+#[test]
+fn test_pick_ids_follow_drawing_order() {
+    let mut ids = PickIds::default();
+    assert_eq!(ids.set_len(2, 3), 0);
+    assert_eq!(ids.set_len(0, 2), 1);
+    assert_eq!(ids.first_id(0), 0);
+    assert_eq!(ids.first_id(1), 3);
+    assert_eq!(ids.decode(0), None);
+    assert_eq!(ids.decode(1), Some((2, 0)));
+    assert_eq!(ids.decode(3), Some((2, 2)));
+    assert_eq!(ids.decode(4), Some((0, 0)));
+    assert_eq!(ids.decode(6), None);
+
+    // Resizing a series keeps its slot and shifts the ids after it
+    assert_eq!(ids.set_len(2, 1), 0);
+    assert_eq!(ids.first_id(1), 1);
+    assert_eq!(ids.decode(2), Some((0, 0)));
+}
+
+#[test]
+fn test_pick_instances_carry_point_index() {
+    let data = data();
+    let instances = PickInstance::from_data(&data);
+    assert_eq!(instances.len(), 3);
+    assert_eq!(instances[2].index, 2);
+    assert_eq!(instances[1].position, data.vertices[1].position);
+    assert_eq!(instances[1].size, 10.0);
+    assert_eq!(std::mem::size_of::<PickInstance>(), 16);
+}
@@ -0,0 +1,97 @@
+use helion_core::data::{Color, Point2D};
+use helion_core::polygon::{earcut, polygon_data, polygons_extent, Polygon};
+
+fn ring(coords: &[(f32, f32)]) -> Vec<Point2D> {
+    coords.iter().map(|&(x, y)| Point2D::new(x, y)).collect()
+}
+
+/// Total area of a triangle list given as vertex triples
+fn triangles_area(vertices: &[Point2D]) -> f32 {
+    vertices
+        .chunks_exact(3)
+        .map(|t| ((t[1].x - t[0].x) * (t[2].y - t[0].y) - (t[1].y - t[0].y) * (t[2].x - t[0].x)).abs() / 2.0)
+        .sum()
+}
+
+#[test]
+fn test_earcut_square() {
+    let indices = earcut(&[0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0], &[]);
+    assert_eq!(indices.len(), 6);
+    assert!(indices.iter().all(|&i| i < 4));
+}
+
+#[test]
+fn test_earcut_winding_and_closing_vertex() {
+    // Clockwise, with the first vertex repeated at the end as in GeoJSON
+    let coords = [0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 0.0, 0.0, 0.0];
+    let indices = earcut(&coords, &[]);
+    assert_eq!(indices.len(), 6);
+    // Triangles come out counter-clockwise
+    for t in indices.chunks_exact(3) {
+        let p = |i: u32| (coords[i as usize * 2], coords[i as usize * 2 + 1]);
+        let (a, b, c) = (p(t[0]), p(t[1]), p(t[2]));
+        assert!((b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0) > 0.0);
+    }
+}
+
+#[test]
+fn test_earcut_degenerate_input() {
+    assert!(earcut(&[], &[]).is_empty());
+    assert!(earcut(&[0.0, 0.0, 1.0, 1.0], &[]).is_empty());
+    assert!(earcut(&[0.0, 0.0, 1.0, 1.0, 0.0, 0.0], &[]).is_empty());
+}
+
+#[test]
+fn test_concave_polygon_covers_its_area() {
+    // A notched square: n - 2 triangles with the exact area
+    let polygon = Polygon::new(ring(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (2.0, 1.0), (0.0, 4.0)]));
+    let triangles = polygon.triangulate();
+    assert_eq!(triangles.len(), 3 * 3);
+    assert!((triangles_area(&triangles) - polygon.area()).abs() < 1e-4);
+    assert_eq!(polygon.area(), 10.0);
+}
+
+#[test]
+fn test_polygon_with_holes() {
+    let outer = ring(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]);
+    let polygon = Polygon::new(outer)
+        .with_hole(ring(&[(1.0, 1.0), (3.0, 1.0), (3.0, 3.0), (1.0, 3.0)]))
+        .with_hole(ring(&[(6.0, 6.0), (8.0, 6.0), (7.0, 8.0)]));
+    assert_eq!(polygon.area(), 100.0 - 4.0 - 2.0);
+
+    let triangles = polygon.triangulate();
+    assert!((triangles_area(&triangles) - polygon.area()).abs() < 1e-3);
+
+    // No triangle covers the inside of a hole
+    for t in triangles.chunks_exact(3) {
+        let centroid = Point2D::new((t[0].x + t[1].x + t[2].x) / 3.0, (t[0].y + t[1].y + t[2].y) / 3.0);
+        assert!(polygon.contains(centroid), "triangle centroid ({}, {}) outside the shape", centroid.x, centroid.y);
+    }
+    assert!(!polygon.contains(Point2D::new(2.0, 2.0)));
+    assert!(!polygon.contains(Point2D::new(11.0, 5.0)));
+    assert!(polygon.contains(Point2D::new(5.0, 5.0)));
+}
+
+#[test]
+fn test_polygon_data_maps_extent_and_cycles_colors() {
+    let red = Color::new(1.0, 0.0, 0.0, 1.0);
+    let blue = Color::new(0.0, 0.0, 1.0, 1.0);
+    let polygons = vec![
+        Polygon::new(ring(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)])),
+        Polygon::new(ring(&[(1.0, 0.0), (2.0, 0.0), (2.0, 1.0)])),
+        Polygon::new(ring(&[(0.0, 1.0), (2.0, 1.0), (1.0, 2.0)])),
+    ];
+    let extent = polygons_extent(&polygons);
+    assert_eq!(extent, ((0.0, 2.0), (0.0, 2.0)));
+
+    let data = polygon_data(&polygons, &[red, blue], extent, (-1.0, 1.0), (-1.0, 1.0), 800.0, 600.0);
+    assert_eq!(data.vertices.len(), 6 + 3 + 3);
+    let xs: Vec<f32> = data.vertices.iter().map(|v| v.position[0]).collect();
+    assert_eq!(xs.iter().cloned().fold(f32::INFINITY, f32::min), -1.0);
+    assert_eq!(xs.iter().cloned().fold(f32::NEG_INFINITY, f32::max), 1.0);
+    assert_eq!(data.vertices[0].color, [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(data.vertices[6].color, [0.0, 0.0, 1.0, 1.0]);
+    assert_eq!(data.vertices[9].color, [1.0, 0.0, 0.0, 1.0]);
+
+    assert!(polygon_data(&polygons, &[], extent, (-1.0, 1.0), (-1.0, 1.0), 800.0, 600.0).vertices.is_empty());
+}
@@ -0,0 +1,103 @@
+use helion_core::cancel::CancelToken;
+use helion_core::data::{ChartData, MissingDataPolicy, PositionVertex};
+use helion_core::pool::ChartDataPool;
+
+fn positions(data: &ChartData) -> Vec<[f32; 2]> {
+    data.vertices.iter().map(|v| v.position).collect()
+}
+
+#[test]
+fn test_fill_scatter_matches_from_scatter() {
+    let x = [0.0, 5.0, 10.0];
+    let y = [1.0, -1.0, 3.0];
+    let expected = ChartData::from_scatter_with_range(&x, &y, None, Some(4.0), 800.0, 600.0, Some((0.0, 1.0)), None);
+
+    // Stale points are replaced, not appended to
+    let mut data = ChartData::from_scatter(&[9.0; 50], &[9.0; 50], None, None, 800.0, 600.0);
+    data.fill_scatter(&x, &y, None, Some(4.0), Some((0.0, 1.0)), None);
+
+    assert_eq!(positions(&data), positions(&expected));
+    assert!(data.vertices.iter().all(|v| v.size == 4.0));
+}
+
+#[test]
+fn test_fill_scatter_reuses_allocation() {
+    let x: Vec<f32> = (0..1000).map(|i| i as f32).collect();
+    let mut data = ChartData::with_capacity(800.0, 600.0, 1000);
+    let ptr = data.vertices.as_ptr();
+
+    for frame in 0..5 {
+        let y: Vec<f32> = x.iter().map(|v| v * frame as f32).collect();
+        data.fill_scatter(&x, &y, None, None, None, None);
+    }
+
+    assert_eq!(data.vertices.len(), 1000);
+    assert_eq!(data.vertices.as_ptr(), ptr);
+}
+
+#[test]
+fn test_fill_scatter_cancelable() {
+    let mut data = ChartData::new(800.0, 600.0);
+    let token = CancelToken::new();
+    token.cancel();
+
+    let result = data.fill_scatter_cancelable(&[1.0, 2.0], &[1.0, 2.0], None, None, None, None, &token, &mut |_| {});
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_position_vertices_into() {
+    let data = ChartData::from_scatter(&[0.0, 1.0], &[0.0, 1.0], None, None, 800.0, 600.0);
+    let mut out = vec![PositionVertex { position: [5.0, 5.0] }; 10];
+
+    data.position_vertices_into(&mut out);
+    assert_eq!(out.len(), 2);
+    assert_eq!(out[1].position, [1.0, 1.0]);
+}
+
+#[test]
+fn test_pool_reuses_released_data() {
+    let mut pool = ChartDataPool::new();
+    let mut data = pool.acquire(800.0, 600.0);
+    data.fill_scatter(&[0.0; 500], &[0.0; 500], None, None, None, None);
+    let ptr = data.vertices.as_ptr();
+    pool.release(data);
+
+    assert_eq!(pool.idle(), 1);
+    assert!(pool.idle_bytes() >= 500 * std::mem::size_of::<helion_core::data::Vertex>());
+
+    let data = pool.acquire(1024.0, 768.0);
+    assert!(data.vertices.is_empty());
+    assert_eq!(data.vertices.as_ptr(), ptr);
+    assert_eq!((data.viewport_width, data.viewport_height), (1024.0, 768.0));
+    assert_eq!(pool.idle(), 0);
+}
+
+#[test]
+fn test_pool_resets_missing_data_state() {
+    let mut pool = ChartDataPool::new();
+    let mut data = pool.acquire(800.0, 600.0);
+    data.set_missing_data(MissingDataPolicy::Clamp);
+    data.fill_scatter(&[0.0, f32::NAN, 1.0], &[0.0, 0.0, f32::NAN], None, None, None, None);
+    assert_eq!(data.dropped(), &[1, 2]);
+    pool.release(data);
+
+    let data = pool.acquire(800.0, 600.0);
+    assert_eq!(data.missing_data(), MissingDataPolicy::default());
+    assert!(data.dropped().is_empty());
+}
+
+#[test]
+fn test_pool_prefers_largest_and_caps_idle() {
+    let mut pool = ChartDataPool::new().with_max_idle(2);
+    pool.release(ChartData::with_capacity(1.0, 1.0, 10));
+    pool.release(ChartData::with_capacity(1.0, 1.0, 1000));
+    pool.release(ChartData::with_capacity(1.0, 1.0, 100));
+    assert_eq!(pool.idle(), 2);
+
+    assert!(pool.acquire(1.0, 1.0).vertices.capacity() >= 1000);
+
+    pool.clear();
+    assert_eq!(pool.idle(), 0);
+    assert_eq!(pool.idle_bytes(), 0);
+}
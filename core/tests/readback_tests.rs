@@ -0,0 +1,32 @@
+use helion_core::readback::{bgra_to_rgba, padded_bytes_per_row, unpad_rows};
+
+#[test]
+fn test_rows_pad_to_256_bytes() {
+    assert_eq!(padded_bytes_per_row(64, 4), 256);
+    assert_eq!(padded_bytes_per_row(65, 4), 512);
+    assert_eq!(padded_bytes_per_row(1, 4), 256);
+    assert_eq!(padded_bytes_per_row(800, 4), 3328);
+}
+
+#[test]
+fn test_unpad_rows() {
+    // 3x2 image: each 12-byte row is padded to 256 bytes
+    let (width, height) = (3, 2);
+    let mut padded = vec![0xAAu8; 256 * 2];
+    for row in 0..2 {
+        for i in 0..12 {
+            padded[row * 256 + i] = (row * 12 + i) as u8;
+        }
+    }
+
+    let pixels = unpad_rows(&padded, width, height, 4);
+    assert_eq!(pixels.len(), 24);
+    assert_eq!(pixels, (0..24).collect::<Vec<u8>>());
+}
+
+#[test]
+fn test_bgra_to_rgba() {
+    let mut pixels = vec![1, 2, 3, 4, 5, 6, 7, 8];
+    bgra_to_rgba(&mut pixels);
+    assert_eq!(pixels, vec![3, 2, 1, 4, 7, 6, 5, 8]);
+}
@@ -0,0 +1,85 @@
+use helion_core::renderer::{BlendMode, RenderOptions, ScissorRect, DEPTH_FORMAT};
+
+#[test]
+fn test_defaults_match_previous_behavior() {
+    let options = RenderOptions::default();
+    assert_eq!(options.clear_color, wgpu::Color::WHITE);
+    assert_eq!(options.sample_count, 1);
+    assert_eq!(options.blend, BlendMode::Alpha);
+    assert_eq!(options.point_size, 2.0);
+    assert!(!options.depth_test);
+    assert!(options.scissor.is_none());
+    assert!(!options.deterministic);
+    assert!(!options.equal_aspect);
+
+    assert_eq!(options.blend_state(), Some(wgpu::BlendState::ALPHA_BLENDING));
+    assert_eq!(options.multisample_state().count, 1);
+    assert!(options.depth_stencil_state().is_none());
+}
+
+#[test]
+fn test_blend_modes() {
+    assert_eq!(
+        BlendMode::Premultiplied.blend_state(),
+        Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING)
+    );
+    assert_eq!(BlendMode::Replace.blend_state(), None);
+
+    let additive = BlendMode::Additive.blend_state().unwrap();
+    assert_eq!(additive.color.src_factor, wgpu::BlendFactor::One);
+    assert_eq!(additive.color.dst_factor, wgpu::BlendFactor::One);
+    assert_eq!(additive.color, additive.alpha);
+}
+
+#[test]
+fn test_builders_feed_pipeline_state() {
+    let options = RenderOptions::default()
+        .with_sample_count(4)
+        .with_blend(BlendMode::Replace)
+        .with_depth_test(true);
+
+    assert_eq!(options.multisample_state().count, 4);
+    assert_eq!(options.blend_state(), None);
+
+    let depth = options.depth_stencil_state().unwrap();
+    assert_eq!(depth.format, DEPTH_FORMAT);
+    assert_eq!(depth.depth_compare, wgpu::CompareFunction::LessEqual);
+}
+
+#[test]
+fn test_validate() {
+    assert!(RenderOptions::default().validate().is_ok());
+    assert!(RenderOptions::default().with_sample_count(4).validate().is_ok());
+    assert!(RenderOptions::default().with_sample_count(2).validate().is_err());
+    assert!(RenderOptions::default().with_sample_count(0).validate().is_err());
+    assert!(RenderOptions::default().with_point_size(0.0).validate().is_err());
+    assert!(RenderOptions::default().with_point_size(f32::NAN).validate().is_err());
+}
+
+#[test]
+fn test_scissor_is_clamped_to_target() {
+    let inside = ScissorRect::new(10, 20, 100, 50);
+    assert_eq!(inside.clamped(800, 600), Some(inside));
+
+    // Overhanging the right/bottom edges is trimmed
+    assert_eq!(
+        ScissorRect::new(700, 550, 200, 200).clamped(800, 600),
+        Some(ScissorRect::new(700, 550, 100, 50))
+    );
+
+    // Fully outside or empty leaves nothing to draw
+    assert_eq!(ScissorRect::new(900, 0, 10, 10).clamped(800, 600), None);
+    assert_eq!(ScissorRect::new(0, 0, 0, 10).clamped(800, 600), None);
+}
+
+#[test]
+fn test_deterministic_mode_pins_sampling() {
+    let options = RenderOptions::default().with_sample_count(4).with_deterministic(true);
+    assert!(options.validate().is_ok());
+    assert_eq!(options.effective_sample_count(), 1);
+    assert_eq!(options.multisample_state().count, 1);
+
+    // The requested count is kept for when the mode is turned off again
+    let options = options.with_deterministic(false);
+    assert_eq!(options.effective_sample_count(), 4);
+}
@@ -0,0 +1,125 @@
+use helion_core::data::Color;
+use helion_core::error::HelionError;
+use helion_core::sankey::{Sankey, SankeyLink};
+use helion_core::text::HAlign;
+
+fn energy() -> Sankey {
+    let labels = ["Coal", "Gas", "Electricity", "Homes", "Industry"];
+    let links = [
+        SankeyLink::new(0, 2, 4.0),
+        SankeyLink::new(1, 2, 2.0),
+        SankeyLink::new(1, 4, 1.0),
+        SankeyLink::new(2, 3, 5.0),
+        SankeyLink::new(2, 4, 1.0),
+    ];
+    Sankey::new(&labels, &links).unwrap().with_node_padding(10.0)
+}
+
+#[test]
+fn test_invalid_links_are_rejected() {
+    let labels = ["a", "b", "c"];
+    let cases = [
+        vec![SankeyLink::new(0, 3, 1.0)],
+        vec![SankeyLink::new(1, 1, 1.0)],
+        vec![SankeyLink::new(0, 1, -1.0)],
+        vec![SankeyLink::new(0, 1, f32::NAN)],
+        vec![SankeyLink::new(0, 1, 1.0), SankeyLink::new(1, 2, 1.0), SankeyLink::new(2, 0, 1.0)],
+    ];
+    for links in cases {
+        let err = Sankey::new(&labels, &links).unwrap_err();
+        assert!(matches!(err, HelionError::InvalidOption(_)), "{:?}", links);
+    }
+    let err = Sankey::new(&labels, &[SankeyLink::new(2, 0, 1.0), SankeyLink::new(0, 2, 1.0)]).unwrap_err();
+    assert_eq!(err.to_string(), "Sankey links must not form a cycle");
+}
+
+#[test]
+fn test_sinks_go_to_last_column() {
+    // "d" only receives from the first column, but ends a flow
+    let links = [SankeyLink::new(0, 1, 1.0), SankeyLink::new(1, 2, 1.0), SankeyLink::new(0, 3, 1.0)];
+    let sankey = Sankey::new(&["a", "b", "c", "d"], &links).unwrap();
+    assert_eq!(sankey.columns(), [0, 1, 2, 2]);
+}
+
+#[test]
+fn test_layout_scales_to_fullest_column() {
+    let sankey = energy();
+    let layout = sankey.layout(400.0, 200.0);
+
+    // The last column holds 7 units and a 10 px gap: 190 px / 7 per unit
+    let scale = 190.0 / 7.0;
+    let [x, y, w, h] = layout.nodes[3];
+    assert_eq!((x, w), (384.0, 16.0));
+    assert!((h - 5.0 * scale).abs() < 1e-3);
+    assert!(y.abs() < 1e-3);
+    let [x, _, _, h] = layout.nodes[2];
+    assert_eq!(x, 192.0);
+    assert!((h - 6.0 * scale).abs() < 1e-3);
+    // The first column is centered
+    let [_, top, _, _] = layout.nodes[0];
+    let [_, gas_top, _, gas_height] = layout.nodes[1];
+    assert!((top - (200.0 - gas_top - gas_height)).abs() < 1e-3);
+}
+
+#[test]
+fn test_bands_stack_without_crossing() {
+    let sankey = energy();
+    let layout = sankey.layout(400.0, 200.0);
+    let [ex, ey, ..] = layout.nodes[2];
+    let [hx, hy, ..] = layout.nodes[3];
+
+    // Into Electricity: Coal's band above Gas's
+    let coal = layout.bands[0];
+    let gas = layout.bands[1];
+    assert_eq!(coal.end, [ex, ey]);
+    assert!((gas.end[1] - (ey + coal.thickness)).abs() < 1e-3);
+    // Out of Electricity: to Homes first, then Industry
+    let homes = layout.bands[3];
+    let industry = layout.bands[4];
+    assert_eq!(homes.start, [ex + 16.0, ey]);
+    assert!((industry.start[1] - (ey + homes.thickness)).abs() < 1e-3);
+
+    // Curves start and end level with the nodes
+    assert_eq!(homes.top(0.0), homes.start);
+    assert!((homes.top(1.0)[0] - hx).abs() < 1e-3 && (homes.top(1.0)[1] - hy).abs() < 1e-3);
+    let near_start = homes.top(0.01);
+    assert!((near_start[1] - homes.start[1]).abs() < 0.01);
+}
+
+#[test]
+fn test_chart_data_fades_between_node_colors() {
+    let red = Color::new(1.0, 0.0, 0.0, 1.0);
+    let blue = Color::new(0.0, 0.0, 1.0, 1.0);
+    let sankey = Sankey::new(&["a", "b"], &[SankeyLink::new(0, 1, 1.0)])
+        .unwrap()
+        .with_colors(vec![red, blue])
+        .with_link_alpha(0.5);
+    let data = sankey.chart_data(200.0, 100.0);
+
+    // One band of 32 quads, then two node rects
+    assert_eq!(data.vertices.len(), 32 * 6 + 2 * 6);
+    let first = data.vertices[0];
+    assert!((first.color[0] - 1.0).abs() < 1e-3 && first.color[2].abs() < 1e-3 && first.color[3] == 0.5);
+    let band_end = data.vertices[31 * 6 + 1];
+    assert!((band_end.color[2] - 1.0).abs() < 1e-3 && band_end.color[3] == 0.5);
+    // Node rects are opaque, the target's spanning the right edge
+    let last = data.vertices.last().unwrap();
+    assert_eq!(last.color, [0.0, 0.0, 1.0, 1.0]);
+    assert!(data.vertices.iter().all(|v| v.position[0] >= -1.0 && v.position[0] <= 1.0));
+}
+
+#[test]
+fn test_labels_face_into_the_diagram_and_hit_testing() {
+    let sankey = energy();
+    let sections = sankey.text_sections(400.0, 200.0);
+    assert_eq!(sections.len(), 5);
+    assert_eq!(sections[0].text, "Coal");
+    assert_eq!(sections[0].h_align, HAlign::Left);
+    assert_eq!(sections[0].position[0], 16.0 + 6.0);
+    assert_eq!(sections[3].h_align, HAlign::Right);
+    assert_eq!(sections[3].position[0], 384.0 - 6.0);
+
+    let [x, y, w, h] = sankey.layout(400.0, 200.0).nodes[2];
+    assert_eq!(sankey.node_at(x + w / 2.0, y + h / 2.0, 400.0, 200.0), Some(2));
+    assert_eq!(sankey.node_at(100.0, 2.0, 400.0, 200.0), None);
+}
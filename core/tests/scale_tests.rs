@@ -0,0 +1,182 @@
+use helion_core::data::ChartData;
+use helion_core::scale::{break_marker_segments, Axis, AxisBreak, AxisScale, BrokenAxis};
+
+#[test]
+fn test_breaks_are_sorted_and_merged() {
+    let axis = BrokenAxis::new(
+        vec![AxisBreak::new(50.0, 60.0), AxisBreak::new(20.0, 10.0), AxisBreak::new(55.0, 70.0)],
+        0.0,
+    );
+    assert_eq!(axis.breaks(), &[AxisBreak::new(10.0, 20.0), AxisBreak::new(50.0, 70.0)]);
+}
+
+#[test]
+fn test_compress_removes_break_ranges() {
+    let axis = BrokenAxis::new(vec![AxisBreak::new(10.0, 20.0), AxisBreak::new(30.0, 40.0)], 0.0);
+    assert_eq!(axis.compress(5.0), 5.0);
+    assert_eq!(axis.compress(25.0), 15.0);
+    assert_eq!(axis.compress(45.0), 25.0);
+    // Values inside a break collapse onto its start
+    assert_eq!(axis.compress(15.0), 10.0);
+}
+
+#[test]
+fn test_gap_keeps_transform_monotonic_and_invertible() {
+    let axis = BrokenAxis::new(vec![AxisBreak::new(10.0, 110.0)], 2.0);
+    assert_eq!(axis.compress(110.0), 12.0);
+    assert_eq!(axis.compress(60.0), 11.0); // halfway through the break
+    for v in [0.0, 10.0, 35.0, 110.0, 200.0] {
+        assert!((axis.expand(axis.compress(v)) - v).abs() < 1e-4, "round trip failed for {}", v);
+    }
+}
+
+#[test]
+fn test_broken_axis_normalization() {
+    // Data with a big empty range between 3 and 1000
+    let x = vec![1.0, 2.0, 3.0, 1000.0];
+    let y = vec![0.0, 1.0, 2.0, 3.0];
+    let axis = BrokenAxis::new(vec![AxisBreak::new(3.5, 999.0)], 0.0);
+
+    let data = ChartData::from_scatter(&axis.compress_all(&x), &y, None, None, 800.0, 600.0);
+
+    // After compression the points are nearly evenly spaced
+    let positions: Vec<f32> = data.vertices.iter().map(|v| v.position[0]).collect();
+    assert!(positions[1] - positions[0] > 0.5);
+
+    let markers = axis.marker_positions(&x, (-1.0, 1.0));
+    assert_eq!(markers.len(), 1);
+    assert!(markers[0] > positions[2] && markers[0] < positions[3]);
+}
+
+#[test]
+fn test_break_marker_segments() {
+    let [a, b] = break_marker_segments(Axis::X, 0.0, -1.0, 0.02);
+    // Two parallel strokes straddling the marker position and the axis line
+    assert!(a[0][0] < 0.0 && b[1][0] > 0.0);
+    assert_eq!(a[0][1], -1.02);
+    assert_eq!(a[1][1], -0.98);
+    assert_eq!(a[1][0] - a[0][0], b[1][0] - b[0][0]);
+}
+
+#[test]
+fn test_log_scale_maps_decades() {
+    let log = AxisScale::from_name("log").unwrap();
+    assert_eq!(log, AxisScale::Log10);
+    assert_eq!(AxisScale::from_name("linear"), Some(AxisScale::Linear));
+    assert_eq!(AxisScale::from_name("logit"), None);
+
+    assert_eq!(log.forward_all(&[1.0, 100.0, 0.001]), [0.0, 2.0, -3.0]);
+    assert!(log.forward(0.0).is_nan() && log.forward(-5.0).is_nan());
+    assert!((log.inverse(log.forward(42.0)) - 42.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_log_ticks_on_decades() {
+    let ticks = AxisScale::Log10.ticks(-2.0, 1.5, 6, 5);
+    assert_eq!(ticks.major, [-2.0, -1.0, 0.0, 1.0]);
+    assert_eq!(AxisScale::Log10.tick_labels(&ticks), ["0.01", "0.1", "1", "10"]);
+    // 2x..9x every decade up to 10^1.5
+    assert_eq!(ticks.minor.len(), 8 * 3 + 2);
+    assert!(ticks.minor.iter().all(|&v| (-2.0..=1.5).contains(&v) && v.fract() != 0.0));
+
+    // Wide ranges skip decades instead of crowding
+    let wide = AxisScale::Log10.ticks(-10.0, 10.0, 5, 5);
+    assert_eq!(wide.major, [-8.0, -4.0, 0.0, 4.0, 8.0]);
+    assert_eq!(AxisScale::Log10.tick_labels(&wide), ["1e-8", "0.0001", "1", "10000", "1e8"]);
+    assert!(wide.minor.is_empty());
+}
+
+#[test]
+fn test_log_ticks_within_a_decade() {
+    let ticks = AxisScale::Log10.ticks(2f32.log10(), 8f32.log10(), 6, 0);
+    assert_eq!(AxisScale::Log10.tick_labels(&ticks), ["2", "3", "4", "5", "6", "7", "8"]);
+    assert!((ticks.major[1] - 3f32.log10()).abs() < 1e-6);
+}
+
+#[test]
+fn test_log2_scale_maps_powers_of_two() {
+    let log2 = AxisScale::from_name("log2").unwrap();
+    assert_eq!(log2, AxisScale::Log2);
+    assert_eq!(log2.forward_all(&[1.0, 1024.0, 0.25]), [0.0, 10.0, -2.0]);
+    assert!(log2.forward(0.0).is_nan() && log2.forward(-1.0).is_nan());
+    assert_eq!(log2.inverse(3.0), 8.0);
+}
+
+#[test]
+fn test_log2_ticks_on_powers_of_two() {
+    let ticks = AxisScale::Log2.ticks(-3.0, 3.0, 8, 5);
+    assert_eq!(ticks.major, [-3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0]);
+    assert_eq!(AxisScale::Log2.tick_labels(&ticks), ["0.125", "0.25", "0.5", "1", "2", "4", "8"]);
+    assert!(ticks.minor.is_empty());
+
+    // Large exponents are written as powers
+    let wide = AxisScale::Log2.ticks(0.0, 30.0, 6, 0);
+    assert_eq!(wide.major, [0.0, 5.0, 10.0, 15.0, 20.0, 25.0, 30.0]);
+    assert_eq!(AxisScale::Log2.tick_labels(&wide), ["1", "32", "1024", "32768", "2^20", "2^25", "2^30"]);
+}
+
+#[test]
+fn test_symlog_scale_keeps_zero_and_negatives() {
+    let symlog = AxisScale::from_name("symlog").unwrap();
+    assert_eq!(symlog, AxisScale::SymLog { linthresh: 1.0 });
+    assert_eq!(symlog.forward(0.0), 0.0);
+    assert_eq!(symlog.forward(9.0), 1.0);
+    assert_eq!(symlog.forward(-99.0), -2.0);
+    // Odd around zero, so both signs get the same room
+    assert_eq!(symlog.forward(-0.5), -symlog.forward(0.5));
+    assert!((symlog.inverse(symlog.forward(-1234.5)) + 1234.5).abs() < 1e-2);
+
+    // A wider linear region squeezes small values harder
+    assert!(AxisScale::symlog(10.0).forward(5.0) < symlog.forward(5.0));
+    assert_eq!(AxisScale::symlog(0.0), AxisScale::SymLog { linthresh: 1.0 });
+}
+
+#[test]
+fn test_symlog_ticks_on_signed_decades() {
+    let symlog = AxisScale::symlog(1.0);
+    let ticks = symlog.ticks(symlog.forward(-100.0), symlog.forward(100.0), 8, 5);
+    assert_eq!(symlog.tick_labels(&ticks), ["-100", "-10", "-1", "0", "1", "10", "100"]);
+    assert!(ticks.major.windows(2).all(|pair| pair[0] < pair[1]));
+    // 2x..9x of the decades from 1 to 10 on each side
+    assert_eq!(ticks.minor.len(), 2 * 8 * 2);
+    assert!(ticks.minor.windows(2).all(|pair| pair[0] < pair[1]));
+
+    // Wide ranges skip decades, always keeping zero
+    let wide = symlog.ticks(symlog.forward(-1e6), symlog.forward(1e6), 6, 5);
+    assert_eq!(symlog.tick_labels(&wide), ["-1e6", "-1000", "-1", "0", "1", "1000", "1e6"]);
+    assert!(wide.minor.is_empty());
+
+    // Decades start at the linear threshold
+    let coarse = AxisScale::symlog(5.0);
+    let ticks = coarse.ticks(coarse.forward(-200.0), coarse.forward(200.0), 8, 0);
+    assert_eq!(coarse.tick_labels(&ticks), ["-100", "-10", "0", "10", "100"]);
+}
+
+#[test]
+fn test_symlog_ticks_within_a_decade() {
+    let symlog = AxisScale::symlog(1.0);
+    let ticks = symlog.ticks(symlog.forward(0.2), symlog.forward(0.8), 6, 0);
+    assert_eq!(symlog.tick_labels(&ticks), ["0.4", "0.6", "0.8"]);
+}
+
+#[test]
+fn test_time_ticks_follow_the_clock() {
+    // 2024-03-01 00:00:30 UTC
+    let time = AxisScale::time(1_709_251_230.0);
+    let ticks = time.ticks(0.0, 6.0 * 3600.0, 6, 0);
+    assert_eq!(ticks.step, 3600.0);
+    assert_eq!(ticks.major[0], 3570.0);
+    assert_eq!(time.tick_labels(&ticks), ["01:00", "02:00", "03:00", "04:00", "05:00", "06:00"]);
+
+    let seconds = time.ticks(0.0, 50.0, 5, 0);
+    assert_eq!(time.tick_labels(&seconds), ["00:00:30", "00:00:40", "00:00:50", "00:01:00", "00:01:10", "00:01:20"]);
+
+    let days = time.ticks(0.0, 10.0 * 86400.0, 5, 0);
+    assert_eq!(
+        time.tick_labels(&days),
+        ["2024-03-02", "2024-03-04", "2024-03-06", "2024-03-08", "2024-03-10"]
+    );
+
+    let fractions = time.ticks(0.0, 0.5, 5, 0);
+    assert_eq!(time.tick_labels(&fractions)[..2], ["00:00:30.0", "00:00:30.1"]);
+}
@@ -12,7 +12,7 @@ fn test_scatter_renderer_struct_size() {
     use helion_core::scatter::ScatterRenderer;
     
     // ScatterRenderer should be relatively small
-    // Contains: render_pipeline, Option<Buffer>, u32
+    // Contains: render_pipeline, Option<ChunkedBuffer>
     let size = size_of::<ScatterRenderer>();
     
     // Should be less than 1KB (currently around 100-200 bytes)
@@ -181,3 +181,53 @@ fn test_vertex_alignment() {
 // These would be in an `integration_tests/` folder with conditional compilation:
 // #[cfg(feature = "gpu-tests")]
 // async fn test_actual_rendering() { ... }
+
+#[test]
+fn test_position_only_layout_is_8_bytes() {
+    use helion_core::data::{PositionVertex, Vertex};
+    use helion_core::scatter::SeriesUniforms;
+    use std::mem::size_of;
+
+    assert_eq!(size_of::<Vertex>(), 40);
+    assert_eq!(size_of::<PositionVertex>(), 8);
+    // Uniform blocks must be a multiple of 16 bytes
+    assert_eq!(size_of::<SeriesUniforms>(), 32);
+}
+
+#[test]
+fn test_vertex_layout_selection() {
+    use helion_core::data::{Point2D, VertexLayout};
+
+    let red = Color::new(1.0, 0.0, 0.0, 1.0);
+    let single = ChartData::from_scatter(&[0.0, 1.0], &[0.0, 1.0], Some(red), Some(3.0), 800.0, 600.0);
+    assert_eq!(single.vertex_layout(), VertexLayout::PositionOnly);
+    assert_eq!(single.uniform_style(), Some((red, 3.0)));
+
+    let positions = single.position_vertices();
+    assert_eq!(positions.len(), 2);
+    assert_eq!(positions[1].position, single.vertices[1].position);
+
+    let mut mixed = single;
+    mixed.add_point(Point2D::new(0.0, 0.0), Color::new(0.0, 0.0, 1.0, 1.0), 3.0);
+    assert_eq!(mixed.vertex_layout(), VertexLayout::Full);
+    assert_eq!(mixed.uniform_style(), None);
+
+    assert_eq!(ChartData::new(1.0, 1.0).vertex_layout(), VertexLayout::Full);
+}
+
+#[test]
+fn test_scatter_uniforms_follow_blend_mode() {
+    use helion_core::renderer::BlendMode;
+    use helion_core::scatter::ScatterUniforms;
+
+    assert_eq!(std::mem::size_of::<ScatterUniforms>(), 16);
+
+    let alpha = ScatterUniforms::new(800.0, 600.0, BlendMode::Alpha);
+    assert_eq!(alpha.viewport, [800.0, 600.0]);
+    assert_eq!((alpha.premultiplied, alpha.hard_edge), (0.0, 0.0));
+
+    // Edge coverage must darken the color itself when it isn't multiplied by alpha later
+    assert_eq!(ScatterUniforms::new(1.0, 1.0, BlendMode::Premultiplied).premultiplied, 1.0);
+    assert_eq!(ScatterUniforms::new(1.0, 1.0, BlendMode::Additive).premultiplied, 1.0);
+    assert_eq!(ScatterUniforms::new(1.0, 1.0, BlendMode::Replace).hard_edge, 1.0);
+}
@@ -1,4 +1,8 @@
-use helion_core::data::{ChartData, Color, Point2D};
+use helion_core::colormap::ColormapLut;
+use helion_core::cancel::CancelToken;
+use helion_core::data::{ChartData, Color, Colormap, MissingDataPolicy, Point2D, ScatterTransform};
+use helion_core::error::HelionError;
+use helion_core::norm::Normalization;
 
 #[test]
 fn test_scatter_basic_creation() {
@@ -89,6 +93,64 @@ fn test_color_from_hex() {
     assert_eq!(red_half.a, 0.5019608); // 128/255
 }
 
+#[test]
+fn test_color_parse_hex_is_strict() {
+    assert_eq!(Color::parse_hex("#0000FF"), Ok(Color::from_hex("#0000FF")));
+    assert_eq!(Color::parse_hex("ff000080"), Ok(Color::from_hex("#FF000080")));
+    assert!(Color::parse_hex("#fff").is_err());
+    assert!(Color::parse_hex("#gg0000").is_err());
+    assert!(Color::parse_hex("").is_err());
+}
+
+#[test]
+fn test_color_from_hex_never_panics() {
+    // Short or garbled literals fall back to black instead of panicking
+    assert_eq!(Color::from_hex("#f"), Color::new(0.0, 0.0, 0.0, 1.0));
+    assert_eq!(Color::from_hex(""), Color::new(0.0, 0.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_named_colors() {
+    assert_eq!(Color::from_name("tomato"), Some(Color::from_hex("#ff6347")));
+    assert_eq!(Color::from_name("Steel Blue"), Some(Color::from_hex("#4682b4")));
+    assert_eq!(Color::from_name("dark_grey"), Color::from_name("darkgray"));
+    assert_eq!(Color::from_name("transparent"), Some(Color::new(0.0, 0.0, 0.0, 0.0)));
+    assert_eq!(Color::from_name("tomatoe"), None);
+
+    let names = helion_core::named_colors::CSS_COLORS;
+    assert_eq!(names.len(), 148);
+    assert!(names.windows(2).all(|pair| pair[0].0 < pair[1].0), "table must stay sorted");
+}
+
+#[test]
+fn test_color_parse_accepts_names_and_hex() {
+    assert_eq!(Color::parse(" rebeccapurple "), Ok(Color::from_hex("#663399")));
+    assert_eq!(Color::parse("ff000080"), Ok(Color::from_hex("#FF000080")));
+    let err = Color::parse("#12345").unwrap_err();
+    assert!(err.contains("#12345"), "{}", err);
+    assert!(Color::parse("").is_err());
+}
+
+#[test]
+fn test_color_from_hsl_and_hsv() {
+    let close = |a: Color, b: Color| {
+        [a.r - b.r, a.g - b.g, a.b - b.b, a.a - b.a].iter().all(|d| d.abs() < 1e-6)
+    };
+    // Primaries, wrapping hues and the gray axis
+    assert!(close(Color::from_hsl(0.0, 1.0, 0.5, 1.0), Color::new(1.0, 0.0, 0.0, 1.0)));
+    assert!(close(Color::from_hsl(480.0, 1.0, 0.5, 1.0), Color::new(0.0, 1.0, 0.0, 1.0)));
+    assert!(close(Color::from_hsl(200.0, 0.0, 0.25, 0.5), Color::new(0.25, 0.25, 0.25, 0.5)));
+    assert!(close(Color::from_hsl(0.0, 1.0, 1.0, 1.0), Color::new(1.0, 1.0, 1.0, 1.0)));
+    assert!(close(Color::from_hsv(240.0, 1.0, 1.0, 1.0), Color::new(0.0, 0.0, 1.0, 1.0)));
+    assert!(close(Color::from_hsv(0.0, 0.0, 0.0, 1.0), Color::new(0.0, 0.0, 0.0, 1.0)));
+
+    // Steelblue is hsl(207, 44%, 49%) and hsv(207, 61%, 71%)
+    let steel = Color::from_name("steelblue").unwrap();
+    for color in [Color::from_hsl(207.27, 0.44, 0.490_2, 1.0), Color::from_hsv(207.27, 0.611_1, 0.705_9, 1.0)] {
+        assert!((color.r - steel.r).abs() < 0.01 && (color.g - steel.g).abs() < 0.01 && (color.b - steel.b).abs() < 0.01);
+    }
+}
+
 #[test]
 fn test_add_point() {
     let mut data = ChartData::new(800.0, 600.0);
@@ -104,3 +166,363 @@ fn test_add_point() {
     assert_eq!(data.vertices[0].position[1], -0.5);
     assert_eq!(data.vertices[0].size, 3.0);
 }
+
+#[test]
+fn test_per_point_alpha() {
+    let x = vec![0.0, 1.0, 2.0];
+    let y = vec![0.0, 1.0, 2.0];
+    let color = Color::new(1.0, 0.0, 0.0, 1.0);
+    let mut data = ChartData::from_scatter(&x, &y, Some(color), None, 800.0, 600.0);
+
+    // Out-of-range values are clamped; RGB is untouched
+    data.set_alpha(&[0.25, 1.5, -1.0]).unwrap();
+    assert_eq!(data.vertices[0].color, [1.0, 0.0, 0.0, 0.25]);
+    assert_eq!(data.vertices[1].color[3], 1.0);
+    assert_eq!(data.vertices[2].color[3], 0.0);
+
+    // Non-finite values leave the point opaque
+    data.set_alpha(&[f32::NAN, f32::INFINITY, 0.5]).unwrap();
+    assert_eq!(data.vertices[0].color[3], 1.0);
+    assert_eq!(data.vertices[1].color[3], 1.0);
+
+    // A length mismatch is refused without touching the points
+    assert_eq!(
+        data.set_alpha(&[0.0, 0.0]),
+        Err(HelionError::ShapeMismatch {
+            what: "alpha values, one per point".to_string(),
+            expected: 3,
+            actual: 2,
+        })
+    );
+    assert_eq!(data.vertices[2].color[3], 0.5);
+}
+
+#[test]
+fn test_alpha_from_transfer_function() {
+    use helion_core::data::AlphaTransfer;
+
+    let x = vec![0.0, 1.0, 2.0];
+    let y = vec![0.0, 1.0, 2.0];
+    let mut data = ChartData::from_scatter(&x, &y, None, None, 800.0, 600.0);
+
+    // Older samples (low timestamps) fade out
+    let transfer = AlphaTransfer { min_alpha: 0.0, max_alpha: 1.0, ..Default::default() };
+    data.set_alpha_from(&[10.0, 15.0, 20.0], &transfer).unwrap();
+    assert_eq!(data.vertices[0].color[3], 0.0);
+    assert_eq!(data.vertices[1].color[3], 0.5);
+    assert_eq!(data.vertices[2].color[3], 1.0);
+
+    // Fixed domain and gamma
+    let transfer = AlphaTransfer { domain: Some((0.0, 1.0)), min_alpha: 0.0, max_alpha: 1.0, gamma: 2.0 };
+    data.set_alpha_from(&[0.5, 2.0, -1.0], &transfer).unwrap();
+    assert_eq!(data.vertices[0].color[3], 0.25);
+    assert_eq!(data.vertices[1].color[3], 1.0);
+    assert_eq!(data.vertices[2].color[3], 0.0);
+}
+
+#[test]
+fn test_per_point_colors() {
+    let colors = [Color::new(1.0, 0.0, 0.0, 1.0), Color::new(0.0, 1.0, 0.0, 0.5)];
+    let data = ChartData::from_scatter_per_point(&[0.0, 1.0, 2.0], &[0.0, 1.0, 2.0], &colors, Some(4.0), 800.0, 600.0);
+    assert_eq!(data.vertices[0].color, [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(data.vertices[1].color, [0.0, 1.0, 0.0, 0.5]);
+    // No color given for the third point
+    let default = Color::default();
+    assert_eq!(data.vertices[2].color, [default.r, default.g, default.b, default.a]);
+    assert_eq!(data.vertices[2].size, 4.0);
+    assert_eq!(data.uniform_style(), None);
+}
+
+#[test]
+fn test_packed_rgba_colors() {
+    let rgba = [1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.25];
+    let data = ChartData::from_scatter_rgba(&[0.0, 1.0], &[0.0, 1.0], &rgba, None, 800.0, 600.0).unwrap();
+    assert_eq!(data.vertices[1].color, [0.0, 0.0, 1.0, 0.25]);
+
+    assert!(ChartData::from_scatter_rgba(&[0.0], &[0.0], &[1.0, 0.0, 0.0], None, 800.0, 600.0).is_err());
+}
+
+#[test]
+fn test_per_point_sizes() {
+    let mut data = ChartData::from_scatter(&[0.0, 1.0, 2.0, 3.0], &[0.0, 1.0, 2.0, 3.0], None, Some(3.0), 800.0, 600.0);
+    data.set_sizes(&[1.0, 20.0, -5.0]);
+    let sizes: Vec<f32> = data.vertices.iter().map(|v| v.size).collect();
+    // Negative sizes are clamped; the fourth point keeps the scalar size
+    assert_eq!(sizes, vec![1.0, 20.0, 0.0, 3.0]);
+    assert_eq!(data.uniform_style(), None);
+
+    data.set_sizes(&[f32::NAN, f32::INFINITY]);
+    assert_eq!(data.vertices[0].size, 0.0);
+    assert_eq!(data.vertices[1].size, 0.0);
+}
+
+#[test]
+fn test_scatter_colormapped_range() {
+    let x = [0.0, 1.0, 2.0, 3.0];
+    let c = [0.0, 5.0, 10.0, 20.0];
+    let bytes = |v: &[f32; 4]| v.map(|c| (c * 255.0).round() as u8);
+    let lut = ColormapLut::magma();
+
+    // Defaults to the data range
+    let data = ChartData::from_scatter_colormapped(&x, &x, &c, &Colormap::Magma, &Normalization::Linear.fit(&c, None), Some(4.0), 800.0, 600.0);
+    assert_eq!(bytes(&data.vertices[0].color), lut.colors[0]);
+    assert_eq!(bytes(&data.vertices[3].color), lut.colors[255]);
+    assert_eq!(data.vertices[0].size, 4.0);
+
+    // Values beyond an explicit range take the end colors
+    let data = ChartData::from_scatter_colormapped(&x, &x, &c, &Colormap::Magma, &Normalization::Linear.fit(&c, Some((5.0, 10.0))), None, 800.0, 600.0);
+    assert_eq!(bytes(&data.vertices[0].color), lut.colors[0]);
+    assert_eq!(bytes(&data.vertices[1].color), lut.colors[0]);
+    assert_eq!(bytes(&data.vertices[3].color), lut.colors[255]);
+}
+
+#[test]
+fn test_scatter_colormapped_log() {
+    let x = [0.0, 1.0, 2.0, 3.0];
+    let c = [1.0, 10.0, 100.0, -1.0];
+    let lut = ColormapLut::viridis();
+    let norm = Normalization::Log.fit(&c, None);
+    let data = ChartData::from_scatter_colormapped(&x, &x, &c, &Colormap::Viridis, &norm, None, 800.0, 600.0);
+    // 10 sits halfway between 1 and 100 on a log scale
+    let bytes = data.vertices[1].color.map(|c| (c * 255.0).round() as u8);
+    assert_eq!(bytes, lut.sample(0.5, 0.0, 1.0));
+    // Values without a logarithm aren't drawn
+    assert_eq!(data.vertices[3].color[3], 0.0);
+}
+
+#[test]
+fn test_scatter_colormapped_centered() {
+    let x = [0.0, 1.0, 2.0];
+    let c = [-1.0, 0.0, 9.0];
+    let lut = ColormapLut::coolwarm();
+    let norm = Normalization::Linear.fit(&c, None).with_center(0.0);
+    let data = ChartData::from_scatter_colormapped(&x, &x, &c, &Colormap::Coolwarm, &norm, None, 800.0, 600.0);
+    let bytes = |v: &[f32; 4]| v.map(|c| (c * 255.0).round() as u8);
+    assert_eq!(bytes(&data.vertices[0].color), lut.colors[0]);
+    assert_eq!(bytes(&data.vertices[1].color), lut.sample(0.5, 0.0, 1.0));
+    assert_eq!(bytes(&data.vertices[2].color), lut.colors[255]);
+}
+
+#[test]
+fn test_scatter_colormapped_short_values() {
+    let x = [0.0, 1.0, 2.0];
+    let data = ChartData::from_scatter_colormapped(&x, &x, &[1.0], &Colormap::Coolwarm, &Normalization::Linear.fit(&[1.0], None), None, 800.0, 600.0);
+    // A constant range maps to the middle; points without a value keep the default
+    let default = ChartData::from_scatter(&x, &x, None, None, 800.0, 600.0);
+    assert_eq!(data.vertices[1].color, default.vertices[1].color);
+    assert_ne!(data.vertices[0].color, default.vertices[0].color);
+}
+
+#[test]
+fn test_colormap_by_name() {
+    for name in ["viridis", "plasma", "inferno", "magma", "coolwarm"] {
+        let cmap = Colormap::by_name(name).unwrap();
+        assert!(!matches!(cmap, Colormap::Custom(_)), "{}", name);
+        assert_eq!(cmap.lut(), ColormapLut::by_name(name).unwrap());
+    }
+    assert_eq!(Colormap::by_name("gray"), Some(Colormap::Custom(ColormapLut::grayscale())));
+    assert_eq!(Colormap::default(), Colormap::Viridis);
+}
+
+#[test]
+fn test_append_points_logs_stream() {
+    let mut data = ChartData::from_scatter(&[0.0, 1.0], &[0.0, 1.0], None, None, 800.0, 600.0);
+    assert_eq!(data.append_log(), None);
+
+    let color = Color::new(1.0, 0.0, 0.0, 1.0);
+    data.append_points(&[Point2D::new(0.5, 0.5)], color, 3.0);
+    data.append_points(&[Point2D::new(0.2, 0.2), Point2D::new(0.3, 0.3)], color, 3.0);
+    let log = data.append_log().unwrap();
+    assert_eq!(log.appended, 3);
+    assert_eq!(data.vertices.len(), 5);
+    assert_eq!(data.vertices[2].size, 3.0);
+
+    // Other changes start a new stream, so renderers upload everything again
+    data.set_sizes(&[1.0]);
+    assert_eq!(data.append_log(), None);
+    data.append_points(&[Point2D::new(0.0, 0.0)], color, 3.0);
+    let next = data.append_log().unwrap();
+    assert_ne!(next.stream, log.stream);
+    assert_eq!(next.appended, 1);
+
+    // Copies don't share the stream
+    assert_eq!(data.clone().append_log(), None);
+}
+
+#[test]
+fn test_max_points_keeps_newest() {
+    let mut data = ChartData::new(800.0, 600.0);
+    let color = Color::default();
+    let points: Vec<Point2D> = (0..5).map(|i| Point2D::new(i as f32, 0.0)).collect();
+    data.append_points(&points, color, 2.0);
+    data.set_max_points(Some(3));
+    assert_eq!(data.vertices.len(), 3);
+    assert_eq!(data.vertices[0].position[0], 2.0);
+
+    data.append_points(&[Point2D::new(9.0, 0.0)], color, 2.0);
+    let xs: Vec<f32> = data.vertices.iter().map(|v| v.position[0]).collect();
+    assert_eq!(xs, vec![3.0, 4.0, 9.0]);
+    assert_eq!(data.max_points(), Some(3));
+}
+
+#[test]
+fn test_scatter_transform_matches_fill() {
+    let x = [2.0, -4.0, 7.5];
+    let y = [1.0, 3.0, -2.0];
+    let data = ChartData::from_scatter_with_range(&x, &y, None, None, 800.0, 600.0, Some((0.0, 1.0)), Some((1.0, -1.0)));
+    let transform = ScatterTransform::fit(&x, &y, Some((0.0, 1.0)), Some((1.0, -1.0)));
+    for (vertex, (&x, &y)) in data.vertices.iter().zip(x.iter().zip(&y)) {
+        let point = transform.apply(x, y);
+        assert_eq!(vertex.position, [point.x, point.y]);
+    }
+}
+
+#[test]
+fn test_missing_points_skipped_by_default() {
+    let x = [0.0, f32::NAN, 5.0, f32::NEG_INFINITY, 10.0];
+    let y = [0.0, 1.0, f32::INFINITY, 2.0, 4.0];
+    let data = ChartData::from_scatter(&x, &y, None, None, 800.0, 600.0);
+    assert_eq!(data.dropped(), &[1, 2, 3]);
+    // The bounds come from the finite points only
+    assert_eq!(data.vertices[0].position, [-1.0, -1.0]);
+    assert_eq!(data.vertices[1].position, [1.0, 1.0]);
+    assert_eq!(data.source_index(1), 4);
+}
+
+#[test]
+fn test_missing_points_clamped() {
+    let x = [0.0, f32::INFINITY, f32::NAN, 10.0];
+    let y = [0.0, f32::NEG_INFINITY, 1.0, 10.0];
+    let mut data = ChartData::new(800.0, 600.0);
+    data.set_missing_data(MissingDataPolicy::Clamp);
+    data.fill_scatter(&x, &y, None, None, Some((0.0, 1.0)), None);
+    assert_eq!(data.vertices.len(), 3);
+    assert_eq!(data.vertices[1].position, [1.0, -1.0]);
+    assert_eq!(data.dropped(), &[2]);
+}
+
+#[test]
+fn test_missing_points_error() {
+    let x = [0.0, 1.0, f32::NAN];
+    let mut data = ChartData::new(800.0, 600.0);
+    data.set_missing_data(MissingDataPolicy::Error);
+    let err = data
+        .fill_scatter_cancelable(&x, &x, None, None, None, None, &CancelToken::new(), &mut |_| {})
+        .unwrap_err();
+    assert!(err.to_string().contains("Point 2"), "{}", err);
+    // The infallible setter skips the point instead of failing
+    data.fill_scatter(&x, &x, None, None, None, None);
+    assert_eq!((data.vertices.len(), data.dropped()), (2, &[2][..]));
+    assert_eq!(MissingDataPolicy::from_name("clamp"), Some(MissingDataPolicy::Clamp));
+    assert_eq!(MissingDataPolicy::from_name("drop"), None);
+}
+
+#[test]
+fn test_equal_values_are_centered() {
+    let data = ChartData::from_scatter(&[3.0, 3.0], &[1.0, 2.0], None, None, 800.0, 600.0);
+    assert_eq!(data.vertices[0].position, [0.0, -1.0]);
+    let data = ChartData::from_scatter_with_range(&[3.0], &[3.0], None, None, 800.0, 600.0, Some((0.0, 1.0)), None);
+    assert_eq!(data.vertices[0].position, [0.5, 0.0]);
+}
+
+#[test]
+fn test_append_scatter_numbers_dropped_points_on() {
+    let x = [0.0, f32::NAN, 10.0];
+    let mut data = ChartData::from_scatter(&x, &x, None, None, 800.0, 600.0);
+    let transform = ScatterTransform::fit(&x, &x, None, None);
+    data.append_scatter(&[f32::INFINITY, 5.0], &[1.0, 5.0], &transform, Color::default(), 2.0)
+        .unwrap();
+    assert_eq!(data.dropped(), &[1, 3]);
+    assert_eq!(data.vertices.len(), 3);
+    assert_eq!(data.source_index(2), 4);
+
+    data.set_missing_data(MissingDataPolicy::Error);
+    assert!(data.append_scatter(&[f32::NAN], &[0.0], &transform, Color::default(), 2.0).is_err());
+    assert_eq!(data.vertices.len(), 3);
+}
+
+#[test]
+fn test_transform_covering_grows_with_headroom() {
+    let empty = (f64::INFINITY, f64::NEG_INFINITY);
+    let transform = ScatterTransform::new(empty, empty, None, None);
+    // The first points are taken as they are
+    let transform = transform.covering((0.0, 10.0), (-1.0, 1.0)).unwrap();
+    assert_eq!((transform.x_in, transform.y_in), ((0.0, 10.0), (-1.0, 1.0)));
+    // Points with nothing finite, or inside, don't change it
+    assert!(transform.covering(empty, empty).is_none());
+    assert!(transform.covering((10.0, 10.0), (0.0, 0.0)).is_none());
+
+    // A step counter refits about log(n) times: each refit spans 1.5x more
+    let mut transform = transform;
+    let mut refits = 0;
+    for step in 11..100_000 {
+        if let Some(grown) = transform.covering((step as f64, step as f64), (0.0, 0.0)) {
+            assert!(grown.x_in.1 >= step as f64);
+            assert_eq!(grown.x_in.0, 0.0);
+            transform = grown;
+            refits += 1;
+        }
+    }
+    assert!(refits <= 25, "{} refits", refits);
+}
+
+#[test]
+fn test_original_points_follow_vertices() {
+    let x = [0.0, f32::NAN, 10.0];
+    let mut data = ChartData::from_scatter(&x, &[1.0, 2.0, 3.0], None, None, 800.0, 600.0);
+    assert_eq!(data.original_points(), Some(&[[0.0, 1.0], [10.0, 3.0]][..]));
+
+    let transform = ScatterTransform::fit(&x, &x, None, None);
+    data.set_max_points(Some(2));
+    data.append_scatter(&[5.0], &[-4.0], &transform, Color::default(), 2.0).unwrap();
+    assert_eq!(data.original_point(0), Some([10.0, 3.0]));
+    assert_eq!(data.original_point(1), Some([5.0, -4.0]));
+
+    // Points added in clip space have no data coordinates
+    data.append_points(&[Point2D::new(0.0, 0.0)], Color::default(), 2.0);
+    assert_eq!(data.original_point(0), None);
+    assert_eq!(data.original_points(), None);
+    data.fill_scatter(&[1.0], &[2.0], None, None, None, None);
+    assert_eq!(data.original_point(0), Some([1.0, 2.0]));
+}
+
+#[test]
+fn test_f64_input_keeps_precision() {
+    // Nanosecond timestamps a microsecond apart collapse to one f32 value
+    let t: Vec<f64> = (0..5).map(|i| 1.7e18 + i as f64 * 1024.0).collect();
+    let y = [0.0, 1.0, 2.0, 3.0, 4.0];
+    let narrow: Vec<f32> = t.iter().map(|&v| v as f32).collect();
+    let collapsed = ChartData::from_scatter(&narrow, &[0.0, 1.0, 2.0, 3.0, 4.0], None, None, 800.0, 600.0);
+    assert!(collapsed.vertices.iter().all(|v| v.position[0] == 0.0));
+
+    let data = ChartData::from_scatter_f64(&t, &y, None, None, 800.0, 600.0);
+    let xs: Vec<f32> = data.vertices.iter().map(|v| v.position[0]).collect();
+    assert_eq!(xs, [-1.0, -0.5, 0.0, 0.5, 1.0]);
+
+    let transform = ScatterTransform::fit_f64(&t, &y, None, None);
+    assert_eq!(transform.x_in, (1.7e18, 1.7e18 + 4096.0));
+    assert_eq!(transform.apply_f64(t[1], 1.0).x, xs[1]);
+}
+
+#[test]
+fn test_f64_missing_data_and_append() {
+    let x = [0.0, f64::NAN, 1e15, 2e15];
+    let mut data = ChartData::new(800.0, 600.0);
+    data.fill_scatter_f64_cancelable(&x, &x, None, None, None, None, &CancelToken::new(), &mut |_| {})
+        .unwrap();
+    assert_eq!(data.dropped(), &[1]);
+    assert_eq!(data.vertices[1].position, [0.0, 0.0]);
+
+    let transform = ScatterTransform::fit_f64(&x, &x, None, None);
+    data.append_scatter_f64(&[1.5e15 + 1.0, f64::INFINITY], &[1.5e15, 0.0], &transform, Color::default(), 2.0)
+        .unwrap();
+    assert_eq!(data.vertices.len(), 4);
+    assert_eq!(data.vertices[3].position, [0.5, 0.5]);
+    assert_eq!(data.dropped(), &[1, 5]);
+
+    data.set_missing_data(MissingDataPolicy::Error);
+    let err = data
+        .fill_scatter_f64_cancelable(&x, &x, None, None, None, None, &CancelToken::new(), &mut |_| {})
+        .unwrap_err();
+    assert!(err.to_string().contains("Point 1"), "{}", err);
+}
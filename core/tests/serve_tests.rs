@@ -0,0 +1,42 @@
+use helion_core::serve::ViewInput;
+
+#[test]
+fn test_pointer_input() {
+    assert_eq!(
+        ViewInput::from_query("kind=pointer&pressed=1&x=12.5&y=40"),
+        Some(ViewInput::Pointer { x: 12.5, y: 40.0, pressed: true })
+    );
+    // Anything but "1" is a released button
+    assert_eq!(
+        ViewInput::from_query("kind=pointer&x=0&y=0&pressed=0"),
+        Some(ViewInput::Pointer { x: 0.0, y: 0.0, pressed: false })
+    );
+    assert_eq!(
+        ViewInput::from_query("x=3&y=4&kind=pointer"),
+        Some(ViewInput::Pointer { x: 3.0, y: 4.0, pressed: false })
+    );
+}
+
+#[test]
+fn test_scroll_input() {
+    assert_eq!(
+        ViewInput::from_query("kind=scroll&steps=-1.5&x=100&y=50"),
+        Some(ViewInput::Scroll { x: 100.0, y: 50.0, steps: -1.5 })
+    );
+}
+
+#[test]
+fn test_leave_and_reset_input() {
+    assert_eq!(ViewInput::from_query("kind=leave"), Some(ViewInput::Leave));
+    assert_eq!(ViewInput::from_query("kind=reset&x=1"), Some(ViewInput::Reset));
+}
+
+#[test]
+fn test_invalid_input_is_ignored() {
+    assert_eq!(ViewInput::from_query(""), None);
+    assert_eq!(ViewInput::from_query("kind=zoom"), None);
+    assert_eq!(ViewInput::from_query("kind=scroll&x=1&y=2"), None);
+    assert_eq!(ViewInput::from_query("kind=pointer&x=abc&y=2"), None);
+    assert_eq!(ViewInput::from_query("kind=scroll&steps=NaN&x=1&y=2"), None);
+    assert_eq!(ViewInput::from_query("kind=pointer&x=inf&y=2"), None);
+}
@@ -43,11 +43,54 @@ fn test_vertex_shader_output_types() {
 
 #[test]
 fn test_scatter_shaders_exist() {
-    // Advanced shaders are present (even if not currently used)
+    // Used together by ScatterRenderer for round points
     assert!(SCATTER_VERTEX_SHADER.contains("@vertex"));
     assert!(SCATTER_FRAGMENT_SHADER.contains("@fragment"));
 }
 
+#[test]
+fn test_scatter_vertex_shader_uses_size() {
+    // Quads are scaled by the per-instance size and the viewport uniform
+    assert!(SCATTER_VERTEX_SHADER.contains("@location(2) size"));
+    assert!(SCATTER_VERTEX_SHADER.contains("instance.size"));
+    assert!(SCATTER_VERTEX_SHADER.contains("var<uniform> scatter"));
+    assert!(SCATTER_VERTEX_SHADER.contains("@builtin(vertex_index)"));
+}
+
+#[test]
+fn test_scatter_stages_agree() {
+    // The fragment stage reads the pixel offset and radius the vertex stage writes
+    for shader in [SCATTER_VERTEX_SHADER, POSITION_ONLY_VERTEX_SHADER, SCATTER_FRAGMENT_SHADER] {
+        assert!(shader.contains("@location(1) offset: vec2<f32>"));
+        assert!(shader.contains("@location(2) radius: f32"));
+        assert!(shader.contains("hard_edge: f32"));
+    }
+    assert!(SCATTER_FRAGMENT_SHADER.contains("discard"));
+}
+
+#[test]
+fn test_position_only_shader_sizes_points() {
+    // The series size sets the quad like a per-point size does
+    assert!(POSITION_ONLY_VERTEX_SHADER.contains("max(series.size, 1.0)"));
+    assert!(POSITION_ONLY_VERTEX_SHADER.contains("@builtin(vertex_index)"));
+    assert!(POSITION_ONLY_VERTEX_SHADER.contains("@group(0) @binding(1)"));
+}
+
+#[test]
+fn test_scatter_shaders_read_camera() {
+    for shader in [SCATTER_VERTEX_SHADER, POSITION_ONLY_VERTEX_SHADER] {
+        assert!(shader.contains("@group(1) @binding(0)"));
+        assert!(shader.contains("camera.view * vec4<f32>"));
+    }
+}
+
+#[test]
+fn test_line_shader_reads_camera() {
+    assert!(LINE_VERTEX_SHADER.contains("fn vs_main"));
+    assert!(LINE_VERTEX_SHADER.contains("@group(0) @binding(0)"));
+    assert!(LINE_VERTEX_SHADER.contains("camera.view * vec4<f32>(vertex.position"));
+}
+
 #[test]
 fn test_scatter_fragment_has_anti_aliasing() {
     // Verify scatter shader uses smoothstep for anti-aliasing
@@ -70,6 +113,64 @@ fn test_shader_syntax_basics() {
     }
 }
 
+#[test]
+fn test_halo_shaders() {
+    assert!(HALO_VERTEX_SHADER.contains("fn vs_main"));
+    assert!(HALO_VERTEX_SHADER.contains("var<uniform> halo"));
+    assert!(HALO_FRAGMENT_SHADER.contains("fn fs_main"));
+    // Instance attributes must match the Vertex layout
+    assert!(HALO_VERTEX_SHADER.contains("@location(0) position"));
+    assert!(HALO_VERTEX_SHADER.contains("@location(2) size"));
+}
+
+#[test]
+fn test_bin2d_compute_shader() {
+    assert!(BIN2D_COMPUTE_SHADER.contains("fn cs_main"));
+    assert!(BIN2D_COMPUTE_SHADER.contains("@workgroup_size(256)"));
+    assert!(BIN2D_COMPUTE_SHADER.contains("atomicAdd"));
+}
+
+#[test]
+fn test_colormap_shaders() {
+    assert!(COLORMAP_VERTEX_SHADER.contains("@location(1) value"));
+    assert!(COLORMAP_FRAGMENT_SHADER.contains("var<uniform> colormap"));
+    assert!(COLORMAP_FRAGMENT_SHADER.contains("texture_2d<f32>"));
+    assert!(COLORMAP_FRAGMENT_SHADER.contains("textureSampleLevel"));
+}
+
+#[test]
+fn test_glyph_vertex_shader() {
+    assert!(GLYPH_VERTEX_SHADER.contains("@vertex"));
+    assert!(GLYPH_VERTEX_SHADER.contains("var<uniform> glyph"));
+    // Mesh corner follows the three per-instance Vertex attributes
+    assert!(GLYPH_VERTEX_SHADER.contains("@location(3) corner"));
+}
+
 // Note: Full shader compilation testing requires a GPU backend.
 // That would be an integration test in tests/ folder, not a unit test.
 // For now, these syntax checks ensure we haven't accidentally broken the shader strings.
+
+#[test]
+fn test_hist1d_compute_shader() {
+    assert!(HIST1D_COMPUTE_SHADER.contains("fn cs_main"));
+    assert!(HIST1D_COMPUTE_SHADER.contains("@workgroup_size(256)"));
+    assert!(HIST1D_COMPUTE_SHADER.contains("var<storage, read> edges"));
+    assert!(HIST1D_COMPUTE_SHADER.contains("atomicAdd"));
+}
+
+#[test]
+fn test_heatmap_shaders() {
+    assert!(HEATMAP_VERTEX_SHADER.contains("fn vs_main"));
+    assert!(HEATMAP_VERTEX_SHADER.contains("var<uniform> camera"));
+    assert!(HEATMAP_FRAGMENT_SHADER.contains("textureLoad(cells"));
+    assert!(HEATMAP_FRAGMENT_SHADER.contains("textureSampleLevel(lut"));
+}
+
+#[test]
+fn test_pick_shaders() {
+    assert!(PICK_VERTEX_SHADER.contains("var<uniform> camera"));
+    assert!(PICK_VERTEX_SHADER.contains("@location(2) index: u32"));
+    assert!(PICK_VERTEX_SHADER.contains("@interpolate(flat)"));
+    assert!(PICK_FRAGMENT_SHADER.contains("@location(0) u32"));
+    assert!(PICK_FRAGMENT_SHADER.contains("discard"));
+}
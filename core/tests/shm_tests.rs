@@ -0,0 +1,121 @@
+use helion_core::ingest::shm::{region_size, SharedMemorySource, SharedMemoryWriter, SHM_HEADER_SIZE};
+use helion_core::ingest::source::{DataSource, SourceState};
+use helion_core::Point2D;
+use std::path::PathBuf;
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("helion_shm_{}_{}", std::process::id(), name))
+}
+
+fn points(range: std::ops::Range<u32>) -> Vec<Point2D> {
+    range.map(|i| Point2D::new(i as f32, i as f32 * 2.0)).collect()
+}
+
+fn poll(source: &mut SharedMemorySource) -> (SourceState, Vec<f32>) {
+    let mut out = Vec::new();
+    let state = source.poll(&mut out);
+    (state, out.iter().map(|p| p.x).collect())
+}
+
+#[test]
+fn test_region_size() {
+    assert_eq!(SHM_HEADER_SIZE, 64);
+    assert_eq!(region_size(100), 64 + 800);
+}
+
+#[test]
+fn test_file_backed_region() {
+    let path = temp_path("basic");
+    let mut writer = SharedMemoryWriter::create(&path, 16).unwrap();
+    let mut source = SharedMemorySource::open(&path).unwrap();
+
+    assert_eq!(poll(&mut source), (SourceState::Open, vec![]));
+
+    writer.push(&points(0..3));
+    let mut out = Vec::new();
+    source.poll(&mut out);
+    assert_eq!(out.iter().map(|p| (p.x, p.y)).collect::<Vec<_>>(), vec![(0.0, 0.0), (1.0, 2.0), (2.0, 4.0)]);
+
+    writer.push(&points(3..5));
+    assert_eq!(poll(&mut source), (SourceState::Open, vec![3.0, 4.0]));
+
+    drop(writer);
+    assert_eq!(poll(&mut source), (SourceState::Closed, vec![]));
+    assert_eq!(source.dropped(), 0);
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_slow_reader_loses_oldest_points() {
+    let path = temp_path("wrap");
+    let mut writer = SharedMemoryWriter::create(&path, 4).unwrap();
+    let mut source = SharedMemorySource::open(&path).unwrap();
+
+    writer.push(&points(0..3));
+    writer.push(&points(3..7));
+    assert_eq!(poll(&mut source), (SourceState::Open, vec![3.0, 4.0, 5.0, 6.0]));
+    assert_eq!(source.dropped(), 3);
+
+    // More points than slots in a single push
+    writer.push(&points(7..17));
+    assert_eq!(poll(&mut source).1, vec![13.0, 14.0, 15.0, 16.0]);
+    assert_eq!(source.dropped(), 9);
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_concurrent_producer() {
+    let path = temp_path("concurrent");
+    let mut writer = SharedMemoryWriter::create(&path, 256).unwrap();
+    let mut source = SharedMemorySource::open(&path).unwrap();
+
+    let producer = std::thread::spawn(move || {
+        for batch in 0..2000 {
+            writer.push(&points(batch * 10..batch * 10 + 10));
+        }
+    });
+
+    let mut received = Vec::new();
+    loop {
+        let state = source.poll(&mut received);
+        if state == SourceState::Closed {
+            break;
+        }
+    }
+    producer.join().unwrap();
+
+    // Whatever was kept arrives in order and untorn
+    assert!(received.windows(2).all(|w| w[0].x < w[1].x));
+    assert!(received.iter().all(|p| p.y == p.x * 2.0));
+    assert_eq!(received.len() as u64 + source.dropped(), 20_000);
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_invalid_regions_are_rejected() {
+    let path = temp_path("invalid");
+    std::fs::write(&path, vec![0u8; 128]).unwrap();
+    assert!(SharedMemorySource::open(&path).is_err());
+    assert!(SharedMemorySource::open(temp_path("missing")).is_err());
+    assert!(SharedMemoryWriter::create(&path, 0).is_err());
+    std::fs::remove_file(path).unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn test_named_shared_memory() {
+    use helion_core::ingest::shm::remove_shm;
+
+    let name = format!("helion_test_{}", std::process::id());
+    let mut writer = SharedMemoryWriter::create_shm(&name, 8).unwrap();
+    let mut source = SharedMemorySource::open_shm(&name).unwrap();
+
+    writer.push(&points(0..2));
+    assert_eq!(poll(&mut source).1, vec![0.0, 1.0]);
+
+    remove_shm(&name).unwrap();
+    assert!(SharedMemorySource::open_shm(&name).is_err());
+}
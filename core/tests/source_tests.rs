@@ -0,0 +1,93 @@
+use helion_core::ingest::source::{channel, DataSource, IterSource, SourceState, DEFAULT_BATCH_SIZE};
+use helion_core::Point2D;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::sync::Arc;
+
+fn xs(points: &[Point2D]) -> Vec<f32> {
+    points.iter().map(|p| p.x).collect()
+}
+
+#[test]
+fn test_receiver_source() {
+    let (sender, mut receiver) = mpsc::channel();
+    let mut out = Vec::new();
+
+    assert_eq!(receiver.poll(&mut out), SourceState::Open);
+    assert!(out.is_empty());
+
+    sender.send(Point2D::new(1.0, 2.0)).unwrap();
+    sender.send(Point2D::new(3.0, 4.0)).unwrap();
+    assert_eq!(receiver.poll(&mut out), SourceState::Open);
+    assert_eq!(xs(&out), vec![1.0, 3.0]);
+
+    // Points sent before the sender went away are still delivered
+    sender.send(Point2D::new(5.0, 6.0)).unwrap();
+    drop(sender);
+    assert_eq!(receiver.poll(&mut out), SourceState::Closed);
+    assert_eq!(xs(&out), vec![1.0, 3.0, 5.0]);
+}
+
+#[test]
+fn test_channel_source_notifies_subscribers() {
+    let (sender, mut source) = channel();
+    let wakeups = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&wakeups);
+    assert!(source.subscribe(Arc::new(move || {
+        counter.fetch_add(1, Ordering::SeqCst);
+    })));
+
+    sender.send(Point2D::new(0.0, 0.0)).unwrap();
+    sender.send_all((1..4).map(|i| Point2D::new(i as f32, 0.0))).unwrap();
+    assert_eq!(wakeups.load(Ordering::SeqCst), 2);
+
+    let mut out = Vec::new();
+    assert_eq!(source.poll(&mut out), SourceState::Open);
+    assert_eq!(xs(&out), vec![0.0, 1.0, 2.0, 3.0]);
+
+    drop(source);
+    assert!(sender.send(Point2D::new(9.0, 9.0)).is_err());
+}
+
+#[test]
+fn test_iter_source_batches() {
+    let points = (0..5).map(|i| Point2D::new(i as f32, 0.0));
+    let mut source = IterSource::new(points).with_batch_size(2);
+    let mut out = Vec::new();
+
+    assert_eq!(source.poll(&mut out), SourceState::Open);
+    assert_eq!(xs(&out), vec![0.0, 1.0]);
+    assert_eq!(source.poll(&mut out), SourceState::Open);
+    assert_eq!(source.poll(&mut out), SourceState::Closed);
+    assert_eq!(xs(&out), vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(source.poll(&mut out), SourceState::Closed);
+    assert_eq!(out.len(), 5);
+}
+
+#[test]
+fn test_endless_iterator_is_capped_per_poll() {
+    let mut source = IterSource::new((0..).map(|i| Point2D::new(i as f32, 0.0)));
+    let mut out = Vec::new();
+
+    assert_eq!(source.poll(&mut out), SourceState::Open);
+    assert_eq!(out.len(), DEFAULT_BATCH_SIZE);
+    // Iterators can't notify, so callers fall back to polling
+    assert!(!source.subscribe(Arc::new(|| {})));
+}
+
+#[test]
+fn test_sources_are_interchangeable() {
+    let (sender, receiver) = mpsc::channel();
+    sender.send(Point2D::new(7.0, 0.0)).unwrap();
+    drop(sender);
+
+    let mut sources: Vec<Box<dyn DataSource>> = vec![
+        Box::new(receiver),
+        Box::new(IterSource::new(vec![Point2D::new(8.0, 0.0)])),
+    ];
+    let mut out = Vec::new();
+    let states: Vec<_> = sources.iter_mut().map(|s| s.poll(&mut out)).collect();
+
+    assert_eq!(states, vec![SourceState::Closed, SourceState::Closed]);
+    assert_eq!(xs(&out), vec![7.0, 8.0]);
+}
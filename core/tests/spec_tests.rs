@@ -0,0 +1,104 @@
+use helion_core::data::Color;
+use helion_core::error::HelionError;
+use helion_core::figure::{ChartKind, Figure, DEFAULT_COLOR_CYCLE};
+use helion_core::scale::AxisScale;
+use helion_core::spec::{FigureSpec, SPEC_VERSION};
+
+#[test]
+fn test_spec_round_trip() {
+    let mut figure = Figure::new(640.0, 480.0).with_title("Load").with_xlim((0.0, 5.0));
+    figure.set_ylabel(Some("req/s".into()));
+    figure.scatter(vec![0.0, 1.0], vec![2.0, 3.0]).unwrap().label = Some("api".into());
+    figure.line(vec![0.0, 5.0], vec![1.0, 1.0]).unwrap().style.line_width = 2.0;
+
+    let json = FigureSpec::from_figure(&figure).to_json();
+    let spec = FigureSpec::from_json(&json).unwrap();
+    assert_eq!(spec.version, SPEC_VERSION);
+    let rebuilt = spec.into_figure().unwrap();
+
+    assert_eq!(rebuilt.text(), figure.text());
+    assert_eq!(rebuilt.xlim(), Some((0.0, 5.0)));
+    assert_eq!(rebuilt.ylim(), None);
+    assert_eq!(rebuilt.len(), 2);
+    assert_eq!(rebuilt.series()[0].label.as_deref(), Some("api"));
+    assert_eq!(rebuilt.series()[1].kind, ChartKind::Line);
+    assert_eq!(rebuilt.series()[1].style.line_width, 2.0);
+    assert_eq!(rebuilt.series()[1].y(), [1.0, 1.0]);
+    assert_eq!(rebuilt.series()[1].style.color.to_hex(), figure.series()[1].style.color.to_hex());
+}
+
+#[test]
+fn test_minimal_spec_uses_defaults() {
+    let spec = FigureSpec::from_json(
+        r#"{"width": 100, "height": 100, "axes": false, "series": [
+            {"x": [0], "y": [0]},
+            {"kind": "line", "x": [0, 1], "y": [0, 1], "style": {"opacity": 0.5}}
+        ]}"#,
+    )
+    .unwrap();
+    let figure = spec.into_figure().unwrap();
+    assert!(figure.axes().is_none());
+    assert_eq!(figure.series()[0].kind, ChartKind::Scatter);
+    // Unstyled series still get their own color from the cycle
+    assert_eq!(figure.series()[1].style.color, Color::from_hex(DEFAULT_COLOR_CYCLE[1]));
+    assert_eq!(figure.series()[1].style.opacity, 0.5);
+}
+
+#[test]
+fn test_invalid_specs() {
+    let invalid = |json: &str| matches!(FigureSpec::from_json(json), Err(HelionError::InvalidOption(_)));
+    assert!(invalid("not json"));
+    assert!(invalid(r#"{"width": 1, "height": 1, "colour": "red"}"#));
+    assert!(invalid(&format!(r#"{{"version": {}, "width": 1, "height": 1}}"#, SPEC_VERSION + 1)));
+
+    let mismatched = FigureSpec::from_json(r#"{"width": 1, "height": 1, "series": [{"x": [0, 1], "y": [0]}]}"#);
+    assert!(matches!(mismatched.unwrap().into_figure(), Err(HelionError::ShapeMismatch { .. })));
+    let bad_style = FigureSpec::from_json(r#"{"width": 1, "height": 1, "series": [{"x": [0], "y": [0], "style": {"shade": 1}}]}"#);
+    assert!(matches!(bad_style.unwrap().into_figure(), Err(HelionError::InvalidOption(_))));
+}
+
+#[test]
+fn test_bars_spec() {
+    let spec = FigureSpec::from_json(r#"{"width": 100, "height": 100, "series": [{"kind": "bars", "x": [0, 1, 2], "y": [3, 1]}]}"#).unwrap();
+    let figure = spec.clone().into_figure().unwrap();
+    assert_eq!(figure.series()[0].kind, ChartKind::Bars);
+    assert_eq!(figure.series()[0].len(), 2);
+    assert_eq!(FigureSpec::from_figure(&figure).series[0].x, spec.series[0].x);
+
+    let mismatched = r#"{"width": 100, "height": 100, "series": [{"kind": "bars", "x": [0, 1], "y": [3, 1]}]}"#;
+    let err = FigureSpec::from_json(mismatched).unwrap().into_figure().unwrap_err();
+    assert!(matches!(err, HelionError::ShapeMismatch { .. }));
+}
+
+#[test]
+fn test_axis_scales_round_trip() {
+    let mut figure = Figure::new(100.0, 100.0);
+    figure.set_xscale(AxisScale::time(1_709_251_200.0));
+    figure.set_yscale(AxisScale::Log10);
+    let json = FigureSpec::from_figure(&figure).to_json();
+    assert!(json.contains(r#""xscale":{"time":{"origin":1709251200.0}}"#));
+    assert!(json.contains(r#""yscale":"log10""#));
+
+    let restored = FigureSpec::from_json(&json).unwrap().into_figure().unwrap();
+    assert_eq!(restored.xscale(), figure.xscale());
+    assert_eq!(restored.yscale(), AxisScale::Log10);
+    // Linear axes are left out
+    assert!(!FigureSpec::from_figure(&Figure::new(1.0, 1.0)).to_json().contains("scale"));
+}
+
+#[test]
+fn test_log2_and_symlog_scales_round_trip() {
+    let mut figure = Figure::new(200.0, 100.0);
+    figure.set_xscale(AxisScale::Log2);
+    figure.set_yscale(AxisScale::symlog(0.5));
+    let json = FigureSpec::from_figure(&figure).to_json();
+    assert!(json.contains(r#""xscale":"log2""#));
+    assert!(json.contains(r#""yscale":{"symlog":{"linthresh":0.5}}"#));
+    let restored = FigureSpec::from_json(&json).unwrap().into_figure().unwrap();
+    assert_eq!(restored.xscale(), AxisScale::Log2);
+    assert_eq!(restored.yscale(), AxisScale::SymLog { linthresh: 0.5 });
+
+    let zero = json.replace(r#""linthresh":0.5"#, r#""linthresh":0.0"#);
+    let err = FigureSpec::from_json(&zero).unwrap_err();
+    assert_eq!(err.to_string(), "yscale linthresh must be a positive number, got 0");
+}
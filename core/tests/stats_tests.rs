@@ -0,0 +1,69 @@
+use helion_core::stats::{DrawStats, FrameRecorder, FrameReport};
+
+#[test]
+fn test_instanced_stats_count_mesh_vertices() {
+    let stats = DrawStats::instanced(48, 1000, 2);
+    assert_eq!(stats.vertices, 48_000);
+    assert_eq!(stats.instances, 1000);
+    assert_eq!(stats.draw_calls, 2);
+
+    let plain = DrawStats::draws(500, 1);
+    assert_eq!(plain.instances, 0);
+}
+
+#[test]
+fn test_report_totals() {
+    let report = FrameReport {
+        frame: 3,
+        series: vec![
+            ("points".to_string(), DrawStats::draws(1000, 1).with_bytes_uploaded(40_000)),
+            ("markers".to_string(), DrawStats::instanced(6, 10, 1)),
+        ],
+    };
+
+    let total = report.total();
+    assert_eq!(total.vertices, 1060);
+    assert_eq!(total.instances, 10);
+    assert_eq!(total.draw_calls, 2);
+    assert_eq!(total.bytes_uploaded, 40_000);
+
+    assert_eq!(report.get("markers").unwrap().instances, 10);
+    assert!(report.get("missing").is_none());
+}
+
+#[test]
+fn test_overlay_lines() {
+    let report = FrameReport {
+        frame: 0,
+        series: vec![
+            ("points".to_string(), DrawStats::draws(2_500_000, 3).with_bytes_uploaded(100_000_000)),
+            ("markers".to_string(), DrawStats::instanced(6, 10, 1)),
+        ],
+    };
+
+    let lines = report.overlay_lines();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0], "frame 0: 4 draws, 2.5M vertices, 95.4 MiB uploaded");
+    assert_eq!(lines[1], "  points: 2.5M vertices");
+    assert_eq!(lines[2], "  markers: 60 vertices, 10 instances");
+}
+
+#[test]
+fn test_recorder_keeps_last_finished_frame() {
+    let mut recorder = FrameRecorder::new();
+    assert!(recorder.last_report().is_none());
+
+    recorder.begin_frame();
+    recorder.record("a", DrawStats::draws(10, 1));
+    assert_eq!(recorder.end_frame().frame, 0);
+
+    // A frame in progress does not replace the last finished one
+    recorder.begin_frame();
+    recorder.record("b", DrawStats::draws(20, 1));
+    assert_eq!(recorder.last_report().unwrap().total().vertices, 10);
+
+    let report = recorder.end_frame();
+    assert_eq!(report.frame, 1);
+    assert_eq!(report.series.len(), 1);
+    assert_eq!(report.total().vertices, 20);
+}
@@ -0,0 +1,93 @@
+use helion_core::axes::LineDash;
+use helion_core::data::{ChartData, Color};
+use helion_core::glyph::GlyphShape;
+use helion_core::style::{Marker, SeriesStyle};
+
+#[test]
+fn test_empty_json_gives_defaults() {
+    assert_eq!(SeriesStyle::from_json("{}").unwrap(), SeriesStyle::default());
+}
+
+#[test]
+fn test_json_round_trip() {
+    let style = SeriesStyle::new()
+        .with_marker(Marker::Cross)
+        .with_size(7.5)
+        .with_color(Color::parse_hex("#336699cc").unwrap())
+        .with_line_width(2.0)
+        .with_dash(LineDash::Dashed { on: 6.0, off: 3.0 })
+        .with_opacity(0.5)
+        .with_z_order(-2);
+
+    let json = style.to_json();
+    assert!(json.contains("\"color\":\"#336699cc\""), "{}", json);
+    assert_eq!(SeriesStyle::from_json(&json).unwrap(), style);
+}
+
+#[test]
+fn test_color_forms() {
+    let named = SeriesStyle::from_json(r#"{"color": "tomato"}"#).unwrap();
+    assert_eq!(named.color, Color::parse("tomato").unwrap());
+
+    let rgb = SeriesStyle::from_json(r#"{"color": [1.0, 0.0, 0.5]}"#).unwrap();
+    assert_eq!(rgb.color, Color::new(1.0, 0.0, 0.5, 1.0));
+
+    let rgba = SeriesStyle::from_json(r#"{"color": [1.0, 0.0, 0.5, 0.25]}"#).unwrap();
+    assert_eq!(rgba.color, Color::new(1.0, 0.0, 0.5, 0.25));
+}
+
+#[test]
+fn test_invalid_styles_are_rejected() {
+    let err = SeriesStyle::from_json(r#"{"color": "tomatoe"}"#).unwrap_err();
+    assert!(err.contains("tomatoe"), "{}", err);
+
+    assert!(SeriesStyle::from_json(r#"{"color": [1.0, 0.0]}"#).is_err());
+    assert!(SeriesStyle::from_json(r#"{"marker": "star"}"#).is_err());
+
+    let err = SeriesStyle::from_json(r#"{"colour": "red"}"#).unwrap_err();
+    assert!(err.contains("colour"), "{}", err);
+}
+
+#[test]
+fn test_patched_keeps_unset_fields() {
+    let theme = SeriesStyle::new()
+        .with_color(Color::new(0.3, 0.3, 0.3, 1.0))
+        .with_size(5.0)
+        .with_z_order(1);
+    let style = theme.patched(r#"{"marker": "square", "dash": "solid"}"#).unwrap();
+
+    assert_eq!(style.marker, Marker::Square);
+    // 0.3 isn't representable in 8 bits; patching must not round it
+    assert_eq!(style.color, theme.color);
+    assert_eq!((style.size, style.z_order), (5.0, 1));
+}
+
+#[test]
+fn test_patched_rejects_bad_input() {
+    let theme = SeriesStyle::default();
+    assert!(theme.patched("[1, 2]").is_err());
+    assert!(theme.patched(r#"{"sizes": 3}"#).unwrap_err().contains("sizes"));
+    assert!(theme.patched(r#"{"size": "big"}"#).unwrap_err().contains("size"));
+}
+
+#[test]
+fn test_apply_sets_color_and_size() {
+    let mut data = ChartData::from_scatter(&[0.0, 1.0, 2.0], &[0.0, 1.0, 2.0], None, Some(1.0), 100.0, 100.0);
+    SeriesStyle::new()
+        .with_color(Color::new(1.0, 0.0, 0.0, 0.8))
+        .with_opacity(0.5)
+        .with_size(9.0)
+        .apply(&mut data);
+
+    for vertex in &data.vertices {
+        assert_eq!(vertex.color, [1.0, 0.0, 0.0, 0.4]);
+        assert_eq!(vertex.size, 9.0);
+    }
+}
+
+#[test]
+fn test_marker_glyphs() {
+    assert_eq!(Marker::Circle.glyph(), GlyphShape::default());
+    assert_eq!(Marker::Triangle.glyph(), GlyphShape::Triangle);
+    assert_eq!(serde_json::to_string(&Marker::Diamond).unwrap(), "\"diamond\"");
+}
@@ -0,0 +1,116 @@
+use helion_core::hierarchy::HierarchyNode;
+use helion_core::sunburst::Sunburst;
+
+fn budget() -> Sunburst {
+    let root = HierarchyNode::group(
+        "Budget",
+        vec![
+            HierarchyNode::group(
+                "Staff",
+                vec![
+                    HierarchyNode::group("Salaries", vec![HierarchyNode::leaf("Engineering", 4.0), HierarchyNode::leaf("Sales", 2.0)]),
+                    HierarchyNode::leaf("Travel", 2.0),
+                ],
+            ),
+            HierarchyNode::leaf("Rent", 8.0),
+            HierarchyNode::leaf("Unused", 0.0),
+        ],
+    );
+    Sunburst::new(root).unwrap()
+}
+
+#[test]
+fn test_arcs_split_parent_angle_by_value() {
+    let sunburst = budget();
+    let arcs = sunburst.arcs();
+    // Zero-valued nodes get no arc; parents come before children
+    let nodes: Vec<usize> = arcs.iter().map(|arc| arc.node).collect();
+    assert_eq!(nodes, [0, 1, 2, 3, 4, 5, 6]);
+
+    // Three rings around the center disc, all equally thick
+    assert_eq!((arcs[0].inner, arcs[0].outer), (0.0, 0.25));
+    let salaries = arcs[2];
+    assert_eq!((salaries.start, salaries.end), (0.0, 135.0));
+    assert_eq!((salaries.inner, salaries.outer), (0.5, 0.75));
+    let sales = arcs[4];
+    assert_eq!((sales.start, sales.end), (90.0, 135.0));
+    assert_eq!(sales.outer, 1.0);
+    let rent = arcs[6];
+    assert_eq!((rent.start, rent.end), (180.0, 360.0));
+}
+
+#[test]
+fn test_zoom_spreads_subtree_around_circle() {
+    let mut sunburst = budget();
+    assert!(sunburst.zoom_to(1));
+    let arcs = sunburst.arcs();
+    let nodes: Vec<usize> = arcs.iter().map(|arc| arc.node).collect();
+    assert_eq!(nodes, [1, 2, 3, 4, 5]);
+    assert_eq!((arcs[0].inner, arcs[0].outer), (0.0, 1.0 / 3.0));
+    // Salaries had 3/4 of Staff's half turn, now 3/4 of the full circle
+    assert_eq!((arcs[1].start, arcs[1].end), (0.0, 270.0));
+
+    // Leaves, the focus itself and unknown nodes can't be zoomed to
+    assert!(!sunburst.zoom_to(6));
+    assert!(!sunburst.zoom_to(1));
+    assert!(!sunburst.zoom_to(99));
+    assert_eq!(sunburst.focus(), 1);
+}
+
+#[test]
+fn test_click_zooms_in_and_out() {
+    let mut sunburst = budget();
+    let (w, h) = (200.0, 200.0);
+    // The inner ring left of the center is Rent, a leaf: nothing happens
+    assert_eq!(sunburst.node_at(60.0, 100.0, w, h), Some(6));
+    assert_eq!(sunburst.click(60.0, 100.0, w, h), None);
+    // Outside the circle there is nothing to click
+    assert_eq!(sunburst.click(2.0, 2.0, w, h), None);
+
+    // Salaries, in the second ring just east of north
+    assert_eq!(sunburst.node_at(110.0, 40.0, w, h), Some(2));
+    assert_eq!(sunburst.click(110.0, 40.0, w, h), Some(2));
+    assert_eq!(sunburst.focus(), 2);
+
+    // The center zooms out one level at a time, and not past the root
+    assert_eq!(sunburst.click(100.0, 100.0, w, h), Some(1));
+    assert_eq!(sunburst.click(100.0, 100.0, w, h), Some(0));
+    assert_eq!(sunburst.click(100.0, 100.0, w, h), None);
+}
+
+#[test]
+fn test_max_depth_limits_rings() {
+    let sunburst = budget().with_max_depth(Some(1));
+    let nodes: Vec<usize> = sunburst.arcs().iter().map(|arc| arc.node).collect();
+    assert_eq!(nodes, [0, 1, 6]);
+    assert_eq!(sunburst.arcs()[1].outer, 1.0);
+}
+
+#[test]
+fn test_colors_follow_top_level_branch() {
+    let sunburst = budget();
+    let staff = sunburst.node_color(1);
+    let salaries = sunburst.node_color(2);
+    let rent = sunburst.node_color(6);
+    assert_ne!(staff, rent);
+    // Deeper levels are lighter shades of their branch's color
+    assert!(salaries.r + salaries.g + salaries.b > staff.r + staff.g + staff.b);
+}
+
+#[test]
+fn test_chart_data_and_labels() {
+    let sunburst = budget();
+    let data = sunburst.chart_data(400.0, 300.0);
+    assert!(!data.vertices.is_empty());
+    // The circle fills the shorter side, centered
+    for vertex in &data.vertices {
+        let [x, y] = vertex.position;
+        assert!(x.abs() <= 0.75 + 1e-4 && y.abs() <= 1.0 + 1e-4);
+    }
+
+    let labels: Vec<String> = sunburst.text_sections(400.0, 300.0).iter().map(|s| s.text.clone()).collect();
+    assert!(labels.contains(&"Budget".to_string()));
+    assert!(labels.contains(&"Rent".to_string()));
+    // Too thin a wedge for its label
+    assert!(!labels.contains(&"Engineering".to_string()));
+}
@@ -0,0 +1,63 @@
+use helion_core::sweep::Sweep;
+
+#[test]
+fn test_samples_fill_slots_left_to_right() {
+    let mut sweep = Sweep::new(5).unwrap().with_y_range(0.0, 10.0);
+    let (first, vertices) = sweep.advance(&[0.0, 5.0, 10.0]);
+    assert_eq!(first, 0);
+    let positions: Vec<[f32; 2]> = vertices.iter().map(|v| v.position).collect();
+    assert_eq!(positions, vec![[-1.0, -1.0], [-0.5, 0.0], [0.0, 1.0]]);
+
+    // Before the first pass completes only the written slots are drawn
+    assert_eq!(sweep.head(), 3);
+    assert_eq!(sweep.visible_slots(), [0..3, 0..0]);
+}
+
+#[test]
+fn test_wrapping_overwrites_oldest_with_erase_bar() {
+    let mut sweep = Sweep::new(10).unwrap().with_erase_width(0.2);
+    assert_eq!(sweep.erase_slots(), 2);
+    sweep.advance(&[0.0; 8]);
+    let (first, vertices) = sweep.advance(&[0.5; 4]);
+    // Writes continue at slot 8 and wrap around to the left edge
+    assert_eq!(first, 8);
+    let xs: Vec<f32> = vertices.iter().map(|v| v.position[0]).collect();
+    assert_eq!(xs, vec![sweep.slot_x(8), sweep.slot_x(9), -1.0, sweep.slot_x(1)]);
+    assert_eq!(sweep.written(), 12);
+    assert_eq!(sweep.visible_slots(), [0..2, 4..10]);
+
+    // Near the right edge the erase bar runs off the panel
+    sweep.advance(&[0.5; 7]);
+    assert_eq!(sweep.head(), 9);
+    assert_eq!(sweep.visible_slots(), [0..9, 10..10]);
+}
+
+#[test]
+fn test_long_push_keeps_last_pass() {
+    let mut sweep = Sweep::new(4).unwrap();
+    sweep.advance(&[0.0]);
+    let samples: Vec<f32> = (0..10).map(|i| i as f32 / 10.0).collect();
+    let (first, vertices) = sweep.advance(&samples);
+    // Samples 0..6 would be overwritten within the call; sample 6 is the
+    // 8th written overall, so it goes to slot 3
+    assert_eq!((first, vertices.len()), (3, 4));
+    assert_eq!(vertices[0].position[1], 0.6);
+    assert_eq!(sweep.written(), 11);
+    assert_eq!(sweep.head(), 3);
+}
+
+#[test]
+fn test_nan_repeats_previous_value() {
+    let mut sweep = Sweep::new(8).unwrap();
+    let (_, vertices) = sweep.advance(&[0.5, f32::NAN, -0.25, f32::NAN]);
+    let ys: Vec<f32> = vertices.iter().map(|v| v.position[1]).collect();
+    assert_eq!(ys, vec![0.5, 0.5, -0.25, -0.25]);
+}
+
+#[test]
+fn test_invalid_capacity() {
+    assert!(Sweep::new(1).is_err());
+    // The erase bar never hides the whole panel
+    let sweep = Sweep::new(3).unwrap().with_erase_width(1.0);
+    assert_eq!(sweep.erase_slots(), 2);
+}
@@ -0,0 +1,94 @@
+use helion_core::ingest::source::{DataSource, SourceState};
+use helion_core::ingest::tail::{Column, TailSource};
+use helion_core::Point2D;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+
+fn temp_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("helion_tail_{}_{}", std::process::id(), name));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn append(path: &PathBuf, text: &str) {
+    let mut file = OpenOptions::new().create(true).append(true).open(path).unwrap();
+    file.write_all(text.as_bytes()).unwrap();
+}
+
+fn poll(source: &mut TailSource) -> Vec<(f32, f32)> {
+    let mut out: Vec<Point2D> = Vec::new();
+    assert_eq!(source.poll(&mut out), SourceState::Open);
+    out.iter().map(|p| (p.x, p.y)).collect()
+}
+
+#[test]
+fn test_tail_csv_by_name() {
+    let path = temp_path("named.csv");
+    let mut source = TailSource::csv(&path, Some("step".into()), "loss".into());
+
+    // Waits for the file to appear
+    assert!(poll(&mut source).is_empty());
+
+    append(&path, "loss,step\n0.9,1\n0.7,");
+    assert_eq!(poll(&mut source), vec![(1.0, 0.9)]);
+
+    // The partial row is completed by the next write
+    append(&path, "2\n0.5,3\n");
+    assert_eq!(poll(&mut source), vec![(2.0, 0.7), (3.0, 0.5)]);
+    assert!(poll(&mut source).is_empty());
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_tail_csv_by_index_uses_row_number() {
+    let path = temp_path("indexed.tsv");
+    append(&path, "time\tvalue\r\n0\t5\r\nnot\ta number\r\n1\t6\r\n");
+    let mut source = TailSource::csv(&path, None, Column::Index(1)).with_delimiter(b'\t');
+
+    assert_eq!(poll(&mut source), vec![(0.0, 5.0), (1.0, 6.0)]);
+    // The header and the bad row
+    assert_eq!(source.skipped_lines(), 2);
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_tail_ndjson() {
+    let path = temp_path("metrics.ndjson");
+    append(&path, "{\"t\": 1, \"temp\": 20.5}\n{\"t\": 2, \"temp\": \"n/a\"}\n\n{\"temp\": 21, \"t\": 3}\n");
+    let mut source = TailSource::ndjson(&path, Some("t"), "temp");
+
+    assert_eq!(poll(&mut source), vec![(1.0, 20.5), (3.0, 21.0)]);
+    assert_eq!(source.skipped_lines(), 1);
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_tail_restarts_after_truncation() {
+    let path = temp_path("rotated.csv");
+    append(&path, "1,10\n2,20\n3,30\n");
+    let mut source = TailSource::csv(&path, Some(0.into()), 1.into());
+    assert_eq!(poll(&mut source).len(), 3);
+
+    std::fs::write(&path, "1,99\n").unwrap();
+    assert_eq!(poll(&mut source), vec![(1.0, 99.0)]);
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_tail_from_end() {
+    let path = temp_path("follow.csv");
+    append(&path, "x,y\n1,1\n2,2\n3,");
+    let mut source = TailSource::csv(&path, Some("x".into()), "y".into()).from_end();
+
+    // Existing rows, including the one being written, are ignored
+    assert!(poll(&mut source).is_empty());
+    append(&path, "3\n4,4\n");
+    assert_eq!(poll(&mut source), vec![(4.0, 4.0)]);
+
+    std::fs::remove_file(path).unwrap();
+}
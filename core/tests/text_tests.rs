@@ -0,0 +1,103 @@
+use helion_core::data::Color;
+use helion_core::text::{quad_vertices, GlyphAtlas, HAlign, TextSection, VAlign};
+
+#[test]
+fn test_layout_one_quad_per_visible_glyph() {
+    let mut atlas = GlyphAtlas::default();
+    let quads = atlas.layout(&TextSection::new("a b\nc", [10.0, 20.0], 12.0));
+    // Spaces and line breaks draw nothing
+    assert_eq!(quads.len(), 3);
+    // The second line sits below the first
+    assert!(quads[2].corners[0][1] > quads[0].corners[0][1] + 10.0);
+    assert!(!atlas.overflowed());
+}
+
+#[test]
+fn test_layout_alignment() {
+    let mut atlas = GlyphAtlas::default();
+    let [width, height] = atlas.measure("Helion", 20.0);
+    let bounds = |atlas: &mut GlyphAtlas, h: HAlign, v: VAlign| {
+        let quads = atlas.layout(&TextSection::new("Helion", [100.0, 100.0], 20.0).with_align(h, v));
+        let xs = quads.iter().flat_map(|q| q.corners.map(|c| c[0]));
+        let ys = quads.iter().flat_map(|q| q.corners.map(|c| c[1]));
+        (
+            xs.clone().fold(f32::INFINITY, f32::min),
+            xs.fold(f32::NEG_INFINITY, f32::max),
+            ys.clone().fold(f32::INFINITY, f32::min),
+            ys.fold(f32::NEG_INFINITY, f32::max),
+        )
+    };
+
+    let (left, right, top, bottom) = bounds(&mut atlas, HAlign::Left, VAlign::Baseline);
+    assert!(left >= 99.0 && right <= 100.0 + width + 1.0);
+    // Glyphs without descenders end on the baseline
+    assert!(top < 100.0 && (bottom - 100.0).abs() <= 1.0);
+
+    let (left, right, _, _) = bounds(&mut atlas, HAlign::Center, VAlign::Baseline);
+    assert!((left + right - 200.0).abs() <= 3.0);
+    let (_, right, _, _) = bounds(&mut atlas, HAlign::Right, VAlign::Baseline);
+    assert!(right <= 101.0 && right > 95.0);
+
+    let (_, _, top, bottom) = bounds(&mut atlas, HAlign::Left, VAlign::Top);
+    assert!(top >= 99.0 && bottom < 100.0 + height);
+    let (_, _, top, _) = bounds(&mut atlas, HAlign::Left, VAlign::Bottom);
+    assert!(top > 100.0 - height - 1.0 && top < 100.0);
+}
+
+#[test]
+fn test_layout_rotation_turns_text_upward() {
+    let mut atlas = GlyphAtlas::default();
+    let quads = atlas.layout(&TextSection::new("ll", [50.0, 50.0], 16.0).with_rotation(90.0));
+    assert_eq!(quads.len(), 2);
+    // Reading direction points up the screen
+    assert!(quads[1].corners[0][1] < quads[0].corners[0][1]);
+    assert!((quads[1].corners[0][0] - quads[0].corners[0][0]).abs() < 1e-3);
+}
+
+#[test]
+fn test_glyphs_are_cached_per_size() {
+    let mut atlas = GlyphAtlas::default();
+    atlas.take_dirty_rows();
+    let first = atlas.layout(&TextSection::new("x", [0.0, 0.0], 12.0));
+    assert!(atlas.take_dirty_rows().is_some());
+    let again = atlas.layout(&TextSection::new("x", [0.0, 0.0], 12.0));
+    assert_eq!(first[0].uv_min, again[0].uv_min);
+    assert!(atlas.take_dirty_rows().is_none());
+
+    let larger = atlas.layout(&TextSection::new("x", [0.0, 0.0], 24.0));
+    assert_ne!(first[0].uv_min, larger[0].uv_min);
+    assert!(atlas.pixels().iter().any(|&p| p > 0));
+}
+
+#[test]
+fn test_atlas_overflow_and_clear() {
+    let mut atlas = GlyphAtlas::new(helion_core::text::DEFAULT_FONT.to_vec(), 32).unwrap();
+    let quads = atlas.layout(&TextSection::new("ABCDEFGH", [0.0, 0.0], 20.0));
+    assert!(atlas.overflowed());
+    assert!(quads.len() < 8);
+
+    atlas.clear();
+    assert!(!atlas.overflowed());
+    assert_eq!(atlas.take_dirty_rows(), Some((0, 32)));
+    assert!(atlas.pixels().iter().all(|&p| p == 0));
+}
+
+#[test]
+fn test_invalid_font_rejected() {
+    assert!(GlyphAtlas::new(vec![0, 1, 2, 3], 64).is_err());
+}
+
+#[test]
+fn test_quad_vertices_in_clip_space() {
+    let mut atlas = GlyphAtlas::default();
+    let section = TextSection::new("H", [100.0, 50.0], 12.0).with_color(Color::new(1.0, 0.0, 0.0, 1.0));
+    let quads = atlas.layout(&section);
+    let vertices = quad_vertices(&quads, 200.0, 100.0);
+    assert_eq!(vertices.len(), 6);
+    for v in &vertices {
+        // Just right of the center, above the middle row
+        assert!(v.position[0] >= -0.01 && v.position[0] < 0.2);
+        assert!(v.position[1] >= -0.01 && v.position[1] < 0.3);
+        assert_eq!(v.color, [1.0, 0.0, 0.0, 1.0]);
+    }
+}
@@ -0,0 +1,38 @@
+use helion_core::timeseries::{gap_indices, gap_threshold, segment_runs, GapThreshold};
+
+#[test]
+fn test_no_threshold_keeps_one_run() {
+    let time = [0.0, 1.0, 50.0, 51.0];
+    let values = [1.0, 2.0, 3.0, 4.0];
+    assert_eq!(segment_runs(&time, &values, None), vec![0..4]);
+}
+
+#[test]
+fn test_absolute_threshold() {
+    let time = [0.0, 1.0, 2.0, 10.0, 11.0, 30.0];
+    let values = [0.0; 6];
+    let runs = segment_runs(&time, &values, Some(GapThreshold::Absolute(5.0)));
+    assert_eq!(runs, vec![0..3, 3..5, 5..6]);
+}
+
+#[test]
+fn test_median_multiple_threshold() {
+    // Median step is 60; 3x median = 180
+    let time = [0.0, 60.0, 120.0, 180.0, 600.0, 660.0];
+    assert_eq!(gap_threshold(&time, GapThreshold::MedianMultiple(3.0)), Some(180.0));
+    assert_eq!(gap_indices(&time, GapThreshold::MedianMultiple(3.0)), vec![4]);
+}
+
+#[test]
+fn test_nan_breaks_run() {
+    let time = [0.0, 1.0, 2.0, 3.0, 4.0];
+    let values = [1.0, 2.0, f32::NAN, 4.0, 5.0];
+    assert_eq!(segment_runs(&time, &values, None), vec![0..2, 3..5]);
+}
+
+#[test]
+fn test_degenerate_thresholds() {
+    assert_eq!(gap_threshold(&[1.0], GapThreshold::MedianMultiple(3.0)), None);
+    assert_eq!(gap_threshold(&[0.0, 1.0], GapThreshold::Absolute(0.0)), None);
+    assert!(segment_runs(&[], &[], Some(GapThreshold::default())).is_empty());
+}
@@ -0,0 +1,58 @@
+use helion_core::text::{HAlign, VAlign};
+use helion_core::tooltip::{Tooltip, TooltipOptions, TooltipPoint};
+
+fn point(value: Option<[f64; 2]>) -> TooltipPoint {
+    TooltipPoint { series: 1, index: 4, value }
+}
+
+#[test]
+fn test_default_text_rounds_values() {
+    let text = |value| Tooltip::default_text(&point(Some(value))).unwrap();
+    // f32 inputs widened to f64 print without their rounding noise
+    assert_eq!(text([0.3f32 as f64, -1234.5678]), "x: 0.3\ny: -1234.57");
+    assert_eq!(text([0.0, 1e9]), "x: 0\ny: 1e9");
+    assert_eq!(text([2.5e-5, 123456.0]), "x: 2.5e-5\ny: 123456");
+    assert_eq!(text([f64::INFINITY, 0.001]), "x: inf\ny: 0.001");
+    assert_eq!(Tooltip::default_text(&point(None)).unwrap(), "Point 4");
+}
+
+#[test]
+fn test_formatter_replaces_text() {
+    let mut tooltip = Tooltip::new().with_formatter(|p| (p.series == 1).then(|| format!("{:?}", p.value)));
+    assert_eq!(tooltip.text(&point(Some([1.0, 2.0]))).unwrap(), "Some([1.0, 2.0])");
+    let other = TooltipPoint { series: 0, ..point(None) };
+    assert_eq!(tooltip.text(&other), None);
+}
+
+#[test]
+fn test_bounds_stay_in_viewport() {
+    let options = TooltipOptions { padding: 5.0, offset: 10.0, ..TooltipOptions::default() };
+    let tooltip = Tooltip::new().with_options(options);
+    let viewport = [400.0, 300.0];
+
+    // Below and right of the cursor, padded around the text
+    assert_eq!(tooltip.bounds([50.0, 20.0], [100.0, 100.0], viewport), [110.0, 110.0, 60.0, 30.0]);
+    // Flipped left and up near the bottom-right corner
+    assert_eq!(tooltip.bounds([50.0, 20.0], [380.0, 290.0], viewport), [310.0, 250.0, 60.0, 30.0]);
+    // Never off the top-left when there is no room either way
+    assert_eq!(tooltip.bounds([500.0, 20.0], [20.0, 20.0], viewport)[0], 0.0);
+}
+
+#[test]
+fn test_box_and_text_placement() {
+    let tooltip = Tooltip::new();
+    let data = tooltip.quad_data([0.0, 0.0, 200.0, 150.0], [400.0, 300.0]);
+    assert_eq!(data.vertices.len(), 6);
+    let xs: Vec<f32> = data.vertices.iter().map(|v| v.position[0]).collect();
+    let ys: Vec<f32> = data.vertices.iter().map(|v| v.position[1]).collect();
+    assert_eq!(xs.iter().copied().fold(f32::MAX, f32::min), -1.0);
+    assert_eq!(xs.iter().copied().fold(f32::MIN, f32::max), 0.0);
+    assert_eq!(ys.iter().copied().fold(f32::MAX, f32::min), 0.0);
+    assert_eq!(ys.iter().copied().fold(f32::MIN, f32::max), 1.0);
+
+    let section = tooltip.text_section("x: 1", [10.0, 20.0, 50.0, 30.0]);
+    let padding = tooltip.options().padding;
+    assert_eq!(section.position, [10.0 + padding, 20.0 + padding]);
+    assert_eq!((section.h_align, section.v_align), (HAlign::Left, VAlign::Top));
+    assert_eq!(section.color, tooltip.options().text_color);
+}
@@ -0,0 +1,58 @@
+use helion_core::renderer::BlendMode;
+use helion_core::trajectory::{stamp_times, Playback, Trail, TrailUniforms};
+use helion_core::transition::FrameClock;
+use helion_core::ChartData;
+
+#[test]
+fn test_trail_fades_linearly_behind_head() {
+    let trail = Trail { head: 10.0, length: 4.0 };
+    let opacities: Vec<f32> = [6.0, 7.0, 8.0, 9.0, 10.0, 11.0].iter().map(|&t| trail.opacity(t)).collect();
+    assert_eq!(opacities, vec![0.0, 0.25, 0.5, 0.75, 1.0, 0.0]);
+    assert_eq!(trail.opacity(-100.0), 0.0);
+}
+
+#[test]
+fn test_unbounded_and_zero_length_trails() {
+    let whole = Trail { head: 10.0, length: f32::INFINITY };
+    assert_eq!(whole.opacity(-1e6), 1.0);
+    assert_eq!(whole.opacity(10.5), 0.0);
+    // The default shows everything
+    assert_eq!(Trail::default().opacity(1e30), 1.0);
+
+    let head_only = Trail { head: 3.0, length: 0.0 };
+    assert_eq!(head_only.opacity(3.0), 1.0);
+    assert_eq!(head_only.opacity(2.999), 0.0);
+}
+
+#[test]
+fn test_playback_follows_fixed_clock() {
+    let playback = Playback { speed: 30.0, length: 10.0 };
+    let mut clock = FrameClock::fixed(60.0);
+    let heads: Vec<f32> = (0..4).map(|_| playback.trail_at(clock.tick(), (5.0, 6.0)).head).collect();
+    assert_eq!(heads, vec![5.0, 5.5, 6.0, 6.0]);
+    // Time before the start doesn't move the head back
+    assert_eq!(playback.trail_at(-1.0, (5.0, 6.0)).head, 5.0);
+}
+
+#[test]
+fn test_uniforms() {
+    let uniforms = TrailUniforms::new(&Trail { head: 8.0, length: 2.0 }, BlendMode::Alpha);
+    assert_eq!((uniforms.head, uniforms.inv_length, uniforms.premultiplied), (8.0, 0.5, 0.0));
+    // Infinities never reach the shader
+    let uniforms = TrailUniforms::new(&Trail::default(), BlendMode::Additive);
+    assert_eq!((uniforms.head, uniforms.inv_length, uniforms.premultiplied), (f32::MAX, 0.0, 1.0));
+}
+
+#[test]
+fn test_stamp_times_keeps_position_and_color() {
+    let data = ChartData::from_line(&[0.0, 1.0, 2.0, 3.0], &[1.0, 0.0, 1.0, 0.0], None, 800.0, 600.0);
+    let by_index = stamp_times(&data.vertices, None).unwrap();
+    let by_time = stamp_times(&data.vertices, Some(&[0.0, 0.1, 0.25, 0.5])).unwrap();
+    assert_eq!(by_index.iter().map(|v| v.size).collect::<Vec<_>>(), vec![0.0, 1.0, 2.0, 3.0]);
+    assert_eq!(by_time.iter().map(|v| v.size).collect::<Vec<_>>(), vec![0.0, 0.1, 0.25, 0.5]);
+    for (stamped, original) in by_time.iter().zip(&data.vertices) {
+        assert_eq!((stamped.position, stamped.color), (original.position, original.color));
+    }
+    let error = stamp_times(&data.vertices, Some(&[0.0])).err().unwrap();
+    assert_eq!(error.to_string(), "Trajectory times, one per point: expected 4, got 1");
+}
@@ -0,0 +1,127 @@
+use helion_core::camera::{Camera2D, PanZoom};
+use helion_core::data::ChartData;
+use helion_core::renderer::RenderOptions;
+use helion_core::transition::{Easing, FrameClock, Transition, ViewRange};
+
+#[test]
+fn test_easing_endpoints() {
+    for easing in [Easing::Linear, Easing::EaseIn, Easing::EaseOut, Easing::EaseInOut] {
+        assert_eq!(easing.apply(0.0), 0.0);
+        assert_eq!(easing.apply(1.0), 1.0);
+        // Out-of-range input is clamped
+        assert_eq!(easing.apply(2.0), 1.0);
+    }
+    assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+}
+
+#[test]
+fn test_view_interpolation() {
+    let mut transition = Transition::new(
+        ViewRange::new((0.0, 10.0), (0.0, 100.0)),
+        ViewRange::new((10.0, 20.0), (50.0, 60.0)),
+        2.0,
+    )
+    .with_easing(Easing::Linear);
+
+    // Nothing moves before start()
+    assert_eq!(transition.progress(1.0), 0.0);
+    assert!(!transition.is_finished(10.0));
+
+    transition.start(1.0);
+    let halfway = transition.view_at(2.0);
+    assert_eq!(halfway.x, (5.0, 15.0));
+    assert_eq!(halfway.y, (25.0, 80.0));
+
+    assert!(transition.is_finished(3.0));
+    assert_eq!(transition.view_at(10.0).x, (10.0, 20.0));
+}
+
+#[test]
+fn test_camera_transition_lands_on_target() {
+    // What a window does to glide between two cameras
+    let clip = ViewRange::new((-1.0, 1.0), (-1.0, 1.0));
+    let mut zoomed = Camera2D::identity();
+    zoomed.zoom_about([4.0, 2.0], [0.5, -0.5]);
+    let mut transition = Transition::new(zoomed.visible_range(&clip), Camera2D::identity().visible_range(&clip), 0.25);
+    assert!(!transition.is_started());
+
+    let mut clock = FrameClock::fixed(60.0);
+    let mut pan_zoom = PanZoom::new(800.0, 600.0);
+    pan_zoom.set_camera(zoomed);
+    loop {
+        let time = clock.tick();
+        if !transition.is_started() {
+            transition.start(time);
+        }
+        let camera = Camera2D::from_view(&clip, &transition.view_at(time));
+        assert!(pan_zoom.set_camera(camera) || time == 0.0);
+        if transition.is_finished(time) {
+            break;
+        }
+    }
+    assert_eq!(*pan_zoom.camera(), Camera2D::identity());
+    // Fixed steps: the same number of frames every run
+    assert_eq!(clock.now(), 0.25);
+}
+
+#[test]
+fn test_zero_duration_jumps_to_target() {
+    let mut transition = Transition::new(ViewRange::default(), ViewRange::new((0.0, 1.0), (0.0, 1.0)), 0.0);
+    transition.start(0.0);
+    assert_eq!(transition.progress(0.0), 1.0);
+    assert_eq!(transition.view_at(0.0).x, (0.0, 1.0));
+}
+
+#[test]
+fn test_position_morph() {
+    let from = ChartData::from_scatter(&[0.0, 1.0], &[0.0, 1.0], None, None, 800.0, 600.0);
+    let to = ChartData::from_scatter(&[1.0, 0.0], &[1.0, 0.0], None, None, 800.0, 600.0);
+
+    let mut transition = Transition::new(ViewRange::default(), ViewRange::default(), 1.0)
+        .with_easing(Easing::Linear)
+        .with_positions(&from, &to);
+    assert!(transition.has_positions());
+    transition.start(0.0);
+
+    let mut data = ChartData::from_scatter(&[0.0, 1.0], &[0.0, 1.0], None, None, 800.0, 600.0);
+    transition.apply_positions(0.5, &mut data);
+    assert_eq!(data.vertices[0].position, [0.0, 0.0]);
+    assert_eq!(data.vertices[1].position, [0.0, 0.0]);
+
+    transition.apply_positions(1.0, &mut data);
+    assert_eq!(data.vertices[0].position, [1.0, 1.0]);
+    assert_eq!(data.vertices[1].position, [-1.0, -1.0]);
+}
+
+#[test]
+fn test_position_morph_skipped_on_length_mismatch() {
+    let from = ChartData::from_scatter(&[0.0, 1.0], &[0.0, 1.0], None, None, 800.0, 600.0);
+    let to = ChartData::from_scatter(&[0.0, 1.0, 2.0], &[0.0, 1.0, 2.0], None, None, 800.0, 600.0);
+
+    let transition = Transition::new(ViewRange::default(), ViewRange::default(), 1.0)
+        .with_positions(&from, &to);
+    assert!(!transition.has_positions());
+}
+
+#[test]
+fn test_fixed_clock_does_not_drift() {
+    let mut clock = FrameClock::fixed(60.0);
+    let times: Vec<f32> = (0..=600).map(|_| clock.tick()).collect();
+    assert_eq!(times[0], 0.0);
+    assert_eq!(times[600], 10.0);
+
+    // Two clocks replay the same frame times
+    let mut replay = FrameClock::fixed(60.0);
+    assert!((0..=600).all(|i| replay.tick() == times[i]));
+}
+
+#[test]
+fn test_clock_for_options() {
+    let options = RenderOptions::default();
+    assert!(!FrameClock::for_options(&options, 30.0).is_fixed());
+    assert!(FrameClock::for_options(&options.with_deterministic(true), 30.0).is_fixed());
+
+    let mut clock = FrameClock::realtime();
+    let first = clock.tick();
+    assert!(clock.tick() >= first);
+}
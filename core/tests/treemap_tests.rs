@@ -0,0 +1,81 @@
+use helion_core::hierarchy::HierarchyNode;
+use helion_core::treemap::Treemap;
+
+fn disk() -> Treemap {
+    let root = HierarchyNode::group(
+        "Disk",
+        vec![
+            HierarchyNode::group("src", vec![HierarchyNode::leaf("lib.rs", 6.0), HierarchyNode::leaf("main.rs", 2.0)]),
+            HierarchyNode::leaf("README.md", 4.0),
+            HierarchyNode::leaf("empty", 0.0),
+        ],
+    );
+    Treemap::new(root).unwrap()
+}
+
+fn area([_, _, w, h]: [f32; 4]) -> f32 {
+    w * h
+}
+
+#[test]
+fn test_top_level_areas_are_proportional() {
+    let treemap = disk();
+    let tiles = treemap.layout(300.0, 200.0);
+    // Zero-valued nodes get no tile; parents come before children
+    let nodes: Vec<usize> = tiles.iter().map(|tile| tile.node).collect();
+    assert_eq!(nodes, [1, 2, 3, 4]);
+
+    let src = area(tiles[0].rect);
+    let readme = area(tiles[3].rect);
+    assert!((src + readme - 300.0 * 200.0).abs() < 1.0);
+    assert!((src / readme - 2.0).abs() < 1e-3);
+
+    // Children stay inside their group's frame, in proportion
+    let [x, y, w, h] = tiles[0].rect;
+    for tile in &tiles[1..3] {
+        let [cx, cy, cw, ch] = tile.rect;
+        assert!(cx >= x && cy >= y + 3.0 && cx + cw <= x + w + 1e-3 && cy + ch <= y + h + 1e-3);
+    }
+    assert!((area(tiles[1].rect) / area(tiles[2].rect) - 3.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_squarified_tiles_stay_near_square() {
+    let leaves = (0..6).map(|i| HierarchyNode::leaf(format!("{}", i), 1.0)).collect();
+    let treemap = Treemap::new(HierarchyNode::group("root", leaves)).unwrap();
+    for tile in treemap.layout(300.0, 200.0) {
+        let [_, _, w, h] = tile.rect;
+        assert!(w.max(h) / w.min(h) < 1.6, "{:?}", tile);
+    }
+}
+
+#[test]
+fn test_pick_ids_round_trip() {
+    let treemap = disk();
+    for node in 1..treemap.hierarchy().len() {
+        assert_eq!(treemap.node_for_pick_id(treemap.pick_id(node)), Some(node));
+    }
+    assert_eq!(treemap.node_for_pick_id(0), None);
+    assert_eq!(treemap.node_for_pick_id(99), None);
+
+    // The header of "src" belongs to it, the space inside to its children
+    let [x, y, _, _] = treemap.layout(300.0, 200.0)[0].rect;
+    assert_eq!(treemap.node_at(x + 10.0, y + 5.0, 300.0, 200.0), Some(1));
+    assert_eq!(treemap.node_at(x + 10.0, y + 30.0, 300.0, 200.0), Some(2));
+}
+
+#[test]
+fn test_deeper_levels_are_lighter() {
+    let treemap = disk();
+    let brightness = |node| {
+        let color = treemap.node_color(node);
+        color.r + color.g + color.b
+    };
+    assert!(brightness(2) > brightness(1));
+    assert_ne!(treemap.node_color(1), treemap.node_color(4));
+
+    let data = treemap.chart_data(300.0, 200.0);
+    assert_eq!(data.vertices.len(), 4 * 6);
+    let sections = treemap.text_sections(300.0, 200.0);
+    assert!(sections.iter().any(|section| section.text == "src"));
+}
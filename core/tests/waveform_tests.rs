@@ -0,0 +1,84 @@
+use helion_core::waveform::Waveform;
+
+fn brute_force(samples: &[f32], from: usize, to: usize) -> [f32; 2] {
+    samples[from..to].iter().fold([f32::INFINITY, f32::NEG_INFINITY], |[lo, hi], &s| [lo.min(s), hi.max(s)])
+}
+
+fn noise(len: usize) -> Vec<f32> {
+    let mut state = 12345u32;
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+            (state >> 8) as f32 / (1 << 24) as f32 * 2.0 - 1.0
+        })
+        .collect()
+}
+
+#[test]
+fn test_pyramid_levels() {
+    let waveform = Waveform::new(vec![0.0; 1000], 1000.0).unwrap();
+    // 63 leaves of 16 samples, halving down to one block
+    assert_eq!(waveform.level_count(), 7);
+    assert_eq!(Waveform::new(vec![0.0; 16], 1000.0).unwrap().level_count(), 1);
+    assert!(Waveform::new(vec![], 0.0).is_err());
+    assert!(Waveform::new(vec![], f32::NAN).is_err());
+}
+
+#[test]
+fn test_envelope_matches_brute_force_on_block_boundaries() {
+    let samples = noise(64 * 1024);
+    let waveform = Waveform::new(samples.clone(), 1024.0).unwrap();
+    // 1024 samples per column reads level 6 directly
+    let envelope = waveform.envelope(0.0, 64.0, 64);
+    for (c, column) in envelope.iter().enumerate() {
+        assert_eq!(*column, brute_force(&samples, c * 1024, (c + 1) * 1024));
+    }
+    // Fewer than 16 samples per column scans the samples
+    let envelope = waveform.envelope(1.0, 1.5, 64);
+    for (c, column) in envelope.iter().enumerate() {
+        assert_eq!(*column, brute_force(&samples, 1024 + c * 8, 1024 + (c + 1) * 8));
+    }
+}
+
+#[test]
+fn test_unaligned_envelope_covers_column() {
+    let samples = noise(10_000);
+    let waveform = Waveform::new(samples.clone(), 1000.0).unwrap();
+    let columns = 37;
+    let envelope = waveform.envelope(0.123, 9.456, columns);
+    let per_column = (9.456 - 0.123) * 1000.0 / columns as f64;
+    for (c, &[lo, hi]) in envelope.iter().enumerate() {
+        let from = (123.0 + c as f64 * per_column).ceil() as usize;
+        let to = (123.0 + (c + 1) as f64 * per_column).floor() as usize;
+        let [true_lo, true_hi] = brute_force(&samples, from, to);
+        assert!(lo <= true_lo && hi >= true_hi);
+    }
+}
+
+#[test]
+fn test_zoomed_in_and_out_of_range() {
+    let waveform = Waveform::new(vec![0.1, 0.2, 0.3, f32::NAN], 4.0).unwrap();
+    // Columns narrower than a sample show the sample under them
+    let envelope = waveform.envelope(0.0, 0.5, 4);
+    assert_eq!(envelope, vec![[0.1, 0.1], [0.1, 0.1], [0.2, 0.2], [0.2, 0.2]]);
+    // Past the end and NaN-only columns are empty
+    let envelope = waveform.envelope(0.75, 2.0, 5);
+    assert!(envelope.iter().all(|&[lo, hi]| lo > hi));
+}
+
+#[test]
+fn test_from_i16_and_chart_data() {
+    let waveform = Waveform::from_i16(&[i16::MIN, 0, 16384, 0], 4.0).unwrap();
+    assert_eq!(waveform.samples(), &[-1.0, 0.0, 0.5, 0.0]);
+    assert_eq!(waveform.duration(), 1.0);
+
+    let data = waveform.chart_data(0.0, 1.0, 4.0, 100.0);
+    assert_eq!(data.vertices.len(), 4 * 6);
+    let ys: Vec<f32> = data.vertices[12..18].iter().map(|v| v.position[1]).collect();
+    // A single sample still draws a bar one pixel tall
+    let (lo, hi) = ys.iter().fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &y| (lo.min(y), hi.max(y)));
+    assert!((lo - 0.49).abs() < 1e-6 && (hi - 0.51).abs() < 1e-6);
+
+    // Nothing is drawn past the end of the recording
+    assert!(waveform.chart_data(2.0, 3.0, 4.0, 100.0).vertices.is_empty());
+}
@@ -0,0 +1,88 @@
+use helion_core::colormap::ColormapLut;
+use helion_core::data::{Color, Point2D};
+use helion_core::error::HelionError;
+use helion_core::polygon::Polygon;
+use helion_core::windrose::{polar_point, Windrose};
+
+fn close(a: Point2D, b: Point2D) -> bool {
+    (a.x - b.x).abs() < 1e-5 && (a.y - b.y).abs() < 1e-5
+}
+
+#[test]
+fn test_polar_point_uses_compass_bearings() {
+    assert!(close(polar_point(0.0, 1.0), Point2D::new(0.0, 1.0)));
+    assert!(close(polar_point(90.0, 2.0), Point2D::new(2.0, 0.0)));
+    assert!(close(polar_point(180.0, 1.0), Point2D::new(0.0, -1.0)));
+    assert!(close(polar_point(-90.0, 1.0), Point2D::new(-1.0, 0.0)));
+}
+
+#[test]
+fn test_directions_wrap_into_sectors() {
+    let directions = [359.0, 44.9, 45.0, 720.0, -90.0, 180.0, f32::NAN];
+    let speeds = [1.0; 7];
+    let rose = Windrose::compute(&directions, &speeds, 4, &[0.0, 10.0]).unwrap();
+    assert_eq!(rose.counts()[0], vec![3.0, 1.0, 1.0, 1.0]);
+    assert_eq!(rose.sector_center(1), 90.0);
+}
+
+#[test]
+fn test_magnitudes_binned_like_a_histogram() {
+    let directions = [0.0; 6];
+    let speeds = [-1.0, 0.0, 2.0, 4.0, 6.0, 6.5];
+    let rose = Windrose::compute(&directions, &speeds, 8, &[0.0, 2.0, 6.0]).unwrap();
+    // Out of range speeds are left out; the last edge is inclusive
+    assert_eq!(rose.counts()[0][0], 1.0);
+    assert_eq!(rose.counts()[1][0], 3.0);
+
+    let rose = rose.normalized();
+    assert_eq!(rose.sector_totals()[0], 100.0);
+    assert_eq!(rose.counts()[0][0], 25.0);
+}
+
+#[test]
+fn test_wedges_stack_outwards() {
+    let directions = [0.0, 0.0, 0.0, 90.0];
+    let speeds = [1.0, 1.0, 5.0, 5.0];
+    let rose = Windrose::compute(&directions, &speeds, 4, &[0.0, 2.0, 10.0]).unwrap().with_opening(1.0);
+    let wedges = rose.wedges();
+    assert_eq!(wedges.iter().map(|(_, level)| *level).collect::<Vec<_>>(), [0, 1, 1]);
+
+    // North's calm level reaches 2/3 of the way out, its strong level the rim
+    let radius = |p: &Point2D| (p.x * p.x + p.y * p.y).sqrt();
+    let rim = |polygon: &Polygon| polygon.exterior.iter().map(radius).fold(0.0, f32::max);
+    assert!((rim(&wedges[0].0) - 2.0 / 3.0).abs() < 1e-5);
+    assert!((rim(&wedges[1].0) - 1.0).abs() < 1e-5);
+    assert!((rim(&wedges[2].0) - 1.0 / 3.0).abs() < 1e-5);
+    // A full opening covers the whole quarter circle
+    let area: f32 = wedges[..2].iter().map(|(polygon, _)| polygon.area()).sum();
+    assert!((area - std::f32::consts::PI / 4.0).abs() < 0.02, "{}", area);
+}
+
+#[test]
+fn test_chart_data_is_round_and_colored_by_level() {
+    let rose = Windrose::compute(&[0.0, 180.0], &[1.0, 3.0], 8, &[0.0, 2.0, 4.0])
+        .unwrap()
+        .with_colormap(ColormapLut::grayscale());
+    assert_eq!(rose.level_colors(), [Color::new(0.0, 0.0, 0.0, 1.0), Color::new(1.0, 1.0, 1.0, 1.0)]);
+
+    let data = rose.chart_data(800.0, 400.0);
+    assert!(!data.vertices.is_empty());
+    let xs = data.vertices.iter().map(|v| v.position[0].abs());
+    assert!(xs.fold(0.0, f32::max) <= 0.5 + 1e-5);
+    assert_eq!(data.vertices[0].color, [0.0, 0.0, 0.0, 1.0]);
+    assert_eq!(data.vertices.last().unwrap().color, [1.0, 1.0, 1.0, 1.0]);
+
+    let empty = Windrose::compute(&[], &[], 8, &[0.0, 1.0]).unwrap();
+    assert!(empty.chart_data(800.0, 400.0).vertices.is_empty());
+}
+
+#[test]
+fn test_invalid_windrose() {
+    assert!(matches!(
+        Windrose::compute(&[0.0], &[], 4, &[0.0, 1.0]),
+        Err(HelionError::ShapeMismatch { expected: 1, actual: 0, .. })
+    ));
+    assert!(matches!(Windrose::compute(&[], &[], 0, &[0.0, 1.0]), Err(HelionError::InvalidOption(_))));
+    assert!(matches!(Windrose::compute(&[], &[], 4, &[1.0]), Err(HelionError::InvalidOption(_))));
+    assert!(matches!(Windrose::compute(&[], &[], 4, &[1.0, 1.0]), Err(HelionError::InvalidOption(_))));
+}